@@ -10,14 +10,17 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use std::collections::VecDeque;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::Infallible;
 use std::sync::RwLock;
 
 use hashbrown::HashMap;
-use petgraph::graph::NodeIndex;
+use petgraph::graph::{IndexType, NodeIndex};
 use petgraph::visit::{
+    EdgeRef,
     GraphBase,
     GraphProp, // allows is_directed
+    IntoEdges,
     IntoNeighborsDirected,
     IntoNodeIdentifiers,
     NodeCount,
@@ -25,6 +28,9 @@ use petgraph::visit::{
 };
 use rayon::prelude::*;
 
+use crate::min_scored::MinScored;
+use crate::Error;
+
 /// Compute the betweenness centrality of all nodes in a graph.
 ///
 /// The algorithm used in this function is based on:
@@ -65,7 +71,7 @@ use rayon::prelude::*;
 ///     output
 /// );
 /// ```
-pub fn betweenness_centrality<G>(
+pub fn betweenness_centrality<G, Ix>(
     graph: G,
     endpoints: bool,
     normalized: bool,
@@ -77,8 +83,9 @@ where
         + IntoNeighborsDirected
         + NodeCount
         + GraphProp
-        + GraphBase<NodeId = NodeIndex>
+        + GraphBase<NodeId = NodeIndex<Ix>>
         + std::marker::Sync,
+    Ix: IndexType + Send + Sync,
     // rustfmt deletes the following comments if placed inline above
     // + IntoNodeIdentifiers // for node_identifiers()
     // + IntoNeighborsDirected // for neighbors()
@@ -102,7 +109,7 @@ where
         betweenness[is] = Some(0.0);
     }
     let locked_betweenness = RwLock::new(&mut betweenness);
-    let node_indices: Vec<NodeIndex> = graph.node_identifiers().collect();
+    let node_indices: Vec<NodeIndex<Ix>> = graph.node_identifiers().collect();
     if graph.node_count() < parallel_threshold {
         node_indices
             .iter()
@@ -190,10 +197,10 @@ fn _rescale(
     }
 }
 
-fn _accumulate_basic(
+fn _accumulate_basic<Ix: IndexType>(
     locked_betweenness: &RwLock<&mut Vec<Option<f64>>>,
     max_index: usize,
-    path_calc: &mut ShortestPathData,
+    path_calc: &mut ShortestPathData<Ix>,
     is: usize,
 ) {
     let mut delta = vec![0.0; max_index];
@@ -215,10 +222,10 @@ fn _accumulate_basic(
     }
 }
 
-fn _accumulate_endpoints(
+fn _accumulate_endpoints<Ix: IndexType>(
     locked_betweenness: &RwLock<&mut Vec<Option<f64>>>,
     max_index: usize,
-    path_calc: &mut ShortestPathData,
+    path_calc: &mut ShortestPathData<Ix>,
     is: usize,
 ) {
     let mut delta = vec![0.0; max_index];
@@ -242,27 +249,28 @@ fn _accumulate_endpoints(
     }
 }
 
-struct ShortestPathData {
-    verts_sorted_by_distance: Vec<NodeIndex>,
-    predecessors: HashMap<NodeIndex, Vec<NodeIndex>>,
-    sigma: HashMap<NodeIndex, f64>,
+struct ShortestPathData<Ix> {
+    verts_sorted_by_distance: Vec<NodeIndex<Ix>>,
+    predecessors: HashMap<NodeIndex<Ix>, Vec<NodeIndex<Ix>>>,
+    sigma: HashMap<NodeIndex<Ix>, f64>,
 }
 
-fn shortest_path_for_centrality<G>(graph: G, node_s: &G::NodeId) -> ShortestPathData
+fn shortest_path_for_centrality<G, Ix>(graph: G, node_s: &G::NodeId) -> ShortestPathData<Ix>
 where
     G: NodeIndexable
         + IntoNodeIdentifiers
         + IntoNeighborsDirected
         + NodeCount
-        + GraphBase<NodeId = NodeIndex>, // for get() and get_mut()
+        + GraphBase<NodeId = NodeIndex<Ix>>, // for get() and get_mut()
+    Ix: IndexType,
 {
-    let mut verts_sorted_by_distance: Vec<NodeIndex> = Vec::new(); // a stack
+    let mut verts_sorted_by_distance: Vec<NodeIndex<Ix>> = Vec::new(); // a stack
     let c = graph.node_count();
     let mut predecessors = HashMap::<G::NodeId, Vec<G::NodeId>>::with_capacity(c);
     let mut sigma = HashMap::<G::NodeId, f64>::with_capacity(c);
     let mut distance = HashMap::<G::NodeId, i64>::with_capacity(c);
     #[allow(non_snake_case)]
-    let mut Q: VecDeque<NodeIndex> = VecDeque::with_capacity(c);
+    let mut Q: VecDeque<NodeIndex<Ix>> = VecDeque::with_capacity(c);
 
     let i_s = graph.to_index(*node_s);
     let index_s = NodeIndex::new(i_s);
@@ -297,3 +305,626 @@ where
         sigma,
     }
 }
+
+/// Compute the betweenness centrality of all nodes in a graph with weighted
+/// edges.
+///
+/// This is the same as [`betweenness_centrality`] except that the shortest
+/// paths are those with the lowest total edge cost, which are counted with
+/// Dijkstra's algorithm instead of a breadth first search as described in
+/// the same paper by Brandes. The function `edge_cost` should return the
+/// non-negative cost of a particular edge. It's called once for each edge
+/// (twice for each edge of an undirected graph, once in each direction)
+/// before any paths are counted, so it doesn't need to be thread safe, and
+/// if it returns an error it is returned as [`Error::Callback`]. Paths are
+/// only counted as equally short if their costs are exactly equal.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `edge_cost` - The function returning the cost of each edge
+/// * `endpoints` - Whether to include the endpoints of paths in the path
+///   lengths used to compute the betweenness
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::weighted_betweenness_centrality;
+/// use retworkx_core::{Error, Result};
+///
+/// // The path 0 - 1 - 2 is cheaper than the direct edge 0 - 2
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)
+/// ]);
+/// let output: Result<Vec<Option<f64>>, Error> =
+///     weighted_betweenness_centrality(&g, |e| Ok(*e.weight()), false, false, 200);
+/// assert_eq!(vec![Some(0.0), Some(1.0), Some(0.0)], output.unwrap());
+/// ```
+pub fn weighted_betweenness_centrality<G, F, E, Ix>(
+    graph: G,
+    mut edge_cost: F,
+    endpoints: bool,
+    normalized: bool,
+    parallel_threshold: usize,
+) -> Result<Vec<Option<f64>>, Error<E>>
+where
+    G: NodeIndexable
+        + IntoNodeIdentifiers
+        + IntoEdges
+        + NodeCount
+        + GraphProp
+        + GraphBase<NodeId = NodeIndex<Ix>>,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    Ix: IndexType + Send + Sync,
+{
+    let max_index = graph.node_bound();
+    // Evaluate the cost of every edge once up front, the searches from each
+    // node only read the costs so they can run in parallel.
+    let mut adjacency: Vec<Vec<(NodeIndex<Ix>, f64)>> = vec![Vec::new(); max_index];
+    for node in graph.node_identifiers() {
+        for edge in graph.edges(node) {
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            adjacency[graph.to_index(node)].push((edge.target(), cost));
+        }
+    }
+
+    let mut betweenness: Vec<Option<f64>> = vec![None; max_index];
+    for node_s in graph.node_identifiers() {
+        betweenness[graph.to_index(node_s)] = Some(0.0);
+    }
+    let locked_betweenness = RwLock::new(&mut betweenness);
+    let node_indices: Vec<NodeIndex<Ix>> = graph.node_identifiers().collect();
+    let node_count = graph.node_count();
+    let accumulate = |node_s: &NodeIndex<Ix>| {
+        let mut shortest_path_calc =
+            weighted_shortest_path_for_centrality(&adjacency, *node_s, node_count);
+        let is = node_s.index();
+        if endpoints {
+            _accumulate_endpoints(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+        } else {
+            _accumulate_basic(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+        }
+    };
+    if node_count < parallel_threshold {
+        node_indices.iter().for_each(accumulate);
+    } else {
+        node_indices.par_iter().for_each(accumulate);
+    }
+    _rescale(
+        &mut betweenness,
+        node_count,
+        normalized,
+        graph.is_directed(),
+        endpoints,
+    );
+
+    Ok(betweenness)
+}
+
+fn weighted_shortest_path_for_centrality<Ix: IndexType>(
+    adjacency: &[Vec<(NodeIndex<Ix>, f64)>],
+    node_s: NodeIndex<Ix>,
+    node_count: usize,
+) -> ShortestPathData<Ix> {
+    let mut verts_sorted_by_distance: Vec<NodeIndex<Ix>> = Vec::new(); // a stack
+    let mut predecessors = HashMap::<NodeIndex<Ix>, Vec<NodeIndex<Ix>>>::with_capacity(node_count);
+    let mut sigma = HashMap::<NodeIndex<Ix>, f64>::with_capacity(node_count);
+    let mut distance = HashMap::<NodeIndex<Ix>, f64>::with_capacity(node_count);
+    let mut finished: Vec<bool> = vec![false; adjacency.len()];
+    let mut heap: BinaryHeap<MinScored<f64, NodeIndex<Ix>>> = BinaryHeap::new();
+
+    predecessors.insert(node_s, Vec::new());
+    sigma.insert(node_s, 1.0);
+    distance.insert(node_s, 0.0);
+    heap.push(MinScored(0.0, node_s));
+    while let Some(MinScored(distance_v, v)) = heap.pop() {
+        if finished[v.index()] {
+            continue;
+        }
+        finished[v.index()] = true;
+        verts_sorted_by_distance.push(v);
+        let sigma_v = sigma[&v];
+        for &(w, cost) in &adjacency[v.index()] {
+            // A node's predecessors have to come before it in the order the
+            // nodes are finished, so once a node is finished no more paths
+            // to it are counted, which only happens with zero cost edges.
+            if finished[w.index()] {
+                continue;
+            }
+            let distance_w = distance_v + cost;
+            match distance.get(&w) {
+                Some(current) if distance_w > *current => {}
+                Some(current) if distance_w == *current => {
+                    *sigma.get_mut(&w).unwrap() += sigma_v;
+                    predecessors.get_mut(&w).unwrap().push(v);
+                }
+                _ => {
+                    distance.insert(w, distance_w);
+                    sigma.insert(w, sigma_v);
+                    predecessors.insert(w, vec![v]);
+                    heap.push(MinScored(distance_w, w));
+                }
+            }
+        }
+    }
+    verts_sorted_by_distance.reverse(); // will be effectively popping from the stack
+    ShortestPathData {
+        verts_sorted_by_distance,
+        predecessors,
+        sigma,
+    }
+}
+
+/// Compute the closeness centrality of all nodes in a graph.
+///
+/// The closeness centrality of a node `u` is the reciprocal of the average
+/// shortest path distance to `u` over the `n - 1` other nodes. For a
+/// directed graph this is the distance of the paths ending at `u`, the
+/// distances are computed with a breadth first search over the incoming
+/// edges of each node. Only the nodes which can reach `u` are counted, so
+/// for a graph which isn't connected the closeness is scaled by the
+/// fraction of the other nodes which can reach `u` if `wf_improved` is set,
+/// which is the improved formula of Wasserman and Faust:
+///
+/// Wasserman, S. and Faust, K., Social Network Analysis: Methods and
+/// Applications. Cambridge University Press, 1994.
+///
+/// A node which no other node can reach has a closeness of `0.0`.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Returns a `Vec` with the closeness centrality of each node indexed by
+/// [`NodeIndexable::to_index`], with [`None`] for indices which aren't nodes.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::closeness_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4)]);
+/// let output = closeness_centrality(&g, true, 200);
+/// assert_eq!(
+///     vec![Some(1.0 / 3.0), Some(0.5), Some(1.0 / 3.0), Some(0.25), Some(0.25)],
+///     output
+/// );
+/// ```
+pub fn closeness_centrality<G>(
+    graph: G,
+    wf_improved: bool,
+    parallel_threshold: usize,
+) -> Vec<Option<f64>>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdges + NodeCount,
+{
+    let incoming = incoming_adjacency(graph, |_| Ok::<f64, Infallible>(1.0)).unwrap();
+    closeness_from_sources(graph, &incoming, wf_improved, parallel_threshold, |node| {
+        let mut distance: Vec<Option<usize>> = vec![None; incoming.len()];
+        let mut reached: usize = 0;
+        let mut total: f64 = 0.0;
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        distance[node] = Some(0);
+        queue.push_back(node);
+        while let Some(v) = queue.pop_front() {
+            let distance_v = distance[v].unwrap();
+            reached += 1;
+            total += distance_v as f64;
+            for &(w, _) in &incoming[v] {
+                if distance[w].is_none() {
+                    distance[w] = Some(distance_v + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+        (reached, total)
+    })
+}
+
+/// Compute the closeness centrality of all nodes in a graph with weighted
+/// edges.
+///
+/// This is the same as [`closeness_centrality`] except that the distance
+/// of a path is the total cost of its edges, and the distances are computed
+/// with Dijkstra's algorithm. The function `edge_cost` should return the
+/// non-negative cost of a particular edge. It's called once for each edge
+/// (twice for each edge of an undirected graph, once in each direction)
+/// before any distances are computed, if it returns an error it is returned
+/// as [`Error::Callback`].
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::weighted_closeness_centrality;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[(0, 1, 1.0), (1, 2, 3.0)]);
+/// let output: Result<Vec<Option<f64>>, Error> =
+///     weighted_closeness_centrality(&g, |e| Ok(*e.weight()), true, 200);
+/// assert_eq!(vec![Some(0.4), Some(0.5), Some(2.0 / 7.0)], output.unwrap());
+/// ```
+pub fn weighted_closeness_centrality<G, F, E>(
+    graph: G,
+    edge_cost: F,
+    wf_improved: bool,
+    parallel_threshold: usize,
+) -> Result<Vec<Option<f64>>, Error<E>>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdges + NodeCount,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let incoming = incoming_adjacency(graph, edge_cost)?;
+    Ok(closeness_from_sources(
+        graph,
+        &incoming,
+        wf_improved,
+        parallel_threshold,
+        |node| {
+            let mut distance: Vec<Option<f64>> = vec![None; incoming.len()];
+            let mut finished: Vec<bool> = vec![false; incoming.len()];
+            let mut reached: usize = 0;
+            let mut total: f64 = 0.0;
+            let mut heap: BinaryHeap<MinScored<f64, usize>> = BinaryHeap::new();
+            distance[node] = Some(0.0);
+            heap.push(MinScored(0.0, node));
+            while let Some(MinScored(distance_v, v)) = heap.pop() {
+                if finished[v] {
+                    continue;
+                }
+                finished[v] = true;
+                reached += 1;
+                total += distance_v;
+                for &(w, cost) in &incoming[v] {
+                    let distance_w = distance_v + cost;
+                    let improved = match distance[w] {
+                        Some(current) => distance_w < current,
+                        None => true,
+                    };
+                    if improved {
+                        distance[w] = Some(distance_w);
+                        heap.push(MinScored(distance_w, w));
+                    }
+                }
+            }
+            (reached, total)
+        },
+    ))
+}
+
+/// The other endpoint and cost of the edges into each node, indexed by
+/// [`NodeIndexable::to_index`].
+fn incoming_adjacency<G, F, E>(
+    graph: G,
+    mut edge_cost: F,
+) -> Result<Vec<Vec<(usize, f64)>>, Error<E>>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdges,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut incoming: Vec<Vec<(usize, f64)>> = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        for edge in graph.edges(node) {
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            incoming[graph.to_index(edge.target())].push((graph.to_index(node), cost));
+        }
+    }
+    Ok(incoming)
+}
+
+/// Compute the closeness of every node from the number of nodes reaching it,
+/// including itself, and their total distance, returned by `search`.
+fn closeness_from_sources<G, S>(
+    graph: G,
+    incoming: &[Vec<(usize, f64)>],
+    wf_improved: bool,
+    parallel_threshold: usize,
+    search: S,
+) -> Vec<Option<f64>>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + NodeCount,
+    S: Fn(usize) -> (usize, f64) + Sync,
+{
+    let node_count = graph.node_count();
+    let closeness = |node: &usize| -> (usize, f64) {
+        let (reached, total) = search(*node);
+        let mut closeness = 0.0;
+        if total > 0.0 && node_count > 1 {
+            closeness = (reached - 1) as f64 / total;
+            if wf_improved {
+                closeness *= (reached - 1) as f64 / (node_count - 1) as f64;
+            }
+        }
+        (*node, closeness)
+    };
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let scores: Vec<(usize, f64)> = if node_count < parallel_threshold {
+        nodes.iter().map(closeness).collect()
+    } else {
+        nodes.par_iter().map(closeness).collect()
+    };
+    let mut out: Vec<Option<f64>> = vec![None; incoming.len()];
+    for (node, score) in scores {
+        out[node] = Some(score);
+    }
+    out
+}
+
+/// The weighted adjacency matrix of a graph in compressed sparse row form.
+///
+/// This is the sparse matrix used by the power iteration based centrality
+/// measures, such as PageRank, eigenvector, Katz and HITS centrality, which
+/// all repeatedly multiply a vector of node scores by the adjacency matrix.
+/// The rows and columns are the node indices from
+/// [`NodeIndexable::to_index`], so vectors of scores have an entry for every
+/// index up to [`node_bound`](WeightedAdjacency::node_bound), and the
+/// weight of each edge is evaluated only once when the matrix is built.
+///
+/// A vector is multiplied by the matrix with
+/// [`propagate`](WeightedAdjacency::propagate), which sends the score of
+/// each node along its outgoing edges, and by the transposed matrix with
+/// [`gather`](WeightedAdjacency::gather), which collects the scores of the
+/// nodes at the end of the outgoing edges of each node. For an undirected
+/// graph both are the same.
+pub struct WeightedAdjacency {
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    weights: Vec<f64>,
+    out_weights: Vec<f64>,
+    nodes: Vec<usize>,
+}
+
+impl WeightedAdjacency {
+    /// Build the weighted adjacency matrix of `graph`.
+    ///
+    /// The function `edge_weight` should return the weight of a particular
+    /// edge. It's called once for each edge (twice for each edge of an
+    /// undirected graph, once in each direction), if it returns an error it
+    /// is returned as [`Error::Callback`].
+    pub fn new<G, F, E>(graph: G, mut edge_weight: F) -> Result<Self, Error<E>>
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    {
+        let node_bound = graph.node_bound();
+        let mut rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_bound];
+        let mut nodes: Vec<usize> = Vec::new();
+        for node in graph.node_identifiers() {
+            let index = graph.to_index(node);
+            nodes.push(index);
+            for edge in graph.edges(node) {
+                let weight = edge_weight(edge).map_err(Error::Callback)?;
+                rows[index].push((graph.to_index(edge.target()), weight));
+            }
+        }
+        nodes.sort_unstable();
+        let mut indptr: Vec<usize> = Vec::with_capacity(node_bound + 1);
+        let mut indices: Vec<usize> = Vec::new();
+        let mut weights: Vec<f64> = Vec::new();
+        let mut out_weights: Vec<f64> = vec![0.; node_bound];
+        indptr.push(0);
+        for (index, row) in rows.into_iter().enumerate() {
+            for (target, weight) in row {
+                indices.push(target);
+                weights.push(weight);
+                out_weights[index] += weight;
+            }
+            indptr.push(indices.len());
+        }
+        Ok(WeightedAdjacency {
+            indptr,
+            indices,
+            weights,
+            out_weights,
+            nodes,
+        })
+    }
+
+    /// The length of a vector of scores, one more than the largest node
+    /// index.
+    pub fn node_bound(&self) -> usize {
+        self.out_weights.len()
+    }
+
+    /// The indices of the nodes of the graph, in increasing order. Scores
+    /// at any other index up to [`node_bound`](WeightedAdjacency::node_bound)
+    /// aren't used.
+    pub fn nodes(&self) -> &[usize] {
+        &self.nodes
+    }
+
+    /// The total weight of the outgoing edges of the node at `index`.
+    pub fn out_weight(&self, index: usize) -> f64 {
+        self.out_weights[index]
+    }
+
+    /// Add the score of each node in `x`, times the weight of the edge and
+    /// `scale`, to the entry of `out` for the target of each of its outgoing
+    /// edges. If `normalized` is true the weights of the outgoing edges of
+    /// each node are divided by their total, as for the transition matrix of
+    /// a random walk, and nodes without outgoing edges send nothing.
+    pub fn propagate(&self, x: &[f64], out: &mut [f64], scale: f64, normalized: bool) {
+        for &node in &self.nodes {
+            let mut share = scale * x[node];
+            if normalized {
+                if self.out_weights[node] == 0. {
+                    continue;
+                }
+                share /= self.out_weights[node];
+            }
+            for arc in self.indptr[node]..self.indptr[node + 1] {
+                out[self.indices[arc]] += share * self.weights[arc];
+            }
+        }
+    }
+
+    /// Add the sum of the scores in `x` of the targets of the outgoing edges
+    /// of each node, times the weight of the edge and `scale`, to the entry
+    /// of `out` for the node.
+    pub fn gather(&self, x: &[f64], out: &mut [f64], scale: f64) {
+        for &node in &self.nodes {
+            let sum: f64 = (self.indptr[node]..self.indptr[node + 1])
+                .map(|arc| self.weights[arc] * x[self.indices[arc]])
+                .sum();
+            out[node] += scale * sum;
+        }
+    }
+}
+
+/// Run a power iteration until the scores converge.
+///
+/// Starting from the vector of scores `start`, indexed by node index, each
+/// iteration calls `step` with the current scores and a vector of zeros of
+/// the same length, which it should fill in with the next scores. Damping,
+/// personalization and normalization are done in `step`, typically using
+/// the matrix products of a [`WeightedAdjacency`]. The iteration stops once
+/// the sum of the absolute differences between the scores of consecutive
+/// iterations, over the indices in `nodes`, is less than `tol` times the
+/// number of nodes.
+///
+/// Returns the converged scores, or [`None`] if they don't converge within
+/// `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::{power_iteration, WeightedAdjacency};
+/// use retworkx_core::{Error, Result};
+///
+/// // PageRank with a damping factor of 0.85
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 1),
+/// ]);
+/// let res: Result<WeightedAdjacency, Error> = WeightedAdjacency::new(&g, |_| Ok(1.));
+/// let adjacency = res.unwrap();
+/// let n = adjacency.nodes().len() as f64;
+/// let alpha = 0.85;
+/// let ranks = power_iteration(
+///     vec![1. / n; adjacency.node_bound()],
+///     adjacency.nodes(),
+///     100,
+///     1e-10,
+///     |ranks, next| {
+///         let dangling: f64 = adjacency
+///             .nodes()
+///             .iter()
+///             .filter(|node| adjacency.out_weight(**node) == 0.)
+///             .map(|node| ranks[*node])
+///             .sum();
+///         for node in adjacency.nodes() {
+///             next[*node] = (1. - alpha + alpha * dangling) / n;
+///         }
+///         adjacency.propagate(ranks, next, alpha, true);
+///     },
+/// )
+/// .unwrap();
+/// assert!((ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+/// assert!(ranks[1] > ranks[0]);
+/// ```
+pub fn power_iteration<F>(
+    start: Vec<f64>,
+    nodes: &[usize],
+    max_iter: usize,
+    tol: f64,
+    mut step: F,
+) -> Option<Vec<f64>>
+where
+    F: FnMut(&[f64], &mut [f64]),
+{
+    let mut scores = start;
+    for _ in 0..max_iter {
+        let mut next_scores: Vec<f64> = vec![0.; scores.len()];
+        step(&scores, &mut next_scores);
+        let err: f64 = nodes
+            .iter()
+            .map(|node| (next_scores[*node] - scores[*node]).abs())
+            .sum();
+        scores = next_scores;
+        if err < nodes.len() as f64 * tol {
+            return Some(scores);
+        }
+    }
+    None
+}
+
+/// Compute the eigenvector centrality of a graph.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the
+/// centralities of its neighbors, so a node is central if it's connected to
+/// other central nodes. The centralities are the entries of the eigenvector
+/// of the weighted adjacency matrix (transposed, for a directed graph) for
+/// its largest eigenvalue, found by power iteration, normalized to a unit
+/// Euclidean norm. For a directed graph the centrality of a node comes from
+/// its predecessors. The iteration uses the matrix plus the identity, which
+/// has the same eigenvectors, so it converges for bipartite graphs too.
+///
+/// The function `edge_weight` should return the weight of a particular edge,
+/// it's called once for each edge (twice for each edge of an undirected
+/// graph), if it returns an error it is returned as [`Error::Callback`]. The
+/// iteration stops once the sum of the absolute changes of the centralities
+/// is less than `tol` times the number of nodes.
+///
+/// Returns a `Vec` of the centralities indexed by node index, with `0.0`
+/// at the indices of removed nodes, or [`None`] if the power iteration
+/// doesn't converge within `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::eigenvector_centrality;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<Option<Vec<f64>>, Error> = eigenvector_centrality(&g, |_| Ok(1.), 100, 1e-8);
+/// let centrality = res.unwrap().unwrap();
+/// assert!((centrality[0] - 0.5).abs() < 1e-6);
+/// assert!((centrality[1] - 0.5f64.sqrt()).abs() < 1e-6);
+/// assert!((centrality[2] - 0.5).abs() < 1e-6);
+/// ```
+pub fn eigenvector_centrality<G, F, E>(
+    graph: G,
+    edge_weight: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<f64>>, Error<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let adjacency = WeightedAdjacency::new(graph, edge_weight)?;
+    let nodes = adjacency.nodes();
+    let mut start: Vec<f64> = vec![0.; adjacency.node_bound()];
+    if nodes.is_empty() {
+        return Ok(Some(start));
+    }
+    for node in nodes {
+        start[*node] = 1. / nodes.len() as f64;
+    }
+    Ok(power_iteration(start, nodes, max_iter, tol, |x, next| {
+        next.copy_from_slice(x);
+        adjacency.propagate(x, next, 1., false);
+        let norm = nodes
+            .iter()
+            .map(|node| next[*node] * next[*node])
+            .sum::<f64>()
+            .sqrt();
+        if norm > 0. {
+            for node in nodes {
+                next[*node] /= norm;
+            }
+        }
+    }))
+}
@@ -0,0 +1,110 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+use std::hash::Hash;
+
+use petgraph::{
+    visit::{EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable},
+    Undirected,
+};
+
+const NULL: usize = usize::MAX;
+
+/// Return the bridges of an undirected graph.
+///
+/// A bridge or cut edge is any edge whose removal increases the number of
+/// connected components of a graph. An undirected connected graph without
+/// bridges is 2-edge-connected.
+///
+/// The bridges are found with a single depth first search in linear time.
+/// Each bridge is returned as a tuple of its endpoints, ordered as the edge
+/// was traversed by the search, so the first node is the one closer to the
+/// root of its connected component. Unlike
+/// [`articulation_points`](crate::connectivity::articulation_points) parallel
+/// edges and self loops are handled: an edge with a parallel edge is never a
+/// bridge and a self loop is never a bridge.
+///
+/// # Example:
+/// ```rust
+/// use std::iter::FromIterator;
+/// use hashbrown::HashSet;
+///
+/// use retworkx_core::connectivity::bridges;
+/// use retworkx_core::petgraph::graph::UnGraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///    (0, 1), (0, 2), (1, 2), (1, 3), (3, 4), (3, 4),
+/// ]);
+///
+/// let bridges = bridges(&graph);
+/// // The edge (3, 4) isn't a bridge because it has a parallel edge
+/// assert_eq!(bridges, HashSet::from_iter([(nx(1), nx(3))]));
+/// ```
+pub fn bridges<G>(graph: G) -> HashSet<(G::NodeId, G::NodeId)>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdges + NodeIndexable + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let num_nodes = graph.node_bound();
+
+    let mut low = vec![NULL; num_nodes];
+    let mut disc = vec![NULL; num_nodes];
+    let mut time: usize = 0;
+    let mut bridges = HashSet::new();
+
+    for root in graph.node_identifiers() {
+        let root_index = graph.to_index(root);
+        if disc[root_index] != NULL {
+            continue;
+        }
+        disc[root_index] = time;
+        low[root_index] = time;
+        time += 1;
+        // Each stack entry holds a node, its parent in the search tree, the
+        // remaining edges to visit and whether the tree edge back to the
+        // parent was skipped already. Only one edge to the parent is the tree
+        // edge, any parallel edge to it is a back edge.
+        let mut stack = vec![(root_index, NULL, graph.edges(root), false)];
+        while let Some((u, parent, edges, skipped_parent)) = stack.last_mut() {
+            let u = *u;
+            let parent = *parent;
+            match edges.next() {
+                Some(edge) => {
+                    let v = graph.to_index(edge.target());
+                    if v == parent && !*skipped_parent {
+                        *skipped_parent = true;
+                    } else if disc[v] == NULL {
+                        disc[v] = time;
+                        low[v] = time;
+                        time += 1;
+                        stack.push((v, u, graph.edges(graph.from_index(v)), false));
+                    } else {
+                        low[u] = low[u].min(disc[v]);
+                    }
+                }
+                None => {
+                    stack.pop();
+                    if parent != NULL {
+                        low[parent] = low[parent].min(low[u]);
+                        if low[u] > disc[parent] {
+                            bridges.insert((graph.from_index(parent), graph.from_index(u)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    bridges
+}
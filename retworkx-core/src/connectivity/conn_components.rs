@@ -0,0 +1,190 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use hashbrown::HashSet;
+use petgraph::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Undirected;
+use rayon::prelude::*;
+
+/// The number of neighbors of each node linked before the largest
+/// intermediate component is found.
+const NEIGHBOR_ROUNDS: usize = 2;
+
+/// A union-find structure which can be shared between threads.
+///
+/// A root is always linked below a root with a smaller index, so following
+/// parent links always decreases the index and no cycles can form even when
+/// several threads link roots at the same time.
+struct ConcurrentUnionFind {
+    parent: Vec<AtomicUsize>,
+}
+
+impl ConcurrentUnionFind {
+    fn new(n: usize) -> Self {
+        ConcurrentUnionFind {
+            parent: (0..n).map(AtomicUsize::new).collect(),
+        }
+    }
+
+    /// Return the root of the set containing `x`, halving the path to it.
+    fn find(&self, mut x: usize) -> usize {
+        loop {
+            let parent = self.parent[x].load(Ordering::Acquire);
+            if parent == x {
+                return x;
+            }
+            let grandparent = self.parent[parent].load(Ordering::Acquire);
+            if grandparent != parent {
+                // A failure only means another thread already moved `x`
+                // closer to the root.
+                let _ = self.parent[x].compare_exchange_weak(
+                    parent,
+                    grandparent,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                );
+            }
+            x = grandparent;
+        }
+    }
+
+    fn union(&self, x: usize, y: usize) {
+        let mut x = x;
+        let mut y = y;
+        loop {
+            x = self.find(x);
+            y = self.find(y);
+            if x == y {
+                return;
+            }
+            let (high, low) = if x > y { (x, y) } else { (y, x) };
+            // Only link `high` if it is still a root, otherwise retry from
+            // the root it was linked to in the meantime.
+            if self.parent[high]
+                .compare_exchange(high, low, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+}
+
+/// Find the connected components of an undirected graph.
+///
+/// This uses a concurrent union-find based on the Afforest algorithm, which
+/// first links every node with only a few of its neighbors to find the
+/// largest component, and then skips the nodes already in that component
+/// when linking the rest of the edges. This avoids processing most of the
+/// edges of a graph with a giant component. The algorithm is described in:
+///
+/// Sutton, Michael, Tal Ben-Nun, and Amnon Barak. "Optimizing Parallel Graph
+/// Connectivity Computation via Subgraph Sampling." 2018 IEEE International
+/// Parallel and Distributed Processing Symposium (IPDPS), 12-21.
+/// <https://doi.org/10.1109/IPDPS.2018.00012>
+///
+/// If the number of nodes in the graph is greater than or equal to
+/// `parallel_threshold` the edges are linked in parallel. If the function
+/// will be running in parallel the env var `RAYON_NUM_THREADS` can be used
+/// to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find the components of
+/// * `parallel_threshold` - The number of nodes in the graph to run in
+///     parallel at
+///
+/// Returns a list of the components of the graph, each a set of nodes. The
+/// components are ordered by the first node of each component in the
+/// order of `graph.node_identifiers()`.
+///
+/// # Example
+/// ```rust
+/// use std::iter::FromIterator;
+/// use hashbrown::HashSet;
+///
+/// use retworkx_core::connectivity::connected_components;
+/// use retworkx_core::petgraph::graph::UnGraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+///
+/// let mut graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 4)]);
+/// graph.add_node(());
+///
+/// let components = connected_components(&graph, 50);
+/// assert_eq!(components, vec![
+///     HashSet::from_iter([nx(0), nx(1), nx(2)]),
+///     HashSet::from_iter([nx(3), nx(4)]),
+///     HashSet::from_iter([nx(5)]),
+/// ]);
+/// ```
+pub fn connected_components<G>(graph: G, parallel_threshold: usize) -> Vec<HashSet<G::NodeId>>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + IntoNeighbors
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + Sync,
+    G::NodeId: Eq + Hash + Send + Sync,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let sets = ConcurrentUnionFind::new(graph.node_bound());
+    let link_neighbors = |node: &G::NodeId, skip: usize, take: usize| {
+        let index = graph.to_index(*node);
+        for neighbor in graph.neighbors(*node).skip(skip).take(take) {
+            sets.union(index, graph.to_index(neighbor));
+        }
+    };
+    let run_parallel = nodes.len() >= parallel_threshold;
+    for round in 0..NEIGHBOR_ROUNDS {
+        if run_parallel {
+            nodes
+                .par_iter()
+                .for_each(|node| link_neighbors(node, round, 1));
+        } else {
+            nodes.iter().for_each(|node| link_neighbors(node, round, 1));
+        }
+    }
+
+    // Every edge is in the neighbors of both its endpoints, so it's enough
+    // to link the remaining neighbors of the nodes outside of the largest
+    // component.
+    let mut sizes: Vec<usize> = vec![0; graph.node_bound()];
+    for node in &nodes {
+        sizes[sets.find(graph.to_index(*node))] += 1;
+    }
+    let largest = (0..sizes.len()).max_by_key(|root| sizes[*root]);
+    let link_rest = |node: &G::NodeId| {
+        if Some(sets.find(graph.to_index(*node))) != largest {
+            link_neighbors(node, NEIGHBOR_ROUNDS, usize::MAX);
+        }
+    };
+    if run_parallel {
+        nodes.par_iter().for_each(link_rest);
+    } else {
+        nodes.iter().for_each(link_rest);
+    }
+
+    let mut component_index: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    let mut components: Vec<HashSet<G::NodeId>> = Vec::new();
+    for node in nodes {
+        let root = sets.find(graph.to_index(node));
+        let index = *component_index[root].get_or_insert_with(|| {
+            components.push(HashSet::new());
+            components.len() - 1
+        });
+        components[index].insert(node);
+    }
+    components
+}
@@ -13,7 +13,11 @@
 //! Module for connectivity and cut algorithms.
 
 mod biconnected;
+mod bridges;
 mod chain;
+mod conn_components;
 
 pub use biconnected::articulation_points;
+pub use bridges::bridges;
 pub use chain::chain_decomposition;
+pub use conn_components::connected_components;
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for checking whether a node is present in a graph
+
+use petgraph::graph::{Graph, IndexType};
+use petgraph::graphmap::{GraphMap, NodeTrait};
+use petgraph::stable_graph::StableGraph;
+use petgraph::visit::{EdgeFiltered, FilterNode, GraphBase, NodeFiltered, Reversed};
+use petgraph::EdgeType;
+
+/// A graph which can check whether a node is present in it.
+///
+/// [`NodeIndexable`](petgraph::visit::NodeIndexable) alone can't tell a
+/// removed node of a [`StableGraph`] apart from a present one, since both
+/// have an index below
+/// [`node_bound()`](petgraph::visit::NodeIndexable::node_bound). The
+/// functions in this crate which return [`Error::InvalidNode`](crate::Error)
+/// use this trait to reject both.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::contains::ContainsNode;
+///
+/// let mut g = petgraph::stable_graph::StableUnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// g.remove_node(NodeIndex::new(1));
+/// assert!(ContainsNode::contains_node(&&g, NodeIndex::new(0)));
+/// assert!(!ContainsNode::contains_node(&&g, NodeIndex::new(1)));
+/// assert!(!ContainsNode::contains_node(&&g, NodeIndex::new(42)));
+/// ```
+pub trait ContainsNode: GraphBase {
+    /// Return `true` if `node` is a node of the graph.
+    fn contains_node(&self, node: Self::NodeId) -> bool;
+}
+
+impl<G> ContainsNode for &G
+where
+    G: ContainsNode,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        (*self).contains_node(node)
+    }
+}
+
+impl<N, E, Ty, Ix> ContainsNode for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        StableGraph::contains_node(self, node)
+    }
+}
+
+impl<N, E, Ty, Ix> ContainsNode for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        self.node_weight(node).is_some()
+    }
+}
+
+impl<N, E, Ty> ContainsNode for GraphMap<N, E, Ty>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        GraphMap::contains_node(self, node)
+    }
+}
+
+impl<G> ContainsNode for Reversed<G>
+where
+    G: ContainsNode,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        self.0.contains_node(node)
+    }
+}
+
+impl<G, F> ContainsNode for NodeFiltered<G, F>
+where
+    G: ContainsNode,
+    F: FilterNode<G::NodeId>,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        self.0.contains_node(node) && self.1.include_node(node)
+    }
+}
+
+impl<G, F> ContainsNode for EdgeFiltered<G, F>
+where
+    G: ContainsNode,
+{
+    fn contains_node(&self, node: Self::NodeId) -> bool {
+        self.0.contains_node(node)
+    }
+}
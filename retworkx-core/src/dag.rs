@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use petgraph::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction::{Incoming, Outgoing};
+
+use crate::Error;
+
+/// A binary min heap of compact node indices ordered by a fallible
+/// comparison, as ``BinaryHeap`` requires an infallible ``Ord``.
+struct ReadyHeap {
+    nodes: Vec<usize>,
+}
+
+impl ReadyHeap {
+    fn push<E>(
+        &mut self,
+        node: usize,
+        less: &mut impl FnMut(usize, usize) -> Result<bool, E>,
+    ) -> Result<(), E> {
+        self.nodes.push(node);
+        let mut pos = self.nodes.len() - 1;
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !less(self.nodes[pos], self.nodes[parent])? {
+                break;
+            }
+            self.nodes.swap(pos, parent);
+            pos = parent;
+        }
+        Ok(())
+    }
+
+    fn pop<E>(
+        &mut self,
+        less: &mut impl FnMut(usize, usize) -> Result<bool, E>,
+    ) -> Result<Option<usize>, E> {
+        if self.nodes.is_empty() {
+            return Ok(None);
+        }
+        let node = self.nodes.swap_remove(0);
+        let mut pos = 0;
+        loop {
+            let mut smallest = pos;
+            for child in [2 * pos + 1, 2 * pos + 2].iter() {
+                if *child < self.nodes.len() && less(self.nodes[*child], self.nodes[smallest])? {
+                    smallest = *child;
+                }
+            }
+            if smallest == pos {
+                break;
+            }
+            self.nodes.swap(pos, smallest);
+            pos = smallest;
+        }
+        Ok(Some(node))
+    }
+}
+
+/// Return a topological sort of the nodes of a directed graph with
+/// deterministic tie-breaking.
+///
+/// A topological sort is a linear ordering of the nodes such that for every
+/// directed edge from node `u` to node `v`, `u` comes before `v` in the
+/// ordering. The sort is done with Kahn's algorithm: of the nodes whose
+/// predecessors have all been output, the next node output is the smallest
+/// by `compare`, with nodes comparing equal ordered by their index. If
+/// `compare` is [`None`] the smallest node index is output first. So the
+/// ordering only depends on the graph and `compare`, not on the order the
+/// nodes and edges were added in, which makes it reproducible.
+///
+/// The function `compare` is called with two nodes and should return their
+/// [`Ordering`], if it returns an error the sort stops and the error is
+/// returned as [`Error::Callback`].
+///
+/// Returns a `Vec` of all the nodes of the graph in topological order. If
+/// the graph has a cycle [`Error::DAGHasCycle`] is returned.
+///
+/// # Example
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// use retworkx_core::dag::topological_sort;
+/// use retworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use retworkx_core::{Error, Result};
+///
+/// let graph = DiGraph::<&str, ()>::from_edges(&[(0, 3), (1, 3), (2, 3), (3, 4)]);
+/// let res: Result<Vec<NodeIndex>, Error> =
+///     topological_sort(&graph, None::<fn(NodeIndex, NodeIndex) -> Result<Ordering>>);
+/// let order: Vec<usize> = res.unwrap().iter().map(|node| node.index()).collect();
+/// assert_eq!(order, vec![0, 1, 2, 3, 4]);
+///
+/// // Output the largest node index first on ties instead
+/// let res: Result<Vec<NodeIndex>, Error> =
+///     topological_sort(&graph, Some(|a: NodeIndex, b: NodeIndex| Ok(b.cmp(&a))));
+/// let order: Vec<usize> = res.unwrap().iter().map(|node| node.index()).collect();
+/// assert_eq!(order, vec![2, 1, 0, 3, 4]);
+///
+/// // A cycle is an error
+/// let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 0)]);
+/// let res: Result<Vec<NodeIndex>, Error> =
+///     topological_sort(&cycle, None::<fn(NodeIndex, NodeIndex) -> Result<Ordering>>);
+/// assert_eq!(res, Err(Error::DAGHasCycle));
+/// ```
+pub fn topological_sort<G, F, E>(graph: G, compare: Option<F>) -> Result<Vec<G::NodeId>, Error<E>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId, G::NodeId) -> Result<Ordering, E>,
+{
+    let mut compare = compare;
+    let mut less = |a: usize, b: usize| -> Result<bool, E> {
+        let ordering = match compare.as_mut() {
+            Some(compare) => compare(graph.from_index(a), graph.from_index(b))?,
+            None => Ordering::Equal,
+        };
+        Ok(ordering.then(a.cmp(&b)) == Ordering::Less)
+    };
+
+    let mut in_degree: Vec<usize> = vec![0; graph.node_bound()];
+    let mut node_count: usize = 0;
+    let mut ready = ReadyHeap { nodes: Vec::new() };
+    for node in graph.node_identifiers() {
+        node_count += 1;
+        let index = graph.to_index(node);
+        in_degree[index] = graph.neighbors_directed(node, Incoming).count();
+        if in_degree[index] == 0 {
+            ready.push(index, &mut less).map_err(Error::Callback)?;
+        }
+    }
+    let mut order: Vec<G::NodeId> = Vec::with_capacity(node_count);
+    while let Some(index) = ready.pop(&mut less).map_err(Error::Callback)? {
+        let node = graph.from_index(index);
+        order.push(node);
+        for successor in graph.neighbors_directed(node, Outgoing) {
+            let successor = graph.to_index(successor);
+            in_degree[successor] -= 1;
+            if in_degree[successor] == 0 {
+                ready.push(successor, &mut less).map_err(Error::Callback)?;
+            }
+        }
+    }
+    if order.len() < node_count {
+        return Err(Error::DAGHasCycle);
+    }
+    Ok(order)
+}
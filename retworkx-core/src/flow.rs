@@ -0,0 +1,303 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use petgraph::algo::Measure;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use petgraph::Directed;
+
+use crate::contains::ContainsNode;
+use crate::dictmap::*;
+use crate::Error;
+
+type MaxFlowOutput<E, K> = (K, DictMap<E, K>);
+type MinCutOutput<N, E, K> = (K, Vec<N>, Vec<E>);
+
+/// The residual network used by Dinic's algorithm.
+///
+/// Every edge of the input graph is stored as a pair of arcs, the forward arc
+/// for the edge at position `2 * i` and its reverse arc at `2 * i + 1`, so the
+/// reverse of an arc is always `arc ^ 1`. All nodes are referred to by their
+/// compact index from [`NodeIndexable::to_index`].
+struct FlowNetwork<K, E> {
+    heads: Vec<usize>,
+    residual: Vec<K>,
+    adjacency: Vec<Vec<usize>>,
+    edges: Vec<E>,
+}
+
+impl<K, E> FlowNetwork<K, E>
+where
+    K: Measure + Copy + Sub<Output = K>,
+{
+    fn new<G, F, Err>(graph: G, mut capacity: F) -> Result<Self, Error<Err>>
+    where
+        G: IntoEdgeReferences<EdgeId = E> + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> Result<K, Err>,
+    {
+        let mut network = FlowNetwork {
+            heads: Vec::new(),
+            residual: Vec::new(),
+            adjacency: vec![Vec::new(); graph.node_bound()],
+            edges: Vec::new(),
+        };
+        for edge in graph.edge_references() {
+            let cap = capacity(edge).map_err(Error::Callback)?;
+            let source = graph.to_index(edge.source());
+            let target = graph.to_index(edge.target());
+            let arc = network.heads.len();
+            network.heads.push(target);
+            network.heads.push(source);
+            network.residual.push(cap);
+            network.residual.push(K::default());
+            network.adjacency[source].push(arc);
+            network.adjacency[target].push(arc + 1);
+            network.edges.push(edge.id());
+        }
+        Ok(network)
+    }
+
+    /// Return the breadth first search level of every node reachable from
+    /// `source` through arcs with remaining capacity.
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let zero = K::default();
+        let mut levels: Vec<Option<usize>> = vec![None; self.adjacency.len()];
+        levels[source] = Some(0);
+        let mut queue: VecDeque<usize> = VecDeque::from(vec![source]);
+        while let Some(node) = queue.pop_front() {
+            let next_level = levels[node].map(|level| level + 1);
+            for arc in &self.adjacency[node] {
+                let head = self.heads[*arc];
+                if levels[head].is_none() && self.residual[*arc] > zero {
+                    levels[head] = next_level;
+                    queue.push_back(head);
+                }
+            }
+        }
+        levels
+    }
+
+    /// Push the maximum flow from `source` to `sink` through the network and
+    /// return its value.
+    fn max_flow(&mut self, source: usize, sink: usize) -> K {
+        let zero = K::default();
+        let mut total = zero;
+        if source == sink {
+            return total;
+        }
+        loop {
+            let mut levels = self.levels(source);
+            if levels[sink].is_none() {
+                break;
+            }
+            // Find a blocking flow in the level graph with an iterative depth
+            // first search, `next_arc` tracks the first arc of each node that
+            // hasn't been ruled out yet.
+            let mut next_arc: Vec<usize> = vec![0; self.adjacency.len()];
+            let mut path: Vec<usize> = Vec::new();
+            let mut node = source;
+            loop {
+                if node == sink {
+                    let mut bottleneck = self.residual[path[0]];
+                    for arc in &path[1..] {
+                        if self.residual[*arc] < bottleneck {
+                            bottleneck = self.residual[*arc];
+                        }
+                    }
+                    for arc in &path {
+                        self.residual[*arc] = self.residual[*arc] - bottleneck;
+                        self.residual[*arc ^ 1] = self.residual[*arc ^ 1] + bottleneck;
+                    }
+                    total = total + bottleneck;
+                    path.clear();
+                    node = source;
+                    continue;
+                }
+                let next_level = levels[node].map(|level| level + 1);
+                let mut advanced = false;
+                while next_arc[node] < self.adjacency[node].len() {
+                    let arc = self.adjacency[node][next_arc[node]];
+                    let head = self.heads[arc];
+                    if self.residual[arc] > zero && levels[head] == next_level {
+                        path.push(arc);
+                        node = head;
+                        advanced = true;
+                        break;
+                    }
+                    next_arc[node] += 1;
+                }
+                if !advanced {
+                    if node == source {
+                        break;
+                    }
+                    // There is no path to the sink from this node, remove it
+                    // from the level graph and retreat along the path.
+                    levels[node] = None;
+                    let arc = path.pop().unwrap();
+                    node = self.heads[arc ^ 1];
+                    next_arc[node] += 1;
+                }
+            }
+        }
+        total
+    }
+}
+
+/// Compute the maximum flow from `source` to `sink` using Dinic's algorithm.
+///
+/// Each edge of the directed graph `graph` can carry at most the flow given
+/// by its capacity from its source node to its target node. The function
+/// `capacity` should return the capacity for a particular edge, capacities
+/// must be non-negative. The capacity type `K` can be any type implementing
+/// [`Measure`] that can be subtracted, such as an integer or float type.
+///
+/// The algorithm runs in `O(|V|^2 |E|)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to compute the flow on
+/// * `source` - The node the flow leaves from
+/// * `sink` - The node the flow arrives at
+/// * `capacity` - A callable that returns the capacity of an edge. If it
+///     returns an error the computation stops and the error is returned as
+///     [`Error::Callback`].
+///
+/// Returns a tuple of the value of the maximum flow and a [`DictMap`] of
+/// every edge in the graph to the flow along that edge. If `source` and `sink`
+/// are the same node the flow is zero. If `source` or `sink` is not a valid
+/// node index [`Error::InvalidNode`] is returned.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::EdgeIndex;
+/// use retworkx_core::flow::max_flow;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 4), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)
+/// ]);
+/// let res: Result<_, Error> = max_flow(&g, 0.into(), 3.into(), |e| Ok(*e.weight()));
+/// let (value, flows) = res.unwrap();
+/// assert_eq!(5, value);
+/// assert_eq!(2, flows[&EdgeIndex::new(3)]);
+/// ```
+pub fn max_flow<G, F, K, E>(
+    graph: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    capacity: F,
+) -> Result<MaxFlowOutput<G::EdgeId, K>, Error<E>>
+where
+    G: IntoEdgeReferences + NodeIndexable + ContainsNode + GraphProp<EdgeType = Directed>,
+    G::EdgeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    if !graph.contains_node(source) || !graph.contains_node(sink) {
+        return Err(Error::InvalidNode);
+    }
+    let source = graph.to_index(source);
+    let sink = graph.to_index(sink);
+    let mut network = FlowNetwork::new(graph, capacity)?;
+    let value = network.max_flow(source, sink);
+    let flows: DictMap<G::EdgeId, K> = network
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(index, edge)| (*edge, network.residual[2 * index + 1]))
+        .collect();
+    Ok((value, flows))
+}
+
+/// Compute a minimum `source`-`sink` cut using Dinic's algorithm.
+///
+/// A cut is a partition of the nodes of the directed graph `graph` into a set
+/// containing `source` and a set containing `sink`, its value is the sum of
+/// the capacities of the edges from the first set to the second. By the
+/// max-flow min-cut theorem the value of the minimum cut is the value of
+/// the maximum flow from `source` to `sink` computed by [`max_flow`].
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to find the cut on
+/// * `source` - The node on the source side of the cut
+/// * `sink` - The node on the sink side of the cut
+/// * `capacity` - A callable that returns the capacity of an edge. If it
+///     returns an error the computation stops and the error is returned as
+///     [`Error::Callback`].
+///
+/// Returns a tuple of the value of the cut, the nodes on the source side of
+/// the cut, and the edges crossing from the source side to the sink side.
+/// The source side contains exactly the nodes still reachable from `source`
+/// in the residual network of a maximum flow. If `source` or `sink` is not a
+/// valid node index [`Error::InvalidNode`] is returned.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::{EdgeIndex, NodeIndex};
+/// use retworkx_core::flow::min_cut;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 4), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)
+/// ]);
+/// let res: Result<_, Error> = min_cut(&g, 0.into(), 3.into(), |e| Ok(*e.weight()));
+/// let (value, source_side, cut_edges) = res.unwrap();
+/// assert_eq!(5, value);
+/// assert_eq!(vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)], source_side);
+/// assert_eq!(vec![EdgeIndex::new(3), EdgeIndex::new(4)], cut_edges);
+/// ```
+pub fn min_cut<G, F, K, E>(
+    graph: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    capacity: F,
+) -> Result<MinCutOutput<G::NodeId, G::EdgeId, K>, Error<E>>
+where
+    G: IntoEdgeReferences + NodeIndexable + ContainsNode + GraphProp<EdgeType = Directed>,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy + Sub<Output = K>,
+{
+    if !graph.contains_node(source) || !graph.contains_node(sink) {
+        return Err(Error::InvalidNode);
+    }
+    let source = graph.to_index(source);
+    let sink = graph.to_index(sink);
+    let mut network = FlowNetwork::new(graph, capacity)?;
+    let value = network.max_flow(source, sink);
+    let reachable: Vec<bool> = network
+        .levels(source)
+        .into_iter()
+        .map(|level| level.is_some())
+        .collect();
+    let source_side: Vec<G::NodeId> = reachable
+        .iter()
+        .enumerate()
+        .filter(|(_, reached)| **reached)
+        .map(|(index, _)| graph.from_index(index))
+        .collect();
+    let cut_edges: Vec<G::EdgeId> = network
+        .edges
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            reachable[network.heads[2 * index + 1]] && !reachable[network.heads[2 * index]]
+        })
+        .map(|(_, edge)| *edge)
+        .collect();
+    Ok((value, source_side, cut_edges))
+}
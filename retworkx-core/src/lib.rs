@@ -48,8 +48,45 @@
 //!
 //! * [`centrality`](./centrality/index.html)
 //! * [`connectivity`](./connectivity/index.html)
+//! * [`dag`](./dag/index.html)
+//! * [`flow`](./flow/index.html)
 //! * [`max_weight_matching`](./max_weight_matching/index.html)
+//! * [`planar`](./planar/index.html)
 //! * [`shortest_path`](./shortest_path/index.html)
+//! * [`token_swapper`](./token_swapper/index.html)
+//!
+//! ## Optional Features
+//!
+//! * `serde` - Implement [`serde`](https://serde.rs/)'s `Serialize` and
+//!   `Deserialize` traits for the types returned by the algorithms in this
+//!   crate, including [`DictMap`](./dictmap/type.DictMap.html) and the
+//!   hashbrown sets and maps used for results, [`Error`], and the petgraph
+//!   graph and index types. This can be used to cache or transport the
+//!   outputs of the algorithms and snapshots of the graphs they ran on.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use retworkx_core::petgraph;
+//! use retworkx_core::shortest_path::dijkstra;
+//! use retworkx_core::dictmap::DictMap;
+//! use retworkx_core::petgraph::graph::NodeIndex;
+//! use retworkx_core::{Error, Result};
+//!
+//! let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+//! let res: Result<DictMap<NodeIndex, usize>, Error> =
+//!     dijkstra(&g, NodeIndex::new(0), None, |_| Ok(1), None);
+//! let scores = res.unwrap();
+//! let serialized = serde_json::to_string(&scores).unwrap();
+//! let deserialized: DictMap<NodeIndex, usize> = serde_json::from_str(&serialized).unwrap();
+//! assert_eq!(scores, deserialized);
+//!
+//! // Graphs can be serialized as well
+//! let graph_json = serde_json::to_string(&g).unwrap();
+//! let new_graph: petgraph::graph::UnGraph<(), ()> = serde_json::from_str(&graph_json).unwrap();
+//! assert_eq!(g.edge_count(), new_graph.edge_count());
+//! # }
+//! ```
 //!
 //! ## Release Notes
 //!
@@ -59,6 +96,8 @@
 //! <https://qiskit.org/documentation/retworkx/release_notes.html>
 
 use std::convert::Infallible;
+use std::error;
+use std::fmt;
 
 /// A convenient type alias that by default assumes no error can happen.
 ///
@@ -67,17 +106,112 @@ use std::convert::Infallible;
 /// error can happen.
 pub type Result<T, E = Infallible> = core::result::Result<T, E>;
 
+/// The error type for functions in retworkx-core which can fail.
+///
+/// Functions which take a user provided callback return the error from that
+/// callback as [`Error::Callback`], by default the callback error type is
+/// [`Infallible`]. The other variants describe why the function itself
+/// failed, instead of panicking on invalid input.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::shortest_path::dijkstra;
+/// use retworkx_core::Error;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let res: Result<Vec<Option<usize>>, Error> =
+///     dijkstra(&g, NodeIndex::new(42), None, |_| Ok(1), None);
+/// assert_eq!(Err(Error::InvalidNode), res);
+/// ```
+///
+/// The index of a node removed from a [`StableGraph`](petgraph::stable_graph::StableGraph)
+/// is below its node bound but is still rejected:
+///
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::flow::max_flow;
+/// use retworkx_core::shortest_path::{astar, dijkstra, k_shortest_path};
+/// use retworkx_core::Error;
+///
+/// let mut g = petgraph::stable_graph::StableDiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (2, 3, 1)
+/// ]);
+/// let removed = NodeIndex::new(1);
+/// g.remove_node(removed);
+/// let res: Result<Vec<Option<u32>>, Error> =
+///     dijkstra(&g, removed, None, |e| Ok(*e.weight()), None);
+/// assert_eq!(Err(Error::InvalidNode), res);
+/// let res: Result<Option<(u32, Vec<NodeIndex>)>, Error> =
+///     astar(&g, removed, |_| Ok(false), |e| Ok(*e.weight()), |_| Ok(0));
+/// assert_eq!(Err(Error::InvalidNode), res);
+/// let res: Result<Vec<Option<u32>>, Error> =
+///     k_shortest_path(&g, removed, None, 2, |e| Ok(*e.weight()));
+/// assert_eq!(Err(Error::InvalidNode), res);
+/// let res: Result<_, Error> = max_flow(&g, NodeIndex::new(0), removed, |e| Ok(*e.weight()));
+/// assert_eq!(Err(Error::InvalidNode), res.map(|(value, _)| value));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Error<E = Infallible> {
+    /// A node passed as an argument is not present in the graph.
+    InvalidNode,
+    /// There is no sequence of swaps which completes the requested mapping.
+    MapNotPossible,
+    /// The graph has a cycle but the function requires a directed acyclic
+    /// graph.
+    DAGHasCycle,
+    /// The error returned by a user provided callback.
+    Callback(E),
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidNode => write!(f, "The input node is not present in the graph."),
+            Error::MapNotPossible => write!(f, "No mapping possible."),
+            Error::DAGHasCycle => write!(f, "Sort encountered a cycle"),
+            Error::Callback(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Callback(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 /// Module for centrality algorithms
 pub mod centrality;
 pub mod connectivity;
+pub mod contains;
+/// Module for directed acyclic graph algorithms
+pub mod dag;
+/// Module for maximum flow and minimum cut algorithms
+pub mod flow;
 /// Module for maximum weight matching algorithmss
 pub mod max_weight_matching;
+/// Module for planarity testing
+pub mod planar;
 pub mod shortest_path;
+/// Module for the token swapper algorithm
+pub mod token_swapper;
 pub mod traversal;
 // These modules define additional data structures
 pub mod dictmap;
 pub mod distancemap;
 mod min_scored;
+pub mod workspace;
 
 // re-export petgraph so there is a consistent version available to users and
 // then only need to require retworkx-core in their dependencies
@@ -18,13 +18,14 @@
 #![allow(clippy::many_single_char_names)]
 
 use std::cmp::max;
+use std::convert::Infallible;
+use std::hash::Hash;
 use std::mem;
 
 use hashbrown::{HashMap, HashSet};
 
-use petgraph::graph::NodeIndex;
 use petgraph::visit::{
-    EdgeCount, EdgeRef, GraphBase, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeCount,
+    EdgeCount, EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable,
 };
 use petgraph::Undirected;
 
@@ -817,7 +818,9 @@ fn verify_optimum(
 ///
 /// Arguments:
 ///
-/// * `graph` - The undirected graph to compute the maximum weight matching for
+/// * `graph` - The undirected graph to compute the maximum weight matching for,
+///     the nodes in the returned matching are the indices of the nodes given by
+///     [`NodeIndexable::to_index`]
 /// * `max_cardinality` - If set to true compute the maximum-cardinality matching
 ///     with maximum weight among all maximum-cardinality matchings
 /// * `weight_fn` - A callback function that will be give a edge reference and
@@ -870,8 +873,9 @@ where
         + NodeCount
         + IntoNodeIdentifiers
         + GraphProp<EdgeType = Undirected>
-        + GraphBase<NodeId = NodeIndex>
+        + NodeIndexable
         + IntoEdges,
+    G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<i128, E>,
 {
     let num_edges = graph.edge_count();
@@ -881,11 +885,11 @@ where
     if num_edges == 0 {
         return Ok(HashSet::new());
     }
-    // Node indicies in the PyGraph may not be contiguous however the
+    // Node indicies in the graph may not be contiguous however the
     // algorithm operates on contiguous indices 0..num_nodes. node_map maps
-    // the PyGraph's NodeIndex to the contingous usize used inside the
+    // the graph's node ids to the contingous usize used inside the
     // algorithm
-    let node_map: HashMap<NodeIndex, usize> = graph
+    let node_map: HashMap<G::NodeId, usize> = graph
         .node_identifiers()
         .enumerate()
         .map(|(index, node_index)| (node_index, index))
@@ -1365,19 +1369,19 @@ where
     }
 
     // Transform mate[] such that mate[v] is the vertex to which v is paired
-    // Also handle holes in node indices from PyGraph node removals by mapping
-    // linear index to node index.
+    // Also handle holes in node indices from node removals by mapping
+    // linear index to node index. The entries are visited in index order so
+    // each edge is always output with its lower node index first.
     let mut seen: HashSet<(usize, usize)> = HashSet::with_capacity(2 * num_nodes);
-    let node_list: Vec<NodeIndex> = graph.node_identifiers().collect();
-    for (index, node) in mate.iter() {
-        let tmp = (
-            node_list[*index].index(),
-            node_list[endpoints[*node]].index(),
-        );
-        let rev_tmp = (
-            node_list[endpoints[*node]].index(),
-            node_list[*index].index(),
-        );
+    let node_list: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let mut mate_entries: Vec<(&usize, &usize)> = mate.iter().collect();
+    mate_entries.sort_unstable();
+    for (index, node) in mate_entries {
+        let tmp = (node_list[*index], node_list[endpoints[*node]]);
+        let rev_tmp = (node_list[endpoints[*node]], node_list[*index]);
         if !seen.contains(&tmp) && !seen.contains(&rev_tmp) {
             out_set.insert(tmp);
             seen.insert(tmp);
@@ -1386,3 +1390,53 @@ where
     }
     Ok(out_set)
 }
+
+/// Compute a maximum cardinality matching in the general undirected graph
+/// `graph`.
+///
+/// A maximum cardinality matching is a matching with the largest possible
+/// number of edges. This uses the same blossom algorithm as
+/// [`max_weight_matching`] with every edge having the same weight, the graph
+/// can be any undirected graph implementing the required petgraph traits.
+///
+/// The function takes time O(n**3)
+///
+/// Returns the matching as a set of pairs of node indices (from
+/// [`NodeIndexable::to_index`]), each edge in the matching is only listed
+/// once in a single direction.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::max_weight_matching::maximum_matching;
+///
+/// // Create a path graph with 4 nodes
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let matching = maximum_matching(&g);
+/// assert_eq!(matching.len(), 2);
+/// assert!(matching.contains(&(0, 1)) || matching.contains(&(1, 0)));
+/// assert!(matching.contains(&(2, 3)) || matching.contains(&(3, 2)));
+///
+/// // Graphs without petgraph's NodeIndex node ids work as well
+/// let map_graph = petgraph::graphmap::UnGraphMap::<&str, ()>::from_edges(&[
+///     ("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")
+/// ]);
+/// assert_eq!(maximum_matching(&map_graph).len(), 2);
+/// ```
+pub fn maximum_matching<G>(graph: G) -> HashSet<(usize, usize)>
+where
+    G: EdgeCount
+        + NodeCount
+        + IntoNodeIdentifiers
+        + GraphProp<EdgeType = Undirected>
+        + NodeIndexable
+        + IntoEdges,
+    G::NodeId: Eq + Hash,
+{
+    let res: Result<HashSet<(usize, usize)>, Infallible> =
+        max_weight_matching(graph, true, |_| Ok(1), false);
+    match res {
+        Ok(matching) => matching,
+        Err(never) => match never {},
+    }
+}
@@ -0,0 +1,658 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Undirected;
+
+use crate::dictmap::*;
+
+/// The result of a planarity test, see [`planarity`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Planarity<N: Hash + Eq> {
+    /// The graph is planar. This holds a combinatorial planar embedding,
+    /// the neighbors of each node in clockwise order around it.
+    Planar(DictMap<N, Vec<N>>),
+    /// The graph isn't planar. This holds the edges of a Kuratowski
+    /// subgraph, a subdivision of `K5` or `K3,3` contained in the graph.
+    NonPlanar(Vec<(N, N)>),
+}
+
+/// An interval of return edges on the stack of conflict pairs, from the
+/// lowest `low` to the highest `high` edge.
+#[derive(Clone, Copy, Default)]
+struct Interval {
+    low: Option<usize>,
+    high: Option<usize>,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.low.is_none() && self.high.is_none()
+    }
+}
+
+/// A pair of intervals of return edges which must be on different sides.
+#[derive(Clone, Copy, Default)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// A combinatorial embedding, each node has a circular list of neighbors
+/// linked in clockwise and counterclockwise order.
+struct Embedding {
+    first: Vec<Option<usize>>,
+    cw: Vec<HashMap<usize, usize>>,
+    ccw: Vec<HashMap<usize, usize>>,
+}
+
+impl Embedding {
+    fn new(node_count: usize) -> Self {
+        Embedding {
+            first: vec![None; node_count],
+            cw: vec![HashMap::new(); node_count],
+            ccw: vec![HashMap::new(); node_count],
+        }
+    }
+
+    /// Add the half edge from `start` to `end` clockwise after the half edge
+    /// to `reference`, or as the only half edge of `start`.
+    fn add_half_edge_cw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        match reference {
+            None => {
+                self.cw[start].insert(end, end);
+                self.ccw[start].insert(end, end);
+                self.first[start] = Some(end);
+            }
+            Some(reference) => {
+                let cw_reference = self.cw[start][&reference];
+                self.cw[start].insert(reference, end);
+                self.cw[start].insert(end, cw_reference);
+                self.ccw[start].insert(cw_reference, end);
+                self.ccw[start].insert(end, reference);
+            }
+        }
+    }
+
+    /// Add the half edge from `start` to `end` counterclockwise before the
+    /// half edge to `reference`, or as the only half edge of `start`.
+    fn add_half_edge_ccw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        match reference {
+            None => self.add_half_edge_cw(start, end, None),
+            Some(reference) => {
+                let ccw_reference = self.ccw[start][&reference];
+                self.add_half_edge_cw(start, end, Some(ccw_reference));
+                if self.first[start] == Some(reference) {
+                    self.first[start] = Some(end);
+                }
+            }
+        }
+    }
+
+    /// Add the half edge from `start` to `end` as the first one of `start`.
+    fn add_half_edge_first(&mut self, start: usize, end: usize) {
+        let reference = self.first[start];
+        self.add_half_edge_ccw(start, end, reference);
+    }
+
+    /// The neighbors of `node` in clockwise order, starting from the first.
+    fn neighbors_cw(&self, node: usize) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        if let Some(first) = self.first[node] {
+            let mut current = first;
+            loop {
+                neighbors.push(current);
+                current = self.cw[node][&current];
+                if current == first {
+                    break;
+                }
+            }
+        }
+        neighbors
+    }
+}
+
+/// The state of the left-right planarity test of a simple graph with
+/// contiguous node indices, the edges are oriented by the first depth first
+/// search and are identified by their position in `edges`.
+struct LRState<'a> {
+    adjacency: Vec<Vec<(usize, usize)>>,
+    edges: &'a [(usize, usize)],
+    oriented: Vec<(usize, usize)>,
+    roots: Vec<usize>,
+    height: Vec<Option<usize>>,
+    parent_edge: Vec<Option<usize>>,
+    out_edges: Vec<Vec<usize>>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<isize>,
+    lowpt_edge: Vec<Option<usize>>,
+    stack_bottom: Vec<usize>,
+    reference: Vec<Option<usize>>,
+    side: Vec<isize>,
+    stack: Vec<ConflictPair>,
+}
+
+impl<'a> LRState<'a> {
+    fn new(node_count: usize, edges: &'a [(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); node_count];
+        for (index, (source, target)) in edges.iter().enumerate() {
+            adjacency[*source].push((*target, index));
+            adjacency[*target].push((*source, index));
+        }
+        let edge_count = edges.len();
+        LRState {
+            adjacency,
+            edges,
+            oriented: edges.to_vec(),
+            roots: Vec::new(),
+            height: vec![None; node_count],
+            parent_edge: vec![None; node_count],
+            out_edges: vec![Vec::new(); node_count],
+            lowpt: vec![0; edge_count],
+            lowpt2: vec![0; edge_count],
+            nesting_depth: vec![0; edge_count],
+            lowpt_edge: vec![None; edge_count],
+            stack_bottom: vec![0; edge_count],
+            reference: vec![None; edge_count],
+            side: vec![1; edge_count],
+            stack: Vec::new(),
+        }
+    }
+
+    /// Orient the edges away from the roots of a depth first search forest
+    /// and compute the lowpoints and nesting depths of the edges.
+    fn dfs_orientation(&mut self, root: usize) {
+        let node_count = self.adjacency.len();
+        let mut next = vec![0; node_count];
+        let mut is_oriented = vec![false; self.edges.len()];
+        let mut tree_edge_visited = vec![false; self.edges.len()];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            let height_v = self.height[v].unwrap();
+            let parent = self.parent_edge[v];
+            while next[v] < self.adjacency[v].len() {
+                let (w, edge) = self.adjacency[v][next[v]];
+                if !tree_edge_visited[edge] {
+                    if is_oriented[edge] {
+                        next[v] += 1;
+                        continue;
+                    }
+                    is_oriented[edge] = true;
+                    self.oriented[edge] = (v, w);
+                    self.out_edges[v].push(edge);
+                    self.lowpt[edge] = height_v;
+                    self.lowpt2[edge] = height_v;
+                    match self.height[w] {
+                        None => {
+                            // Tree edge, visit w and come back to v
+                            self.parent_edge[w] = Some(edge);
+                            self.height[w] = Some(height_v + 1);
+                            tree_edge_visited[edge] = true;
+                            stack.push(v);
+                            stack.push(w);
+                            break;
+                        }
+                        Some(height_w) => self.lowpt[edge] = height_w,
+                    }
+                }
+                self.nesting_depth[edge] = 2 * self.lowpt[edge] as isize;
+                if self.lowpt2[edge] < height_v {
+                    // The edge is chordal
+                    self.nesting_depth[edge] += 1;
+                }
+                if let Some(parent) = parent {
+                    if self.lowpt[edge] < self.lowpt[parent] {
+                        self.lowpt2[parent] = self.lowpt[parent].min(self.lowpt2[edge]);
+                        self.lowpt[parent] = self.lowpt[edge];
+                    } else if self.lowpt[edge] > self.lowpt[parent] {
+                        self.lowpt2[parent] = self.lowpt2[parent].min(self.lowpt[edge]);
+                    } else {
+                        self.lowpt2[parent] = self.lowpt2[parent].min(self.lowpt2[edge]);
+                    }
+                }
+                next[v] += 1;
+            }
+        }
+    }
+
+    fn sort_out_edges(&mut self) {
+        for edges in self.out_edges.iter_mut() {
+            let nesting_depth = &self.nesting_depth;
+            edges.sort_by_key(|edge| nesting_depth[*edge]);
+        }
+    }
+
+    fn conflicting(&self, interval: &Interval, edge: usize) -> bool {
+        match interval.high {
+            Some(high) => self.lowpt[high] > self.lowpt[edge],
+            None => false,
+        }
+    }
+
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        match (pair.left.low, pair.right.low) {
+            (None, Some(right)) => self.lowpt[right],
+            (Some(left), None) => self.lowpt[left],
+            (Some(left), Some(right)) => self.lowpt[left].min(self.lowpt[right]),
+            (None, None) => unreachable!("empty conflict pairs aren't pushed"),
+        }
+    }
+
+    /// Test the constraints of the oriented graph, returns `false` if they
+    /// can't be satisfied and the graph isn't planar.
+    fn dfs_testing(&mut self, root: usize) -> bool {
+        let mut next = vec![0; self.adjacency.len()];
+        let mut tree_edge_visited = vec![false; self.edges.len()];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            let height_v = self.height[v].unwrap();
+            let parent = self.parent_edge[v];
+            let mut descended = false;
+            while next[v] < self.out_edges[v].len() {
+                let edge = self.out_edges[v][next[v]];
+                let w = self.oriented[edge].1;
+                if !tree_edge_visited[edge] {
+                    self.stack_bottom[edge] = self.stack.len();
+                    if self.parent_edge[w] == Some(edge) {
+                        tree_edge_visited[edge] = true;
+                        descended = true;
+                        stack.push(v);
+                        stack.push(w);
+                        break;
+                    }
+                    // Back edge
+                    self.lowpt_edge[edge] = Some(edge);
+                    self.stack.push(ConflictPair {
+                        left: Interval::default(),
+                        right: Interval {
+                            low: Some(edge),
+                            high: Some(edge),
+                        },
+                    });
+                }
+                // Integrate the new return edges
+                if self.lowpt[edge] < height_v {
+                    let parent = parent.unwrap();
+                    if next[v] == 0 {
+                        self.lowpt_edge[parent] = self.lowpt_edge[edge];
+                    } else if !self.add_constraints(edge, parent) {
+                        return false;
+                    }
+                }
+                next[v] += 1;
+            }
+            if !descended {
+                if let Some(parent) = parent {
+                    self.remove_back_edges(parent);
+                }
+            }
+        }
+        true
+    }
+
+    fn add_constraints(&mut self, edge: usize, parent: usize) -> bool {
+        let mut pair = ConflictPair::default();
+        // Merge the return edges of edge into the right interval
+        loop {
+            let mut other = self.stack.pop().unwrap();
+            if !other.left.is_empty() {
+                other.swap();
+            }
+            if !other.left.is_empty() {
+                return false;
+            }
+            let other_low = other.right.low.unwrap();
+            if self.lowpt[other_low] > self.lowpt[parent] {
+                match pair.right.low {
+                    None => pair.right = other.right,
+                    Some(low) => self.reference[low] = other.right.high,
+                }
+                pair.right.low = other.right.low;
+            } else {
+                self.reference[other_low] = self.lowpt_edge[parent];
+            }
+            if self.stack.len() == self.stack_bottom[edge] {
+                break;
+            }
+        }
+        // Merge the conflicting return edges of the previous siblings into
+        // the left interval
+        while let Some(top) = self.stack.last() {
+            if !self.conflicting(&top.left, edge) && !self.conflicting(&top.right, edge) {
+                break;
+            }
+            let mut other = self.stack.pop().unwrap();
+            if self.conflicting(&other.right, edge) {
+                other.swap();
+            }
+            if self.conflicting(&other.right, edge) {
+                return false;
+            }
+            if let Some(low) = pair.right.low {
+                self.reference[low] = other.right.high;
+            }
+            if other.right.low.is_some() {
+                pair.right.low = other.right.low;
+            }
+            if pair.left.is_empty() {
+                pair.left = other.left;
+            } else if let Some(low) = pair.left.low {
+                self.reference[low] = other.left.high;
+            }
+            pair.left.low = other.left.low;
+        }
+        if !pair.left.is_empty() || !pair.right.is_empty() {
+            self.stack.push(pair);
+        }
+        true
+    }
+
+    fn remove_back_edges(&mut self, edge: usize) {
+        let u = self.oriented[edge].0;
+        let height_u = self.height[u].unwrap();
+        // Drop the conflict pairs of back edges ending at u
+        while let Some(top) = self.stack.last() {
+            if self.lowest(top) != height_u {
+                break;
+            }
+            let pair = self.stack.pop().unwrap();
+            if let Some(low) = pair.left.low {
+                self.side[low] = -1;
+            }
+        }
+        // Trim the intervals of the next conflict pair
+        if let Some(mut pair) = self.stack.pop() {
+            while let Some(high) = pair.left.high {
+                if self.oriented[high].1 != u {
+                    break;
+                }
+                pair.left.high = self.reference[high];
+            }
+            if pair.left.high.is_none() {
+                if let Some(low) = pair.left.low {
+                    self.reference[low] = pair.right.low;
+                    self.side[low] = -1;
+                    pair.left.low = None;
+                }
+            }
+            while let Some(high) = pair.right.high {
+                if self.oriented[high].1 != u {
+                    break;
+                }
+                pair.right.high = self.reference[high];
+            }
+            if pair.right.high.is_none() {
+                if let Some(low) = pair.right.low {
+                    self.reference[low] = pair.left.low;
+                    self.side[low] = -1;
+                    pair.right.low = None;
+                }
+            }
+            self.stack.push(pair);
+        }
+        // The side of edge is the side of its highest return edge
+        if self.lowpt[edge] < height_u {
+            let top = self.stack.last().unwrap();
+            let (left, right) = (top.left.high, top.right.high);
+            self.reference[edge] = match (left, right) {
+                (Some(l), Some(r)) if self.lowpt[l] > self.lowpt[r] => left,
+                (Some(_), None) => left,
+                _ => right,
+            };
+        }
+    }
+
+    /// Resolve the side of `edge` relative to the chain of references.
+    fn sign(&mut self, edge: usize, old_reference: &mut [Option<usize>]) -> isize {
+        let mut stack = vec![edge];
+        while let Some(current) = stack.pop() {
+            if let Some(reference) = self.reference[current] {
+                stack.push(current);
+                stack.push(reference);
+                old_reference[current] = Some(reference);
+                self.reference[current] = None;
+            } else if let Some(reference) = old_reference[current].take() {
+                self.side[current] *= self.side[reference];
+            }
+        }
+        self.side[edge]
+    }
+
+    fn embedding(&mut self) -> Embedding {
+        let mut old_reference = vec![None; self.edges.len()];
+        for edge in 0..self.edges.len() {
+            self.nesting_depth[edge] *= self.sign(edge, &mut old_reference);
+        }
+        self.sort_out_edges();
+        let node_count = self.adjacency.len();
+        let mut embedding = Embedding::new(node_count);
+        for v in 0..node_count {
+            let mut previous = None;
+            for edge in &self.out_edges[v] {
+                let w = self.oriented[*edge].1;
+                embedding.add_half_edge_cw(v, w, previous);
+                previous = Some(w);
+            }
+        }
+        let mut left_reference = vec![0; node_count];
+        let mut right_reference = vec![0; node_count];
+        let mut next = vec![0; node_count];
+        for root in &self.roots {
+            let mut stack = vec![*root];
+            while let Some(v) = stack.pop() {
+                while next[v] < self.out_edges[v].len() {
+                    let edge = self.out_edges[v][next[v]];
+                    next[v] += 1;
+                    let w = self.oriented[edge].1;
+                    if self.parent_edge[w] == Some(edge) {
+                        embedding.add_half_edge_first(w, v);
+                        left_reference[v] = w;
+                        right_reference[v] = w;
+                        stack.push(v);
+                        stack.push(w);
+                        break;
+                    } else if self.side[edge] == 1 {
+                        embedding.add_half_edge_cw(w, v, Some(right_reference[w]));
+                    } else {
+                        embedding.add_half_edge_ccw(w, v, Some(left_reference[w]));
+                        left_reference[w] = v;
+                    }
+                }
+            }
+        }
+        embedding
+    }
+}
+
+/// Run the left-right planarity test on a simple graph with `node_count`
+/// nodes and the given edges, returns the embedding if it's planar.
+fn lr_planarity(node_count: usize, edges: &[(usize, usize)], embed: bool) -> Option<Embedding> {
+    if node_count > 2 && edges.len() > 3 * node_count - 6 {
+        return None;
+    }
+    let mut state = LRState::new(node_count, edges);
+    for node in 0..node_count {
+        if state.height[node].is_none() {
+            state.height[node] = Some(0);
+            state.roots.push(node);
+            state.dfs_orientation(node);
+        }
+    }
+    state.sort_out_edges();
+    for root in state.roots.clone() {
+        if !state.dfs_testing(root) {
+            return None;
+        }
+    }
+    if embed {
+        Some(state.embedding())
+    } else {
+        Some(Embedding::new(0))
+    }
+}
+
+/// Map the nodes of a graph to contiguous indices and collect its edges
+/// without self loops and parallel edges.
+fn simple_edges<G>(graph: G) -> (Vec<G::NodeId>, Vec<(usize, usize)>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut compact = vec![0; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut edges = Vec::new();
+    for edge in graph.edge_references() {
+        let source = compact[graph.to_index(edge.source())];
+        let target = compact[graph.to_index(edge.target())];
+        if source != target && seen.insert((source.min(target), source.max(target))) {
+            edges.push((source, target));
+        }
+    }
+    (nodes, edges)
+}
+
+/// Test whether an undirected graph is planar.
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing. This uses the left-right planarity test of Brandes \[1\], which
+/// runs in linear time. Self loops and parallel edges don't change the
+/// planarity of a graph and are ignored.
+///
+/// \[1\] Ulrik Brandes, "The Left-Right Planarity Test", 2009
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::graph::UnGraph;
+/// use retworkx_core::planar::is_planar;
+///
+/// // K4 is planar
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// assert!(is_planar(&graph));
+/// // K3,3 isn't
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///     (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5),
+/// ]);
+/// assert!(!is_planar(&graph));
+/// ```
+pub fn is_planar<G>(graph: G) -> bool
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, edges) = simple_edges(graph);
+    lr_planarity(nodes.len(), &edges, false).is_some()
+}
+
+/// Test whether an undirected graph is planar and return a certificate.
+///
+/// This uses the left-right planarity test of Brandes \[1\]. If the graph is
+/// planar a combinatorial planar embedding is returned as
+/// [`Planarity::Planar`], it maps each node to its neighbors in clockwise
+/// order around it in a drawing of the graph without crossing edges. Self
+/// loops and parallel edges are ignored and don't appear in the embedding.
+///
+/// If the graph isn't planar the edges of a Kuratowski subgraph are returned
+/// as [`Planarity::NonPlanar`], this is a subgraph which is a subdivision of
+/// the complete graph `K5` or of the complete bipartite graph `K3,3`. It's
+/// found by removing the edges of the graph one at a time and keeping those
+/// whose removal makes the remaining graph planar, so finding it runs in
+/// quadratic time.
+///
+/// \[1\] Ulrik Brandes, "The Left-Right Planarity Test", 2009
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+/// use retworkx_core::planar::{planarity, Planarity};
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 3)]);
+/// match planarity(&graph) {
+///     Planarity::Planar(embedding) => {
+///         assert_eq!(embedding[&NodeIndex::new(0)].len(), 3);
+///         assert_eq!(embedding[&NodeIndex::new(3)], vec![NodeIndex::new(0)]);
+///     }
+///     Planarity::NonPlanar(_) => panic!("the graph is planar"),
+/// }
+///
+/// // K5 with one subdivided edge isn't planar and is its own Kuratowski
+/// // subgraph
+/// let mut edges = vec![(0, 5), (5, 1)];
+/// for i in 0..5 {
+///     for j in (i + 1)..5 {
+///         if (i, j) != (0, 1) {
+///             edges.push((i, j));
+///         }
+///     }
+/// }
+/// let graph = UnGraph::<(), ()>::from_edges(&edges);
+/// match planarity(&graph) {
+///     Planarity::NonPlanar(kuratowski) => assert_eq!(kuratowski.len(), 11),
+///     Planarity::Planar(_) => panic!("the graph isn't planar"),
+/// }
+/// ```
+pub fn planarity<G>(graph: G) -> Planarity<G::NodeId>
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Hash + Eq,
+{
+    let (nodes, mut edges) = simple_edges(graph);
+    if let Some(embedding) = lr_planarity(nodes.len(), &edges, true) {
+        return Planarity::Planar(
+            nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| {
+                    let neighbors = embedding
+                        .neighbors_cw(index)
+                        .into_iter()
+                        .map(|neighbor| nodes[neighbor])
+                        .collect();
+                    (*node, neighbors)
+                })
+                .collect(),
+        );
+    }
+    // Remove every edge which isn't needed for the graph to stay non planar,
+    // what remains is a minimal non planar subgraph.
+    let mut index = 0;
+    while index < edges.len() {
+        let edge = edges.remove(index);
+        if lr_planarity(nodes.len(), &edges, false).is_some() {
+            edges.insert(index, edge);
+            index += 1;
+        }
+    }
+    Planarity::NonPlanar(
+        edges
+            .into_iter()
+            .map(|(source, target)| (nodes[source], nodes[target]))
+            .collect(),
+    )
+}
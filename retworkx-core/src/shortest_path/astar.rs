@@ -25,9 +25,11 @@ use hashbrown::hash_map::Entry::{Occupied, Vacant};
 use hashbrown::HashMap;
 
 use petgraph::algo::Measure;
-use petgraph::visit::{EdgeRef, GraphBase, IntoEdges, VisitMap, Visitable};
+use petgraph::visit::{EdgeRef, GraphBase, IntoEdges, NodeIndexable, VisitMap, Visitable};
 
+use crate::contains::ContainsNode;
 use crate::min_scored::MinScored;
+use crate::Error;
 
 type AstarOutput<K, N> = Option<(K, Vec<N>)>;
 
@@ -46,14 +48,14 @@ type AstarOutput<K, N> = Option<(K, Vec<N>)>;
 /// it should never overestimate the actual cost to get to the nearest goal node. Estimate costs
 /// must also be non-negative.
 ///
-/// The graph should be [`Visitable`] and implement [`IntoEdges`].
+/// The graph should be [`Visitable`] and implement [`IntoEdges`] and [`NodeIndexable`].
 ///
 /// # Example
 /// ```
 /// use retworkx_core::petgraph::graph::NodeIndex;
 /// use retworkx_core::petgraph::Graph;
 /// use retworkx_core::shortest_path::astar;
-/// use retworkx_core::Result;
+/// use retworkx_core::{Error, Result};
 ///
 /// let mut g = Graph::new();
 /// let a = g.add_node((0., 0.));
@@ -82,7 +84,7 @@ type AstarOutput<K, N> = Option<(K, Vec<N>)>;
 /// // | 1*    | 1*    |
 /// // \------ e ------/
 ///
-/// let res: Result<Option<(u64, Vec<NodeIndex>)>> = astar(
+/// let res: Result<Option<(u64, Vec<NodeIndex>)>, Error> = astar(
 ///     &g, a, |finish| Ok(finish == f), |e| Ok(*e.weight()), |_| Ok(0)
 /// );
 /// let path = res.unwrap();
@@ -90,22 +92,26 @@ type AstarOutput<K, N> = Option<(K, Vec<N>)>;
 /// ```
 ///
 /// Returns the total cost + the path of subsequent `NodeId` from start to finish, if one was
-/// found.
+/// found. If `start` is not a valid node index [`Error::InvalidNode`] is returned and if any of
+/// the callbacks return an error it is returned as [`Error::Callback`].
 pub fn astar<G, F, H, K, IsGoal, E>(
     graph: G,
     start: G::NodeId,
     mut is_goal: IsGoal,
     mut edge_cost: F,
     mut estimate_cost: H,
-) -> Result<AstarOutput<K, G::NodeId>, E>
+) -> Result<AstarOutput<K, G::NodeId>, Error<E>>
 where
-    G: IntoEdges + Visitable,
+    G: IntoEdges + Visitable + NodeIndexable + ContainsNode,
     IsGoal: FnMut(G::NodeId) -> Result<bool, E>,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
     H: FnMut(G::NodeId) -> Result<K, E>,
     K: Measure + Copy,
 {
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
     let mut visited = graph.visit_map();
     let mut visit_next = BinaryHeap::new();
     let mut scores = HashMap::new();
@@ -113,11 +119,11 @@ where
 
     let zero_score = K::default();
     scores.insert(start, zero_score);
-    let estimate = estimate_cost(start)?;
+    let estimate = estimate_cost(start).map_err(Error::Callback)?;
     visit_next.push(MinScored(estimate, start));
 
     while let Some(MinScored(_, node)) = visit_next.pop() {
-        let result = is_goal(node)?;
+        let result = is_goal(node).map_err(Error::Callback)?;
         if result {
             let path = path_tracker.reconstruct_path_to(node);
             let cost = scores[&node];
@@ -140,7 +146,7 @@ where
                 continue;
             }
 
-            let cost = edge_cost(edge)?;
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
             let mut next_score = node_score + cost;
 
             match scores.entry(next) {
@@ -159,7 +165,7 @@ where
                 }
             }
 
-            let estimate = estimate_cost(next)?;
+            let estimate = estimate_cost(next).map_err(Error::Callback)?;
             let next_estimate_score = next_score + estimate;
             visit_next.push(MinScored(next_estimate_score, next));
         }
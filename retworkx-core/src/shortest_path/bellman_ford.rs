@@ -0,0 +1,176 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use petgraph::algo::Measure;
+use petgraph::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::contains::ContainsNode;
+use crate::dictmap::*;
+use crate::distancemap::DistanceMap;
+use crate::Error;
+
+type BellmanFordOutput<S, N> = (S, DictMap<N, N>, Option<Vec<N>>);
+
+/// Bellman-Ford shortest path algorithm.
+///
+/// Compute the length of the shortest path from `start` to every reachable
+/// node, and the predecessor of each reachable node on its shortest path.
+/// Unlike [`dijkstra`](crate::shortest_path::dijkstra) edge costs can be
+/// negative, the cost type `K` can be any signed type implementing
+/// [`Measure`] such as a signed integer or float type.
+///
+/// The graph should implement [`IntoEdges`] and [`IntoNodeIdentifiers`]. The
+/// function `edge_cost` should return the cost for a particular edge, it's
+/// called once for each edge (twice for each edge of an undirected graph,
+/// once in each direction). An undirected edge with a negative cost is a
+/// negative cycle between its endpoints.
+///
+/// Computes in **O(|V| * |E|)** time, the relaxation stops early once a pass
+/// over the edges doesn't improve any distance.
+///
+/// Returns a tuple of a [`DistanceMap`] that maps `NodeId` to path cost, a
+/// [`DictMap`] that maps each reachable `NodeId`, other than `start`, to the
+/// `NodeId` preceding it on the shortest path, and a negative cycle if one is
+/// reachable from `start`. The cycle is a `Vec` of the nodes on it in order,
+/// with an edge from each node to the next one and from the last node to
+/// the first one. If there is a negative cycle the shortest paths aren't
+/// defined and the distances and predecessors are the values after the last
+/// pass over the edges. If `start` is not a valid node index
+/// [`Error::InvalidNode`] is returned and if `edge_cost` returns an error it
+/// is returned as [`Error::Callback`].
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::shortest_path::bellman_ford;
+/// use retworkx_core::{Error, Result};
+///
+/// let mut graph: Graph<(), i32, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 4), (a, c, 2), (c, b, -1), (b, d, 1)]);
+///
+/// let res: Result<(DictMap<NodeIndex, i32>, _, _), Error> =
+///     bellman_ford(&graph, a, |e| Ok(*e.weight()));
+/// let (scores, predecessors, negative_cycle) = res.unwrap();
+/// assert_eq!(scores[&d], 2);
+/// assert_eq!(predecessors[&b], c);
+/// assert_eq!(negative_cycle, None);
+///
+/// // Adding an edge from d back to c closes the cycle c -> b -> d -> c
+/// // with a total cost of -1
+/// graph.add_edge(d, c, -1);
+/// let res: Result<(DictMap<NodeIndex, i32>, _, _), Error> =
+///     bellman_ford(&graph, a, |e| Ok(*e.weight()));
+/// let (_, _, negative_cycle) = res.unwrap();
+/// let mut cycle = negative_cycle.unwrap();
+/// let first = cycle.iter().position(|node| *node == c).unwrap();
+/// cycle.rotate_left(first);
+/// assert_eq!(cycle, vec![c, b, d]);
+/// ```
+pub fn bellman_ford<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    mut edge_cost: F,
+) -> Result<BellmanFordOutput<S, G::NodeId>, Error<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
+    let node_bound = graph.node_bound();
+    let start = graph.to_index(start);
+    // Evaluate the cost of every edge once up front, so the callback isn't
+    // called again on every pass.
+    let mut arcs: Vec<(usize, usize, K)> = Vec::new();
+    for node in graph.node_identifiers() {
+        for edge in graph.edges(node) {
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            arcs.push((graph.to_index(node), graph.to_index(edge.target()), cost));
+        }
+    }
+
+    let mut scores: Vec<Option<K>> = vec![None; node_bound];
+    let mut predecessors: Vec<Option<usize>> = vec![None; node_bound];
+    scores[start] = Some(K::default());
+    let relax =
+        |scores: &mut Vec<Option<K>>, predecessors: &mut Vec<Option<usize>>| -> Option<usize> {
+            let mut relaxed = None;
+            for &(source, target, cost) in &arcs {
+                if let Some(source_score) = scores[source] {
+                    let next_score = source_score + cost;
+                    let improved = match scores[target] {
+                        Some(current_score) => next_score < current_score,
+                        None => true,
+                    };
+                    if improved {
+                        scores[target] = Some(next_score);
+                        predecessors[target] = Some(source);
+                        relaxed = Some(target);
+                    }
+                }
+            }
+            relaxed
+        };
+    let node_count = graph.node_identifiers().count();
+    let mut converged = false;
+    for _ in 1..node_count {
+        if relax(&mut scores, &mut predecessors).is_none() {
+            converged = true;
+            break;
+        }
+    }
+    let negative_cycle = if converged {
+        None
+    } else {
+        relax(&mut scores, &mut predecessors).map(|relaxed| {
+            // The node relaxed on the extra pass is reachable from a negative
+            // cycle in the predecessor graph, after following the predecessors
+            // back for as many steps as there are nodes it's on the cycle.
+            let mut node = relaxed;
+            for _ in 0..node_count {
+                node = predecessors[node].unwrap();
+            }
+            let mut cycle = vec![graph.from_index(node)];
+            let mut previous = predecessors[node].unwrap();
+            while previous != node {
+                cycle.push(graph.from_index(previous));
+                previous = predecessors[previous].unwrap();
+            }
+            cycle.reverse();
+            cycle
+        })
+    };
+
+    let mut distances = S::build(node_bound);
+    let mut predecessor_map: DictMap<G::NodeId, G::NodeId> = DictMap::new();
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        if let Some(score) = scores[index] {
+            distances.put_item(node, score);
+            if let Some(predecessor) = predecessors[index] {
+                predecessor_map.insert(node, graph.from_index(predecessor));
+            }
+        }
+    }
+    Ok((distances, predecessor_map, negative_cycle))
+}
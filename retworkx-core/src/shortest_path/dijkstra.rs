@@ -23,9 +23,15 @@ use std::hash::Hash;
 use petgraph::algo::Measure;
 use petgraph::visit::{EdgeRef, IntoEdges, NodeIndexable, VisitMap, Visitable};
 
+use crate::contains::ContainsNode;
 use crate::dictmap::*;
 use crate::distancemap::DistanceMap;
 use crate::min_scored::MinScored;
+use crate::workspace::Workspace;
+use crate::Error;
+
+type PredecessorOutput<S, N> = (S, DictMap<N, N>);
+type PathOutput<K, N> = Option<(K, Vec<N>)>;
 
 /// Dijkstra's shortest path algorithm.
 ///
@@ -44,14 +50,16 @@ use crate::min_scored::MinScored;
 /// the value is a Vec of node indices of the path starting with `start` and
 /// ending at the index.
 ///
-/// Returns a [`DistanceMap`] that maps `NodeId` to path cost.
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost. If `start` is
+/// not a valid node index [`Error::InvalidNode`] is returned and if
+/// `edge_cost` returns an error it is returned as [`Error::Callback`].
 /// # Example
 /// ```rust
 /// use retworkx_core::petgraph::Graph;
 /// use retworkx_core::petgraph::prelude::*;
 /// use retworkx_core::dictmap::DictMap;
 /// use retworkx_core::shortest_path::dijkstra;
-/// use retworkx_core::Result;
+/// use retworkx_core::{Error, Result};
 ///
 /// let mut graph : Graph<(),(),Directed>= Graph::new();
 /// let a = graph.add_node(()); // node with no weight
@@ -91,26 +99,320 @@ use crate::min_scored::MinScored;
 ///      (g, 3),
 ///      (h, 4)
 ///     ].iter().cloned().collect();
-/// let res: Result<DictMap<NodeIndex, usize>> = dijkstra(
+/// let res: Result<DictMap<NodeIndex, usize>, Error> = dijkstra(
 ///     &graph, b, None, |_| Ok(1), None
 /// );
 /// assert_eq!(res.unwrap(), expected_res);
 /// // z is not inside res because there is not path from b to z.
 /// ```
 pub fn dijkstra<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    edge_cost: F,
+    path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<S, Error<E>>
+where
+    G: IntoEdges + Visitable + NodeIndexable + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    dijkstra_search(graph, start, goal, edge_cost, path, None)
+}
+
+/// Dijkstra's shortest path algorithm with a predecessor map.
+///
+/// Compute the length of the shortest path from `start` to every reachable
+/// node, the same as [`dijkstra`], and also record the predecessor of each
+/// reachable node on its shortest path from `start`. The shortest path to any
+/// node can be rebuilt by following the predecessors back to `start`, which
+/// avoids storing a full path for every node.
+///
+/// The graph should be [`Visitable`] and implement [`IntoEdges`]. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative. The cost type `K`
+/// can be any type implementing [`Measure`], for example an integer type, a
+/// float, or a custom ordered type.
+///
+/// If `goal` is not [`None`], then the algorithm terminates once the `goal` node's
+/// cost is calculated.
+///
+/// Returns a tuple of a [`DistanceMap`] that maps `NodeId` to path cost and a
+/// [`DictMap`] that maps each reachable `NodeId`, other than `start`, to the
+/// `NodeId` preceding it on the shortest path. Errors are returned the same
+/// as for [`dijkstra`].
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::shortest_path::dijkstra_with_predecessors;
+/// use retworkx_core::{Error, Result};
+///
+/// let mut graph: Graph<(), u32, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let d = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 1), (b, c, 1), (a, c, 3), (c, d, 2)]);
+///
+/// let res: Result<(DictMap<NodeIndex, u32>, DictMap<NodeIndex, NodeIndex>), Error> =
+///     dijkstra_with_predecessors(&graph, a, None, |e| Ok(*e.weight()));
+/// let (scores, predecessors) = res.unwrap();
+/// assert_eq!(scores[&d], 4);
+/// // Walk the predecessors back from d to rebuild the shortest path
+/// let mut path = vec![d];
+/// while let Some(node) = predecessors.get(path.last().unwrap()) {
+///     path.push(*node);
+/// }
+/// path.reverse();
+/// assert_eq!(path, vec![a, b, c, d]);
+/// ```
+pub fn dijkstra_with_predecessors<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    edge_cost: F,
+) -> Result<PredecessorOutput<S, G::NodeId>, Error<E>>
+where
+    G: IntoEdges + Visitable + NodeIndexable + ContainsNode,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let mut predecessors: DictMap<G::NodeId, G::NodeId> = DictMap::new();
+    let scores = dijkstra_search(graph, start, goal, edge_cost, None, Some(&mut predecessors))?;
+    Ok((scores, predecessors))
+}
+
+/// Dijkstra's shortest path algorithm reusing the buffers of a [`Workspace`].
+///
+/// Compute the length of the shortest path from `start` to every reachable
+/// node, the same as [`dijkstra`], but store the distances and predecessors
+/// in `workspace` instead of allocating new output for every call. This is
+/// useful when running many searches on the same graph. The results can be
+/// read from `workspace` after the function returns, they're valid until the
+/// next search run with it.
+///
+/// `generation` identifies the state of the graph, it should be changed by
+/// the caller whenever the graph is modified. If it differs from the
+/// generation of the previous search run with `workspace` the buffers are
+/// resized and fully cleared, otherwise only the entries written by the
+/// previous search are reset.
+///
+/// Returns [`Error::InvalidNode`] if `start` is not a valid node index and
+/// the error from `edge_cost` as [`Error::Callback`].
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::shortest_path::dijkstra_with_workspace;
+/// use retworkx_core::workspace::Workspace;
+/// use retworkx_core::{Error, Result};
+///
+/// let graph: Graph<(), u32, Directed> =
+///     Graph::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 3), (2, 3, 2)]);
+/// let mut workspace = Workspace::new();
+/// for start in graph.node_indices() {
+///     let res: Result<(), Error> =
+///         dijkstra_with_workspace(&graph, start, None, |e| Ok(*e.weight()), &mut workspace, 0);
+///     res.unwrap();
+///     assert_eq!(workspace.distance(start.index()), Some(&0));
+/// }
+/// // The workspace holds the result of the last search, from node 3
+/// assert_eq!(workspace.reached(), &[3]);
+///
+/// let res: Result<(), Error> = dijkstra_with_workspace(
+///     &graph, NodeIndex::new(0), None, |e| Ok(*e.weight()), &mut workspace, 0
+/// );
+/// res.unwrap();
+/// assert_eq!(workspace.distance(3), Some(&4));
+/// assert_eq!(workspace.path_to(3), Some(vec![0, 1, 2, 3]));
+/// ```
+pub fn dijkstra_with_workspace<G, F, K, E>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+    workspace: &mut Workspace<K>,
+    generation: u64,
+) -> Result<(), Error<E>>
+where
+    G: IntoEdges + NodeIndexable + ContainsNode,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+{
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
+    let start = graph.to_index(start);
+    let goal = goal.map(|goal| graph.to_index(goal));
+    workspace.reset(graph.node_bound(), generation);
+    let zero_score = K::default();
+    workspace.scores[start] = Some(zero_score);
+    workspace.reached.push(start);
+    workspace.heap.push(MinScored(zero_score, start));
+    while let Some(MinScored(node_score, node)) = workspace.heap.pop() {
+        if workspace.visited[node] {
+            continue;
+        }
+        if goal == Some(node) {
+            break;
+        }
+        for edge in graph.edges(graph.from_index(node)) {
+            let next = graph.to_index(edge.target());
+            if workspace.visited[next] {
+                continue;
+            }
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            let next_score = node_score + cost;
+            let improved = match workspace.scores[next] {
+                Some(current_score) => next_score < current_score,
+                None => {
+                    workspace.reached.push(next);
+                    true
+                }
+            };
+            if improved {
+                workspace.scores[next] = Some(next_score);
+                workspace.predecessors[next] = Some(node);
+                workspace.heap.push(MinScored(next_score, next));
+            }
+        }
+        workspace.visited[node] = true;
+    }
+    Ok(())
+}
+
+/// Dijkstra's shortest path algorithm between two sets of nodes.
+///
+/// Find the shortest path from any node in `sources` to any node in
+/// `targets`. This is a single search which is equivalent to running
+/// [`dijkstra`] from a virtual super source joined to every node in
+/// `sources` by an edge with zero cost, stopping at a virtual super sink
+/// joined to every node in `targets` the same way. If a node is in both sets
+/// the path is just that node.
+///
+/// The graph should be [`Visitable`] and implement [`IntoEdges`]. The function
+/// `edge_cost` should return the cost for a particular edge, which is used
+/// to compute path costs. Edge costs must be non-negative.
+///
+/// Returns the cost of the shortest path and the nodes along it, starting
+/// with a node in `sources` and ending with a node in `targets`, or [`None`]
+/// if no target can be reached from the sources. If a node in `sources` or
+/// `targets` is not a valid node index [`Error::InvalidNode`] is returned and
+/// if `edge_cost` returns an error it is returned as [`Error::Callback`].
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::shortest_path::dijkstra_between_sets;
+/// use retworkx_core::{Error, Result};
+///
+/// let graph: Graph<(), u32, Directed> =
+///     Graph::from_edges(&[(0, 2, 5), (1, 2, 1), (2, 3, 1), (2, 4, 3), (1, 4, 6)]);
+/// let sources = [NodeIndex::new(0), NodeIndex::new(1)];
+/// let targets = [NodeIndex::new(3), NodeIndex::new(4)];
+/// let res: Result<Option<(u32, Vec<NodeIndex>)>, Error> = dijkstra_between_sets(
+///     &graph, sources.iter().copied(), targets.iter().copied(), |e| Ok(*e.weight())
+/// );
+/// let (cost, path) = res.unwrap().unwrap();
+/// assert_eq!(cost, 2);
+/// assert_eq!(path, vec![NodeIndex::new(1), NodeIndex::new(2), NodeIndex::new(3)]);
+/// ```
+pub fn dijkstra_between_sets<G, I, J, F, K, E>(
+    graph: G,
+    sources: I,
+    targets: J,
+    mut edge_cost: F,
+) -> Result<PathOutput<K, G::NodeId>, Error<E>>
+where
+    G: IntoEdges + Visitable + NodeIndexable + ContainsNode,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+    J: IntoIterator<Item = G::NodeId>,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+{
+    // The edges from the super sink are represented by marking the targets
+    let mut is_target = graph.visit_map();
+    for target in targets {
+        if !graph.contains_node(target) {
+            return Err(Error::InvalidNode);
+        }
+        is_target.visit(target);
+    }
+    let mut visited = graph.visit_map();
+    let mut scores: DictMap<G::NodeId, K> = DictMap::new();
+    let mut predecessors: DictMap<G::NodeId, G::NodeId> = DictMap::new();
+    let mut visit_next = BinaryHeap::new();
+    let zero_score = K::default();
+    // and the edges from the super source by starting at every source
+    for source in sources {
+        if !graph.contains_node(source) {
+            return Err(Error::InvalidNode);
+        }
+        if scores.insert(source, zero_score).is_none() {
+            visit_next.push(MinScored(zero_score, source));
+        }
+    }
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        if is_target.is_visited(&node) {
+            let mut path = vec![node];
+            let mut current = node;
+            while let Some(previous) = predecessors.get(&current) {
+                current = *previous;
+                path.push(current);
+            }
+            path.reverse();
+            return Ok(Some((node_score, path)));
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            let next_score = node_score + cost;
+            let improved = match scores.get(&next) {
+                Some(current_score) => next_score < *current_score,
+                None => true,
+            };
+            if improved {
+                scores.insert(next, next_score);
+                predecessors.insert(next, node);
+                visit_next.push(MinScored(next_score, next));
+            }
+        }
+        visited.visit(node);
+    }
+    Ok(None)
+}
+
+fn dijkstra_search<G, F, K, E, S>(
     graph: G,
     start: G::NodeId,
     goal: Option<G::NodeId>,
     mut edge_cost: F,
     mut path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
-) -> Result<S, E>
+    mut predecessors: Option<&mut DictMap<G::NodeId, G::NodeId>>,
+) -> Result<S, Error<E>>
 where
-    G: IntoEdges + Visitable + NodeIndexable,
+    G: IntoEdges + Visitable + NodeIndexable + ContainsNode,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
     K: Measure + Copy,
     S: DistanceMap<G::NodeId, K>,
 {
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
     let mut visited = graph.visit_map();
     let mut scores: S = S::build(graph.node_bound());
     let mut visit_next = BinaryHeap::new();
@@ -132,13 +434,16 @@ where
             if visited.is_visited(&next) {
                 continue;
             }
-            let cost = edge_cost(edge)?;
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
             let next_score = node_score + cost;
             match scores.get_item(next) {
                 Some(current_score) => {
                     if next_score < *current_score {
                         scores.put_item(next, next_score);
                         visit_next.push(MinScored(next_score, next));
+                        if let Some(predecessors) = predecessors.as_mut() {
+                            predecessors.insert(next, node);
+                        }
                         if path.is_some() {
                             let mut node_path = path.as_mut().unwrap().get(&node).unwrap().clone();
                             node_path.push(next);
@@ -151,6 +456,9 @@ where
                 None => {
                     scores.put_item(next, next_score);
                     visit_next.push(MinScored(next_score, next));
+                    if let Some(predecessors) = predecessors.as_mut() {
+                        predecessors.insert(next, node);
+                    }
                     if path.is_some() {
                         let mut node_path = path.as_mut().unwrap().get(&node).unwrap().clone();
                         node_path.push(next);
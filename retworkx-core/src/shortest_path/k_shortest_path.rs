@@ -20,13 +20,19 @@
 use std::collections::BinaryHeap;
 use std::hash::Hash;
 
+use hashbrown::{HashMap, HashSet};
+
 use petgraph::algo::Measure;
 use petgraph::visit::{
     EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, Visitable,
 };
 
+use crate::contains::ContainsNode;
 use crate::distancemap::DistanceMap;
 use crate::min_scored::MinScored;
+use crate::Error;
+
+type SimplePathsOutput<K, N> = Vec<(K, Vec<N>)>;
 
 /// k'th shortest path algorithm.
 ///
@@ -42,7 +48,9 @@ use crate::min_scored::MinScored;
 ///
 /// Computes in **O(k * (|E| + |V|*log(|V|)))** time (average).
 ///
-/// Returns a [`DistanceMap`] that maps `NodeId` to path cost as the value.
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost as the value. If
+/// `start` is not a valid node index [`Error::InvalidNode`] is returned and if
+/// `edge_cost` returns an error it is returned as [`Error::Callback`].
 ///
 /// # Example:
 /// ```rust
@@ -51,13 +59,13 @@ use crate::min_scored::MinScored;
 /// use retworkx_core::petgraph::graph::NodeIndex;
 /// use retworkx_core::shortest_path::k_shortest_path;
 /// use hashbrown::HashMap;
-/// use retworkx_core::Result;
+/// use retworkx_core::{Error, Result};
 ///
 /// let g = petgraph::graph::UnGraph::<i32, _>::from_edges(&[
 ///     (0, 1), (1, 2), (2, 3), (3, 0), (4, 5), (1, 4), (5, 6), (6, 7), (7, 5)
 /// ]);
 ///
-/// let res: Result<HashMap<NodeIndex, f64>> = k_shortest_path(
+/// let res: Result<HashMap<NodeIndex, f64>, Error> = k_shortest_path(
 ///     &g, NodeIndex::new(1), None, 2,
 ///     |e: retworkx_core::petgraph::graph::EdgeReference<&'static str>| Ok(1.0),
 /// );
@@ -81,14 +89,17 @@ pub fn k_shortest_path<G, F, E, K, S>(
     goal: Option<G::NodeId>,
     k: usize,
     mut edge_cost: F,
-) -> Result<S, E>
+) -> Result<S, Error<E>>
 where
-    G: IntoEdges + Visitable + NodeCount + NodeIndexable + IntoNodeIdentifiers,
+    G: IntoEdges + Visitable + NodeCount + NodeIndexable + ContainsNode + IntoNodeIdentifiers,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
     K: Measure + Copy,
     S: DistanceMap<G::NodeId, K>,
 {
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
     let mut counter: Vec<usize> = vec![0; graph.node_bound()];
     let mut scores: S = S::build(graph.node_bound());
     let mut visit_next = BinaryHeap::new();
@@ -114,9 +125,303 @@ where
         }
 
         for edge in graph.edges(node) {
-            visit_next.push(MinScored(node_score + edge_cost(edge)?, edge.target()));
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            visit_next.push(MinScored(node_score + cost, edge.target()));
         }
     }
 
     Ok(scores)
 }
+
+/// Lengths of the `k` shortest paths between two nodes.
+///
+/// Compute the lengths of the `k` shortest paths from `start` to `goal`, in
+/// increasing order. Like [`k_shortest_path`] the paths aren't required to
+/// be simple, they can visit a node more than once, so a graph with a cycle
+/// reachable from `start` from which `goal` is reachable always has `k`
+/// paths. If there are fewer than `k` paths all of their lengths are
+/// returned.
+///
+/// The graph should implement [`IntoEdges`]. The function `edge_cost` should
+/// return the cost for a particular edge, which is used to compute path
+/// costs. Edge costs must be non-negative.
+///
+/// Computes in **O(k * (|E| + |V|*log(|V|)))** time (average).
+///
+/// Returns a `Vec` of the path lengths. If `start` or `goal` is not a valid
+/// node index [`Error::InvalidNode`] is returned and if `edge_cost` returns
+/// an error it is returned as [`Error::Callback`].
+///
+/// # Example:
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::shortest_path::k_shortest_path_lengths;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 3, 1), (0, 2, 2), (2, 3, 2), (3, 0, 1)
+/// ]);
+/// let res: Result<Vec<u32>, Error> = k_shortest_path_lengths(
+///     &g, NodeIndex::new(0), NodeIndex::new(3), 3, |e| Ok(*e.weight())
+/// );
+/// // The third path goes around the cycle through node 3 once
+/// assert_eq!(res.unwrap(), vec![2, 4, 5]);
+/// ```
+pub fn k_shortest_path_lengths<G, F, E, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    k: usize,
+    mut edge_cost: F,
+) -> Result<Vec<K>, Error<E>>
+where
+    G: IntoEdges + NodeIndexable + ContainsNode,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+{
+    if !graph.contains_node(start) || !graph.contains_node(goal) {
+        return Err(Error::InvalidNode);
+    }
+    let mut counter: Vec<usize> = vec![0; graph.node_bound()];
+    let mut lengths: Vec<K> = Vec::with_capacity(k);
+    let mut visit_next = BinaryHeap::new();
+    visit_next.push(MinScored(K::default(), start));
+
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if lengths.len() == k {
+            break;
+        }
+        let index = graph.to_index(node);
+        counter[index] += 1;
+        // A node popped more than k times can't be on any of the k shortest
+        // paths to goal.
+        if counter[index] > k {
+            continue;
+        }
+        if node == goal {
+            lengths.push(node_score);
+        }
+        for edge in graph.edges(node) {
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            visit_next.push(MinScored(node_score + cost, edge.target()));
+        }
+    }
+
+    Ok(lengths)
+}
+
+/// The shortest path from `start` to `goal` in the compact adjacency list
+/// `adjacency` avoiding `removed_nodes` and `removed_arcs`.
+fn restricted_shortest_path<K: Measure + Copy>(
+    adjacency: &[Vec<(usize, K)>],
+    start: usize,
+    goal: usize,
+    removed_nodes: &[bool],
+    removed_arcs: &HashSet<(usize, usize)>,
+) -> Option<(K, Vec<usize>)> {
+    let mut scores: Vec<Option<K>> = vec![None; adjacency.len()];
+    let mut predecessors: Vec<Option<usize>> = vec![None; adjacency.len()];
+    let mut visited: Vec<bool> = vec![false; adjacency.len()];
+    let mut visit_next = BinaryHeap::new();
+    scores[start] = Some(K::default());
+    visit_next.push(MinScored(K::default(), start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited[node] {
+            continue;
+        }
+        visited[node] = true;
+        if node == goal {
+            let mut path = vec![goal];
+            while let Some(previous) = predecessors[*path.last().unwrap()] {
+                path.push(previous);
+            }
+            path.reverse();
+            return Some((node_score, path));
+        }
+        for &(next, cost) in &adjacency[node] {
+            if visited[next] || removed_nodes[next] || removed_arcs.contains(&(node, next)) {
+                continue;
+            }
+            let next_score = node_score + cost;
+            let improved = match scores[next] {
+                Some(current_score) => next_score < current_score,
+                None => true,
+            };
+            if improved {
+                scores[next] = Some(next_score);
+                predecessors[next] = Some(node);
+                visit_next.push(MinScored(next_score, next));
+            }
+        }
+    }
+    None
+}
+
+/// The `k` shortest simple paths between two nodes with Yen's algorithm.
+///
+/// Compute the `k` shortest simple paths, which don't visit any node more
+/// than once, from `start` to `goal` in increasing order of length \[1\]. If
+/// there are fewer than `k` simple paths all of them are returned. Paths
+/// are sequences of nodes, so of parallel edges between two nodes only the
+/// one with the lowest cost is used.
+///
+/// The graph should implement [`IntoEdges`] and [`IntoNodeIdentifiers`]. The
+/// function `edge_cost` should return the cost for a particular edge, which
+/// is used to compute path costs. It's called once for each edge (twice for
+/// each edge of an undirected graph, once in each direction) before the
+/// search. Edge costs must be non-negative.
+///
+/// Computes in **O(k * |V| * (|E| + |V|*log(|V|)))** time.
+///
+/// Returns a `Vec` of tuples of the length of a path and the `Vec` of the
+/// nodes on it, starting with `start` and ending with `goal`. If `start` or
+/// `goal` is not a valid node index [`Error::InvalidNode`] is returned and if
+/// `edge_cost` returns an error it is returned as [`Error::Callback`].
+///
+/// \[1\] Yen, Jin Y. "Finding the k shortest loopless paths in a network."
+///     Management Science 17.11 (1971): 712-716.
+///
+/// # Example:
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::shortest_path::k_shortest_simple_paths;
+/// use retworkx_core::{Error, Result};
+///
+/// let g = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 3, 1), (0, 2, 2), (2, 3, 2), (1, 2, 1), (3, 0, 1)
+/// ]);
+/// let res: Result<Vec<(u32, Vec<NodeIndex>)>, Error> = k_shortest_simple_paths(
+///     &g, NodeIndex::new(0), NodeIndex::new(3), 5, |e| Ok(*e.weight())
+/// );
+/// let paths: Vec<(u32, Vec<usize>)> = res
+///     .unwrap()
+///     .into_iter()
+///     .map(|(length, path)| (length, path.iter().map(|n| n.index()).collect()))
+///     .collect();
+/// assert_eq!(
+///     paths,
+///     vec![(2, vec![0, 1, 3]), (4, vec![0, 2, 3]), (4, vec![0, 1, 2, 3])]
+/// );
+/// ```
+pub fn k_shortest_simple_paths<G, F, E, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: G::NodeId,
+    k: usize,
+    mut edge_cost: F,
+) -> Result<SimplePathsOutput<K, G::NodeId>, Error<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + ContainsNode,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure + Copy,
+{
+    if !graph.contains_node(start) || !graph.contains_node(goal) {
+        return Err(Error::InvalidNode);
+    }
+    let node_bound = graph.node_bound();
+    let start_index = graph.to_index(start);
+    let goal_index = graph.to_index(goal);
+    // Evaluate the cost of every edge once, keeping only the cheapest of
+    // parallel edges.
+    let mut arc_costs: HashMap<(usize, usize), K> = HashMap::new();
+    let mut adjacency: Vec<Vec<(usize, K)>> = vec![Vec::new(); node_bound];
+    for node in graph.node_identifiers() {
+        let source = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let cost = edge_cost(edge).map_err(Error::Callback)?;
+            let target = graph.to_index(edge.target());
+            let arc_cost = arc_costs.entry((source, target)).or_insert(cost);
+            if cost < *arc_cost {
+                *arc_cost = cost;
+            }
+        }
+    }
+    for (&(source, target), &cost) in &arc_costs {
+        adjacency[source].push((target, cost));
+    }
+    // Sort the arcs so the result doesn't depend on the hash map order
+    for arcs in adjacency.iter_mut() {
+        arcs.sort_by_key(|arc| arc.0);
+    }
+
+    let mut removed_nodes: Vec<bool> = vec![false; node_bound];
+    let mut removed_arcs: HashSet<(usize, usize)> = HashSet::new();
+    let mut paths: Vec<(K, Vec<usize>)> = Vec::with_capacity(k);
+    if k > 0 {
+        if let Some(path) = restricted_shortest_path(
+            &adjacency,
+            start_index,
+            goal_index,
+            &removed_nodes,
+            &removed_arcs,
+        ) {
+            paths.push(path);
+        }
+    }
+    let mut candidates: Vec<(K, Vec<usize>)> = Vec::new();
+    let mut seen: HashSet<Vec<usize>> = paths.iter().map(|path| path.1.clone()).collect();
+    while !paths.is_empty() && paths.len() < k {
+        let previous = paths.last().unwrap().1.clone();
+        let mut root_cost = K::default();
+        for i in 0..previous.len() - 1 {
+            let spur_node = previous[i];
+            let root = &previous[..=i];
+            // Block the next arc of every found path sharing this root, and
+            // the root nodes before the spur node, so the spur path deviates
+            // from all of them.
+            removed_arcs.clear();
+            for (_, path) in &paths {
+                if path.len() > i + 1 && &path[..=i] == root {
+                    removed_arcs.insert((path[i], path[i + 1]));
+                }
+            }
+            for node in &root[..i] {
+                removed_nodes[*node] = true;
+            }
+            if let Some((spur_cost, spur_path)) = restricted_shortest_path(
+                &adjacency,
+                spur_node,
+                goal_index,
+                &removed_nodes,
+                &removed_arcs,
+            ) {
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+                if seen.insert(path.clone()) {
+                    candidates.push((root_cost + spur_cost, path));
+                }
+            }
+            for node in &root[..i] {
+                removed_nodes[*node] = false;
+            }
+            root_cost = root_cost + arc_costs[&(previous[i], previous[i + 1])];
+        }
+        // Take the cheapest candidate, the earliest found on ties so the
+        // order is deterministic.
+        let mut best: Option<usize> = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            best = match best {
+                Some(best_index) if candidates[best_index].0 <= candidate.0 => Some(best_index),
+                _ => Some(index),
+            };
+        }
+        match best {
+            Some(best_index) => paths.push(candidates.remove(best_index)),
+            None => break,
+        }
+    }
+
+    Ok(paths
+        .into_iter()
+        .map(|(cost, path)| {
+            (
+                cost,
+                path.into_iter()
+                    .map(|node| graph.from_index(node))
+                    .collect(),
+            )
+        })
+        .collect())
+}
@@ -16,9 +16,13 @@
 //! shortest path of a graph.
 
 mod astar;
+mod bellman_ford;
 mod dijkstra;
 mod k_shortest_path;
 
 pub use astar::astar;
-pub use dijkstra::dijkstra;
-pub use k_shortest_path::k_shortest_path;
+pub use bellman_ford::bellman_ford;
+pub use dijkstra::{
+    dijkstra, dijkstra_between_sets, dijkstra_with_predecessors, dijkstra_with_workspace,
+};
+pub use k_shortest_path::{k_shortest_path, k_shortest_path_lengths, k_shortest_simple_paths};
@@ -0,0 +1,306 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::visit::{GraphBase, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+use rayon::prelude::*;
+
+use crate::contains::ContainsNode;
+use crate::Error;
+
+type Swap<G> = (<G as GraphBase>::NodeId, <G as GraphBase>::NodeId);
+
+/// Visit state of a node for the depth first search used to find a sequence
+/// of happy swaps.
+#[derive(Copy, Clone, PartialEq)]
+enum Visit {
+    New,
+    OnStack,
+    Done,
+}
+
+/// The state shared between all the trials of the token swapper.
+///
+/// All nodes are referred to by their compact index from
+/// [`NodeIndexable::to_index`].
+struct TokenSwapper {
+    neighbors: Vec<Vec<usize>>,
+    distances: HashMap<usize, Vec<Option<usize>>>,
+    tokens: Vec<Option<usize>>,
+}
+
+impl TokenSwapper {
+    /// Return the distance from ``node`` to ``dest``. Every destination's
+    /// distances were checked to be reachable from its token before any
+    /// trial is run.
+    fn distance(&self, node: usize, dest: usize) -> usize {
+        self.distances[&dest][node].unwrap()
+    }
+
+    /// Return the neighbors of ``node`` that are closer to the destination
+    /// of the token on ``node``. Only nodes with a token that is not at its
+    /// destination have any.
+    fn successors(&self, tokens: &[Option<usize>], node: usize) -> Vec<usize> {
+        match tokens[node] {
+            Some(dest) if dest != node => {
+                let dist = self.distance(node, dest);
+                self.neighbors[node]
+                    .iter()
+                    .copied()
+                    .filter(|neighbor| self.distance(*neighbor, dest) < dist)
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Find a sequence of nodes where every swap between consecutive nodes
+    /// moves a token closer to its destination without moving any other
+    /// token further from its own, i.e. a cycle or a path ending in a node
+    /// without a token. The swaps should be performed from the end of the
+    /// returned sequence to the start.
+    fn find_happy_sequence(
+        &self,
+        tokens: &[Option<usize>],
+        todo: &[usize],
+        rng: &mut Pcg64,
+    ) -> Option<Vec<usize>> {
+        let mut state = vec![Visit::New; tokens.len()];
+        let mut starts = todo.to_vec();
+        starts.shuffle(rng);
+        for start in starts {
+            if state[start] != Visit::New {
+                continue;
+            }
+            let mut path: Vec<usize> = vec![start];
+            let mut stack: Vec<VecDeque<usize>> = Vec::new();
+            let mut successors = self.successors(tokens, start);
+            successors.shuffle(rng);
+            stack.push(successors.into());
+            state[start] = Visit::OnStack;
+            while let Some(next_nodes) = stack.last_mut() {
+                match next_nodes.pop_front() {
+                    Some(next) => match state[next] {
+                        Visit::OnStack => {
+                            let pos = path.iter().position(|node| *node == next).unwrap();
+                            return Some(path[pos..].to_vec());
+                        }
+                        Visit::Done => (),
+                        Visit::New => {
+                            path.push(next);
+                            if tokens[next].is_none() {
+                                return Some(path);
+                            }
+                            state[next] = Visit::OnStack;
+                            let mut successors = self.successors(tokens, next);
+                            successors.shuffle(rng);
+                            stack.push(successors.into());
+                        }
+                    },
+                    None => {
+                        stack.pop();
+                        state[path.pop().unwrap()] = Visit::Done;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Run a single randomized trial of the algorithm and return the swaps
+    /// it found.
+    fn trial(&self, seed: u64) -> Vec<(usize, usize)> {
+        let mut rng = Pcg64::seed_from_u64(seed);
+        let mut tokens = self.tokens.clone();
+        let mut swaps: Vec<(usize, usize)> = Vec::new();
+        let is_todo = |tokens: &[Option<usize>], node: usize| match tokens[node] {
+            Some(dest) => dest != node,
+            None => false,
+        };
+        let mut todo: Vec<usize> = (0..tokens.len())
+            .filter(|node| is_todo(&tokens, *node))
+            .collect();
+        while !todo.is_empty() {
+            let mut swap_sequence: Vec<(usize, usize)> = Vec::new();
+            match self.find_happy_sequence(&tokens, &todo, &mut rng) {
+                Some(sequence) => {
+                    for i in (0..sequence.len() - 1).rev() {
+                        swap_sequence.push((sequence[i], sequence[i + 1]));
+                    }
+                }
+                None => {
+                    // There are no happy swaps left so follow the path of a
+                    // token towards its destination until it hits a node
+                    // whose token is already at its destination and swap
+                    // them, moving that token one step away from it.
+                    let mut node = todo[rng.gen_range(0..todo.len())];
+                    loop {
+                        let next = *self.successors(&tokens, node).choose(&mut rng).unwrap();
+                        if !is_todo(&tokens, next) {
+                            swap_sequence.push((node, next));
+                            break;
+                        }
+                        node = next;
+                    }
+                }
+            }
+            let mut changed: HashSet<usize> = HashSet::with_capacity(2 * swap_sequence.len());
+            for (node_a, node_b) in swap_sequence {
+                tokens.swap(node_a, node_b);
+                swaps.push((node_a, node_b));
+                changed.insert(node_a);
+                changed.insert(node_b);
+            }
+            todo.retain(|node| !changed.contains(node));
+            todo.extend(changed.into_iter().filter(|node| is_todo(&tokens, *node)));
+            todo.sort_unstable();
+        }
+        swaps
+    }
+}
+
+/// Find a sequence of swaps along the edges of a graph which moves each token
+/// to its destination.
+///
+/// Each node in the graph can hold at most one token and a swap exchanges
+/// the tokens (if any) on the two endpoints of an edge. This is an
+/// approximate solver, the number of swaps returned is at most 4 times the
+/// optimal solution. The algorithm used is based on:
+///
+/// Miltzow, Tillmann, et al. Approximation and Hardness of Token Swapping.
+/// 24th Annual European Symposium on Algorithms (ESA 2016), 66:1-66:15.
+/// <https://arxiv.org/abs/1602.05150>
+///
+/// This function is randomized, the algorithm is run ``trials`` times and
+/// the shortest sequence of swaps is returned. If the number of nodes in the
+/// graph is greater than or equal to ``parallel_threshold`` the trials are
+/// run in parallel. If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find swaps on
+/// * `mapping` - A map of each node with a token to the destination node of
+///     that token. Nodes which aren't present in the map don't have a token.
+/// * `trials` - The number of randomized trials to run, by default this is
+///     `4`
+/// * `seed` - An optional seed to use for the random number generator
+/// * `parallel_threshold` - The number of nodes in the graph to run the
+///     trials in parallel at, by default this is `50`
+///
+/// Returns the sequence of swaps as pairs of nodes, in order, or
+/// [`Error::MapNotPossible`] if there is a token without a path to its
+/// destination or multiple tokens share a destination. If a node in `mapping`
+/// is not a valid node index [`Error::InvalidNode`] is returned.
+///
+/// # Example
+/// ```rust
+/// use hashbrown::HashMap;
+/// use retworkx_core::petgraph;
+/// use retworkx_core::token_swapper::token_swapper;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// // Reverse the order of the tokens on the path
+/// let mapping: HashMap<NodeIndex, NodeIndex> = [(0, 3), (1, 2), (2, 1), (3, 0)]
+///     .iter()
+///     .map(|(node, dest)| (NodeIndex::new(*node), NodeIndex::new(*dest)))
+///     .collect();
+/// let swaps = token_swapper(&g, mapping, Some(4), Some(4), None).unwrap();
+/// assert_eq!(6, swaps.len());
+/// ```
+pub fn token_swapper<G>(
+    graph: G,
+    mapping: HashMap<G::NodeId, G::NodeId>,
+    trials: Option<usize>,
+    seed: Option<u64>,
+    parallel_threshold: Option<usize>,
+) -> Result<Vec<Swap<G>>, Error>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeCount + NodeIndexable + ContainsNode,
+    G::NodeId: Hash + Eq,
+{
+    let node_bound = graph.node_bound();
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); node_bound];
+    for node in graph.node_identifiers() {
+        neighbors[graph.to_index(node)] = graph
+            .neighbors(node)
+            .map(|neighbor| graph.to_index(neighbor))
+            .collect();
+    }
+    let mut tokens: Vec<Option<usize>> = vec![None; node_bound];
+    let mut distances: HashMap<usize, Vec<Option<usize>>> = HashMap::with_capacity(mapping.len());
+    for (node, dest) in mapping {
+        if !graph.contains_node(node) || !graph.contains_node(dest) {
+            return Err(Error::InvalidNode);
+        }
+        let node = graph.to_index(node);
+        let dest = graph.to_index(dest);
+        if distances.contains_key(&dest) {
+            return Err(Error::MapNotPossible);
+        }
+        // Breadth first search from the destination to find the distance
+        // from it to every node.
+        let mut dest_distances: Vec<Option<usize>> = vec![None; node_bound];
+        dest_distances[dest] = Some(0);
+        let mut queue: VecDeque<usize> = VecDeque::from(vec![dest]);
+        while let Some(current) = queue.pop_front() {
+            let dist = dest_distances[current].unwrap();
+            for neighbor in &neighbors[current] {
+                if dest_distances[*neighbor].is_none() {
+                    dest_distances[*neighbor] = Some(dist + 1);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+        if dest_distances[node].is_none() {
+            return Err(Error::MapNotPossible);
+        }
+        distances.insert(dest, dest_distances);
+        tokens[node] = Some(dest);
+    }
+    let swapper = TokenSwapper {
+        neighbors,
+        distances,
+        tokens,
+    };
+
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    };
+    let trial_seeds: Vec<u64> = (0..trials.unwrap_or(4)).map(|_| rng.gen()).collect();
+    let results: Vec<Vec<(usize, usize)>> = if graph.node_count() < parallel_threshold.unwrap_or(50)
+    {
+        trial_seeds
+            .iter()
+            .map(|trial_seed| swapper.trial(*trial_seed))
+            .collect()
+    } else {
+        trial_seeds
+            .par_iter()
+            .map(|trial_seed| swapper.trial(*trial_seed))
+            .collect()
+    };
+    Ok(results
+        .into_iter()
+        .min_by_key(|swaps| swaps.len())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(node_a, node_b)| (graph.from_index(node_a), graph.from_index(node_b)))
+        .collect())
+}
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{IntoNeighbors, NodeIndexable};
+
+use crate::contains::ContainsNode;
+use crate::workspace::Workspace;
+use crate::Error;
+
+/// A breadth first search reusing the buffers of a [`Workspace`].
+///
+/// Find every node reachable from `start`, recording the order the nodes
+/// were reached in and the predecessor of each node in the breadth first
+/// search tree. Instead of allocating new output for every call the results
+/// are stored in `workspace` and can be read from it with
+/// [`Workspace::reached`], [`Workspace::predecessor`] and
+/// [`Workspace::path_to`] after the function returns. The distances in
+/// `workspace` are not set by this function.
+///
+/// `generation` identifies the state of the graph, it should be changed by
+/// the caller whenever the graph is modified. If it differs from the
+/// generation of the previous search run with `workspace` the buffers are
+/// resized and fully cleared, otherwise only the entries written by the
+/// previous search are reset.
+///
+/// Returns [`Error::InvalidNode`] if `start` is not a valid node index.
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+/// use retworkx_core::traversal::bfs_with_workspace;
+/// use retworkx_core::workspace::Workspace;
+///
+/// let graph = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (2, 3), (4, 0)]);
+/// let mut workspace: Workspace<()> = Workspace::new();
+/// bfs_with_workspace(&graph, NodeIndex::new(0), &mut workspace, 0).unwrap();
+/// // Graph iterates over the neighbors of a node in reverse insertion order
+/// assert_eq!(workspace.reached(), &[0, 2, 1, 3]);
+/// assert_eq!(workspace.path_to(3), Some(vec![0, 2, 3]));
+/// assert_eq!(workspace.path_to(4), None);
+/// ```
+pub fn bfs_with_workspace<G, K>(
+    graph: G,
+    start: G::NodeId,
+    workspace: &mut Workspace<K>,
+    generation: u64,
+) -> Result<(), Error>
+where
+    G: IntoNeighbors + NodeIndexable + ContainsNode,
+{
+    if !graph.contains_node(start) {
+        return Err(Error::InvalidNode);
+    }
+    let start = graph.to_index(start);
+    workspace.reset(graph.node_bound(), generation);
+    workspace.visited[start] = true;
+    workspace.reached.push(start);
+    workspace.queue.push_back(start);
+    while let Some(node) = workspace.queue.pop_front() {
+        for neighbor in graph.neighbors(graph.from_index(node)) {
+            let next = graph.to_index(neighbor);
+            if !workspace.visited[next] {
+                workspace.visited[next] = true;
+                workspace.predecessors[next] = Some(node);
+                workspace.reached.push(next);
+                workspace.queue.push_back(next);
+            }
+        }
+    }
+    Ok(())
+}
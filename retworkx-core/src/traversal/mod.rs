@@ -13,11 +13,13 @@
 //! Module for graph traversal algorithms.
 
 mod bfs_visit;
+mod bfs_workspace;
 mod dfs_edges;
 mod dfs_visit;
 mod dijkstra_visit;
 
 pub use bfs_visit::{breadth_first_search, BfsEvent};
+pub use bfs_workspace::bfs_with_workspace;
 pub use dfs_edges::dfs_edges;
 pub use dfs_visit::{depth_first_search, DfsEvent};
 pub use dijkstra_visit::{dijkstra_search, DijkstraEvent};
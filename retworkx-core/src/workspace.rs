@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the [`Workspace`] type which holds reusable scratch
+//! buffers for searches run many times on the same graph.
+//!
+//! It is used by [`dijkstra_with_workspace`](crate::shortest_path::dijkstra_with_workspace)
+//! and [`bfs_with_workspace`](crate::traversal::bfs_with_workspace).
+
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::min_scored::MinScored;
+
+/// Reusable distance and predecessor buffers for repeated searches.
+///
+/// Allocating the distance, predecessor and visited buffers for every search
+/// dominates the runtime when a search is run thousands of times on the same
+/// graph. A [`Workspace`] keeps those buffers between searches and only
+/// resets the entries the previous search wrote to.
+///
+/// The buffers are keyed to a generation counter maintained by the caller.
+/// When a search is run with a different generation than the previous one,
+/// for example because the graph was modified in between, the buffers are
+/// resized to the node bound of the graph and fully cleared.
+///
+/// The results of the last search run with the workspace can be read with
+/// [`Workspace::distance`], [`Workspace::predecessor`],
+/// [`Workspace::reached`] and [`Workspace::path_to`].
+#[derive(Debug, Clone)]
+pub struct Workspace<K> {
+    generation: Option<u64>,
+    pub(crate) scores: Vec<Option<K>>,
+    pub(crate) predecessors: Vec<Option<usize>>,
+    pub(crate) visited: Vec<bool>,
+    pub(crate) reached: Vec<usize>,
+    pub(crate) heap: BinaryHeap<MinScored<K, usize>>,
+    pub(crate) queue: VecDeque<usize>,
+}
+
+impl<K> Default for Workspace<K> {
+    fn default() -> Self {
+        Workspace {
+            generation: None,
+            scores: Vec::new(),
+            predecessors: Vec::new(),
+            visited: Vec::new(),
+            reached: Vec::new(),
+            heap: BinaryHeap::new(),
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+impl<K> Workspace<K> {
+    /// Create an empty workspace, the buffers are allocated by the first
+    /// search run with it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The generation of the graph the last search was run on, or [`None`]
+    /// if no search was run yet.
+    pub fn generation(&self) -> Option<u64> {
+        self.generation
+    }
+
+    /// The distance from the start node of the last search to the node at
+    /// `index`, or [`None`] if it wasn't reached.
+    pub fn distance(&self, index: usize) -> Option<&K> {
+        self.scores.get(index).and_then(|score| score.as_ref())
+    }
+
+    /// The index of the node preceding the node at `index` on its shortest
+    /// path from the start node of the last search.
+    pub fn predecessor(&self, index: usize) -> Option<usize> {
+        self.predecessors.get(index).copied().flatten()
+    }
+
+    /// The indices of the nodes reached by the last search, starting with
+    /// the start node, in the order they were first reached.
+    pub fn reached(&self) -> &[usize] {
+        &self.reached
+    }
+
+    /// Rebuild the path from the start node of the last search to the node
+    /// at `index` by following the predecessors, or [`None`] if the node
+    /// wasn't reached.
+    pub fn path_to(&self, index: usize) -> Option<Vec<usize>> {
+        if self.reached.first() != Some(&index) && self.predecessor(index).is_none() {
+            return None;
+        }
+        let mut path = vec![index];
+        while let Some(node) = self.predecessor(*path.last().unwrap()) {
+            path.push(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Prepare the buffers for a search on a graph with `node_bound` node
+    /// slots at `generation`.
+    pub(crate) fn reset(&mut self, node_bound: usize, generation: u64) {
+        if self.generation == Some(generation) && self.scores.len() == node_bound {
+            for index in self.reached.drain(..) {
+                self.scores[index] = None;
+                self.predecessors[index] = None;
+                self.visited[index] = false;
+            }
+        } else {
+            self.scores.clear();
+            self.scores.resize_with(node_bound, || None);
+            self.predecessors.clear();
+            self.predecessors.resize(node_bound, None);
+            self.visited.clear();
+            self.visited.resize(node_bound, false);
+            self.reached.clear();
+            self.generation = Some(generation);
+        }
+        self.heap.clear();
+        self.queue.clear();
+    }
+}
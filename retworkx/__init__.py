@@ -292,6 +292,59 @@ def _graph_adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_valu
     )
 
 
+@functools.singledispatch
+def modularity_matrix(graph, weight_fn=None, default_weight=1.0, resolution=1.0):
+    r"""Return the modularity matrix of a graph
+
+    The modularity matrix :math:`B` of an undirected graph is
+
+    .. math::
+
+        B_{ij} = A_{ij} - \gamma \frac{k_i k_j}{2m}
+
+    and of a directed graph
+
+    .. math::
+
+        B_{ij} = A_{ij} - \gamma \frac{k_i^{out} k_j^{in}}{m}
+
+    where :math:`A` is the adjacency matrix, :math:`k_i` is the weighted
+    degree of node :math:`i`, :math:`m` is the total weight of the edges and
+    :math:`\gamma` is the resolution. If the graph has no edges every entry
+    is ``0.0``.
+
+    :param graph: The graph to compute the modularity matrix of. Can either
+        be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used as the weight of the edge. The weights of parallel
+        edges are summed.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of each edge.
+    :param float resolution: The resolution parameter :math:`\gamma`, values
+        larger than 1 favor smaller communities. By default this is ``1.0``.
+
+    :returns: The modularity matrix, the rows and columns are in node index
+        order without the indices of any removed nodes
+    :rtype: numpy.ndarray
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@modularity_matrix.register(PyDiGraph)
+def _digraph_modularity_matrix(graph, weight_fn=None, default_weight=1.0, resolution=1.0):
+    return digraph_modularity_matrix(
+        graph, weight_fn=weight_fn, default_weight=default_weight, resolution=resolution
+    )
+
+
+@modularity_matrix.register(PyGraph)
+def _graph_modularity_matrix(graph, weight_fn=None, default_weight=1.0, resolution=1.0):
+    return graph_modularity_matrix(
+        graph, weight_fn=weight_fn, default_weight=default_weight, resolution=resolution
+    )
+
+
 @functools.singledispatch
 def all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
     """Return all simple paths between 2 nodes in a PyGraph object
@@ -325,6 +378,79 @@ def _graph_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
     return graph_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
 
 
+@functools.singledispatch
+def iter_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    """Return an iterator over the simple paths between 2 nodes in a graph
+
+    A simple path is a path with no repeated nodes. Unlike
+    :func:`~retworkx.all_simple_paths` the paths are found lazily as the
+    returned iterator is consumed, so they don't all have to fit in memory
+    and the search can be stopped early. A path through parallel edges is
+    only returned once, use :func:`~retworkx.all_simple_edge_paths` to tell
+    them apart.
+
+    :param graph: The graph to find the paths in. Can either be a
+        class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int from_: The node index to find the paths from
+    :param int to: The node index to find the paths to
+    :param int min_depth: The minimum number of nodes in the returned paths.
+        By default all paths are returned regardless of depth.
+    :param int cutoff: The maximum number of nodes in the returned paths. By
+        default all paths are returned regardless of depth, setting to 0 will
+        behave like the default.
+
+    :returns: An iterator over the simple paths, each a list of node indices
+    :rtype: SimplePathIterator
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@iter_all_simple_paths.register(PyDiGraph)
+def _digraph_iter_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return digraph_iter_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+
+
+@iter_all_simple_paths.register(PyGraph)
+def _graph_iter_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return graph_iter_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+
+
+@functools.singledispatch
+def all_simple_edge_paths(graph, from_, to, min_depth=None, cutoff=None):
+    """Return an iterator over the simple paths between 2 nodes in a graph as
+    lists of edge indices
+
+    A simple path is a path with no repeated nodes. Each path is returned as
+    the indices of its edges, so in a multigraph the paths which go through
+    different parallel edges between the same nodes are told apart. The paths
+    are found lazily as the returned iterator is consumed.
+
+    :param graph: The graph to find the paths in. Can either be a
+        class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int from_: The node index to find the paths from
+    :param int to: The node index to find the paths to
+    :param int min_depth: The minimum number of nodes in the returned paths.
+        By default all paths are returned regardless of depth.
+    :param int cutoff: The maximum number of nodes in the returned paths. By
+        default all paths are returned regardless of depth, setting to 0 will
+        behave like the default.
+
+    :returns: An iterator over the simple paths, each a list of edge indices
+    :rtype: SimplePathIterator
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@all_simple_edge_paths.register(PyDiGraph)
+def _digraph_all_simple_edge_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return digraph_all_simple_edge_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+
+
+@all_simple_edge_paths.register(PyGraph)
+def _graph_all_simple_edge_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return graph_all_simple_edge_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+
+
 @functools.singledispatch
 def floyd_warshall(
     graph,
@@ -519,6 +645,296 @@ def _graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost
     return graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn)
 
 
+@functools.singledispatch
+def shortest_path_between_sets(graph, sources, targets, weight_fn=None, default_weight=1.0):
+    """Find the shortest path from any node in a set of sources to any node in
+    a set of targets
+
+    The path is found with a single run of Dijkstra's algorithm from all the
+    sources at once, which is the same as searching from a virtual node
+    joined to every source by a zero weight edge to a virtual node joined to
+    every target by a zero weight edge. It's much faster than finding the
+    shortest path between every pair of a source and a target.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list sources: The node indices to find the path from
+    :param list targets: The node indices to find the path to
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight/cost of each edge.
+
+    :returns: The node indices of the shortest path, starting with a node in
+        ``sources`` and ending with a node in ``targets``. If a node is in
+        both sets the path is just that node.
+    :rtype: NodeIndices
+    :raises NoPathFound: If there is no path from any source to any target
+    :raises ValueError: when an edge weight with NaN or negative value
+        is provided.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@shortest_path_between_sets.register(PyDiGraph)
+def _digraph_shortest_path_between_sets(
+    graph, sources, targets, weight_fn=None, default_weight=1.0
+):
+    return digraph_shortest_path_between_sets(
+        graph, sources, targets, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@shortest_path_between_sets.register(PyGraph)
+def _graph_shortest_path_between_sets(graph, sources, targets, weight_fn=None, default_weight=1.0):
+    return graph_shortest_path_between_sets(
+        graph, sources, targets, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@functools.singledispatch
+def is_path(graph, path):
+    """Check if a list of nodes is a path in a graph
+
+    The nodes are a path if they're all in the graph and there is an edge
+    from each node to the next node. The path doesn't have to be simple, it
+    can visit a node more than once.
+
+    :param graph: The graph to check the path in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list path: The node indices of the path
+
+    :returns: ``True`` if ``path`` is a path in the graph, an empty list is
+        not a path
+    :rtype: bool
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@is_path.register(PyDiGraph)
+def _digraph_is_path(graph, path):
+    return digraph_is_path(graph, path)
+
+
+@is_path.register(PyGraph)
+def _graph_is_path(graph, path):
+    return graph_is_path(graph, path)
+
+
+@functools.singledispatch
+def path_weight(graph, path, weight_fn=None, default_weight=1.0, edge_path=False):
+    """Compute the total weight of a path in a graph
+
+    The path is either a list of node indices or, if ``edge_path`` is set, a
+    list of edge indices such as those returned by
+    :func:`~retworkx.all_simple_edge_paths`. If there are parallel edges
+    between two consecutive nodes of a path of nodes the edge with the lowest
+    weight is used.
+
+    :param graph: The graph to compute the path weight in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list path: The node indices, or edge indices if ``edge_path`` is
+        set, of the path
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight/cost of each edge.
+    :param bool edge_path: If set to ``True`` ``path`` is a list of edge
+        indices instead of node indices
+
+    :returns: The sum of the weights of the edges of the path
+    :rtype: float
+    :raises InvalidNode: If a node of the path isn't in the graph
+    :raises NoEdgeBetweenNodes: If there is no edge from a node of the path
+        to the next node
+    :raises IndexError: If an edge of the path isn't in the graph
+    :raises ValueError: If an edge of the path doesn't continue from the
+        previous edge
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@path_weight.register(PyDiGraph)
+def _digraph_path_weight(graph, path, weight_fn=None, default_weight=1.0, edge_path=False):
+    return digraph_path_weight(
+        graph, path, weight_fn=weight_fn, default_weight=default_weight, edge_path=edge_path
+    )
+
+
+@path_weight.register(PyGraph)
+def _graph_path_weight(graph, path, weight_fn=None, default_weight=1.0, edge_path=False):
+    return graph_path_weight(
+        graph, path, weight_fn=weight_fn, default_weight=default_weight, edge_path=edge_path
+    )
+
+
+@functools.singledispatch
+def edges_of_path(graph, path, weight_fn=None, default_weight=1.0):
+    """Get the edges along a path of nodes in a graph
+
+    A path of nodes doesn't identify which of any parallel edges between two
+    consecutive nodes is traversed, the edge with the lowest weight is used
+    and on a tie the one with the lowest index.
+
+    :param graph: The graph the path is in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list path: The node indices of the path
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to choose between parallel edges
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of each edge.
+
+    :returns: The edge indices along the path
+    :rtype: EdgeIndices
+    :raises InvalidNode: If a node of the path isn't in the graph
+    :raises NoEdgeBetweenNodes: If there is no edge from a node of the path
+        to the next node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@edges_of_path.register(PyDiGraph)
+def _digraph_edges_of_path(graph, path, weight_fn=None, default_weight=1.0):
+    return digraph_edges_of_path(graph, path, weight_fn=weight_fn, default_weight=default_weight)
+
+
+@edges_of_path.register(PyGraph)
+def _graph_edges_of_path(graph, path, weight_fn=None, default_weight=1.0):
+    return graph_edges_of_path(graph, path, weight_fn=weight_fn, default_weight=default_weight)
+
+
+@functools.singledispatch
+def is_tree(graph):
+    """Check if a graph is a tree
+
+    A tree is a connected graph without cycles, ignoring the direction of
+    the edges of a :class:`~retworkx.PyDiGraph`. Self loops and parallel
+    edges are cycles and a graph without nodes isn't a tree.
+
+    :param graph: The graph to check. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+        a tree. Otherwise it's falsy and its reason is ``"null graph"``,
+        ``"cycle"`` or ``"disconnected"``, with a counterexample.
+    :rtype: TreeCheck
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@is_tree.register(PyDiGraph)
+def _digraph_is_tree(graph):
+    return digraph_is_tree(graph)
+
+
+@is_tree.register(PyGraph)
+def _graph_is_tree(graph):
+    return graph_is_tree(graph)
+
+
+@functools.singledispatch
+def is_forest(graph):
+    """Check if a graph is a forest
+
+    A forest is a graph without cycles, ignoring the direction of the edges
+    of a :class:`~retworkx.PyDiGraph`, so each of its connected components
+    is a tree. Self loops and parallel edges are cycles and a graph without
+    nodes is a forest.
+
+    :param graph: The graph to check. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+        a forest. Otherwise it's falsy and its reason is ``"cycle"``, with
+        the edges of a cycle as the counterexample.
+    :rtype: TreeCheck
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@is_forest.register(PyDiGraph)
+def _digraph_is_forest(graph):
+    return digraph_is_forest(graph)
+
+
+@is_forest.register(PyGraph)
+def _graph_is_forest(graph):
+    return graph_is_forest(graph)
+
+
+@functools.singledispatch
+def longest_common_subpath(graph, first, second):
+    """Find the longest common subpath of two paths in a graph
+
+    The longest common subpath is the longest run of consecutive nodes that
+    is in both paths. For a :class:`~retworkx.PyGraph` the run can be in
+    ``second`` in reverse. On a tie the run which ends first in ``first`` is
+    returned.
+
+    :param graph: The graph the paths are in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list first: The node indices of the first path
+    :param list second: The node indices of the second path
+
+    :returns: The node indices of the longest common subpath, in the order
+        of ``first``. If the paths don't have a node in common it is empty
+    :rtype: NodeIndices
+    :raises InvalidNode: If a node of a path isn't in the graph
+    :raises NoEdgeBetweenNodes: If there is no edge from a node of a path to
+        the next node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@longest_common_subpath.register(PyDiGraph)
+def _digraph_longest_common_subpath(graph, first, second):
+    return digraph_longest_common_subpath(graph, first, second)
+
+
+@longest_common_subpath.register(PyGraph)
+def _graph_longest_common_subpath(graph, first, second):
+    return graph_longest_common_subpath(graph, first, second)
+
+
+@functools.singledispatch
+def path_jaccard_similarity(graph, first, second):
+    """Compute the Jaccard similarity of the edges of two paths in a graph
+
+    The similarity is the number of edges traversed by both paths divided
+    by the number of edges traversed by either path. An edge is identified by
+    its endpoints, so parallel edges are the same edge. For a
+    :class:`~retworkx.PyGraph` the direction a path traverses an edge in
+    doesn't matter.
+
+    :param graph: The graph the paths are in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list first: The node indices of the first path
+    :param list second: The node indices of the second path
+
+    :returns: The similarity of the paths between ``0.0`` and ``1.0``. If
+        neither path has an edge it is ``1.0`` if the paths are equal and
+        ``0.0`` otherwise
+    :rtype: float
+    :raises InvalidNode: If a node of a path isn't in the graph
+    :raises NoEdgeBetweenNodes: If there is no edge from a node of a path to
+        the next node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@path_jaccard_similarity.register(PyDiGraph)
+def _digraph_path_jaccard_similarity(graph, first, second):
+    return digraph_path_jaccard_similarity(graph, first, second)
+
+
+@path_jaccard_similarity.register(PyGraph)
+def _graph_path_jaccard_similarity(graph, first, second):
+    return graph_path_jaccard_similarity(graph, first, second)
+
+
 @functools.singledispatch
 def dijkstra_shortest_paths(
     graph,
@@ -527,6 +943,8 @@ def dijkstra_shortest_paths(
     weight_fn=None,
     default_weight=1.0,
     as_undirected=False,
+    edge_filter_fn=None,
+    node_mask=None,
 ):
     """Find the shortest path from a node
 
@@ -545,6 +963,15 @@ def dijkstra_shortest_paths(
     :param bool as_undirected: If set to true the graph will be treated as
         undirected for finding the shortest path. This only works with a
         :class:`~retworkx.PyDiGraph` input for ``graph``
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
 
     :return: Dictionary of paths. The keys are destination node indices and
         the dict values are lists of node indices making the path.
@@ -561,6 +988,8 @@ def _digraph_dijkstra_shortest_path(
     weight_fn=None,
     default_weight=1.0,
     as_undirected=False,
+    edge_filter_fn=None,
+    node_mask=None,
 ):
     return digraph_dijkstra_shortest_paths(
         graph,
@@ -569,22 +998,80 @@ def _digraph_dijkstra_shortest_path(
         weight_fn=weight_fn,
         default_weight=default_weight,
         as_undirected=as_undirected,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
     )
 
 
 @dijkstra_shortest_paths.register(PyGraph)
-def _graph_dijkstra_shortest_path(graph, source, target=None, weight_fn=None, default_weight=1.0):
+def _graph_dijkstra_shortest_path(
+    graph,
+    source,
+    target=None,
+    weight_fn=None,
+    default_weight=1.0,
+    edge_filter_fn=None,
+    node_mask=None,
+):
     return graph_dijkstra_shortest_paths(
         graph,
         source,
         target=target,
         weight_fn=weight_fn,
         default_weight=default_weight,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+    )
+
+
+@functools.singledispatch
+def dijkstra_shortest_path_edges(graph, source, target, weight_fn=None, default_weight=1.0):
+    """Find the shortest path between two nodes as a list of edge indices
+
+    This function uses Dijkstra's algorithm to find the shortest path from
+    ``source`` to ``target`` and returns the indices of the edges along that
+    path. Unlike :func:`~retworkx.dijkstra_shortest_paths` the output is
+    unambiguous for multigraphs; when there are parallel edges between two
+    nodes on the path the edge with the lowest weight is used.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find the path from
+    :param int target: The node index to find the path to
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight/cost of each edge.
+
+    :return: The edge indices of the shortest path in order from ``source``
+        to ``target``. If ``source`` and ``target`` are the same node the
+        list will be empty.
+    :rtype: EdgeIndices
+    :raises InvalidNode: when ``source`` or ``target`` are not in the graph
+    :raises NoPathFound: when there is no path from ``source`` to ``target``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dijkstra_shortest_path_edges.register(PyDiGraph)
+def _digraph_dijkstra_shortest_path_edges(
+    graph, source, target, weight_fn=None, default_weight=1.0
+):
+    return digraph_dijkstra_shortest_path_edges(
+        graph, source, target, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@dijkstra_shortest_path_edges.register(PyGraph)
+def _graph_dijkstra_shortest_path_edges(graph, source, target, weight_fn=None, default_weight=1.0):
+    return graph_dijkstra_shortest_path_edges(
+        graph, source, target, weight_fn=weight_fn, default_weight=default_weight
     )
 
 
 @functools.singledispatch
-def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
+def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn, edge_filter_fn=None, node_mask=None):
     """For each node in the graph, finds the shortest paths to all others.
 
     This function will generate the shortest path from all nodes in the graph
@@ -600,6 +1087,15 @@ def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
         weight/cost of the edge
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
 
     :return: A read-only dictionary of paths. The keys are source node
         indices and the values are a dict of target node indices and a list
@@ -617,17 +1113,25 @@ def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
 
 
 @all_pairs_dijkstra_shortest_paths.register(PyDiGraph)
-def _digraph_all_pairsdijkstra_shortest_path(graph, edge_cost_fn):
-    return digraph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+def _digraph_all_pairsdijkstra_shortest_path(
+    graph, edge_cost_fn, edge_filter_fn=None, node_mask=None
+):
+    return digraph_all_pairs_dijkstra_shortest_paths(
+        graph, edge_cost_fn, edge_filter_fn=edge_filter_fn, node_mask=node_mask
+    )
 
 
 @all_pairs_dijkstra_shortest_paths.register(PyGraph)
-def _graph_all_pairs_dijkstra_shortest_path(graph, edge_cost_fn):
-    return graph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+def _graph_all_pairs_dijkstra_shortest_path(
+    graph, edge_cost_fn, edge_filter_fn=None, node_mask=None
+):
+    return graph_all_pairs_dijkstra_shortest_paths(
+        graph, edge_cost_fn, edge_filter_fn=edge_filter_fn, node_mask=node_mask
+    )
 
 
 @functools.singledispatch
-def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
+def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn, edge_filter_fn=None, node_mask=None):
     """For each node in the graph, calculates the lengths of the shortest paths to all others.
 
     This function will generate the shortest path lengths from all nodes in the
@@ -643,6 +1147,15 @@ def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
         an edge. It will accept a single positional argument, the edge's weight
         object and will return a float which will be used to represent the
         weight/cost of the edge
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
 
     :return: A read-only dictionary of path lengths. The keys are the source
         node indices and the values are a dict of the target node and the
@@ -660,17 +1173,27 @@ def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
 
 
 @all_pairs_dijkstra_path_lengths.register(PyDiGraph)
-def _digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
-    return digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+def _digraph_all_pairs_dijkstra_path_lengths(
+    graph, edge_cost_fn, edge_filter_fn=None, node_mask=None
+):
+    return digraph_all_pairs_dijkstra_path_lengths(
+        graph, edge_cost_fn, edge_filter_fn=edge_filter_fn, node_mask=node_mask
+    )
 
 
 @all_pairs_dijkstra_path_lengths.register(PyGraph)
-def _graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
-    return graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+def _graph_all_pairs_dijkstra_path_lengths(
+    graph, edge_cost_fn, edge_filter_fn=None, node_mask=None
+):
+    return graph_all_pairs_dijkstra_path_lengths(
+        graph, edge_cost_fn, edge_filter_fn=edge_filter_fn, node_mask=node_mask
+    )
 
 
 @functools.singledispatch
-def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
+def dijkstra_shortest_path_lengths(
+    graph, node, edge_cost_fn, goal=None, workspace=None, edge_filter_fn=None, node_mask=None
+):
     """Compute the lengths of the shortest paths for a graph object using
     Dijkstra's algorithm.
 
@@ -685,6 +1208,19 @@ def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
         When specified the traversal will stop when the goal is reached and
         the output dictionary will only have a single entry with the length
         of the shortest path to the goal node.
+    :param Workspace workspace: An optional :class:`~retworkx.Workspace`
+        whose buffers are reused for the search instead of allocating new
+        ones. This avoids the allocations when the function is called many
+        times on the same graph.
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
 
     :returns: A dictionary of the shortest paths from the provided node where
         the key is the node index of the end of the path and the value is the
@@ -695,13 +1231,33 @@ def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
 
 
 @dijkstra_shortest_path_lengths.register(PyDiGraph)
-def _digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
-    return digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+def _digraph_dijkstra_shortest_path_lengths(
+    graph, node, edge_cost_fn, goal=None, workspace=None, edge_filter_fn=None, node_mask=None
+):
+    return digraph_dijkstra_shortest_path_lengths(
+        graph,
+        node,
+        edge_cost_fn,
+        goal=goal,
+        workspace=workspace,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+    )
 
 
 @dijkstra_shortest_path_lengths.register(PyGraph)
-def _graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
-    return graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+def _graph_dijkstra_shortest_path_lengths(
+    graph, node, edge_cost_fn, goal=None, workspace=None, edge_filter_fn=None, node_mask=None
+):
+    return graph_dijkstra_shortest_path_lengths(
+        graph,
+        node,
+        edge_cost_fn,
+        goal=goal,
+        workspace=workspace,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+    )
 
 
 @functools.singledispatch
@@ -739,6 +1295,50 @@ def _graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
     return graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=goal)
 
 
+@functools.singledispatch
+def bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn):
+    """Compute the lengths of the shortest paths for a graph object using
+    the Bellman-Ford algorithm
+
+    Unlike :func:`~retworkx.dijkstra_shortest_path_lengths` the edge costs
+    can be negative, as long as there is no cycle with a negative total cost
+    reachable from ``node``. For a :class:`~retworkx.PyGraph` every edge can
+    be traversed in both directions, so an edge with a negative cost
+    reachable from ``node`` is a negative cycle.
+
+    Computes in :math:`O(|V| * |E|)` time.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The node index to use as the source for finding the
+        shortest paths from
+    :param edge_cost_fn: A python callable that will take in 1 parameter, an
+        edge's data object and will return a float that represents the
+        cost/weight of that edge
+
+    :returns: A dictionary of the shortest paths from the provided node where
+        the key is the node index of the end of the path and the value is the
+        cost/sum of the weights of path
+    :rtype: PathLengthMapping
+    :raises ValueError: when an edge weight with NaN value is provided.
+    :raises InvalidNode: when ``node`` isn't a node in the graph
+    :raises NegativeCycleError: when a negative cycle is reachable from
+        ``node``, the ``cycle`` attribute of the exception is a list of the
+        node indices on the cycle in order
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@bellman_ford_shortest_path_lengths.register(PyDiGraph)
+def _digraph_bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn):
+    return digraph_bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn)
+
+
+@bellman_ford_shortest_path_lengths.register(PyGraph)
+def _graph_bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn):
+    return graph_bellman_ford_shortest_path_lengths(graph, node, edge_cost_fn)
+
+
 @functools.singledispatch
 def dfs_edges(graph, source=None):
     """Get an edge list of the tree edges from a depth-first traversal
@@ -1053,28 +1653,786 @@ def core_number(graph):
 
     .. note::
 
-        The function implicitly assumes that there are no parallel edges
-        or self loops. It may produce incorrect/unexpected results if the
-        input graph has self loops or parallel edges.
+        The function implicitly assumes that there are no parallel edges
+        or self loops. It may produce incorrect/unexpected results if the
+        input graph has self loops or parallel edges.
+
+    :param graph: The graph to get core numbers. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A dictionary keyed by node index to the core number
+    :rtype: dict
+        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@core_number.register(PyDiGraph)
+def _digraph_core_number(graph):
+    return digraph_core_number(graph)
+
+
+@core_number.register(PyGraph)
+def _graph_core_number(graph):
+    return graph_core_number(graph)
+
+
+@functools.singledispatch
+def truss_number(graph):
+    """Return the truss number for each edge in the graph.
+
+    A k-truss is a maximal subgraph in which every edge is part of at least
+    k - 2 triangles of the subgraph, and the truss number of an edge is the
+    largest k such that the edge is in the k-truss.
+
+    The direction of the edges of a :class:`~retworkx.PyDiGraph` is ignored.
+    Parallel edges are treated as a single edge and all get the same truss
+    number, self loops are part of no triangle and have a truss number of 2.
+
+    :param graph: The graph to get truss numbers. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A dictionary keyed by edge index to the truss number
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@truss_number.register(PyDiGraph)
+def _digraph_truss_number(graph):
+    return digraph_truss_number(graph)
+
+
+@truss_number.register(PyGraph)
+def _graph_truss_number(graph):
+    return graph_truss_number(graph)
+
+
+@functools.singledispatch
+def feedback_vertex_set(graph):
+    """Find a small feedback vertex set of a graph.
+
+    A feedback vertex set is a set of nodes whose removal, along with their
+    edges, leaves the graph without cycles. Finding a minimum feedback vertex
+    set is NP-hard, so this function uses a greedy heuristic followed by
+    removing every redundant node from the set. The set returned is minimal,
+    but not necessarily minimum. For a :class:`~retworkx.PyDiGraph` only
+    directed cycles are broken.
+
+    :param graph: The graph to find a feedback vertex set of. Can either be
+        a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A set of the indices of the nodes in the feedback vertex set
+    :rtype: set
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@feedback_vertex_set.register(PyDiGraph)
+def _digraph_feedback_vertex_set(graph):
+    return digraph_feedback_vertex_set(graph)
+
+
+@feedback_vertex_set.register(PyGraph)
+def _graph_feedback_vertex_set(graph):
+    return graph_feedback_vertex_set(graph)
+
+
+@functools.singledispatch
+def simrank_similarity(
+    graph, pairs=None, importance_factor=0.9, max_iterations=1000, tolerance=1e-4
+):
+    """Compute the SimRank similarity of the nodes of a graph.
+
+    SimRank considers two nodes similar if their neighbors are similar, for
+    a :class:`~retworkx.PyDiGraph` the predecessors of the nodes are used.
+    If ``pairs`` isn't set the full similarity matrix is computed, for large
+    graphs ``pairs`` can be set to only compute the similarity of those
+    pairs.
+
+    :param graph: The graph to compute the similarities of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list pairs: An optional list of node index pairs to compute the
+        similarity of
+    :param float importance_factor: The factor the similarity of the
+        neighbors is scaled by, between 0 and 1. By default this is ``0.9``.
+    :param int max_iterations: The maximum number of iterations. By default
+        this is ``1000``.
+    :param float tolerance: The tolerance for convergence. By default this
+        is ``1e-4``.
+
+    :returns: If ``pairs`` isn't set a numpy array of the similarity of every
+        pair of nodes, with the rows and columns in node index order,
+        otherwise a numpy array of the similarity of each pair in ``pairs``
+    :rtype: numpy.ndarray
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@simrank_similarity.register(PyDiGraph)
+def _digraph_simrank_similarity(
+    graph, pairs=None, importance_factor=0.9, max_iterations=1000, tolerance=1e-4
+):
+    return digraph_simrank_similarity(
+        graph,
+        pairs=pairs,
+        importance_factor=importance_factor,
+        max_iterations=max_iterations,
+        tolerance=tolerance,
+    )
+
+
+@simrank_similarity.register(PyGraph)
+def _graph_simrank_similarity(
+    graph, pairs=None, importance_factor=0.9, max_iterations=1000, tolerance=1e-4
+):
+    return graph_simrank_similarity(
+        graph,
+        pairs=pairs,
+        importance_factor=importance_factor,
+        max_iterations=max_iterations,
+        tolerance=tolerance,
+    )
+
+
+@functools.singledispatch
+def robustness_curve(graph, order=None, remove_edges=False, seed=None):
+    """Compute the robustness curve of a graph
+
+    The nodes, or edges, of the graph are removed one at a time and the size
+    of the largest (weakly) connected component is recorded after every
+    removal. The order the nodes or edges are removed in can either be given
+    explicitly or be one of the following strategies:
+
+    * ``"degree"``: nodes in order of decreasing degree, or edges in order of
+      decreasing product of the degrees of their endpoints
+    * ``"betweenness"``: nodes or edges in order of decreasing betweenness
+      centrality
+    * ``"random"``: a uniformly random order, which simulates random failures
+      or site and bond percolation
+
+    The degrees and centralities are those of the original graph and ties are
+    broken by index.
+
+    :param graph: The graph to remove nodes or edges from, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param order: Either the name of a strategy, ``"degree"``,
+        ``"betweenness"`` or ``"random"``, or a sequence of the indices of the
+        nodes, or edges, to remove in order. A sequence doesn't need to contain
+        every node or edge. By default this is ``"degree"``.
+    :param bool remove_edges: If set to ``True`` edges are removed instead of
+        nodes.
+    :param int seed: An optional seed to use for the random number generator
+        of the ``"random"`` strategy
+
+    :returns: A tuple of the indices of the nodes, or edges, in the order they
+        are removed and a list of the sizes of the largest connected
+        component, as a number of nodes, before any removal and after each
+        removal. The size is ``0`` when there are no nodes left.
+    :rtype: tuple
+    :raises ValueError: If ``order`` is an invalid strategy or contains an
+        index more than once
+    :raises InvalidNode: If ``order`` contains an invalid node index
+    :raises IndexError: If ``order`` contains an invalid edge index
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@robustness_curve.register(PyDiGraph)
+def _digraph_robustness_curve(graph, order=None, remove_edges=False, seed=None):
+    return digraph_robustness_curve(graph, order=order, remove_edges=remove_edges, seed=seed)
+
+
+@robustness_curve.register(PyGraph)
+def _graph_robustness_curve(graph, order=None, remove_edges=False, seed=None):
+    return graph_robustness_curve(graph, order=order, remove_edges=remove_edges, seed=seed)
+
+
+@functools.singledispatch
+def sample_nodes(graph, k, weight_fn=None, replace=False, seed=None):
+    """Randomly sample nodes of a graph
+
+    The nodes are drawn with probabilities proportional to their weight,
+    or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+    is independent and takes constant time using an alias table built in
+    linear time. Without replacement a node is drawn at most once, which is
+    equivalent to ``k`` successive weighted draws each excluding the nodes
+    drawn before. Nodes with a weight of ``0`` are never drawn.
+
+    :param graph: The graph to sample nodes from, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int k: The number of nodes to draw
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of a node, and returns its
+        sampling weight as a non-negative ``float``. If not specified every
+        node has a weight of ``1.0``.
+    :param bool replace: If set to ``True`` nodes are drawn with
+        replacement, so a node can be drawn more than once
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: The indices of the drawn nodes, in the order they were drawn
+    :rtype: NodeIndices
+    :raises ValueError: If a weight is negative, NaN or infinite, or if there
+        aren't enough nodes with a positive weight to draw ``k`` nodes
+        from
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@sample_nodes.register(PyDiGraph)
+def _digraph_sample_nodes(graph, k, weight_fn=None, replace=False, seed=None):
+    return digraph_sample_nodes(graph, k, weight_fn=weight_fn, replace=replace, seed=seed)
+
+
+@sample_nodes.register(PyGraph)
+def _graph_sample_nodes(graph, k, weight_fn=None, replace=False, seed=None):
+    return graph_sample_nodes(graph, k, weight_fn=weight_fn, replace=replace, seed=seed)
+
+
+@functools.singledispatch
+def sample_edges(graph, k, weight_fn=None, replace=False, seed=None):
+    """Randomly sample edges of a graph
+
+    The edges are drawn with probabilities proportional to their weight,
+    or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+    is independent and takes constant time using an alias table built in
+    linear time. Without replacement a edge is drawn at most once, which is
+    equivalent to ``k`` successive weighted draws each excluding the edges
+    drawn before. Edges with a weight of ``0`` are never drawn.
+
+    :param graph: The graph to sample edges from, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int k: The number of edges to draw
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of a edge, and returns its
+        sampling weight as a non-negative ``float``. If not specified every
+        edge has a weight of ``1.0``.
+    :param bool replace: If set to ``True`` edges are drawn with
+        replacement, so a edge can be drawn more than once
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: The indices of the drawn edges, in the order they were drawn
+    :rtype: EdgeIndices
+    :raises ValueError: If a weight is negative, NaN or infinite, or if there
+        aren't enough edges with a positive weight to draw ``k`` edges
+        from
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@sample_edges.register(PyDiGraph)
+def _digraph_sample_edges(graph, k, weight_fn=None, replace=False, seed=None):
+    return digraph_sample_edges(graph, k, weight_fn=weight_fn, replace=replace, seed=seed)
+
+
+@sample_edges.register(PyGraph)
+def _graph_sample_edges(graph, k, weight_fn=None, replace=False, seed=None):
+    return graph_sample_edges(graph, k, weight_fn=weight_fn, replace=replace, seed=seed)
+
+
+@functools.singledispatch
+def sample_subgraph(
+    graph,
+    num_nodes,
+    method="snowball",
+    burn_probability=0.7,
+    restart_probability=0.15,
+    seed=None,
+):
+    """Sample a subgraph of a graph
+
+    The nodes are picked by exploring the graph from a random start node
+    with one of the following methods, until ``num_nodes`` nodes are
+    visited:
+
+    * ``"snowball"``: a breadth first search, visiting the neighbors of each
+      node in a random order
+    * ``"forest_fire"``: the forest fire model, each visited node "burns" a
+      geometrically distributed number of its unvisited neighbors, with a
+      mean of ``burn_probability / (1 - burn_probability)``, which are
+      visited next
+    * ``"random_walk"``: a random walk which moves to a random neighbor, or
+      jumps back to the start node with probability ``restart_probability``
+
+    Whenever the exploration can't continue, because the fire died out, the
+    connected component is exhausted, or the random walk didn't find a new
+    node in as many steps as there are nodes in the graph, it's restarted
+    from a new random node. The edge directions of a
+    :class:`~retworkx.PyDiGraph` are ignored when exploring the graph but
+    they're kept in the subgraph. The sampled subgraph is the subgraph
+    induced by the visited nodes, with its nodes in the same relative order
+    as in ``graph``.
+
+    :param graph: The graph to sample a subgraph of, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int num_nodes: The number of nodes in the sampled subgraph
+    :param str method: The exploration method, ``"snowball"``,
+        ``"forest_fire"`` or ``"random_walk"``. By default this is
+        ``"snowball"``.
+    :param float burn_probability: The burn probability of the
+        ``"forest_fire"`` method, by default ``0.7``
+    :param float restart_probability: The probability the ``"random_walk"``
+        method jumps back to its start node at each step, by default ``0.15``
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A tuple of the sampled subgraph, of the same type as ``graph``,
+        and a :class:`~retworkx.NodeMap` mapping the index of each sampled
+        node in ``graph`` to its index in the subgraph
+    :rtype: tuple
+    :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+        in ``graph``, ``method`` is invalid, or the probability of the method
+        isn't at least ``0`` and less than ``1``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@sample_subgraph.register(PyDiGraph)
+def _digraph_sample_subgraph(
+    graph,
+    num_nodes,
+    method="snowball",
+    burn_probability=0.7,
+    restart_probability=0.15,
+    seed=None,
+):
+    return digraph_sample_subgraph(
+        graph,
+        num_nodes,
+        method=method,
+        burn_probability=burn_probability,
+        restart_probability=restart_probability,
+        seed=seed,
+    )
+
+
+@sample_subgraph.register(PyGraph)
+def _graph_sample_subgraph(
+    graph,
+    num_nodes,
+    method="snowball",
+    burn_probability=0.7,
+    restart_probability=0.15,
+    seed=None,
+):
+    return graph_sample_subgraph(
+        graph,
+        num_nodes,
+        method=method,
+        burn_probability=burn_probability,
+        restart_probability=restart_probability,
+        seed=seed,
+    )
+
+
+@functools.singledispatch
+def epidemic_simulation(
+    graph,
+    initial_infected,
+    model="SIR",
+    transmission_fn=None,
+    default_transmission=0.1,
+    recovery_rate=0.1,
+    num_trials=1,
+    max_steps=100,
+    seed=None,
+):
+    """Simulate the spread of an epidemic on a graph
+
+    Every node of the graph is either susceptible, infected or recovered, and
+    the simulation starts with the ``initial_infected`` nodes infected and
+    every other node susceptible. At every discrete time step, every infected
+    node infects each of its susceptible neighbors, or successors for a
+    :class:`~retworkx.PyDiGraph`, independently with the transmission
+    probability of the edge between them, and then recovers with probability
+    ``recovery_rate``. Nodes infected during a step can only spread the
+    infection from the next step on. With the ``"SIR"`` model a recovered
+    node is immune, while with the ``"SIS"`` model it becomes susceptible
+    again. Parallel edges give independent chances of infection.
+
+    The trials are independent and run in parallel, they stop when there are
+    no infected nodes left or after ``max_steps`` steps.
+
+    :param graph: The graph to simulate the epidemic on, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list initial_infected: The indices of the nodes which are infected
+        at the start of the simulation
+    :param str model: Either ``"SIR"`` or ``"SIS"``, whether recovered nodes
+        are immune or susceptible again. By default this is ``"SIR"``.
+    :param transmission_fn: An optional callable object (function, lambda,
+        etc) which will be passed the edge object and expected to return a
+        ``float`` probability of transmission along the edge at every step. If
+        it's not specified ``default_transmission`` is used for all edges.
+    :param float default_transmission: The probability of transmission along
+        every edge if ``transmission_fn`` isn't specified. By default this is
+        ``0.1``.
+    :param float recovery_rate: The probability that an infected node
+        recovers at every step. By default this is ``0.1``.
+    :param int num_trials: The number of independent trials to run. By
+        default this is ``1``.
+    :param int max_steps: The maximum number of steps of every trial. By
+        default this is ``100``.
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A tuple of two numpy arrays. The first has shape
+        ``(num_trials, max_steps + 1, 3)`` and contains the number of
+        susceptible, infected and recovered nodes at the start of every trial
+        and after every step, repeating the final counts once a trial ends.
+        The second has shape ``(num_trials, num_nodes)`` and contains the
+        final state of every node, ``0`` for susceptible, ``1`` for infected
+        and ``2`` for recovered, with the nodes in the order of
+        ``graph.node_indices()``.
+    :rtype: tuple
+    :raises InvalidNode: If ``initial_infected`` contains an invalid node
+        index
+    :raises ValueError: If ``model`` is invalid or a probability isn't between
+        0 and 1
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@epidemic_simulation.register(PyDiGraph)
+def _digraph_epidemic_simulation(
+    graph,
+    initial_infected,
+    model="SIR",
+    transmission_fn=None,
+    default_transmission=0.1,
+    recovery_rate=0.1,
+    num_trials=1,
+    max_steps=100,
+    seed=None,
+):
+    return digraph_epidemic_simulation(
+        graph,
+        initial_infected,
+        model=model,
+        transmission_fn=transmission_fn,
+        default_transmission=default_transmission,
+        recovery_rate=recovery_rate,
+        num_trials=num_trials,
+        max_steps=max_steps,
+        seed=seed,
+    )
+
+
+@epidemic_simulation.register(PyGraph)
+def _graph_epidemic_simulation(
+    graph,
+    initial_infected,
+    model="SIR",
+    transmission_fn=None,
+    default_transmission=0.1,
+    recovery_rate=0.1,
+    num_trials=1,
+    max_steps=100,
+    seed=None,
+):
+    return graph_epidemic_simulation(
+        graph,
+        initial_infected,
+        model=model,
+        transmission_fn=transmission_fn,
+        default_transmission=default_transmission,
+        recovery_rate=recovery_rate,
+        num_trials=num_trials,
+        max_steps=max_steps,
+        seed=seed,
+    )
+
+
+@functools.singledispatch
+def temporal_descendants(graph, source, time_fn=None, start_time=None, strict=False):
+    """Find the nodes reachable from a node by a time-respecting path
+
+    Every edge of the graph is an instantaneous contact at a given time. A
+    time-respecting path is a path whose edge times never decrease, or always
+    increase if ``strict`` is ``True``, and whose first edge isn't before
+    ``start_time``. Edges of a :class:`~retworkx.PyGraph` can be traversed in
+    either direction.
+
+    :param graph: The graph to search, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find the descendants of
+    :param time_fn: An optional callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return the ``float``
+        time of the edge. If it's not specified the edge objects are used as
+        the times.
+    :param float start_time: The earliest time a path can start at. By
+        default paths can start at any time.
+    :param bool strict: If set to ``True`` the times of consecutive edges on
+        a path must be strictly increasing.
+
+    :returns: A set of the indices of the nodes reachable from ``source`` by a
+        time-respecting path, not including ``source``
+    :rtype: set
+    :raises InvalidNode: If ``source`` is not in the graph
+    :raises ValueError: If an edge time is NaN
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@temporal_descendants.register(PyDiGraph)
+def _digraph_temporal_descendants(graph, source, time_fn=None, start_time=None, strict=False):
+    return digraph_temporal_descendants(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@temporal_descendants.register(PyGraph)
+def _graph_temporal_descendants(graph, source, time_fn=None, start_time=None, strict=False):
+    return graph_temporal_descendants(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@functools.singledispatch
+def earliest_arrival_paths(graph, source, time_fn=None, start_time=None, strict=False):
+    """Find the earliest arrival paths from a node
+
+    Every edge of the graph is an instantaneous contact at a given time. A
+    time-respecting path is a path whose edge times never decrease, or always
+    increase if ``strict`` is ``True``, and whose first edge isn't before
+    ``start_time``. Edges of a :class:`~retworkx.PyGraph` can be traversed in
+    either direction.
+
+    The path returned for every node is a time-respecting path reaching it at
+    the earliest possible time, which is found with a single pass over the
+    edges in time order in :math:`O(|E| \\log |E|)` time.
+
+    :param graph: The graph to search, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find the paths from
+    :param time_fn: An optional callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return the ``float``
+        time of the edge. If it's not specified the edge objects are used as
+        the times.
+    :param float start_time: The earliest time a path can start at. By
+        default paths can start at any time.
+    :param bool strict: If set to ``True`` the times of consecutive edges on
+        a path must be strictly increasing.
+
+    :returns: A mapping of the index of every node reachable from ``source``
+        by a time-respecting path to a list of the node indices of an earliest
+        arrival path from ``source`` to it
+    :rtype: PathMapping
+    :raises InvalidNode: If ``source`` is not in the graph
+    :raises ValueError: If an edge time is NaN
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@earliest_arrival_paths.register(PyDiGraph)
+def _digraph_earliest_arrival_paths(graph, source, time_fn=None, start_time=None, strict=False):
+    return digraph_earliest_arrival_paths(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@earliest_arrival_paths.register(PyGraph)
+def _graph_earliest_arrival_paths(graph, source, time_fn=None, start_time=None, strict=False):
+    return graph_earliest_arrival_paths(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@functools.singledispatch
+def earliest_arrival_times(graph, source, time_fn=None, start_time=None, strict=False):
+    """Find the earliest arrival times from a node
+
+    Every edge of the graph is an instantaneous contact at a given time. A
+    time-respecting path is a path whose edge times never decrease, or always
+    increase if ``strict`` is ``True``, and whose first edge isn't before
+    ``start_time``. Edges of a :class:`~retworkx.PyGraph` can be traversed in
+    either direction.
+
+    The earliest arrival time of a node is the smallest time of the last edge
+    of a time-respecting path to it.
+
+    :param graph: The graph to search, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find the arrival times from
+    :param time_fn: An optional callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return the ``float``
+        time of the edge. If it's not specified the edge objects are used as
+        the times.
+    :param float start_time: The earliest time a path can start at. By
+        default paths can start at any time.
+    :param bool strict: If set to ``True`` the times of consecutive edges on
+        a path must be strictly increasing.
+
+    :returns: A mapping of the index of every node reachable from ``source``
+        by a time-respecting path to its earliest arrival time
+    :rtype: PathLengthMapping
+    :raises InvalidNode: If ``source`` is not in the graph
+    :raises ValueError: If an edge time is NaN
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@earliest_arrival_times.register(PyDiGraph)
+def _digraph_earliest_arrival_times(graph, source, time_fn=None, start_time=None, strict=False):
+    return digraph_earliest_arrival_times(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@earliest_arrival_times.register(PyGraph)
+def _graph_earliest_arrival_times(graph, source, time_fn=None, start_time=None, strict=False):
+    return graph_earliest_arrival_times(
+        graph, source, time_fn=time_fn, start_time=start_time, strict=strict
+    )
+
+
+@functools.singledispatch
+def temporal_connected_components(graph, time_fn=None, strict=False):
+    """Find the temporal connected components of a graph
+
+    Every edge of the graph is an instantaneous contact at a given time. A
+    time-respecting path is a path whose edge times never decrease, or always
+    increase if ``strict`` is ``True``. Edges of a :class:`~retworkx.PyGraph`
+    can be traversed in either direction. Reachability by time-respecting
+    paths isn't transitive, and finding the largest sets of nodes which can
+    all reach each other is NP-hard, so two nodes are in the same temporal
+    connected component if they are linked by a chain of pairs of nodes which
+    can reach each other by time-respecting paths.
+
+    :param graph: The graph to find the components of, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param time_fn: An optional callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return the ``float``
+        time of the edge. If it's not specified the edge objects are used as
+        the times.
+    :param bool strict: If set to ``True`` the times of consecutive edges on
+        a path must be strictly increasing.
+
+    :returns: A list of sets where each set is a temporal connected component
+        of the graph
+    :rtype: list
+    :raises ValueError: If an edge time is NaN
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@temporal_connected_components.register(PyDiGraph)
+def _digraph_temporal_connected_components(graph, time_fn=None, strict=False):
+    return digraph_temporal_connected_components(graph, time_fn=time_fn, strict=strict)
+
+
+@temporal_connected_components.register(PyGraph)
+def _graph_temporal_connected_components(graph, time_fn=None, strict=False):
+    return graph_temporal_connected_components(graph, time_fn=time_fn, strict=strict)
+
+
+@functools.singledispatch
+def coarsen(graph, weight_fn=None, default_weight=1.0, min_nodes=1, max_levels=None, seed=None):
+    """Build a hierarchy of progressively coarser graphs from a graph
+
+    Each level of the hierarchy is built by finding a heavy edge matching of
+    the previous level, visiting its nodes in a random order and matching each
+    node with the unmatched neighbor it shares the heaviest edges with, and
+    then contracting each matched pair of nodes into a single node. The edge
+    directions of a :class:`~retworkx.PyDiGraph` are ignored when finding the
+    matching but they're kept in the coarse graphs. This is the coarsening
+    phase of multilevel graph partitioning and layout algorithms, which solve
+    a problem on the coarsest graph and then project the solution back
+    through the levels.
+
+    The nodes of each coarse graph have the number of nodes of the original
+    graph they contain as their weight/data payload, and its edges have the
+    total weight of the edges they replace as their weight/data payload. Edges
+    within a contracted node are dropped. Coarsening stops when the coarsest
+    graph has at most ``min_nodes`` nodes, after ``max_levels`` levels, or
+    when a level shrinks the graph by less than 10%, which happens when the
+    matching can't make progress, such as on a star graph.
+
+    :param graph: The graph to coarsen, can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional weight function for the edges. It will be
+        passed the weight/data payload of an edge and is expected to return a
+        ``float``. If not specified ``default_weight`` is used for every edge.
+    :param float default_weight: The weight of each edge if ``weight_fn``
+        isn't specified. By default this is ``1.0``.
+    :param int min_nodes: Stop coarsening once the coarsest graph has at most
+        this many nodes. By default this is ``1``.
+    :param int max_levels: The maximum number of levels to build. By default
+        there's no limit.
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A list of the levels of the hierarchy, from the finest to the
+        coarsest, as tuples of the coarse graph, of the same type as
+        ``graph``, and a :class:`~retworkx.NodeMap` mapping each node index of
+        the previous level (``graph`` for the first level) to the node it's
+        contracted into. The list is empty if ``graph`` can't be coarsened.
+    :rtype: list
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@coarsen.register(PyDiGraph)
+def _digraph_coarsen(
+    graph, weight_fn=None, default_weight=1.0, min_nodes=1, max_levels=None, seed=None
+):
+    return digraph_coarsen(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        min_nodes=min_nodes,
+        max_levels=max_levels,
+        seed=seed,
+    )
+
+
+@coarsen.register(PyGraph)
+def _graph_coarsen(
+    graph, weight_fn=None, default_weight=1.0, min_nodes=1, max_levels=None, seed=None
+):
+    return graph_coarsen(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        min_nodes=min_nodes,
+        max_levels=max_levels,
+        seed=seed,
+    )
+
 
-    :param graph: The graph to get core numbers. Can either be a
+@functools.singledispatch
+def double_edge_swap(graph, nswap=1, max_tries=None, seed=None):
+    """Randomize a graph in place while keeping the degree of every node
+
+    Each double edge swap replaces two edges ``(a, b)`` and ``(c, d)``, chosen
+    uniformly at random, with ``(a, d)`` and ``(c, b)``, so every node keeps
+    its degree (its in degree and out degree for a
+    :class:`~retworkx.PyDiGraph`) while the rest of the structure of the graph
+    is randomized. This is commonly used to generate null models for measures
+    like assortativity or clustering. Swaps that would create self loops or
+    parallel edges are rejected, as are swaps that would create a cycle in a
+    :class:`~retworkx.PyDiGraph` with cycle checking enabled. The edges keep
+    their indices and weight/data payloads, so the edge ``(a, b)`` becomes
+    the edge ``(a, d)``.
+
+    :param graph: The graph to randomize, can either be a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int nswap: The number of double edge swaps to make
+    :param int max_tries: The maximum number of swaps to attempt. By default
+        this is ``100 * nswap``.
+    :param int seed: An optional seed to use for the random number generator
 
-    :returns: A dictionary keyed by node index to the core number
-    :rtype: dict
-        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    :returns: The number of swaps made, which is less than ``nswap`` if
+        ``max_tries`` was reached first
+    :rtype: int
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@core_number.register(PyDiGraph)
-def _digraph_core_number(graph):
-    return digraph_core_number(graph)
+@double_edge_swap.register(PyDiGraph)
+def _digraph_double_edge_swap(graph, nswap=1, max_tries=None, seed=None):
+    return digraph_double_edge_swap(graph, nswap=nswap, max_tries=max_tries, seed=seed)
 
 
-@core_number.register(PyGraph)
-def _graph_core_number(graph):
-    return graph_core_number(graph)
+@double_edge_swap.register(PyGraph)
+def _graph_double_edge_swap(graph, nswap=1, max_tries=None, seed=None):
+    return graph_double_edge_swap(graph, nswap=nswap, max_tries=max_tries, seed=seed)
 
 
 @functools.singledispatch
@@ -1104,6 +2462,42 @@ def _graph_complement(graph):
     return graph_complement(graph)
 
 
+@functools.singledispatch
+def power(graph, k):
+    """Compute the k-th power of a graph.
+
+    The k-th power of a graph has the same nodes as the graph and an edge
+    from every node to each other node within ``k`` edges of it in the
+    graph. For a :class:`~retworkx.PyDiGraph` the edge directions are
+    followed.
+
+    :param graph: The graph to be used, can be either a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int k: The maximum distance between two nodes joined by an edge,
+        it must be at least 1.
+
+    :returns: The k-th power of the graph, with the same node indices and
+        node weight/data payloads as ``graph``.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :raises ValueError: If ``k`` is 0
+
+    .. note::
+        Parallel edges and self-loops are never created,
+        even if the ``multigraph`` is set to ``True``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@power.register(PyDiGraph)
+def _digraph_power(graph, k):
+    return digraph_power(graph, k)
+
+
+@power.register(PyGraph)
+def _graph_power(graph, k):
+    return graph_power(graph, k)
+
+
 @functools.singledispatch
 def random_layout(graph, center=None, seed=None):
     """Generate a random layout
@@ -1495,7 +2889,16 @@ def _graph_num_shortest_paths_unweighted(graph, source):
 
 
 @functools.singledispatch
-def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+def betweenness_centrality(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
     r"""Returns the betweenness centrality of each node in the graph.
 
     Betweenness centrality of a node :math:`v` is the sum of the
@@ -1530,6 +2933,24 @@ def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_thr
         the betweenness centrality in parallel at if the number of nodes in
         the graph is less than this value it will run in a single thread. The
         default value is 50
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of an edge, and returns its weight
+        as a non-negative float. If set the shortest paths are the paths with
+        the lowest total weight, which are counted with Dijkstra's algorithm,
+        and paths are only equally short if their total weights are exactly
+        equal. If not specified every edge has the weight ``default_weight``.
+    :param float default_weight: The weight of every edge if ``weight_fn``
+        isn't specified. Any positive value gives the same result as counting
+        the unweighted shortest paths. Default: ``1.0``
 
     :returns: A dictionary mapping each node index to its betweenness centrality.
     :rtype: dict
@@ -1538,22 +2959,290 @@ def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_thr
 
 
 @betweenness_centrality.register(PyDiGraph)
-def _digraph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+def _digraph_betweenness_centrality(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
     return digraph_betweenness_centrality(
         graph,
         normalized=normalized,
         endpoints=endpoints,
         parallel_threshold=parallel_threshold,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
     )
 
 
 @betweenness_centrality.register(PyGraph)
-def _graph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+def _graph_betweenness_centrality(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
     return graph_betweenness_centrality(
         graph,
         normalized=normalized,
         endpoints=endpoints,
         parallel_threshold=parallel_threshold,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+    )
+
+
+@functools.singledispatch
+def betweenness_centrality_array(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
+    """Returns the betweenness centrality of each node in the graph as a
+    numpy array.
+
+    This is the same as :func:`~retworkx.betweenness_centrality` but the
+    betweenness scores are returned in a dense numpy array, which avoids
+    building a :class:`~retworkx.CentralityMapping` for large graphs.
+
+    This function is multithreaded and will run in parallel if the number
+    of nodes in the graph is above the value of ``parallel_threshold`` (it
+    defaults to 50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+
+    :param graph: The input graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param bool normalized: Whether to normalize the betweenness scores by
+        the number of distinct paths between all pairs of nodes.
+    :param bool endpoints: Whether to include the endpoints of paths in
+        path lengths used to compute the betweenness.
+    :param int parallel_threshold: The number of nodes to calculate the
+        the betweenness centrality in parallel at if the number of nodes in
+        the graph is less than this value it will run in a single thread. The
+        default value is 50
+    :param edge_filter_fn: An optional callable which takes a single
+        positional argument, the weight/data payload of an edge, and returns
+        ``True`` if the edge should be used. Edges for which it returns
+        ``False`` are ignored as if they weren't in the graph. If not
+        specified every edge is used.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph. If not specified every node is used.
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of an edge, and returns its weight
+        as a non-negative float. If set the shortest paths are the paths with
+        the lowest total weight, which are counted with Dijkstra's algorithm,
+        and paths are only equally short if their total weights are exactly
+        equal. If not specified every edge has the weight ``default_weight``.
+    :param float default_weight: The weight of every edge if ``weight_fn``
+        isn't specified. Any positive value gives the same result as counting
+        the unweighted shortest paths. Default: ``1.0``
+
+    :returns: A 1D numpy array of ``float64`` where the value at position
+        ``i`` is the betweenness score of the node with index ``i``. If any
+        nodes have been removed from the graph the positions of their indices
+        are ``nan``.
+    :rtype: numpy.ndarray
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@betweenness_centrality_array.register(PyDiGraph)
+def _digraph_betweenness_centrality_array(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
+    return digraph_betweenness_centrality_array(
+        graph,
+        normalized=normalized,
+        endpoints=endpoints,
+        parallel_threshold=parallel_threshold,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+    )
+
+
+@betweenness_centrality_array.register(PyGraph)
+def _graph_betweenness_centrality_array(
+    graph,
+    normalized=True,
+    endpoints=False,
+    parallel_threshold=50,
+    edge_filter_fn=None,
+    node_mask=None,
+    weight_fn=None,
+    default_weight=1.0,
+):
+    return graph_betweenness_centrality_array(
+        graph,
+        normalized=normalized,
+        endpoints=endpoints,
+        parallel_threshold=parallel_threshold,
+        edge_filter_fn=edge_filter_fn,
+        node_mask=node_mask,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+    )
+
+
+@functools.singledispatch
+def closeness_centrality(
+    graph, wf_improved=True, weight_fn=None, default_weight=1.0, parallel_threshold=50
+):
+    r"""Returns the closeness centrality of each node in the graph.
+
+    The closeness centrality of a node :math:`u` is the reciprocal of the
+    average shortest path distance to :math:`u` over the other nodes
+
+    .. math::
+
+       C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+
+    where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+    :math:`u` and :math:`n` is the number of nodes that can reach :math:`u`.
+    For a :class:`~retworkx.PyDiGraph` the distances are of the paths ending
+    at :math:`u`. If not every node can reach :math:`u`, by default the
+    improved formula of Wasserman and Faust is used to scale the closeness
+    by the fraction of the other nodes which can reach it
+
+    .. math::
+
+       C_{WF}(u) = \frac{n - 1}{N - 1} \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+
+    where :math:`N` is the number of nodes in the graph.
+
+    This function is multithreaded and will run in parallel if the number
+    of nodes in the graph is above the value of ``parallel_threshold`` (it
+    defaults to 50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+
+    :param graph: The input graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param bool wf_improved: Whether to use the improved formula of
+        Wasserman and Faust for nodes which not every node can reach.
+        Default: ``True``
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of an edge, and returns its weight
+        as a non-negative float, the distance of a path is then the total
+        weight of its edges. If not specified every edge has the weight
+        ``default_weight``.
+    :param float default_weight: The weight of every edge if ``weight_fn``
+        isn't specified. Default: ``1.0``
+    :param int parallel_threshold: The number of nodes to calculate the
+        the closeness centrality in parallel at if the number of nodes in
+        the graph is less than this value it will run in a single thread. The
+        default value is 50
+
+    :returns: a read-only dict-like object whose keys are the node indices
+        and values are the closeness centrality of each node.
+    :rtype: CentralityMapping
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@closeness_centrality.register(PyDiGraph)
+def _digraph_closeness_centrality(
+    graph, wf_improved=True, weight_fn=None, default_weight=1.0, parallel_threshold=50
+):
+    return digraph_closeness_centrality(
+        graph,
+        wf_improved=wf_improved,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@closeness_centrality.register(PyGraph)
+def _graph_closeness_centrality(
+    graph, wf_improved=True, weight_fn=None, default_weight=1.0, parallel_threshold=50
+):
+    return graph_closeness_centrality(
+        graph,
+        wf_improved=wf_improved,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def eigenvector_centrality(graph, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6):
+    """Returns the eigenvector centrality of each node in the graph.
+
+    The eigenvector centrality of a node is proportional to the sum of the
+    centralities of its neighbors, or of its predecessors for a
+    :class:`~retworkx.PyDiGraph`, so a node is central if it's connected to
+    other central nodes. The centralities are the entries of the eigenvector
+    of the weighted adjacency matrix (transposed, for a
+    :class:`~retworkx.PyDiGraph`) for its largest eigenvalue, found by power
+    iteration and normalized to a unit Euclidean norm.
+
+    :param graph: The input graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param weight_fn: An optional callable which takes a single positional
+        argument, the weight/data payload of an edge, and returns its weight
+        as a non-negative float. If not specified every edge has the weight
+        ``default_weight``.
+    :param float default_weight: The weight of every edge if ``weight_fn``
+        isn't specified. Default: ``1.0``
+    :param int max_iter: The maximum number of iterations of the power
+        method, by default ``100``
+    :param float tol: The error tolerance used to check for convergence,
+        the iteration stops when the sum of the absolute changes of all the
+        centralities is less than the number of nodes times ``tol``. By
+        default ``1e-6``
+
+    :returns: a read-only dict-like object whose keys are the node indices
+        and values are the eigenvector centrality of each node.
+    :rtype: CentralityMapping
+    :raises ConvergenceError: If the power iteration doesn't converge in
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@eigenvector_centrality.register(PyDiGraph)
+def _digraph_eigenvector_centrality(
+    graph, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6
+):
+    return digraph_eigenvector_centrality(
+        graph, weight_fn=weight_fn, default_weight=default_weight, max_iter=max_iter, tol=tol
+    )
+
+
+@eigenvector_centrality.register(PyGraph)
+def _graph_eigenvector_centrality(
+    graph, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6
+):
+    return graph_eigenvector_centrality(
+        graph, weight_fn=weight_fn, default_weight=default_weight, max_iter=max_iter, tol=tol
     )
 
 
@@ -1661,6 +3350,139 @@ def _graph_vf2_mapping(
     )
 
 
+def isomorphism_classes(
+    graphs,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=True,
+    call_limit=None,
+):
+    """Group a list of graphs into isomorphism classes
+
+    This compares all the graphs in ``graphs`` and groups the ones which are
+    isomorphic, both structurally and comparing the node and edge data with
+    the optional matcher functions as in :func:`~retworkx.is_isomorphic`.
+    To avoid comparing every pair of graphs the graphs are first grouped by
+    an isomorphism invariant (the sorted Weisfeiler-Lehman subtree hashes of
+    their nodes, see :func:`~retworkx.weisfeiler_lehman_subgraph_hashes`)
+    and only graphs with the same invariant are compared with the VF2
+    algorithm. Each graph is compared to one graph of each candidate class
+    and these comparisons are run in parallel. You can tune the number of
+    threads with the ``RAYON_NUM_THREADS`` environment variable.
+
+    .. jupyter-execute::
+
+      import retworkx
+
+      graphs = [
+          retworkx.generators.path_graph(4),
+          retworkx.generators.star_graph(4),
+          retworkx.generators.cycle_graph(4),
+          retworkx.generators.path_graph(4),
+      ]
+      print(retworkx.isomorphism_classes(graphs))
+
+    :param list graphs: The list of graphs to group, either all
+        :class:`~retworkx.PyGraph` or all :class:`~retworkx.PyDiGraph` objects
+    :param callable node_matcher: An optional python callable object that
+        takes 2 positional arguments, one for each node data object. If the
+        return of this function evaluates to True then the nodes passed to it
+        are viewed as matching.
+    :param callable edge_matcher: An optional python callable object that
+        takes 2 positional arguments, one for each edge data object. If the
+        return of this function evaluates to True then the edges passed to it
+        are viewed as matching.
+    :param bool id_order: If set to ``False`` this function will use a
+        heuristic matching order based on [VF2]_ paper. Otherwise it will
+        default to matching the nodes in order specified by their ids.
+    :param int call_limit: An optional bound on the number of states that VF2
+        algorithm visits while searching for a solution for each pair of
+        graphs. If it exceeds this limit the graphs are treated as not
+        isomorphic.
+
+    :returns: A list of the isomorphism classes, each a list of the indices in
+        ``graphs`` of its graphs in increasing order. The classes are ordered
+        by their first index.
+    :rtype: list
+    """
+    graphs = list(graphs)
+    if all(isinstance(graph, PyDiGraph) for graph in graphs):
+        isomorphism_classes_func = digraph_isomorphism_classes
+    elif all(isinstance(graph, PyGraph) for graph in graphs):
+        isomorphism_classes_func = graph_isomorphism_classes
+    else:
+        raise TypeError("graphs must be all PyGraph or all PyDiGraph objects")
+    return isomorphism_classes_func(
+        graphs,
+        node_matcher=node_matcher,
+        edge_matcher=edge_matcher,
+        id_order=id_order,
+        call_limit=call_limit,
+    )
+
+
+@functools.singledispatch
+def weisfeiler_lehman_subgraph_hashes(graph, depth=3, node_attr_fn=None, edge_attr_fn=None):
+    """Compute the Weisfeiler-Lehman subtree hash of each node of a graph
+
+    Each node starts with a label, its degree (its in and out degrees for a
+    :class:`~retworkx.PyDiGraph`) or the output of ``node_attr_fn``, and at
+    each iteration the label of a node is replaced by a hash of its label and
+    the sorted labels of its neighbors (successors for a
+    :class:`~retworkx.PyDiGraph`). After ``depth`` iterations the hash
+    of a node identifies the subtree of depth ``depth`` rooted at it. Nodes
+    with different hashes can't be mapped to each other by an isomorphism,
+    so the hashes can be used to find duplicate nodes or to filter the
+    candidate nodes for subgraph matching. Nodes with equal hashes aren't
+    necessarily equivalent.
+
+    The hashes don't depend on a random seed, so they can be compared
+    between graphs and between runs.
+
+    .. jupyter-execute::
+
+      import retworkx
+
+      graph = retworkx.generators.path_graph(5)
+      hashes = retworkx.weisfeiler_lehman_subgraph_hashes(graph, depth=2)
+      print(hashes[0] == hashes[4], hashes[0] == hashes[2])
+
+    :param graph: The graph to hash, either a :class:`~retworkx.PyGraph` or
+        :class:`~retworkx.PyDiGraph`
+    :param int depth: The number of iterations, which is the depth of the
+        subtree hashed for each node. With a depth of ``0`` only the initial
+        label of each node is hashed. By default this is ``3``.
+    :param node_attr_fn: An optional callable which will be passed the
+        data payload of a node and returns a string used as the initial label
+        of the node. If it isn't specified the degree of the node is used.
+    :param edge_attr_fn: An optional callable which will be passed the
+        data payload of an edge and returns a string which is added to the
+        label of the neighbor across the edge. If it isn't specified edges
+        aren't labeled.
+
+    :returns: A dictionary mapping each node index to its hash as a hex
+        string
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@weisfeiler_lehman_subgraph_hashes.register(PyDiGraph)
+def _digraph_weisfeiler_lehman_subgraph_hashes(
+    graph, depth=3, node_attr_fn=None, edge_attr_fn=None
+):
+    return digraph_weisfeiler_lehman_subgraph_hashes(
+        graph, depth=depth, node_attr_fn=node_attr_fn, edge_attr_fn=edge_attr_fn
+    )
+
+
+@weisfeiler_lehman_subgraph_hashes.register(PyGraph)
+def _graph_weisfeiler_lehman_subgraph_hashes(graph, depth=3, node_attr_fn=None, edge_attr_fn=None):
+    return graph_weisfeiler_lehman_subgraph_hashes(
+        graph, depth=depth, node_attr_fn=node_attr_fn, edge_attr_fn=edge_attr_fn
+    )
+
+
 @functools.singledispatch
 def union(
     first,
@@ -1722,6 +3544,89 @@ def _graph_union(
     return graph_union(first, second, merge_nodes=merge_nodes, merge_edges=merge_edges)
 
 
+@functools.singledispatch
+def difference(first, second, node_map=None):
+    """Return a new graph with the edges of ``first`` which aren't in ``second``
+
+    The nodes of ``second`` are aligned with the nodes of ``first`` either by
+    the explicit ``node_map`` or, if it's not set, with the first node of
+    ``first`` that has an equal weight/data payload (which operates in
+    :math:`\\mathcal{O}(n_1 n_2)`). An edge of ``first`` is in the difference
+    unless ``second`` has an edge between the nodes aligned with its
+    endpoints (in the same direction for a :class:`~retworkx.PyDiGraph`).
+    Edges are compared only by their endpoints, not by their weight/data
+    payloads, and all the parallel edges between a pair of nodes are kept or
+    removed together.
+
+    :param first: The first graph object
+    :param second: The second graph object, of the same type as ``first``
+    :param dict node_map: An optional dictionary mapping node indices of
+        ``second`` to the node indices of ``first`` they're aligned with.
+        Nodes of ``second`` not in the dictionary aren't aligned with any
+        node. If not set, nodes are aligned by weight/data payload equality.
+
+    :returns: A new graph object with all the nodes of ``first``, with the
+        same indices, and the edges of ``first`` which aren't in ``second``.
+        It's worth noting the weight/data payload objects are passed by
+        reference from ``first`` to this new object.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+        corresponding graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@difference.register(PyDiGraph)
+def _digraph_difference(first, second, node_map=None):
+    return digraph_difference(first, second, node_map=node_map)
+
+
+@difference.register(PyGraph)
+def _graph_difference(first, second, node_map=None):
+    return graph_difference(first, second, node_map=node_map)
+
+
+@functools.singledispatch
+def symmetric_difference(first, second, node_map=None):
+    """Return a new graph with the edges in exactly one of ``first`` and ``second``
+
+    The nodes of ``second`` are aligned with the nodes of ``first`` the same
+    way as in :func:`~retworkx.difference`. The output has all the nodes of
+    ``first``, with the same indices, followed by the nodes of ``second``
+    which aren't aligned with any node of ``first``. Its edges are the edges
+    of :func:`~retworkx.difference` of ``first`` and ``second`` and the edges
+    of ``second`` which aren't in ``first``, added between the corresponding
+    nodes of the output.
+
+    :param first: The first graph object
+    :param second: The second graph object, of the same type as ``first``
+    :param dict node_map: An optional dictionary mapping node indices of
+        ``second`` to the node indices of ``first`` they're aligned with.
+        Nodes of ``second`` not in the dictionary aren't aligned with any
+        node. If not set, nodes are aligned by weight/data payload equality.
+
+    :returns: A new graph object with the edges in exactly one of ``first``
+        and ``second``. It's worth noting the weight/data payload objects are
+        passed by reference from ``first`` and ``second`` to this new object.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+        corresponding graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@symmetric_difference.register(PyDiGraph)
+def _digraph_symmetric_difference(first, second, node_map=None):
+    return digraph_symmetric_difference(first, second, node_map=node_map)
+
+
+@symmetric_difference.register(PyGraph)
+def _graph_symmetric_difference(first, second, node_map=None):
+    return graph_symmetric_difference(first, second, node_map=node_map)
+
+
 @functools.singledispatch
 def tensor_product(
     first,
@@ -1815,7 +3720,7 @@ def _graph_cartesian_product(
 
 
 @functools.singledispatch
-def bfs_search(graph, source, visitor):
+def bfs_search(graph, source, visitor, node_mask=None):
     """Breadth-first traversal of a directed/undirected graph.
 
     The pseudo-code for the BFS algorithm is listed below, with the annotated
@@ -1884,22 +3789,27 @@ def bfs_search(graph, source, visitor):
         graph are searched.
     :param visitor: A visitor object that is invoked at the event points inside the
         algorithm. This should be a subclass of :class:`~retworkx.visit.BFSVisitor`.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph, including masked nodes in ``source``. If not specified every
+        node is used.
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @bfs_search.register(PyDiGraph)
-def _digraph_bfs_search(graph, source, visitor):
-    return digraph_bfs_search(graph, source, visitor)
+def _digraph_bfs_search(graph, source, visitor, node_mask=None):
+    return digraph_bfs_search(graph, source, visitor, node_mask=node_mask)
 
 
 @bfs_search.register(PyGraph)
-def _graph_bfs_search(graph, source, visitor):
-    return graph_bfs_search(graph, source, visitor)
+def _graph_bfs_search(graph, source, visitor, node_mask=None):
+    return graph_bfs_search(graph, source, visitor, node_mask=node_mask)
 
 
 @functools.singledispatch
-def dfs_search(graph, source, visitor):
+def dfs_search(graph, source, visitor, node_mask=None):
     """Depth-first traversal of a directed/undirected graph.
 
     The pseudo-code for the DFS algorithm is listed below, with the annotated
@@ -1964,18 +3874,23 @@ def dfs_search(graph, source, visitor):
         graph are searched.
     :param visitor: A visitor object that is invoked at the event points inside the
         algorithm. This should be a subclass of :class:`~retworkx.visit.DFSVisitor`.
+    :param node_mask: An optional boolean numpy array with an entry for each
+        node index, or an iterable of node indices, of the nodes to mask.
+        Masked nodes, and their edges, are ignored as if they weren't in the
+        graph, including masked nodes in ``source``. If not specified every
+        node is used.
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @dfs_search.register(PyDiGraph)
-def _digraph_dfs_search(graph, source, visitor):
-    return digraph_dfs_search(graph, source, visitor)
+def _digraph_dfs_search(graph, source, visitor, node_mask=None):
+    return digraph_dfs_search(graph, source, visitor, node_mask=node_mask)
 
 
 @dfs_search.register(PyGraph)
-def _graph_dfs_search(graph, source, visitor):
-    return graph_dfs_search(graph, source, visitor)
+def _graph_dfs_search(graph, source, visitor, node_mask=None):
+    return graph_dfs_search(graph, source, visitor, node_mask=node_mask)
 
 
 @functools.singledispatch
@@ -2043,3 +3958,91 @@ def _digraph_dijkstra_search(graph, source, weight_fn, visitor):
 @dijkstra_search.register(PyGraph)
 def _graph_dijkstra_search(graph, source, weight_fn, visitor):
     return graph_dijkstra_search(graph, source, weight_fn, visitor)
+
+
+@functools.singledispatch
+def ego_graph(graph, node, radius=1.0, distance_fn=None, undirected=False):
+    """Return the ego graph of a node
+
+    The ego graph is the subgraph induced by the center node and all the
+    nodes within a given distance ``radius`` of it.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The node index of the center of the ego graph
+    :param float radius: The maximum distance from ``node`` for a node to be
+        included in the ego graph. By default this is the number of hops from
+        ``node``.
+    :param distance_fn: An optional callable which takes a single positional
+        argument, an edge's weight/data payload, and returns a float
+        representing the distance along that edge. It must be non-negative.
+        If not specified each edge will have a distance of ``1.0``.
+    :param bool undirected: If set to ``True`` the edge directions will be
+        ignored when finding the nodes within ``radius`` of ``node``. This
+        only works with a :class:`~retworkx.PyDiGraph` input for ``graph``
+
+    :returns: A new graph for the subgraph induced by the nodes within
+        ``radius`` of ``node``. The node indices in the ego graph will be
+        compacted the same as for :meth:`~retworkx.PyGraph.subgraph`, and
+        node and edge weight/data payloads are shared references with
+        ``graph``.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :raises InvalidNode: When an invalid node index is provided.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@ego_graph.register(PyDiGraph)
+def _digraph_ego_graph(graph, node, radius=1.0, distance_fn=None, undirected=False):
+    return digraph_ego_graph(
+        graph, node, radius=radius, distance_fn=distance_fn, undirected=undirected
+    )
+
+
+@ego_graph.register(PyGraph)
+def _graph_ego_graph(graph, node, radius=1.0, distance_fn=None, undirected=False):
+    return graph_ego_graph(graph, node, radius=radius, distance_fn=distance_fn)
+
+
+@functools.singledispatch
+def hamiltonian_path(graph, cycle=False, method="exact"):
+    """Find a Hamiltonian path or cycle of a graph
+
+    A Hamiltonian path visits every node of the graph exactly once, a
+    Hamiltonian cycle additionally has an edge from its last node back to its
+    first node.
+
+    With ``method="exact"`` the search is exhaustive, graphs with at most 24
+    nodes are solved with a dynamic program over the subsets of nodes and
+    larger graphs with a depth first search which prunes the paths which
+    can't be completed. The exact search supports graphs with at most 64
+    nodes. With ``method="heuristic"`` paths are extended greedily from each
+    node, this is fast for any graph size but may not find a path when one
+    exists.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param bool cycle: If ``True`` search for a Hamiltonian cycle instead of a
+        path. Defaults to ``False``.
+    :param str method: Either ``"exact"`` or ``"heuristic"``, defaults to
+        ``"exact"``
+
+    :returns: The node indices in the order they're visited, the first node
+        isn't repeated at the end of a cycle. ``None`` if the exact search
+        proved there is no Hamiltonian path or cycle, or if the heuristic
+        search didn't find one.
+    :rtype: NodeIndices
+    :raises GraphTooLarge: If ``method="exact"`` and the graph has more than
+        64 nodes
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@hamiltonian_path.register(PyDiGraph)
+def _digraph_hamiltonian_path(graph, cycle=False, method="exact"):
+    return digraph_hamiltonian_path(graph, cycle=cycle, method=method)
+
+
+@hamiltonian_path.register(PyGraph)
+def _graph_hamiltonian_path(graph, cycle=False, method="exact"):
+    return graph_hamiltonian_path(graph, cycle=cycle, method=method)
@@ -6,6 +6,8 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+import os
+
 from setuptools import setup
 from setuptools_rust import Binding, RustExtension
 
@@ -18,6 +20,12 @@ def readme():
 mpl_extras = ['matplotlib>=3.0']
 graphviz_extras = ['pillow>=5.4']
 
+# Build with usize node and edge indices instead of u32 to support graphs
+# with more than 2**32 - 2 nodes or edges, at the cost of more memory.
+rust_features = []
+if os.getenv("RETWORKX_USIZE_INDICES"):
+    rust_features.append("usize-indices")
+
 
 setup(
     name="retworkx",
@@ -50,7 +58,8 @@ setup(
         "Documentation": "https://qiskit.org/documentation/retworkx",
     },
     rust_extensions=[RustExtension("retworkx.retworkx", "Cargo.toml",
-                                   binding=Binding.PyO3)],
+                                   binding=Binding.PyO3,
+                                   features=rust_features)],
     include_package_data=True,
     packages=["retworkx", "retworkx.visualization"],
     zip_safe=False,
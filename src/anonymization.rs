@@ -0,0 +1,312 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+
+use rand::prelude::*;
+
+use crate::graph;
+use crate::iterators::EdgeList;
+use crate::random_seed::seeded_rng;
+use crate::NodeIndex;
+
+/// Find the k-anonymous degree sequence closest to ``degrees`` in L1 norm,
+/// where every degree can only be increased.
+///
+/// The degrees are sorted in decreasing order and split into groups of
+/// between ``k`` and ``2k - 1`` consecutive degrees by dynamic programming,
+/// the degrees of each group are raised to the largest degree in the group
+/// [1]. This takes O(nk) time after sorting.
+///
+/// [1] Liu, Kun, and Evimaria Terzi. "Towards identity anonymization on
+/// graphs." Proceedings of the 2008 ACM SIGMOD International Conference on
+/// Management of Data (2008): 93-106.
+fn anonymize_degrees(degrees: &[usize], k: usize) -> Vec<usize> {
+    let count = degrees.len();
+    if count == 0 {
+        return Vec::new();
+    }
+    let mut order: Vec<usize> = (0..count).collect();
+    order.sort_by(|a, b| degrees[*b].cmp(&degrees[*a]).then(a.cmp(b)));
+    let sorted: Vec<usize> = order.iter().map(|index| degrees[*index]).collect();
+    let mut prefix_sum: Vec<usize> = vec![0; count + 1];
+    for (index, degree) in sorted.iter().enumerate() {
+        prefix_sum[index + 1] = prefix_sum[index] + degree;
+    }
+    // The cost of raising the degrees first..=last to sorted[first]
+    let cost = |first: usize, last: usize| -> usize {
+        sorted[first] * (last - first + 1) - (prefix_sum[last + 1] - prefix_sum[first])
+    };
+    // best[j] is the lowest cost of anonymizing the first j + 1 degrees and
+    // start[j] the first index of the last group in that solution.
+    let mut best: Vec<usize> = vec![usize::MAX; count];
+    let mut start: Vec<usize> = vec![0; count];
+    for last in (k - 1)..count {
+        if last + 1 < 2 * k {
+            best[last] = cost(0, last);
+            continue;
+        }
+        // The last group has between k and 2k - 1 degrees, larger groups
+        // can always be split without increasing the cost
+        for split in std::cmp::max(k, last + 2 - 2 * k)..=(last + 1 - k) {
+            if best[split - 1] == usize::MAX {
+                continue;
+            }
+            let total = best[split - 1] + cost(split, last);
+            if total < best[last] {
+                best[last] = total;
+                start[last] = split;
+            }
+        }
+    }
+    let mut anonymized: Vec<usize> = vec![0; count];
+    let mut last = count;
+    while last > 0 {
+        let first = start[last - 1];
+        for index in first..last {
+            anonymized[order[index]] = sorted[first];
+        }
+        last = first;
+    }
+    anonymized
+}
+
+fn check_k(k: usize, count: usize) -> PyResult<()> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    if count > 0 && k > count {
+        return Err(PyValueError::new_err(format!(
+            "k {} is larger than the number of nodes {}",
+            k, count
+        )));
+    }
+    Ok(())
+}
+
+/// The degree of every node index, with self loops counted twice
+fn degrees(graph: &graph::PyGraph) -> Vec<usize> {
+    let mut degree = vec![0; graph.graph.node_bound()];
+    for edge in graph.graph.edge_references() {
+        degree[edge.source().index()] += 1;
+        degree[edge.target().index()] += 1;
+    }
+    degree
+}
+
+/// Try to add edges between the ``nodes`` so each node's degree increases
+/// by its ``residual``, without adding self loops or edges between adjacent
+/// nodes. The node with the largest residual is repeatedly connected to the
+/// nodes with the largest residuals, as in the supergraph construction of
+/// Liu and Terzi. Returns the new edges or ``None`` if this fails.
+fn realize_residuals(
+    graph: &graph::PyGraph,
+    nodes: &[NodeIndex],
+    mut residual: Vec<usize>,
+) -> Option<Vec<(usize, usize)>> {
+    if residual.iter().sum::<usize>() % 2 != 0 {
+        return None;
+    }
+    let mut added: HashSet<(usize, usize)> = HashSet::new();
+    let mut new_edges: Vec<(usize, usize)> = Vec::new();
+    loop {
+        let (node, needed) = residual
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
+            .map(|(node, needed)| (node, *needed))?;
+        if needed == 0 {
+            return Some(new_edges);
+        }
+        residual[node] = 0;
+        let mut candidates: Vec<usize> = (0..nodes.len())
+            .filter(|other| {
+                *other != node
+                    && residual[*other] > 0
+                    && !added.contains(&(node.min(*other), node.max(*other)))
+                    && !graph.graph.contains_edge(nodes[node], nodes[*other])
+            })
+            .collect();
+        if candidates.len() < needed {
+            return None;
+        }
+        candidates.sort_by(|a, b| residual[*b].cmp(&residual[*a]).then(a.cmp(b)));
+        for other in candidates.into_iter().take(needed) {
+            residual[other] -= 1;
+            added.insert((node.min(other), node.max(other)));
+            new_edges.push((nodes[node].index(), nodes[other].index()));
+        }
+    }
+}
+
+/// Compute the k-anonymous degree sequence closest to a degree sequence
+///
+/// A degree sequence is k-anonymous if every degree in it appears at least
+/// ``k`` times. This finds the k-anonymous degree sequence which only
+/// increases degrees and has the smallest total increase, with the dynamic
+/// programming algorithm of Liu and Terzi [1]_: the degrees are sorted and
+/// split into groups of at least ``k`` consecutive degrees, and the degrees
+/// of each group are raised to the largest degree in the group. This takes
+/// :math:`O(nk)` time after sorting.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   print(retworkx.anonymize_degree_sequence([5, 3, 3, 2, 2, 1], 2))
+///
+/// :param list degree_sequence: The degree sequence to anonymize, as a list
+///     of non-negative integers
+/// :param int k: The minimum number of times every degree has to appear
+///
+/// :returns: The anonymized degree sequence, with the anonymized degree of
+///     each entry of ``degree_sequence`` in the same position. The result
+///     isn't necessarily graphical.
+/// :rtype: list
+/// :raises ValueError: If ``k`` is ``0`` or larger than the length of
+///     ``degree_sequence``
+///
+/// .. [1] Liu, Kun, and Evimaria Terzi. "Towards identity anonymization on
+///     graphs." Proceedings of the 2008 ACM SIGMOD International Conference
+///     on Management of Data (2008): 93-106.
+#[pyfunction]
+#[pyo3(text_signature = "(degree_sequence, k, /)")]
+pub fn anonymize_degree_sequence(degree_sequence: Vec<usize>, k: usize) -> PyResult<Vec<usize>> {
+    check_k(k, degree_sequence.len())?;
+    Ok(anonymize_degrees(&degree_sequence, k))
+}
+
+/// Check if a :class:`~retworkx.PyGraph` is k-degree anonymous
+///
+/// A graph is k-degree anonymous if for every node there are at least
+/// ``k - 1`` other nodes with the same degree, so a node can't be
+/// identified by its degree alone.
+///
+/// :param PyGraph graph: The graph to check
+/// :param int k: The minimum number of nodes with each degree
+///
+/// :returns: ``True`` if the graph is k-degree anonymous
+/// :rtype: bool
+/// :raises ValueError: If ``k`` is ``0``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn is_k_degree_anonymous(graph: &graph::PyGraph, k: usize) -> PyResult<bool> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    let degree = degrees(graph);
+    let mut counts: Vec<usize> = Vec::new();
+    for node in graph.graph.node_indices() {
+        let node_degree = degree[node.index()];
+        if node_degree >= counts.len() {
+            counts.resize(node_degree + 1, 0);
+        }
+        counts[node_degree] += 1;
+    }
+    Ok(counts.into_iter().all(|count| count == 0 || count >= k))
+}
+
+/// Make a :class:`~retworkx.PyGraph` k-degree anonymous in place by adding
+/// edges
+///
+/// A graph is k-degree anonymous if for every node there are at least
+/// ``k - 1`` other nodes with the same degree, so a node can't be
+/// identified by its degree alone. This implements the supergraph method of
+/// Liu and Terzi [1]_: the degree sequence of the graph is anonymized with
+/// :func:`~retworkx.anonymize_degree_sequence` and edges are added to the
+/// graph to realize the anonymized degrees, by repeatedly connecting the
+/// node missing the most edges to the other nodes missing the most edges.
+/// The existing edges are kept and no self loops or parallel edges are
+/// added. If the anonymized degrees can't be realized this way the original
+/// degrees are perturbed by increasing the degree of a random node and the
+/// degree sequence is anonymized again, up to ``max_tries`` times.
+///
+/// Since double edge swaps keep the degree of every node, the anonymized
+/// graph can be randomized further without losing its anonymity with
+/// :func:`~retworkx.graph_double_edge_swap`.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(5)
+///   graph.add_node(None)
+///   added = retworkx.k_degree_anonymize(graph, 2, seed=42)
+///   print(added)
+///   print(retworkx.is_k_degree_anonymous(graph, 2))
+///
+/// :param PyGraph graph: The graph to anonymize
+/// :param int k: The minimum number of nodes with each degree
+/// :param int max_tries: The maximum number of times to anonymize the
+///     degree sequence, by default ``100``
+/// :param int seed: An optional seed to use for the random number generator
+///     used to perturb the degrees
+///
+/// :returns: The added edges, the weight/data payload of each is ``None``
+/// :rtype: EdgeList
+/// :raises ValueError: If ``k`` is ``0`` or larger than the number of nodes,
+///     or if the graph couldn't be anonymized in ``max_tries`` attempts. The
+///     graph isn't modified if an error is raised.
+///
+/// .. [1] Liu, Kun, and Evimaria Terzi. "Towards identity anonymization on
+///     graphs." Proceedings of the 2008 ACM SIGMOD International Conference
+///     on Management of Data (2008): 93-106.
+#[pyfunction(max_tries = "100")]
+#[pyo3(text_signature = "(graph, k, /, max_tries=100, seed=None)")]
+pub fn k_degree_anonymize(
+    py: Python,
+    graph: &mut graph::PyGraph,
+    k: usize,
+    max_tries: usize,
+    seed: Option<u64>,
+) -> PyResult<EdgeList> {
+    let nodes: Vec<NodeIndex> = graph.graph.node_indices().collect();
+    check_k(k, nodes.len())?;
+    let degree = degrees(graph);
+    let original: Vec<usize> = nodes.iter().map(|node| degree[node.index()]).collect();
+    let mut perturbed = original.clone();
+    let mut rng = seeded_rng(seed);
+    for _ in 0..max_tries {
+        let anonymized = anonymize_degrees(&perturbed, k);
+        let residual: Vec<usize> = anonymized
+            .iter()
+            .zip(&original)
+            .map(|(target, degree)| target - degree)
+            .collect();
+        if let Some(new_edges) = realize_residuals(graph, &nodes, residual) {
+            for (source, target) in &new_edges {
+                graph.add_edge(*source, *target, py.None())?;
+            }
+            return Ok(EdgeList { edges: new_edges });
+        }
+        // Probe with a random node's degree increased, out of the nodes
+        // which can still gain an edge
+        let growable: Vec<usize> = (0..nodes.len())
+            .filter(|index| perturbed[*index] + 1 < nodes.len())
+            .collect();
+        match growable.choose(&mut rng) {
+            Some(index) => perturbed[*index] += 1,
+            None => break,
+        }
+    }
+    Err(PyValueError::new_err(format!(
+        "The graph couldn't be made {}-degree anonymous in {} tries",
+        k, max_tries
+    )))
+}
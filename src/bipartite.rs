@@ -0,0 +1,334 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+
+use retworkx_core::dictmap::*;
+
+use crate::graph;
+use crate::iterators::CentralityMapping;
+use crate::InvalidNode;
+use crate::NodeIndex;
+
+/// Check the node indices are in the graph and return a membership flag for
+/// each node index.
+fn node_flags(graph: &graph::PyGraph, nodes: &[usize]) -> PyResult<Vec<bool>> {
+    let mut flags = vec![false; graph.graph.node_bound()];
+    for node in nodes {
+        if !graph.graph.contains_node(NodeIndex::new(*node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not present in the graph",
+                node
+            )));
+        }
+        flags[*node] = true;
+    }
+    Ok(flags)
+}
+
+/// The distinct neighbors of every node index, without the node itself.
+fn neighbor_sets(graph: &graph::PyGraph) -> Vec<HashSet<usize>> {
+    let mut neighbors = vec![HashSet::new(); graph.graph.node_bound()];
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if source != target {
+            neighbors[source].insert(target);
+            neighbors[target].insert(source);
+        }
+    }
+    neighbors
+}
+
+/// The nodes to compute a per node metric for, all the nodes by default.
+fn target_nodes(graph: &graph::PyGraph, nodes: Option<Vec<usize>>) -> PyResult<Vec<usize>> {
+    match nodes {
+        Some(nodes) => {
+            node_flags(graph, &nodes)?;
+            Ok(nodes)
+        }
+        None => Ok(graph
+            .graph
+            .node_indices()
+            .map(|node| node.index())
+            .collect()),
+    }
+}
+
+/// Compute the density of a bipartite graph
+///
+/// The density of a bipartite graph is the number of edges between the two
+/// node sets divided by the number of possible edges between them,
+/// :math:`|top| \cdot |bottom|`. Edges within a node set aren't counted.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from(range(5))
+///   graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 3), (1, 4)])
+///   print(retworkx.bipartite_density(graph, [0, 1]))
+///
+/// :param PyGraph graph: The bipartite graph
+/// :param list top_nodes: The node indices of one of the node sets, the
+///     other node set is made up of the rest of the nodes
+///
+/// :returns: The density of the graph, ``0.0`` if one of the node sets is
+///     empty
+/// :rtype: float
+/// :raises InvalidNode: If a node index in ``top_nodes`` isn't in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, top_nodes, /)")]
+pub fn bipartite_density(graph: &graph::PyGraph, top_nodes: Vec<usize>) -> PyResult<f64> {
+    let top = node_flags(graph, &top_nodes)?;
+    let top_count = top.iter().filter(|is_top| **is_top).count();
+    let bottom_count = graph.graph.node_count() - top_count;
+    if top_count == 0 || bottom_count == 0 {
+        return Ok(0.0);
+    }
+    let edge_count = graph
+        .graph
+        .edge_references()
+        .filter(|edge| top[edge.source().index()] != top[edge.target().index()])
+        .count();
+    Ok(edge_count as f64 / (top_count * bottom_count) as f64)
+}
+
+/// Compute the degree centrality of the nodes of a bipartite graph
+///
+/// The degree centrality of a node in a bipartite graph is its degree
+/// divided by the number of nodes in the other node set [1]_, which is the
+/// most neighbors it can have.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from(range(5))
+///   graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 3), (1, 4)])
+///   print(retworkx.bipartite_degree_centrality(graph, [0, 1]))
+///
+/// :param PyGraph graph: The bipartite graph
+/// :param list top_nodes: The node indices of one of the node sets, the
+///     other node set is made up of the rest of the nodes
+///
+/// :returns: A mapping of node indices to their degree centrality. The
+///     centrality of the nodes of a node set is ``0.0`` if the other node
+///     set is empty.
+/// :rtype: CentralityMapping
+/// :raises InvalidNode: If a node index in ``top_nodes`` isn't in the graph
+///
+/// .. [1] Borgatti, Stephen P., and Martin G. Everett. "Network analysis of
+///     2-mode data." Social Networks 19.3 (1997): 243-269.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, top_nodes, /)")]
+pub fn bipartite_degree_centrality(
+    graph: &graph::PyGraph,
+    top_nodes: Vec<usize>,
+) -> PyResult<CentralityMapping> {
+    let top = node_flags(graph, &top_nodes)?;
+    let top_count = top.iter().filter(|is_top| **is_top).count();
+    let bottom_count = graph.graph.node_count() - top_count;
+    let centralities: DictMap<usize, f64> = graph
+        .graph
+        .node_indices()
+        .map(|node| {
+            let other_count = if top[node.index()] {
+                bottom_count
+            } else {
+                top_count
+            };
+            let centrality = if other_count == 0 {
+                0.0
+            } else {
+                graph.graph.edges(node).count() as f64 / other_count as f64
+            };
+            (node.index(), centrality)
+        })
+        .collect();
+    Ok(CentralityMapping { centralities })
+}
+
+/// Compute the redundancy coefficient of nodes of a bipartite graph
+///
+/// The redundancy coefficient of a node :math:`v` is the fraction of pairs
+/// of its neighbors which are both neighbors of another node too [1]_,
+///
+/// .. math::
+///
+///     rc(v) = \frac{|\{\{u, w\} \subseteq N(v) : \exists v' \neq v,
+///         u, w \in N(v')\}|}{\frac{|N(v)| (|N(v)| - 1)}{2}}
+///
+/// so it's the fraction of the pairs of neighbors which would still be
+/// connected by a node in a projection of the graph onto the other node set
+/// if :math:`v` was removed. Parallel edges and self loops are ignored.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from(range(5))
+///   graph.add_edges_from_no_data([(0, 2), (0, 3), (0, 4), (1, 2), (1, 3)])
+///   print(retworkx.node_redundancy(graph))
+///
+/// :param PyGraph graph: The bipartite graph
+/// :param list nodes: An optional list of the node indices to compute the
+///     redundancy coefficient of. If not specified it's computed for every
+///     node.
+///
+/// :returns: A dictionary mapping node indices to their redundancy
+///     coefficient
+/// :rtype: dict
+/// :raises InvalidNode: If a node index in ``nodes`` isn't in the graph
+/// :raises ValueError: If a node has fewer than 2 neighbors
+///
+/// .. [1] Latapy, Matthieu, Clémence Magnien, and Nathalie Del Vecchio.
+///     "Basic notions for the analysis of large two-mode networks." Social
+///     Networks 30.1 (2008): 31-48.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, nodes=None)")]
+pub fn node_redundancy(
+    graph: &graph::PyGraph,
+    nodes: Option<Vec<usize>>,
+) -> PyResult<DictMap<usize, f64>> {
+    let nodes = target_nodes(graph, nodes)?;
+    let neighbors = neighbor_sets(graph);
+    let mut out_map: DictMap<usize, f64> = DictMap::with_capacity(nodes.len());
+    for node in nodes {
+        let mut node_neighbors: Vec<usize> = neighbors[node].iter().copied().collect();
+        node_neighbors.sort_unstable();
+        let degree = node_neighbors.len();
+        if degree < 2 {
+            return Err(PyValueError::new_err(format!(
+                "Node {} has fewer than 2 neighbors, its redundancy coefficient isn't defined",
+                node
+            )));
+        }
+        let mut overlap: usize = 0;
+        for (position, first) in node_neighbors.iter().enumerate() {
+            for second in &node_neighbors[position + 1..] {
+                let (smaller, larger) = if neighbors[*first].len() < neighbors[*second].len() {
+                    (&neighbors[*first], &neighbors[*second])
+                } else {
+                    (&neighbors[*second], &neighbors[*first])
+                };
+                if smaller
+                    .iter()
+                    .any(|other| *other != node && larger.contains(other))
+                {
+                    overlap += 1;
+                }
+            }
+        }
+        out_map.insert(node, 2.0 * overlap as f64 / (degree * (degree - 1)) as f64);
+    }
+    Ok(out_map)
+}
+
+/// Compute the bipartite clustering coefficient of nodes of a bipartite
+/// graph
+///
+/// Since a bipartite graph has no triangles, the clustering coefficient of a
+/// node :math:`v` is instead defined by Latapy et al. [1]_ as the average
+/// overlap of its neighborhood with the neighborhoods of the nodes at
+/// distance 2, :math:`N(N(v))`,
+///
+/// .. math::
+///
+///     c(v) = \frac{\sum_{u \in N(N(v))} c_{uv}}{|N(N(v))|}
+///
+/// where the overlap :math:`c_{uv}` depends on ``mode``:
+///
+/// * ``"dot"``: :math:`c_{uv} = \frac{|N(u) \cap N(v)|}{|N(u) \cup N(v)|}`
+/// * ``"min"``: :math:`c_{uv} = \frac{|N(u) \cap N(v)|}{\min(|N(u)|, |N(v)|)}`
+/// * ``"max"``: :math:`c_{uv} = \frac{|N(u) \cap N(v)|}{\max(|N(u)|, |N(v)|)}`
+///
+/// The clustering coefficient of a node without nodes at distance 2 is
+/// ``0.0``. Parallel edges and self loops are ignored.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   print(retworkx.bipartite_clustering(graph))
+///   print(retworkx.bipartite_clustering(graph, mode="min"))
+///
+/// :param PyGraph graph: The bipartite graph
+/// :param list nodes: An optional list of the node indices to compute the
+///     clustering coefficient of. If not specified it's computed for every
+///     node.
+/// :param str mode: The overlap to use, ``"dot"``, ``"min"`` or ``"max"``.
+///     By default this is ``"dot"``.
+///
+/// :returns: A dictionary mapping node indices to their clustering
+///     coefficient
+/// :rtype: dict
+/// :raises InvalidNode: If a node index in ``nodes`` isn't in the graph
+/// :raises ValueError: If ``mode`` is invalid
+///
+/// .. [1] Latapy, Matthieu, Clémence Magnien, and Nathalie Del Vecchio.
+///     "Basic notions for the analysis of large two-mode networks." Social
+///     Networks 30.1 (2008): 31-48.
+#[pyfunction(mode = "\"dot\"")]
+#[pyo3(text_signature = "(graph, /, nodes=None, mode=\"dot\")")]
+pub fn bipartite_clustering(
+    graph: &graph::PyGraph,
+    nodes: Option<Vec<usize>>,
+    mode: &str,
+) -> PyResult<DictMap<usize, f64>> {
+    let overlap: fn(usize, usize, usize) -> f64 = match mode {
+        "dot" => |common, first, second| common as f64 / (first + second - common) as f64,
+        "min" => |common, first, second| common as f64 / first.min(second) as f64,
+        "max" => |common, first, second| common as f64 / first.max(second) as f64,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid mode '{}', must be 'dot', 'min' or 'max'",
+                mode
+            )))
+        }
+    };
+    let nodes = target_nodes(graph, nodes)?;
+    let neighbors = neighbor_sets(graph);
+    let mut out_map: DictMap<usize, f64> = DictMap::with_capacity(nodes.len());
+    for node in nodes {
+        // Count the common neighbors with every node at distance 2
+        let mut common: DictMap<usize, usize> = DictMap::new();
+        for neighbor in &neighbors[node] {
+            for second in &neighbors[*neighbor] {
+                if *second != node {
+                    *common.entry(*second).or_insert(0) += 1;
+                }
+            }
+        }
+        let clustering = if common.is_empty() {
+            0.0
+        } else {
+            let node_degree = neighbors[node].len();
+            common
+                .iter()
+                .map(|(second, count)| overlap(*count, node_degree, neighbors[*second].len()))
+                .sum::<f64>()
+                / common.len() as f64
+        };
+        out_map.insert(node, clustering);
+    }
+    Ok(out_map)
+}
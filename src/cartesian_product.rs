@@ -10,10 +10,13 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::clusters::Clusters;
 use crate::iterators::ProductNodeMap;
+use crate::key_index::KeyIndexes;
+use crate::transaction::MutationLog;
 use crate::{digraph, graph, StablePyGraph};
 
-use hashbrown::HashMap;
+use retworkx_core::dictmap::*;
 
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
 use petgraph::{algo, EdgeType};
@@ -31,7 +34,7 @@ fn cartesian_product<Ty: EdgeType>(
         first.node_count() * second.edge_count() + first.edge_count() * second.node_count(),
     );
 
-    let mut hash_nodes = HashMap::with_capacity(first.node_count() * second.node_count());
+    let mut hash_nodes = DictMap::with_capacity(first.node_count() * second.node_count());
 
     for (x, weight_x) in first.node_references() {
         for (y, weight_y) in second.node_references() {
@@ -109,9 +112,12 @@ pub fn graph_cartesian_product(
 
     (
         graph::PyGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             multigraph: true,
             node_removed: false,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         },
         out_node_map,
     )
@@ -158,11 +164,14 @@ pub fn digraph_cartesian_product(
 
     (
         digraph::PyDiGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             cycle_state: algo::DfsSpace::default(),
             check_cycle: false,
             node_removed: false,
             multigraph: true,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         },
         out_node_map,
     )
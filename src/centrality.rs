@@ -10,15 +10,72 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use std::convert::{Infallible, TryFrom};
+
+use crate::edge_filter::EdgeMask;
 use crate::iterators::CentralityMapping;
+use crate::node_filter::NodeMask;
 
 use crate::digraph;
 use crate::graph;
+use crate::{convergence_error, CostFn, StablePyGraph};
+
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
 
 use pyo3::prelude::*;
 
+use numpy::IntoPyArray;
+
 use retworkx_core::centrality;
 
+/// Compute the betweenness centrality for the betweenness functions. The
+/// shortest paths are counted with a breadth first search if every edge has
+/// the same positive weight, and with Dijkstra's algorithm otherwise.
+#[allow(clippy::too_many_arguments)]
+fn betweenness<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    normalized: bool,
+    endpoints: bool,
+    parallel_threshold: usize,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<Option<f64>>> {
+    let edge_mask = EdgeMask::new(py, graph, edge_filter_fn)?;
+    let node_mask = NodeMask::new(py, graph, node_mask)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    if let CostFn::Default(weight) = cost_fn {
+        if weight > 0.0 {
+            return Ok(py.allow_threads(|| {
+                centrality::betweenness_centrality(
+                    &node_mask.filter(&edge_mask.filter(graph)),
+                    endpoints,
+                    normalized,
+                    parallel_threshold,
+                )
+            }));
+        }
+    }
+    let cost_fn = cost_fn.prepare(py, graph)?;
+    let mut weights: Vec<f64> = vec![0.; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+    Ok(py.allow_threads(|| {
+        let res: Result<Vec<Option<f64>>, _> = centrality::weighted_betweenness_centrality(
+            &node_mask.filter(&edge_mask.filter(graph)),
+            |e| Ok::<f64, Infallible>(weights[e.id().index()]),
+            endpoints,
+            normalized,
+            parallel_threshold,
+        );
+        res.unwrap()
+    }))
+}
+
 /// Compute the betweenness centrality of all nodes in a PyGraph.
 ///
 /// Betweenness centrality of a node :math:`v` is the sum of the
@@ -53,27 +110,67 @@ use retworkx_core::centrality;
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If set the shortest paths are the paths with
+///     the lowest total weight, which are counted with Dijkstra's algorithm,
+///     and paths are only equally short if their total weights are exactly
+///     equal. If not specified every edge has the weight ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Any positive value gives the same result as counting
+///     the unweighted shortest paths. Default: ``1.0``
 ///
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      betweenness score for each node.
 /// :rtype: CentralityMapping
-#[pyfunction(normalized = "true", endpoints = "false", parallel_threshold = "50")]
-#[pyo3(text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50)")]
+#[pyfunction(
+    normalized = "true",
+    endpoints = "false",
+    parallel_threshold = "50",
+    default_weight = "1.0"
+)]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, edge_filter_fn=None, node_mask=None, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn graph_betweenness_centrality(
+    py: Python,
     graph: &graph::PyGraph,
     normalized: bool,
     endpoints: bool,
     parallel_threshold: usize,
-) -> CentralityMapping {
-    let betweenness =
-        centrality::betweenness_centrality(&graph.graph, endpoints, normalized, parallel_threshold);
-    CentralityMapping {
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<CentralityMapping> {
+    let betweenness = betweenness(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        edge_filter_fn,
+        node_mask,
+        weight_fn,
+        default_weight,
+    )?;
+    Ok(CentralityMapping {
         centralities: betweenness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
-    }
+    })
 }
 
 /// Compute the betweenness centrality of all nodes in a PyDiGraph.
@@ -110,25 +207,588 @@ pub fn graph_betweenness_centrality(
 ///     the betweenness centrality in parallel at if the number of nodes in
 ///     the graph is less than this value it will run in a single thread. The
 ///     default value is 50
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If set the shortest paths are the paths with
+///     the lowest total weight, which are counted with Dijkstra's algorithm,
+///     and paths are only equally short if their total weights are exactly
+///     equal. If not specified every edge has the weight ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Any positive value gives the same result as counting
+///     the unweighted shortest paths. Default: ``1.0``
 ///
 /// :returns: a read-only dict-like object whose keys are the node indices and values are the
 ///      betweenness score for each node.
 /// :rtype: CentralityMapping
-#[pyfunction(normalized = "true", endpoints = "false", parallel_threshold = "50")]
-#[pyo3(text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50)")]
+#[pyfunction(
+    normalized = "true",
+    endpoints = "false",
+    parallel_threshold = "50",
+    default_weight = "1.0"
+)]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, edge_filter_fn=None, node_mask=None, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn digraph_betweenness_centrality(
+    py: Python,
     graph: &digraph::PyDiGraph,
     normalized: bool,
     endpoints: bool,
     parallel_threshold: usize,
-) -> CentralityMapping {
-    let betweenness =
-        centrality::betweenness_centrality(&graph.graph, endpoints, normalized, parallel_threshold);
-    CentralityMapping {
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<CentralityMapping> {
+    let betweenness = betweenness(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        edge_filter_fn,
+        node_mask,
+        weight_fn,
+        default_weight,
+    )?;
+    Ok(CentralityMapping {
         centralities: betweenness
             .into_iter()
             .enumerate()
             .filter_map(|(i, v)| v.map(|x| (i, x)))
             .collect(),
+    })
+}
+
+/// Compute the betweenness centrality of all nodes in a PyGraph as a numpy
+/// array
+///
+/// This is the same as :func:`~retworkx.graph_betweenness_centrality` but the
+/// betweenness scores are returned in a dense numpy array, which avoids
+/// building a :class:`~retworkx.CentralityMapping` for large graphs.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The input graph
+/// :param bool normalized: Whether to normalize the betweenness scores by the number of distinct
+///    paths between all pairs of nodes.
+/// :param bool endpoints: Whether to include the endpoints of paths in pathlengths used to
+///    compute the betweenness.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the betweenness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If set the shortest paths are the paths with
+///     the lowest total weight, which are counted with Dijkstra's algorithm,
+///     and paths are only equally short if their total weights are exactly
+///     equal. If not specified every edge has the weight ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Any positive value gives the same result as counting
+///     the unweighted shortest paths. Default: ``1.0``
+///
+/// :returns: A 1D numpy array of ``float64`` where the value at position
+///     ``i`` is the betweenness score of the node with index ``i``. If any
+///     nodes have been removed from the graph the positions of their indices
+///     are ``nan``.
+/// :rtype: numpy.ndarray
+#[pyfunction(
+    normalized = "true",
+    endpoints = "false",
+    parallel_threshold = "50",
+    default_weight = "1.0"
+)]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, edge_filter_fn=None, node_mask=None, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_betweenness_centrality_array(
+    py: Python,
+    graph: &graph::PyGraph,
+    normalized: bool,
+    endpoints: bool,
+    parallel_threshold: usize,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<PyObject> {
+    let betweenness = betweenness(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        edge_filter_fn,
+        node_mask,
+        weight_fn,
+        default_weight,
+    )?;
+    Ok(dense_centralities(betweenness).into_pyarray(py).into())
+}
+
+/// Compute the betweenness centrality of all nodes in a PyDiGraph as a numpy
+/// array
+///
+/// This is the same as :func:`~retworkx.digraph_betweenness_centrality` but
+/// the betweenness scores are returned in a dense numpy array, which avoids
+/// building a :class:`~retworkx.CentralityMapping` for large graphs.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param bool normalized: Whether to normalize the betweenness scores by the number of distinct
+///    paths between all pairs of nodes.
+/// :param bool endpoints: Whether to include the endpoints of paths in pathlengths used to
+///    compute the betweenness.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the betweenness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If set the shortest paths are the paths with
+///     the lowest total weight, which are counted with Dijkstra's algorithm,
+///     and paths are only equally short if their total weights are exactly
+///     equal. If not specified every edge has the weight ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Any positive value gives the same result as counting
+///     the unweighted shortest paths. Default: ``1.0``
+///
+/// :returns: A 1D numpy array of ``float64`` where the value at position
+///     ``i`` is the betweenness score of the node with index ``i``. If any
+///     nodes have been removed from the graph the positions of their indices
+///     are ``nan``.
+/// :rtype: numpy.ndarray
+#[pyfunction(
+    normalized = "true",
+    endpoints = "false",
+    parallel_threshold = "50",
+    default_weight = "1.0"
+)]
+#[pyo3(
+    text_signature = "(graph, /, normalized=True, endpoints=False, parallel_threshold=50, edge_filter_fn=None, node_mask=None, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_betweenness_centrality_array(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    normalized: bool,
+    endpoints: bool,
+    parallel_threshold: usize,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<PyObject> {
+    let betweenness = betweenness(
+        py,
+        &graph.graph,
+        normalized,
+        endpoints,
+        parallel_threshold,
+        edge_filter_fn,
+        node_mask,
+        weight_fn,
+        default_weight,
+    )?;
+    Ok(dense_centralities(betweenness).into_pyarray(py).into())
+}
+
+/// Compute the closeness centrality for the closeness functions. The
+/// distances are computed with a breadth first search if every edge has the
+/// same positive weight, and with Dijkstra's algorithm otherwise.
+fn closeness<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let closeness = match cost_fn {
+        CostFn::Default(weight) if weight > 0.0 => py.allow_threads(|| {
+            centrality::closeness_centrality(graph, wf_improved, parallel_threshold)
+                .into_iter()
+                .map(|x| x.map(|y| y / weight))
+                .collect::<Vec<Option<f64>>>()
+        }),
+        cost_fn => {
+            let cost_fn = cost_fn.prepare(py, graph)?;
+            let mut weights: Vec<f64> = vec![0.; graph.edge_bound()];
+            for edge in graph.edge_references() {
+                weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+            }
+            py.allow_threads(|| {
+                let res: Result<Vec<Option<f64>>, _> = centrality::weighted_closeness_centrality(
+                    graph,
+                    |e| Ok::<f64, Infallible>(weights[e.id().index()]),
+                    wf_improved,
+                    parallel_threshold,
+                );
+                res.unwrap()
+            })
+        }
+    };
+    Ok(CentralityMapping {
+        centralities: closeness
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
+    })
+}
+
+/// Compute the closeness centrality of all nodes in a PyGraph.
+///
+/// The closeness centrality of a node :math:`u` is the reciprocal of the
+/// average shortest path distance to :math:`u` over the other nodes
+///
+/// .. math::
+///
+///    C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` and :math:`n` is the number of nodes that can reach :math:`u`.
+/// If the graph isn't connected this only considers the nodes in the
+/// same component as :math:`u`, so by default the improved formula of
+/// Wasserman and Faust is used to scale it by the fraction of the other
+/// nodes in that component
+///
+/// .. math::
+///
+///    C_{WF}(u) = \frac{n - 1}{N - 1} \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`N` is the number of nodes in the graph. A node without any
+/// neighbors has a closeness of ``0.0``. The shortest paths are found with
+/// a breadth first search from each node, or with Dijkstra's algorithm if
+/// ``weight_fn`` is set.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(5)
+///   print(retworkx.graph_closeness_centrality(graph))
+///
+/// :param PyGraph graph: The input graph
+/// :param bool wf_improved: Whether to use the improved formula of
+///     Wasserman and Faust for graphs which aren't connected. Default:
+///     ``True``
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float, the distance of a path is then the total
+///     weight of its edges. If not specified every edge has the weight
+///     ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Default: ``1.0``
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the closeness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices
+///     and values are the closeness centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// :raises ValueError: If a weight is negative or NaN
+#[pyfunction(
+    wf_improved = "true",
+    default_weight = "1.0",
+    parallel_threshold = "50"
+)]
+#[pyo3(
+    text_signature = "(graph, /, wf_improved=True, weight_fn=None, default_weight=1.0, parallel_threshold=50)"
+)]
+pub fn graph_closeness_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    closeness(
+        py,
+        &graph.graph,
+        wf_improved,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
+
+/// Compute the closeness centrality of all nodes in a PyDiGraph.
+///
+/// The closeness centrality of a node :math:`u` is the reciprocal of the
+/// average shortest path distance to :math:`u` over the other nodes
+///
+/// .. math::
+///
+///    C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` and :math:`n` is the number of nodes that can reach :math:`u`.
+/// The distances are of the paths ending at :math:`u`, to compute the
+/// closeness with the distances of the paths starting at :math:`u` use
+/// this function on a copy of the graph with every edge reversed. If
+/// not every node can reach :math:`u`, by default the improved formula of
+/// Wasserman and Faust is used to scale the closeness by the fraction of
+/// the other nodes which can reach it
+///
+/// .. math::
+///
+///    C_{WF}(u) = \frac{n - 1}{N - 1} \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`N` is the number of nodes in the graph. A node which no
+/// other node can reach has a closeness of ``0.0``. The shortest paths are
+/// found with a breadth first search from each node, or with Dijkstra's
+/// algorithm if ``weight_fn`` is set.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(5)
+///   print(retworkx.digraph_closeness_centrality(graph))
+///
+/// :param PyDiGraph graph: The input graph
+/// :param bool wf_improved: Whether to use the improved formula of
+///     Wasserman and Faust for nodes which not every node can reach.
+///     Default: ``True``
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float, the distance of a path is then the total
+///     weight of its edges. If not specified every edge has the weight
+///     ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Default: ``1.0``
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the closeness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices
+///     and values are the closeness centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// :raises ValueError: If a weight is negative or NaN
+#[pyfunction(
+    wf_improved = "true",
+    default_weight = "1.0",
+    parallel_threshold = "50"
+)]
+#[pyo3(
+    text_signature = "(graph, /, wf_improved=True, weight_fn=None, default_weight=1.0, parallel_threshold=50)"
+)]
+pub fn digraph_closeness_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    closeness(
+        py,
+        &graph.graph,
+        wf_improved,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
+
+fn eigenvector<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, graph)?;
+    let mut weights: Vec<f64> = vec![0.; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+    let res: Result<Option<Vec<f64>>, _> = py.allow_threads(|| {
+        centrality::eigenvector_centrality(
+            graph,
+            |e| Ok::<f64, Infallible>(weights[e.id().index()]),
+            max_iter,
+            tol,
+        )
+    });
+    match res.unwrap() {
+        Some(centrality) => Ok(CentralityMapping {
+            centralities: graph
+                .node_indices()
+                .map(|node| (node.index(), centrality[node.index()]))
+                .collect(),
+        }),
+        None => Err(convergence_error(
+            py,
+            format!(
+                "Eigenvector centrality failed to converge in {} iterations",
+                max_iter
+            ),
+            max_iter,
+        )),
     }
 }
+
+/// Compute the eigenvector centrality of a :class:`~retworkx.PyGraph`.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the
+/// centralities of its neighbors, so a node is central if it's connected to
+/// other central nodes. The centralities are the entries of the eigenvector
+/// of the weighted adjacency matrix for its largest eigenvalue, found by
+/// power iteration and normalized to a unit Euclidean norm. For a graph
+/// which isn't connected the eigenvector isn't unique, and the result
+/// depends on the number of nodes of each component.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(5)
+///   print(retworkx.graph_eigenvector_centrality(graph))
+///
+/// :param PyGraph graph: The input graph
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If not specified every edge has the weight
+///     ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Default: ``1.0``
+/// :param int max_iter: The maximum number of iterations of the power
+///     method, by default ``100``
+/// :param float tol: The error tolerance used to check for convergence,
+///     the iteration stops when the sum of the absolute changes of all the
+///     centralities is less than the number of nodes times ``tol``. By
+///     default ``1e-6``
+///
+/// :returns: a read-only dict-like object whose keys are the node indices
+///     and values are the eigenvector centrality of each node.
+/// :rtype: CentralityMapping
+/// :raises ConvergenceError: If the power iteration doesn't converge in
+///     ``max_iter`` iterations
+/// :raises ValueError: If a weight is negative or NaN
+#[pyfunction(default_weight = "1.0", max_iter = "100", tol = "1e-6")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6)")]
+pub fn graph_eigenvector_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    eigenvector(py, &graph.graph, weight_fn, default_weight, max_iter, tol)
+}
+
+/// Compute the eigenvector centrality of a :class:`~retworkx.PyDiGraph`.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the
+/// centralities of its predecessors, so a node is central if central nodes
+/// have edges to it. The centralities are the entries of the left
+/// eigenvector of the weighted adjacency matrix for its largest eigenvalue,
+/// found by power iteration and normalized to a unit Euclidean norm. For a
+/// graph which isn't strongly connected the eigenvector isn't unique, and
+/// nodes which can't be reached from a cycle have a centrality of ``0.0``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_cycle_graph(4)
+///   graph.add_edge(0, 2, None)
+///   print(retworkx.digraph_eigenvector_centrality(graph))
+///
+/// :param PyDiGraph graph: The input graph
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of an edge, and returns its weight
+///     as a non-negative float. If not specified every edge has the weight
+///     ``default_weight``.
+/// :param float default_weight: The weight of every edge if ``weight_fn``
+///     isn't specified. Default: ``1.0``
+/// :param int max_iter: The maximum number of iterations of the power
+///     method, by default ``100``
+/// :param float tol: The error tolerance used to check for convergence,
+///     the iteration stops when the sum of the absolute changes of all the
+///     centralities is less than the number of nodes times ``tol``. By
+///     default ``1e-6``
+///
+/// :returns: a read-only dict-like object whose keys are the node indices
+///     and values are the eigenvector centrality of each node.
+/// :rtype: CentralityMapping
+/// :raises ConvergenceError: If the power iteration doesn't converge in
+///     ``max_iter`` iterations
+/// :raises ValueError: If a weight is negative or NaN
+#[pyfunction(default_weight = "1.0", max_iter = "100", tol = "1e-6")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6)")]
+pub fn digraph_eigenvector_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<CentralityMapping> {
+    eigenvector(py, &graph.graph, weight_fn, default_weight, max_iter, tol)
+}
+
+/// Fill the positions of the indices of removed nodes with ``nan``.
+fn dense_centralities(centralities: Vec<Option<f64>>) -> Vec<f64> {
+    centralities
+        .into_iter()
+        .map(|x| x.unwrap_or(f64::NAN))
+        .collect()
+}
@@ -0,0 +1,306 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use crate::graph;
+use crate::iterators::NodeIndices;
+use crate::NodeIndex;
+
+/// The nodes of a graph with contiguous indices and a bitset of the
+/// neighbors of each node, self loops are ignored.
+pub(crate) struct Adjacency {
+    pub(crate) nodes: Vec<NodeIndex>,
+    pub(crate) neighbors: Vec<FixedBitSet>,
+}
+
+impl Adjacency {
+    fn new(graph: &graph::PyGraph) -> Self {
+        let nodes: Vec<NodeIndex> = graph.graph.node_indices().collect();
+        let mut compact: Vec<usize> = vec![0; graph.graph.node_bound()];
+        for (index, node) in nodes.iter().enumerate() {
+            compact[node.index()] = index;
+        }
+        let mut neighbors = vec![FixedBitSet::with_capacity(nodes.len()); nodes.len()];
+        for edge in graph.graph.edge_references() {
+            let source = compact[edge.source().index()];
+            let target = compact[edge.target().index()];
+            if source != target {
+                neighbors[source].insert(target);
+                neighbors[target].insert(source);
+            }
+        }
+        Adjacency { nodes, neighbors }
+    }
+
+    fn intersection(&self, set: &FixedBitSet, node: usize) -> FixedBitSet {
+        let mut out = set.clone();
+        out.intersect_with(&self.neighbors[node]);
+        out
+    }
+}
+
+/// Color the ``candidates`` greedily in order, returning them sorted by
+/// color along with the number of colors used up to each of them.
+///
+/// The number of colors is an upper bound on the size of a clique among the
+/// candidates up to that position.
+fn color_sort(adjacency: &Adjacency, candidates: &[usize]) -> (Vec<usize>, Vec<usize>) {
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    for node in candidates {
+        match classes.iter_mut().find(|class| {
+            class
+                .iter()
+                .all(|other| !adjacency.neighbors[*node][*other])
+        }) {
+            Some(class) => class.push(*node),
+            None => classes.push(vec![*node]),
+        }
+    }
+    let mut order = Vec::with_capacity(candidates.len());
+    let mut bounds = Vec::with_capacity(candidates.len());
+    for (color, class) in classes.into_iter().enumerate() {
+        for node in class {
+            order.push(node);
+            bounds.push(color + 1);
+        }
+    }
+    (order, bounds)
+}
+
+fn expand_clique(
+    adjacency: &Adjacency,
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut bounds: Vec<usize>,
+    best: &mut Vec<usize>,
+) {
+    while let Some(node) = candidates.pop() {
+        let bound = bounds.pop().unwrap();
+        if clique.len() + bound <= best.len() {
+            return;
+        }
+        clique.push(node);
+        let next: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|other| adjacency.neighbors[node][*other])
+            .collect();
+        if next.is_empty() {
+            if clique.len() > best.len() {
+                *best = clique.clone();
+            }
+        } else {
+            let (next, next_bounds) = color_sort(adjacency, &next);
+            expand_clique(adjacency, clique, next, next_bounds, best);
+        }
+        clique.pop();
+    }
+}
+
+/// Find a maximum clique of the graph described by ``adjacency``, returns
+/// the contiguous indices of its nodes.
+pub(crate) fn maximum_clique(adjacency: &Adjacency) -> Vec<usize> {
+    // Visit the nodes with the highest degrees first, the candidates are
+    // popped from the back.
+    let mut initial: Vec<usize> = (0..adjacency.nodes.len()).collect();
+    initial.sort_by_key(|node| adjacency.neighbors[*node].count_ones(..));
+    let (candidates, bounds) = color_sort(adjacency, &initial);
+    let mut best: Vec<usize> = Vec::new();
+    expand_clique(adjacency, &mut Vec::new(), candidates, bounds, &mut best);
+    best
+}
+
+/// Find a maximum clique of a graph
+///
+/// A clique is a set of nodes which are all adjacent to each other, this
+/// finds a clique with the largest number of nodes. Finding a maximum clique
+/// is NP-hard, this uses an exact branch and bound algorithm which bounds
+/// the size of the cliques in each branch with a greedy coloring of the
+/// candidate nodes [1]_. The runtime is exponential in the worst case.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.extend_from_edge_list([(3, 4), (4, 5), (5, 3)])
+///   print(retworkx.max_clique(graph))
+///
+/// :param PyGraph graph: The undirected graph to find a maximum clique of.
+///     Self loops and parallel edges are ignored.
+///
+/// :returns: The node indices of a maximum clique, sorted by index. If the
+///     graph is empty the list is empty.
+/// :rtype: NodeIndices
+///
+/// .. [1] Tomita, Etsuji, and Tomokazu Seki. "An efficient branch-and-bound
+///     algorithm for finding a maximum clique." Discrete Mathematics and
+///     Theoretical Computer Science (2003): 278-289.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn max_clique(py: Python, graph: &graph::PyGraph) -> NodeIndices {
+    let adjacency = Adjacency::new(graph);
+    let best = py.allow_threads(|| maximum_clique(&adjacency));
+    let mut nodes: Vec<usize> = best
+        .into_iter()
+        .map(|node| adjacency.nodes[node].index())
+        .collect();
+    nodes.sort_unstable();
+    NodeIndices { nodes }
+}
+
+/// The state of a level of the Bron–Kerbosch search.
+struct CliqueSearchLevel {
+    /// The nodes adjacent to every node of the current clique.
+    subgraph: FixedBitSet,
+    /// The nodes of ``subgraph`` which can still extend the clique.
+    candidates: FixedBitSet,
+    /// The candidates left to extend the clique with at this level, the
+    /// candidates adjacent to the pivot are skipped.
+    extensions: Vec<usize>,
+}
+
+/// An iterator over the maximal cliques of a graph
+///
+/// This is returned by :func:`~retworkx.find_cliques`, the cliques are found
+/// lazily as the iterator is consumed. Each clique is a list of node indices.
+#[pyclass(module = "retworkx")]
+pub struct CliqueIterator {
+    adjacency: Adjacency,
+    clique: Vec<usize>,
+    stack: Vec<CliqueSearchLevel>,
+}
+
+impl CliqueIterator {
+    fn new(adjacency: Adjacency) -> Self {
+        let mut all = FixedBitSet::with_capacity(adjacency.nodes.len());
+        all.insert_range(..);
+        let mut iter = CliqueIterator {
+            adjacency,
+            clique: Vec::new(),
+            stack: Vec::new(),
+        };
+        if !iter.adjacency.nodes.is_empty() {
+            let level = iter.level(all.clone(), all);
+            iter.stack.push(level);
+        }
+        iter
+    }
+
+    /// Create a search level, choosing the node of ``subgraph`` with the most
+    /// neighbors among ``candidates`` as the pivot.
+    fn level(&self, subgraph: FixedBitSet, candidates: FixedBitSet) -> CliqueSearchLevel {
+        let pivot = subgraph
+            .ones()
+            .max_by_key(|node| {
+                self.adjacency.neighbors[*node]
+                    .intersection(&candidates)
+                    .count()
+            })
+            .unwrap();
+        let extensions = candidates
+            .ones()
+            .filter(|node| !self.adjacency.neighbors[pivot][*node])
+            .collect();
+        CliqueSearchLevel {
+            subgraph,
+            candidates,
+            extensions,
+        }
+    }
+
+    /// Advance the search to the next maximal clique.
+    fn next_clique(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let level = self.stack.last_mut()?;
+            let node = match level.extensions.pop() {
+                Some(node) => node,
+                None => {
+                    self.stack.pop();
+                    self.clique.pop();
+                    continue;
+                }
+            };
+            level.candidates.set(node, false);
+            let subgraph = self.adjacency.intersection(&level.subgraph, node);
+            let candidates = self.adjacency.intersection(&level.candidates, node);
+            if subgraph.count_ones(..) == 0 {
+                let mut clique = self.clique.clone();
+                clique.push(node);
+                return Some(clique);
+            }
+            if candidates.count_ones(..) > 0 {
+                let next_level = self.level(subgraph, candidates);
+                self.clique.push(node);
+                self.stack.push(next_level);
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl CliqueIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<Vec<usize>> {
+        let clique = slf.next_clique()?;
+        let mut nodes: Vec<usize> = clique
+            .into_iter()
+            .map(|node| slf.adjacency.nodes[node].index())
+            .collect();
+        nodes.sort_unstable();
+        Some(nodes)
+    }
+}
+
+/// Find the maximal cliques of a graph
+///
+/// A clique is a set of nodes which are all adjacent to each other, it's
+/// maximal if no other node can be added to it. This uses the Bron–Kerbosch
+/// algorithm with pivoting [1]_, the cliques are found lazily as the returned
+/// iterator is consumed so the search can be stopped early. The number of
+/// maximal cliques of a graph can be exponential in its number of nodes.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.extend_from_edge_list([(3, 4), (4, 5), (5, 3)])
+///   print(sorted(sorted(clique) for clique in retworkx.find_cliques(graph)))
+///
+/// :param PyGraph graph: The undirected graph to find the maximal cliques
+///     of. Self loops and parallel edges are ignored. The graph is copied
+///     when the iterator is created so later changes to it aren't reflected.
+///
+/// :returns: An iterator over the maximal cliques of the graph, each a list
+///     of node indices sorted by index. An isolated node is a maximal clique
+///     by itself.
+/// :rtype: CliqueIterator
+///
+/// .. [1] Tomita, Etsuji, Akira Tanaka, and Haruhisa Takahashi. "The
+///     worst-case time complexity for generating all maximal cliques and
+///     computational experiments." Theoretical Computer Science 363.1
+///     (2006): 28-42.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn find_cliques(graph: &graph::PyGraph) -> CliqueIterator {
+    CliqueIterator::new(Adjacency::new(graph))
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use retworkx_core::dictmap::*;
+
+use crate::{NodeIndex, StablePyGraph};
+
+/// The name of the cluster each node of a graph is assigned to, for the nodes
+/// which are assigned to one.
+pub type Clusters = DictMap<usize, String>;
+
+/// Group the nodes of a graph by the cluster they're assigned to. The
+/// clusters are ordered by their lowest node index and the nodes of each
+/// cluster are sorted.
+pub fn cluster_members(clusters: &Clusters) -> DictMap<String, Vec<usize>> {
+    let mut nodes: Vec<(usize, &String)> =
+        clusters.iter().map(|(node, name)| (*node, name)).collect();
+    nodes.sort_unstable();
+    let mut members: DictMap<String, Vec<usize>> = DictMap::new();
+    for (node, name) in nodes {
+        members.entry(name.clone()).or_default().push(node);
+    }
+    members
+}
+
+/// Build the quotient graph of ``graph`` by its clusters. There's a node for
+/// each cluster, with the name of the cluster as its weight, followed by a
+/// copy of each node which isn't assigned to a cluster. There's an edge
+/// between two nodes of the quotient graph for each pair of them joined by at
+/// least one edge in ``graph``, and its weight is a list of the weights of
+/// those edges or the return of ``edge_weight_fn`` when called with that list.
+/// Edges within a cluster are dropped.
+pub fn quotient_graph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    clusters: &Clusters,
+    edge_weight_fn: Option<PyObject>,
+) -> PyResult<StablePyGraph<Ty>> {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(0, 0);
+    let mut node_map: DictMap<NodeIndex, NodeIndex> = DictMap::with_capacity(graph.node_count());
+    for (name, nodes) in cluster_members(clusters) {
+        let new_node = out_graph.add_node(name.into_py(py));
+        for node in nodes {
+            node_map.insert(NodeIndex::new(node), new_node);
+        }
+    }
+    for node in graph.node_indices() {
+        if !node_map.contains_key(&node) {
+            let new_node = out_graph.add_node(graph[node].clone_ref(py));
+            node_map.insert(node, new_node);
+        }
+    }
+    let mut edge_weights: DictMap<(NodeIndex, NodeIndex), Vec<PyObject>> = DictMap::new();
+    for edge in graph.edge_references() {
+        let mut source = node_map[&edge.source()];
+        let mut target = node_map[&edge.target()];
+        if source == target {
+            continue;
+        }
+        if !Ty::is_directed() && source > target {
+            std::mem::swap(&mut source, &mut target);
+        }
+        edge_weights
+            .entry((source, target))
+            .or_default()
+            .push(edge.weight().clone_ref(py));
+    }
+    for ((source, target), weights) in edge_weights {
+        let weights: PyObject = PyList::new(py, weights).into();
+        let weight = match edge_weight_fn {
+            Some(ref edge_weight_fn) => edge_weight_fn.call1(py, (weights,))?,
+            None => weights,
+        };
+        out_graph.add_edge(source, target, weight);
+    }
+    Ok(out_graph)
+}
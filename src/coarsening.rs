@@ -0,0 +1,449 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::algo;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use retworkx_core::dictmap::*;
+
+use crate::clusters::Clusters;
+use crate::iterators::NodeMap;
+use crate::key_index::KeyIndexes;
+use crate::random_seed::seeded_rng;
+use crate::transaction::MutationLog;
+use crate::{digraph, graph, weight_callable, NodeIndex, StablePyGraph};
+
+/// A weighted graph with contiguous node indices, as used by multilevel
+/// algorithms.
+pub struct CoarseGraph {
+    /// The number of nodes of the original graph contracted into each node.
+    pub node_weights: Vec<usize>,
+    /// The weighted edges of the graph. There's at most one edge between each
+    /// pair of nodes (in each direction if the graph is directed) and there
+    /// are no self loops.
+    pub edges: Vec<(usize, usize, f64)>,
+    pub directed: bool,
+}
+
+/// A level of a coarsening hierarchy.
+pub struct Level {
+    pub graph: CoarseGraph,
+    /// The node of ``graph`` each node of the finer graph is contracted into.
+    pub node_map: Vec<usize>,
+}
+
+impl CoarseGraph {
+    /// Build a coarse graph from ``graph``, with its nodes numbered in node
+    /// index order and its parallel edges merged by adding their weights.
+    pub fn from_graph<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        weight_fn: &Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<Self> {
+        let mut index: Vec<usize> = vec![0; graph.node_bound()];
+        for (count, node) in graph.node_indices().enumerate() {
+            index[node.index()] = count;
+        }
+        let mut edges: Vec<(usize, usize, f64)> = Vec::with_capacity(graph.edge_count());
+        for edge in graph.edge_references() {
+            let weight: f64 = weight_callable(py, weight_fn, edge.weight(), default_weight)?;
+            edges.push((
+                index[edge.source().index()],
+                index[edge.target().index()],
+                weight,
+            ));
+        }
+        Ok(CoarseGraph {
+            node_weights: vec![1; graph.node_count()],
+            edges: merge_edges(edges, Ty::is_directed()),
+            directed: Ty::is_directed(),
+        })
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    /// Match each node with its unmatched neighbor joined by the heaviest
+    /// edge, ignoring the edge directions, visiting the nodes in a random
+    /// order. Returns the node each node is matched with, which is the node
+    /// itself if it's unmatched.
+    pub fn heavy_edge_matching(&self, rng: &mut Pcg64) -> Vec<usize> {
+        let node_count = self.node_count();
+        let mut neighbors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); node_count];
+        for &(source, target, weight) in &self.edges {
+            neighbors[source].push((target, weight));
+            neighbors[target].push((source, weight));
+        }
+        let mut order: Vec<usize> = (0..node_count).collect();
+        order.shuffle(rng);
+        let mut mate: Vec<Option<usize>> = vec![None; node_count];
+        // The total weight of the edges to each neighbor of the node being
+        // matched, so the edges in both directions of a directed graph count.
+        let mut weights: Vec<f64> = vec![0.0; node_count];
+        for node in order {
+            if mate[node].is_some() {
+                continue;
+            }
+            for &(neighbor, weight) in &neighbors[node] {
+                weights[neighbor] += weight;
+            }
+            let mut best: Option<(usize, f64)> = None;
+            for &(neighbor, _) in &neighbors[node] {
+                if mate[neighbor].is_some() {
+                    continue;
+                }
+                let weight = weights[neighbor];
+                best = match best {
+                    Some((best_node, best_weight))
+                        if best_weight > weight
+                            || (best_weight == weight && best_node < neighbor) =>
+                    {
+                        Some((best_node, best_weight))
+                    }
+                    _ => Some((neighbor, weight)),
+                };
+            }
+            for &(neighbor, _) in &neighbors[node] {
+                weights[neighbor] = 0.0;
+            }
+            let other = best.map_or(node, |(neighbor, _)| neighbor);
+            mate[node] = Some(other);
+            mate[other] = Some(node);
+        }
+        mate.into_iter().map(|other| other.unwrap()).collect()
+    }
+
+    /// Contract each pair of matched nodes into a single node. The nodes of
+    /// the contracted graph are numbered in the order of the lowest node of
+    /// each pair.
+    pub fn contract(&self, mate: &[usize]) -> Level {
+        let mut node_map: Vec<Option<usize>> = vec![None; self.node_count()];
+        let mut node_weights: Vec<usize> = Vec::new();
+        for node in 0..self.node_count() {
+            if node_map[node].is_some() {
+                continue;
+            }
+            node_map[node] = Some(node_weights.len());
+            let mut weight = self.node_weights[node];
+            if mate[node] != node {
+                node_map[mate[node]] = Some(node_weights.len());
+                weight += self.node_weights[mate[node]];
+            }
+            node_weights.push(weight);
+        }
+        let node_map: Vec<usize> = node_map.into_iter().map(|node| node.unwrap()).collect();
+        let edges: Vec<(usize, usize, f64)> = self
+            .edges
+            .iter()
+            .map(|&(source, target, weight)| (node_map[source], node_map[target], weight))
+            .collect();
+        Level {
+            graph: CoarseGraph {
+                node_weights,
+                edges: merge_edges(edges, self.directed),
+                directed: self.directed,
+            },
+            node_map,
+        }
+    }
+}
+
+/// Merge parallel edges by adding their weights and drop self loops, keeping
+/// the edges in the order they're first seen.
+fn merge_edges(edges: Vec<(usize, usize, f64)>, directed: bool) -> Vec<(usize, usize, f64)> {
+    let mut merged: DictMap<(usize, usize), f64> = DictMap::with_capacity(edges.len());
+    for (source, target, weight) in edges {
+        if source == target {
+            continue;
+        }
+        let key = if directed || source < target {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        *merged.entry(key).or_insert(0.0) += weight;
+    }
+    merged
+        .into_iter()
+        .map(|((source, target), weight)| (source, target, weight))
+        .collect()
+}
+
+/// Build a hierarchy of progressively coarser graphs by repeatedly
+/// contracting a heavy edge matching. Coarsening stops when the coarsest
+/// graph has at most ``min_nodes`` nodes, after ``max_levels`` levels, or
+/// when a level shrinks the graph by less than 10%, which happens when the
+/// matching can't make progress, such as on a star graph.
+pub fn coarsen(
+    graph: CoarseGraph,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    rng: &mut Pcg64,
+) -> Vec<Level> {
+    let mut levels: Vec<Level> = Vec::new();
+    while levels.len() < max_levels.unwrap_or(usize::MAX) {
+        let current = levels.last().map_or(&graph, |level| &level.graph);
+        let node_count = current.node_count();
+        if node_count <= min_nodes {
+            break;
+        }
+        let mate = current.heavy_edge_matching(rng);
+        let level = current.contract(&mate);
+        let coarse_node_count = level.graph.node_count();
+        if coarse_node_count == node_count {
+            break;
+        }
+        levels.push(level);
+        if (node_count - coarse_node_count) * 10 < node_count {
+            break;
+        }
+    }
+    levels
+}
+
+fn coarsen_graph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<Vec<(StablePyGraph<Ty>, NodeMap)>> {
+    let mut rng = seeded_rng(seed);
+    let coarse_graph = CoarseGraph::from_graph(py, graph, &weight_fn, default_weight)?;
+    let levels = coarsen(coarse_graph, min_nodes, max_levels, &mut rng);
+    let original_nodes: Vec<usize> = graph.node_indices().map(|node| node.index()).collect();
+    Ok(levels
+        .into_iter()
+        .enumerate()
+        .map(|(depth, level)| {
+            let mut out_graph = StablePyGraph::<Ty>::with_capacity(
+                level.graph.node_count(),
+                level.graph.edges.len(),
+            );
+            for weight in &level.graph.node_weights {
+                out_graph.add_node(weight.into_py(py));
+            }
+            for &(source, target, weight) in &level.graph.edges {
+                out_graph.add_edge(
+                    NodeIndex::new(source),
+                    NodeIndex::new(target),
+                    weight.into_py(py),
+                );
+            }
+            let node_map: DictMap<usize, usize> = level
+                .node_map
+                .iter()
+                .enumerate()
+                .map(|(node, coarse_node)| {
+                    if depth == 0 {
+                        (original_nodes[node], *coarse_node)
+                    } else {
+                        (node, *coarse_node)
+                    }
+                })
+                .collect();
+            (out_graph, NodeMap { node_map })
+        })
+        .collect())
+}
+
+/// Build a hierarchy of progressively coarser graphs from a :class:`~retworkx.PyGraph`
+///
+/// Each level of the hierarchy is built by finding a heavy edge matching of
+/// the previous level, visiting its nodes in a random order and matching each
+/// node with the unmatched neighbor it shares the heaviest edge with, and then
+/// contracting each matched pair of nodes into a single node. This is the
+/// coarsening phase of multilevel graph partitioning and layout algorithms
+/// [1]_, which solve a problem on the coarsest graph and then project the
+/// solution back through the levels.
+///
+/// The nodes of each coarse graph have the number of nodes of the original
+/// graph they contain as their weight/data payload, and its edges have the
+/// total weight of the edges they replace as their weight/data payload. Edges
+/// within a contracted node are dropped. Coarsening stops when the coarsest
+/// graph has at most ``min_nodes`` nodes, after ``max_levels`` levels, or
+/// when a level shrinks the graph by less than 10%, which happens when the
+/// matching can't make progress, such as on a star graph.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(4, 4)
+///   levels = retworkx.graph_coarsen(graph, min_nodes=4, seed=42)
+///   for coarse_graph, node_map in levels:
+///       print(coarse_graph.nodes(), dict(node_map))
+///
+/// :param PyGraph graph: The graph to coarsen
+/// :param weight_fn: An optional weight function for the edges. It will be
+///     passed the weight/data payload of an edge and is expected to return a
+///     ``float``. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of each edge if ``weight_fn`` isn't
+///     specified. By default this is ``1.0``.
+/// :param int min_nodes: Stop coarsening once the coarsest graph has at most
+///     this many nodes. By default this is ``1``.
+/// :param int max_levels: The maximum number of levels to build. By default
+///     there's no limit.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A list of the levels of the hierarchy, from the finest to the
+///     coarsest, as tuples of the coarse :class:`~retworkx.PyGraph` and a
+///     :class:`~retworkx.NodeMap` mapping each node index of the previous level
+///     (``graph`` for the first level) to the node it's contracted into. The
+///     list is empty if ``graph`` can't be coarsened.
+/// :rtype: list
+///
+/// .. [1] Hendrickson, Bruce, and Robert Leland. "A Multi-Level Algorithm For
+///     Partitioning Graphs." Supercomputing '95 (1995).
+#[pyfunction(default_weight = "1.0", min_nodes = "1")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, min_nodes=1, max_levels=None, seed=None)"
+)]
+pub fn graph_coarsen(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<Vec<(graph::PyGraph, NodeMap)>> {
+    let levels = coarsen_graph(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        min_nodes,
+        max_levels,
+        seed,
+    )?;
+    Ok(levels
+        .into_iter()
+        .map(|(out_graph, node_map)| {
+            (
+                graph::PyGraph {
+                    graph: out_graph.into(),
+                    node_removed: false,
+                    multigraph: true,
+                    log: MutationLog::default(),
+                    clusters: Clusters::default(),
+                    indexes: KeyIndexes::default(),
+                },
+                node_map,
+            )
+        })
+        .collect())
+}
+
+/// Build a hierarchy of progressively coarser graphs from a :class:`~retworkx.PyDiGraph`
+///
+/// Each level of the hierarchy is built by finding a heavy edge matching of
+/// the previous level, visiting its nodes in a random order and matching each
+/// node with the unmatched neighbor it shares the heaviest edges with, and
+/// then contracting each matched pair of nodes into a single node. The edge
+/// directions are ignored when finding the matching, so the weights of the
+/// edges in both directions between two nodes are added, but they're kept in
+/// the coarse graphs. This is the coarsening phase of multilevel graph
+/// partitioning and layout algorithms [1]_, which solve a problem on the
+/// coarsest graph and then project the solution back through the levels.
+///
+/// The nodes of each coarse graph have the number of nodes of the original
+/// graph they contain as their weight/data payload, and its edges have the
+/// total weight of the edges they replace as their weight/data payload. Edges
+/// within a contracted node are dropped, so a coarse graph of a DAG can
+/// contain cycles. Coarsening stops when the coarsest graph has at most
+/// ``min_nodes`` nodes, after ``max_levels`` levels, or when a level shrinks
+/// the graph by less than 10%, which happens when the matching can't make
+/// progress, such as on a star graph.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(4, 4)
+///   levels = retworkx.digraph_coarsen(graph, min_nodes=4, seed=42)
+///   for coarse_graph, node_map in levels:
+///       print(coarse_graph.nodes(), dict(node_map))
+///
+/// :param PyDiGraph graph: The graph to coarsen
+/// :param weight_fn: An optional weight function for the edges. It will be
+///     passed the weight/data payload of an edge and is expected to return a
+///     ``float``. If not specified ``default_weight`` is used for every edge.
+/// :param float default_weight: The weight of each edge if ``weight_fn`` isn't
+///     specified. By default this is ``1.0``.
+/// :param int min_nodes: Stop coarsening once the coarsest graph has at most
+///     this many nodes. By default this is ``1``.
+/// :param int max_levels: The maximum number of levels to build. By default
+///     there's no limit.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A list of the levels of the hierarchy, from the finest to the
+///     coarsest, as tuples of the coarse :class:`~retworkx.PyDiGraph` and a
+///     :class:`~retworkx.NodeMap` mapping each node index of the previous level
+///     (``graph`` for the first level) to the node it's contracted into. The
+///     list is empty if ``graph`` can't be coarsened.
+/// :rtype: list
+///
+/// .. [1] Hendrickson, Bruce, and Robert Leland. "A Multi-Level Algorithm For
+///     Partitioning Graphs." Supercomputing '95 (1995).
+#[pyfunction(default_weight = "1.0", min_nodes = "1")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, min_nodes=1, max_levels=None, seed=None)"
+)]
+pub fn digraph_coarsen(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<Vec<(digraph::PyDiGraph, NodeMap)>> {
+    let levels = coarsen_graph(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        min_nodes,
+        max_levels,
+        seed,
+    )?;
+    Ok(levels
+        .into_iter()
+        .map(|(out_graph, node_map)| {
+            (
+                digraph::PyDiGraph {
+                    graph: out_graph.into(),
+                    cycle_state: algo::DfsSpace::default(),
+                    check_cycle: false,
+                    node_removed: false,
+                    multigraph: true,
+                    log: MutationLog::default(),
+                    clusters: Clusters::default(),
+                    indexes: KeyIndexes::default(),
+                },
+                node_map,
+            )
+        })
+        .collect())
+}
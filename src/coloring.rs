@@ -20,12 +20,13 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3::Python;
 
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::NodeCount;
 
 use rayon::prelude::*;
 
+use crate::NodeIndex;
+
 /// Color a PyGraph using a largest_first strategy greedy graph coloring.
 ///
 /// :param PyGraph: The input PyGraph object to color
@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::prelude::*;
+
+use petgraph::visit::{EdgeRef, NodeIndexable};
+use petgraph::EdgeType;
+
+use crate::{InvalidNode, NodeIndex, StablePyGraph};
+
+/// An iterator over the simple paths between two nodes of a graph
+///
+/// This is returned by :func:`~retworkx.iter_all_simple_paths` and
+/// :func:`~retworkx.all_simple_edge_paths`, the paths are found lazily as the
+/// iterator is consumed. Each path is a list of node indices or, for
+/// :func:`~retworkx.all_simple_edge_paths`, a list of edge indices.
+#[pyclass(module = "retworkx")]
+pub struct SimplePathIterator {
+    /// The neighbors of each node and the edges to them.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    target: usize,
+    min_nodes: usize,
+    max_nodes: usize,
+    edge_paths: bool,
+    nodes: Vec<usize>,
+    edges: Vec<usize>,
+    visited: FixedBitSet,
+    /// The position in the adjacency list of each node of the path of the
+    /// next neighbor to extend it with.
+    stack: Vec<usize>,
+}
+
+impl SimplePathIterator {
+    /// Snapshot the adjacency of ``graph`` for a search from ``source`` to
+    /// ``target``. ``min_depth`` and ``cutoff`` bound the number of nodes in
+    /// a path. Parallel edges are only followed once unless ``edge_paths``
+    /// is set, so each path of nodes is returned once.
+    pub fn new<Ty: EdgeType>(
+        graph: &StablePyGraph<Ty>,
+        source: usize,
+        target: usize,
+        min_depth: Option<usize>,
+        cutoff: Option<usize>,
+        edge_paths: bool,
+    ) -> PyResult<Self> {
+        if !graph.contains_node(NodeIndex::new(source)) {
+            return Err(InvalidNode::new_err(
+                "The input index for 'from' is not a valid node index",
+            ));
+        }
+        if !graph.contains_node(NodeIndex::new(target)) {
+            return Err(InvalidNode::new_err(
+                "The input index for 'to' is not a valid node index",
+            ));
+        }
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); graph.node_bound()];
+        for node in graph.node_indices() {
+            let mut neighbors: Vec<(usize, usize)> = graph
+                .edges(node)
+                .map(|edge| {
+                    let neighbor = if edge.source() == node {
+                        edge.target()
+                    } else {
+                        edge.source()
+                    };
+                    (neighbor.index(), edge.id().index())
+                })
+                .filter(|(neighbor, _)| *neighbor != node.index())
+                .collect();
+            neighbors.sort_unstable();
+            if !edge_paths {
+                neighbors.dedup_by_key(|(neighbor, _)| *neighbor);
+            }
+            adjacency[node.index()] = neighbors;
+        }
+        let mut visited = FixedBitSet::with_capacity(graph.node_bound());
+        visited.insert(source);
+        Ok(SimplePathIterator {
+            adjacency,
+            target,
+            min_nodes: min_depth.unwrap_or(0),
+            max_nodes: match cutoff {
+                Some(cutoff) if cutoff > 0 => cutoff,
+                _ => usize::MAX,
+            },
+            edge_paths,
+            nodes: vec![source],
+            edges: Vec::new(),
+            visited,
+            stack: vec![0],
+        })
+    }
+
+    /// Advance the search to the next simple path, returning it as either
+    /// node or edge indices.
+    fn next_path(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let node = *self.nodes.last()?;
+            let position = self.stack.last_mut()?;
+            let (neighbor, edge) = match self.adjacency[node].get(*position) {
+                Some(next) => *next,
+                None => {
+                    self.stack.pop();
+                    self.visited.set(node, false);
+                    self.nodes.pop();
+                    self.edges.pop();
+                    continue;
+                }
+            };
+            *position += 1;
+            if self.visited[neighbor] {
+                continue;
+            }
+            let length = self.nodes.len() + 1;
+            if neighbor == self.target {
+                if length >= self.min_nodes && length <= self.max_nodes {
+                    return Some(if self.edge_paths {
+                        let mut path = self.edges.clone();
+                        path.push(edge);
+                        path
+                    } else {
+                        let mut path = self.nodes.clone();
+                        path.push(neighbor);
+                        path
+                    });
+                }
+            } else if length < self.max_nodes {
+                self.visited.insert(neighbor);
+                self.nodes.push(neighbor);
+                self.edges.push(edge);
+                self.stack.push(0);
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl SimplePathIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<Vec<usize>> {
+        slf.next_path()
+    }
+}
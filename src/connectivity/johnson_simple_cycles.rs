@@ -0,0 +1,254 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::{HashMap, HashSet};
+
+use pyo3::prelude::*;
+
+use petgraph::algo;
+use petgraph::graph::DiGraph;
+use petgraph::visit::NodeIndexable;
+
+use crate::digraph;
+use crate::{GraphIx, NodeIndex};
+
+/// The strongly connected components with more than one node of the
+/// subgraph induced by ``nodes``.
+fn strongly_connected(adjacency: &[Vec<usize>], nodes: &[usize]) -> Vec<Vec<usize>> {
+    let mut subgraph: DiGraph<usize, (), GraphIx> = DiGraph::with_capacity(nodes.len(), 0);
+    let local: HashMap<usize, NodeIndex> = nodes
+        .iter()
+        .map(|node| (*node, subgraph.add_node(*node)))
+        .collect();
+    for node in nodes {
+        for neighbor in &adjacency[*node] {
+            if let Some(target) = local.get(neighbor) {
+                subgraph.add_edge(local[node], *target, ());
+            }
+        }
+    }
+    algo::tarjan_scc(&subgraph)
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|component| {
+            let mut component: Vec<usize> =
+                component.into_iter().map(|node| subgraph[node]).collect();
+            component.sort_unstable();
+            component
+        })
+        .collect()
+}
+
+/// The state of the search for the cycles through ``start`` in a strongly
+/// connected ``component`` in which ``start`` is the lowest node.
+struct CycleSearch {
+    start: usize,
+    component: HashSet<usize>,
+    bound: usize,
+    path: Vec<usize>,
+    in_path: HashSet<usize>,
+    /// The position of the next neighbor to visit for each node on the path
+    next: Vec<usize>,
+    /// For each node on the path, a bound on the length of the shortest
+    /// path from it back to ``start`` found so far
+    blen: Vec<usize>,
+    lock: HashMap<usize, usize>,
+    blocked: HashMap<usize, HashSet<usize>>,
+}
+
+impl CycleSearch {
+    fn new(component: Vec<usize>, bound: usize) -> Self {
+        let start = component[0];
+        let bound = bound.min(component.len());
+        let mut lock = HashMap::new();
+        lock.insert(start, 0);
+        let mut in_path = HashSet::new();
+        in_path.insert(start);
+        CycleSearch {
+            start,
+            component: component.into_iter().collect(),
+            bound,
+            path: vec![start],
+            in_path,
+            next: vec![0],
+            blen: vec![bound],
+            lock,
+            blocked: HashMap::new(),
+        }
+    }
+
+    fn lock(&self, node: usize) -> usize {
+        self.lock.get(&node).copied().unwrap_or(self.bound)
+    }
+
+    /// Advance the search to the next cycle, or return ``None`` once all the
+    /// cycles through ``start`` were found.
+    fn next_cycle(&mut self, adjacency: &[Vec<usize>]) -> Option<Vec<usize>> {
+        'search: while let Some(node) = self.path.last().copied() {
+            let depth = self.path.len() - 1;
+            while self.next[depth] < adjacency[node].len() {
+                let neighbor = adjacency[node][self.next[depth]];
+                self.next[depth] += 1;
+                if !self.component.contains(&neighbor) {
+                    continue;
+                }
+                if neighbor == self.start {
+                    self.blen[depth] = 1;
+                    return Some(self.path.clone());
+                }
+                if self.path.len() < self.lock(neighbor) {
+                    self.path.push(neighbor);
+                    self.in_path.insert(neighbor);
+                    self.next.push(0);
+                    self.blen.push(self.bound);
+                    self.lock.insert(neighbor, self.path.len());
+                    continue 'search;
+                }
+            }
+            // Backtrack, all the neighbors of node were visited
+            self.path.pop();
+            self.in_path.remove(&node);
+            self.next.pop();
+            let length = self.blen.pop().unwrap();
+            if let Some(parent_length) = self.blen.last_mut() {
+                *parent_length = (*parent_length).min(length);
+            }
+            if length < self.bound {
+                // Node is on a short enough path back to start, relax the
+                // locks of the nodes blocked by it
+                let mut relax = vec![(length, node)];
+                while let Some((length, blocked)) = relax.pop() {
+                    if self.lock(blocked) < self.bound - length + 1 {
+                        self.lock.insert(blocked, self.bound - length + 1);
+                        if let Some(nodes) = self.blocked.get(&blocked) {
+                            relax.extend(
+                                nodes
+                                    .iter()
+                                    .filter(|other| !self.in_path.contains(*other))
+                                    .map(|other| (length + 1, *other)),
+                            );
+                        }
+                    }
+                }
+            } else {
+                for neighbor in &adjacency[node] {
+                    if self.component.contains(neighbor) {
+                        self.blocked
+                            .entry(*neighbor)
+                            .or_insert_with(HashSet::new)
+                            .insert(node);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the simple cycles of a directed graph
+///
+/// This is returned by :func:`~retworkx.simple_cycles`, the cycles are found
+/// lazily as the iterator is consumed. Each cycle is a list of node indices.
+#[pyclass(module = "retworkx")]
+pub struct SimpleCycleIterator {
+    adjacency: Vec<Vec<usize>>,
+    self_loops: Vec<usize>,
+    components: Vec<Vec<usize>>,
+    bound: usize,
+    search: Option<CycleSearch>,
+}
+
+impl SimpleCycleIterator {
+    pub fn new(graph: &digraph::PyDiGraph, length_bound: Option<usize>) -> Self {
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); graph.graph.node_bound()];
+        let mut self_loops: Vec<usize> = Vec::new();
+        for node in graph.graph.node_indices() {
+            let mut neighbors: Vec<usize> = graph
+                .graph
+                .neighbors(node)
+                .map(|neighbor| neighbor.index())
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            if let Ok(position) = neighbors.binary_search(&node.index()) {
+                neighbors.remove(position);
+                self_loops.push(node.index());
+            }
+            adjacency[node.index()] = neighbors;
+        }
+        let bound = length_bound.unwrap_or_else(|| graph.graph.node_count());
+        if bound == 0 {
+            self_loops.clear();
+        }
+        let components = if bound > 1 {
+            let nodes: Vec<usize> = graph
+                .graph
+                .node_indices()
+                .map(|node| node.index())
+                .collect();
+            let mut components = strongly_connected(&adjacency, &nodes);
+            // Search the components with the lowest nodes first
+            components.reverse();
+            components
+        } else {
+            Vec::new()
+        };
+        // Yield the self loops in node index order before the other cycles
+        self_loops.reverse();
+        SimpleCycleIterator {
+            adjacency,
+            self_loops,
+            components,
+            bound,
+            search: None,
+        }
+    }
+
+    fn next_cycle(&mut self) -> Option<Vec<usize>> {
+        if let Some(node) = self.self_loops.pop() {
+            return Some(vec![node]);
+        }
+        loop {
+            if let Some(search) = self.search.as_mut() {
+                if let Some(cycle) = search.next_cycle(&self.adjacency) {
+                    return Some(cycle);
+                }
+                // All the cycles through the start node were found, search
+                // the rest of its component without it
+                let search = self.search.take().unwrap();
+                let start = search.start;
+                let mut rest: Vec<usize> = search
+                    .component
+                    .into_iter()
+                    .filter(|node| *node != start)
+                    .collect();
+                rest.sort_unstable();
+                let mut components = strongly_connected(&self.adjacency, &rest);
+                components.reverse();
+                self.components.extend(components);
+            }
+            let component = self.components.pop()?;
+            self.search = Some(CycleSearch::new(component, self.bound));
+        }
+    }
+}
+
+#[pymethods]
+impl SimpleCycleIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<Vec<usize>> {
+        slf.next_cycle()
+    }
+}
@@ -0,0 +1,241 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::convert::Infallible;
+
+use hashbrown::HashMap;
+
+use fixedbitset::FixedBitSet;
+use petgraph::graph::UnGraph;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::Undirected;
+
+use rayon::prelude::*;
+
+use retworkx_core::shortest_path::dijkstra_with_predecessors;
+
+use crate::{EdgeIndex, GraphIx, NodeIndex, StablePyGraph};
+
+/// A cycle as the sequence of its nodes, each paired with the edge leading
+/// from it to the next node of the cycle.
+pub type Cycle = Vec<(NodeIndex, EdgeIndex)>;
+
+/// Find a cycle in the union of the edges ``edges`` whose intersection with
+/// the edges marked in ``in_witness`` is odd. Every node must be incident to
+/// an even number of the edges and their intersection must be odd.
+fn odd_cycle(graph: &StablePyGraph<Undirected>, edges: &[EdgeIndex], in_witness: &[bool]) -> Cycle {
+    let mut incident: HashMap<NodeIndex, Vec<EdgeIndex>> = HashMap::new();
+    for edge in edges {
+        let (source, target) = graph.edge_endpoints(*edge).unwrap();
+        incident.entry(source).or_insert_with(Vec::new).push(*edge);
+        if source != target {
+            incident.entry(target).or_insert_with(Vec::new).push(*edge);
+        }
+    }
+    let mut used: HashMap<EdgeIndex, bool> = edges.iter().map(|edge| (*edge, false)).collect();
+    let mut path_nodes: Vec<NodeIndex> = Vec::new();
+    let mut path_edges: Vec<EdgeIndex> = Vec::new();
+    let mut position: HashMap<NodeIndex, usize> = HashMap::new();
+    loop {
+        let current = match path_nodes.last() {
+            Some(node) => *node,
+            None => {
+                let start = graph
+                    .edge_endpoints(*edges.iter().find(|e| !used[e]).unwrap())
+                    .unwrap()
+                    .0;
+                path_nodes.push(start);
+                position.insert(start, 0);
+                start
+            }
+        };
+        let next_edge = incident[&current].iter().find(|e| !used[e]).copied();
+        let edge = match next_edge {
+            Some(edge) => edge,
+            None => {
+                path_nodes.clear();
+                path_edges.clear();
+                position.clear();
+                continue;
+            }
+        };
+        used.insert(edge, true);
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        let next = if source == current { target } else { source };
+        match position.get(&next).copied() {
+            Some(index) => {
+                // Walking back to a node on the path closes a cycle
+                path_edges.push(edge);
+                let cycle: Cycle = path_nodes[index..]
+                    .iter()
+                    .copied()
+                    .zip(path_edges[index..].iter().copied())
+                    .collect();
+                let parity = cycle
+                    .iter()
+                    .filter(|(_, edge)| in_witness[edge.index()])
+                    .count();
+                if parity % 2 == 1 {
+                    return cycle;
+                }
+                for node in path_nodes.drain(index + 1..) {
+                    position.remove(&node);
+                }
+                path_edges.truncate(index);
+            }
+            None => {
+                position.insert(next, path_nodes.len());
+                path_nodes.push(next);
+                path_edges.push(edge);
+            }
+        }
+    }
+}
+
+/// Find the shortest closed walk from ``start`` with an odd intersection
+/// with the edges marked in ``in_witness``. This is a shortest path from the
+/// even copy of ``start`` to its odd copy in ``lifted``, the graph with an
+/// even and odd copy of every node in which the marked edges switch between
+/// the copies.
+fn shortest_odd_walk(
+    lifted: &UnGraph<(), EdgeIndex, GraphIx>,
+    weights: &[f64],
+    start: NodeIndex,
+) -> (f64, Vec<EdgeIndex>) {
+    let even = NodeIndex::new(2 * start.index());
+    let odd = NodeIndex::new(2 * start.index() + 1);
+    let res: Result<(Vec<Option<f64>>, _), _> =
+        dijkstra_with_predecessors(lifted, even, Some(odd), |e| {
+            Ok::<f64, Infallible>(weights[e.weight().index()])
+        });
+    let (scores, predecessors) = res.unwrap();
+    // There is always a path, through an edge of the witness and back along
+    // the spanning tree
+    let cost = scores[odd.index()].unwrap();
+    let mut walk = Vec::new();
+    let mut node = odd;
+    while let Some(previous) = predecessors.get(&node) {
+        // Use the lightest of the parallel edges joining the two copies
+        let edge = lifted
+            .edges_connecting(*previous, node)
+            .map(|e| *e.weight())
+            .min_by(|a, b| {
+                weights[a.index()]
+                    .partial_cmp(&weights[b.index()])
+                    .unwrap_or(Ordering::Less)
+                    .then(a.cmp(b))
+            })
+            .unwrap();
+        walk.push(edge);
+        node = *previous;
+    }
+    (cost, walk)
+}
+
+/// Find a minimum weight cycle basis of a graph with the algorithm of
+/// de Pina, ``weights`` is indexed by edge index and must not be negative.
+///
+/// The cycles are found one at a time, each is the lightest cycle with an
+/// odd intersection with a witness set of edges which is orthogonal to all
+/// the cycles found before it.
+pub fn minimum_cycle_basis(graph: &StablePyGraph<Undirected>, weights: &[f64]) -> Vec<Cycle> {
+    // The edges outside of a spanning forest, there is one cycle in the
+    // basis for each of them.
+    let mut forest = UnionFind::new(graph.node_bound());
+    let non_tree: Vec<EdgeIndex> = graph
+        .edge_references()
+        .filter(|edge| !forest.union(edge.source().index(), edge.target().index()))
+        .map(|edge| edge.id())
+        .collect();
+    let mut witnesses: Vec<FixedBitSet> = (0..non_tree.len())
+        .map(|position| {
+            let mut witness = FixedBitSet::with_capacity(non_tree.len());
+            witness.insert(position);
+            witness
+        })
+        .collect();
+    let mut cycles: Vec<Cycle> = Vec::with_capacity(non_tree.len());
+    for index in 0..non_tree.len() {
+        let mut in_witness = vec![false; graph.edge_bound()];
+        for position in witnesses[index].ones() {
+            in_witness[non_tree[position].index()] = true;
+        }
+        let mut lifted: UnGraph<(), EdgeIndex, GraphIx> =
+            UnGraph::with_capacity(2 * graph.node_bound(), 2 * graph.edge_count());
+        for _ in 0..2 * graph.node_bound() {
+            lifted.add_node(());
+        }
+        for edge in graph.edge_references() {
+            let source = 2 * edge.source().index();
+            let target = 2 * edge.target().index();
+            let id = edge.id();
+            if in_witness[id.index()] {
+                lifted.add_edge(NodeIndex::new(source), NodeIndex::new(target + 1), id);
+                lifted.add_edge(NodeIndex::new(source + 1), NodeIndex::new(target), id);
+            } else {
+                lifted.add_edge(NodeIndex::new(source), NodeIndex::new(target), id);
+                lifted.add_edge(NodeIndex::new(source + 1), NodeIndex::new(target + 1), id);
+            }
+        }
+        // An odd cycle contains an edge of the witness, so it's enough to
+        // search from their endpoints.
+        let mut starts: Vec<NodeIndex> = witnesses[index]
+            .ones()
+            .flat_map(|position| {
+                let (source, target) = graph.edge_endpoints(non_tree[position]).unwrap();
+                vec![source, target]
+            })
+            .collect();
+        starts.sort_unstable();
+        starts.dedup();
+        let (_, walk) = starts
+            .par_iter()
+            .map(|start| shortest_odd_walk(&lifted, weights, *start))
+            .reduce_with(|a, b| match b.0.partial_cmp(&a.0) {
+                Some(Ordering::Less) => b,
+                _ => a,
+            })
+            .unwrap();
+        // The shortest walk is a cycle unless some edges have zero weight,
+        // drop the edges it traverses twice and split what remains.
+        let mut counts: HashMap<EdgeIndex, usize> = HashMap::new();
+        for edge in walk {
+            *counts.entry(edge).or_insert(0) += 1;
+        }
+        let mut edges: Vec<EdgeIndex> = counts
+            .into_iter()
+            .filter(|(_, count)| count % 2 == 1)
+            .map(|(edge, _)| edge)
+            .collect();
+        edges.sort_unstable();
+        let cycle = odd_cycle(graph, &edges, &in_witness);
+
+        // Keep the remaining witnesses orthogonal to the new cycle
+        let witness = witnesses[index].clone();
+        for other in witnesses[index + 1..].iter_mut() {
+            let parity = cycle
+                .iter()
+                .filter(|(_, edge)| match non_tree.binary_search(edge) {
+                    Ok(position) => other.contains(position),
+                    Err(_) => false,
+                })
+                .count();
+            if parity % 2 == 1 {
+                other.symmetric_difference_with(&witness);
+            }
+        }
+        cycles.push(cycle);
+    }
+    cycles
+}
@@ -12,20 +12,32 @@
 
 #![allow(clippy::float_cmp)]
 
+pub mod all_simple_paths;
 mod conn_components;
 mod core_number;
+pub mod johnson_simple_cycles;
+mod min_cycle_basis;
+mod power;
+mod truss_number;
 
-use super::{digraph, get_edge_iter_with_weights, graph, weight_callable, InvalidNode, NullGraph};
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+
+use super::{
+    digraph, get_edge_iter_with_weights, graph, weight_callable, CostFn, InvalidNode, NodeIndex,
+    NullGraph, StablePyGraph,
+};
 
 use hashbrown::{HashMap, HashSet};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::algo;
-use petgraph::graph::NodeIndex;
 use petgraph::unionfind::UnionFind;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeCount, NodeIndexable, Visitable};
+use petgraph::EdgeType;
 
 use ndarray::prelude::*;
 use numpy::IntoPyArray;
@@ -61,11 +73,13 @@ use retworkx_core::connectivity;
 #[pyo3(text_signature = "(graph, /, root=None)")]
 pub fn cycle_basis(graph: &graph::PyGraph, root: Option<usize>) -> Vec<Vec<usize>> {
     let mut root_node = root;
-    let mut graph_nodes: HashSet<NodeIndex> = graph.graph.node_indices().collect();
+    // A BTreeSet so the next root is always the lowest unexamined node index
+    // and the output doesn't depend on hashing.
+    let mut graph_nodes: BTreeSet<NodeIndex> = graph.graph.node_indices().collect();
     let mut cycles: Vec<Vec<usize>> = Vec::new();
     while !graph_nodes.is_empty() {
         let temp_value: NodeIndex;
-        // If root_node is not set get an arbitrary node from the set of graph
+        // If root_node is not set get the lowest node from the set of graph
         // nodes we've not "examined"
         let root_index = match root_node {
             Some(root_value) => NodeIndex::new(root_value),
@@ -115,16 +129,128 @@ pub fn cycle_basis(graph: &graph::PyGraph, root: Option<usize>) -> Vec<Vec<usize
                 }
             }
         }
-        let mut temp_hashset: HashSet<NodeIndex> = HashSet::new();
-        for key in pred.keys() {
-            temp_hashset.insert(*key);
-        }
-        graph_nodes = graph_nodes.difference(&temp_hashset).copied().collect();
+        graph_nodes.retain(|node| !pred.contains_key(node));
         root_node = None;
     }
     cycles
 }
 
+/// Return a minimum weight cycle basis of a PyGraph
+///
+/// A cycle basis is a minimal collection of cycles such that any cycle of
+/// the graph can be written as a sum of cycles in the basis, where the sum
+/// of cycles is the exclusive or of their edges. A minimum weight cycle
+/// basis is a cycle basis with the least total weight of the edges of its
+/// cycles. It has one cycle for each edge outside of a spanning forest of
+/// the graph, unlike :func:`~retworkx.cycle_basis` self loops and parallel
+/// edges are supported.
+///
+/// This uses the algorithm of de Pina [1]_, which finds the cycles one at a
+/// time as the lightest cycle with an odd number of edges in a witness set
+/// orthogonal to all the cycles found before it, with a shortest path search
+/// in a graph with two copies of every node.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   print(retworkx.minimum_cycle_basis(graph))
+///
+/// :param PyGraph graph: The graph to find the minimum cycle basis of
+/// :param weight_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``. The
+///     weights must not be negative. If not specified every edge has the
+///     weight ``default_weight``.
+/// :param float default_weight: If ``weight_fn`` is not specified this is the
+///     weight used for every edge, defaults to ``1.0``
+/// :param bool edge_indices: If ``True`` each cycle is returned as a list of
+///     the indices of its edges instead of a list of its nodes, defaults to
+///     ``False``
+///
+/// :returns: A list of cycles, each is a list of node indices (or edge
+///     indices) in the order they're traversed around the cycle
+/// :rtype: list
+///
+/// .. [1] de Pina, J. C. Applications of shortest path methods. PhD thesis,
+///    University of Amsterdam, 1995.
+#[pyfunction(default_weight = "1.0", edge_indices = "false")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, edge_indices=False)")]
+pub fn minimum_cycle_basis(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    edge_indices: bool,
+) -> PyResult<Vec<Vec<usize>>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    let mut weights: Vec<f64> = vec![0.; petgraph::visit::EdgeIndexable::edge_bound(&graph.graph)];
+    for edge in graph.graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+    let cycles = py.allow_threads(|| min_cycle_basis::minimum_cycle_basis(&graph.graph, &weights));
+    Ok(cycles
+        .into_iter()
+        .map(|cycle| {
+            cycle
+                .into_iter()
+                .map(|(node, edge)| {
+                    if edge_indices {
+                        edge.index()
+                    } else {
+                        node.index()
+                    }
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Find the simple cycles of a directed graph
+///
+/// A simple cycle, or elementary circuit, is a closed path in which no node
+/// appears twice. Two cycles which are cyclic permutations of each other are
+/// the same cycle, so each is returned once. The cycles are found lazily as
+/// the returned iterator is consumed, the number of simple cycles of a graph
+/// can be exponential in its number of nodes.
+///
+/// The search is done with Johnson's algorithm [1]_ on each strongly
+/// connected component of the graph, using the blocking scheme of Gupta and
+/// Suzumura [2]_ which also supports bounding the length of the cycles.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 0), (0, 1), (1, 2), (2, 0), (2, 1), (1, 0)])
+///   print(list(retworkx.simple_cycles(graph)))
+///   print(list(retworkx.simple_cycles(graph, length_bound=2)))
+///
+/// :param PyDiGraph graph: The directed graph to find the simple cycles of.
+///     Parallel edges are ignored and a self loop is a cycle of a single
+///     node. The graph is copied when the iterator is created so later
+///     changes to it aren't reflected.
+/// :param int length_bound: An optional maximum number of nodes in the
+///     returned cycles, if not specified all the simple cycles are returned
+///
+/// :returns: An iterator over the simple cycles of the graph, each a list of
+///     node indices in the order they're traversed
+/// :rtype: SimpleCycleIterator
+///
+/// .. [1] Johnson, Donald B. "Finding all the elementary circuits of a
+///    directed graph." SIAM Journal on Computing 4.1 (1975): 77-84.
+/// .. [2] Gupta, Anshul, and Toyotaro Suzumura. "Finding All Bounded-Length
+///    Simple Cycles in a Directed Graph." arXiv:2105.10094 (2021).
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, length_bound=None)")]
+pub fn simple_cycles(
+    graph: &digraph::PyDiGraph,
+    length_bound: Option<usize>,
+) -> johnson_simple_cycles::SimpleCycleIterator {
+    johnson_simple_cycles::SimpleCycleIterator::new(graph, length_bound)
+}
+
 /// Compute the strongly connected components for a directed graph
 ///
 /// This function is implemented using Kosaraju's algorithm
@@ -132,14 +258,20 @@ pub fn cycle_basis(graph: &graph::PyGraph, root: Option<usize>) -> Vec<Vec<usize
 /// :param PyDiGraph graph: The input graph to find the strongly connected
 ///     components for.
 ///
-/// :return: A list of list of node ids for strongly connected components
+/// :return: A list of list of node ids for strongly connected components,
+///     in reverse topological order of the components. The node ids of each
+///     component are sorted.
 /// :rtype: list
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn strongly_connected_components(graph: &digraph::PyDiGraph) -> Vec<Vec<usize>> {
     algo::kosaraju_scc(&graph.graph)
         .iter()
-        .map(|x| x.iter().map(|id| id.index()).collect())
+        .map(|x| {
+            let mut component: Vec<usize> = x.iter().map(|id| id.index()).collect();
+            component.sort_unstable();
+            component
+        })
         .collect()
 }
 
@@ -147,8 +279,9 @@ pub fn strongly_connected_components(graph: &digraph::PyDiGraph) -> Vec<Vec<usiz
 /// empty list is returned if no cycle is found
 ///
 /// :param PyDiGraph graph: The graph to find the cycle in
-/// :param int source: Optional index to find a cycle for. If not specified an
-///     arbitrary node will be selected from the graph.
+/// :param int source: Optional index to find a cycle for. If not specified
+///     the node with the lowest index is used, and an empty list is returned
+///     for a graph without nodes.
 ///
 /// :returns: A list describing the cycle. The index of node ids which
 ///     forms a cycle (loop) in the input graph
@@ -156,18 +289,15 @@ pub fn strongly_connected_components(graph: &digraph::PyDiGraph) -> Vec<Vec<usiz
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /, source=None)")]
 pub fn digraph_find_cycle(graph: &digraph::PyDiGraph, source: Option<usize>) -> EdgeList {
-    let mut graph_nodes: HashSet<NodeIndex> = graph.graph.node_indices().collect();
     let mut cycle: Vec<(usize, usize)> = Vec::with_capacity(graph.graph.edge_count());
-    let temp_value: NodeIndex;
-    // If source is not set get an arbitrary node from the set of graph
-    // nodes we've not "examined"
+    // If source is not set start from the lowest node index, a graph without
+    // nodes has no cycle
     let source_index = match source {
         Some(source_value) => NodeIndex::new(source_value),
-        None => {
-            temp_value = *graph_nodes.iter().next().unwrap();
-            graph_nodes.remove(&temp_value);
-            temp_value
-        }
+        None => match graph.graph.node_indices().next() {
+            Some(node) => node,
+            None => return EdgeList { edges: cycle },
+        },
     };
 
     // Stack (ie "pushdown list") of vertices already in the spanning tree
@@ -234,15 +364,32 @@ pub fn number_connected_components(graph: &graph::PyGraph) -> usize {
 
 /// Find the connected components in an undirected graph
 ///
+/// The components are found with a concurrent union-find of the nodes of
+/// the graph. This function is multithreaded and will run in parallel if the
+/// number of nodes in the graph is above the value of ``parallel_threshold``
+/// (it defaults to 100000). If the function will be running in parallel the
+/// env var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will
+/// be used.
+///
 /// :param PyGraph graph: The graph to find the connected components.
+/// :param int parallel_threshold: The number of nodes to find the components
+///     in parallel at, if the number of nodes in the graph is less than this
+///     value it will run in a single thread. The default value is 100000
 ///
 /// :returns: A list of sets where each set is a connected component of
 ///     the graph
 /// :rtype: list
-#[pyfunction]
-#[pyo3(text_signature = "(graph, /)")]
-pub fn connected_components(graph: &graph::PyGraph) -> Vec<HashSet<usize>> {
-    conn_components::connected_components(&graph.graph)
+#[pyfunction(parallel_threshold = "100000")]
+#[pyo3(text_signature = "(graph, /, parallel_threshold=100000)")]
+pub fn connected_components(
+    py: Python,
+    graph: &graph::PyGraph,
+    parallel_threshold: usize,
+) -> Vec<HashSet<usize>> {
+    py.allow_threads(|| connectivity::connected_components(&graph.graph, parallel_threshold))
+        .into_iter()
+        .map(|component| component.into_iter().map(|node| node.index()).collect())
+        .collect()
 }
 
 /// Returns the set of nodes in the component of graph containing `node`.
@@ -450,6 +597,137 @@ pub fn graph_adjacency_matrix(
     Ok(matrix.into_pyarray(py).into())
 }
 
+fn modularity_matrix<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<PyObject> {
+    let n = graph.node_count();
+    let mut matrix = Array2::<f64>::zeros((n, n));
+    for (i, j, weight) in get_edge_iter_with_weights(graph) {
+        let edge_weight = weight_callable(py, &weight_fn, &weight, default_weight)?;
+        matrix[[i, j]] += edge_weight;
+        if !Ty::is_directed() && i != j {
+            matrix[[j, i]] += edge_weight;
+        }
+    }
+    // The sum of the adjacency matrix is the total weight of the edges of a
+    // directed graph and twice it for an undirected graph.
+    let total = matrix.sum();
+    if total != 0.0 {
+        let out_degree = matrix.sum_axis(Axis(1));
+        let in_degree = matrix.sum_axis(Axis(0));
+        for ((i, j), value) in matrix.indexed_iter_mut() {
+            *value -= resolution * out_degree[i] * in_degree[j] / total;
+        }
+    }
+    Ok(matrix.into_pyarray(py).into())
+}
+
+/// Return the modularity matrix of a PyGraph
+///
+/// The modularity matrix :math:`B` of an undirected graph is
+///
+/// .. math::
+///
+///     B_{ij} = A_{ij} - \gamma \frac{k_i k_j}{2m}
+///
+/// where :math:`A` is the adjacency matrix, :math:`k_i` is the weighted
+/// degree of node :math:`i`, :math:`m` is the total weight of the edges and
+/// :math:`\gamma` is the resolution [1]_. The modularity of a partition of
+/// the nodes into communities is the sum of :math:`B_{ij}` over the pairs
+/// of nodes in the same community divided by :math:`2m`, so the leading
+/// eigenvectors of :math:`B` are used for spectral community detection. A
+/// self loop adds its weight to :math:`A_{ii}` once. If the graph has no
+/// edges every entry is ``0.0``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(3)
+///   print(retworkx.graph_modularity_matrix(graph))
+///
+/// :param PyGraph graph: The graph to compute the modularity matrix of
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the weight of the edge. The weights of parallel
+///     edges are summed.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+/// :param float resolution: The resolution parameter :math:`\gamma`, values
+///     larger than 1 favor smaller communities. By default this is ``1.0``.
+///
+/// :returns: The modularity matrix, the rows and columns are in node index
+///     order without the indices of any removed nodes
+/// :rtype: numpy.ndarray
+///
+/// .. [1] Newman, M. E. J. "Modularity and community structure in networks."
+///    Proceedings of the National Academy of Sciences 103.23 (2006): 8577-8582.
+#[pyfunction(default_weight = "1.0", resolution = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, resolution=1.0)")]
+pub fn graph_modularity_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<PyObject> {
+    modularity_matrix(py, &graph.graph, weight_fn, default_weight, resolution)
+}
+
+/// Return the modularity matrix of a PyDiGraph
+///
+/// The modularity matrix :math:`B` of a directed graph is
+///
+/// .. math::
+///
+///     B_{ij} = A_{ij} - \gamma \frac{k_i^{out} k_j^{in}}{m}
+///
+/// where :math:`A` is the adjacency matrix, :math:`k_i^{out}` and
+/// :math:`k_j^{in}` are the weighted out degree of node :math:`i` and in
+/// degree of node :math:`j`, :math:`m` is the total weight of the edges and
+/// :math:`\gamma` is the resolution [1]_. The matrix isn't symmetric, for
+/// spectral community detection the symmetric matrix :math:`B + B^T` is
+/// used. If the graph has no edges every entry is ``0.0``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(3)
+///   print(retworkx.digraph_modularity_matrix(graph))
+///
+/// :param PyDiGraph graph: The graph to compute the modularity matrix of
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the weight of the edge. The weights of parallel
+///     edges are summed.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+/// :param float resolution: The resolution parameter :math:`\gamma`, values
+///     larger than 1 favor smaller communities. By default this is ``1.0``.
+///
+/// :returns: The modularity matrix, the rows and columns are in node index
+///     order without the indices of any removed nodes
+/// :rtype: numpy.ndarray
+///
+/// .. [1] Leicht, E. A., and M. E. J. Newman. "Community structure in
+///    directed networks." Physical Review Letters 100.11 (2008): 118703.
+#[pyfunction(default_weight = "1.0", resolution = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, resolution=1.0)")]
+pub fn digraph_modularity_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<PyObject> {
+    modularity_matrix(py, &graph.graph, weight_fn, default_weight, resolution)
+}
+
 /// Compute the complement of an undirected graph.
 ///
 /// :param PyGraph graph: The graph to be used.
@@ -517,6 +795,101 @@ pub fn digraph_complement(py: Python, graph: &digraph::PyDiGraph) -> PyResult<di
     Ok(complement_graph)
 }
 
+/// Compute the k-th power of an undirected graph.
+///
+/// The k-th power of a graph has the same nodes as the graph and an edge
+/// between every pair of distinct nodes which are at most ``k`` edges apart
+/// in the graph. The nodes within distance ``k`` of every node are found with
+/// a breadth first search truncated at depth ``k``, so this takes
+/// :math:`O(|V|(|V| + |E|))` time in the worst case.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(5)
+///   square = retworkx.graph_power(graph, 2)
+///   print(square.edge_list())
+///
+/// :param PyGraph graph: The graph to be used.
+/// :param int k: The maximum distance between two nodes joined by an edge,
+///     it must be at least 1.
+///
+/// :returns: The k-th power of the graph. The node indices and node
+///     weight/data payloads are the same as in ``graph``, and every edge has
+///     a weight/data payload of ``None``.
+/// :rtype: PyGraph
+/// :raises ValueError: If ``k`` is 0
+///
+/// .. note::
+///
+///     Parallel edges and self-loops are never created,
+///     even if the :attr:`~retworkx.PyGraph.multigraph`
+///     attribute is set to ``True``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn graph_power(py: Python, graph: &graph::PyGraph, k: usize) -> PyResult<graph::PyGraph> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    let mut power_graph = graph.clone(); // keep same node indices
+    power_graph.graph.clear_edges();
+    for (source, target) in power::power_edges(&graph.graph, k) {
+        power_graph.graph.add_edge(source, target, py.None());
+    }
+    Ok(power_graph)
+}
+
+/// Compute the k-th power of a directed graph.
+///
+/// The k-th power of a directed graph has the same nodes as the graph and an
+/// edge from every node to each other node which can be reached from it by
+/// following at most ``k`` edges in the graph. The nodes reachable within
+/// ``k`` edges of every node are found with a breadth first search truncated
+/// at depth ``k``, so this takes :math:`O(|V|(|V| + |E|))` time in the worst
+/// case.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(5)
+///   square = retworkx.digraph_power(graph, 2)
+///   print(square.edge_list())
+///
+/// :param PyDiGraph graph: The graph to be used.
+/// :param int k: The maximum number of edges on a path from one node to
+///     another for them to be joined by an edge, it must be at least 1.
+///
+/// :returns: The k-th power of the graph. The node indices and node
+///     weight/data payloads are the same as in ``graph``, and every edge has
+///     a weight/data payload of ``None``.
+/// :rtype: :class:`~retworkx.PyDiGraph`
+/// :raises ValueError: If ``k`` is 0
+///
+/// .. note::
+///
+///     Parallel edges and self-loops are never created,
+///     even if the :attr:`~retworkx.PyDiGraph.multigraph`
+///     attribute is set to ``True``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn digraph_power(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    k: usize,
+) -> PyResult<digraph::PyDiGraph> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    let mut power_graph = graph.clone(); // keep same node indices
+    power_graph.graph.clear_edges();
+    for (source, target) in power::power_edges(&graph.graph, k) {
+        power_graph.graph.add_edge(source, target, py.None());
+    }
+    Ok(power_graph)
+}
+
 /// Return all simple paths between 2 nodes in a PyGraph object
 ///
 /// A simple path is a path with no repeated nodes.
@@ -625,6 +998,172 @@ pub fn digraph_all_simple_paths(
     Ok(result)
 }
 
+/// Return an iterator over the simple paths between 2 nodes in a PyGraph
+/// object
+///
+/// A simple path is a path with no repeated nodes. Unlike
+/// :func:`~retworkx.graph_all_simple_paths` the paths are found lazily as
+/// the returned iterator is consumed, so they don't all have to fit in
+/// memory and the search can be stopped early. A path through parallel
+/// edges is only returned once, use
+/// :func:`~retworkx.graph_all_simple_edge_paths` to tell them apart.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   for path in retworkx.graph_iter_all_simple_paths(graph, 0, 3, cutoff=3):
+///       print(path)
+///
+/// :param PyGraph graph: The graph to find the paths in. The graph is copied
+///     when the iterator is created so later changes to it aren't reflected.
+/// :param int from: The node index to find the paths from
+/// :param int to: The node index to find the paths to
+/// :param int min_depth: The minimum number of nodes in the returned paths.
+///     By default all paths are returned regardless of depth.
+/// :param int cutoff: The maximum number of nodes in the returned paths. By
+///     default all paths are returned regardless of depth, setting to 0 will
+///     behave like the default.
+///
+/// :returns: An iterator over the simple paths, each a list of node indices
+/// :rtype: SimplePathIterator
+#[pyfunction]
+#[pyo3(text_signature = "(graph, from, to, /, min_depth=None, cutoff=None)")]
+pub fn graph_iter_all_simple_paths(
+    graph: &graph::PyGraph,
+    from: usize,
+    to: usize,
+    min_depth: Option<usize>,
+    cutoff: Option<usize>,
+) -> PyResult<all_simple_paths::SimplePathIterator> {
+    all_simple_paths::SimplePathIterator::new(&graph.graph, from, to, min_depth, cutoff, false)
+}
+
+/// Return an iterator over the simple paths between 2 nodes in a PyDiGraph
+/// object
+///
+/// A simple path is a path with no repeated nodes. Unlike
+/// :func:`~retworkx.digraph_all_simple_paths` the paths are found lazily as
+/// the returned iterator is consumed, so they don't all have to fit in
+/// memory and the search can be stopped early. A path through parallel
+/// edges is only returned once, use
+/// :func:`~retworkx.digraph_all_simple_edge_paths` to tell them apart.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(3, 3)
+///   for path in retworkx.digraph_iter_all_simple_paths(graph, 0, 8):
+///       print(path)
+///
+/// :param PyDiGraph graph: The graph to find the paths in. The graph is
+///     copied when the iterator is created so later changes to it aren't
+///     reflected.
+/// :param int from: The node index to find the paths from
+/// :param int to: The node index to find the paths to
+/// :param int min_depth: The minimum number of nodes in the returned paths.
+///     By default all paths are returned regardless of depth.
+/// :param int cutoff: The maximum number of nodes in the returned paths. By
+///     default all paths are returned regardless of depth, setting to 0 will
+///     behave like the default.
+///
+/// :returns: An iterator over the simple paths, each a list of node indices
+/// :rtype: SimplePathIterator
+#[pyfunction]
+#[pyo3(text_signature = "(graph, from, to, /, min_depth=None, cutoff=None)")]
+pub fn digraph_iter_all_simple_paths(
+    graph: &digraph::PyDiGraph,
+    from: usize,
+    to: usize,
+    min_depth: Option<usize>,
+    cutoff: Option<usize>,
+) -> PyResult<all_simple_paths::SimplePathIterator> {
+    all_simple_paths::SimplePathIterator::new(&graph.graph, from, to, min_depth, cutoff, false)
+}
+
+/// Return an iterator over the simple paths between 2 nodes in a PyGraph
+/// object as lists of edge indices
+///
+/// A simple path is a path with no repeated nodes. Each path is returned as
+/// the indices of its edges, so in a multigraph the paths which go through
+/// different parallel edges between the same nodes are told apart. The paths
+/// are found lazily as the returned iterator is consumed.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2)])
+///   print(list(retworkx.graph_all_simple_edge_paths(graph, 0, 2)))
+///
+/// :param PyGraph graph: The graph to find the paths in. The graph is copied
+///     when the iterator is created so later changes to it aren't reflected.
+/// :param int from: The node index to find the paths from
+/// :param int to: The node index to find the paths to
+/// :param int min_depth: The minimum number of nodes in the returned paths.
+///     By default all paths are returned regardless of depth.
+/// :param int cutoff: The maximum number of nodes in the returned paths. By
+///     default all paths are returned regardless of depth, setting to 0 will
+///     behave like the default.
+///
+/// :returns: An iterator over the simple paths, each a list of edge indices
+/// :rtype: SimplePathIterator
+#[pyfunction]
+#[pyo3(text_signature = "(graph, from, to, /, min_depth=None, cutoff=None)")]
+pub fn graph_all_simple_edge_paths(
+    graph: &graph::PyGraph,
+    from: usize,
+    to: usize,
+    min_depth: Option<usize>,
+    cutoff: Option<usize>,
+) -> PyResult<all_simple_paths::SimplePathIterator> {
+    all_simple_paths::SimplePathIterator::new(&graph.graph, from, to, min_depth, cutoff, true)
+}
+
+/// Return an iterator over the simple paths between 2 nodes in a PyDiGraph
+/// object as lists of edge indices
+///
+/// A simple path is a path with no repeated nodes. Each path is returned as
+/// the indices of its edges, so in a multigraph the paths which go through
+/// different parallel edges between the same nodes are told apart. The paths
+/// are found lazily as the returned iterator is consumed.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2)])
+///   print(list(retworkx.digraph_all_simple_edge_paths(graph, 0, 2)))
+///
+/// :param PyDiGraph graph: The graph to find the paths in. The graph is
+///     copied when the iterator is created so later changes to it aren't
+///     reflected.
+/// :param int from: The node index to find the paths from
+/// :param int to: The node index to find the paths to
+/// :param int min_depth: The minimum number of nodes in the returned paths.
+///     By default all paths are returned regardless of depth.
+/// :param int cutoff: The maximum number of nodes in the returned paths. By
+///     default all paths are returned regardless of depth, setting to 0 will
+///     behave like the default.
+///
+/// :returns: An iterator over the simple paths, each a list of edge indices
+/// :rtype: SimplePathIterator
+#[pyfunction]
+#[pyo3(text_signature = "(graph, from, to, /, min_depth=None, cutoff=None)")]
+pub fn digraph_all_simple_edge_paths(
+    graph: &digraph::PyDiGraph,
+    from: usize,
+    to: usize,
+    min_depth: Option<usize>,
+    cutoff: Option<usize>,
+) -> PyResult<all_simple_paths::SimplePathIterator> {
+    all_simple_paths::SimplePathIterator::new(&graph.graph, from, to, min_depth, cutoff, true)
+}
+
 /// Return the core number for each node in the graph.
 ///
 /// A k-core is a maximal subgraph that contains nodes of degree k or more.
@@ -666,6 +1205,61 @@ pub fn digraph_core_number(py: Python, graph: &digraph::PyDiGraph) -> PyResult<P
     core_number::core_number(py, &graph.graph)
 }
 
+/// Return the truss number for each edge in the graph.
+///
+/// A k-truss is a maximal subgraph in which every edge is part of at least
+/// k - 2 triangles of the subgraph, and the truss number of an edge is the
+/// largest k such that the edge is in the k-truss. Every edge is in the
+/// 2-truss and the k-truss is always contained in the (k - 1)-core.
+///
+/// The truss numbers are computed by repeatedly removing the edge in the
+/// fewest remaining triangles, which takes :math:`O(m^{1.5} \log m)` time
+/// for a graph with :math:`m` edges [1]_.
+///
+/// Parallel edges are treated as a single edge and all get the same truss
+/// number, self loops are part of no triangle and have a truss number of 2.
+///
+/// :param PyGraph: The graph to get truss numbers
+///
+/// :returns: A dictionary keyed by edge index to the truss number
+/// :rtype: dict
+///
+/// .. [1] Wang, Jia, and James Cheng. "Truss decomposition in massive
+///     networks." Proceedings of the VLDB Endowment 5.9 (2012): 812-823.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_truss_number(py: Python, graph: &graph::PyGraph) -> PyResult<PyObject> {
+    truss_number::truss_number(py, &graph.graph)
+}
+
+/// Return the truss number for each edge in the directed graph.
+///
+/// A k-truss is a maximal subgraph in which every edge is part of at least
+/// k - 2 triangles of the subgraph, and the truss number of an edge is the
+/// largest k such that the edge is in the k-truss. For directed graphs the
+/// direction of the edges is ignored, so edges in opposite directions between
+/// the same nodes are treated as a single edge like parallel edges.
+///
+/// The truss numbers are computed by repeatedly removing the edge in the
+/// fewest remaining triangles, which takes :math:`O(m^{1.5} \log m)` time
+/// for a graph with :math:`m` edges [1]_.
+///
+/// Parallel edges are treated as a single edge and all get the same truss
+/// number, self loops are part of no triangle and have a truss number of 2.
+///
+/// :param PyDiGraph: The directed graph to get truss numbers
+///
+/// :returns: A dictionary keyed by edge index to the truss number
+/// :rtype: dict
+///
+/// .. [1] Wang, Jia, and James Cheng. "Truss decomposition in massive
+///     networks." Proceedings of the VLDB Endowment 5.9 (2012): 812-823.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_truss_number(py: Python, graph: &digraph::PyDiGraph) -> PyResult<PyObject> {
+    truss_number::truss_number(py, &graph.graph)
+}
+
 /// Return the articulation points of an undirected graph.
 ///
 /// An articulation point or cut vertex is any node whose removal (along with
@@ -718,11 +1312,14 @@ pub fn biconnected_components(graph: &graph::PyGraph) -> BiconnectedComponents {
     let mut bicomp = HashMap::new();
     connectivity::articulation_points(&graph.graph, Some(&mut bicomp));
 
+    // Sort by edge endpoints so the iteration order doesn't depend on hashing
+    let mut bicon_comp: Vec<((usize, usize), usize)> = bicomp
+        .into_iter()
+        .map(|((v, w), comp)| ((v.index(), w.index()), comp))
+        .collect();
+    bicon_comp.sort_unstable();
     BiconnectedComponents {
-        bicon_comp: bicomp
-            .into_iter()
-            .map(|((v, w), comp)| ((v.index(), w.index()), comp))
-            .collect(),
+        bicon_comp: bicon_comp.into_iter().collect(),
     }
 }
 
@@ -0,0 +1,50 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use crate::{NodeIndex, StablePyGraph};
+
+/// Return the pairs of distinct nodes with a path of at most ``k`` edges
+/// from the first to the second, found with a breadth first search from
+/// every node truncated at depth ``k``. For an undirected graph every pair
+/// is only returned once, with the smaller node index first.
+pub fn power_edges<Ty>(graph: &StablePyGraph<Ty>, k: usize) -> Vec<(NodeIndex, NodeIndex)>
+where
+    Ty: EdgeType,
+{
+    let mut edges: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+    let mut seen: Vec<Option<NodeIndex>> = vec![None; graph.node_bound()];
+    for source in graph.node_indices() {
+        seen[source.index()] = Some(source);
+        let mut frontier = vec![source];
+        let mut depth = 0;
+        while !frontier.is_empty() && depth < k {
+            let mut next_frontier = Vec::new();
+            for node in frontier {
+                for neighbor in graph.neighbors(node) {
+                    if seen[neighbor.index()] != Some(source) {
+                        seen[neighbor.index()] = Some(source);
+                        next_frontier.push(neighbor);
+                        if Ty::is_directed() || source < neighbor {
+                            edges.push((source, neighbor));
+                        }
+                    }
+                }
+            }
+            frontier = next_frontier;
+            depth += 1;
+        }
+    }
+    edges
+}
@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use crate::StablePyGraph;
+
+pub fn truss_number<Ty>(py: Python, graph: &StablePyGraph<Ty>) -> PyResult<PyObject>
+where
+    Ty: EdgeType,
+{
+    // Collapse the graph to its underlying simple undirected graph, every
+    // edge is mapped to the pair of its endpoints and self loops, which are
+    // in no triangle, are left in the 2-truss.
+    let mut neighbors: Vec<HashMap<usize, usize>> = vec![HashMap::new(); graph.node_bound()];
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut edge_pair: Vec<(usize, Option<usize>)> = Vec::with_capacity(graph.edge_count());
+    for edge in graph.edge_references() {
+        let source = edge.source().index();
+        let target = edge.target().index();
+        if source == target {
+            edge_pair.push((edge.id().index(), None));
+            continue;
+        }
+        let pair = *neighbors[source].entry(target).or_insert_with(|| {
+            pairs.push((source, target));
+            pairs.len() - 1
+        });
+        neighbors[target].insert(source, pair);
+        edge_pair.push((edge.id().index(), Some(pair)));
+    }
+
+    let common = |neighbors: &[HashMap<usize, usize>], u: usize, v: usize| -> Vec<usize> {
+        let (small, large) = if neighbors[u].len() < neighbors[v].len() {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        neighbors[small]
+            .keys()
+            .filter(|w| neighbors[large].contains_key(*w))
+            .copied()
+            .collect()
+    };
+
+    let mut support: Vec<usize> = pairs
+        .iter()
+        .map(|(u, v)| common(&neighbors, *u, *v).len())
+        .collect();
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = support
+        .iter()
+        .enumerate()
+        .map(|(pair, pair_support)| Reverse((*pair_support, pair)))
+        .collect();
+    let mut truss: Vec<Option<usize>> = vec![None; pairs.len()];
+    let mut level = 2;
+    while let Some(Reverse((pair_support, pair))) = heap.pop() {
+        if truss[pair].is_some() || pair_support != support[pair] {
+            continue;
+        }
+        level = std::cmp::max(level, pair_support + 2);
+        truss[pair] = Some(level);
+        let (u, v) = pairs[pair];
+        for w in common(&neighbors, u, v) {
+            for other in [neighbors[u][&w], neighbors[v][&w]].iter() {
+                support[*other] -= 1;
+                heap.push(Reverse((support[*other], *other)));
+            }
+        }
+        neighbors[u].remove(&v);
+        neighbors[v].remove(&u);
+    }
+
+    let out_dict = PyDict::new(py);
+    for (edge, pair) in edge_pair {
+        let edge_truss = match pair {
+            Some(pair) => truss[pair].unwrap(),
+            None => 2,
+        };
+        out_dict.set_item(edge, edge_truss)?;
+    }
+    Ok(out_dict.into())
+}
@@ -0,0 +1,510 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::TryFrom;
+
+use crate::iterators::{CentralityMapping, PathLengthMapping};
+use crate::{convergence_error, CostFn, GraphIx, InvalidNode, StablePyGraph};
+
+use numpy::IntoPyArray;
+use rayon::prelude::*;
+use retworkx_core::centrality::power_iteration;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+/// An entry in the priority queue used by Dijkstra's algorithm, ordered so
+/// that the entry with the smallest distance is at the top of the heap.
+struct MinDistance(f64, usize);
+
+impl PartialEq for MinDistance {
+    fn eq(&self, other: &MinDistance) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MinDistance {}
+
+impl PartialOrd for MinDistance {
+    fn partial_cmp(&self, other: &MinDistance) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinDistance {
+    fn cmp(&self, other: &MinDistance) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// An immutable compressed sparse row (CSR) snapshot of a graph
+///
+/// The snapshot stores the adjacency of the graph at the time it was created
+/// in three flat arrays. The nodes of the graph are renumbered to a
+/// contiguous range ``0..len(snapshot)`` in node index order, the arcs
+/// leaving compact node ``i`` are at positions ``indptr[i]..indptr[i + 1]``
+/// of ``indices`` (the compact index of the node the arc points to) and
+/// ``weights`` (the weight of the edge), sorted by the node they point to.
+/// The compact node indices in ``indices`` are stored with the same integer
+/// width as the indices of the graph, 32 bit unless retworkx was built with
+/// the ``usize-indices`` feature.
+/// For a snapshot of an undirected :class:`~retworkx.PyGraph` every edge is
+/// stored as an arc in both directions, except for self loops which are only
+/// stored once.
+///
+/// Because the snapshot is contiguous in memory the read-only algorithms
+/// available on it have much better cache locality than running the
+/// equivalent functions on the graph itself, which makes it worthwhile for
+/// running several analyses on a large graph that isn't being modified. All
+/// the methods take and return the original node indices of the graph, the
+/// snapshot doesn't track any later changes to the graph.
+///
+/// A snapshot is created with :meth:`~retworkx.PyGraph.to_csr_snapshot` or
+/// :meth:`~retworkx.PyDiGraph.to_csr_snapshot`.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   snapshot = graph.to_csr_snapshot()
+///   print(snapshot.indptr)
+///   print(snapshot.indices)
+///   print(snapshot.dijkstra_shortest_path_lengths(0))
+#[pyclass(module = "retworkx")]
+pub struct CSRSnapshot {
+    /// Whether the snapshot was created from a directed graph
+    #[pyo3(get)]
+    directed: bool,
+    indptr: Vec<usize>,
+    indices: Vec<GraphIx>,
+    weights: Vec<f64>,
+    node_indices: Vec<usize>,
+    compact_indices: Vec<Option<usize>>,
+}
+
+impl CSRSnapshot {
+    pub fn from_graph<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<Self> {
+        let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, graph)?;
+        let mut edge_weights: Vec<f64> = vec![0.; graph.edge_bound()];
+        for edge in graph.edge_references() {
+            edge_weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+        }
+        let node_indices: Vec<usize> = graph.node_indices().map(|node| node.index()).collect();
+        let mut compact_indices: Vec<Option<usize>> = vec![None; graph.node_bound()];
+        for (compact, node) in node_indices.iter().enumerate() {
+            compact_indices[*node] = Some(compact);
+        }
+        let mut indptr: Vec<usize> = Vec::with_capacity(node_indices.len() + 1);
+        let mut indices: Vec<GraphIx> = Vec::new();
+        let mut weights: Vec<f64> = Vec::new();
+        indptr.push(0);
+        for node in graph.node_indices() {
+            let mut row: Vec<(GraphIx, f64)> = graph
+                .edges(node)
+                .map(|edge| {
+                    let neighbor = if edge.source() == node {
+                        edge.target()
+                    } else {
+                        edge.source()
+                    };
+                    (
+                        compact_indices[neighbor.index()].unwrap() as GraphIx,
+                        edge_weights[edge.id().index()],
+                    )
+                })
+                .collect();
+            // Sort the arcs by their head so the layout of the snapshot
+            // doesn't depend on the order of the graph's internal edge lists.
+            row.sort_by_key(|(neighbor, _)| *neighbor);
+            for (neighbor, weight) in row {
+                indices.push(neighbor);
+                weights.push(weight);
+            }
+            indptr.push(indices.len());
+        }
+        Ok(CSRSnapshot {
+            directed: graph.is_directed(),
+            indptr,
+            indices,
+            weights,
+            node_indices,
+            compact_indices,
+        })
+    }
+
+    /// Return the compact index of the original node index ``node``
+    fn compact_index(&self, node: usize) -> PyResult<usize> {
+        match self.compact_indices.get(node) {
+            Some(Some(compact)) => Ok(*compact),
+            _ => Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the snapshot",
+                node
+            ))),
+        }
+    }
+
+    /// Return the range of positions in ``indices`` and ``weights`` of the
+    /// arcs leaving the compact node ``node``
+    fn arcs(&self, node: usize) -> std::ops::Range<usize> {
+        self.indptr[node]..self.indptr[node + 1]
+    }
+
+    /// Map a vector of values by compact index to a mapping of original node
+    /// indices, skipping the nodes without a value.
+    fn to_mapping(&self, values: Vec<Option<f64>>) -> impl Iterator<Item = (usize, f64)> + '_ {
+        values
+            .into_iter()
+            .enumerate()
+            .filter_map(move |(compact, value)| value.map(|x| (self.node_indices[compact], x)))
+    }
+
+    /// Compute the dependency of every node on the shortest paths from the
+    /// compact node ``source`` with Brandes' algorithm.
+    fn betweenness_from(&self, source: usize, endpoints: bool) -> Vec<f64> {
+        let node_count = self.node_indices.len();
+        let mut verts_sorted_by_distance: Vec<usize> = Vec::with_capacity(node_count);
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        let mut sigma: Vec<f64> = vec![0.; node_count];
+        let mut distance: Vec<i64> = vec![-1; node_count];
+        let mut queue: VecDeque<usize> = VecDeque::with_capacity(node_count);
+        sigma[source] = 1.;
+        distance[source] = 0;
+        queue.push_back(source);
+        while let Some(v) = queue.pop_front() {
+            verts_sorted_by_distance.push(v);
+            for arc in self.arcs(v) {
+                let w = self.indices[arc] as usize;
+                if distance[w] < 0 {
+                    queue.push_back(w);
+                    distance[w] = distance[v] + 1;
+                }
+                if distance[w] == distance[v] + 1 {
+                    sigma[w] += sigma[v];
+                    predecessors[w].push(v);
+                }
+            }
+        }
+        let mut delta: Vec<f64> = vec![0.; node_count];
+        for w in verts_sorted_by_distance.iter().rev() {
+            let coeff = (1. + delta[*w]) / sigma[*w];
+            for v in &predecessors[*w] {
+                delta[*v] += sigma[*v] * coeff;
+            }
+        }
+        let mut betweenness: Vec<f64> = vec![0.; node_count];
+        for w in &verts_sorted_by_distance {
+            if *w == source {
+                if endpoints {
+                    betweenness[source] = (verts_sorted_by_distance.len() - 1) as f64;
+                }
+            } else if endpoints {
+                betweenness[*w] = delta[*w] + 1.;
+            } else {
+                betweenness[*w] = delta[*w];
+            }
+        }
+        betweenness
+    }
+
+    /// Compute the PageRank of every compact node by power iteration, or
+    /// ``None`` if it doesn't converge in ``max_iter`` iterations.
+    fn pagerank_ranks(&self, alpha: f64, max_iter: usize, tol: f64) -> Option<Vec<f64>> {
+        let node_count = self.node_indices.len();
+        if node_count == 0 {
+            return Some(Vec::new());
+        }
+        let uniform = 1. / node_count as f64;
+        let out_weights: Vec<f64> = (0..node_count)
+            .map(|node| self.arcs(node).map(|arc| self.weights[arc]).sum())
+            .collect();
+        let nodes: Vec<usize> = (0..node_count).collect();
+        power_iteration(
+            vec![uniform; node_count],
+            &nodes,
+            max_iter,
+            tol,
+            |ranks, next_ranks| {
+                let dangling: f64 = (0..node_count)
+                    .filter(|node| out_weights[*node] == 0.)
+                    .map(|node| ranks[node])
+                    .sum();
+                let base = (1. - alpha) * uniform + alpha * dangling * uniform;
+                next_ranks.iter_mut().for_each(|rank| *rank = base);
+                for node in 0..node_count {
+                    if out_weights[node] == 0. {
+                        continue;
+                    }
+                    let share = alpha * ranks[node] / out_weights[node];
+                    for arc in self.arcs(node) {
+                        next_ranks[self.indices[arc] as usize] += share * self.weights[arc];
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[pymethods]
+impl CSRSnapshot {
+    fn __len__(&self) -> usize {
+        self.node_indices.len()
+    }
+
+    /// The offsets of the arcs leaving each node in :attr:`indices` and
+    /// :attr:`weights`, a 1D numpy array of length ``len(snapshot) + 1``
+    #[getter]
+    fn indptr(&self, py: Python) -> PyObject {
+        self.indptr.clone().into_pyarray(py).into()
+    }
+
+    /// The compact index of the node each arc points to, a 1D numpy array of
+    /// unsigned integers with the same width as the graph's indices
+    #[getter]
+    fn indices(&self, py: Python) -> PyObject {
+        self.indices.clone().into_pyarray(py).into()
+    }
+
+    /// The weight of each arc, a 1D numpy array of floats
+    #[getter]
+    fn weights(&self, py: Python) -> PyObject {
+        self.weights.clone().into_pyarray(py).into()
+    }
+
+    /// The original node index of each compact node index, a 1D numpy array
+    #[getter]
+    fn node_indices(&self, py: Python) -> PyObject {
+        self.node_indices.clone().into_pyarray(py).into()
+    }
+
+    /// Compute the number of edges on the shortest path from a node to every
+    /// node reachable from it
+    ///
+    /// :param int source: The node index to find distances from
+    ///
+    /// :returns: A read-only dictionary of the number of edges on the shortest
+    ///     path from ``source`` to each node reachable from it. ``source``
+    ///     itself is not included.
+    /// :rtype: PathLengthMapping
+    /// :raises InvalidNode: If ``source`` is not a node in the snapshot
+    #[pyo3(text_signature = "(self, source, /)")]
+    pub fn bfs_distances(&self, source: usize) -> PyResult<PathLengthMapping> {
+        let start = self.compact_index(source)?;
+        let mut distances: Vec<Option<f64>> = vec![None; self.node_indices.len()];
+        distances[start] = Some(0.);
+        let mut queue: VecDeque<usize> = VecDeque::from(vec![start]);
+        while let Some(node) = queue.pop_front() {
+            let next_distance = distances[node].map(|dist| dist + 1.);
+            for arc in self.arcs(node) {
+                let neighbor = self.indices[arc] as usize;
+                if distances[neighbor].is_none() {
+                    distances[neighbor] = next_distance;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        distances[start] = None;
+        Ok(PathLengthMapping {
+            path_lengths: self.to_mapping(distances).collect(),
+        })
+    }
+
+    /// Compute the lengths of the shortest paths from a node to every node
+    /// reachable from it using Dijkstra's algorithm
+    ///
+    /// The edge weights used are the ones computed when the snapshot was
+    /// created.
+    ///
+    /// :param int source: The node index to find path lengths from
+    /// :param int goal: An optional node index, if specified only the path
+    ///     length to ``goal`` is computed.
+    ///
+    /// :returns: A read-only dictionary of the lengths of the shortest paths
+    ///     from ``source`` to each node reachable from it. ``source`` itself
+    ///     is not included.
+    /// :rtype: PathLengthMapping
+    /// :raises InvalidNode: If ``source`` or ``goal`` is not a node in the
+    ///     snapshot
+    #[pyo3(text_signature = "(self, source, /, goal=None)")]
+    pub fn dijkstra_shortest_path_lengths(
+        &self,
+        source: usize,
+        goal: Option<usize>,
+    ) -> PyResult<PathLengthMapping> {
+        let start = self.compact_index(source)?;
+        let goal = goal.map(|goal| self.compact_index(goal)).transpose()?;
+        let mut distances: Vec<Option<f64>> = vec![None; self.node_indices.len()];
+        let mut visited: Vec<bool> = vec![false; self.node_indices.len()];
+        let mut heap: BinaryHeap<MinDistance> = BinaryHeap::new();
+        distances[start] = Some(0.);
+        heap.push(MinDistance(0., start));
+        while let Some(MinDistance(dist, node)) = heap.pop() {
+            if visited[node] {
+                continue;
+            }
+            if goal == Some(node) {
+                break;
+            }
+            visited[node] = true;
+            for arc in self.arcs(node) {
+                let neighbor = self.indices[arc] as usize;
+                if visited[neighbor] {
+                    continue;
+                }
+                let next_dist = dist + self.weights[arc];
+                match distances[neighbor] {
+                    Some(current) if current <= next_dist => (),
+                    _ => {
+                        distances[neighbor] = Some(next_dist);
+                        heap.push(MinDistance(next_dist, neighbor));
+                    }
+                }
+            }
+        }
+        distances[start] = None;
+        let path_lengths = match goal {
+            Some(goal) => match distances[goal] {
+                Some(dist) => vec![(self.node_indices[goal], dist)].into_iter().collect(),
+                None => Default::default(),
+            },
+            None => self.to_mapping(distances).collect(),
+        };
+        Ok(PathLengthMapping { path_lengths })
+    }
+
+    /// Compute the betweenness centrality of all nodes in the snapshot
+    ///
+    /// This computes the same values as
+    /// :func:`~retworkx.betweenness_centrality` on the graph the snapshot was
+    /// created from, the edge weights are not used.
+    ///
+    /// This method is multithreaded and will run in parallel if the number
+    /// of nodes in the snapshot is above the value of ``parallel_threshold``
+    /// (it defaults to 50). If the method will be running in parallel the env
+    /// var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will
+    /// be used.
+    ///
+    /// :param bool normalized: Whether to normalize the betweenness scores by
+    ///     the number of distinct paths between all pairs of nodes.
+    /// :param bool endpoints: Whether to include the endpoints of paths in
+    ///     pathlengths used to compute the betweenness.
+    /// :param int parallel_threshold: The number of nodes to calculate the
+    ///     the betweenness centrality in parallel at if the number of nodes in
+    ///     the snapshot is less than this value it will run in a single
+    ///     thread. The default value is 50
+    ///
+    /// :returns: A read-only dictionary of the betweenness score of each node.
+    /// :rtype: CentralityMapping
+    #[args(normalized = "true", endpoints = "false", parallel_threshold = "50")]
+    #[pyo3(text_signature = "(self, /, normalized=True, endpoints=False, parallel_threshold=50)")]
+    pub fn betweenness_centrality(
+        &self,
+        py: Python,
+        normalized: bool,
+        endpoints: bool,
+        parallel_threshold: usize,
+    ) -> CentralityMapping {
+        let node_count = self.node_indices.len();
+        let sum = |mut total: Vec<f64>, values: Vec<f64>| {
+            for (x, y) in total.iter_mut().zip(values) {
+                *x += y;
+            }
+            total
+        };
+        let betweenness: Vec<f64> = py.allow_threads(|| {
+            if node_count < parallel_threshold {
+                (0..node_count)
+                    .map(|source| self.betweenness_from(source, endpoints))
+                    .fold(vec![0.; node_count], sum)
+            } else {
+                (0..node_count)
+                    .into_par_iter()
+                    .map(|source| self.betweenness_from(source, endpoints))
+                    .reduce(|| vec![0.; node_count], sum)
+            }
+        });
+        let scale = if normalized {
+            if endpoints && node_count >= 2 {
+                Some(1. / (node_count * (node_count - 1)) as f64)
+            } else if !endpoints && node_count > 2 {
+                Some(1. / ((node_count - 1) * (node_count - 2)) as f64)
+            } else {
+                None
+            }
+        } else if !self.directed {
+            Some(0.5)
+        } else {
+            None
+        };
+        CentralityMapping {
+            centralities: self
+                .to_mapping(
+                    betweenness
+                        .into_iter()
+                        .map(|x| Some(scale.map_or(x, |scale| x * scale)))
+                        .collect(),
+                )
+                .collect(),
+        }
+    }
+
+    /// Compute the PageRank of all nodes in the snapshot
+    ///
+    /// The PageRank is computed by power iteration using the edge weights the
+    /// snapshot was created with. Nodes without any outgoing arcs distribute
+    /// their rank uniformly over all nodes. For an undirected graph each edge
+    /// contributes in both directions.
+    ///
+    /// :param float alpha: The damping factor, by default ``0.85``
+    /// :param int max_iter: The maximum number of iterations of the power
+    ///     method, by default ``100``
+    /// :param float tol: The error tolerance used to check for convergence,
+    ///     the iteration stops when the sum of the absolute changes of all the
+    ///     ranks is less than ``len(snapshot) * tol``. By default ``1e-6``
+    ///
+    /// :returns: A read-only dictionary of the PageRank of each node.
+    /// :rtype: CentralityMapping
+    /// :raises ConvergenceError: If the power iteration doesn't converge in
+    ///     ``max_iter`` iterations
+    #[args(alpha = "0.85", max_iter = "100", tol = "1e-6")]
+    #[pyo3(text_signature = "(self, /, alpha=0.85, max_iter=100, tol=1e-6)")]
+    pub fn pagerank(
+        &self,
+        py: Python,
+        alpha: f64,
+        max_iter: usize,
+        tol: f64,
+    ) -> PyResult<CentralityMapping> {
+        match py.allow_threads(|| self.pagerank_ranks(alpha, max_iter, tol)) {
+            Some(ranks) => Ok(CentralityMapping {
+                centralities: self
+                    .to_mapping(ranks.into_iter().map(Some).collect())
+                    .collect(),
+            }),
+            None => Err(convergence_error(
+                py,
+                format!("PageRank failed to converge in {} iterations", max_iter),
+                max_iter,
+            )),
+        }
+    }
+}
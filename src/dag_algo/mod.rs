@@ -11,13 +11,15 @@
 // under the License.
 
 mod longest_path;
+mod path_cover;
 
 use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
 use super::iterators::NodeIndices;
-use crate::{digraph, DAGHasCycle, InvalidNode};
+use crate::{digraph, DAGHasCycle, InvalidNode, NodeIndex};
+use retworkx_core::dictmap::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -25,9 +27,10 @@ use pyo3::types::PyList;
 use pyo3::Python;
 
 use petgraph::algo;
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
-use petgraph::visit::NodeCount;
+use petgraph::visit::{NodeCount, NodeIndexable};
+
+use rayon::prelude::*;
 
 /// Find the longest path in a DAG
 ///
@@ -604,3 +607,145 @@ pub fn collect_bicolor_runs(
 
     Ok(block_list)
 }
+
+/// Map a function over the nodes of a DAG layer by layer in parallel
+///
+/// The nodes are scheduled in topological layers, the first layer is the
+/// nodes without predecessors and each following layer is the nodes whose
+/// predecessors are all in earlier layers. ``map_fn`` is called for each
+/// node with the node's data payload and a dictionary mapping the index of
+/// each of its predecessors to the return of ``map_fn`` for that
+/// predecessor, so results flow along the edges of the DAG like in a
+/// dataflow graph. The calls for the nodes of a layer are dispatched to a
+/// thread pool and a layer only starts once the whole previous layer has
+/// finished. You can tune the number of threads with the
+/// ``RAYON_NUM_THREADS`` environment variable.
+///
+/// .. note::
+///
+///     Each call holds the GIL, so the calls only run concurrently while
+///     ``map_fn`` releases the GIL, for example while it's waiting on I/O or
+///     running a numpy operation.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   dag = retworkx.PyDAG()
+///   dag.add_nodes_from([1, 2, 3, 4])
+///   dag.add_edges_from_no_data([(0, 2), (1, 2), (2, 3)])
+///
+///   def add_inputs(data, inputs):
+///       return data + sum(inputs.values())
+///
+///   print(retworkx.dag_parallel_map(dag, add_inputs))
+///
+/// :param PyDiGraph dag: The DAG to map over
+/// :param map_fn: A python callable which takes 2 positional arguments, the
+///     data payload/weight object of a node and a dictionary mapping the node
+///     index of each predecessor of the node to its result, and returns the
+///     result for the node.
+///
+/// :returns: A dictionary mapping each node index to the result of
+///     ``map_fn`` for the node, in the order the nodes were scheduled
+/// :rtype: dict
+///
+/// :raises DAGHasCycle: If the graph has a cycle, before ``map_fn`` is called
+#[pyfunction]
+#[pyo3(text_signature = "(dag, map_fn, /)")]
+pub fn dag_parallel_map(
+    py: Python,
+    dag: &digraph::PyDiGraph,
+    map_fn: PyObject,
+) -> PyResult<DictMap<usize, PyObject>> {
+    if algo::toposort(&dag.graph, None).is_err() {
+        return Err(DAGHasCycle::new_err("Sort encountered a cycle"));
+    }
+    let graph = &dag.graph;
+    let mut in_degree: Vec<usize> = vec![0; graph.node_bound()];
+    let mut layer: Vec<NodeIndex> = Vec::new();
+    for node in graph.node_indices() {
+        in_degree[node.index()] = graph.edges_directed(node, Incoming).count();
+        if in_degree[node.index()] == 0 {
+            layer.push(node);
+        }
+    }
+    let mut results: Vec<Option<PyObject>> = vec![None; graph.node_bound()];
+    let mut output: DictMap<usize, PyObject> = DictMap::with_capacity(graph.node_count());
+    while !layer.is_empty() {
+        let layer_results: Vec<PyObject> = py.allow_threads(|| {
+            layer
+                .par_iter()
+                .map(|node| {
+                    Python::with_gil(|py| {
+                        let mut predecessors: Vec<usize> = graph
+                            .neighbors_directed(*node, Incoming)
+                            .map(|predecessor| predecessor.index())
+                            .collect();
+                        predecessors.sort_unstable();
+                        predecessors.dedup();
+                        let inputs: DictMap<usize, PyObject> = predecessors
+                            .into_iter()
+                            .map(|predecessor| {
+                                let result = results[predecessor].as_ref().unwrap();
+                                (predecessor, result.clone_ref(py))
+                            })
+                            .collect();
+                        map_fn.call1(py, (&graph[*node], inputs))
+                    })
+                })
+                .collect::<PyResult<Vec<PyObject>>>()
+        })?;
+        let mut next_layer: Vec<NodeIndex> = Vec::new();
+        for (node, result) in layer.iter().zip(layer_results) {
+            for successor in graph.neighbors_directed(*node, Outgoing) {
+                in_degree[successor.index()] -= 1;
+                if in_degree[successor.index()] == 0 {
+                    next_layer.push(successor);
+                }
+            }
+            output.insert(node.index(), result.clone_ref(py));
+            results[node.index()] = Some(result);
+        }
+        layer = next_layer;
+    }
+    Ok(output)
+}
+
+/// Find a minimum path cover of a DAG
+///
+/// A path cover is a set of vertex-disjoint paths, each following the edges
+/// of the DAG, such that every node is on exactly one path. A minimum path
+/// cover has the fewest paths, which is the smallest number of sequential
+/// chains that a set of tasks with dependencies can be split into. It's
+/// computed from a maximum matching between the nodes and their successors
+/// in a bipartite graph, found with the Hopcroft-Karp algorithm, as each
+/// matched pair of nodes joins two paths into one. This runs in
+/// :math:`O(|E| \sqrt{|V|})` time.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   dag = retworkx.PyDiGraph()
+///   dag.add_nodes_from(range(6))
+///   dag.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5)])
+///   print(retworkx.dag_minimum_path_cover(dag))
+///
+/// :param PyDiGraph graph: The DAG to find a minimum path cover of
+///
+/// :returns: A list of paths, each a list of the node indices on the path
+///     in order. Every node of the graph is on exactly one path, a node not
+///     joined to any other node is a path by itself. The paths are ordered
+///     by the index of their first node.
+/// :rtype: list
+///
+/// :raises DAGHasCycle: If the graph has a cycle
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn dag_minimum_path_cover(graph: &digraph::PyDiGraph) -> PyResult<Vec<Vec<usize>>> {
+    if algo::toposort(&graph.graph, None).is_err() {
+        return Err(DAGHasCycle::new_err("Sort encountered a cycle"));
+    }
+    Ok(path_cover::minimum_path_cover(&graph.graph))
+}
@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use crate::StablePyGraph;
+
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+
+const NONE: usize = usize::MAX;
+
+/// Find a maximum matching between the nodes of ``graph`` as sources and the
+/// nodes of ``graph`` as targets, where a source can be matched with any of
+/// its successors, with the Hopcroft-Karp algorithm. Returns the successor
+/// matched with each node, indexed by node index.
+fn successor_matching(graph: &StablePyGraph<Directed>) -> Vec<usize> {
+    let node_bound = graph.node_bound();
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); node_bound];
+    for node in graph.node_indices() {
+        let adjacent = &mut successors[node.index()];
+        adjacent.extend(graph.neighbors_directed(node, Outgoing).map(|n| n.index()));
+        adjacent.sort_unstable();
+        adjacent.dedup();
+    }
+
+    let mut match_source: Vec<usize> = vec![NONE; node_bound];
+    let mut match_target: Vec<usize> = vec![NONE; node_bound];
+    let mut layer: Vec<usize> = vec![NONE; node_bound];
+    let mut next: Vec<usize> = vec![0; node_bound];
+    loop {
+        // Layer the unmatched sources and the sources reachable from them by
+        // alternating paths with a breadth first search
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for node in graph.node_indices() {
+            let node = node.index();
+            if match_source[node] == NONE {
+                layer[node] = 0;
+                queue.push_back(node);
+            } else {
+                layer[node] = NONE;
+            }
+        }
+        let mut found = false;
+        while let Some(source) = queue.pop_front() {
+            for &target in &successors[source] {
+                let matched = match_target[target];
+                if matched == NONE {
+                    found = true;
+                } else if layer[matched] == NONE {
+                    layer[matched] = layer[source] + 1;
+                    queue.push_back(matched);
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        // Augment along vertex disjoint shortest alternating paths with an
+        // iterative depth first search from each unmatched source
+        next.iter_mut().for_each(|x| *x = 0);
+        for root in graph.node_indices() {
+            let root = root.index();
+            if match_source[root] != NONE {
+                continue;
+            }
+            let mut stack: Vec<usize> = vec![root];
+            while let Some(&source) = stack.last() {
+                if next[source] == successors[source].len() {
+                    layer[source] = NONE;
+                    stack.pop();
+                    continue;
+                }
+                let target = successors[source][next[source]];
+                next[source] += 1;
+                let matched = match_target[target];
+                if matched == NONE {
+                    for &node in &stack {
+                        let node_target = successors[node][next[node] - 1];
+                        match_source[node] = node_target;
+                        match_target[node_target] = node;
+                    }
+                    break;
+                } else if layer[matched] != NONE && layer[matched] == layer[source] + 1 {
+                    stack.push(matched);
+                }
+            }
+        }
+    }
+    match_source
+}
+
+/// Return the paths of a minimum path cover of a DAG, each path starting at
+/// a node whose predecessors are all on other paths. The paths are ordered
+/// by their first node index.
+pub fn minimum_path_cover(graph: &StablePyGraph<Directed>) -> Vec<Vec<usize>> {
+    let matching = successor_matching(graph);
+    let mut has_predecessor: Vec<bool> = vec![false; graph.node_bound()];
+    for target in matching.iter().filter(|target| **target != NONE) {
+        has_predecessor[*target] = true;
+    }
+    graph
+        .node_indices()
+        .filter(|node| !has_predecessor[node.index()])
+        .map(|start| {
+            let mut path = vec![start.index()];
+            let mut node = start.index();
+            while matching[node] != NONE {
+                node = matching[node];
+                path.push(node);
+            }
+            path
+        })
+        .collect()
+}
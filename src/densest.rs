@@ -0,0 +1,276 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::convert::Infallible;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::graph::DiGraph;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use retworkx_core::flow::min_cut;
+use retworkx_core::Error;
+
+use crate::graph;
+use crate::iterators::NodeIndices;
+use crate::{GraphIx, NodeIndex};
+
+/// The nodes of a graph by compact position and its edges between those
+/// positions, self loops included.
+struct Multigraph {
+    nodes: Vec<usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Multigraph {
+    fn new(graph: &graph::PyGraph) -> Self {
+        let mut position: Vec<usize> = vec![0; graph.graph.node_bound()];
+        let nodes: Vec<usize> = graph.graph.node_indices().map(|n| n.index()).collect();
+        for (index, node) in nodes.iter().enumerate() {
+            position[*node] = index;
+        }
+        let edges = graph
+            .graph
+            .edge_references()
+            .map(|e| (position[e.source().index()], position[e.target().index()]))
+            .collect();
+        Multigraph { nodes, edges }
+    }
+
+    /// Return the nodes, by position, of a subgraph ``S`` maximizing
+    /// ``size * |E(S)| - edges * |S|``, which is positive if and only if
+    /// ``S`` is denser than ``edges / size``, with Goldberg's minimum cut
+    /// construction.
+    fn densest_improvement(&self, edges: i64, size: i64) -> Vec<usize> {
+        let n = self.nodes.len();
+        let m = self.edges.len() as i64;
+        let mut degree: Vec<i64> = vec![0; n];
+        for (source, target) in &self.edges {
+            degree[*source] += 1;
+            degree[*target] += 1;
+        }
+        // The cut with source side S has capacity
+        // 2 * n * m * size + 2 * (edges * |S| - size * |E(S)|), the capacity
+        // of the arcs to the sink is non-negative because no degree is more
+        // than 2 * m.
+        let base = 2 * m * size;
+        let mut network: DiGraph<(), i64, GraphIx> =
+            DiGraph::with_capacity(n + 2, 2 * (n + self.edges.len()));
+        for _ in 0..n + 2 {
+            network.add_node(());
+        }
+        let source = NodeIndex::new(n);
+        let sink = NodeIndex::new(n + 1);
+        for (node, node_degree) in degree.iter().enumerate() {
+            network.add_edge(source, NodeIndex::new(node), base);
+            network.add_edge(
+                NodeIndex::new(node),
+                sink,
+                base + 2 * edges - node_degree * size,
+            );
+        }
+        for (first, second) in &self.edges {
+            if first != second {
+                network.add_edge(NodeIndex::new(*first), NodeIndex::new(*second), size);
+                network.add_edge(NodeIndex::new(*second), NodeIndex::new(*first), size);
+            }
+        }
+        let res: Result<_, Error> = min_cut(&network, source, sink, |e| {
+            Ok::<i64, Infallible>(*e.weight())
+        });
+        let (_, source_side, _) = res.unwrap();
+        source_side
+            .into_iter()
+            .map(|node| node.index())
+            .filter(|node| *node < n)
+            .collect()
+    }
+
+    fn induced_edge_count(&self, subset: &[usize]) -> i64 {
+        let mut member = vec![false; self.nodes.len()];
+        for node in subset {
+            member[*node] = true;
+        }
+        self.edges
+            .iter()
+            .filter(|(source, target)| member[*source] && member[*target])
+            .count() as i64
+    }
+}
+
+/// Find a densest subgraph of a graph
+///
+/// The density of a subgraph is the number of its edges divided by the
+/// number of its nodes, so this is the induced subgraph with the largest
+/// average degree. Parallel edges and self loops are each counted as an
+/// edge.
+///
+/// The subgraph is found exactly with Goldberg's algorithm [1]_, which
+/// finds a denser subgraph than a given density, if there is one, from a
+/// minimum cut of an auxiliary flow network. Starting from the density of
+/// the whole graph the density is raised to that of the subgraph found
+/// until no denser subgraph exists. Every minimum cut is found with the same
+/// algorithm as :func:`~retworkx.digraph_minimum_cut` with exact integer
+/// capacities.
+///
+/// For large graphs :func:`~retworkx.approximate_densest_subgraph` is much
+/// faster and finds a subgraph with at least half the maximum density.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.add_nodes_from(range(3))
+///   graph.add_edges_from_no_data([(3, 4), (4, 5), (5, 6)])
+///   nodes, density = retworkx.densest_subgraph(graph)
+///   print(list(nodes), density)
+///
+/// :param PyGraph graph: The graph to find the densest subgraph of
+///
+/// :returns: A tuple of the indices of the nodes of a densest subgraph and
+///     its density. If the graph has no nodes the subgraph is empty and its
+///     density is ``0.0``, if it has no edges it is the whole graph.
+/// :rtype: tuple
+///
+/// .. [1] Goldberg, Andrew V. "Finding a maximum density subgraph."
+///     Technical Report UCB/CSD-84-171, University of California, Berkeley
+///     (1984).
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn densest_subgraph(py: Python, graph: &graph::PyGraph) -> (NodeIndices, f64) {
+    let multigraph = Multigraph::new(graph);
+    if multigraph.nodes.is_empty() {
+        return (NodeIndices { nodes: Vec::new() }, 0.0);
+    }
+    let (subset, edges, size) = py.allow_threads(|| {
+        let mut subset: Vec<usize> = (0..multigraph.nodes.len()).collect();
+        let mut edges = multigraph.edges.len() as i64;
+        let mut size = subset.len() as i64;
+        loop {
+            let candidate = multigraph.densest_improvement(edges, size);
+            if candidate.is_empty() {
+                break;
+            }
+            let candidate_edges = multigraph.induced_edge_count(&candidate);
+            if candidate_edges * size <= edges * candidate.len() as i64 {
+                break;
+            }
+            edges = candidate_edges;
+            size = candidate.len() as i64;
+            subset = candidate;
+        }
+        (subset, edges, size)
+    });
+    (
+        NodeIndices {
+            nodes: subset
+                .into_iter()
+                .map(|node| multigraph.nodes[node])
+                .collect(),
+        },
+        edges as f64 / size as f64,
+    )
+}
+
+/// Find a subgraph of a graph with at least half the maximum density
+///
+/// The density of a subgraph is the number of its edges divided by the
+/// number of its nodes, with parallel edges and self loops each counted as
+/// an edge. This function uses Charikar's greedy peeling algorithm [1]_,
+/// which repeatedly removes a node with the fewest remaining edges and
+/// returns the densest of the subgraphs left along the way. It runs in
+/// :math:`O((|V| + |E|) \log |V|)` time and the density of the subgraph
+/// found is at least half the density of the densest subgraph, as found by
+/// :func:`~retworkx.densest_subgraph`.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.add_nodes_from(range(3))
+///   graph.add_edges_from_no_data([(3, 4), (4, 5), (5, 6)])
+///   nodes, density = retworkx.approximate_densest_subgraph(graph)
+///   print(list(nodes), density)
+///
+/// :param PyGraph graph: The graph to find a dense subgraph of
+///
+/// :returns: A tuple of the indices of the nodes of the subgraph and its
+///     density. If the graph has no nodes the subgraph is empty and its
+///     density is ``0.0``.
+/// :rtype: tuple
+///
+/// .. [1] Charikar, Moses. "Greedy approximation algorithms for finding
+///     dense components in a graph." International Workshop on
+///     Approximation Algorithms for Combinatorial Optimization (2000).
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn approximate_densest_subgraph(graph: &graph::PyGraph) -> (NodeIndices, f64) {
+    let multigraph = Multigraph::new(graph);
+    let n = multigraph.nodes.len();
+    if n == 0 {
+        return (NodeIndices { nodes: Vec::new() }, 0.0);
+    }
+    let mut incident: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (edge, (source, target)) in multigraph.edges.iter().enumerate() {
+        incident[*source].push(edge);
+        if source != target {
+            incident[*target].push(edge);
+        }
+    }
+    let mut degree: Vec<usize> = incident.iter().map(|edges| edges.len()).collect();
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = degree
+        .iter()
+        .enumerate()
+        .map(|(node, node_degree)| Reverse((*node_degree, node)))
+        .collect();
+    let mut removed_node = vec![false; n];
+    let mut removed_edge = vec![false; multigraph.edges.len()];
+    let mut edges = multigraph.edges.len();
+    let mut best = (edges, n, 0);
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+    while let Some(Reverse((node_degree, node))) = heap.pop() {
+        if removed_node[node] || node_degree != degree[node] {
+            continue;
+        }
+        removed_node[node] = true;
+        order.push(node);
+        for edge in &incident[node] {
+            if removed_edge[*edge] {
+                continue;
+            }
+            removed_edge[*edge] = true;
+            edges -= 1;
+            let (source, target) = multigraph.edges[*edge];
+            let other = if source == node { target } else { source };
+            if other != node {
+                degree[other] -= 1;
+                heap.push(Reverse((degree[other], other)));
+            }
+        }
+        let remaining = n - order.len();
+        if remaining > 0 && edges * best.1 > best.0 * remaining {
+            best = (edges, remaining, order.len());
+        }
+    }
+    let (best_edges, best_size, peeled) = best;
+    let mut nodes: Vec<usize> = order[peeled..]
+        .iter()
+        .map(|node| multigraph.nodes[*node])
+        .collect();
+    nodes.sort_unstable();
+    (NodeIndices { nodes }, best_edges as f64 / best_size as f64)
+}
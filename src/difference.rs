@@ -0,0 +1,612 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::clusters::Clusters;
+use crate::iterators::{EdgeIndices, NodeIndices, NodeMap};
+use crate::key_index::KeyIndexes;
+use crate::transaction::MutationLog;
+use crate::{
+    digraph, find_node_by_weight, graph, EdgeIndex, InvalidNode, NodeIndex, StablePyGraph,
+};
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::{algo, EdgeType};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+
+/// Map each node of ``second`` to the node of ``first`` it's aligned with,
+/// either with the explicit ``node_map`` or by finding a node of ``first``
+/// with an equal payload.
+fn align_nodes<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    node_map: Option<HashMap<usize, usize>>,
+) -> PyResult<Vec<Option<NodeIndex>>> {
+    let mut alignment: Vec<Option<NodeIndex>> = vec![None; second.node_bound()];
+    match node_map {
+        Some(node_map) => {
+            for (second_node, first_node) in node_map {
+                if !second.contains_node(NodeIndex::new(second_node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node {} in node_map isn't in second",
+                        second_node
+                    )));
+                }
+                if !first.contains_node(NodeIndex::new(first_node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node {} in node_map isn't in first",
+                        first_node
+                    )));
+                }
+                alignment[second_node] = Some(NodeIndex::new(first_node));
+            }
+        }
+        None => {
+            for node in second.node_indices() {
+                alignment[node.index()] = find_node_by_weight(py, first, &second[node])?;
+            }
+        }
+    }
+    Ok(alignment)
+}
+
+/// The key of an edge between two nodes, with the endpoints in index order
+/// for undirected graphs so both directions of an edge have the same key.
+fn edge_key<Ty: EdgeType>(source: NodeIndex, target: NodeIndex) -> (NodeIndex, NodeIndex) {
+    if !Ty::is_directed() && target < source {
+        (target, source)
+    } else {
+        (source, target)
+    }
+}
+
+/// The keys of the edges of ``second`` with both endpoints aligned with
+/// nodes of ``first``, in terms of the nodes of ``first``.
+fn aligned_edge_keys<Ty: EdgeType>(
+    second: &StablePyGraph<Ty>,
+    alignment: &[Option<NodeIndex>],
+) -> HashSet<(NodeIndex, NodeIndex)> {
+    second
+        .edge_references()
+        .filter_map(|edge| {
+            match (
+                alignment[edge.source().index()],
+                alignment[edge.target().index()],
+            ) {
+                (Some(source), Some(target)) => Some(edge_key::<Ty>(source, target)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn difference<Ty: EdgeType>(
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    alignment: &[Option<NodeIndex>],
+) -> StablePyGraph<Ty> {
+    let second_edges = aligned_edge_keys(second, alignment);
+    let mut out_graph = first.clone();
+    out_graph.retain_edges(|graph, edge| {
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        !second_edges.contains(&edge_key::<Ty>(source, target))
+    });
+    out_graph
+}
+
+fn symmetric_difference<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    alignment: &[Option<NodeIndex>],
+) -> StablePyGraph<Ty> {
+    let mut out_graph = difference(first, second, alignment);
+    let first_edges: HashSet<(NodeIndex, NodeIndex)> = first
+        .edge_references()
+        .map(|edge| edge_key::<Ty>(edge.source(), edge.target()))
+        .collect();
+
+    let mut out_nodes: Vec<NodeIndex> = vec![NodeIndex::end(); second.node_bound()];
+    for node in second.node_indices() {
+        out_nodes[node.index()] = match alignment[node.index()] {
+            Some(index) => index,
+            None => out_graph.add_node(second[node].clone_ref(py)),
+        };
+    }
+    for edge in second.edge_references() {
+        let aligned = alignment[edge.source().index()].is_some()
+            && alignment[edge.target().index()].is_some();
+        let source = out_nodes[edge.source().index()];
+        let target = out_nodes[edge.target().index()];
+        if aligned && first_edges.contains(&edge_key::<Ty>(source, target)) {
+            continue;
+        }
+        out_graph.add_edge(source, target, edge.weight().clone_ref(py));
+    }
+    out_graph
+}
+
+/// Return a new PyGraph with the edges of ``first`` which aren't in ``second``
+///
+/// The nodes of ``second`` are aligned with the nodes of ``first`` either by
+/// the explicit ``node_map`` or, if it's not set, with the first node of
+/// ``first`` that has an equal weight/data payload (which operates in
+/// :math:`\mathcal{O}(n_1 n_2)`). An edge of ``first`` is in the difference
+/// unless ``second`` has an edge between the nodes aligned with its
+/// endpoints. Edges are compared only by their endpoints, not by their
+/// weight/data payloads, and all the parallel edges between a pair of nodes
+/// are kept or removed together.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   old = retworkx.PyGraph()
+///   old.add_nodes_from(["a", "b", "c"])
+///   old.add_edges_from_no_data([(0, 1), (1, 2)])
+///   new = retworkx.PyGraph()
+///   new.add_nodes_from(["c", "b", "a"])
+///   new.add_edges_from_no_data([(0, 1)])
+///   print(retworkx.graph_difference(old, new).edge_list())
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param dict node_map: An optional dictionary mapping node indices of
+///     ``second`` to the node indices of ``first`` they're aligned with.
+///     Nodes of ``second`` not in the dictionary aren't aligned with any
+///     node. If not set, nodes are aligned by weight/data payload equality.
+///
+/// :returns: A new PyGraph object with all the nodes of ``first``, with the
+///     same indices, and the edges of ``first`` which aren't in ``second``.
+///     It's worth noting the weight/data payload objects are passed by
+///     reference from ``first`` to this new object.
+/// :rtype: PyGraph
+///
+/// :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+///     corresponding graph
+#[pyfunction(node_map = "None")]
+#[pyo3(text_signature = "(first, second, /, node_map=None)")]
+pub fn graph_difference(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    node_map: Option<HashMap<usize, usize>>,
+) -> PyResult<graph::PyGraph> {
+    let alignment = align_nodes(py, &first.graph, &second.graph, node_map)?;
+    let out_graph = difference(&first.graph, &second.graph, &alignment);
+
+    Ok(graph::PyGraph {
+        graph: out_graph.into(),
+        node_removed: first.node_removed,
+        multigraph: first.multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
+    })
+}
+
+/// Return a new PyDiGraph with the edges of ``first`` which aren't in ``second``
+///
+/// The nodes of ``second`` are aligned with the nodes of ``first`` either by
+/// the explicit ``node_map`` or, if it's not set, with the first node of
+/// ``first`` that has an equal weight/data payload (which operates in
+/// :math:`\mathcal{O}(n_1 n_2)`). An edge of ``first`` is in the difference
+/// unless ``second`` has an edge in the same direction between the nodes
+/// aligned with its endpoints. Edges are compared only by their endpoints,
+/// not by their weight/data payloads, and all the parallel edges between a
+/// pair of nodes are kept or removed together.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   old = retworkx.PyDiGraph()
+///   old.add_nodes_from(["app", "lib", "util"])
+///   old.add_edges_from_no_data([(0, 1), (1, 2)])
+///   new = retworkx.PyDiGraph()
+///   new.add_nodes_from(["app", "lib", "util"])
+///   new.add_edges_from_no_data([(0, 1), (0, 2)])
+///   print(retworkx.digraph_difference(old, new).edge_list())
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param dict node_map: An optional dictionary mapping node indices of
+///     ``second`` to the node indices of ``first`` they're aligned with.
+///     Nodes of ``second`` not in the dictionary aren't aligned with any
+///     node. If not set, nodes are aligned by weight/data payload equality.
+///
+/// :returns: A new PyDiGraph object with all the nodes of ``first``, with
+///     the same indices, and the edges of ``first`` which aren't in
+///     ``second``. It's worth noting the weight/data payload objects are
+///     passed by reference from ``first`` to this new object.
+/// :rtype: PyDiGraph
+///
+/// :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+///     corresponding graph
+#[pyfunction(node_map = "None")]
+#[pyo3(text_signature = "(first, second, /, node_map=None)")]
+pub fn digraph_difference(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    node_map: Option<HashMap<usize, usize>>,
+) -> PyResult<digraph::PyDiGraph> {
+    let alignment = align_nodes(py, &first.graph, &second.graph, node_map)?;
+    let out_graph = difference(&first.graph, &second.graph, &alignment);
+
+    Ok(digraph::PyDiGraph {
+        graph: out_graph.into(),
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: first.node_removed,
+        multigraph: first.multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
+    })
+}
+
+/// Return a new PyGraph with the edges in exactly one of ``first`` and
+/// ``second``
+///
+/// The nodes of ``second`` are aligned with the nodes of ``first`` the same
+/// way as in :func:`~retworkx.graph_difference`. The output has all the
+/// nodes of ``first``, with the same indices, followed by the nodes of
+/// ``second`` which aren't aligned with any node of ``first``. Its edges are
+/// the edges of :func:`~retworkx.graph_difference` of ``first`` and
+/// ``second`` and the edges of ``second`` which aren't in ``first``, added
+/// between the corresponding nodes of the output.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   old = retworkx.PyGraph()
+///   old.add_nodes_from(["a", "b", "c"])
+///   old.add_edges_from_no_data([(0, 1), (1, 2)])
+///   new = retworkx.PyGraph()
+///   new.add_nodes_from(["a", "b", "c", "d"])
+///   new.add_edges_from_no_data([(0, 1), (2, 3)])
+///   print(retworkx.graph_symmetric_difference(old, new).edge_list())
+///
+/// :param PyGraph first: The first undirected graph object
+/// :param PyGraph second: The second undirected graph object
+/// :param dict node_map: An optional dictionary mapping node indices of
+///     ``second`` to the node indices of ``first`` they're aligned with.
+///     Nodes of ``second`` not in the dictionary aren't aligned with any
+///     node. If not set, nodes are aligned by weight/data payload equality.
+///
+/// :returns: A new PyGraph object with the edges in exactly one of ``first``
+///     and ``second``. It's worth noting the weight/data payload objects are
+///     passed by reference from ``first`` and ``second`` to this new object.
+/// :rtype: PyGraph
+///
+/// :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+///     corresponding graph
+#[pyfunction(node_map = "None")]
+#[pyo3(text_signature = "(first, second, /, node_map=None)")]
+pub fn graph_symmetric_difference(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    node_map: Option<HashMap<usize, usize>>,
+) -> PyResult<graph::PyGraph> {
+    let alignment = align_nodes(py, &first.graph, &second.graph, node_map)?;
+    let out_graph = symmetric_difference(py, &first.graph, &second.graph, &alignment);
+
+    Ok(graph::PyGraph {
+        graph: out_graph.into(),
+        node_removed: first.node_removed,
+        multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
+    })
+}
+
+/// Return a new PyDiGraph with the edges in exactly one of ``first`` and
+/// ``second``
+///
+/// The nodes of ``second`` are aligned with the nodes of ``first`` the same
+/// way as in :func:`~retworkx.digraph_difference`. The output has all the
+/// nodes of ``first``, with the same indices, followed by the nodes of
+/// ``second`` which aren't aligned with any node of ``first``. Its edges are
+/// the edges of :func:`~retworkx.digraph_difference` of ``first`` and
+/// ``second`` and the edges of ``second`` which aren't in ``first``, added
+/// between the corresponding nodes of the output.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   old = retworkx.PyDiGraph()
+///   old.add_nodes_from(["app", "lib", "util"])
+///   old.add_edges_from_no_data([(0, 1), (1, 2)])
+///   new = retworkx.PyDiGraph()
+///   new.add_nodes_from(["app", "lib", "util"])
+///   new.add_edges_from_no_data([(0, 1), (0, 2)])
+///   print(retworkx.digraph_symmetric_difference(old, new).edge_list())
+///
+/// :param PyDiGraph first: The first directed graph object
+/// :param PyDiGraph second: The second directed graph object
+/// :param dict node_map: An optional dictionary mapping node indices of
+///     ``second`` to the node indices of ``first`` they're aligned with.
+///     Nodes of ``second`` not in the dictionary aren't aligned with any
+///     node. If not set, nodes are aligned by weight/data payload equality.
+///
+/// :returns: A new PyDiGraph object with the edges in exactly one of
+///     ``first`` and ``second``. It's worth noting the weight/data payload
+///     objects are passed by reference from ``first`` and ``second`` to this
+///     new object.
+/// :rtype: PyDiGraph
+///
+/// :raises InvalidNode: If ``node_map`` has a node index which isn't in the
+///     corresponding graph
+#[pyfunction(node_map = "None")]
+#[pyo3(text_signature = "(first, second, /, node_map=None)")]
+pub fn digraph_symmetric_difference(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    node_map: Option<HashMap<usize, usize>>,
+) -> PyResult<digraph::PyDiGraph> {
+    let alignment = align_nodes(py, &first.graph, &second.graph, node_map)?;
+    let out_graph = symmetric_difference(py, &first.graph, &second.graph, &alignment);
+
+    Ok(digraph::PyDiGraph {
+        graph: out_graph.into(),
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: first.node_removed,
+        multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
+    })
+}
+
+/// Return a dictionary from the key of each node of ``graph`` to its index.
+fn node_keys<'p, Ty: EdgeType>(
+    py: Python<'p>,
+    graph: &StablePyGraph<Ty>,
+    node_key_fn: &Option<PyObject>,
+) -> PyResult<&'p PyDict> {
+    let keys = PyDict::new(py);
+    for node in graph.node_indices() {
+        let key = match node_key_fn {
+            Some(node_key_fn) => node_key_fn.call1(py, (&graph[node],))?,
+            None => graph[node].clone_ref(py),
+        };
+        if keys.contains(&key)? {
+            return Err(PyValueError::new_err(format!(
+                "Duplicate node key {} for node {}",
+                key.as_ref(py).repr()?,
+                node.index()
+            )));
+        }
+        keys.set_item(key, node.index())?;
+    }
+    Ok(keys)
+}
+
+fn diff<Ty: EdgeType>(
+    py: Python,
+    old: &StablePyGraph<Ty>,
+    new: &StablePyGraph<Ty>,
+    node_key_fn: Option<PyObject>,
+) -> PyResult<PyObject> {
+    let weights_equal = |a: &PyObject, b: &PyObject| -> PyResult<bool> {
+        a.as_ref(py)
+            .rich_compare(b, pyo3::basic::CompareOp::Eq)?
+            .is_true()
+    };
+
+    let old_keys = node_keys(py, old, &node_key_fn)?;
+    let new_keys = node_keys(py, new, &node_key_fn)?;
+
+    // Every node of new is identified by the index of the old node with the
+    // same key or, if there isn't one, by its own index offset past the
+    // indices of old, so matching edges have the same endpoint ids.
+    let mut new_ids: Vec<usize> = vec![0; new.node_bound()];
+    let mut node_map: DictMap<usize, usize> = DictMap::new();
+    let mut nodes_added: Vec<usize> = Vec::new();
+    let mut nodes_changed: Vec<(usize, usize)> = Vec::new();
+    for (key, new_index) in new_keys.iter() {
+        let new_index: usize = new_index.extract()?;
+        match old_keys.get_item(key) {
+            Some(old_index) => {
+                let old_index: usize = old_index.extract()?;
+                new_ids[new_index] = old_index;
+                node_map.insert(old_index, new_index);
+                if !weights_equal(
+                    &old[NodeIndex::new(old_index)],
+                    &new[NodeIndex::new(new_index)],
+                )? {
+                    nodes_changed.push((old_index, new_index));
+                }
+            }
+            None => {
+                new_ids[new_index] = old.node_bound() + new_index;
+                nodes_added.push(new_index);
+            }
+        }
+    }
+    let nodes_removed: Vec<usize> = old
+        .node_indices()
+        .map(|node| node.index())
+        .filter(|node| !node_map.contains_key(node))
+        .collect();
+
+    // Parallel edges between the same pair of nodes are matched in edge
+    // index order.
+    let edge_id = |source: usize, target: usize| -> (usize, usize) {
+        if !Ty::is_directed() && target < source {
+            (target, source)
+        } else {
+            (source, target)
+        }
+    };
+    let mut old_edges: HashMap<(usize, usize), VecDeque<EdgeIndex>> = HashMap::new();
+    for edge in old.edge_references() {
+        old_edges
+            .entry(edge_id(edge.source().index(), edge.target().index()))
+            .or_insert_with(VecDeque::new)
+            .push_back(edge.id());
+    }
+    let mut edges_added: Vec<usize> = Vec::new();
+    let mut edges_changed: Vec<(usize, usize)> = Vec::new();
+    for edge in new.edge_references() {
+        let id = edge_id(
+            new_ids[edge.source().index()],
+            new_ids[edge.target().index()],
+        );
+        match old_edges.get_mut(&id).and_then(|edges| edges.pop_front()) {
+            Some(old_edge) => {
+                if !weights_equal(&old[old_edge], edge.weight())? {
+                    edges_changed.push((old_edge.index(), edge.id().index()));
+                }
+            }
+            None => edges_added.push(edge.id().index()),
+        }
+    }
+    let mut edges_removed: Vec<usize> = old_edges
+        .into_iter()
+        .flat_map(|(_, edges)| edges.into_iter().map(|edge| edge.index()))
+        .collect();
+    edges_removed.sort_unstable();
+
+    let out_dict = PyDict::new(py);
+    out_dict.set_item(
+        "nodes_added",
+        NodeIndices { nodes: nodes_added }.into_py(py),
+    )?;
+    out_dict.set_item(
+        "nodes_removed",
+        NodeIndices {
+            nodes: nodes_removed,
+        }
+        .into_py(py),
+    )?;
+    out_dict.set_item("nodes_changed", nodes_changed)?;
+    out_dict.set_item(
+        "edges_added",
+        EdgeIndices { edges: edges_added }.into_py(py),
+    )?;
+    out_dict.set_item(
+        "edges_removed",
+        EdgeIndices {
+            edges: edges_removed,
+        }
+        .into_py(py),
+    )?;
+    out_dict.set_item("edges_changed", edges_changed)?;
+    out_dict.set_item("node_map", NodeMap { node_map }.into_py(py))?;
+    Ok(out_dict.into())
+}
+
+/// Compute an edit script of the changes between two snapshots of a graph
+///
+/// The nodes of ``old`` and ``new`` are matched by a key, the return value
+/// of ``node_key_fn`` for their weight/data payload, so unlike
+/// :func:`~retworkx.difference` the node indices of the two graphs don't need
+/// to be related. A matched node whose payload isn't equal in both graphs is
+/// changed. Edges are matched by the keys of their endpoints (and their
+/// direction for a :class:`~retworkx.PyDiGraph`), with parallel edges between
+/// the same nodes matched in edge index order, and a matched edge whose
+/// payload isn't equal in both graphs is changed.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   old = retworkx.PyDiGraph()
+///   old.add_nodes_from([("app", "1.0"), ("lib", "2.0"), ("util", "0.1")])
+///   old.add_edges_from([(0, 1, "requires"), (1, 2, "requires")])
+///   new = retworkx.PyDiGraph()
+///   new.add_nodes_from([("lib", "2.1"), ("app", "1.0"), ("log", "0.3")])
+///   new.add_edges_from([(1, 0, "requires"), (0, 2, "requires")])
+///   diff = retworkx.graph_diff(old, new, node_key_fn=lambda node: node[0])
+///   for old_index, new_index in diff["nodes_changed"]:
+///       print("changed", old[old_index], "->", new[new_index])
+///   for index in diff["nodes_added"]:
+///       print("added", new[index])
+///   for index in diff["nodes_removed"]:
+///       print("removed", old[index])
+///   for index in diff["edges_added"]:
+///       source, target = new.get_edge_endpoints_by_index(index)
+///       print("added edge", new[source][0], "->", new[target][0])
+///
+/// :param old: The old graph, either a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`
+/// :param new: The new graph, of the same type as ``old``
+/// :param node_key_fn: An optional callable which will be passed the
+///     weight/data payload of each node and returns the key used to match
+///     the nodes of the two graphs, which must be hashable and unique within
+///     each graph. If not specified the payloads themselves are the keys.
+///
+/// :returns: A dictionary with the keys:
+///
+///     * ``nodes_added``: The indices in ``new`` of the nodes not in ``old``
+///       as a :class:`~retworkx.NodeIndices` object
+///     * ``nodes_removed``: The indices in ``old`` of the nodes not in
+///       ``new`` as a :class:`~retworkx.NodeIndices` object
+///     * ``nodes_changed``: A list of ``(old_index, new_index)`` tuples of the
+///       matched nodes with different payloads
+///     * ``edges_added``: The indices in ``new`` of the edges not in ``old``
+///       as an :class:`~retworkx.EdgeIndices` object
+///     * ``edges_removed``: The indices in ``old`` of the edges not in
+///       ``new`` as an :class:`~retworkx.EdgeIndices` object
+///     * ``edges_changed``: A list of ``(old_index, new_index)`` tuples of
+///       the matched edges with different payloads
+///     * ``node_map``: A :class:`~retworkx.NodeMap` from the index in ``old``
+///       to the index in ``new`` of every matched node
+/// :rtype: dict
+///
+/// :raises TypeError: If ``old`` and ``new`` aren't both a
+///     :class:`~retworkx.PyGraph` or both a :class:`~retworkx.PyDiGraph`
+/// :raises ValueError: If two nodes of the same graph have the same key
+#[pyfunction(node_key_fn = "None")]
+#[pyo3(text_signature = "(old, new, /, node_key_fn=None)")]
+pub fn graph_diff(
+    py: Python,
+    old: &PyAny,
+    new: &PyAny,
+    node_key_fn: Option<PyObject>,
+) -> PyResult<PyObject> {
+    if let (Ok(old), Ok(new)) = (
+        old.extract::<PyRef<graph::PyGraph>>(),
+        new.extract::<PyRef<graph::PyGraph>>(),
+    ) {
+        diff(py, &old.graph, &new.graph, node_key_fn)
+    } else if let (Ok(old), Ok(new)) = (
+        old.extract::<PyRef<digraph::PyDiGraph>>(),
+        new.extract::<PyRef<digraph::PyDiGraph>>(),
+    ) {
+        diff(py, &old.graph, &new.graph, node_key_fn)
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "Invalid Input Types {} and {} for old and new",
+            old.get_type(),
+            new.get_type()
+        )))
+    }
+}
@@ -12,7 +12,7 @@
 
 use std::cmp;
 use std::cmp::Ordering;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use std::fs::File;
 use std::io::prelude::*;
@@ -24,7 +24,7 @@ use indexmap::IndexSet;
 
 use retworkx_core::dictmap::*;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyValueError};
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PyList, PyLong, PyString, PyTuple};
@@ -34,24 +34,32 @@ use pyo3::Python;
 use ndarray::prelude::*;
 use num_complex::Complex64;
 use num_traits::Zero;
-use numpy::PyReadonlyArray2;
+use numpy::{IntoPyArray, PyReadonlyArray2};
 
 use petgraph::algo;
-use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
 
 use petgraph::visit::{
-    GraphBase, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeFiltered, NodeIndexable,
-    Visitable,
+    EdgeIndexable, GraphBase, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeFiltered,
+    NodeIndexable, Visitable,
 };
 
+use super::clusters::{cluster_members, quotient_graph, Clusters};
+use super::csr::CSRSnapshot;
 use super::dot_utils::build_dot;
+use super::isomorphism::vf2::DiGraphVf2Mapping;
 use super::iterators::{
-    EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, NodeMap, WeightedEdgeList,
+    EdgeIndexMap, EdgeIndices, EdgeList, EdgeMap, NodeIndices, NodeMap, WeightedEdgeList,
 };
+use super::key_index::{not_indexed, KeyIndex, KeyIndexes};
+use super::property::PropertyArray;
+use super::shared_graph::SharedGraph;
+use super::transaction::{GraphTransaction, MutationLog};
 use super::{
-    find_node_by_weight, merge_duplicates, weight_callable, DAGHasCycle, DAGWouldCycle, IsNan,
-    NoEdgeBetweenNodes, NoSuitableNeighbors, NodesRemoved, StablePyGraph,
+    adjacency_row, compact_graph, degree_histogram, edges_from_arrays, find_node_by_weight,
+    merge_duplicates, parallel_edge_groups, self_loop_edges, weight_callable, DAGHasCycle,
+    DAGWouldCycle, EdgeIndex, IsNan, NoEdgeBetweenNodes, NoSuitableNeighbors, NodeIndex,
+    NodesRemoved, StablePyGraph,
 };
 
 use super::dag_algo::is_directed_acyclic_graph;
@@ -154,11 +162,14 @@ use super::dag_algo::is_directed_acyclic_graph;
 #[pyo3(text_signature = "(/, check_cycle=False, multigraph=True)")]
 #[derive(Clone)]
 pub struct PyDiGraph {
-    pub graph: StablePyGraph<Directed>,
+    pub graph: SharedGraph<Directed>,
     pub cycle_state: algo::DfsSpace<NodeIndex, <StablePyGraph<Directed> as Visitable>::Map>,
     pub check_cycle: bool,
     pub node_removed: bool,
     pub multigraph: bool,
+    pub log: MutationLog<Directed>,
+    pub clusters: Clusters,
+    pub indexes: KeyIndexes,
 }
 
 impl GraphBase for PyDiGraph {
@@ -180,6 +191,192 @@ impl NodeCount for PyDiGraph {
 
 // Rust side only PyDiGraph methods
 impl PyDiGraph {
+    /// Undo the changes made since the innermost open transaction was opened
+    pub fn rollback_transaction(&mut self, py: Python) {
+        self.log.rollback(py, &mut self.graph, &mut self.clusters);
+        if self.graph.node_bound() != self.graph.node_count() {
+            self.node_removed = true;
+        }
+    }
+
+    /// Splice ``other`` into this graph in place of ``node_index``. This can
+    /// fail part way through, so callers should run it in a transaction.
+    fn splice_subgraph(
+        &mut self,
+        py: Python,
+        node_index: NodeIndex,
+        other: &PyDiGraph,
+        edge_map_fn: PyObject,
+        node_filter: Option<PyObject>,
+        edge_weight_map: Option<PyObject>,
+    ) -> PyResult<NodeMap> {
+        let weight_map_fn = |obj: &PyObject, weight_fn: &Option<PyObject>| -> PyResult<PyObject> {
+            match weight_fn {
+                Some(weight_fn) => weight_fn.call1(py, (obj,)),
+                None => Ok(obj.clone_ref(py)),
+            }
+        };
+        let map_fn = |source: usize, target: usize, weight: &PyObject| -> PyResult<Option<usize>> {
+            let res = edge_map_fn.call1(py, (source, target, weight))?;
+            res.extract(py)
+        };
+        let filter_fn = |obj: &PyObject, filter_fn: &Option<PyObject>| -> PyResult<bool> {
+            match filter_fn {
+                Some(filter) => {
+                    let res = filter.call1(py, (obj,))?;
+                    res.extract(py)
+                }
+                None => Ok(true),
+            }
+        };
+        // Copy nodes from other to self
+        let mut out_map: DictMap<usize, usize> = DictMap::with_capacity(other.node_count());
+        for node in other.graph.node_indices() {
+            let node_weight = other.graph[node].clone_ref(py);
+            if !filter_fn(&node_weight, &node_filter)? {
+                continue;
+            }
+            let new_index = self.log.add_node(&mut self.graph, node_weight);
+            out_map.insert(node.index(), new_index.index());
+        }
+        // If no nodes are copied bail here since there is nothing left
+        // to do.
+        if out_map.is_empty() {
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, node_index);
+            self.node_removed = true;
+            // Return a new empty map to clear allocation from out_map
+            return Ok(NodeMap {
+                node_map: DictMap::new(),
+            });
+        }
+        // Copy edges from other to self
+        for edge in other.graph.edge_references().filter(|edge| {
+            out_map.contains_key(&edge.target().index())
+                && out_map.contains_key(&edge.source().index())
+        }) {
+            self._add_edge(
+                NodeIndex::new(out_map[&edge.source().index()]),
+                NodeIndex::new(out_map[&edge.target().index()]),
+                weight_map_fn(edge.weight(), &edge_weight_map)?,
+            )?;
+        }
+        // Add edges to/from node to nodes in other
+        let in_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
+            .graph
+            .edges_directed(node_index, petgraph::Direction::Incoming)
+            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
+            .collect();
+        let out_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
+            .graph
+            .edges_directed(node_index, petgraph::Direction::Outgoing)
+            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
+            .collect();
+        for (source, target, weight) in in_edges {
+            let old_index = map_fn(source.index(), target.index(), &weight)?;
+            let target_out = match old_index {
+                Some(old_index) => match out_map.get(&old_index) {
+                    Some(new_index) => NodeIndex::new(*new_index),
+                    None => {
+                        return Err(PyIndexError::new_err(format!(
+                            "No mapped index {} found",
+                            old_index
+                        )))
+                    }
+                },
+                None => continue,
+            };
+            self._add_edge(source, target_out, weight)?;
+        }
+        for (source, target, weight) in out_edges {
+            let old_index = map_fn(source.index(), target.index(), &weight)?;
+            let source_out = match old_index {
+                Some(old_index) => match out_map.get(&old_index) {
+                    Some(new_index) => NodeIndex::new(*new_index),
+                    None => {
+                        return Err(PyIndexError::new_err(format!(
+                            "No mapped index {} found",
+                            old_index
+                        )))
+                    }
+                },
+                None => continue,
+            };
+            self._add_edge(source_out, target, weight)?;
+        }
+        // Remove node
+        self.log
+            .remove_node(&mut self.graph, &mut self.clusters, node_index);
+        self.node_removed = true;
+        Ok(NodeMap { node_map: out_map })
+    }
+
+    /// Replace an occurrence of a pattern, given as a mapping of the nodes of
+    /// the pattern to the nodes of this graph, with a copy of ``replacement``
+    /// and rewire the edges between the occurrence and the rest of the graph
+    /// with ``boundary_map``. Returns ``None`` without changing the graph if
+    /// a pattern node that isn't in ``boundary_map`` has an edge to the rest
+    /// of the graph.
+    fn substitute_occurrence(
+        &mut self,
+        py: Python,
+        occurrence: &DictMap<usize, usize>,
+        replacement: &PyDiGraph,
+        boundary_map: &HashMap<usize, usize>,
+    ) -> PyResult<Option<NodeMap>> {
+        let pattern_node: HashMap<NodeIndex, usize> = occurrence
+            .iter()
+            .map(|(pattern, node)| (NodeIndex::new(*node), *pattern))
+            .collect();
+        let mut boundary_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+        for (pattern, node) in occurrence.iter() {
+            let node = NodeIndex::new(*node);
+            let in_edges = self
+                .graph
+                .edges_directed(node, petgraph::Direction::Incoming)
+                .filter(|edge| !pattern_node.contains_key(&edge.source()));
+            let out_edges = self
+                .graph
+                .edges_directed(node, petgraph::Direction::Outgoing)
+                .filter(|edge| !pattern_node.contains_key(&edge.target()));
+            for edge in in_edges.chain(out_edges) {
+                if !boundary_map.contains_key(pattern) {
+                    return Ok(None);
+                }
+                boundary_edges.push((edge.source(), edge.target(), edge.weight().clone_ref(py)));
+            }
+        }
+        let mut out_map: DictMap<usize, usize> = DictMap::with_capacity(replacement.node_count());
+        for node in replacement.graph.node_indices() {
+            let new_index = self
+                .log
+                .add_node(&mut self.graph, replacement.graph[node].clone_ref(py));
+            out_map.insert(node.index(), new_index.index());
+        }
+        for edge in replacement.graph.edge_references() {
+            self._add_edge(
+                NodeIndex::new(out_map[&edge.source().index()]),
+                NodeIndex::new(out_map[&edge.target().index()]),
+                edge.weight().clone_ref(py),
+            )?;
+        }
+        for node in occurrence.values() {
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, NodeIndex::new(*node));
+        }
+        self.node_removed = true;
+        let rewire = |node: NodeIndex| -> NodeIndex {
+            match pattern_node.get(&node) {
+                Some(pattern) => NodeIndex::new(out_map[&boundary_map[pattern]]),
+                None => node,
+            }
+        };
+        for (source, target, weight) in boundary_edges {
+            self._add_edge(rewire(source), rewire(target), weight)?;
+        }
+        Ok(Some(NodeMap { node_map: out_map }))
+    }
+
     fn add_edge_no_cycle_check(
         &mut self,
         p_index: NodeIndex,
@@ -189,12 +386,11 @@ impl PyDiGraph {
         if !self.multigraph {
             let exists = self.graph.find_edge(p_index, c_index);
             if let Some(index) = exists {
-                let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                *edge_weight = edge;
+                self.log.set_edge_weight(&mut self.graph, index, edge);
                 return index.index();
             }
         }
-        let edge = self.graph.add_edge(p_index, c_index, edge);
+        let edge = self.log.add_edge(&mut self.graph, p_index, c_index, edge);
         edge.index()
     }
 
@@ -252,7 +448,7 @@ impl PyDiGraph {
                 self._add_edge(other_index, index, weight.clone_ref(py))?;
                 self._add_edge(index, node_between_index, weight.clone_ref(py))?;
             }
-            self.graph.remove_edge(edge_index);
+            self.log.remove_edge(&mut self.graph, edge_index);
         }
         Ok(())
     }
@@ -264,11 +460,14 @@ impl PyDiGraph {
     #[args(check_cycle = "false", multigraph = "true")]
     fn new(check_cycle: bool, multigraph: bool) -> Self {
         PyDiGraph {
-            graph: StablePyGraph::<Directed>::new(),
+            graph: StablePyGraph::<Directed>::default().into(),
             cycle_state: algo::DfsSpace::default(),
             check_cycle,
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         }
     }
 
@@ -279,6 +478,7 @@ impl PyDiGraph {
         out_dict.set_item("nodes", node_dict)?;
         out_dict.set_item("nodes_removed", self.node_removed)?;
         out_dict.set_item("multigraph", self.multigraph)?;
+        out_dict.set_item("clusters", self.clusters.to_object(py))?;
         let dir = petgraph::Direction::Incoming;
         for node_index in self.graph.node_indices() {
             let node_data = self.graph.node_weight(node_index).unwrap();
@@ -295,7 +495,7 @@ impl PyDiGraph {
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
-        self.graph = StablePyGraph::<Directed>::new();
+        self.graph = StablePyGraph::<Directed>::default().into();
         let dict_state = state.cast_as::<PyDict>(py)?;
 
         let nodes_dict = dict_state.get_item("nodes").unwrap().downcast::<PyDict>()?;
@@ -310,6 +510,12 @@ impl PyDiGraph {
             .unwrap()
             .downcast::<PyBool>()?;
         self.multigraph = multigraph_raw.extract()?;
+        self.clusters = match dict_state.get_item("clusters") {
+            Some(raw_clusters) => raw_clusters.extract()?,
+            None => Clusters::default(),
+        };
+        self.log.node_changes.touch_all();
+        self.log.edge_changes.touch_all();
         let mut node_indices: Vec<usize> = Vec::new();
         for raw_index in nodes_dict.keys() {
             let tmp_index = raw_index.downcast::<PyLong>()?;
@@ -403,6 +609,172 @@ impl PyDiGraph {
         }
         false
     }
+
+    /// Return the number of self loops in the graph
+    ///
+    /// A self loop is an edge whose source and target are the same node.
+    ///
+    /// :returns: The number of self loop edges
+    /// :rtype: int
+    #[pyo3(text_signature = "(self)")]
+    pub fn number_of_selfloops(&self) -> usize {
+        self.graph
+            .edge_references()
+            .filter(|edge| edge.source() == edge.target())
+            .count()
+    }
+
+    /// Return the self loops in the graph
+    ///
+    /// :returns: The edge indices of the self loop edges in increasing order.
+    ///     The endpoints of each edge can be looked up with
+    ///     :meth:`~PyDiGraph.get_edge_endpoints_by_index`.
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn selfloop_edges(&self) -> EdgeIndices {
+        EdgeIndices {
+            edges: self_loop_edges(&self.graph)
+                .into_iter()
+                .map(|edge| edge.index())
+                .collect(),
+        }
+    }
+
+    /// Return the groups of parallel edges in the graph
+    ///
+    /// :returns: A list of lists of edge indices, one list for each pair of
+    ///     nodes with more than one edge between them. The groups are ordered
+    ///     by their lowest edge index and the edge indices in each group are
+    ///     in increasing order.
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn parallel_edge_groups(&self) -> Vec<Vec<usize>> {
+        parallel_edge_groups(&self.graph)
+            .into_iter()
+            .filter(|(_, edges)| edges.len() > 1)
+            .map(|(_, edges)| edges.into_iter().map(|edge| edge.index()).collect())
+            .collect()
+    }
+
+    /// Return the number of edges between each pair of adjacent nodes
+    ///
+    /// :returns: A dictionary where the keys are tuples of node indices
+    ///     ``(source, target)`` and the values are the number of edges
+    ///     between them.
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_multiplicities(&self) -> DictMap<(usize, usize), usize> {
+        parallel_edge_groups(&self.graph)
+            .into_iter()
+            .map(|(endpoints, edges)| (endpoints, edges.len()))
+            .collect()
+    }
+
+    /// Merge all the parallel edges in the graph into a single edge
+    ///
+    /// For each group of parallel edges (see
+    /// :meth:`~PyDiGraph.parallel_edge_groups`) the edge with the lowest index
+    /// is kept and the other edges are removed from the graph. After this
+    /// method is called :meth:`~PyDiGraph.has_parallel_edges` will return
+    /// ``False``.
+    ///
+    /// :param merge_fn: An optional callable which takes two positional
+    ///     arguments, the weight/data payloads of two parallel edges, and
+    ///     returns a new weight/data payload for the merged edge. It's
+    ///     called on the kept edge's payload and each removed edge's payload
+    ///     in edge index order, similar to :func:`functools.reduce`. If not
+    ///     specified the weight/data payload of the kept edge is unchanged.
+    #[pyo3(text_signature = "(self, /, merge_fn=None)")]
+    pub fn merge_parallel_edges(&mut self, py: Python, merge_fn: Option<PyObject>) -> PyResult<()> {
+        self.remove_parallel_edges(py, merge_fn)?;
+        Ok(())
+    }
+
+    /// Remove all the parallel edges from the graph
+    ///
+    /// This works like :meth:`~PyDiGraph.merge_parallel_edges`, for each group of
+    /// parallel edges the edge with the lowest index is kept and the other
+    /// edges are removed, except that the removed edge indices are returned.
+    ///
+    /// :param combiner: An optional callable which takes two positional
+    ///     arguments, the weight/data payloads of two parallel edges, and
+    ///     returns a new weight/data payload for the kept edge. It's called
+    ///     the same way as ``merge_fn`` for
+    ///     :meth:`~PyDiGraph.merge_parallel_edges`. If not specified the
+    ///     weight/data payload of the kept edge is unchanged.
+    ///
+    /// :returns: The indices of the removed edges in increasing order
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, /, combiner=None)")]
+    pub fn remove_parallel_edges(
+        &mut self,
+        py: Python,
+        combiner: Option<PyObject>,
+    ) -> PyResult<EdgeIndices> {
+        let mut removed: Vec<usize> = Vec::new();
+        for (_, edges) in parallel_edge_groups(&self.graph) {
+            if edges.len() < 2 {
+                continue;
+            }
+            let kept = edges[0];
+            if let Some(combiner) = &combiner {
+                let mut weight = self.graph[kept].clone_ref(py);
+                for edge in &edges[1..] {
+                    weight = combiner.call1(py, (weight, &self.graph[*edge]))?;
+                }
+                self.log.set_edge_weight(&mut self.graph, kept, weight);
+            }
+            for edge in &edges[1..] {
+                self.log.remove_edge(&mut self.graph, *edge);
+                removed.push(edge.index());
+            }
+        }
+        removed.sort_unstable();
+        Ok(EdgeIndices { edges: removed })
+    }
+
+    /// Remove all the self loops from the graph
+    ///
+    /// A self loop is an edge whose source and target are the same node.
+    ///
+    /// :returns: The indices of the removed edges in increasing order
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn remove_self_loops(&mut self) -> EdgeIndices {
+        let self_loops = self_loop_edges(&self.graph);
+        for edge in &self_loops {
+            self.log.remove_edge(&mut self.graph, *edge);
+        }
+        EdgeIndices {
+            edges: self_loops.into_iter().map(|edge| edge.index()).collect(),
+        }
+    }
+
+    /// Remove all the isolated nodes from the graph
+    ///
+    /// An isolated node is a node without any edges. A node whose only edges
+    /// are self loops isn't isolated.
+    ///
+    /// :returns: The indices of the removed nodes in increasing order
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn remove_isolates(&mut self) -> NodeIndices {
+        let isolates: Vec<NodeIndex> = self
+            .graph
+            .node_indices()
+            .filter(|node| self.graph.neighbors_undirected(*node).next().is_none())
+            .collect();
+        for node in &isolates {
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, *node);
+        }
+        if !isolates.is_empty() {
+            self.node_removed = true;
+        }
+        NodeIndices {
+            nodes: isolates.into_iter().map(|node| node.index()).collect(),
+        }
+    }
     /// Return the number of nodes in the graph
     #[pyo3(text_signature = "(self)")]
     pub fn num_nodes(&self) -> usize {
@@ -710,8 +1082,7 @@ impl PyDiGraph {
             Some(edge_index) => edge_index,
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
-        let data = self.graph.edge_weight_mut(edge_index).unwrap();
-        *data = edge;
+        self.log.set_edge_weight(&mut self.graph, edge_index, edge);
         Ok(())
     }
 
@@ -724,11 +1095,13 @@ impl PyDiGraph {
     ///     index
     #[pyo3(text_signature = "(self, edge_index, edge, /)")]
     pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
-        match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
-            Some(data) => *data = edge,
-            None => return Err(PyIndexError::new_err("No edge found for index")),
-        };
-        Ok(())
+        match self
+            .log
+            .set_edge_weight(&mut self.graph, EdgeIndex::new(edge_index), edge)
+        {
+            Some(_) => Ok(()),
+            None => Err(PyIndexError::new_err("No edge found for index")),
+        }
     }
 
     /// Return the node data for a given node index
@@ -850,7 +1223,8 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
         let index = NodeIndex::new(node);
-        self.graph.remove_node(index);
+        self.log
+            .remove_node(&mut self.graph, &mut self.clusters, index);
         self.node_removed = true;
         Ok(())
     }
@@ -922,7 +1296,8 @@ impl PyDiGraph {
         for (source, target, weight) in edge_list {
             self._add_edge(source, target, weight)?;
         }
-        self.graph.remove_node(index);
+        self.log
+            .remove_node(&mut self.graph, &mut self.clusters, index);
         self.node_removed = true;
         Ok(())
     }
@@ -1001,6 +1376,58 @@ impl PyDiGraph {
         Ok(out_list)
     }
 
+    /// Add new edges to the dag from arrays of node indices.
+    ///
+    /// This method is functionally equivalent to :meth:`add_edges_from` but
+    /// the edges are described by two 1 dimensional numpy integer arrays
+    /// of equal length, where the edge ``i`` is from ``sources[i]`` to
+    /// ``targets[i]``. This avoids creating a Python tuple for every edge and
+    /// is significantly faster when building large graphs, for example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import numpy as np
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.PyDiGraph()
+    ///     graph.add_nodes_from(range(4))
+    ///     sources = np.array([0, 1, 2])
+    ///     targets = np.array([1, 2, 3])
+    ///     graph.add_edges_from_array(sources, targets, np.array([0.5, 1.5, 2.5]))
+    ///     print(graph.weighted_edge_list())
+    ///
+    /// :param numpy.ndarray sources: A 1 dimensional integer array of node
+    ///     indices for the parent of each edge
+    /// :param numpy.ndarray targets: A 1 dimensional integer array of node
+    ///     indices for the child of each edge
+    /// :param weights: An optional sequence (or numpy array) the same length
+    ///     as ``sources`` with the weight/data payload for each edge. A
+    ///     ``float64`` numpy array will have its elements converted to Python
+    ///     floats. If not specified ``None`` is used for every edge.
+    ///
+    /// :returns: The edge indices of the newly created (or updated) edges
+    /// :rtype: EdgeIndices
+    /// :raises IndexError: If any of the node indices are not present in the
+    ///     graph
+    /// :raises ValueError: If the input arrays are not the same length
+    /// :raises DAGWouldCycle: If :attr:`~retworkx.PyDiGraph.check_cycle` is
+    ///     enabled and adding an edge would introduce a cycle
+    #[pyo3(text_signature = "(self, sources, targets, /, weights=None)")]
+    pub fn add_edges_from_array(
+        &mut self,
+        py: Python,
+        sources: &PyAny,
+        targets: &PyAny,
+        weights: Option<&PyAny>,
+    ) -> PyResult<EdgeIndices> {
+        let new_edges = edges_from_arrays(py, &self.graph, sources, targets, weights)?;
+        let mut out_list: Vec<usize> = Vec::with_capacity(new_edges.len());
+        for (p_index, c_index, weight) in new_edges {
+            out_list.push(self._add_edge(p_index, c_index, weight)?);
+        }
+        Ok(EdgeIndices { edges: out_list })
+    }
+
     /// Extend graph from an edge list
     ///
     /// This method differs from :meth:`add_edges_from_no_data` in that it will
@@ -1019,7 +1446,7 @@ impl PyDiGraph {
         for (source, target) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+                self.log.add_node(&mut self.graph, py.None());
             }
             self._add_edge(NodeIndex::new(source), NodeIndex::new(target), py.None())?;
         }
@@ -1044,7 +1471,7 @@ impl PyDiGraph {
         for (source, target, weight) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+                self.log.add_node(&mut self.graph, py.None());
             }
             self._add_edge(NodeIndex::new(source), NodeIndex::new(target), weight)?;
         }
@@ -1165,7 +1592,7 @@ impl PyDiGraph {
             Some(edge_index) => edge_index,
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
-        self.graph.remove_edge(edge_index);
+        self.log.remove_edge(&mut self.graph, edge_index);
         Ok(())
     }
 
@@ -1175,7 +1602,7 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(self, edge, /)")]
     pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
         let edge_index = EdgeIndex::new(edge);
-        self.graph.remove_edge(edge_index);
+        self.log.remove_edge(&mut self.graph, edge_index);
         Ok(())
     }
 
@@ -1199,7 +1626,7 @@ impl PyDiGraph {
                 Some(edge_index) => edge_index,
                 None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
             };
-            self.graph.remove_edge(edge_index);
+            self.log.remove_edge(&mut self.graph, edge_index);
         }
         Ok(())
     }
@@ -1212,7 +1639,7 @@ impl PyDiGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
     pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
-        let index = self.graph.add_node(obj);
+        let index = self.log.add_node(&mut self.graph, obj);
         Ok(index.index())
     }
 
@@ -1222,16 +1649,104 @@ impl PyDiGraph {
     /// indices in order. If there is more than one node in the graph with the
     /// same weight only the first match (by node index) will be returned.
     ///
+    /// If the nodes are indexed by their weight/data payloads, by calling
+    /// :meth:`~retworkx.PyDiGraph.index_nodes_by` without a ``key_fn``, a
+    /// hashable ``obj`` is looked up in that index in O(1) time instead.
+    ///
     /// :param obj: The weight to look for in the graph.
     ///
     /// :returns: the index of the first node in the graph that is equal to the
     ///     weight. If no match is found ``None`` will be returned.
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
-    pub fn find_node_by_weight(&self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
+    pub fn find_node_by_weight(&mut self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
+        if let Some(index) = self.indexes.nodes.as_mut() {
+            if index.by_payload() && obj.as_ref(py).hash().is_ok() {
+                let graph = &self.graph;
+                index.update(
+                    py,
+                    &mut self.log.node_changes,
+                    |node| graph.node_weight(NodeIndex::new(node)),
+                    graph
+                        .node_indices()
+                        .map(|node| (node.index(), &graph[node])),
+                )?;
+                return Ok(index.get(py, obj)?.first().copied());
+            }
+        }
         find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
     }
 
+    /// Return the indices of all nodes whose weight matches a filter function
+    ///
+    /// ``filter_function`` is called once for each node weight in the graph,
+    /// in node index order, and the index of every node it returns ``True``
+    /// for is included in the output.
+    ///
+    /// If ``batch_size`` is set ``filter_function`` is instead called with a
+    /// list of up to ``batch_size`` node weights at a time, again in node
+    /// index order, and must return a sequence of booleans of the same
+    /// length. This cuts the number of Python calls, which lets a vectorized
+    /// filter (for example one using numpy) evaluate many nodes at once.
+    ///
+    /// :param filter_function: A callable which takes a single positional
+    ///     argument, the node weight, and returns a boolean indicating
+    ///     whether the node should be included.
+    /// :param int batch_size: The number of node weights to pass to
+    ///     ``filter_function`` per call. If not set ``filter_function`` is
+    ///     called with one node weight at a time.
+    ///
+    /// :returns: A list of the node indices which match the filter function
+    /// :rtype: NodeIndices
+    ///
+    /// :raises ValueError: If ``batch_size`` is 0 or ``filter_function``
+    ///     returns the wrong number of results for a batch
+    #[pyo3(text_signature = "(self, filter_function, /, batch_size=None)")]
+    pub fn filter_nodes(
+        &self,
+        py: Python,
+        filter_function: PyObject,
+        batch_size: Option<usize>,
+    ) -> PyResult<NodeIndices> {
+        let mut nodes: Vec<usize> = Vec::new();
+        match batch_size {
+            None => {
+                for (index, weight) in self
+                    .graph
+                    .node_indices()
+                    .map(|index| (index, self.graph.node_weight(index).unwrap()))
+                {
+                    if filter_function.call1(py, (weight,))?.is_true(py)? {
+                        nodes.push(index.index());
+                    }
+                }
+            }
+            Some(0) => return Err(PyValueError::new_err("batch_size must be at least 1")),
+            Some(batch_size) => {
+                let indices: Vec<NodeIndex> = self.graph.node_indices().collect();
+                for batch in indices.chunks(batch_size) {
+                    let weights = PyList::new(py, batch.iter().map(|index| &self.graph[*index]));
+                    let res = filter_function.call1(py, (weights,))?;
+                    let mut count = 0;
+                    for matched in res.as_ref(py).iter()? {
+                        if count < batch.len() && matched?.is_true()? {
+                            nodes.push(batch[count].index());
+                        }
+                        count += 1;
+                    }
+                    if count != batch.len() {
+                        return Err(PyValueError::new_err(format!(
+                            "filter_function returned {} results for a batch of {} nodes",
+                            count,
+                            batch.len()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(NodeIndices { nodes })
+    }
+
     /// Merge two nodes in the graph.
     ///
     /// If the nodes have equal weight objects then all the edges into and out of `u` will be added
@@ -1298,8 +1813,8 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(self, parent, obj, edge, /)")]
     pub fn add_child(&mut self, parent: usize, obj: PyObject, edge: PyObject) -> PyResult<usize> {
         let index = NodeIndex::new(parent);
-        let child_node = self.graph.add_node(obj);
-        self.graph.add_edge(index, child_node, edge);
+        let child_node = self.log.add_node(&mut self.graph, obj);
+        self.log.add_edge(&mut self.graph, index, child_node, edge);
         Ok(child_node.index())
     }
 
@@ -1317,8 +1832,8 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(self, child, obj, edge, /)")]
     pub fn add_parent(&mut self, child: usize, obj: PyObject, edge: PyObject) -> PyResult<usize> {
         let index = NodeIndex::new(child);
-        let parent_node = self.graph.add_node(obj);
-        self.graph.add_edge(parent_node, index, edge);
+        let parent_node = self.log.add_node(&mut self.graph, obj);
+        self.log.add_edge(&mut self.graph, parent_node, index, edge);
         Ok(parent_node.index())
     }
 
@@ -1389,7 +1904,7 @@ impl PyDiGraph {
     ///
     /// :param int node: The index of the node to get the neighbors of
     ///
-    /// :returns: A list of the neighbor node indices
+    /// :returns: A list of the neighbor node indices, sorted by index
     /// :rtype: NodeIndices
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn neighbors(&self, node: usize) -> NodeIndices {
@@ -1398,8 +1913,8 @@ impl PyDiGraph {
                 .graph
                 .neighbors(NodeIndex::new(node))
                 .map(|node| node.index())
-                .collect::<HashSet<usize>>()
-                .drain()
+                .collect::<BTreeSet<usize>>()
+                .into_iter()
                 .collect(),
         }
     }
@@ -1610,7 +2125,7 @@ impl PyDiGraph {
     pub fn add_nodes_from(&mut self, obj_list: Vec<PyObject>) -> NodeIndices {
         let out_list: Vec<usize> = obj_list
             .into_iter()
-            .map(|obj| self.graph.add_node(obj).index())
+            .map(|obj| self.log.add_node(&mut self.graph, obj).index())
             .collect();
         NodeIndices { nodes: out_list }
     }
@@ -1625,7 +2140,8 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_nodes_from(&mut self, index_list: Vec<usize>) -> PyResult<()> {
         for node in index_list.iter().map(|x| NodeIndex::new(*x)) {
-            self.graph.remove_node(node);
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, node);
         }
         Ok(())
     }
@@ -1657,17 +2173,273 @@ impl PyDiGraph {
         neighbors.count()
     }
 
-    /// Find a target node with a specific edge
+    /// Return the degree of every node in the graph
     ///
-    /// This method is used to find a target node that is a adjacent to a given
-    /// node given an edge condition.
+    /// The degree of a node is the sum of its inbound and outbound degree, see
+    /// :meth:`~PyDiGraph.in_degree` and :meth:`~PyDiGraph.out_degree`.
     ///
-    /// :param int node: The node to use as the source of the search
-    /// :param callable predicate: A python callable that will take a single
-    ///     parameter, the edge object, and will return a boolean if the
-    ///     edge matches or not
+    /// :returns: A 1D numpy array of the degree of each node in the graph
+    ///     ordered by node index. Use :meth:`~PyDiGraph.node_indices` to map
+    ///     positions in the array to node indices if any nodes have been
+    ///     removed from the graph.
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_sequence(&self, py: Python) -> PyObject {
+        let degrees: Vec<usize> = self
+            .graph
+            .node_indices()
+            .map(|node| self.in_degree(node.index()) + self.out_degree(node.index()))
+            .collect();
+        degrees.into_pyarray(py).into()
+    }
+
+    /// Return a histogram of the degrees of the nodes in the graph
     ///
-    /// :returns: The node object that has an edge to it from the provided
+    /// :returns: A 1D numpy array where the value at position ``i`` is the
+    ///     number of nodes in the graph with degree ``i`` (inbound plus
+    ///     outbound). The length of the array is one more than the maximum
+    ///     degree in the graph.
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_histogram(&self, py: Python) -> PyObject {
+        let degrees: Vec<usize> = self
+            .graph
+            .node_indices()
+            .map(|node| self.in_degree(node.index()) + self.out_degree(node.index()))
+            .collect();
+        degree_histogram(&degrees).into_pyarray(py).into()
+    }
+
+    /// Return the strength (weighted degree) of every node in the graph
+    ///
+    /// The strength of a node is the sum of the weights of its inbound and
+    /// outbound edges.
+    ///
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0`` which
+    ///     makes the strength equal to the degree.
+    ///
+    /// :returns: A 1D numpy array of the strength of each node in the graph
+    ///     ordered by node index.
+    /// :rtype: numpy.ndarray
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, /, weight_fn=None, default_weight=1.0)")]
+    pub fn strength(
+        &self,
+        py: Python,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<PyObject> {
+        let mut strengths: Vec<f64> = vec![0.; self.graph.node_bound()];
+        for edge in self.graph.edge_references() {
+            let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+            strengths[edge.source().index()] += weight;
+            strengths[edge.target().index()] += weight;
+        }
+        let strengths: Vec<f64> = self
+            .graph
+            .node_indices()
+            .map(|node| strengths[node.index()])
+            .collect();
+        Ok(strengths.into_pyarray(py).into())
+    }
+
+    /// Return the strength (weighted degree) of a node
+    ///
+    /// This is the entry for ``node`` of :meth:`~PyDiGraph.strength` without
+    /// computing the strength of every node, the sum of the weights of the
+    /// inbound and outbound edges of ``node``.
+    ///
+    /// :param int node: The index of the node
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0`` which
+    ///     makes the strength equal to the degree.
+    ///
+    /// :returns: The sum of the weights of the edges incident to ``node``
+    /// :rtype: float
+    ///
+    /// :raises IndexError: If ``node`` isn't in the graph
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, node, /, weight_fn=None, default_weight=1.0)")]
+    pub fn weighted_degree(
+        &self,
+        py: Python,
+        node: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<f64> {
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        let mut strength = 0.;
+        for edge in self
+            .graph
+            .edges_directed(index, petgraph::Direction::Outgoing)
+            .chain(
+                self.graph
+                    .edges_directed(index, petgraph::Direction::Incoming),
+            )
+        {
+            strength += weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+        }
+        Ok(strength)
+    }
+
+    /// Return the row of the adjacency matrix for a node
+    ///
+    /// This is the row for ``node`` of :func:`~retworkx.adjacency_matrix`
+    /// without building the whole matrix, the entry for each successor is
+    /// the sum of the weights of the edges from ``node`` to the successor.
+    ///
+    /// :param int node: The index of the node
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0``.
+    /// :param float null_value: An optional float that will be used for the
+    ///     nodes which aren't successors of ``node``. By default this is
+    ///     ``0.0``.
+    ///
+    /// :returns: A 1D numpy array with an entry for each node in the graph
+    ///     ordered by node index. Use :meth:`~PyDiGraph.node_indices` to map
+    ///     positions in the array to node indices if any nodes have been
+    ///     removed from the graph.
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises IndexError: If ``node`` isn't in the graph
+    #[args(default_weight = "1.0", null_value = "0.0")]
+    #[pyo3(text_signature = "(self, node, /, weight_fn=None, default_weight=1.0, null_value=0.0)")]
+    pub fn adjacency_row(
+        &self,
+        py: Python,
+        node: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+        null_value: f64,
+    ) -> PyResult<PyObject> {
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        let row = adjacency_row(
+            py,
+            &self.graph,
+            self.graph
+                .edges_directed(index, petgraph::Direction::Outgoing)
+                .map(|edge| (edge.target(), edge.weight())),
+            &weight_fn,
+            default_weight,
+            null_value,
+        )?;
+        Ok(row.into_pyarray(py).into())
+    }
+
+    /// Create a new property array with a value for each node of the graph
+    ///
+    /// The returned :class:`~retworkx.PropertyArray` stores a boolean,
+    /// integer or float value for each node index of the graph outside of
+    /// the node data payloads, see :class:`~retworkx.PropertyArray` for
+    /// details.
+    ///
+    /// :param str dtype: The type of the values, one of ``"bool"``, ``"i64"``
+    ///     or ``"f64"``
+    /// :param default: The initial value of every node, and the value of
+    ///     nodes added later until they're written. If not specified this is
+    ///     ``False``, ``0`` or ``0.0`` depending on ``dtype``.
+    ///
+    /// :returns: A new property array with an entry for each index up to the
+    ///     largest node index of the graph
+    /// :rtype: PropertyArray
+    ///
+    /// :raises ValueError: If ``dtype`` isn't a valid type
+    #[pyo3(text_signature = "(self, dtype, /, default=None)")]
+    pub fn new_node_property(
+        &self,
+        dtype: &str,
+        default: Option<&PyAny>,
+    ) -> PyResult<PropertyArray> {
+        PropertyArray::new(self.graph.node_bound(), dtype, default)
+    }
+
+    /// Create a new property array with a value for each edge of the graph
+    ///
+    /// The returned :class:`~retworkx.PropertyArray` stores a boolean,
+    /// integer or float value for each edge index of the graph outside of
+    /// the edge data payloads, see :class:`~retworkx.PropertyArray` for
+    /// details.
+    ///
+    /// :param str dtype: The type of the values, one of ``"bool"``, ``"i64"``
+    ///     or ``"f64"``
+    /// :param default: The initial value of every edge, and the value of
+    ///     edges added later until they're written. If not specified this is
+    ///     ``False``, ``0`` or ``0.0`` depending on ``dtype``.
+    ///
+    /// :returns: A new property array with an entry for each index up to the
+    ///     largest edge index of the graph
+    /// :rtype: PropertyArray
+    ///
+    /// :raises ValueError: If ``dtype`` isn't a valid type
+    #[pyo3(text_signature = "(self, dtype, /, default=None)")]
+    pub fn new_edge_property(
+        &self,
+        dtype: &str,
+        default: Option<&PyAny>,
+    ) -> PyResult<PropertyArray> {
+        PropertyArray::new(self.graph.edge_bound(), dtype, default)
+    }
+
+    /// Create an immutable CSR snapshot of the graph for analysis
+    ///
+    /// The returned :class:`~retworkx.CSRSnapshot` stores the current
+    /// adjacency of the graph in compressed sparse row form with the edge
+    /// weights computed once up front. Read-only algorithms run on the
+    /// snapshot have better cache locality than the equivalent functions run
+    /// on the graph. Later changes to the graph are not reflected in the
+    /// snapshot.
+    ///
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. It must be non-negative. If not specified
+    ///     ``default_weight`` will be used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0``.
+    ///
+    /// :returns: A snapshot of the graph
+    /// :rtype: CSRSnapshot
+    /// :raises ValueError: If a weight is negative or NaN
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, /, weight_fn=None, default_weight=1.0)")]
+    pub fn to_csr_snapshot(
+        &self,
+        py: Python,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<CSRSnapshot> {
+        CSRSnapshot::from_graph(py, &self.graph, weight_fn, default_weight)
+    }
+
+    /// Find a target node with a specific edge
+    ///
+    /// This method is used to find a target node that is a adjacent to a given
+    /// node given an edge condition.
+    ///
+    /// :param int node: The node to use as the source of the search
+    /// :param callable predicate: A python callable that will take a single
+    ///     parameter, the edge object, and will return a boolean if the
+    ///     edge matches or not
+    ///
+    /// :returns: The node object that has an edge to it from the provided
     ///     node index which matches the provided condition
     #[pyo3(text_signature = "(self, node, predicate, /)")]
     pub fn find_adjacent_node_by_edge(
@@ -1820,7 +2592,7 @@ impl PyDiGraph {
     ) -> PyResult<PyDiGraph> {
         let file = File::open(path)?;
         let buf_reader = BufReader::new(file);
-        let mut out_graph = StablePyGraph::<Directed>::new();
+        let mut out_graph = StablePyGraph::<Directed>::default();
         let mut label_map: HashMap<String, usize> = HashMap::new();
         for line_raw in buf_reader.lines() {
             let line = line_raw?;
@@ -1887,11 +2659,14 @@ impl PyDiGraph {
             out_graph.add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
         }
         Ok(PyDiGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             cycle_state: algo::DfsSpace::default(),
             check_cycle: false,
             node_removed: false,
             multigraph: true,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         })
     }
 
@@ -2106,11 +2881,10 @@ impl PyDiGraph {
         // TODO: Reimplement this without looping over the graphs
         // Loop over other nodes add add to self graph
         for node in other.graph.node_indices() {
-            let new_index = self.graph.add_node(weight_transform_callable(
-                py,
-                &node_map_func,
-                &other.graph[node],
-            )?);
+            let new_index = self.log.add_node(
+                &mut self.graph,
+                weight_transform_callable(py, &node_map_func, &other.graph[node])?,
+            );
             new_node_map.insert(node, new_index);
         }
 
@@ -2139,6 +2913,10 @@ impl PyDiGraph {
 
     /// Substitute a node with a PyDigraph object
     ///
+    /// If an error is raised while the node is being substituted (for example
+    /// if ``edge_map_fn`` returns an index that isn't in ``other``) this graph
+    /// is left unmodified.
+    ///
     /// :param int node: The node to replace with the PyDiGraph object
     /// :param PyDiGraph other: The other graph to replace ``node`` with
     /// :param callable edge_map_fn: A callable object that will take 3 position
@@ -2179,25 +2957,6 @@ impl PyDiGraph {
         node_filter: Option<PyObject>,
         edge_weight_map: Option<PyObject>,
     ) -> PyResult<NodeMap> {
-        let weight_map_fn = |obj: &PyObject, weight_fn: &Option<PyObject>| -> PyResult<PyObject> {
-            match weight_fn {
-                Some(weight_fn) => weight_fn.call1(py, (obj,)),
-                None => Ok(obj.clone_ref(py)),
-            }
-        };
-        let map_fn = |source: usize, target: usize, weight: &PyObject| -> PyResult<Option<usize>> {
-            let res = edge_map_fn.call1(py, (source, target, weight))?;
-            res.extract(py)
-        };
-        let filter_fn = |obj: &PyObject, filter_fn: &Option<PyObject>| -> PyResult<bool> {
-            match filter_fn {
-                Some(filter) => {
-                    let res = filter.call1(py, (obj,))?;
-                    res.extract(py)
-                }
-                None => Ok(true),
-            }
-        };
         let node_index: NodeIndex = NodeIndex::new(node);
         if self.graph.node_weight(node_index).is_none() {
             return Err(PyIndexError::new_err(format!(
@@ -2205,82 +2964,172 @@ impl PyDiGraph {
                 node
             )));
         }
-        // Copy nodes from other to self
-        let mut out_map: DictMap<usize, usize> = DictMap::with_capacity(other.node_count());
-        for node in other.graph.node_indices() {
-            let node_weight = other.graph[node].clone_ref(py);
-            if !filter_fn(&node_weight, &node_filter)? {
-                continue;
-            }
-            let new_index = self.graph.add_node(node_weight);
-            out_map.insert(node.index(), new_index.index());
+        self.log.begin();
+        let res = self.splice_subgraph(
+            py,
+            node_index,
+            other,
+            edge_map_fn,
+            node_filter,
+            edge_weight_map,
+        );
+        if res.is_err() {
+            self.rollback_transaction(py);
         }
-        // If no nodes are copied bail here since there is nothing left
-        // to do.
-        if out_map.is_empty() {
-            self.graph.remove_node(node_index);
-            // Return a new empty map to clear allocation from out_map
-            return Ok(NodeMap {
-                node_map: DictMap::new(),
-            });
+        self.log.commit();
+        res
+    }
+
+    /// Substitute the occurrences of a pattern graph with a replacement graph
+    ///
+    /// The occurrences of ``match_graph`` in this graph are found with the
+    /// VF2 algorithm, as with :func:`~retworkx.digraph_vf2_mapping` with
+    /// ``subgraph=True``, and each one is replaced by a copy of
+    /// ``replacement_graph``. The occurrences are substituted in the order
+    /// they're found, skipping any occurrence which shares a node with an
+    /// occurrence already substituted. The edges between the nodes of an
+    /// occurrence are removed along with them, and every edge between a
+    /// node of an occurrence and the rest of the graph is rewired to the
+    /// node of the copy of ``replacement_graph`` that ``boundary_map`` maps
+    /// the corresponding node of ``match_graph`` to, keeping its weight/data
+    /// payload. An occurrence where a node of ``match_graph`` which isn't in
+    /// ``boundary_map`` has an edge to the rest of the graph isn't
+    /// substituted, since the edge would be left dangling.
+    ///
+    /// For example, to fuse every pair of consecutive ``"h"`` nodes of a
+    /// circuit DAG into a single ``"id"`` node:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   dag = retworkx.PyDiGraph()
+    ///   dag.add_nodes_from(["in", "h", "h", "x", "h", "h", "out"])
+    ///   dag.add_edges_from_no_data([(i, i + 1) for i in range(6)])
+    ///   pattern = retworkx.PyDiGraph()
+    ///   pattern.add_nodes_from(["h", "h"])
+    ///   pattern.add_edge(0, 1, None)
+    ///   replacement = retworkx.PyDiGraph()
+    ///   replacement.add_node("id")
+    ///   dag.substitute_subgraph(pattern, replacement, lambda a, b: a == b, {0: 0, 1: 0})
+    ///   print([dag[node] for node in retworkx.topological_sort(dag)])
+    ///
+    /// If an error is raised, for example if rewiring an edge would introduce a
+    /// cycle when :attr:`~retworkx.PyDiGraph.check_cycle` is ``True``, the
+    /// graph is left unchanged.
+    ///
+    /// :param PyDiGraph match_graph: The pattern graph to find the occurrences
+    ///     of. It must have at least one node.
+    /// :param PyDiGraph replacement_graph: The graph to replace each
+    ///     occurrence with. Its node and edge weight/data payloads are copied
+    ///     by reference.
+    /// :param node_matcher: A python callable object that takes 2 positional
+    ///     arguments, the data object of a node in this graph and of a node in
+    ///     ``match_graph``, and returns ``True`` if they match. If ``None`` any
+    ///     nodes can match.
+    /// :param dict boundary_map: A mapping of node indices in ``match_graph``
+    ///     to node indices in ``replacement_graph``, which the edges between
+    ///     an occurrence and the rest of the graph are rewired to.
+    /// :param edge_matcher: An optional python callable object that takes 2
+    ///     positional arguments, the data object of an edge in this graph and
+    ///     of an edge in ``match_graph``, and returns ``True`` if they match.
+    /// :param bool induced: If set to ``True`` (the default) an occurrence
+    ///     can't have edges between its nodes other than those of
+    ///     ``match_graph``.
+    /// :param int call_limit: An optional bound on the number of states the
+    ///     VF2 algorithm visits while searching for occurrences.
+    ///
+    /// :returns: A list with a :class:`~retworkx.NodeMap` for every
+    ///     substituted occurrence, mapping the node indices in
+    ///     ``replacement_graph`` to the node indices of their copies in this
+    ///     graph
+    /// :rtype: list
+    /// :raises IndexError: If ``boundary_map`` contains a node index that isn't
+    ///     in ``match_graph`` or ``replacement_graph``
+    /// :raises ValueError: If ``match_graph`` has no nodes
+    #[args(induced = "true")]
+    #[pyo3(
+        text_signature = "(self, match_graph, replacement_graph, node_matcher, boundary_map, /,
+                          edge_matcher=None, induced=True, call_limit=None)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub fn substitute_subgraph(
+        &mut self,
+        py: Python,
+        match_graph: &PyDiGraph,
+        replacement_graph: &PyDiGraph,
+        node_matcher: Option<PyObject>,
+        boundary_map: HashMap<usize, usize>,
+        edge_matcher: Option<PyObject>,
+        induced: bool,
+        call_limit: Option<usize>,
+    ) -> PyResult<Vec<NodeMap>> {
+        if match_graph.graph.node_count() == 0 {
+            return Err(PyValueError::new_err(
+                "match_graph must have at least one node",
+            ));
         }
-        // Copy edges from other to self
-        for edge in other.graph.edge_references().filter(|edge| {
-            out_map.contains_key(&edge.target().index())
-                && out_map.contains_key(&edge.source().index())
-        }) {
-            self._add_edge(
-                NodeIndex::new(out_map[&edge.source().index()]),
-                NodeIndex::new(out_map[&edge.target().index()]),
-                weight_map_fn(edge.weight(), &edge_weight_map)?,
-            )?;
+        for (pattern, replacement) in boundary_map.iter() {
+            if !match_graph.graph.contains_node(NodeIndex::new(*pattern)) {
+                return Err(PyIndexError::new_err(format!(
+                    "Node index {} is not in match_graph",
+                    pattern
+                )));
+            }
+            if !replacement_graph
+                .graph
+                .contains_node(NodeIndex::new(*replacement))
+            {
+                return Err(PyIndexError::new_err(format!(
+                    "Node index {} is not in replacement_graph",
+                    replacement
+                )));
+            }
         }
-        // Add edges to/from node to nodes in other
-        let in_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
-            .graph
-            .edges_directed(node_index, petgraph::Direction::Incoming)
-            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
-            .collect();
-        let out_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = self
-            .graph
-            .edges_directed(node_index, petgraph::Direction::Outgoing)
-            .map(|edge| (edge.source(), edge.target(), edge.weight().clone_ref(py)))
-            .collect();
-        for (source, target, weight) in in_edges {
-            let old_index = map_fn(source.index(), target.index(), &weight)?;
-            let target_out = match old_index {
-                Some(old_index) => match out_map.get(&old_index) {
-                    Some(new_index) => NodeIndex::new(*new_index),
-                    None => {
-                        return Err(PyIndexError::new_err(format!(
-                            "No mapped index {} found",
-                            old_index
-                        )))
-                    }
-                },
-                None => continue,
-            };
-            self._add_edge(source, target_out, weight)?;
+        let mut vf2 = DiGraphVf2Mapping::new(
+            py,
+            &self.graph,
+            &match_graph.graph,
+            node_matcher,
+            edge_matcher,
+            true,
+            Ordering::Greater,
+            induced,
+            call_limit,
+        );
+        let mut used: HashSet<usize> = HashSet::new();
+        let mut occurrences: Vec<DictMap<usize, usize>> = Vec::new();
+        while let Some(mapping) = vf2.next_mapping(py)? {
+            if mapping.node_map.keys().any(|node| used.contains(node)) {
+                continue;
+            }
+            used.extend(mapping.node_map.keys());
+            occurrences.push(
+                mapping
+                    .node_map
+                    .iter()
+                    .map(|(node, pattern)| (*pattern, *node))
+                    .collect(),
+            );
         }
-        for (source, target, weight) in out_edges {
-            let old_index = map_fn(source.index(), target.index(), &weight)?;
-            let source_out = match old_index {
-                Some(old_index) => match out_map.get(&old_index) {
-                    Some(new_index) => NodeIndex::new(*new_index),
-                    None => {
-                        return Err(PyIndexError::new_err(format!(
-                            "No mapped index {} found",
-                            old_index
-                        )))
-                    }
-                },
-                None => continue,
-            };
-            self._add_edge(source_out, target, weight)?;
+        self.log.begin();
+        let mut out: Vec<NodeMap> = Vec::new();
+        let mut result: PyResult<()> = Ok(());
+        for occurrence in &occurrences {
+            match self.substitute_occurrence(py, occurrence, replacement_graph, &boundary_map) {
+                Ok(Some(node_map)) => out.push(node_map),
+                Ok(None) => (),
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            }
         }
-        // Remove node
-        self.graph.remove_node(node_index);
-        Ok(NodeMap { node_map: out_map })
+        if result.is_err() {
+            self.rollback_transaction(py);
+        }
+        self.log.commit();
+        result.map(|_| out)
     }
 
     /// Substitute a set of nodes with a single new node.
@@ -2354,7 +3203,7 @@ impl PyDiGraph {
         }
 
         // Create new node.
-        let node_index = self.graph.add_node(obj);
+        let node_index = self.log.add_node(&mut self.graph, obj);
 
         // Sanitize new node index from user input.
         indices_to_remove.remove(&node_index);
@@ -2388,7 +3237,8 @@ impl PyDiGraph {
 
         // Remove nodes that will be replaced.
         for index in indices_to_remove {
-            self.graph.remove_node(index);
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, index);
         }
 
         // If `weight_combo_fn` was specified, merge edges according
@@ -2430,7 +3280,7 @@ impl PyDiGraph {
         let node_set: HashSet<usize> = nodes.iter().cloned().collect();
         let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(nodes.len());
         let node_filter = |node: NodeIndex| -> bool { node_set.contains(&node.index()) };
-        let mut out_graph = StablePyGraph::<Directed>::new();
+        let mut out_graph = StablePyGraph::<Directed>::default();
         let filtered = NodeFiltered(&self.graph, node_filter);
         for node in filtered.node_references() {
             let new_node = out_graph.add_node(node.1.clone_ref(py));
@@ -2442,11 +3292,14 @@ impl PyDiGraph {
             out_graph.add_edge(new_source, new_target, edge.weight().clone_ref(py));
         }
         PyDiGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             node_removed: false,
             cycle_state: algo::DfsSpace::default(),
             check_cycle: self.check_cycle,
             multigraph: self.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         }
     }
 
@@ -2507,6 +3360,57 @@ impl PyDiGraph {
         out_graph
     }
 
+    /// Return a new PyDiGraph object for the subgraph induced by a list of edge indices
+    ///
+    /// This works like :meth:`~PyDiGraph.edge_subgraph` except that the edges are
+    /// specified by their edge index instead of by their endpoints. This makes
+    /// it possible to select only some of the parallel edges between a pair
+    /// of nodes in a multigraph. The subgraph contains each edge in
+    /// ``edge_indices`` and each node incident to any of those edges.
+    ///
+    /// The node and edge indices in the subgraph are the same as in this
+    /// graph, and all the node and edge weight/data payloads in the subgraph
+    /// are shared references to the payloads in this graph.
+    ///
+    /// :param list edge_indices: A list of edge indices to generate the
+    ///     subgraph from. Any index which is not present in the graph will be
+    ///     silently ignored.
+    ///
+    /// :returns: The edge subgraph
+    /// :rtype: PyDiGraph
+    #[pyo3(text_signature = "(self, edge_indices, /)")]
+    pub fn edge_subgraph_from_indices(&self, edge_indices: Vec<usize>) -> PyDiGraph {
+        let edge_set: HashSet<EdgeIndex> = edge_indices
+            .into_iter()
+            .map(EdgeIndex::new)
+            .filter(|edge| self.graph.edge_weight(*edge).is_some())
+            .collect();
+        let nodes: HashSet<NodeIndex> = edge_set
+            .iter()
+            .flat_map(|edge| {
+                let (source, target) = self.graph.edge_endpoints(*edge).unwrap();
+                [source, target]
+            })
+            .collect();
+        let mut out_graph = self.clone();
+        for node in self
+            .graph
+            .node_indices()
+            .filter(|node| !nodes.contains(node))
+        {
+            out_graph.graph.remove_node(node);
+            out_graph.node_removed = true;
+        }
+        for edge in self
+            .graph
+            .edge_indices()
+            .filter(|edge| !edge_set.contains(edge))
+        {
+            out_graph.graph.remove_edge(edge);
+        }
+        out_graph
+    }
+
     /// Check if the graph is symmetric
     ///
     /// :returns: True if the graph is symmetric
@@ -2545,6 +3449,18 @@ impl PyDiGraph {
     ///     The node indices in the output :class:`~retworkx.PyGraph` may
     ///     differ if nodes have been removed.
     ///
+    /// For example, to collapse reciprocal directed edges into a single
+    /// undirected edge whose weight is the sum of the original weights:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     digraph = retworkx.PyDiGraph()
+    ///     digraph.extend_from_weighted_edge_list([(0, 1, 2), (1, 0, 3), (1, 2, 4)])
+    ///     graph = digraph.to_undirected(multigraph=False, weight_combo_fn=lambda a, b: a + b)
+    ///     print(graph.weighted_edge_list())
+    ///
     /// :param bool multigraph: If set to `False` the output graph will not
     ///     allow parallel edges. Instead parallel edges will be condensed
     ///     into a single edge and their data will be combined using
@@ -2623,21 +3539,465 @@ impl PyDiGraph {
             }
         }
         Ok(crate::graph::PyGraph {
-            graph: new_graph,
+            graph: new_graph.into(),
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         })
     }
 
+    /// Open a transaction on the graph
+    ///
+    /// The returned :class:`~retworkx.GraphTransaction` is a context manager
+    /// which logs the changes made to the graph while it's open, so they can
+    /// be rolled back without copying the graph. For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.directed_path_graph(4)
+    ///   with graph.transaction() as transaction:
+    ///       graph.remove_node(1)
+    ///       graph.add_edge(0, 2, None)
+    ///       print(graph.edge_list())
+    ///       transaction.rollback()
+    ///   print(graph.edge_list())
+    ///
+    /// If the ``with`` block exits with an exception the changes are also
+    /// rolled back.
+    ///
+    /// :returns: A new transaction on the graph
+    /// :rtype: GraphTransaction
+    #[pyo3(text_signature = "(self)")]
+    pub fn transaction(slf: &PyCell<Self>) -> GraphTransaction {
+        GraphTransaction::from_digraph(slf.into())
+    }
+
     /// Return a shallow copy of the graph
     ///
     /// All node and edge weight/data payloads in the copy will have a
     /// shared reference to the original graph.
+    ///
+    /// Only the structure of the graph (the nodes, edges, and their indices)
+    /// is duplicated, so creating a copy takes :math:`O(|V| + |E|)` time and
+    /// memory regardless of the size of the payload objects. The structure
+    /// is not shared between the copy and the original graph, so mutating
+    /// one will never be reflected in the other. To defer duplicating the
+    /// structure until one of the graphs is mutated use
+    /// :meth:`~retworkx.PyDiGraph.snapshot`.
     #[pyo3(text_signature = "(self)")]
     pub fn copy(&self) -> PyDiGraph {
         self.clone()
     }
 
+    /// Return a copy-on-write snapshot of the graph
+    ///
+    /// The snapshot shares the structure of the graph (the nodes, edges, and
+    /// their indices) with the original graph instead of duplicating it. Only
+    /// the cluster assignments and key indexes of the graph are copied, so
+    /// creating a snapshot takes time and memory proportional to the number of
+    /// clustered nodes and indexed payloads instead of the size of the graph.
+    /// The structure is only duplicated, as by
+    /// :meth:`~retworkx.PyDiGraph.copy`, the first time either graph is
+    /// mutated, after which changes to one graph are not reflected in the
+    /// other. This makes it cheap to keep many variants of a large base graph
+    /// that are only read or only a few of which are modified.
+    ///
+    /// All node and edge weight/data payloads in the snapshot will have a
+    /// shared reference to the original graph.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.directed_path_graph(4)
+    ///   snapshot = graph.snapshot()
+    ///   graph.remove_node(0)
+    ///   print(graph.node_indices())
+    ///   print(snapshot.node_indices())
+    ///
+    /// :returns: A snapshot of the graph
+    /// :rtype: PyDiGraph
+    #[pyo3(text_signature = "(self)")]
+    pub fn snapshot(&self) -> PyDiGraph {
+        PyDiGraph {
+            graph: self.graph.share(),
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: self.check_cycle,
+            node_removed: self.node_removed,
+            multigraph: self.multigraph,
+            log: self.log.clone(),
+            clusters: self.clusters.clone(),
+            indexes: self.indexes.clone(),
+        }
+    }
+
+    /// Assign nodes to a cluster
+    ///
+    /// Clusters group nodes of the graph under a name, for example the nodes
+    /// of a module or a region, so they can be worked with together using
+    /// :meth:`~retworkx.PyDiGraph.clusters`, :meth:`~retworkx.PyDiGraph.cluster_subgraph`
+    /// and :meth:`~retworkx.PyDiGraph.quotient_graph`. A node is assigned to at
+    /// most one cluster, so a node that's already assigned to a cluster is
+    /// moved to the new one. When a node is removed from the graph it's also
+    /// removed from its cluster.
+    ///
+    /// :param list nodes: The indices of the nodes to assign to the cluster
+    /// :param str name: The name of the cluster
+    ///
+    /// :raises IndexError: If any of the nodes are not in the graph, in which
+    ///     case none of the nodes are assigned
+    #[pyo3(text_signature = "(self, nodes, name, /)")]
+    pub fn assign_cluster(&mut self, nodes: Vec<usize>, name: String) -> PyResult<()> {
+        for node in &nodes {
+            if !self.graph.contains_node(NodeIndex::new(*node)) {
+                return Err(PyIndexError::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+        }
+        for node in nodes {
+            self.log
+                .set_cluster(&mut self.clusters, NodeIndex::new(node), Some(name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Remove nodes from the clusters they're assigned to
+    ///
+    /// :param list nodes: The indices of the nodes to remove from their
+    ///     clusters. If a node isn't assigned to a cluster it will silently be
+    ///     ignored.
+    #[pyo3(text_signature = "(self, nodes, /)")]
+    pub fn unassign_cluster(&mut self, nodes: Vec<usize>) {
+        for node in nodes {
+            self.log
+                .set_cluster(&mut self.clusters, NodeIndex::new(node), None);
+        }
+    }
+
+    /// Return the name of the cluster a node is assigned to
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The name of the cluster the node is assigned to or ``None``
+    ///     if it isn't assigned to a cluster
+    /// :rtype: str
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn node_cluster(&self, node: usize) -> Option<String> {
+        self.clusters.get(&node).cloned()
+    }
+
+    /// Return the clusters of the graph
+    ///
+    /// :returns: A dictionary mapping the name of each cluster to a sorted
+    ///     list of the indices of the nodes assigned to it. The clusters are
+    ///     ordered by their lowest node index.
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn clusters(&self) -> DictMap<String, Vec<usize>> {
+        cluster_members(&self.clusters)
+    }
+
+    /// Return a new PyDiGraph object for the subgraph induced by a cluster
+    ///
+    /// :param str name: The name of the cluster
+    ///
+    /// :returns: A new PyDiGraph object for the subgraph induced by the nodes
+    ///     assigned to the cluster, in the same way as
+    ///     :meth:`~retworkx.PyDiGraph.subgraph`
+    /// :rtype: PyDiGraph
+    ///
+    /// :raises KeyError: If there are no nodes assigned to a cluster named
+    ///     ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn cluster_subgraph(&self, py: Python, name: &str) -> PyResult<PyDiGraph> {
+        let mut nodes: Vec<usize> = self
+            .clusters
+            .iter()
+            .filter(|(_, cluster)| *cluster == name)
+            .map(|(node, _)| *node)
+            .collect();
+        if nodes.is_empty() {
+            return Err(PyKeyError::new_err(format!("No cluster named {}", name)));
+        }
+        nodes.sort_unstable();
+        Ok(self.subgraph(py, nodes))
+    }
+
+    /// Return a new PyDiGraph object for the subgraph induced by each cluster
+    ///
+    /// :returns: A dictionary mapping the name of each cluster to the
+    ///     subgraph induced by the nodes assigned to it, in the same order as
+    ///     :meth:`~retworkx.PyDiGraph.clusters`
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn cluster_subgraphs(&self, py: Python) -> DictMap<String, PyDiGraph> {
+        cluster_members(&self.clusters)
+            .into_iter()
+            .map(|(name, nodes)| (name, self.subgraph(py, nodes)))
+            .collect()
+    }
+
+    /// Return the quotient graph of the graph by its clusters
+    ///
+    /// The quotient graph contracts each cluster to a single node, whose
+    /// weight/data payload is the name of the cluster. The clusters come first,
+    /// in the same order as :meth:`~retworkx.PyDiGraph.clusters`, followed by the
+    /// nodes that aren't assigned to a cluster, which keep their weight/data
+    /// payloads, in node index order. There is an edge from one node of the
+    /// quotient graph to another if there is at least one edge between them in
+    /// that direction in this graph, so the quotient graph of a DAG can
+    /// contain cycles and cycle checking is disabled on it.
+    /// Edges between nodes in the same cluster are dropped. As the quotient
+    /// graph is a PyDiGraph its nodes can be clustered in turn to build a hierarchy
+    /// of clusters.
+    ///
+    /// :param callable edge_weight_fn: An optional callable that will be passed
+    ///     the list of weight/data payloads of the edges between two nodes of
+    ///     the quotient graph, in edge index order, and returns the
+    ///     weight/data payload of the edge between them. If not specified the
+    ///     list is used as the weight/data payload.
+    ///
+    /// :returns: The quotient graph
+    /// :rtype: PyDiGraph
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.directed_path_graph(6)
+    ///   graph.assign_cluster([0, 1, 2], "a")
+    ///   graph.assign_cluster([3, 4], "b")
+    ///   quotient = graph.quotient_graph(len)
+    ///   print(quotient.nodes())
+    ///   print(quotient.weighted_edge_list())
+    #[pyo3(text_signature = "(self, /, edge_weight_fn=None)")]
+    pub fn quotient_graph(
+        &self,
+        py: Python,
+        edge_weight_fn: Option<PyObject>,
+    ) -> PyResult<PyDiGraph> {
+        let out_graph = quotient_graph(py, &self.graph, &self.clusters, edge_weight_fn)?;
+        Ok(PyDiGraph {
+            graph: out_graph.into(),
+            node_removed: false,
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            multigraph: self.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
+        })
+    }
+
+    /// Index the nodes of the graph by a key computed from their weight/data
+    /// payloads
+    ///
+    /// After the nodes are indexed :meth:`~retworkx.PyDiGraph.nodes_with_key`
+    /// finds the nodes with a given key without scanning the graph. The index
+    /// is kept up to date as nodes are added, removed or have their
+    /// weight/data payload replaced (including by a rolled back
+    /// :meth:`~retworkx.PyDiGraph.transaction`), with ``key_fn`` only called for
+    /// the nodes that changed. Mutating a weight/data payload object in place
+    /// isn't tracked, call this method again to rebuild the index if that
+    /// changes any keys. Calling this method replaces any existing node index,
+    /// and the index isn't kept by copies or pickles of the graph.
+    ///
+    /// :param callable key_fn: A callable that will be passed the weight/data
+    ///     payload of a node and returns its key. The key must be hashable. If
+    ///     not set the weight/data payloads themselves are the keys.
+    ///
+    /// :raises TypeError: If a key isn't hashable. Any exception raised by
+    ///     ``key_fn`` is also raised and the nodes are left unindexed.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.PyDiGraph()
+    ///   graph.add_nodes_from([("a", 1), ("b", 2), ("a", 3)])
+    ///   graph.index_nodes_by(lambda node: node[0])
+    ///   print(graph.nodes_with_key("a"))
+    ///   graph.add_node(("a", 4))
+    ///   print(graph.nodes_with_key("a"))
+    #[pyo3(text_signature = "(self, /, key_fn=None)")]
+    pub fn index_nodes_by(&mut self, py: Python, key_fn: Option<PyObject>) -> PyResult<()> {
+        self.indexes.nodes = None;
+        self.log.node_changes.enable(true);
+        self.log.node_changes.touch_all();
+        let mut index = KeyIndex::new(py, key_fn);
+        let graph = &self.graph;
+        if let Err(err) = index.update(
+            py,
+            &mut self.log.node_changes,
+            |node| graph.node_weight(NodeIndex::new(node)),
+            graph
+                .node_indices()
+                .map(|node| (node.index(), &graph[node])),
+        ) {
+            self.log.node_changes.enable(false);
+            return Err(err);
+        }
+        self.indexes.nodes = Some(index);
+        Ok(())
+    }
+
+    /// Return the indices of the nodes with a given key
+    ///
+    /// :param key: The key to look up, as returned by the ``key_fn`` passed
+    ///     to :meth:`~retworkx.PyDiGraph.index_nodes_by`
+    ///
+    /// :returns: The sorted indices of the nodes with the key, which is empty
+    ///     if there are none
+    /// :rtype: NodeIndices
+    ///
+    /// :raises RuntimeError: If the nodes of the graph aren't indexed
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn nodes_with_key(&mut self, py: Python, key: PyObject) -> PyResult<NodeIndices> {
+        let index = self
+            .indexes
+            .nodes
+            .as_mut()
+            .ok_or_else(|| not_indexed("node"))?;
+        let graph = &self.graph;
+        index.update(
+            py,
+            &mut self.log.node_changes,
+            |node| graph.node_weight(NodeIndex::new(node)),
+            graph
+                .node_indices()
+                .map(|node| (node.index(), &graph[node])),
+        )?;
+        Ok(NodeIndices {
+            nodes: index.get(py, key)?,
+        })
+    }
+
+    /// Remove the node index created by
+    /// :meth:`~retworkx.PyDiGraph.index_nodes_by`
+    ///
+    /// If the nodes aren't indexed this does nothing.
+    #[pyo3(text_signature = "(self)")]
+    pub fn drop_node_index(&mut self) {
+        self.indexes.nodes = None;
+        self.log.node_changes.enable(false);
+    }
+
+    /// Index the edges of the graph by a key computed from their weight/data
+    /// payloads
+    ///
+    /// After the edges are indexed :meth:`~retworkx.PyDiGraph.edges_with_key`
+    /// finds the edges with a given key without scanning the graph. The index
+    /// is kept up to date as edges are added, removed or have their
+    /// weight/data payload replaced (including by a rolled back
+    /// :meth:`~retworkx.PyDiGraph.transaction`), with ``key_fn`` only called for
+    /// the edges that changed. Mutating a weight/data payload object in place
+    /// isn't tracked, call this method again to rebuild the index if that
+    /// changes any keys. Calling this method replaces any existing edge index,
+    /// and the index isn't kept by copies or pickles of the graph.
+    ///
+    /// :param callable key_fn: A callable that will be passed the weight/data
+    ///     payload of an edge and returns its key. The key must be hashable. If
+    ///     not set the weight/data payloads themselves are the keys.
+    ///
+    /// :raises TypeError: If a key isn't hashable. Any exception raised by
+    ///     ``key_fn`` is also raised and the edges are left unindexed.
+    #[pyo3(text_signature = "(self, /, key_fn=None)")]
+    pub fn index_edges_by(&mut self, py: Python, key_fn: Option<PyObject>) -> PyResult<()> {
+        self.indexes.edges = None;
+        self.log.edge_changes.enable(true);
+        self.log.edge_changes.touch_all();
+        let mut index = KeyIndex::new(py, key_fn);
+        let graph = &self.graph;
+        if let Err(err) = index.update(
+            py,
+            &mut self.log.edge_changes,
+            |edge| graph.edge_weight(EdgeIndex::new(edge)),
+            graph
+                .edge_references()
+                .map(|edge| (edge.id().index(), edge.weight())),
+        ) {
+            self.log.edge_changes.enable(false);
+            return Err(err);
+        }
+        self.indexes.edges = Some(index);
+        Ok(())
+    }
+
+    /// Return the indices of the edges with a given key
+    ///
+    /// :param key: The key to look up, as returned by the ``key_fn`` passed
+    ///     to :meth:`~retworkx.PyDiGraph.index_edges_by`
+    ///
+    /// :returns: The sorted indices of the edges with the key, which is empty
+    ///     if there are none
+    /// :rtype: EdgeIndices
+    ///
+    /// :raises RuntimeError: If the edges of the graph aren't indexed
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn edges_with_key(&mut self, py: Python, key: PyObject) -> PyResult<EdgeIndices> {
+        let index = self
+            .indexes
+            .edges
+            .as_mut()
+            .ok_or_else(|| not_indexed("edge"))?;
+        let graph = &self.graph;
+        index.update(
+            py,
+            &mut self.log.edge_changes,
+            |edge| graph.edge_weight(EdgeIndex::new(edge)),
+            graph
+                .edge_references()
+                .map(|edge| (edge.id().index(), edge.weight())),
+        )?;
+        Ok(EdgeIndices {
+            edges: index.get(py, key)?,
+        })
+    }
+
+    /// Remove the edge index created by
+    /// :meth:`~retworkx.PyDiGraph.index_edges_by`
+    ///
+    /// If the edges aren't indexed this does nothing.
+    #[pyo3(text_signature = "(self)")]
+    pub fn drop_edge_index(&mut self) {
+        self.indexes.edges = None;
+        self.log.edge_changes.enable(false);
+    }
+
+    /// Reindex the nodes and edges of the graph so their indices are contiguous
+    ///
+    /// After nodes or edges are removed from a graph there will be gaps in
+    /// the indices. This method rebuilds the graph in place so the node
+    /// indices are ``0`` to ``num_nodes() - 1`` and the edge indices are
+    /// ``0`` to ``num_edges() - 1``. The relative order of the indices is
+    /// preserved and node and edge weight/data payloads are not copied. The
+    /// nodes stay assigned to their clusters.
+    ///
+    /// :returns: A tuple of a :class:`~retworkx.NodeMap` mapping the old node
+    ///     indices to the new node indices and an :class:`~retworkx.EdgeMap`
+    ///     mapping the old edge indices to the new edge indices.
+    /// :rtype: tuple
+    #[pyo3(text_signature = "(self)")]
+    pub fn compact(&mut self, py: Python) -> (NodeMap, EdgeMap) {
+        let (out_graph, node_map, edge_map) = compact_graph(py, &self.graph);
+        let clusters: Clusters = self
+            .clusters
+            .iter()
+            .map(|(node, name)| (node_map[node], name.clone()))
+            .collect();
+        self.log
+            .replace(&mut self.graph, &mut self.clusters, out_graph, clusters);
+        self.node_removed = false;
+        self.cycle_state = algo::DfsSpace::default();
+        (NodeMap { node_map }, EdgeMap { edge_map })
+    }
+
     /// Return the number of nodes in the graph
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.graph.node_count())
@@ -2651,16 +4011,21 @@ impl PyDiGraph {
     }
 
     fn __setitem__(&mut self, idx: usize, value: PyObject) -> PyResult<()> {
-        let data = match self.graph.node_weight_mut(NodeIndex::new(idx as usize)) {
-            Some(node_data) => node_data,
-            None => return Err(PyIndexError::new_err("No node found for index")),
-        };
-        *data = value;
-        Ok(())
+        match self
+            .log
+            .set_node_weight(&mut self.graph, NodeIndex::new(idx), value)
+        {
+            Some(_) => Ok(()),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
     }
 
     fn __delitem__(&mut self, idx: usize) -> PyResult<()> {
-        match self.graph.remove_node(NodeIndex::new(idx as usize)) {
+        match self.log.remove_node(
+            &mut self.graph,
+            &mut self.clusters,
+            NodeIndex::new(idx as usize),
+        ) {
             Some(_) => Ok(()),
             None => Err(PyIndexError::new_err("No node found for index")),
         }
@@ -2674,20 +4039,27 @@ impl PyDiGraph {
     // [1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_traverse
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
-        for node in self
-            .graph
-            .node_indices()
-            .map(|node| self.graph.node_weight(node).unwrap())
-        {
-            visit.call(node)?;
-        }
-        for edge in self
-            .graph
-            .edge_indices()
-            .map(|edge| self.graph.edge_weight(edge).unwrap())
-        {
-            visit.call(edge)?;
+        // The payloads of a structure shared with a snapshot are referenced
+        // once by the shared structure, not once by each graph sharing it,
+        // so only report them while this graph is its sole owner.
+        if !self.graph.is_shared() {
+            for node in self
+                .graph
+                .node_indices()
+                .map(|node| self.graph.node_weight(node).unwrap())
+            {
+                visit.call(node)?;
+            }
+            for edge in self
+                .graph
+                .edge_indices()
+                .map(|edge| self.graph.edge_weight(edge).unwrap())
+            {
+                visit.call(edge)?;
+            }
         }
+        self.log.traverse(&visit)?;
+        self.indexes.traverse(&visit)?;
         Ok(())
     }
 
@@ -2698,8 +4070,10 @@ impl PyDiGraph {
     // ]1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_clear
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __clear__(&mut self) {
-        self.graph = StablePyGraph::<Directed>::new();
+        self.graph = StablePyGraph::<Directed>::default().into();
         self.node_removed = false;
+        self.log.clear();
+        self.indexes.clear();
     }
 }
 
@@ -2737,7 +4111,7 @@ where
 {
     let array = matrix.as_array();
     let shape = array.shape();
-    let mut out_graph = StablePyGraph::<Directed>::new();
+    let mut out_graph = StablePyGraph::<Directed>::default();
     let _node_indices: Vec<NodeIndex> = (0..shape[0])
         .map(|node| out_graph.add_node(node.to_object(py)))
         .collect();
@@ -2766,10 +4140,13 @@ where
         });
 
     PyDiGraph {
-        graph: out_graph,
+        graph: out_graph.into(),
         cycle_state: algo::DfsSpace::default(),
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     }
 }
@@ -0,0 +1,279 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use pyo3::prelude::*;
+
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+
+use crate::digraph::PyDiGraph;
+use crate::{DictMap, InvalidNode, NodeIndex};
+
+/// The depth first search numbering of the nodes reachable from the root
+/// and the predecessors of every numbered node, by number.
+struct Numbering {
+    vertex: Vec<usize>,
+    parent: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl Numbering {
+    fn new(graph: &PyDiGraph, root: NodeIndex) -> Self {
+        let mut number: Vec<Option<usize>> = vec![None; graph.graph.node_bound()];
+        let mut vertex = vec![root.index()];
+        let mut parent = vec![0];
+        number[root.index()] = Some(0);
+        let mut stack = vec![(
+            root,
+            graph.graph.neighbors_directed(root, Outgoing).detach(),
+        )];
+        while let Some((node, walker)) = stack.last_mut() {
+            match walker.next_node(&graph.graph) {
+                Some(child) => {
+                    if number[child.index()].is_none() {
+                        number[child.index()] = Some(vertex.len());
+                        parent.push(number[node.index()].unwrap());
+                        vertex.push(child.index());
+                        let walker = graph.graph.neighbors_directed(child, Outgoing).detach();
+                        stack.push((child, walker));
+                    }
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        let predecessors = vertex
+            .iter()
+            .map(|node| {
+                graph
+                    .graph
+                    .neighbors_directed(NodeIndex::new(*node), Incoming)
+                    .filter_map(|pred| number[pred.index()])
+                    .collect()
+            })
+            .collect();
+        Numbering {
+            vertex,
+            parent,
+            predecessors,
+        }
+    }
+}
+
+/// The link-eval forest of the Lengauer-Tarjan algorithm with path
+/// compression, by depth first search number.
+struct Forest {
+    ancestor: Vec<Option<usize>>,
+    label: Vec<usize>,
+}
+
+impl Forest {
+    fn new(size: usize) -> Self {
+        Forest {
+            ancestor: vec![None; size],
+            label: (0..size).collect(),
+        }
+    }
+
+    fn link(&mut self, parent: usize, node: usize) {
+        self.ancestor[node] = Some(parent);
+    }
+
+    /// Return the node with the smallest semidominator on the path from
+    /// ``node`` to the root of its tree in the forest, excluding the root.
+    fn eval(&mut self, node: usize, semi: &[usize]) -> usize {
+        if self.ancestor[node].is_none() {
+            return node;
+        }
+        let mut path = vec![node];
+        while let Some(ancestor) = self.ancestor[*path.last().unwrap()] {
+            if self.ancestor[ancestor].is_none() {
+                break;
+            }
+            path.push(ancestor);
+        }
+        // Compress the path from its top down so every node is labeled with
+        // the smallest semidominator above it
+        for pair in (0..path.len() - 1).rev() {
+            let (node, ancestor) = (path[pair], path[pair + 1]);
+            if semi[self.label[ancestor]] < semi[self.label[node]] {
+                self.label[node] = self.label[ancestor];
+            }
+            self.ancestor[node] = self.ancestor[ancestor];
+        }
+        self.label[node]
+    }
+}
+
+/// Compute the immediate dominator of every numbered node with the
+/// Lengauer-Tarjan algorithm, returns the number of the immediate dominator
+/// of each node by number. The root is its own immediate dominator.
+fn lengauer_tarjan(numbering: &Numbering) -> Vec<usize> {
+    let size = numbering.vertex.len();
+    let mut semi: Vec<usize> = (0..size).collect();
+    let mut idom: Vec<usize> = vec![0; size];
+    let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); size];
+    let mut forest = Forest::new(size);
+    for node in (1..size).rev() {
+        for pred in &numbering.predecessors[node] {
+            let candidate = forest.eval(*pred, &semi);
+            if semi[candidate] < semi[node] {
+                semi[node] = semi[candidate];
+            }
+        }
+        bucket[semi[node]].push(node);
+        let parent = numbering.parent[node];
+        forest.link(parent, node);
+        for dominated in std::mem::take(&mut bucket[parent]) {
+            let candidate = forest.eval(dominated, &semi);
+            idom[dominated] = if semi[candidate] < semi[dominated] {
+                candidate
+            } else {
+                parent
+            };
+        }
+    }
+    for node in 1..size {
+        if idom[node] != semi[node] {
+            idom[node] = idom[idom[node]];
+        }
+    }
+    idom
+}
+
+fn check_root(graph: &PyDiGraph, root: usize) -> PyResult<NodeIndex> {
+    let root = NodeIndex::new(root);
+    if !graph.graph.contains_node(root) {
+        return Err(InvalidNode::new_err(format!(
+            "Node index {} is not present in the graph",
+            root.index()
+        )));
+    }
+    Ok(root)
+}
+
+/// Compute the immediate dominators of the nodes of a directed graph
+///
+/// A node ``d`` dominates a node ``n`` if every path from ``root`` to ``n``
+/// goes through ``d``. The immediate dominator of ``n`` is the unique node
+/// that dominates ``n`` and is dominated by every other node dominating
+/// ``n``, other than ``n`` itself. The immediate dominators form the
+/// dominator tree of the graph, rooted at ``root``.
+///
+/// This function uses the Lengauer-Tarjan algorithm [1]_ which runs in
+/// :math:`O(m \log n)` time, where :math:`m` is the number of edges and
+/// :math:`n` is the number of nodes reachable from ``root``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(6))
+///   graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 1), (4, 5)])
+///   print(retworkx.immediate_dominators(graph, 0))
+///
+/// :param PyDiGraph graph: The directed graph to find the dominators of
+/// :param int root: The index of the node all the paths start from
+///
+/// :returns: A dictionary mapping the index of every node reachable from
+///     ``root`` to the index of its immediate dominator. The root is mapped
+///     to itself.
+/// :rtype: dict
+///
+/// :raises InvalidNode: If ``root`` is not a node of the graph
+///
+/// .. [1] Lengauer, Thomas, and Robert Endre Tarjan. "A fast algorithm for
+///     finding dominators in a flowgraph." ACM Transactions on Programming
+///     Languages and Systems 1.1 (1979): 121-141.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, root, /)")]
+pub fn immediate_dominators(graph: &PyDiGraph, root: usize) -> PyResult<DictMap<usize, usize>> {
+    let root = check_root(graph, root)?;
+    let numbering = Numbering::new(graph, root);
+    let idom = lengauer_tarjan(&numbering);
+    Ok(numbering
+        .vertex
+        .iter()
+        .zip(idom)
+        .map(|(node, dominator)| (*node, numbering.vertex[dominator]))
+        .collect())
+}
+
+/// Compute the dominance frontiers of the nodes of a directed graph
+///
+/// The dominance frontier of a node ``d`` is the set of nodes ``n`` such
+/// that ``d`` dominates a predecessor of ``n`` but doesn't strictly
+/// dominate ``n``. These are the nodes where the paths from ``d`` join
+/// paths that don't go through ``d``, which is where static single
+/// assignment form places its phi functions.
+///
+/// The immediate dominators are found with the same algorithm as
+/// :func:`~retworkx.immediate_dominators` and the frontiers are then
+/// collected by walking up the dominator tree from the predecessors of
+/// every join node [1]_.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(6))
+///   graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 1), (4, 5)])
+///   print(retworkx.dominance_frontiers(graph, 0))
+///
+/// :param PyDiGraph graph: The directed graph to find the dominance
+///     frontiers of
+/// :param int root: The index of the node all the paths start from
+///
+/// :returns: A dictionary mapping the index of every node reachable from
+///     ``root`` to the set of node indices in its dominance frontier
+/// :rtype: dict
+///
+/// :raises InvalidNode: If ``root`` is not a node of the graph
+///
+/// .. [1] Cooper, Keith D., Timothy J. Harvey, and Ken Kennedy. "A simple,
+///     fast dominance algorithm." Software Practice & Experience 4 (2001).
+#[pyfunction]
+#[pyo3(text_signature = "(graph, root, /)")]
+pub fn dominance_frontiers(
+    graph: &PyDiGraph,
+    root: usize,
+) -> PyResult<DictMap<usize, HashSet<usize>>> {
+    let root = check_root(graph, root)?;
+    let numbering = Numbering::new(graph, root);
+    let idom = lengauer_tarjan(&numbering);
+    let mut frontiers: Vec<HashSet<usize>> = vec![HashSet::new(); idom.len()];
+    for (node, predecessors) in numbering.predecessors.iter().enumerate() {
+        // The root has no immediate dominator, so the walks from its
+        // predecessors go up to and include the root itself
+        let stop = if node == 0 { None } else { Some(idom[node]) };
+        for pred in predecessors {
+            let mut runner = Some(*pred);
+            while runner != stop {
+                let current = runner.unwrap();
+                if !frontiers[current].insert(numbering.vertex[node]) {
+                    break;
+                }
+                runner = if current == 0 {
+                    None
+                } else {
+                    Some(idom[current])
+                };
+            }
+        }
+    }
+    Ok(numbering.vertex.iter().copied().zip(frontiers).collect())
+}
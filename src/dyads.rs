@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use pyo3::prelude::*;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use crate::digraph::PyDiGraph;
+use retworkx_core::dictmap::*;
+
+/// The counts of a single scan over the edges of a directed graph, with
+/// self loops ignored and parallel edges counted once.
+struct EdgeScan {
+    /// The number of distinct directed edges
+    edges: usize,
+    /// The number of distinct directed edges whose reverse edge is also in
+    /// the graph
+    reciprocated: usize,
+    /// The number of distinct neighbors of each node over both directions
+    /// and the number of its edges that are reciprocated, by node index
+    degree: Vec<usize>,
+    mutual: Vec<usize>,
+}
+
+impl EdgeScan {
+    fn new(graph: &PyDiGraph) -> Self {
+        let mut seen: HashSet<(usize, usize)> = HashSet::with_capacity(graph.graph.edge_count());
+        let mut scan = EdgeScan {
+            edges: 0,
+            reciprocated: 0,
+            degree: vec![0; graph.graph.node_bound()],
+            mutual: vec![0; graph.graph.node_bound()],
+        };
+        for edge in graph.graph.edge_references() {
+            let (source, target) = (edge.source().index(), edge.target().index());
+            if source == target || !seen.insert((source, target)) {
+                continue;
+            }
+            scan.edges += 1;
+            scan.degree[source] += 1;
+            scan.degree[target] += 1;
+            if seen.contains(&(target, source)) {
+                scan.reciprocated += 2;
+                scan.mutual[source] += 2;
+                scan.mutual[target] += 2;
+            }
+        }
+        scan
+    }
+}
+
+/// Compute the reciprocity of a directed graph
+///
+/// The reciprocity of a directed graph is the fraction of its edges whose
+/// reverse edge is also in the graph:
+///
+/// .. math::
+///     r = \frac{|\{(u, v) \in E : (v, u) \in E\}|}{|E|}
+///
+/// Self loops are ignored and parallel edges are only counted once.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)])
+///   print(retworkx.reciprocity(graph))
+///
+/// :param PyDiGraph graph: The directed graph to compute the reciprocity of
+///
+/// :returns: The reciprocity of the graph, 0.0 if it has no edges
+/// :rtype: float
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn reciprocity(graph: &PyDiGraph) -> f64 {
+    let scan = EdgeScan::new(graph);
+    match scan.edges {
+        0 => 0.0,
+        _ => scan.reciprocated as f64 / scan.edges as f64,
+    }
+}
+
+/// Compute the reciprocity of each node of a directed graph
+///
+/// The reciprocity of a node is the fraction of the edges into or out of the
+/// node whose reverse edge is also in the graph:
+///
+/// .. math::
+///     r_u = \frac{2 |N^{in}(u) \cap N^{out}(u)|}{|N^{in}(u)| + |N^{out}(u)|}
+///
+/// where :math:`N^{in}(u)` and :math:`N^{out}(u)` are the predecessors and
+/// successors of :math:`u`. Self loops are ignored and parallel edges are
+/// only counted once.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)])
+///   print(retworkx.node_reciprocity(graph))
+///
+/// :param PyDiGraph graph: The directed graph to compute the reciprocity of
+///
+/// :returns: A dictionary mapping each node index to its reciprocity, 0.0
+///     for nodes without any edges to other nodes
+/// :rtype: dict
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn node_reciprocity(graph: &PyDiGraph) -> DictMap<usize, f64> {
+    let scan = EdgeScan::new(graph);
+    graph
+        .graph
+        .node_indices()
+        .map(|node| {
+            let node = node.index();
+            let reciprocity = match scan.degree[node] {
+                0 => 0.0,
+                degree => scan.mutual[node] as f64 / degree as f64,
+            };
+            (node, reciprocity)
+        })
+        .collect()
+}
+
+/// Compute the dyad census of a directed graph
+///
+/// The dyad census classifies every unordered pair of distinct nodes by the
+/// edges between them. A pair is mutual if there are edges in both
+/// directions, asymmetric if there is an edge in only one direction, and
+/// null if there are no edges between them. Self loops are ignored and
+/// parallel edges are only counted once.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)])
+///   print(retworkx.dyad_census(graph))
+///
+/// :param PyDiGraph graph: The directed graph to compute the dyad census of
+///
+/// :returns: A dictionary with the number of ``"mutual"``, ``"asymmetric"``,
+///     and ``"null"`` pairs of nodes
+/// :rtype: dict
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn dyad_census(graph: &PyDiGraph) -> DictMap<&'static str, u64> {
+    let scan = EdgeScan::new(graph);
+    let node_count = graph.graph.node_count() as u64;
+    let mutual = (scan.reciprocated / 2) as u64;
+    let asymmetric = (scan.edges - scan.reciprocated) as u64;
+    let pairs = node_count * node_count.saturating_sub(1) / 2;
+    let mut census = DictMap::with_capacity(3);
+    census.insert("mutual", mutual);
+    census.insert("asymmetric", asymmetric);
+    census.insert("null", pairs - mutual - asymmetric);
+    census
+}
@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeFiltered, EdgeIndexable, EdgeRef, FilterEdge, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use crate::{EdgeIndex, StablePyGraph};
+
+/// The edges of a graph which pass an ``edge_filter_fn`` predicate. The
+/// predicate is called once for each edge, and the mask is used with
+/// petgraph's ``EdgeFiltered`` adaptor so algorithms skip the other edges
+/// without building a subgraph.
+pub struct EdgeMask {
+    mask: Option<FixedBitSet>,
+}
+
+impl EdgeMask {
+    pub fn new<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        edge_filter_fn: Option<PyObject>,
+    ) -> PyResult<Self> {
+        let mask = match edge_filter_fn {
+            Some(edge_filter_fn) => {
+                let mut mask = FixedBitSet::with_capacity(graph.edge_bound());
+                for edge in graph.edge_references() {
+                    if edge_filter_fn.call1(py, (edge.weight(),))?.is_true(py)? {
+                        mask.insert(edge.id().index());
+                    }
+                }
+                Some(mask)
+            }
+            None => None,
+        };
+        Ok(EdgeMask { mask })
+    }
+
+    pub fn contains(&self, edge: EdgeIndex) -> bool {
+        match &self.mask {
+            Some(mask) => mask.contains(edge.index()),
+            None => true,
+        }
+    }
+
+    /// A view of ``graph`` with only the edges in the mask.
+    pub fn filter<'a, Ty: EdgeType>(
+        &'a self,
+        graph: &'a StablePyGraph<Ty>,
+    ) -> EdgeFiltered<&'a StablePyGraph<Ty>, &'a EdgeMask> {
+        EdgeFiltered(graph, self)
+    }
+}
+
+impl<E: EdgeRef<EdgeId = EdgeIndex>> FilterEdge<E> for &EdgeMask {
+    fn include_edge(&self, edge: E) -> bool {
+        self.contains(edge.id())
+    }
+}
@@ -0,0 +1,257 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+
+use petgraph::algo::has_path_connecting;
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use rand::prelude::*;
+
+use crate::random_seed::seeded_rng;
+use crate::transaction::MutationLog;
+use crate::{digraph, graph, DisconnectedGraphError, EdgeIndex, NodeIndex, StablePyGraph};
+
+type Endpoints = (NodeIndex, NodeIndex);
+
+/// Replace the edges ``first`` and ``second`` with edges between the new
+/// endpoints, keeping the weight/data payload of each edge. The edges are
+/// added back in reverse order so they reuse their indices.
+fn swap_edges<Ty: EdgeType>(
+    graph: &mut StablePyGraph<Ty>,
+    log: &mut MutationLog<Ty>,
+    (first, second): (EdgeIndex, EdgeIndex),
+    (new_first, new_second): (Endpoints, Endpoints),
+) -> (EdgeIndex, EdgeIndex) {
+    let first_weight = log.remove_edge(graph, first).unwrap();
+    let second_weight = log.remove_edge(graph, second).unwrap();
+    let second = log.add_edge(graph, new_second.0, new_second.1, second_weight);
+    let first = log.add_edge(graph, new_first.0, new_first.1, first_weight);
+    (first, second)
+}
+
+/// Make up to ``nswap`` double edge swaps, which replace two edges ``(a, b)``
+/// and ``(c, d)`` with ``(a, d)`` and ``(c, b)``, in at most ``max_tries``
+/// attempts. A swap is rejected if it would create a self loop or a parallel
+/// edge, or if ``keep`` returns ``false`` for the swapped graph and the
+/// replaced edges, in which case it's undone. Returns the number of swaps
+/// made.
+fn double_edge_swap<Ty, F>(
+    graph: &mut StablePyGraph<Ty>,
+    log: &mut MutationLog<Ty>,
+    nswap: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+    keep: F,
+) -> usize
+where
+    Ty: EdgeType,
+    F: Fn(&StablePyGraph<Ty>, Endpoints, Endpoints) -> bool,
+{
+    let mut edges: Vec<EdgeIndex> = graph.edge_indices().collect();
+    if edges.len() < 2 {
+        return 0;
+    }
+    let mut rng = seeded_rng(seed);
+    let max_tries = max_tries.unwrap_or_else(|| nswap.saturating_mul(100));
+    let mut swaps = 0;
+    let mut tries = 0;
+    while swaps < nswap && tries < max_tries {
+        tries += 1;
+        let first = rng.gen_range(0..edges.len());
+        let second = rng.gen_range(0..edges.len());
+        let (a, b) = graph.edge_endpoints(edges[first]).unwrap();
+        let (mut c, mut d) = graph.edge_endpoints(edges[second]).unwrap();
+        if !Ty::is_directed() && rng.gen() {
+            std::mem::swap(&mut c, &mut d);
+        }
+        if first == second || a == b || c == d || a == c || a == d || b == c || b == d {
+            continue;
+        }
+        if graph.contains_edge(a, d) || graph.contains_edge(c, b) {
+            continue;
+        }
+        let (new_first, new_second) =
+            swap_edges(graph, log, (edges[first], edges[second]), ((a, d), (c, b)));
+        if keep(graph, (a, b), (c, d)) {
+            edges[first] = new_first;
+            edges[second] = new_second;
+            swaps += 1;
+        } else {
+            let (old_first, old_second) =
+                swap_edges(graph, log, (new_first, new_second), ((a, b), (c, d)));
+            edges[first] = old_first;
+            edges[second] = old_second;
+        }
+    }
+    swaps
+}
+
+/// Randomize a :class:`~retworkx.PyGraph` in place while keeping the degree
+/// of every node
+///
+/// Each double edge swap replaces two edges ``(a, b)`` and ``(c, d)``, chosen
+/// uniformly at random, with ``(a, d)`` and ``(c, b)``, so every node keeps
+/// its degree while the rest of the structure of the graph is randomized.
+/// This is commonly used to generate null models for measures like
+/// assortativity or clustering. Swaps that would create self loops or
+/// parallel edges are rejected. The edges keep their indices and weight/data
+/// payloads, so the edge ``(a, b)`` becomes the edge ``(a, d)``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(4, 4)
+///   swaps = retworkx.graph_double_edge_swap(graph, nswap=10, seed=42)
+///   print(swaps, graph.edge_list())
+///
+/// :param PyGraph graph: The graph to randomize
+/// :param int nswap: The number of double edge swaps to make
+/// :param int max_tries: The maximum number of swaps to attempt. By default
+///     this is ``100 * nswap``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps made, which is less than ``nswap`` if
+///     ``max_tries`` was reached first
+/// :rtype: int
+#[pyfunction(nswap = "1")]
+#[pyo3(text_signature = "(graph, /, nswap=1, max_tries=None, seed=None)")]
+pub fn graph_double_edge_swap(
+    graph: &mut graph::PyGraph,
+    nswap: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> usize {
+    double_edge_swap(
+        &mut graph.graph,
+        &mut graph.log,
+        nswap,
+        max_tries,
+        seed,
+        |_, _, _| true,
+    )
+}
+
+/// Randomize a :class:`~retworkx.PyDiGraph` in place while keeping the in and
+/// out degree of every node
+///
+/// Each double edge swap replaces two edges ``(a, b)`` and ``(c, d)``, chosen
+/// uniformly at random, with ``(a, d)`` and ``(c, b)``, so every node keeps
+/// its in degree and out degree while the rest of the structure of the graph
+/// is randomized. This is commonly used to generate null models for measures
+/// like assortativity or clustering. Swaps that would create self loops or
+/// parallel edges are rejected, as are swaps that would create a cycle if
+/// cycle checking is enabled on the graph. The edges keep their indices and
+/// weight/data payloads, so the edge ``(a, b)`` becomes the edge ``(a, d)``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(4, 4)
+///   swaps = retworkx.digraph_double_edge_swap(graph, nswap=10, seed=42)
+///   print(swaps, graph.edge_list())
+///
+/// :param PyDiGraph graph: The graph to randomize
+/// :param int nswap: The number of double edge swaps to make
+/// :param int max_tries: The maximum number of swaps to attempt. By default
+///     this is ``100 * nswap``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps made, which is less than ``nswap`` if
+///     ``max_tries`` was reached first
+/// :rtype: int
+#[pyfunction(nswap = "1")]
+#[pyo3(text_signature = "(graph, /, nswap=1, max_tries=None, seed=None)")]
+pub fn digraph_double_edge_swap(
+    graph: &mut digraph::PyDiGraph,
+    nswap: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> usize {
+    let check_cycle = graph.check_cycle;
+    double_edge_swap(
+        &mut graph.graph,
+        &mut graph.log,
+        nswap,
+        max_tries,
+        seed,
+        |graph, (a, b), (c, d)| {
+            // The graph was acyclic, so a cycle has to go through one of the
+            // new edges (a, d) and (c, b).
+            !check_cycle
+                || !(has_path_connecting(graph, d, a, None)
+                    || has_path_connecting(graph, b, c, None))
+        },
+    )
+}
+
+/// Randomize a connected :class:`~retworkx.PyGraph` in place while keeping
+/// the degree of every node and keeping it connected
+///
+/// This makes double edge swaps like :func:`~retworkx.graph_double_edge_swap`
+/// but a swap is rejected, and undone, if it disconnects the graph. This is
+/// commonly used to generate null models for measures like the average
+/// shortest path length which are only defined for connected graphs.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(4, 4)
+///   swaps = retworkx.connected_double_edge_swap(graph, nswap=10, seed=42)
+///   print(swaps, retworkx.is_connected(graph))
+///
+/// :param PyGraph graph: The graph to randomize
+/// :param int nswap: The number of double edge swaps to make
+/// :param int max_tries: The maximum number of swaps to attempt. By default
+///     this is ``100 * nswap``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps made, which is less than ``nswap`` if
+///     ``max_tries`` was reached first
+/// :rtype: int
+///
+/// :raises DisconnectedGraphError: If the graph is not connected
+#[pyfunction(nswap = "1")]
+#[pyo3(text_signature = "(graph, /, nswap=1, max_tries=None, seed=None)")]
+pub fn connected_double_edge_swap(
+    graph: &mut graph::PyGraph,
+    nswap: usize,
+    max_tries: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<usize> {
+    if let Some(start) = graph.graph.node_indices().next() {
+        let mut dfs = Dfs::new(&graph.graph, start);
+        let mut reached = 0;
+        while dfs.next(&graph.graph).is_some() {
+            reached += 1;
+        }
+        if reached != graph.graph.node_count() {
+            return Err(DisconnectedGraphError::new_err(
+                "The graph is not connected",
+            ));
+        }
+    }
+    // The swapped graph is connected if a and b are, since a is joined to d
+    // and c is joined to b.
+    Ok(double_edge_swap(
+        &mut graph.graph,
+        &mut graph.log,
+        nswap,
+        max_tries,
+        seed,
+        |graph, (a, b), _| has_path_connecting(graph, a, b, None),
+    ))
+}
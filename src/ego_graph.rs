@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::{core_error, digraph, graph, CostFn, InvalidNode, NodeIndex, StablePyGraph};
+
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::shortest_path::dijkstra;
+
+/// Find the nodes within ``radius`` of ``node`` sorted by node index.
+fn ego_nodes<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    node: NodeIndex,
+    radius: f64,
+    cost_fn: &CostFn,
+) -> PyResult<Vec<usize>> {
+    let distances: Vec<Option<f64>> = dijkstra(
+        graph,
+        node,
+        None,
+        |e| cost_fn.call_edge(py, e.id(), e.weight()),
+        None,
+    )
+    .map_err(core_error)?;
+    Ok(distances
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, dist)| match dist {
+            Some(dist) if dist <= radius => Some(index),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Return the ego graph of a node in a :class:`~retworkx.PyGraph`
+///
+/// The ego graph is the subgraph induced by the center node and all the
+/// nodes within a given distance ``radius`` of it.
+///
+/// :param PyGraph graph: The input graph
+/// :param int node: The node index of the center of the ego graph
+/// :param float radius: The maximum distance from ``node`` for a node to be
+///     included in the ego graph. By default this is the number of hops from
+///     ``node``.
+/// :param distance_fn: An optional callable which takes a single positional
+///     argument, an edge's weight/data payload, and returns a float
+///     representing the distance along that edge. It must be non-negative.
+///     If not specified each edge will have a distance of ``1.0``.
+///
+/// :returns: A new graph for the subgraph induced by the nodes within
+///     ``radius`` of ``node``. The node indices in the ego graph will be
+///     compacted the same as for :meth:`~retworkx.PyGraph.subgraph`, and
+///     node and edge weight/data payloads are shared references with
+///     ``graph``.
+/// :rtype: PyGraph
+/// :raises InvalidNode: When an invalid node index is provided.
+/// :raises ValueError: when ``distance_fn`` returns a NaN or negative value.
+#[pyfunction(radius = "1.0")]
+#[pyo3(text_signature = "(graph, node, /, radius=1.0, distance_fn=None)")]
+pub fn graph_ego_graph(
+    py: Python,
+    graph: &graph::PyGraph,
+    node: usize,
+    radius: f64,
+    distance_fn: Option<PyObject>,
+) -> PyResult<graph::PyGraph> {
+    let center = NodeIndex::new(node);
+    if !graph.graph.contains_node(center) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'node' is not a valid node index",
+        ));
+    }
+    let cost_fn = CostFn::try_from((distance_fn, 1.0))?.prepare(py, &graph.graph)?;
+    let nodes = ego_nodes(py, &graph.graph, center, radius, &cost_fn)?;
+    Ok(graph.subgraph(py, nodes))
+}
+
+/// Return the ego graph of a node in a :class:`~retworkx.PyDiGraph`
+///
+/// The ego graph is the subgraph induced by the center node and all the
+/// nodes within a given distance ``radius`` of it. By default only the
+/// nodes reachable from ``node`` by following the edge directions are
+/// considered.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int node: The node index of the center of the ego graph
+/// :param float radius: The maximum distance from ``node`` for a node to be
+///     included in the ego graph. By default this is the number of hops from
+///     ``node``.
+/// :param distance_fn: An optional callable which takes a single positional
+///     argument, an edge's weight/data payload, and returns a float
+///     representing the distance along that edge. It must be non-negative.
+///     If not specified each edge will have a distance of ``1.0``.
+/// :param bool undirected: If set to ``True`` the edge directions will be
+///     ignored when finding the nodes within ``radius`` of ``node``. The
+///     returned ego graph is still directed.
+///
+/// :returns: A new graph for the subgraph induced by the nodes within
+///     ``radius`` of ``node``. The node indices in the ego graph will be
+///     compacted the same as for :meth:`~retworkx.PyDiGraph.subgraph`, and
+///     node and edge weight/data payloads are shared references with
+///     ``graph``.
+/// :rtype: PyDiGraph
+/// :raises InvalidNode: When an invalid node index is provided.
+/// :raises ValueError: when ``distance_fn`` returns a NaN or negative value.
+#[pyfunction(radius = "1.0", undirected = "false")]
+#[pyo3(text_signature = "(graph, node, /, radius=1.0, distance_fn=None, undirected=False)")]
+pub fn digraph_ego_graph(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    radius: f64,
+    distance_fn: Option<PyObject>,
+    undirected: bool,
+) -> PyResult<digraph::PyDiGraph> {
+    let center = NodeIndex::new(node);
+    if !graph.graph.contains_node(center) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'node' is not a valid node index",
+        ));
+    }
+    let cost_fn = CostFn::try_from((distance_fn, 1.0))?.prepare(py, &graph.graph)?;
+    let nodes = if undirected {
+        ego_nodes(
+            py,
+            &graph.to_undirected(py, true, None)?.graph,
+            center,
+            radius,
+            &cost_fn,
+        )?
+    } else {
+        ego_nodes(py, &graph.graph, center, radius, &cost_fn)?
+    };
+    Ok(graph.subgraph(py, nodes))
+}
@@ -0,0 +1,415 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+use rayon::prelude::*;
+
+use crate::random_seed::seeded_rng;
+use crate::{digraph, graph, weight_callable, InvalidNode, StablePyGraph};
+
+const SUSCEPTIBLE: u8 = 0;
+const INFECTED: u8 = 1;
+const RECOVERED: u8 = 2;
+// A susceptible node infected during the current step, which can't spread
+// the infection until the next step
+const EXPOSED: u8 = 3;
+
+/// The compartmental model of the simulation.
+#[derive(Clone, Copy)]
+enum Model {
+    Sir,
+    Sis,
+}
+
+/// The nodes of a graph by compact position and, for every node, the nodes
+/// it can infect with the probability of infection along each edge.
+struct Contacts {
+    nodes: Vec<usize>,
+    targets: Vec<Vec<(usize, f64)>>,
+}
+
+impl Contacts {
+    fn new<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        transmission_fn: Option<PyObject>,
+        default_transmission: f64,
+    ) -> PyResult<Self> {
+        let mut position: Vec<usize> = vec![0; graph.node_bound()];
+        let nodes: Vec<usize> = graph.node_indices().map(|n| n.index()).collect();
+        for (index, node) in nodes.iter().enumerate() {
+            position[*node] = index;
+        }
+        let mut targets: Vec<Vec<(usize, f64)>> = vec![Vec::new(); nodes.len()];
+        for edge in graph.edge_references() {
+            let probability =
+                weight_callable(py, &transmission_fn, edge.weight(), default_transmission)?;
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(PyValueError::new_err(format!(
+                    "Transmission probability {} is not between 0 and 1",
+                    probability
+                )));
+            }
+            let source = position[edge.source().index()];
+            let target = position[edge.target().index()];
+            targets[source].push((target, probability));
+            if !Ty::is_directed() && source != target {
+                targets[target].push((source, probability));
+            }
+        }
+        Ok(Contacts { nodes, targets })
+    }
+
+    /// Run a single trial of the simulation and return the number of
+    /// susceptible, infected and recovered nodes after every step and the
+    /// final state of every node.
+    fn simulate(
+        &self,
+        model: Model,
+        initial: &[usize],
+        recovery_rate: f64,
+        max_steps: usize,
+        seed: u64,
+    ) -> (Vec<[usize; 3]>, Vec<u8>) {
+        let mut rng = Pcg64::seed_from_u64(seed);
+        let mut state = vec![SUSCEPTIBLE; self.nodes.len()];
+        let mut infected: Vec<usize> = Vec::with_capacity(initial.len());
+        for node in initial {
+            if state[*node] == SUSCEPTIBLE {
+                state[*node] = INFECTED;
+                infected.push(*node);
+            }
+        }
+        let mut counts = [self.nodes.len() - infected.len(), infected.len(), 0];
+        let mut series = Vec::with_capacity(max_steps + 1);
+        series.push(counts);
+        for _ in 0..max_steps {
+            if infected.is_empty() {
+                break;
+            }
+            let mut exposed: Vec<usize> = Vec::new();
+            for node in &infected {
+                for (target, probability) in &self.targets[*node] {
+                    if state[*target] == SUSCEPTIBLE && rng.gen::<f64>() < *probability {
+                        state[*target] = EXPOSED;
+                        exposed.push(*target);
+                    }
+                }
+            }
+            let mut still_infected: Vec<usize> = Vec::with_capacity(infected.len());
+            for node in infected {
+                if rng.gen::<f64>() < recovery_rate {
+                    match model {
+                        Model::Sir => {
+                            state[node] = RECOVERED;
+                            counts[2] += 1;
+                        }
+                        Model::Sis => {
+                            state[node] = SUSCEPTIBLE;
+                            counts[0] += 1;
+                        }
+                    }
+                    counts[1] -= 1;
+                } else {
+                    still_infected.push(node);
+                }
+            }
+            for node in &exposed {
+                state[*node] = INFECTED;
+            }
+            counts[0] -= exposed.len();
+            counts[1] += exposed.len();
+            still_infected.append(&mut exposed);
+            infected = still_infected;
+            series.push(counts);
+        }
+        // Nothing changes once there are no infected nodes left
+        while series.len() < max_steps + 1 {
+            series.push(counts);
+        }
+        (series, state)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn epidemic_simulation<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    initial_infected: Vec<usize>,
+    model: &str,
+    transmission_fn: Option<PyObject>,
+    default_transmission: f64,
+    recovery_rate: f64,
+    num_trials: usize,
+    max_steps: usize,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    let model = match model {
+        "SIR" => Model::Sir,
+        "SIS" => Model::Sis,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid model '{}', must be 'SIR' or 'SIS'",
+                model
+            )))
+        }
+    };
+    if !(0.0..=1.0).contains(&recovery_rate) {
+        return Err(PyValueError::new_err(
+            "recovery_rate must be between 0 and 1",
+        ));
+    }
+    let contacts = Contacts::new(py, graph, transmission_fn, default_transmission)?;
+    let mut position: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    for (index, node) in contacts.nodes.iter().enumerate() {
+        position[*node] = Some(index);
+    }
+    let initial = initial_infected
+        .iter()
+        .map(|node| match position.get(*node) {
+            Some(Some(index)) => Ok(*index),
+            _ => Err(InvalidNode::new_err(format!(
+                "Node index {} is not present in the graph",
+                node
+            ))),
+        })
+        .collect::<PyResult<Vec<usize>>>()?;
+    let mut rng = seeded_rng(seed);
+    // Every trial gets its own generator so the results don't depend on how
+    // the trials are split between threads
+    let trial_seeds: Vec<u64> = (0..num_trials).map(|_| rng.gen()).collect();
+    let trials: Vec<(Vec<[usize; 3]>, Vec<u8>)> = py.allow_threads(|| {
+        trial_seeds
+            .into_par_iter()
+            .map(|trial_seed| {
+                contacts.simulate(model, &initial, recovery_rate, max_steps, trial_seed)
+            })
+            .collect()
+    });
+    let node_count = contacts.nodes.len();
+    let mut counts = Array3::<usize>::zeros((num_trials, max_steps + 1, 3));
+    let mut states = Array2::<u8>::zeros((num_trials, node_count));
+    for (trial, (series, state)) in trials.into_iter().enumerate() {
+        for (step, step_counts) in series.iter().enumerate() {
+            for (compartment, count) in step_counts.iter().enumerate() {
+                counts[[trial, step, compartment]] = *count;
+            }
+        }
+        for (index, node_state) in state.into_iter().enumerate() {
+            states[[trial, index]] = node_state;
+        }
+    }
+    Ok((counts.into_pyarray(py), states.into_pyarray(py)).into_py(py))
+}
+
+/// Simulate the spread of an epidemic on an undirected graph
+///
+/// Every node of the graph is either susceptible, infected or recovered, and
+/// the simulation starts with the ``initial_infected`` nodes infected and
+/// every other node susceptible. At every discrete time step, every infected
+/// node infects each of its susceptible neighbors independently with the
+/// transmission probability of the edge between them, and then recovers
+/// with probability ``recovery_rate``. Nodes infected during a step can only
+/// spread the infection from the next step on. With the ``"SIR"`` model a
+/// recovered node is immune, while with the ``"SIS"`` model it becomes
+/// susceptible again. Parallel edges give independent chances of infection.
+///
+/// The trials are independent and run in parallel, they stop when there are
+/// no infected nodes left or after ``max_steps`` steps.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(10, 10)
+///   counts, states = retworkx.graph_epidemic_simulation(
+///       graph, [0], default_transmission=0.5, recovery_rate=0.2, max_steps=20, seed=42
+///   )
+///   print(counts[0, :, 1])
+///
+/// :param PyGraph graph: The graph to simulate the epidemic on
+/// :param list initial_infected: The indices of the nodes which are infected
+///     at the start of the simulation
+/// :param str model: Either ``"SIR"`` or ``"SIS"``, whether recovered nodes
+///     are immune or susceptible again. By default this is ``"SIR"``.
+/// :param transmission_fn: An optional callable object (function, lambda,
+///     etc) which will be passed the edge object and expected to return a
+///     ``float`` probability of transmission along the edge at every step. If
+///     it's not specified ``default_transmission`` is used for all edges.
+/// :param float default_transmission: The probability of transmission along
+///     every edge if ``transmission_fn`` isn't specified. By default this is
+///     ``0.1``.
+/// :param float recovery_rate: The probability that an infected node
+///     recovers at every step. By default this is ``0.1``.
+/// :param int num_trials: The number of independent trials to run. By
+///     default this is ``1``.
+/// :param int max_steps: The maximum number of steps of every trial. By
+///     default this is ``100``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of two numpy arrays. The first has shape
+///     ``(num_trials, max_steps + 1, 3)`` and contains the number of
+///     susceptible, infected and recovered nodes at the start of every trial
+///     and after every step, repeating the final counts once a trial ends.
+///     The second has shape ``(num_trials, num_nodes)`` and contains the
+///     final state of every node, ``0`` for susceptible, ``1`` for infected
+///     and ``2`` for recovered, with the nodes in the order of
+///     :meth:`~retworkx.PyGraph.node_indices`.
+/// :rtype: tuple
+/// :raises InvalidNode: If ``initial_infected`` contains an invalid node
+///     index
+/// :raises ValueError: If ``model`` is invalid or a probability isn't between
+///     0 and 1
+#[pyfunction(
+    model = "\"SIR\"",
+    default_transmission = "0.1",
+    recovery_rate = "0.1",
+    num_trials = "1",
+    max_steps = "100"
+)]
+#[pyo3(
+    text_signature = "(graph, initial_infected, /, model=\"SIR\", transmission_fn=None, default_transmission=0.1, recovery_rate=0.1, num_trials=1, max_steps=100, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_epidemic_simulation(
+    py: Python,
+    graph: &graph::PyGraph,
+    initial_infected: Vec<usize>,
+    model: &str,
+    transmission_fn: Option<PyObject>,
+    default_transmission: f64,
+    recovery_rate: f64,
+    num_trials: usize,
+    max_steps: usize,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    epidemic_simulation(
+        py,
+        &graph.graph,
+        initial_infected,
+        model,
+        transmission_fn,
+        default_transmission,
+        recovery_rate,
+        num_trials,
+        max_steps,
+        seed,
+    )
+}
+
+/// Simulate the spread of an epidemic on a directed graph
+///
+/// Every node of the graph is either susceptible, infected or recovered, and
+/// the simulation starts with the ``initial_infected`` nodes infected and
+/// every other node susceptible. At every discrete time step, every infected
+/// node infects each of its susceptible successors independently with the
+/// transmission probability of the edge to them, and then recovers with
+/// probability ``recovery_rate``. The infection only spreads along the edge
+/// directions. Nodes infected during a step can only spread the infection
+/// from the next step on. With the ``"SIR"`` model a recovered node is
+/// immune, while with the ``"SIS"`` model it becomes susceptible again.
+/// Parallel edges give independent chances of infection.
+///
+/// The trials are independent and run in parallel, they stop when there are
+/// no infected nodes left or after ``max_steps`` steps.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(10, 10)
+///   counts, states = retworkx.digraph_epidemic_simulation(
+///       graph, [0], default_transmission=0.5, recovery_rate=0.2, max_steps=20, seed=42
+///   )
+///   print(counts[0, :, 1])
+///
+/// :param PyDiGraph graph: The graph to simulate the epidemic on
+/// :param list initial_infected: The indices of the nodes which are infected
+///     at the start of the simulation
+/// :param str model: Either ``"SIR"`` or ``"SIS"``, whether recovered nodes
+///     are immune or susceptible again. By default this is ``"SIR"``.
+/// :param transmission_fn: An optional callable object (function, lambda,
+///     etc) which will be passed the edge object and expected to return a
+///     ``float`` probability of transmission along the edge at every step. If
+///     it's not specified ``default_transmission`` is used for all edges.
+/// :param float default_transmission: The probability of transmission along
+///     every edge if ``transmission_fn`` isn't specified. By default this is
+///     ``0.1``.
+/// :param float recovery_rate: The probability that an infected node
+///     recovers at every step. By default this is ``0.1``.
+/// :param int num_trials: The number of independent trials to run. By
+///     default this is ``1``.
+/// :param int max_steps: The maximum number of steps of every trial. By
+///     default this is ``100``.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of two numpy arrays. The first has shape
+///     ``(num_trials, max_steps + 1, 3)`` and contains the number of
+///     susceptible, infected and recovered nodes at the start of every trial
+///     and after every step, repeating the final counts once a trial ends.
+///     The second has shape ``(num_trials, num_nodes)`` and contains the
+///     final state of every node, ``0`` for susceptible, ``1`` for infected
+///     and ``2`` for recovered, with the nodes in the order of
+///     :meth:`~retworkx.PyDiGraph.node_indices`.
+/// :rtype: tuple
+/// :raises InvalidNode: If ``initial_infected`` contains an invalid node
+///     index
+/// :raises ValueError: If ``model`` is invalid or a probability isn't between
+///     0 and 1
+#[pyfunction(
+    model = "\"SIR\"",
+    default_transmission = "0.1",
+    recovery_rate = "0.1",
+    num_trials = "1",
+    max_steps = "100"
+)]
+#[pyo3(
+    text_signature = "(graph, initial_infected, /, model=\"SIR\", transmission_fn=None, default_transmission=0.1, recovery_rate=0.1, num_trials=1, max_steps=100, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_epidemic_simulation(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    initial_infected: Vec<usize>,
+    model: &str,
+    transmission_fn: Option<PyObject>,
+    default_transmission: f64,
+    recovery_rate: f64,
+    num_trials: usize,
+    max_steps: usize,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    epidemic_simulation(
+        py,
+        &graph.graph,
+        initial_infected,
+        model,
+        transmission_fn,
+        default_transmission,
+        recovery_rate,
+        num_trials,
+        max_steps,
+        seed,
+    )
+}
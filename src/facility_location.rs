@@ -0,0 +1,449 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::convert::{Infallible, TryFrom};
+
+use rand::seq::index::sample;
+use rayon::prelude::*;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::shortest_path::dijkstra;
+
+use crate::iterators::{NodeIndices, NodeMap};
+use crate::random_seed::seeded_rng;
+use crate::{digraph, graph, CostFn, NoPathFound, NodeIndex, StablePyGraph};
+
+/// The minimum decrease of the cost for a swap of the local search to be
+/// applied, this avoids cycling on floating point rounding errors.
+const IMPROVEMENT_TOLERANCE: f64 = 1e-9;
+
+/// The cost of an assignment of nodes to centers to minimize.
+#[derive(Clone, Copy)]
+enum Objective {
+    /// The total distance from the centers to their nodes
+    Median,
+    /// The largest distance from a center to one of its nodes
+    Center,
+}
+
+/// Assign each node to a center and return the center of each node, by
+/// position in ``centers``, and the cost of the assignment. Without a
+/// ``capacity`` each node is assigned to its closest center, otherwise the
+/// pairs of a node and a center are assigned greedily from the closest one
+/// while the center has room. Each center is always assigned to itself.
+fn assign(
+    distances: &[Vec<f64>],
+    centers: &[usize],
+    capacity: Option<usize>,
+    objective: Objective,
+) -> (Vec<usize>, f64) {
+    let node_count = distances.len();
+    let mut assignment: Vec<usize> = vec![usize::MAX; node_count];
+    match capacity {
+        None => {
+            for (node, center) in assignment.iter_mut().enumerate() {
+                *center = (0..centers.len())
+                    .min_by(|a, b| {
+                        distances[centers[*a]][node]
+                            .partial_cmp(&distances[centers[*b]][node])
+                            .unwrap_or(Ordering::Less)
+                    })
+                    .unwrap();
+            }
+        }
+        Some(capacity) => {
+            let mut load: Vec<usize> = vec![1; centers.len()];
+            for (position, center) in centers.iter().enumerate() {
+                assignment[*center] = position;
+            }
+            let mut pairs: Vec<(f64, usize, usize)> = (0..node_count)
+                .filter(|node| assignment[*node] == usize::MAX)
+                .flat_map(|node| {
+                    centers
+                        .iter()
+                        .enumerate()
+                        .map(move |(position, center)| (distances[*center][node], node, position))
+                })
+                .collect();
+            pairs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Less));
+            for (_, node, position) in pairs {
+                if assignment[node] == usize::MAX && load[position] < capacity {
+                    assignment[node] = position;
+                    load[position] += 1;
+                }
+            }
+        }
+    }
+    let costs = assignment
+        .iter()
+        .enumerate()
+        .map(|(node, position)| distances[centers[*position]][node]);
+    let cost = match objective {
+        Objective::Median => costs.sum(),
+        Objective::Center => costs.fold(0., f64::max),
+    };
+    (assignment, cost)
+}
+
+/// Improve a set of centers by swapping a center for another node while it
+/// lowers the cost of the assignment, the vertex substitution heuristic of
+/// Teitz and Bart.
+fn vertex_substitution(
+    distances: &[Vec<f64>],
+    centers: &mut [usize],
+    capacity: Option<usize>,
+    objective: Objective,
+) -> f64 {
+    let node_count = distances.len();
+    let mut is_center = vec![false; node_count];
+    for center in centers.iter() {
+        is_center[*center] = true;
+    }
+    let mut cost = assign(distances, centers, capacity, objective).1;
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for position in 0..centers.len() {
+            for node in 0..node_count {
+                if is_center[node] {
+                    continue;
+                }
+                let old = centers[position];
+                centers[position] = node;
+                let new_cost = assign(distances, centers, capacity, objective).1;
+                if new_cost < cost - IMPROVEMENT_TOLERANCE {
+                    is_center[old] = false;
+                    is_center[node] = true;
+                    cost = new_cost;
+                    improved = true;
+                } else {
+                    centers[position] = old;
+                }
+            }
+        }
+    }
+    cost
+}
+
+#[allow(clippy::too_many_arguments)]
+fn facility_location<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    p: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    capacity: Option<usize>,
+    num_samples: usize,
+    seed: Option<u64>,
+    objective: Objective,
+) -> PyResult<(NodeIndices, NodeMap, f64)> {
+    let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+    if p > nodes.len() || p == 0 && !nodes.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "The number of centers must be between 1 and the number of nodes, {}",
+            nodes.len()
+        )));
+    }
+    if let Some(capacity) = capacity {
+        if p * capacity < nodes.len() {
+            return Err(PyValueError::new_err(format!(
+                "{} centers with a capacity of {} can't serve {} nodes",
+                p,
+                capacity,
+                nodes.len()
+            )));
+        }
+    }
+    if nodes.is_empty() {
+        return Ok((
+            NodeIndices { nodes: Vec::new() },
+            NodeMap {
+                node_map: DictMap::new(),
+            },
+            0.,
+        ));
+    }
+
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, graph)?;
+    let mut weights: Vec<f64> = vec![0.; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+    let mut rng = seeded_rng(seed);
+    let samples: Vec<Vec<usize>> = (0..num_samples.max(1))
+        .map(|_| sample(&mut rng, nodes.len(), p).into_vec())
+        .collect();
+
+    let (centers, assignment, cost) = py.allow_threads(|| {
+        // The distance from each node, by position in ``nodes``, to every
+        // other node
+        let distances: Vec<Vec<f64>> = nodes
+            .par_iter()
+            .map(|node| {
+                let res: Result<Vec<Option<f64>>, _> = dijkstra(
+                    graph,
+                    *node,
+                    None,
+                    |e| Ok::<f64, Infallible>(weights[e.id().index()]),
+                    None,
+                );
+                let scores = res.unwrap();
+                nodes
+                    .iter()
+                    .map(|other| scores[other.index()].unwrap_or(f64::INFINITY))
+                    .collect()
+            })
+            .collect();
+        let (mut centers, _) = samples
+            .into_par_iter()
+            .map(|mut centers| {
+                let cost = vertex_substitution(&distances, &mut centers, capacity, objective);
+                (centers, cost)
+            })
+            .reduce_with(|a, b| if b.1 < a.1 { b } else { a })
+            .unwrap();
+        centers.sort_unstable();
+        let (assignment, cost) = assign(&distances, &centers, capacity, objective);
+        (centers, assignment, cost)
+    });
+    if cost.is_infinite() {
+        return Err(NoPathFound::new_err(
+            "Unable to find centers with a path to every node",
+        ));
+    }
+    Ok((
+        NodeIndices {
+            nodes: centers
+                .iter()
+                .map(|center| nodes[*center].index())
+                .collect(),
+        },
+        NodeMap {
+            node_map: assignment
+                .into_iter()
+                .enumerate()
+                .map(|(node, position)| (nodes[node].index(), nodes[centers[position]].index()))
+                .collect(),
+        },
+        cost,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn solve(
+    py: Python,
+    graph: &PyAny,
+    p: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    capacity: Option<usize>,
+    num_samples: usize,
+    seed: Option<u64>,
+    objective: Objective,
+) -> PyResult<(NodeIndices, NodeMap, f64)> {
+    if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+        facility_location(
+            py,
+            &graph.graph,
+            p,
+            weight_fn,
+            default_weight,
+            capacity,
+            num_samples,
+            seed,
+            objective,
+        )
+    } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+        facility_location(
+            py,
+            &graph.graph,
+            p,
+            weight_fn,
+            default_weight,
+            capacity,
+            num_samples,
+            seed,
+            objective,
+        )
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "Invalid Input Type {} for graph",
+            graph.get_type()
+        )))
+    }
+}
+
+/// Find ``p`` centers minimizing the total distance to the nodes of a graph
+///
+/// This is a heuristic for the p-median problem, a facility location
+/// problem: choose ``p`` nodes as centers (facilities) and assign every node
+/// to a center so that the total shortest path distance from the centers to
+/// their nodes is as small as possible. For a :class:`~retworkx.PyDiGraph`
+/// the distances are along the direction of the edges, from the center to
+/// the node.
+///
+/// ``num_samples`` random sets of centers are each improved with the vertex
+/// substitution heuristic of Teitz and Bart [1]_, which swaps a center for
+/// another node while that lowers the total distance, and the best result
+/// is returned. Without a ``capacity`` each node is assigned to its closest
+/// center. With a ``capacity`` each center serves at most that many nodes,
+/// counting itself, and the nodes are assigned greedily, the closest pair of
+/// a node and a center with room first.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(5, 5)
+///   centers, assignment, cost = retworkx.p_median(graph, 2, seed=42)
+///   print(centers, cost)
+///
+/// :param graph: The graph to find the centers in, either a
+///     :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+/// :param int p: The number of centers, between 1 and the number of nodes
+/// :param weight_fn: An optional callable object that will be passed an
+///     edge's data payload and expected to return a non-negative ``float``
+///     for the weight of that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     float value will be used for the weight of each edge.
+/// :param int capacity: The maximum number of nodes assigned to a center,
+///     including the center. If not specified there is no limit
+/// :param int num_samples: The number of random sets of centers to start
+///     the local search from, at least one is used. Default: ``10``
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the indices of the centers in ascending order, a
+///     :class:`~retworkx.NodeMap` from the index of each node to the index
+///     of its center and the total distance from the centers to their nodes
+/// :rtype: tuple
+/// :raises ValueError: If ``p`` isn't between 1 and the number of nodes,
+///     the centers can't serve every node with their ``capacity`` or an edge
+///     weight is NaN or negative
+/// :raises NoPathFound: If the centers found don't have a path to every node
+/// :raises TypeError: If ``graph`` isn't a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`
+///
+/// .. [1] Teitz, Michael B. and Bart, Polly. "Heuristic methods for
+///    estimating the generalized vertex median of a weighted graph."
+///    Operations Research 16.5 (1968): 955-961.
+#[pyfunction(default_weight = "1.0", num_samples = "10")]
+#[pyo3(
+    text_signature = "(graph, p, /, weight_fn=None, default_weight=1.0, capacity=None, num_samples=10, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn p_median(
+    py: Python,
+    graph: &PyAny,
+    p: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    capacity: Option<usize>,
+    num_samples: usize,
+    seed: Option<u64>,
+) -> PyResult<(NodeIndices, NodeMap, f64)> {
+    solve(
+        py,
+        graph,
+        p,
+        weight_fn,
+        default_weight,
+        capacity,
+        num_samples,
+        seed,
+        Objective::Median,
+    )
+}
+
+/// Find ``k`` centers minimizing the largest distance to the nodes of a graph
+///
+/// This is a heuristic for the k-center problem, a facility location
+/// problem: choose ``k`` nodes as centers (facilities) and assign every node
+/// to a center so that the largest shortest path distance from a center to
+/// one of its nodes is as small as possible. For a
+/// :class:`~retworkx.PyDiGraph` the distances are along the direction of the
+/// edges, from the center to the node.
+///
+/// ``num_samples`` random sets of centers are each improved by swapping a
+/// center for another node while that lowers the largest distance, and the
+/// best result is returned. Without a ``capacity`` each node is assigned to
+/// its closest center. With a ``capacity`` each center serves at most that
+/// many nodes, counting itself, and the nodes are assigned greedily, the
+/// closest pair of a node and a center with room first.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(5, 5)
+///   centers, assignment, cost = retworkx.k_center(graph, 2, seed=42)
+///   print(centers, cost)
+///
+/// :param graph: The graph to find the centers in, either a
+///     :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+/// :param int k: The number of centers, between 1 and the number of nodes
+/// :param weight_fn: An optional callable object that will be passed an
+///     edge's data payload and expected to return a non-negative ``float``
+///     for the weight of that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     float value will be used for the weight of each edge.
+/// :param int capacity: The maximum number of nodes assigned to a center,
+///     including the center. If not specified there is no limit
+/// :param int num_samples: The number of random sets of centers to start
+///     the local search from, at least one is used. Default: ``10``
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the indices of the centers in ascending order, a
+///     :class:`~retworkx.NodeMap` from the index of each node to the index
+///     of its center and the largest distance from a center to one of its
+///     nodes
+/// :rtype: tuple
+/// :raises ValueError: If ``k`` isn't between 1 and the number of nodes,
+///     the centers can't serve every node with their ``capacity`` or an edge
+///     weight is NaN or negative
+/// :raises NoPathFound: If the centers found don't have a path to every node
+/// :raises TypeError: If ``graph`` isn't a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`
+#[pyfunction(default_weight = "1.0", num_samples = "10")]
+#[pyo3(
+    text_signature = "(graph, k, /, weight_fn=None, default_weight=1.0, capacity=None, num_samples=10, seed=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn k_center(
+    py: Python,
+    graph: &PyAny,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    capacity: Option<usize>,
+    num_samples: usize,
+    seed: Option<u64>,
+) -> PyResult<(NodeIndices, NodeMap, f64)> {
+    solve(
+        py,
+        graph,
+        k,
+        weight_fn,
+        default_weight,
+        capacity,
+        num_samples,
+        seed,
+        Objective::Center,
+    )
+}
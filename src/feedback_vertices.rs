@@ -0,0 +1,318 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use hashbrown::HashSet;
+
+use pyo3::prelude::*;
+
+use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+
+use crate::{digraph, graph, NodeIndex};
+
+fn has_self_loop<Ty: petgraph::EdgeType>(
+    graph: &crate::StablePyGraph<Ty>,
+    node: NodeIndex,
+) -> bool {
+    graph
+        .edges_directed(node, Outgoing)
+        .any(|edge| edge.target() == node && edge.source() == node)
+}
+
+/// Greedily pick the nodes of a feedback vertex set of an undirected graph.
+///
+/// Nodes with at most one remaining edge are in no cycle and are pruned,
+/// when there are none left the node with the most remaining edges is added
+/// to the set. Returns the set in the order the nodes were added.
+fn undirected_candidates(graph: &graph::PyGraph) -> Vec<NodeIndex> {
+    let bound = graph.graph.node_bound();
+    let mut removed = vec![false; bound];
+    let mut selected: Vec<NodeIndex> = Vec::new();
+    for node in graph.graph.node_indices() {
+        if has_self_loop(&graph.graph, node) {
+            removed[node.index()] = true;
+            selected.push(node);
+        }
+    }
+    let mut degree: Vec<usize> = vec![0; bound];
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if !removed[source] && !removed[target] {
+            degree[source] += 1;
+            degree[target] += 1;
+        }
+    }
+    let mut leaves: VecDeque<usize> = VecDeque::new();
+    let mut heap: BinaryHeap<(usize, Reverse<usize>)> = BinaryHeap::new();
+    for node in graph.graph.node_indices() {
+        let index = node.index();
+        if !removed[index] {
+            if degree[index] <= 1 {
+                leaves.push_back(index);
+            }
+            heap.push((degree[index], Reverse(index)));
+        }
+    }
+    loop {
+        let node = if let Some(leaf) = leaves.pop_front() {
+            if removed[leaf] {
+                continue;
+            }
+            leaf
+        } else if let Some((node_degree, Reverse(index))) = heap.pop() {
+            if removed[index] || node_degree != degree[index] {
+                continue;
+            }
+            selected.push(NodeIndex::new(index));
+            index
+        } else {
+            break;
+        };
+        removed[node] = true;
+        for neighbor in graph.graph.neighbors(NodeIndex::new(node)) {
+            let neighbor = neighbor.index();
+            if removed[neighbor] {
+                continue;
+            }
+            degree[neighbor] -= 1;
+            if degree[neighbor] == 1 {
+                leaves.push_back(neighbor);
+            }
+            heap.push((degree[neighbor], Reverse(neighbor)));
+        }
+    }
+    selected
+}
+
+/// Greedily pick the nodes of a feedback vertex set of a directed graph.
+///
+/// Nodes without remaining incoming or outgoing edges are in no cycle and
+/// are pruned, when there are none left the node with the largest product
+/// of its remaining in and out degrees is added to the set. Returns the set
+/// in the order the nodes were added.
+fn directed_candidates(graph: &digraph::PyDiGraph) -> Vec<NodeIndex> {
+    let bound = graph.graph.node_bound();
+    let mut removed = vec![false; bound];
+    let mut selected: Vec<NodeIndex> = Vec::new();
+    for node in graph.graph.node_indices() {
+        if has_self_loop(&graph.graph, node) {
+            removed[node.index()] = true;
+            selected.push(node);
+        }
+    }
+    let mut in_degree: Vec<usize> = vec![0; bound];
+    let mut out_degree: Vec<usize> = vec![0; bound];
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if !removed[source] && !removed[target] {
+            out_degree[source] += 1;
+            in_degree[target] += 1;
+        }
+    }
+    let mut pruned: VecDeque<usize> = VecDeque::new();
+    let mut heap: BinaryHeap<(usize, Reverse<usize>)> = BinaryHeap::new();
+    for node in graph.graph.node_indices() {
+        let index = node.index();
+        if !removed[index] {
+            if in_degree[index] == 0 || out_degree[index] == 0 {
+                pruned.push_back(index);
+            }
+            heap.push((in_degree[index] * out_degree[index], Reverse(index)));
+        }
+    }
+    loop {
+        let node = if let Some(node) = pruned.pop_front() {
+            if removed[node] {
+                continue;
+            }
+            node
+        } else if let Some((score, Reverse(index))) = heap.pop() {
+            if removed[index] || score != in_degree[index] * out_degree[index] {
+                continue;
+            }
+            selected.push(NodeIndex::new(index));
+            index
+        } else {
+            break;
+        };
+        removed[node] = true;
+        for edge in graph.graph.edges_directed(NodeIndex::new(node), Outgoing) {
+            let target = edge.target().index();
+            if removed[target] {
+                continue;
+            }
+            in_degree[target] -= 1;
+            if in_degree[target] == 0 {
+                pruned.push_back(target);
+            }
+            heap.push((in_degree[target] * out_degree[target], Reverse(target)));
+        }
+        for edge in graph.graph.edges_directed(NodeIndex::new(node), Incoming) {
+            let source = edge.source().index();
+            if removed[source] {
+                continue;
+            }
+            out_degree[source] -= 1;
+            if out_degree[source] == 0 {
+                pruned.push_back(source);
+            }
+            heap.push((in_degree[source] * out_degree[source], Reverse(source)));
+        }
+    }
+    selected
+}
+
+/// Find a small feedback vertex set of an undirected graph
+///
+/// A feedback vertex set is a set of nodes whose removal, along with their
+/// edges, leaves the graph without cycles. Finding a minimum feedback vertex
+/// set is NP-hard, so this function uses a greedy heuristic. Nodes with at
+/// most one edge are repeatedly pruned since they can't be in a cycle, and
+/// when none are left the node with the most edges is added to the set.
+/// Finally every node of the set which can be put back into the remaining
+/// forest without creating a cycle is removed from the set, so the set
+/// returned is minimal, but not necessarily minimum.
+///
+/// Nodes with a self loop are always in the set, and two parallel edges form
+/// a cycle.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   print(retworkx.graph_feedback_vertex_set(graph))
+///
+/// :param PyGraph graph: The graph to find a feedback vertex set of
+///
+/// :returns: A set of the indices of the nodes in the feedback vertex set
+/// :rtype: set
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_feedback_vertex_set(graph: &graph::PyGraph) -> HashSet<usize> {
+    let candidates = undirected_candidates(graph);
+    let mut in_set = vec![false; graph.graph.node_bound()];
+    for node in &candidates {
+        in_set[node.index()] = true;
+    }
+    let mut forest: UnionFind<usize> = UnionFind::new(graph.graph.node_bound());
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if !in_set[source] && !in_set[target] {
+            forest.union(source, target);
+        }
+    }
+    // Put nodes back, most recently added first, as long as their edges
+    // into the forest all go to different trees
+    for node in candidates.iter().rev() {
+        if has_self_loop(&graph.graph, *node) {
+            continue;
+        }
+        let mut trees: HashSet<usize> = HashSet::new();
+        let mut joins_trees = true;
+        for neighbor in graph.graph.neighbors(*node) {
+            if !in_set[neighbor.index()] && !trees.insert(forest.find_mut(neighbor.index())) {
+                joins_trees = false;
+                break;
+            }
+        }
+        if joins_trees {
+            in_set[node.index()] = false;
+            for tree in trees {
+                forest.union(node.index(), tree);
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .map(|node| node.index())
+        .filter(|node| in_set[*node])
+        .collect()
+}
+
+/// Find a small feedback vertex set of a directed graph
+///
+/// A feedback vertex set is a set of nodes whose removal, along with their
+/// edges, leaves the graph without directed cycles. Finding a minimum
+/// feedback vertex set is NP-hard, so this function uses a greedy
+/// heuristic. Nodes without incoming or outgoing edges are repeatedly pruned
+/// since they can't be in a cycle, and when none are left the node with the
+/// largest product of its in and out degree is added to the set. Finally
+/// every node of the set which can be put back into the remaining graph
+/// without creating a cycle is removed from the set, so the set returned is
+/// minimal, but not necessarily minimum.
+///
+/// Nodes with a self loop are always in the set.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_cycle_graph(4, bidirectional=True)
+///   print(retworkx.digraph_feedback_vertex_set(graph))
+///
+/// :param PyDiGraph graph: The directed graph to find a feedback vertex set
+///     of
+///
+/// :returns: A set of the indices of the nodes in the feedback vertex set
+/// :rtype: set
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_feedback_vertex_set(graph: &digraph::PyDiGraph) -> HashSet<usize> {
+    let candidates = directed_candidates(graph);
+    let mut in_set = vec![false; graph.graph.node_bound()];
+    for node in &candidates {
+        in_set[node.index()] = true;
+    }
+    // Put nodes back, most recently added first, as long as they can't
+    // reach themselves through the nodes outside of the set
+    let mut seen = vec![false; graph.graph.node_bound()];
+    for node in candidates.iter().rev() {
+        if has_self_loop(&graph.graph, *node) {
+            continue;
+        }
+        let mut visited: Vec<usize> = Vec::new();
+        let mut stack: Vec<NodeIndex> = vec![*node];
+        let mut on_cycle = false;
+        while let Some(current) = stack.pop() {
+            for next in graph.graph.neighbors_directed(current, Outgoing) {
+                if next == *node {
+                    on_cycle = true;
+                    break;
+                }
+                if !in_set[next.index()] && !seen[next.index()] {
+                    seen[next.index()] = true;
+                    visited.push(next.index());
+                    stack.push(next);
+                }
+            }
+            if on_cycle {
+                break;
+            }
+        }
+        for index in visited {
+            seen[index] = false;
+        }
+        if !on_cycle {
+            in_set[node.index()] = false;
+        }
+    }
+    candidates
+        .into_iter()
+        .map(|node| node.index())
+        .filter(|node| in_set[*node])
+        .collect()
+}
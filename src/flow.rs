@@ -0,0 +1,164 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::iterators::{EdgeFlowMapping, EdgeIndices, NodeIndices};
+use crate::{core_error, digraph, CostFn, InvalidNode, NodeIndex};
+
+use petgraph::visit::EdgeRef;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::flow;
+
+/// Check that ``source`` and ``sink`` are distinct nodes in ``graph``.
+fn check_terminals(
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    sink: usize,
+) -> PyResult<(NodeIndex, NodeIndex)> {
+    let source = NodeIndex::new(source);
+    let sink = NodeIndex::new(sink);
+    if !graph.graph.contains_node(source) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'source' is not a valid node index",
+        ));
+    }
+    if !graph.graph.contains_node(sink) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'sink' is not a valid node index",
+        ));
+    }
+    if source == sink {
+        return Err(PyValueError::new_err(
+            "source and sink must be different nodes",
+        ));
+    }
+    Ok((source, sink))
+}
+
+/// Compute the maximum flow between two nodes of a :class:`~retworkx.PyDiGraph`
+///
+/// Each edge can carry at most the flow given by its capacity in the
+/// direction of the edge. The maximum flow is computed with Dinic's algorithm
+/// which runs in :math:`O(|V|^2 |E|)` time.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int source: The node index the flow leaves from
+/// :param int sink: The node index the flow arrives at
+/// :param capacity_fn: An optional callable which takes a single positional
+///     argument, an edge's weight/data payload, and returns a float
+///     representing the capacity of that edge. It must be non-negative. If
+///     not specified ``default_capacity`` is used for every edge.
+/// :param float default_capacity: The capacity to use for every edge if
+///     ``capacity_fn`` is not specified. By default this is ``1.0``.
+///
+/// :returns: A tuple of the value of the maximum flow and a mapping of every
+///     edge index in the graph to the flow along that edge.
+/// :rtype: tuple
+/// :raises InvalidNode: When an invalid node index is provided.
+/// :raises ValueError: When ``source`` and ``sink`` are the same node or
+///     ``capacity_fn`` returns a NaN or negative value.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_weighted_edge_list(
+///       [(0, 1, 4), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)]
+///   )
+///   value, flows = retworkx.digraph_maximum_flow(
+///       graph, 0, 3, capacity_fn=float
+///   )
+///   print(value)
+///   print(flows)
+#[pyfunction(default_capacity = "1.0")]
+#[pyo3(text_signature = "(graph, source, sink, /, capacity_fn=None, default_capacity=1.0)")]
+pub fn digraph_maximum_flow(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    sink: usize,
+    capacity_fn: Option<PyObject>,
+    default_capacity: f64,
+) -> PyResult<(f64, EdgeFlowMapping)> {
+    let (source, sink) = check_terminals(graph, source, sink)?;
+    let cost_fn = CostFn::try_from((capacity_fn, default_capacity))?.prepare(py, &graph.graph)?;
+    let (value, flows) = flow::max_flow(&graph.graph, source, sink, |e| {
+        cost_fn.call_edge(py, e.id(), e.weight())
+    })
+    .map_err(core_error)?;
+    Ok((
+        value,
+        EdgeFlowMapping {
+            flows: flows
+                .into_iter()
+                .map(|(edge, flow)| (edge.index(), flow))
+                .collect(),
+        },
+    ))
+}
+
+/// Compute a minimum cut between two nodes of a :class:`~retworkx.PyDiGraph`
+///
+/// The cut partitions the nodes into a set containing ``source`` and a set
+/// containing ``sink``, its value is the sum of the capacities of the edges
+/// from the source side to the sink side. The cut is found from the maximum
+/// flow computed by :func:`~retworkx.digraph_maximum_flow`, the source side
+/// is the set of nodes reachable from ``source`` in the residual graph.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int source: The node index on the source side of the cut
+/// :param int sink: The node index on the sink side of the cut
+/// :param capacity_fn: An optional callable which takes a single positional
+///     argument, an edge's weight/data payload, and returns a float
+///     representing the capacity of that edge. It must be non-negative. If
+///     not specified ``default_capacity`` is used for every edge.
+/// :param float default_capacity: The capacity to use for every edge if
+///     ``capacity_fn`` is not specified. By default this is ``1.0``.
+///
+/// :returns: A tuple of the value of the cut, the node indices on the source
+///     side of the cut, and the edge indices of the edges crossing the cut.
+/// :rtype: tuple
+/// :raises InvalidNode: When an invalid node index is provided.
+/// :raises ValueError: When ``source`` and ``sink`` are the same node or
+///     ``capacity_fn`` returns a NaN or negative value.
+#[pyfunction(default_capacity = "1.0")]
+#[pyo3(text_signature = "(graph, source, sink, /, capacity_fn=None, default_capacity=1.0)")]
+pub fn digraph_minimum_cut(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    sink: usize,
+    capacity_fn: Option<PyObject>,
+    default_capacity: f64,
+) -> PyResult<(f64, NodeIndices, EdgeIndices)> {
+    let (source, sink) = check_terminals(graph, source, sink)?;
+    let cost_fn = CostFn::try_from((capacity_fn, default_capacity))?.prepare(py, &graph.graph)?;
+    let (value, source_side, cut_edges) = flow::min_cut(&graph.graph, source, sink, |e| {
+        cost_fn.call_edge(py, e.id(), e.weight())
+    })
+    .map_err(core_error)?;
+    Ok((
+        value,
+        NodeIndices {
+            nodes: source_side.into_iter().map(|node| node.index()).collect(),
+        },
+        EdgeIndices {
+            edges: cut_edges.into_iter().map(|edge| edge.index()).collect(),
+        },
+    ))
+}
@@ -14,7 +14,6 @@ use std::collections::VecDeque;
 use std::iter;
 
 use petgraph::algo;
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
 use petgraph::Undirected;
@@ -24,7 +23,10 @@ use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 use pyo3::Python;
 
-use super::{digraph, graph, StablePyGraph};
+use super::clusters::Clusters;
+use super::key_index::KeyIndexes;
+use super::transaction::MutationLog;
+use super::{digraph, graph, GraphIx, NodeIndex, StablePyGraph};
 
 pub fn pairwise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, I::Item)>
 where
@@ -112,11 +114,14 @@ pub fn directed_cycle_graph(
         graph.add_edge(first_node_index, last_node_index, py.None());
     }
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -187,9 +192,12 @@ pub fn cycle_graph(
     let first_node_index = NodeIndex::new(0);
     graph.add_edge(last_node_index, first_node_index, py.None());
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -260,11 +268,14 @@ pub fn directed_path_graph(
         };
     }
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -327,9 +338,12 @@ pub fn path_graph(
         };
     }
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -414,11 +428,14 @@ pub fn directed_star_graph(
         }
     }
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -478,9 +495,12 @@ pub fn star_graph(
         graph.add_edge(nodes[0], *node, py.None());
     }
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -543,9 +563,12 @@ pub fn mesh_graph(
         }
     }
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -608,11 +631,14 @@ pub fn directed_mesh_graph(
         }
     }
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -711,9 +737,12 @@ pub fn grid_graph(
         }
     }
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -829,11 +858,14 @@ pub fn directed_grid_graph(
         }
     }
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -924,9 +956,12 @@ pub fn binomial_tree_graph(
     }
 
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1002,9 +1037,12 @@ pub fn full_rary_tree(
     }
 
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1108,11 +1146,14 @@ pub fn directed_binomial_tree_graph(
     }
 
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1191,9 +1232,12 @@ pub fn heavy_square_graph(py: Python, d: usize, multigraph: bool) -> PyResult<gr
     if d == 1 {
         graph.add_node(py.None());
         return Ok(graph::PyGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         });
     }
 
@@ -1254,9 +1298,12 @@ pub fn heavy_square_graph(py: Python, d: usize, multigraph: bool) -> PyResult<gr
     }
 
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1337,11 +1384,14 @@ pub fn directed_heavy_square_graph(
     if d == 1 {
         graph.add_node(py.None());
         return Ok(digraph::PyDiGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             check_cycle: false,
             cycle_state: algo::DfsSpace::default(),
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         });
     }
 
@@ -1430,11 +1480,14 @@ pub fn directed_heavy_square_graph(
     }
 
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1521,9 +1574,12 @@ pub fn heavy_hex_graph(py: Python, d: usize, multigraph: bool) -> PyResult<graph
     if d == 1 {
         graph.add_node(py.None());
         return Ok(graph::PyGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         });
     }
 
@@ -1592,9 +1648,12 @@ pub fn heavy_hex_graph(py: Python, d: usize, multigraph: bool) -> PyResult<graph
     }
 
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1686,11 +1745,14 @@ pub fn directed_heavy_hex_graph(
     if d == 1 {
         graph.add_node(py.None());
         return Ok(digraph::PyDiGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             check_cycle: false,
             cycle_state: algo::DfsSpace::default(),
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         });
     }
 
@@ -1795,11 +1857,14 @@ pub fn directed_heavy_hex_graph(
     }
 
     Ok(digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -1838,9 +1903,12 @@ pub fn hexagonal_lattice_graph(
 
     if rows == 0 || cols == 0 {
         return graph::PyGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         };
     }
 
@@ -1903,9 +1971,12 @@ pub fn hexagonal_lattice_graph(
     }
 
     graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     }
 }
 
@@ -1948,11 +2019,14 @@ pub fn directed_hexagonal_lattice_graph(
 
     if rows == 0 || cols == 0 {
         return digraph::PyDiGraph {
-            graph,
+            graph: graph.into(),
             node_removed: false,
             check_cycle: false,
             cycle_state: algo::DfsSpace::default(),
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         };
     }
 
@@ -2045,11 +2119,14 @@ pub fn directed_hexagonal_lattice_graph(
     }
 
     digraph::PyDiGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         check_cycle: false,
         cycle_state: algo::DfsSpace::default(),
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     }
 }
 
@@ -2219,9 +2296,12 @@ pub fn generalized_petersen_graph(
     }
 
     Ok(graph::PyGraph {
-        graph,
+        graph: graph.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -2268,7 +2348,7 @@ pub fn barbell_graph(
         return Err(PyIndexError::new_err("num_mesh_nodes not specified"));
     }
 
-    let mut left_mesh = StableUnGraph::<PyObject, PyObject>::default();
+    let mut left_mesh = StableUnGraph::<PyObject, PyObject, GraphIx>::default();
     let mesh_nodes: Vec<NodeIndex> = (0..num_mesh_nodes.unwrap())
         .map(|_| left_mesh.add_node(py.None()))
         .collect();
@@ -2318,9 +2398,12 @@ pub fn barbell_graph(
     }
 
     Ok(graph::PyGraph {
-        graph: left_mesh,
+        graph: left_mesh.into(),
         node_removed: false,
         multigraph,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -11,7 +11,7 @@
 // under the License.
 
 use std::cmp;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
@@ -21,7 +21,7 @@ use hashbrown::{HashMap, HashSet};
 use indexmap::IndexSet;
 use retworkx_core::dictmap::*;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyValueError};
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PyList, PyLong, PyString, PyTuple};
@@ -31,20 +31,29 @@ use pyo3::Python;
 use ndarray::prelude::*;
 use num_complex::Complex64;
 use num_traits::Zero;
-use numpy::PyReadonlyArray2;
+use numpy::{IntoPyArray, PyReadonlyArray2};
 
+use super::clusters::{cluster_members, quotient_graph, Clusters};
+use super::csr::CSRSnapshot;
 use super::dot_utils::build_dot;
-use super::iterators::{EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use super::iterators::{
+    EdgeIndexMap, EdgeIndices, EdgeList, EdgeMap, NodeIndices, NodeMap, WeightedEdgeList,
+};
+use super::key_index::{not_indexed, KeyIndex, KeyIndexes};
+use super::property::PropertyArray;
+use super::shared_graph::SharedGraph;
+use super::transaction::{GraphTransaction, MutationLog};
 use super::{
-    find_node_by_weight, merge_duplicates, weight_callable, IsNan, NoEdgeBetweenNodes,
-    NodesRemoved, StablePyGraph,
+    adjacency_row, compact_graph, degree_histogram, edges_from_arrays, find_node_by_weight,
+    merge_duplicates, parallel_edge_groups, self_loop_edges, weight_callable, EdgeIndex, IsNan,
+    NoEdgeBetweenNodes, NodeIndex, NodesRemoved, StablePyGraph,
 };
 
 use petgraph::algo;
-use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
 use petgraph::visit::{
-    GraphBase, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeFiltered, NodeIndexable,
+    EdgeIndexable, GraphBase, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeFiltered,
+    NodeIndexable,
 };
 
 /// A class for creating undirected graphs
@@ -117,9 +126,12 @@ use petgraph::visit::{
 #[pyo3(text_signature = "(/, multigraph=True)")]
 #[derive(Clone)]
 pub struct PyGraph {
-    pub graph: StablePyGraph<Undirected>,
+    pub graph: SharedGraph<Undirected>,
     pub node_removed: bool,
     pub multigraph: bool,
+    pub log: MutationLog<Undirected>,
+    pub clusters: Clusters,
+    pub indexes: KeyIndexes,
 }
 
 impl GraphBase for PyGraph {
@@ -139,15 +151,29 @@ impl NodeCount for PyGraph {
     }
 }
 
+// Rust side only PyGraph methods
+impl PyGraph {
+    /// Undo the changes made since the innermost open transaction was opened
+    pub fn rollback_transaction(&mut self, py: Python) {
+        self.log.rollback(py, &mut self.graph, &mut self.clusters);
+        if self.graph.node_bound() != self.graph.node_count() {
+            self.node_removed = true;
+        }
+    }
+}
+
 #[pymethods]
 impl PyGraph {
     #[new]
     #[args(multigraph = "true")]
     fn new(multigraph: bool) -> Self {
         PyGraph {
-            graph: StablePyGraph::<Undirected>::default(),
+            graph: StablePyGraph::<Undirected>::default().into(),
             node_removed: false,
             multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         }
     }
 
@@ -158,6 +184,7 @@ impl PyGraph {
         out_dict.set_item("nodes", node_dict)?;
         out_dict.set_item("nodes_removed", self.node_removed)?;
         out_dict.set_item("multigraph", self.multigraph)?;
+        out_dict.set_item("clusters", self.clusters.to_object(py))?;
         for node_index in self.graph.node_indices() {
             let node_data = self.graph.node_weight(node_index).unwrap();
             node_dict.set_item(node_index.index(), node_data)?;
@@ -175,7 +202,7 @@ impl PyGraph {
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
-        self.graph = StablePyGraph::<Undirected>::default();
+        self.graph = StablePyGraph::<Undirected>::default().into();
         let dict_state = state.cast_as::<PyDict>(py)?;
         let nodes_dict = dict_state.get_item("nodes").unwrap().downcast::<PyDict>()?;
         let edges_list = dict_state.get_item("edges").unwrap().downcast::<PyList>()?;
@@ -189,6 +216,12 @@ impl PyGraph {
             .unwrap()
             .downcast::<PyBool>()?;
         self.multigraph = multigraph_raw.extract()?;
+        self.clusters = match dict_state.get_item("clusters") {
+            Some(raw_clusters) => raw_clusters.extract()?,
+            None => Clusters::default(),
+        };
+        self.log.node_changes.touch_all();
+        self.log.edge_changes.touch_all();
 
         let mut node_indices: Vec<usize> = Vec::new();
         for raw_index in nodes_dict.keys() {
@@ -265,6 +298,173 @@ impl PyGraph {
         false
     }
 
+    /// Return the number of self loops in the graph
+    ///
+    /// A self loop is an edge whose source and target are the same node.
+    ///
+    /// :returns: The number of self loop edges
+    /// :rtype: int
+    #[pyo3(text_signature = "(self)")]
+    pub fn number_of_selfloops(&self) -> usize {
+        self.graph
+            .edge_references()
+            .filter(|edge| edge.source() == edge.target())
+            .count()
+    }
+
+    /// Return the self loops in the graph
+    ///
+    /// :returns: The edge indices of the self loop edges in increasing order.
+    ///     The endpoints of each edge can be looked up with
+    ///     :meth:`~PyGraph.get_edge_endpoints_by_index`.
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn selfloop_edges(&self) -> EdgeIndices {
+        EdgeIndices {
+            edges: self_loop_edges(&self.graph)
+                .into_iter()
+                .map(|edge| edge.index())
+                .collect(),
+        }
+    }
+
+    /// Return the groups of parallel edges in the graph
+    ///
+    /// :returns: A list of lists of edge indices, one list for each pair of
+    ///     nodes with more than one edge between them. The groups are ordered
+    ///     by their lowest edge index and the edge indices in each group are
+    ///     in increasing order.
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn parallel_edge_groups(&self) -> Vec<Vec<usize>> {
+        parallel_edge_groups(&self.graph)
+            .into_iter()
+            .filter(|(_, edges)| edges.len() > 1)
+            .map(|(_, edges)| edges.into_iter().map(|edge| edge.index()).collect())
+            .collect()
+    }
+
+    /// Return the number of edges between each pair of adjacent nodes
+    ///
+    /// :returns: A dictionary where the keys are tuples of node indices
+    ///     ``(source, target)`` and the values are the number of edges
+    ///     between them. The lower node index is always the
+    ///     first element of the key.
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_multiplicities(&self) -> DictMap<(usize, usize), usize> {
+        parallel_edge_groups(&self.graph)
+            .into_iter()
+            .map(|(endpoints, edges)| (endpoints, edges.len()))
+            .collect()
+    }
+
+    /// Merge all the parallel edges in the graph into a single edge
+    ///
+    /// For each group of parallel edges (see
+    /// :meth:`~PyGraph.parallel_edge_groups`) the edge with the lowest index
+    /// is kept and the other edges are removed from the graph. After this
+    /// method is called :meth:`~PyGraph.has_parallel_edges` will return
+    /// ``False``.
+    ///
+    /// :param merge_fn: An optional callable which takes two positional
+    ///     arguments, the weight/data payloads of two parallel edges, and
+    ///     returns a new weight/data payload for the merged edge. It's
+    ///     called on the kept edge's payload and each removed edge's payload
+    ///     in edge index order, similar to :func:`functools.reduce`. If not
+    ///     specified the weight/data payload of the kept edge is unchanged.
+    #[pyo3(text_signature = "(self, /, merge_fn=None)")]
+    pub fn merge_parallel_edges(&mut self, py: Python, merge_fn: Option<PyObject>) -> PyResult<()> {
+        self.remove_parallel_edges(py, merge_fn)?;
+        Ok(())
+    }
+
+    /// Remove all the parallel edges from the graph
+    ///
+    /// This works like :meth:`~PyGraph.merge_parallel_edges`, for each group of
+    /// parallel edges the edge with the lowest index is kept and the other
+    /// edges are removed, except that the removed edge indices are returned.
+    ///
+    /// :param combiner: An optional callable which takes two positional
+    ///     arguments, the weight/data payloads of two parallel edges, and
+    ///     returns a new weight/data payload for the kept edge. It's called
+    ///     the same way as ``merge_fn`` for
+    ///     :meth:`~PyGraph.merge_parallel_edges`. If not specified the
+    ///     weight/data payload of the kept edge is unchanged.
+    ///
+    /// :returns: The indices of the removed edges in increasing order
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self, /, combiner=None)")]
+    pub fn remove_parallel_edges(
+        &mut self,
+        py: Python,
+        combiner: Option<PyObject>,
+    ) -> PyResult<EdgeIndices> {
+        let mut removed: Vec<usize> = Vec::new();
+        for (_, edges) in parallel_edge_groups(&self.graph) {
+            if edges.len() < 2 {
+                continue;
+            }
+            let kept = edges[0];
+            if let Some(combiner) = &combiner {
+                let mut weight = self.graph[kept].clone_ref(py);
+                for edge in &edges[1..] {
+                    weight = combiner.call1(py, (weight, &self.graph[*edge]))?;
+                }
+                self.log.set_edge_weight(&mut self.graph, kept, weight);
+            }
+            for edge in &edges[1..] {
+                self.log.remove_edge(&mut self.graph, *edge);
+                removed.push(edge.index());
+            }
+        }
+        removed.sort_unstable();
+        Ok(EdgeIndices { edges: removed })
+    }
+
+    /// Remove all the self loops from the graph
+    ///
+    /// A self loop is an edge whose source and target are the same node.
+    ///
+    /// :returns: The indices of the removed edges in increasing order
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn remove_self_loops(&mut self) -> EdgeIndices {
+        let self_loops = self_loop_edges(&self.graph);
+        for edge in &self_loops {
+            self.log.remove_edge(&mut self.graph, *edge);
+        }
+        EdgeIndices {
+            edges: self_loops.into_iter().map(|edge| edge.index()).collect(),
+        }
+    }
+
+    /// Remove all the isolated nodes from the graph
+    ///
+    /// An isolated node is a node without any edges. A node whose only edges
+    /// are self loops isn't isolated.
+    ///
+    /// :returns: The indices of the removed nodes in increasing order
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn remove_isolates(&mut self) -> NodeIndices {
+        let isolates: Vec<NodeIndex> = self
+            .graph
+            .node_indices()
+            .filter(|node| self.graph.neighbors_undirected(*node).next().is_none())
+            .collect();
+        for node in &isolates {
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, *node);
+        }
+        if !isolates.is_empty() {
+            self.node_removed = true;
+        }
+        NodeIndices {
+            nodes: isolates.into_iter().map(|node| node.index()).collect(),
+        }
+    }
+
     /// Return the number of nodes in the graph
     #[pyo3(text_signature = "(self)")]
     pub fn num_nodes(&self) -> usize {
@@ -412,6 +612,21 @@ impl PyGraph {
     ///     identify that the neighbor node is the second element in the
     ///     tuple for a given edge index.
     /// :rtype: EdgeIndexMap
+    ///
+    /// This can be used to iterate over the neighbors of a node together with
+    /// the index and data of the edge connecting them, without any separate
+    /// calls to :meth:`~PyGraph.neighbors` and :meth:`~PyGraph.get_edge_data`.
+    /// Unlike :meth:`~PyGraph.adj` each parallel edge is included. For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.PyGraph()
+    ///   graph.add_nodes_from(["a", "b", "c"])
+    ///   graph.add_edges_from([(0, 1, 1.5), (0, 2, 2.5), (1, 0, 3.5)])
+    ///   for edge_index, (_, neighbor, weight) in graph.incident_edge_index_map(0).items():
+    ///       print(neighbor, edge_index, weight)
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn incident_edge_index_map(&self, py: Python, node: usize) -> EdgeIndexMap {
         let node_index = NodeIndex::new(node);
@@ -541,8 +756,7 @@ impl PyGraph {
             Some(edge_index) => edge_index,
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
-        let data = self.graph.edge_weight_mut(edge_index).unwrap();
-        *data = edge;
+        self.log.set_edge_weight(&mut self.graph, edge_index, edge);
         Ok(())
     }
 
@@ -555,11 +769,13 @@ impl PyGraph {
     ///     index
     #[pyo3(text_signature = "(self, edge_index, edge, /)")]
     pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
-        match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
-            Some(data) => *data = edge,
-            None => return Err(PyIndexError::new_err("No edge found for index")),
-        };
-        Ok(())
+        match self
+            .log
+            .set_edge_weight(&mut self.graph, EdgeIndex::new(edge_index), edge)
+        {
+            Some(_) => Ok(()),
+            None => Err(PyIndexError::new_err("No edge found for index")),
+        }
     }
 
     /// Return the node data for a given node index
@@ -682,7 +898,8 @@ impl PyGraph {
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
         let index = NodeIndex::new(node);
-        self.graph.remove_node(index);
+        self.log
+            .remove_node(&mut self.graph, &mut self.clusters, index);
         self.node_removed = true;
         Ok(())
     }
@@ -708,12 +925,11 @@ impl PyGraph {
         if !self.multigraph {
             let exists = self.graph.find_edge(p_index, c_index);
             if let Some(index) = exists {
-                let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                *edge_weight = edge;
+                self.log.set_edge_weight(&mut self.graph, index, edge);
                 return Ok(index.index());
             }
         }
-        let edge = self.graph.add_edge(p_index, c_index, edge);
+        let edge = self.log.add_edge(&mut self.graph, p_index, c_index, edge);
         Ok(edge.index())
     }
 
@@ -744,13 +960,12 @@ impl PyGraph {
             if !self.multigraph {
                 let exists = self.graph.find_edge(p_index, c_index);
                 if let Some(index) = exists {
-                    let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                    *edge_weight = obj.2;
+                    self.log.set_edge_weight(&mut self.graph, index, obj.2);
                     out_list.push(index.index());
                     continue;
                 }
             }
-            let edge = self.graph.add_edge(p_index, c_index, obj.2);
+            let edge = self.log.add_edge(&mut self.graph, p_index, c_index, obj.2);
             out_list.push(edge.index());
         }
         Ok(out_list)
@@ -783,18 +998,82 @@ impl PyGraph {
             if !self.multigraph {
                 let exists = self.graph.find_edge(p_index, c_index);
                 if let Some(index) = exists {
-                    let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                    *edge_weight = py.None();
+                    self.log.set_edge_weight(&mut self.graph, index, py.None());
                     out_list.push(index.index());
                     continue;
                 }
             }
-            let edge = self.graph.add_edge(p_index, c_index, py.None());
+            let edge = self
+                .log
+                .add_edge(&mut self.graph, p_index, c_index, py.None());
             out_list.push(edge.index());
         }
         Ok(out_list)
     }
 
+    /// Add new edges to the graph from arrays of node indices.
+    ///
+    /// This method is functionally equivalent to :meth:`add_edges_from` but
+    /// the edges are described by two 1 dimensional numpy integer arrays
+    /// of equal length, where the edge ``i`` is between ``sources[i]`` and
+    /// ``targets[i]``. This avoids creating a Python tuple for every edge and
+    /// is significantly faster when building large graphs, for example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import numpy as np
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.PyGraph()
+    ///     graph.add_nodes_from(range(4))
+    ///     sources = np.array([0, 1, 2])
+    ///     targets = np.array([1, 2, 3])
+    ///     graph.add_edges_from_array(sources, targets, np.array([0.5, 1.5, 2.5]))
+    ///     print(graph.weighted_edge_list())
+    ///
+    /// If :attr:`~retworkx.PyGraph.multigraph` is ``False`` and an edge already
+    /// exists between ``sources[i]`` and ``targets[i]`` the weight/payload of
+    /// that existing edge will be updated instead.
+    ///
+    /// :param numpy.ndarray sources: A 1 dimensional integer array of node
+    ///     indices for the first endpoint of each edge
+    /// :param numpy.ndarray targets: A 1 dimensional integer array of node
+    ///     indices for the second endpoint of each edge
+    /// :param weights: An optional sequence (or numpy array) the same length
+    ///     as ``sources`` with the weight/data payload for each edge. A
+    ///     ``float64`` numpy array will have its elements converted to Python
+    ///     floats. If not specified ``None`` is used for every edge.
+    ///
+    /// :returns: The edge indices of the newly created (or updated) edges
+    /// :rtype: EdgeIndices
+    /// :raises IndexError: If any of the node indices are not present in the
+    ///     graph
+    /// :raises ValueError: If the input arrays are not the same length
+    #[pyo3(text_signature = "(self, sources, targets, /, weights=None)")]
+    pub fn add_edges_from_array(
+        &mut self,
+        py: Python,
+        sources: &PyAny,
+        targets: &PyAny,
+        weights: Option<&PyAny>,
+    ) -> PyResult<EdgeIndices> {
+        let new_edges = edges_from_arrays(py, &self.graph, sources, targets, weights)?;
+        let mut out_list: Vec<usize> = Vec::with_capacity(new_edges.len());
+        for (p_index, c_index, weight) in new_edges {
+            if !self.multigraph {
+                let exists = self.graph.find_edge(p_index, c_index);
+                if let Some(index) = exists {
+                    self.log.set_edge_weight(&mut self.graph, index, weight);
+                    out_list.push(index.index());
+                    continue;
+                }
+            }
+            let edge = self.log.add_edge(&mut self.graph, p_index, c_index, weight);
+            out_list.push(edge.index());
+        }
+        Ok(EdgeIndices { edges: out_list })
+    }
+
     /// Extend graph from an edge list
     ///
     /// This method differs from :meth:`add_edges_from_no_data` in that it will
@@ -813,19 +1092,19 @@ impl PyGraph {
         for (source, target) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+                self.log.add_node(&mut self.graph, py.None());
             }
             let source_index = NodeIndex::new(source);
             let target_index = NodeIndex::new(target);
             if !self.multigraph {
                 let exists = self.graph.find_edge(source_index, target_index);
                 if let Some(index) = exists {
-                    let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                    *edge_weight = py.None();
+                    self.log.set_edge_weight(&mut self.graph, index, py.None());
                     continue;
                 }
             }
-            self.graph.add_edge(source_index, target_index, py.None());
+            self.log
+                .add_edge(&mut self.graph, source_index, target_index, py.None());
         }
     }
 
@@ -853,19 +1132,19 @@ impl PyGraph {
         for (source, target, weight) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
-                self.graph.add_node(py.None());
+                self.log.add_node(&mut self.graph, py.None());
             }
             let source_index = NodeIndex::new(source);
             let target_index = NodeIndex::new(target);
             if !self.multigraph {
                 let exists = self.graph.find_edge(source_index, target_index);
                 if let Some(index) = exists {
-                    let edge_weight = self.graph.edge_weight_mut(index).unwrap();
-                    *edge_weight = weight;
+                    self.log.set_edge_weight(&mut self.graph, index, weight);
                     continue;
                 }
             }
-            self.graph.add_edge(source_index, target_index, weight);
+            self.log
+                .add_edge(&mut self.graph, source_index, target_index, weight);
         }
     }
 
@@ -887,7 +1166,7 @@ impl PyGraph {
             Some(edge_index) => edge_index,
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
-        self.graph.remove_edge(edge_index);
+        self.log.remove_edge(&mut self.graph, edge_index);
         Ok(())
     }
 
@@ -897,7 +1176,7 @@ impl PyGraph {
     #[pyo3(text_signature = "(self, edge, /)")]
     pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
         let edge_index = EdgeIndex::new(edge);
-        self.graph.remove_edge(edge_index);
+        self.log.remove_edge(&mut self.graph, edge_index);
         Ok(())
     }
 
@@ -921,7 +1200,7 @@ impl PyGraph {
                 Some(edge_index) => edge_index,
                 None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
             };
-            self.graph.remove_edge(edge_index);
+            self.log.remove_edge(&mut self.graph, edge_index);
         }
         Ok(())
     }
@@ -934,7 +1213,7 @@ impl PyGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
     pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
-        let index = self.graph.add_node(obj);
+        let index = self.log.add_node(&mut self.graph, obj);
         Ok(index.index())
     }
 
@@ -948,7 +1227,7 @@ impl PyGraph {
     pub fn add_nodes_from(&mut self, obj_list: Vec<PyObject>) -> NodeIndices {
         let out_list: Vec<usize> = obj_list
             .into_iter()
-            .map(|obj| self.graph.add_node(obj).index())
+            .map(|obj| self.log.add_node(&mut self.graph, obj).index())
             .collect();
         NodeIndices { nodes: out_list }
     }
@@ -963,7 +1242,8 @@ impl PyGraph {
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_nodes_from(&mut self, index_list: Vec<usize>) -> PyResult<()> {
         for node in index_list.iter().map(|x| NodeIndex::new(*x)) {
-            self.graph.remove_node(node);
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, node);
         }
         Ok(())
     }
@@ -974,16 +1254,104 @@ impl PyGraph {
     /// indices in order. If there is more than one node in the graph with the
     /// same weight only the first match (by node index) will be returned.
     ///
+    /// If the nodes are indexed by their weight/data payloads, by calling
+    /// :meth:`~retworkx.PyGraph.index_nodes_by` without a ``key_fn``, a
+    /// hashable ``obj`` is looked up in that index in O(1) time instead.
+    ///
     /// :param obj: The weight to look for in the graph.
     ///
     /// :returns: the index of the first node in the graph that is equal to the
     ///     weight. If no match is found ``None`` will be returned.
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
-    pub fn find_node_by_weight(&self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
+    pub fn find_node_by_weight(&mut self, py: Python, obj: PyObject) -> PyResult<Option<usize>> {
+        if let Some(index) = self.indexes.nodes.as_mut() {
+            if index.by_payload() && obj.as_ref(py).hash().is_ok() {
+                let graph = &self.graph;
+                index.update(
+                    py,
+                    &mut self.log.node_changes,
+                    |node| graph.node_weight(NodeIndex::new(node)),
+                    graph
+                        .node_indices()
+                        .map(|node| (node.index(), &graph[node])),
+                )?;
+                return Ok(index.get(py, obj)?.first().copied());
+            }
+        }
         find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
     }
 
+    /// Return the indices of all nodes whose weight matches a filter function
+    ///
+    /// ``filter_function`` is called once for each node weight in the graph,
+    /// in node index order, and the index of every node it returns ``True``
+    /// for is included in the output.
+    ///
+    /// If ``batch_size`` is set ``filter_function`` is instead called with a
+    /// list of up to ``batch_size`` node weights at a time, again in node
+    /// index order, and must return a sequence of booleans of the same
+    /// length. This cuts the number of Python calls, which lets a vectorized
+    /// filter (for example one using numpy) evaluate many nodes at once.
+    ///
+    /// :param filter_function: A callable which takes a single positional
+    ///     argument, the node weight, and returns a boolean indicating
+    ///     whether the node should be included.
+    /// :param int batch_size: The number of node weights to pass to
+    ///     ``filter_function`` per call. If not set ``filter_function`` is
+    ///     called with one node weight at a time.
+    ///
+    /// :returns: A list of the node indices which match the filter function
+    /// :rtype: NodeIndices
+    ///
+    /// :raises ValueError: If ``batch_size`` is 0 or ``filter_function``
+    ///     returns the wrong number of results for a batch
+    #[pyo3(text_signature = "(self, filter_function, /, batch_size=None)")]
+    pub fn filter_nodes(
+        &self,
+        py: Python,
+        filter_function: PyObject,
+        batch_size: Option<usize>,
+    ) -> PyResult<NodeIndices> {
+        let mut nodes: Vec<usize> = Vec::new();
+        match batch_size {
+            None => {
+                for (index, weight) in self
+                    .graph
+                    .node_indices()
+                    .map(|index| (index, self.graph.node_weight(index).unwrap()))
+                {
+                    if filter_function.call1(py, (weight,))?.is_true(py)? {
+                        nodes.push(index.index());
+                    }
+                }
+            }
+            Some(0) => return Err(PyValueError::new_err("batch_size must be at least 1")),
+            Some(batch_size) => {
+                let indices: Vec<NodeIndex> = self.graph.node_indices().collect();
+                for batch in indices.chunks(batch_size) {
+                    let weights = PyList::new(py, batch.iter().map(|index| &self.graph[*index]));
+                    let res = filter_function.call1(py, (weights,))?;
+                    let mut count = 0;
+                    for matched in res.as_ref(py).iter()? {
+                        if count < batch.len() && matched?.is_true()? {
+                            nodes.push(batch[count].index());
+                        }
+                        count += 1;
+                    }
+                    if count != batch.len() {
+                        return Err(PyValueError::new_err(format!(
+                            "filter_function returned {} results for a batch of {} nodes",
+                            count,
+                            batch.len()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(NodeIndices { nodes })
+    }
+
     /// Get the index and data for the neighbors of a node.
     ///
     /// This will return a dictionary where the keys are the node indices of
@@ -1013,7 +1381,7 @@ impl PyGraph {
     ///
     /// :param int node: The index of the node to get the neighbors of
     ///
-    /// :returns: A list of the neighbor node indicies
+    /// :returns: A list of the neighbor node indices, sorted by index
     /// :rtype: NodeIndices
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn neighbors(&self, node: usize) -> NodeIndices {
@@ -1022,8 +1390,8 @@ impl PyGraph {
                 .graph
                 .neighbors(NodeIndex::new(node))
                 .map(|node| node.index())
-                .collect::<HashSet<usize>>()
-                .drain()
+                .collect::<BTreeSet<usize>>()
+                .into_iter()
                 .collect(),
         }
     }
@@ -1046,6 +1414,266 @@ impl PyGraph {
         })
     }
 
+    /// Return the degree of every node in the graph
+    ///
+    /// A self loop counts twice towards the degree of its node, the same as
+    /// for :meth:`~PyGraph.degree`.
+    ///
+    /// :returns: A 1D numpy array of the degree of each node in the graph
+    ///     ordered by node index. Use :meth:`~PyGraph.node_indices` to map
+    ///     positions in the array to node indices if any nodes have been
+    ///     removed from the graph.
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_sequence(&self, py: Python) -> PyObject {
+        let degrees: Vec<usize> = self
+            .graph
+            .node_indices()
+            .map(|node| self.degree(node.index()))
+            .collect();
+        degrees.into_pyarray(py).into()
+    }
+
+    /// Return a histogram of the degrees of the nodes in the graph
+    ///
+    /// :returns: A 1D numpy array where the value at position ``i`` is the
+    ///     number of nodes in the graph with degree ``i``. The length of the
+    ///     array is one more than the maximum degree in the graph.
+    /// :rtype: numpy.ndarray
+    #[pyo3(text_signature = "(self)")]
+    pub fn degree_histogram(&self, py: Python) -> PyObject {
+        let degrees: Vec<usize> = self
+            .graph
+            .node_indices()
+            .map(|node| self.degree(node.index()))
+            .collect();
+        degree_histogram(&degrees).into_pyarray(py).into()
+    }
+
+    /// Return the strength (weighted degree) of every node in the graph
+    ///
+    /// The strength of a node is the sum of the weights of the edges incident
+    /// to it. A self loop counts twice towards the strength of its node.
+    ///
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0`` which
+    ///     makes the strength equal to the degree.
+    ///
+    /// :returns: A 1D numpy array of the strength of each node in the graph
+    ///     ordered by node index.
+    /// :rtype: numpy.ndarray
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, /, weight_fn=None, default_weight=1.0)")]
+    pub fn strength(
+        &self,
+        py: Python,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<PyObject> {
+        let mut strengths: Vec<f64> = Vec::with_capacity(self.graph.node_count());
+        for node in self.graph.node_indices() {
+            let mut strength = 0.;
+            for edge in self.graph.edges(node) {
+                let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+                if edge.source() == edge.target() {
+                    strength += 2. * weight;
+                } else {
+                    strength += weight;
+                }
+            }
+            strengths.push(strength);
+        }
+        Ok(strengths.into_pyarray(py).into())
+    }
+
+    /// Return the strength (weighted degree) of a node
+    ///
+    /// This is the entry for ``node`` of :meth:`~PyGraph.strength` without
+    /// computing the strength of every node. A self loop counts twice towards
+    /// the strength of its node.
+    ///
+    /// :param int node: The index of the node
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0`` which
+    ///     makes the strength equal to the degree.
+    ///
+    /// :returns: The sum of the weights of the edges incident to ``node``
+    /// :rtype: float
+    ///
+    /// :raises IndexError: If ``node`` isn't in the graph
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, node, /, weight_fn=None, default_weight=1.0)")]
+    pub fn weighted_degree(
+        &self,
+        py: Python,
+        node: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<f64> {
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        let mut strength = 0.;
+        for edge in self.graph.edges(index) {
+            let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+            if edge.source() == edge.target() {
+                strength += 2. * weight;
+            } else {
+                strength += weight;
+            }
+        }
+        Ok(strength)
+    }
+
+    /// Return the row of the adjacency matrix for a node
+    ///
+    /// This is the row for ``node`` of :func:`~retworkx.adjacency_matrix`
+    /// without building the whole matrix, the entry for each neighbor is the
+    /// sum of the weights of the edges between ``node`` and the neighbor.
+    ///
+    /// :param int node: The index of the node
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. If not specified ``default_weight`` will be
+    ///     used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0``.
+    /// :param float null_value: An optional float that will be used for the
+    ///     nodes which aren't adjacent to ``node``. By default this is
+    ///     ``0.0``.
+    ///
+    /// :returns: A 1D numpy array with an entry for each node in the graph
+    ///     ordered by node index. Use :meth:`~PyGraph.node_indices` to map
+    ///     positions in the array to node indices if any nodes have been
+    ///     removed from the graph.
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises IndexError: If ``node`` isn't in the graph
+    #[args(default_weight = "1.0", null_value = "0.0")]
+    #[pyo3(text_signature = "(self, node, /, weight_fn=None, default_weight=1.0, null_value=0.0)")]
+    pub fn adjacency_row(
+        &self,
+        py: Python,
+        node: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+        null_value: f64,
+    ) -> PyResult<PyObject> {
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        let row = adjacency_row(
+            py,
+            &self.graph,
+            self.graph.edges(index).map(|edge| {
+                let neighbor = if edge.source() == index {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                (neighbor, edge.weight())
+            }),
+            &weight_fn,
+            default_weight,
+            null_value,
+        )?;
+        Ok(row.into_pyarray(py).into())
+    }
+
+    /// Create a new property array with a value for each node of the graph
+    ///
+    /// The returned :class:`~retworkx.PropertyArray` stores a boolean,
+    /// integer or float value for each node index of the graph outside of
+    /// the node data payloads, see :class:`~retworkx.PropertyArray` for
+    /// details.
+    ///
+    /// :param str dtype: The type of the values, one of ``"bool"``, ``"i64"``
+    ///     or ``"f64"``
+    /// :param default: The initial value of every node, and the value of
+    ///     nodes added later until they're written. If not specified this is
+    ///     ``False``, ``0`` or ``0.0`` depending on ``dtype``.
+    ///
+    /// :returns: A new property array with an entry for each index up to the
+    ///     largest node index of the graph
+    /// :rtype: PropertyArray
+    ///
+    /// :raises ValueError: If ``dtype`` isn't a valid type
+    #[pyo3(text_signature = "(self, dtype, /, default=None)")]
+    pub fn new_node_property(
+        &self,
+        dtype: &str,
+        default: Option<&PyAny>,
+    ) -> PyResult<PropertyArray> {
+        PropertyArray::new(self.graph.node_bound(), dtype, default)
+    }
+
+    /// Create a new property array with a value for each edge of the graph
+    ///
+    /// The returned :class:`~retworkx.PropertyArray` stores a boolean,
+    /// integer or float value for each edge index of the graph outside of
+    /// the edge data payloads, see :class:`~retworkx.PropertyArray` for
+    /// details.
+    ///
+    /// :param str dtype: The type of the values, one of ``"bool"``, ``"i64"``
+    ///     or ``"f64"``
+    /// :param default: The initial value of every edge, and the value of
+    ///     edges added later until they're written. If not specified this is
+    ///     ``False``, ``0`` or ``0.0`` depending on ``dtype``.
+    ///
+    /// :returns: A new property array with an entry for each index up to the
+    ///     largest edge index of the graph
+    /// :rtype: PropertyArray
+    ///
+    /// :raises ValueError: If ``dtype`` isn't a valid type
+    #[pyo3(text_signature = "(self, dtype, /, default=None)")]
+    pub fn new_edge_property(
+        &self,
+        dtype: &str,
+        default: Option<&PyAny>,
+    ) -> PyResult<PropertyArray> {
+        PropertyArray::new(self.graph.edge_bound(), dtype, default)
+    }
+
+    /// Create an immutable CSR snapshot of the graph for analysis
+    ///
+    /// The returned :class:`~retworkx.CSRSnapshot` stores the current
+    /// adjacency of the graph in compressed sparse row form with the edge
+    /// weights computed once up front. Read-only algorithms run on the
+    /// snapshot have better cache locality than the equivalent functions run
+    /// on the graph. Later changes to the graph are not reflected in the
+    /// snapshot.
+    ///
+    /// :param weight_fn: An optional callable which takes a single positional
+    ///     argument, an edge's weight/data payload, and returns a float
+    ///     weight for that edge. It must be non-negative. If not specified
+    ///     ``default_weight`` will be used for every edge.
+    /// :param float default_weight: The weight to use for each edge if
+    ///     ``weight_fn`` is not specified. By default this is ``1.0``.
+    ///
+    /// :returns: A snapshot of the graph
+    /// :rtype: CSRSnapshot
+    /// :raises ValueError: If a weight is negative or NaN
+    #[args(default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, /, weight_fn=None, default_weight=1.0)")]
+    pub fn to_csr_snapshot(
+        &self,
+        py: Python,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<CSRSnapshot> {
+        CSRSnapshot::from_graph(py, &self.graph, weight_fn, default_weight)
+    }
+
     /// Generate a new :class:`~retworkx.PyDiGraph` object from this graph
     ///
     /// This will create a new :class:`~retworkx.PyDiGraph` object from this
@@ -1081,11 +1709,14 @@ impl PyGraph {
             new_graph.add_edge(target, source, weight.clone_ref(py));
         }
         crate::digraph::PyDiGraph {
-            graph: new_graph,
+            graph: new_graph.into(),
             node_removed: false,
             cycle_state: algo::DfsSpace::default(),
             check_cycle: false,
             multigraph: self.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         }
     }
 
@@ -1283,9 +1914,12 @@ impl PyGraph {
             out_graph.add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
         }
         Ok(PyGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             node_removed: false,
             multigraph: true,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         })
     }
 
@@ -1506,11 +2140,10 @@ impl PyGraph {
         // TODO: Reimplement this without looping over the graphs
         // Loop over other nodes add add to self graph
         for node in other.graph.node_indices() {
-            let new_index = self.graph.add_node(weight_transform_callable(
-                py,
-                &node_map_func,
-                &other.graph[node],
-            )?);
+            let new_index = self.log.add_node(
+                &mut self.graph,
+                weight_transform_callable(py, &node_map_func, &other.graph[node])?,
+            );
             new_node_map.insert(node, new_index);
         }
 
@@ -1519,12 +2152,14 @@ impl PyGraph {
             let new_p_index = new_node_map.get(&edge.source()).unwrap();
             let new_c_index = new_node_map.get(&edge.target()).unwrap();
             let weight = weight_transform_callable(py, &edge_map_func, edge.weight())?;
-            self.graph.add_edge(*new_p_index, *new_c_index, weight);
+            self.log
+                .add_edge(&mut self.graph, *new_p_index, *new_c_index, weight);
         }
         // Add edges from map
         for (this_index, (index, weight)) in node_map.iter() {
             let new_index = new_node_map.get(&NodeIndex::new(*index)).unwrap();
-            self.graph.add_edge(
+            self.log.add_edge(
+                &mut self.graph,
                 NodeIndex::new(*this_index),
                 *new_index,
                 weight.clone_ref(py),
@@ -1571,7 +2206,7 @@ impl PyGraph {
             nodes.into_iter().map(NodeIndex::new).collect();
 
         // Create new node.
-        let node_index = self.graph.add_node(obj);
+        let node_index = self.log.add_node(&mut self.graph, obj);
 
         // Sanitize new node index from user input.
         indices_to_remove.remove(&node_index);
@@ -1595,7 +2230,8 @@ impl PyGraph {
 
         // Remove nodes that will be replaced.
         for index in indices_to_remove {
-            self.graph.remove_node(index);
+            self.log
+                .remove_node(&mut self.graph, &mut self.clusters, index);
         }
 
         // If `weight_combo_fn` was specified, merge edges according
@@ -1642,9 +2278,12 @@ impl PyGraph {
             out_graph.add_edge(new_source, new_target, edge.weight().clone_ref(py));
         }
         PyGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             node_removed: false,
             multigraph: self.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         }
     }
 
@@ -1704,15 +2343,500 @@ impl PyGraph {
         out_graph
     }
 
+    /// Return a new PyGraph object for the subgraph induced by a list of edge indices
+    ///
+    /// This works like :meth:`~PyGraph.edge_subgraph` except that the edges are
+    /// specified by their edge index instead of by their endpoints. This makes
+    /// it possible to select only some of the parallel edges between a pair
+    /// of nodes in a multigraph. The subgraph contains each edge in
+    /// ``edge_indices`` and each node incident to any of those edges.
+    ///
+    /// The node and edge indices in the subgraph are the same as in this
+    /// graph, and all the node and edge weight/data payloads in the subgraph
+    /// are shared references to the payloads in this graph.
+    ///
+    /// :param list edge_indices: A list of edge indices to generate the
+    ///     subgraph from. Any index which is not present in the graph will be
+    ///     silently ignored.
+    ///
+    /// :returns: The edge subgraph
+    /// :rtype: PyGraph
+    #[pyo3(text_signature = "(self, edge_indices, /)")]
+    pub fn edge_subgraph_from_indices(&self, edge_indices: Vec<usize>) -> PyGraph {
+        let edge_set: HashSet<EdgeIndex> = edge_indices
+            .into_iter()
+            .map(EdgeIndex::new)
+            .filter(|edge| self.graph.edge_weight(*edge).is_some())
+            .collect();
+        let nodes: HashSet<NodeIndex> = edge_set
+            .iter()
+            .flat_map(|edge| {
+                let (source, target) = self.graph.edge_endpoints(*edge).unwrap();
+                [source, target]
+            })
+            .collect();
+        let mut out_graph = self.clone();
+        for node in self
+            .graph
+            .node_indices()
+            .filter(|node| !nodes.contains(node))
+        {
+            out_graph.graph.remove_node(node);
+            out_graph.node_removed = true;
+        }
+        for edge in self
+            .graph
+            .edge_indices()
+            .filter(|edge| !edge_set.contains(edge))
+        {
+            out_graph.graph.remove_edge(edge);
+        }
+        out_graph
+    }
+
+    /// Open a transaction on the graph
+    ///
+    /// The returned :class:`~retworkx.GraphTransaction` is a context manager
+    /// which logs the changes made to the graph while it's open, so they can
+    /// be rolled back without copying the graph. For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.path_graph(4)
+    ///   with graph.transaction() as transaction:
+    ///       graph.remove_node(1)
+    ///       graph.add_edge(0, 2, None)
+    ///       print(graph.edge_list())
+    ///       transaction.rollback()
+    ///   print(graph.edge_list())
+    ///
+    /// If the ``with`` block exits with an exception the changes are also
+    /// rolled back.
+    ///
+    /// :returns: A new transaction on the graph
+    /// :rtype: GraphTransaction
+    #[pyo3(text_signature = "(self)")]
+    pub fn transaction(slf: &PyCell<Self>) -> GraphTransaction {
+        GraphTransaction::from_graph(slf.into())
+    }
+
     /// Return a shallow copy of the graph
     ///
     /// All node and edge weight/data payloads in the copy will have a
     /// shared reference to the original graph.
+    ///
+    /// Only the structure of the graph (the nodes, edges, and their indices)
+    /// is duplicated, so creating a copy takes :math:`O(|V| + |E|)` time and
+    /// memory regardless of the size of the payload objects. The structure
+    /// is not shared between the copy and the original graph, so mutating
+    /// one will never be reflected in the other. To defer duplicating the
+    /// structure until one of the graphs is mutated use
+    /// :meth:`~retworkx.PyGraph.snapshot`.
     #[pyo3(text_signature = "(self)")]
     pub fn copy(&self) -> PyGraph {
         self.clone()
     }
 
+    /// Return a copy-on-write snapshot of the graph
+    ///
+    /// The snapshot shares the structure of the graph (the nodes, edges, and
+    /// their indices) with the original graph instead of duplicating it. Only
+    /// the cluster assignments and key indexes of the graph are copied, so
+    /// creating a snapshot takes time and memory proportional to the number of
+    /// clustered nodes and indexed payloads instead of the size of the graph.
+    /// The structure is only duplicated, as by :meth:`~retworkx.PyGraph.copy`,
+    /// the first time either graph is mutated, after which changes to one graph
+    /// are not reflected in the other. This makes it cheap to keep many
+    /// variants of a large base graph that are only read or only a few of which
+    /// are modified.
+    ///
+    /// All node and edge weight/data payloads in the snapshot will have a
+    /// shared reference to the original graph.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.path_graph(4)
+    ///   snapshot = graph.snapshot()
+    ///   graph.remove_node(0)
+    ///   print(graph.node_indices())
+    ///   print(snapshot.node_indices())
+    ///
+    /// :returns: A snapshot of the graph
+    /// :rtype: PyGraph
+    #[pyo3(text_signature = "(self)")]
+    pub fn snapshot(&self) -> PyGraph {
+        PyGraph {
+            graph: self.graph.share(),
+            node_removed: self.node_removed,
+            multigraph: self.multigraph,
+            log: self.log.clone(),
+            clusters: self.clusters.clone(),
+            indexes: self.indexes.clone(),
+        }
+    }
+
+    /// Assign nodes to a cluster
+    ///
+    /// Clusters group nodes of the graph under a name, for example the nodes
+    /// of a module or a region, so they can be worked with together using
+    /// :meth:`~retworkx.PyGraph.clusters`, :meth:`~retworkx.PyGraph.cluster_subgraph`
+    /// and :meth:`~retworkx.PyGraph.quotient_graph`. A node is assigned to at
+    /// most one cluster, so a node that's already assigned to a cluster is
+    /// moved to the new one. When a node is removed from the graph it's also
+    /// removed from its cluster.
+    ///
+    /// :param list nodes: The indices of the nodes to assign to the cluster
+    /// :param str name: The name of the cluster
+    ///
+    /// :raises IndexError: If any of the nodes are not in the graph, in which
+    ///     case none of the nodes are assigned
+    #[pyo3(text_signature = "(self, nodes, name, /)")]
+    pub fn assign_cluster(&mut self, nodes: Vec<usize>, name: String) -> PyResult<()> {
+        for node in &nodes {
+            if !self.graph.contains_node(NodeIndex::new(*node)) {
+                return Err(PyIndexError::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+        }
+        for node in nodes {
+            self.log
+                .set_cluster(&mut self.clusters, NodeIndex::new(node), Some(name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Remove nodes from the clusters they're assigned to
+    ///
+    /// :param list nodes: The indices of the nodes to remove from their
+    ///     clusters. If a node isn't assigned to a cluster it will silently be
+    ///     ignored.
+    #[pyo3(text_signature = "(self, nodes, /)")]
+    pub fn unassign_cluster(&mut self, nodes: Vec<usize>) {
+        for node in nodes {
+            self.log
+                .set_cluster(&mut self.clusters, NodeIndex::new(node), None);
+        }
+    }
+
+    /// Return the name of the cluster a node is assigned to
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The name of the cluster the node is assigned to or ``None``
+    ///     if it isn't assigned to a cluster
+    /// :rtype: str
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn node_cluster(&self, node: usize) -> Option<String> {
+        self.clusters.get(&node).cloned()
+    }
+
+    /// Return the clusters of the graph
+    ///
+    /// :returns: A dictionary mapping the name of each cluster to a sorted
+    ///     list of the indices of the nodes assigned to it. The clusters are
+    ///     ordered by their lowest node index.
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn clusters(&self) -> DictMap<String, Vec<usize>> {
+        cluster_members(&self.clusters)
+    }
+
+    /// Return a new PyGraph object for the subgraph induced by a cluster
+    ///
+    /// :param str name: The name of the cluster
+    ///
+    /// :returns: A new PyGraph object for the subgraph induced by the nodes
+    ///     assigned to the cluster, in the same way as
+    ///     :meth:`~retworkx.PyGraph.subgraph`
+    /// :rtype: PyGraph
+    ///
+    /// :raises KeyError: If there are no nodes assigned to a cluster named
+    ///     ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn cluster_subgraph(&self, py: Python, name: &str) -> PyResult<PyGraph> {
+        let mut nodes: Vec<usize> = self
+            .clusters
+            .iter()
+            .filter(|(_, cluster)| *cluster == name)
+            .map(|(node, _)| *node)
+            .collect();
+        if nodes.is_empty() {
+            return Err(PyKeyError::new_err(format!("No cluster named {}", name)));
+        }
+        nodes.sort_unstable();
+        Ok(self.subgraph(py, nodes))
+    }
+
+    /// Return a new PyGraph object for the subgraph induced by each cluster
+    ///
+    /// :returns: A dictionary mapping the name of each cluster to the
+    ///     subgraph induced by the nodes assigned to it, in the same order as
+    ///     :meth:`~retworkx.PyGraph.clusters`
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn cluster_subgraphs(&self, py: Python) -> DictMap<String, PyGraph> {
+        cluster_members(&self.clusters)
+            .into_iter()
+            .map(|(name, nodes)| (name, self.subgraph(py, nodes)))
+            .collect()
+    }
+
+    /// Return the quotient graph of the graph by its clusters
+    ///
+    /// The quotient graph contracts each cluster to a single node, whose
+    /// weight/data payload is the name of the cluster. The clusters come first,
+    /// in the same order as :meth:`~retworkx.PyGraph.clusters`, followed by the
+    /// nodes that aren't assigned to a cluster, which keep their weight/data
+    /// payloads, in node index order. There is an edge between two nodes of the
+    /// quotient graph if there is at least one edge between them in this graph.
+    /// Edges between nodes in the same cluster are dropped. As the quotient
+    /// graph is a PyGraph its nodes can be clustered in turn to build a hierarchy
+    /// of clusters.
+    ///
+    /// :param callable edge_weight_fn: An optional callable that will be passed
+    ///     the list of weight/data payloads of the edges between two nodes of
+    ///     the quotient graph, in edge index order, and returns the
+    ///     weight/data payload of the edge between them. If not specified the
+    ///     list is used as the weight/data payload.
+    ///
+    /// :returns: The quotient graph
+    /// :rtype: PyGraph
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.path_graph(6)
+    ///   graph.assign_cluster([0, 1, 2], "a")
+    ///   graph.assign_cluster([3, 4], "b")
+    ///   quotient = graph.quotient_graph(len)
+    ///   print(quotient.nodes())
+    ///   print(quotient.weighted_edge_list())
+    #[pyo3(text_signature = "(self, /, edge_weight_fn=None)")]
+    pub fn quotient_graph(
+        &self,
+        py: Python,
+        edge_weight_fn: Option<PyObject>,
+    ) -> PyResult<PyGraph> {
+        let out_graph = quotient_graph(py, &self.graph, &self.clusters, edge_weight_fn)?;
+        Ok(PyGraph {
+            graph: out_graph.into(),
+            node_removed: false,
+            multigraph: self.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
+        })
+    }
+
+    /// Index the nodes of the graph by a key computed from their weight/data
+    /// payloads
+    ///
+    /// After the nodes are indexed :meth:`~retworkx.PyGraph.nodes_with_key`
+    /// finds the nodes with a given key without scanning the graph. The index
+    /// is kept up to date as nodes are added, removed or have their
+    /// weight/data payload replaced (including by a rolled back
+    /// :meth:`~retworkx.PyGraph.transaction`), with ``key_fn`` only called for
+    /// the nodes that changed. Mutating a weight/data payload object in place
+    /// isn't tracked, call this method again to rebuild the index if that
+    /// changes any keys. Calling this method replaces any existing node index,
+    /// and the index isn't kept by copies or pickles of the graph.
+    ///
+    /// :param callable key_fn: A callable that will be passed the weight/data
+    ///     payload of a node and returns its key. The key must be hashable. If
+    ///     not set the weight/data payloads themselves are the keys.
+    ///
+    /// :raises TypeError: If a key isn't hashable. Any exception raised by
+    ///     ``key_fn`` is also raised and the nodes are left unindexed.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.PyGraph()
+    ///   graph.add_nodes_from([("a", 1), ("b", 2), ("a", 3)])
+    ///   graph.index_nodes_by(lambda node: node[0])
+    ///   print(graph.nodes_with_key("a"))
+    ///   graph.add_node(("a", 4))
+    ///   print(graph.nodes_with_key("a"))
+    #[pyo3(text_signature = "(self, /, key_fn=None)")]
+    pub fn index_nodes_by(&mut self, py: Python, key_fn: Option<PyObject>) -> PyResult<()> {
+        self.indexes.nodes = None;
+        self.log.node_changes.enable(true);
+        self.log.node_changes.touch_all();
+        let mut index = KeyIndex::new(py, key_fn);
+        let graph = &self.graph;
+        if let Err(err) = index.update(
+            py,
+            &mut self.log.node_changes,
+            |node| graph.node_weight(NodeIndex::new(node)),
+            graph
+                .node_indices()
+                .map(|node| (node.index(), &graph[node])),
+        ) {
+            self.log.node_changes.enable(false);
+            return Err(err);
+        }
+        self.indexes.nodes = Some(index);
+        Ok(())
+    }
+
+    /// Return the indices of the nodes with a given key
+    ///
+    /// :param key: The key to look up, as returned by the ``key_fn`` passed
+    ///     to :meth:`~retworkx.PyGraph.index_nodes_by`
+    ///
+    /// :returns: The sorted indices of the nodes with the key, which is empty
+    ///     if there are none
+    /// :rtype: NodeIndices
+    ///
+    /// :raises RuntimeError: If the nodes of the graph aren't indexed
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn nodes_with_key(&mut self, py: Python, key: PyObject) -> PyResult<NodeIndices> {
+        let index = self
+            .indexes
+            .nodes
+            .as_mut()
+            .ok_or_else(|| not_indexed("node"))?;
+        let graph = &self.graph;
+        index.update(
+            py,
+            &mut self.log.node_changes,
+            |node| graph.node_weight(NodeIndex::new(node)),
+            graph
+                .node_indices()
+                .map(|node| (node.index(), &graph[node])),
+        )?;
+        Ok(NodeIndices {
+            nodes: index.get(py, key)?,
+        })
+    }
+
+    /// Remove the node index created by
+    /// :meth:`~retworkx.PyGraph.index_nodes_by`
+    ///
+    /// If the nodes aren't indexed this does nothing.
+    #[pyo3(text_signature = "(self)")]
+    pub fn drop_node_index(&mut self) {
+        self.indexes.nodes = None;
+        self.log.node_changes.enable(false);
+    }
+
+    /// Index the edges of the graph by a key computed from their weight/data
+    /// payloads
+    ///
+    /// After the edges are indexed :meth:`~retworkx.PyGraph.edges_with_key`
+    /// finds the edges with a given key without scanning the graph. The index
+    /// is kept up to date as edges are added, removed or have their
+    /// weight/data payload replaced (including by a rolled back
+    /// :meth:`~retworkx.PyGraph.transaction`), with ``key_fn`` only called for
+    /// the edges that changed. Mutating a weight/data payload object in place
+    /// isn't tracked, call this method again to rebuild the index if that
+    /// changes any keys. Calling this method replaces any existing edge index,
+    /// and the index isn't kept by copies or pickles of the graph.
+    ///
+    /// :param callable key_fn: A callable that will be passed the weight/data
+    ///     payload of an edge and returns its key. The key must be hashable. If
+    ///     not set the weight/data payloads themselves are the keys.
+    ///
+    /// :raises TypeError: If a key isn't hashable. Any exception raised by
+    ///     ``key_fn`` is also raised and the edges are left unindexed.
+    #[pyo3(text_signature = "(self, /, key_fn=None)")]
+    pub fn index_edges_by(&mut self, py: Python, key_fn: Option<PyObject>) -> PyResult<()> {
+        self.indexes.edges = None;
+        self.log.edge_changes.enable(true);
+        self.log.edge_changes.touch_all();
+        let mut index = KeyIndex::new(py, key_fn);
+        let graph = &self.graph;
+        if let Err(err) = index.update(
+            py,
+            &mut self.log.edge_changes,
+            |edge| graph.edge_weight(EdgeIndex::new(edge)),
+            graph
+                .edge_references()
+                .map(|edge| (edge.id().index(), edge.weight())),
+        ) {
+            self.log.edge_changes.enable(false);
+            return Err(err);
+        }
+        self.indexes.edges = Some(index);
+        Ok(())
+    }
+
+    /// Return the indices of the edges with a given key
+    ///
+    /// :param key: The key to look up, as returned by the ``key_fn`` passed
+    ///     to :meth:`~retworkx.PyGraph.index_edges_by`
+    ///
+    /// :returns: The sorted indices of the edges with the key, which is empty
+    ///     if there are none
+    /// :rtype: EdgeIndices
+    ///
+    /// :raises RuntimeError: If the edges of the graph aren't indexed
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn edges_with_key(&mut self, py: Python, key: PyObject) -> PyResult<EdgeIndices> {
+        let index = self
+            .indexes
+            .edges
+            .as_mut()
+            .ok_or_else(|| not_indexed("edge"))?;
+        let graph = &self.graph;
+        index.update(
+            py,
+            &mut self.log.edge_changes,
+            |edge| graph.edge_weight(EdgeIndex::new(edge)),
+            graph
+                .edge_references()
+                .map(|edge| (edge.id().index(), edge.weight())),
+        )?;
+        Ok(EdgeIndices {
+            edges: index.get(py, key)?,
+        })
+    }
+
+    /// Remove the edge index created by
+    /// :meth:`~retworkx.PyGraph.index_edges_by`
+    ///
+    /// If the edges aren't indexed this does nothing.
+    #[pyo3(text_signature = "(self)")]
+    pub fn drop_edge_index(&mut self) {
+        self.indexes.edges = None;
+        self.log.edge_changes.enable(false);
+    }
+
+    /// Reindex the nodes and edges of the graph so their indices are contiguous
+    ///
+    /// After nodes or edges are removed from a graph there will be gaps in
+    /// the indices. This method rebuilds the graph in place so the node
+    /// indices are ``0`` to ``num_nodes() - 1`` and the edge indices are
+    /// ``0`` to ``num_edges() - 1``. The relative order of the indices is
+    /// preserved and node and edge weight/data payloads are not copied. The
+    /// nodes stay assigned to their clusters.
+    ///
+    /// :returns: A tuple of a :class:`~retworkx.NodeMap` mapping the old node
+    ///     indices to the new node indices and an :class:`~retworkx.EdgeMap`
+    ///     mapping the old edge indices to the new edge indices.
+    /// :rtype: tuple
+    #[pyo3(text_signature = "(self)")]
+    pub fn compact(&mut self, py: Python) -> (NodeMap, EdgeMap) {
+        let (out_graph, node_map, edge_map) = compact_graph(py, &self.graph);
+        let clusters: Clusters = self
+            .clusters
+            .iter()
+            .map(|(node, name)| (node_map[node], name.clone()))
+            .collect();
+        self.log
+            .replace(&mut self.graph, &mut self.clusters, out_graph, clusters);
+        self.node_removed = false;
+        (NodeMap { node_map }, EdgeMap { edge_map })
+    }
+
     /// Return the nmber of nodes in the graph
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.graph.node_count())
@@ -1726,16 +2850,21 @@ impl PyGraph {
     }
 
     fn __setitem__(&mut self, idx: usize, value: PyObject) -> PyResult<()> {
-        let data = match self.graph.node_weight_mut(NodeIndex::new(idx)) {
-            Some(node_data) => node_data,
-            None => return Err(PyIndexError::new_err("No node found for index")),
-        };
-        *data = value;
-        Ok(())
+        match self
+            .log
+            .set_node_weight(&mut self.graph, NodeIndex::new(idx), value)
+        {
+            Some(_) => Ok(()),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
     }
 
     fn __delitem__(&mut self, idx: usize) -> PyResult<()> {
-        match self.graph.remove_node(NodeIndex::new(idx as usize)) {
+        match self.log.remove_node(
+            &mut self.graph,
+            &mut self.clusters,
+            NodeIndex::new(idx as usize),
+        ) {
             Some(_) => Ok(()),
             None => Err(PyIndexError::new_err("No node found for index")),
         }
@@ -1749,20 +2878,27 @@ impl PyGraph {
     // [1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_traverse
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
-        for node in self
-            .graph
-            .node_indices()
-            .map(|node| self.graph.node_weight(node).unwrap())
-        {
-            visit.call(node)?;
-        }
-        for edge in self
-            .graph
-            .edge_indices()
-            .map(|edge| self.graph.edge_weight(edge).unwrap())
-        {
-            visit.call(edge)?;
+        // The payloads of a structure shared with a snapshot are referenced
+        // once by the shared structure, not once by each graph sharing it,
+        // so only report them while this graph is its sole owner.
+        if !self.graph.is_shared() {
+            for node in self
+                .graph
+                .node_indices()
+                .map(|node| self.graph.node_weight(node).unwrap())
+            {
+                visit.call(node)?;
+            }
+            for edge in self
+                .graph
+                .edge_indices()
+                .map(|edge| self.graph.edge_weight(edge).unwrap())
+            {
+                visit.call(edge)?;
+            }
         }
+        self.log.traverse(&visit)?;
+        self.indexes.traverse(&visit)?;
         Ok(())
     }
 
@@ -1773,8 +2909,10 @@ impl PyGraph {
     // ]1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_clear
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __clear__(&mut self) {
-        self.graph = StablePyGraph::<Undirected>::default();
+        self.graph = StablePyGraph::<Undirected>::default().into();
         self.node_removed = false;
+        self.log.clear();
+        self.indexes.clear();
     }
 }
 
@@ -1833,8 +2971,11 @@ where
             }
         });
     PyGraph {
-        graph: out_graph,
+        graph: out_graph.into(),
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     }
 }
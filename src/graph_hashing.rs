@@ -0,0 +1,223 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use retworkx_core::dictmap::*;
+
+use crate::{digraph, graph, StablePyGraph};
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Hash a label with the 128 bit FNV-1a hash as a hex string. Unlike the
+/// hasher of ``HashMap`` this doesn't depend on a random seed, so the hashes
+/// are the same between runs and can be compared across processes.
+fn hash_label(label: &str) -> String {
+    let hash = label.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u128).wrapping_mul(FNV_PRIME)
+    });
+    format!("{:032x}", hash)
+}
+
+/// The Weisfeiler-Lehman subtree hash of each node of ``graph`` after
+/// ``depth`` iterations by node index.
+pub fn subgraph_hashes<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    depth: usize,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<DictMap<usize, String>> {
+    let mut hashes: Vec<String> = vec![String::new(); graph.node_bound()];
+    for node in graph.node_indices() {
+        let label: String = match &node_attr_fn {
+            Some(node_attr_fn) => node_attr_fn.call1(py, (&graph[node],))?.extract(py)?,
+            None => {
+                if Ty::is_directed() {
+                    // Label by in and out degree like networkx so that the
+                    // direction of the edges matters from depth 0
+                    format!(
+                        "{}_{}",
+                        graph.edges_directed(node, Incoming).count(),
+                        graph.edges_directed(node, Outgoing).count()
+                    )
+                } else {
+                    graph
+                        .edges(node)
+                        .map(|edge| if edge.source() == edge.target() { 2 } else { 1 })
+                        .sum::<usize>()
+                        .to_string()
+                }
+            }
+        };
+        hashes[node.index()] = hash_label(&label);
+    }
+    let edge_labels: Option<Vec<String>> = match &edge_attr_fn {
+        Some(edge_attr_fn) => {
+            let mut edge_labels: Vec<String> = vec![String::new(); graph.edge_bound()];
+            for edge in graph.edge_references() {
+                edge_labels[edge.id().index()] =
+                    edge_attr_fn.call1(py, (edge.weight(),))?.extract(py)?;
+            }
+            Some(edge_labels)
+        }
+        None => None,
+    };
+    for _ in 0..depth {
+        let mut next_hashes: Vec<String> = vec![String::new(); graph.node_bound()];
+        for node in graph.node_indices() {
+            let mut neighborhood: Vec<String> = graph
+                .edges(node)
+                .map(|edge| {
+                    let neighbor = if edge.source() == node {
+                        edge.target()
+                    } else {
+                        edge.source()
+                    };
+                    match &edge_labels {
+                        Some(edge_labels) => {
+                            edge_labels[edge.id().index()].clone() + &hashes[neighbor.index()]
+                        }
+                        None => hashes[neighbor.index()].clone(),
+                    }
+                })
+                .collect();
+            neighborhood.sort_unstable();
+            let label = hashes[node.index()].clone() + &neighborhood.concat();
+            next_hashes[node.index()] = hash_label(&label);
+        }
+        hashes = next_hashes;
+    }
+    Ok(graph
+        .node_indices()
+        .map(|node| (node.index(), hashes[node.index()].clone()))
+        .collect())
+}
+
+/// Compute the Weisfeiler-Lehman subtree hash of each node of a
+/// :class:`~retworkx.PyGraph`
+///
+/// Each node starts with a label, its degree or the output of
+/// ``node_attr_fn``, and at each iteration the label of a node is replaced
+/// by a hash of its label and the sorted labels of its neighbors [1]_. After
+/// ``depth`` iterations the hash of a node identifies the subtree of depth
+/// ``depth`` rooted at it, the tree of all walks of at most ``depth`` edges
+/// from the node. Nodes with different hashes can't be mapped to each other
+/// by an isomorphism, so the hashes can be used to find duplicate nodes or to
+/// filter the candidate nodes for subgraph matching. Nodes with equal hashes
+/// aren't necessarily equivalent.
+///
+/// The hashes are the 128 bit FNV-1a hash of the labels, which doesn't
+/// depend on a random seed, so they can be compared between graphs and
+/// between runs.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(5)
+///   hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=2)
+///   print(hashes[0] == hashes[4], hashes[0] == hashes[2])
+///
+/// :param PyGraph graph: The graph to hash
+/// :param int depth: The number of iterations, which is the depth of the
+///     subtree hashed for each node. With a depth of ``0`` only the initial
+///     label of each node is hashed. By default this is ``3``.
+/// :param node_attr_fn: An optional callable which will be passed the
+///     data payload of a node and returns a string used as the initial label
+///     of the node. If it isn't specified the degree of the node is used.
+/// :param edge_attr_fn: An optional callable which will be passed the
+///     data payload of an edge and returns a string which is added to the
+///     label of the neighbor across the edge. If it isn't specified edges
+///     aren't labeled.
+///
+/// :returns: A dictionary mapping each node index to its hash as a hex
+///     string
+/// :rtype: dict
+///
+/// .. [1] Shervashidze, Nino, Pascal Schweitzer, Erik Jan Van Leeuwen,
+///    Kurt Mehlhorn, and Karsten M. Borgwardt. "Weisfeiler-Lehman graph
+///    kernels." Journal of Machine Learning Research 12 (2011): 2539-2561.
+#[pyfunction(depth = "3")]
+#[pyo3(text_signature = "(graph, /, depth=3, node_attr_fn=None, edge_attr_fn=None)")]
+pub fn graph_weisfeiler_lehman_subgraph_hashes(
+    py: Python,
+    graph: &graph::PyGraph,
+    depth: usize,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<DictMap<usize, String>> {
+    subgraph_hashes(py, &graph.graph, depth, node_attr_fn, edge_attr_fn)
+}
+
+/// Compute the Weisfeiler-Lehman subtree hash of each node of a
+/// :class:`~retworkx.PyDiGraph`
+///
+/// Each node starts with a label, its in and out degrees or the output of
+/// ``node_attr_fn``, and at each iteration the label of a node is replaced by
+/// a hash of its label and the sorted labels of its successors [1]_. After ``depth`` iterations the hash of a node
+/// identifies the subtree of depth ``depth`` rooted at it, the tree of all
+/// directed paths of at most ``depth`` edges out of the node. Nodes with
+/// different hashes can't be mapped to each other by an isomorphism, so the
+/// hashes can be used to find duplicate nodes or to filter the candidate
+/// nodes for subgraph matching. Nodes with equal hashes aren't necessarily
+/// equivalent.
+///
+/// The hashes are the 128 bit FNV-1a hash of the labels, which doesn't
+/// depend on a random seed, so they can be compared between graphs and
+/// between runs.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_star_graph(4)
+///   hashes = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(graph)
+///   print(hashes)
+///
+/// :param PyDiGraph graph: The graph to hash
+/// :param int depth: The number of iterations, which is the depth of the
+///     subtree hashed for each node. With a depth of ``0`` only the initial
+///     label of each node is hashed. By default this is ``3``.
+/// :param node_attr_fn: An optional callable which will be passed the
+///     data payload of a node and returns a string used as the initial label
+///     of the node. If it isn't specified the in and out degrees of the node
+///     are used.
+/// :param edge_attr_fn: An optional callable which will be passed the
+///     data payload of an edge and returns a string which is added to the
+///     label of the successor across the edge. If it isn't specified edges
+///     aren't labeled.
+///
+/// :returns: A dictionary mapping each node index to its hash as a hex
+///     string
+/// :rtype: dict
+///
+/// .. [1] Shervashidze, Nino, Pascal Schweitzer, Erik Jan Van Leeuwen,
+///    Kurt Mehlhorn, and Karsten M. Borgwardt. "Weisfeiler-Lehman graph
+///    kernels." Journal of Machine Learning Research 12 (2011): 2539-2561.
+#[pyfunction(depth = "3")]
+#[pyo3(text_signature = "(graph, /, depth=3, node_attr_fn=None, edge_attr_fn=None)")]
+pub fn digraph_weisfeiler_lehman_subgraph_hashes(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    depth: usize,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+) -> PyResult<DictMap<usize, String>> {
+    subgraph_hashes(py, &graph.graph, depth, node_attr_fn, edge_attr_fn)
+}
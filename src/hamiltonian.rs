@@ -0,0 +1,500 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, GraphTooLarge, NodeIndex, StablePyGraph};
+
+/// The largest graph searched with the dynamic program over subsets of
+/// nodes, which needs ``4 * 2**n`` bytes of memory.
+const DP_MAX_NODES: usize = 24;
+
+/// The largest graph supported by the exact search, the sets of nodes are
+/// stored as 64 bit masks.
+const EXACT_MAX_NODES: usize = 64;
+
+/// The maximum number of rotations applied to a path by the heuristic search
+/// from a single start node.
+const MAX_ROTATIONS: usize = 64;
+
+/// The neighbors of each node of a graph with contiguous node indices,
+/// self loops and parallel edges are dropped.
+struct Neighbors {
+    nodes: Vec<NodeIndex>,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl Neighbors {
+    fn new<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Self {
+        let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+        let mut compact = vec![0; graph.node_bound()];
+        for (index, node) in nodes.iter().enumerate() {
+            compact[node.index()] = index;
+        }
+        let mut outgoing = vec![Vec::new(); nodes.len()];
+        let mut incoming = vec![Vec::new(); nodes.len()];
+        for (index, node) in nodes.iter().enumerate() {
+            for neighbor in graph.neighbors(*node) {
+                let neighbor = compact[neighbor.index()];
+                if neighbor != index {
+                    outgoing[index].push(neighbor);
+                    incoming[neighbor].push(index);
+                }
+            }
+        }
+        for list in outgoing.iter_mut().chain(incoming.iter_mut()) {
+            list.sort_unstable();
+            list.dedup();
+        }
+        Neighbors {
+            nodes,
+            outgoing,
+            incoming,
+        }
+    }
+
+    fn masks(lists: &[Vec<usize>]) -> Vec<u64> {
+        lists
+            .iter()
+            .map(|list| list.iter().fold(0, |mask, node| mask | 1 << node))
+            .collect()
+    }
+}
+
+fn bits(mut mask: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let bit = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(bit)
+    })
+}
+
+/// Find a Hamiltonian path or cycle with a dynamic program over the subsets
+/// of nodes, ``ends[mask]`` holds the nodes at which a path visiting exactly
+/// the nodes of ``mask`` can end. A cycle starts at node 0.
+fn dp_search(outgoing: &[u64], incoming: &[u64], cycle: bool) -> Option<Vec<usize>> {
+    let node_count = outgoing.len();
+    let full = (1usize << node_count) - 1;
+    let mut ends: Vec<u32> = vec![0; full + 1];
+    if cycle {
+        ends[1] = 1;
+    } else {
+        for node in 0..node_count {
+            ends[1 << node] = 1 << node;
+        }
+    }
+    for mask in 1..full {
+        let current = ends[mask] as u64;
+        if current == 0 {
+            continue;
+        }
+        for node in bits(!(mask as u64) & full as u64) {
+            if incoming[node] & current != 0 {
+                ends[mask | 1 << node] |= 1 << node;
+            }
+        }
+    }
+    let mut end = bits(ends[full] as u64).find(|end| !cycle || outgoing[*end] & 1 != 0)?;
+    // Walk back through the subsets to rebuild the path
+    let mut path = vec![end];
+    let mut mask = full;
+    while path.len() < node_count {
+        mask ^= 1 << end;
+        end = bits(ends[mask] as u64 & incoming[end]).next().unwrap();
+        path.push(end);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// A depth first search for a Hamiltonian path or cycle extending a path,
+/// pruning the paths which can't be completed.
+struct ExactSearch {
+    outgoing: Vec<u64>,
+    incoming: Vec<u64>,
+    directed: bool,
+    cycle: bool,
+    all: u64,
+}
+
+impl ExactSearch {
+    /// Check whether the path from ``start`` to ``end`` visiting ``visited``
+    /// might still be extended to a Hamiltonian path or cycle.
+    fn feasible(&self, start: usize, end: usize, visited: u64) -> bool {
+        let unvisited = self.all & !visited;
+        let start_bit = if self.cycle { 1 << start } else { 0 };
+        let mut dead_ends = 0;
+        for node in bits(unvisited) {
+            if self.directed {
+                if self.incoming[node] & (unvisited | 1 << end) == 0 {
+                    return false;
+                }
+                if self.outgoing[node] & (unvisited | start_bit) == 0 {
+                    dead_ends += 1;
+                }
+            } else {
+                let available = self.outgoing[node] & (unvisited | 1 << end | start_bit);
+                match available.count_ones() {
+                    0 => return false,
+                    1 => dead_ends += 1,
+                    _ => (),
+                }
+            }
+            // A path has a single last node, a cycle has none
+            if dead_ends > 1 || (self.cycle && dead_ends > 0) {
+                return false;
+            }
+        }
+        // All the unvisited nodes must be reachable from the end of the path
+        let mut reached = 0;
+        let mut frontier: u64 = 1 << end;
+        while frontier != 0 {
+            let next = bits(frontier).fold(0, |mask, node| mask | self.outgoing[node]);
+            frontier = next & unvisited & !reached;
+            reached |= frontier;
+        }
+        reached == unvisited
+    }
+
+    fn extend(&self, path: &mut Vec<usize>, visited: u64) -> bool {
+        let start = path[0];
+        let end = *path.last().unwrap();
+        if visited == self.all {
+            return !self.cycle || self.outgoing[end] & 1 << start != 0;
+        }
+        if !self.feasible(start, end, visited) {
+            return false;
+        }
+        // Visit the neighbors with the fewest onward options first
+        let mut candidates: Vec<usize> = bits(self.outgoing[end] & !visited).collect();
+        candidates.sort_by_key(|node| (self.outgoing[*node] & !visited).count_ones());
+        for node in candidates {
+            path.push(node);
+            if self.extend(path, visited | 1 << node) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    fn search(&self, starts: &[usize]) -> Option<Vec<usize>> {
+        for start in starts {
+            let mut path = vec![*start];
+            if self.extend(&mut path, 1 << start) {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+/// Find a Hamiltonian path or cycle exactly, returns ``None`` if the graph
+/// doesn't have one.
+fn exact_search(neighbors: &Neighbors, directed: bool, cycle: bool) -> Option<Vec<usize>> {
+    let node_count = neighbors.nodes.len();
+    let outgoing = Neighbors::masks(&neighbors.outgoing);
+    let incoming = Neighbors::masks(&neighbors.incoming);
+    let all = if node_count == 64 {
+        u64::MAX
+    } else {
+        (1 << node_count) - 1
+    };
+    // Nodes which must be the first or last node of a path
+    let sources: Vec<usize> = (0..node_count).filter(|n| incoming[*n] == 0).collect();
+    let sinks: Vec<usize> = (0..node_count).filter(|n| outgoing[*n] == 0).collect();
+    if cycle && (!sources.is_empty() || !sinks.is_empty()) {
+        return None;
+    }
+    let leaves: Vec<usize> = (0..node_count)
+        .filter(|n| incoming[*n].count_ones() <= 1)
+        .collect();
+    if sources.len() > 1 || sinks.len() > 1 || (!directed && leaves.len() > 2) {
+        return None;
+    }
+    if node_count <= DP_MAX_NODES {
+        return dp_search(&outgoing, &incoming, cycle);
+    }
+    let search = ExactSearch {
+        outgoing,
+        incoming,
+        directed,
+        cycle,
+        all,
+    };
+    let starts: Vec<usize> = if cycle {
+        vec![0]
+    } else if !sources.is_empty() {
+        sources
+    } else if !directed && !leaves.is_empty() {
+        vec![leaves[0]]
+    } else {
+        let mut starts: Vec<usize> = (0..node_count).collect();
+        starts.sort_by_key(|node| search.outgoing[*node].count_ones());
+        starts
+    };
+    search.search(&starts)
+}
+
+/// Search for a Hamiltonian path or cycle with a greedy heuristic, returns
+/// ``None`` if none was found which doesn't mean that none exists.
+///
+/// From each start node the path is extended greedily at both ends to the
+/// neighbor with the fewest unvisited neighbors. In an undirected graph a
+/// path which can't be extended is rotated: if the end of the path is
+/// adjacent to an inner node, the part of the path after that node is
+/// reversed which gives the path a new end.
+fn heuristic_search(neighbors: &Neighbors, directed: bool, cycle: bool) -> Option<Vec<usize>> {
+    let node_count = neighbors.nodes.len();
+    let outgoing = &neighbors.outgoing;
+    let incoming = &neighbors.incoming;
+    let mut starts: Vec<usize> = (0..node_count).collect();
+    starts.sort_by_key(|node| outgoing[*node].len() + incoming[*node].len());
+    let mut visited = vec![false; node_count];
+    let unvisited_degree = |node: usize, visited: &[bool], lists: &[Vec<usize>]| {
+        lists[node].iter().filter(|other| !visited[**other]).count()
+    };
+    for start in starts {
+        visited.iter_mut().for_each(|v| *v = false);
+        let mut path: VecDeque<usize> = VecDeque::with_capacity(node_count);
+        path.push_back(start);
+        visited[start] = true;
+        let mut rotations = 0;
+        loop {
+            let end = *path.back().unwrap();
+            if let Some(next) = outgoing[end]
+                .iter()
+                .filter(|node| !visited[**node])
+                .min_by_key(|node| unvisited_degree(**node, &visited, outgoing))
+            {
+                visited[*next] = true;
+                path.push_back(*next);
+                continue;
+            }
+            let front = *path.front().unwrap();
+            if let Some(previous) = incoming[front]
+                .iter()
+                .filter(|node| !visited[**node])
+                .min_by_key(|node| unvisited_degree(**node, &visited, incoming))
+            {
+                visited[*previous] = true;
+                path.push_front(*previous);
+                continue;
+            }
+            if directed || path.len() == node_count || rotations == MAX_ROTATIONS {
+                break;
+            }
+            // Rotate the path to an end with unvisited neighbors
+            let path_len = path.len();
+            let pivot = (0..path_len.saturating_sub(2)).rev().find(|index| {
+                outgoing[end].binary_search(&path[*index]).is_ok()
+                    && outgoing[path[index + 1]].iter().any(|node| !visited[*node])
+            });
+            match pivot {
+                Some(index) => {
+                    path.make_contiguous()[index + 1..].reverse();
+                    rotations += 1;
+                }
+                None => break,
+            }
+        }
+        if path.len() < node_count {
+            continue;
+        }
+        let mut path: Vec<usize> = path.into_iter().collect();
+        if !cycle {
+            return Some(path);
+        }
+        let closes = |path: &[usize]| {
+            outgoing[path[node_count - 1]]
+                .binary_search(&path[0])
+                .is_ok()
+        };
+        if closes(&path) {
+            return Some(path);
+        }
+        if !directed {
+            // Close the cycle with a rotation if the end is adjacent to an
+            // inner node whose successor is adjacent to the start
+            let end = path[node_count - 1];
+            let pivot = (1..node_count - 2).find(|index| {
+                outgoing[end].binary_search(&path[*index]).is_ok()
+                    && outgoing[path[0]].binary_search(&path[index + 1]).is_ok()
+            });
+            if let Some(index) = pivot {
+                path[index + 1..].reverse();
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+fn hamiltonian_path<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    cycle: bool,
+    method: &str,
+) -> PyResult<Option<NodeIndices>> {
+    let exact = match method {
+        "exact" => true,
+        "heuristic" => false,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid method '{}', must be 'exact' or 'heuristic'",
+                method
+            )))
+        }
+    };
+    let neighbors = Neighbors::new(graph);
+    let node_count = neighbors.nodes.len();
+    if exact && node_count > EXACT_MAX_NODES {
+        return Err(GraphTooLarge::new_err(format!(
+            "The exact Hamiltonian path search supports graphs with at most {} nodes, \
+             this graph has {} nodes. Use method='heuristic' instead.",
+            EXACT_MAX_NODES, node_count
+        )));
+    }
+    // A directed cycle can go back and forth between 2 nodes while an
+    // undirected one needs at least 3 distinct edges.
+    let directed = graph.is_directed();
+    if cycle && node_count < if directed { 2 } else { 3 } {
+        return Ok(None);
+    }
+    if node_count <= 1 {
+        return Ok(Some(NodeIndices {
+            nodes: neighbors.nodes.iter().map(|node| node.index()).collect(),
+        }));
+    }
+    let path = py.allow_threads(|| {
+        if exact {
+            exact_search(&neighbors, directed, cycle)
+        } else {
+            heuristic_search(&neighbors, directed, cycle)
+        }
+    });
+    Ok(path.map(|path| NodeIndices {
+        nodes: path
+            .into_iter()
+            .map(|node| neighbors.nodes[node].index())
+            .collect(),
+    }))
+}
+
+/// Find a Hamiltonian path or cycle of a :class:`~retworkx.PyGraph`
+///
+/// A Hamiltonian path visits every node of the graph exactly once, a
+/// Hamiltonian cycle additionally has an edge from its last node back to its
+/// first node. Finding one is NP-complete.
+///
+/// With ``method="exact"`` the search is exhaustive, graphs with at most 24
+/// nodes are solved with a dynamic program over the subsets of nodes in
+/// :math:`O(2^n n)` time and larger graphs with a depth first search which
+/// prunes the paths which can't be completed. The exact search supports
+/// graphs with at most 64 nodes. With ``method="heuristic"`` paths are
+/// extended greedily from each node and rotated when they get stuck, this
+/// is fast for any graph size but may not find a path when one exists.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.generalized_petersen_graph(5, 2)
+///   print(retworkx.graph_hamiltonian_path(graph))
+///   print(retworkx.graph_hamiltonian_path(graph, cycle=True))
+///
+/// :param PyGraph graph: The graph to search. Self loops and parallel edges
+///     are ignored.
+/// :param bool cycle: If ``True`` search for a Hamiltonian cycle instead of a
+///     path. A cycle needs at least 3 nodes. Defaults to ``False``.
+/// :param str method: Either ``"exact"`` or ``"heuristic"``, defaults to
+///     ``"exact"``
+///
+/// :returns: The node indices in the order they're visited, the first node
+///     isn't repeated at the end of a cycle. ``None`` if the exact search
+///     proved there is no Hamiltonian path or cycle, or if the heuristic
+///     search didn't find one.
+/// :rtype: NodeIndices
+///
+/// :raises GraphTooLarge: If ``method="exact"`` and the graph has more than
+///     64 nodes
+/// :raises ValueError: If ``method`` is invalid
+#[pyfunction(cycle = "false", method = "\"exact\"")]
+#[pyo3(text_signature = "(graph, /, cycle=False, method=\"exact\")")]
+pub fn graph_hamiltonian_path(
+    py: Python,
+    graph: &graph::PyGraph,
+    cycle: bool,
+    method: &str,
+) -> PyResult<Option<NodeIndices>> {
+    hamiltonian_path(py, &graph.graph, cycle, method)
+}
+
+/// Find a Hamiltonian path or cycle of a :class:`~retworkx.PyDiGraph`
+///
+/// A Hamiltonian path visits every node of the graph exactly once following
+/// the direction of the edges, a Hamiltonian cycle additionally has an edge
+/// from its last node back to its first node. Finding one is NP-complete.
+///
+/// With ``method="exact"`` the search is exhaustive, graphs with at most 24
+/// nodes are solved with a dynamic program over the subsets of nodes in
+/// :math:`O(2^n n)` time and larger graphs with a depth first search which
+/// prunes the paths which can't be completed. The exact search supports
+/// graphs with at most 64 nodes. With ``method="heuristic"`` paths are
+/// extended greedily from each node, this is fast for any graph size but may
+/// not find a path when one exists.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_cycle_graph(5)
+///   print(retworkx.digraph_hamiltonian_path(graph, cycle=True))
+///
+/// :param PyDiGraph graph: The graph to search. Self loops and parallel
+///     edges are ignored.
+/// :param bool cycle: If ``True`` search for a Hamiltonian cycle instead of a
+///     path. A cycle needs at least 2 nodes. Defaults to ``False``.
+/// :param str method: Either ``"exact"`` or ``"heuristic"``, defaults to
+///     ``"exact"``
+///
+/// :returns: The node indices in the order they're visited, the first node
+///     isn't repeated at the end of a cycle. ``None`` if the exact search
+///     proved there is no Hamiltonian path or cycle, or if the heuristic
+///     search didn't find one.
+/// :rtype: NodeIndices
+///
+/// :raises GraphTooLarge: If ``method="exact"`` and the graph has more than
+///     64 nodes
+/// :raises ValueError: If ``method`` is invalid
+#[pyfunction(cycle = "false", method = "\"exact\"")]
+#[pyo3(text_signature = "(graph, /, cycle=False, method=\"exact\")")]
+pub fn digraph_hamiltonian_path(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    cycle: bool,
+    method: &str,
+) -> PyResult<Option<NodeIndices>> {
+    hamiltonian_path(py, &graph.graph, cycle, method)
+}
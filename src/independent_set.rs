@@ -0,0 +1,450 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+
+use fixedbitset::FixedBitSet;
+use hashbrown::HashSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+use crate::clique::{maximum_clique, Adjacency};
+use crate::{graph, is_valid_weight, weight_callable, NodeIndex};
+
+/// Find an independent set with the minimum degree greedy heuristic,
+/// repeatedly adding the node with the fewest remaining neighbors and
+/// removing its neighbors from the graph. Nodes with a self loop are never
+/// added.
+fn greedy_independent_set(graph: &graph::PyGraph) -> HashSet<usize> {
+    let node_bound = graph.graph.node_bound();
+    let mut removed: Vec<bool> = vec![true; node_bound];
+    let mut degrees: Vec<usize> = vec![0; node_bound];
+    for node in graph.graph.node_indices() {
+        removed[node.index()] = false;
+    }
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if source == target {
+            removed[source] = true;
+        } else {
+            degrees[source] += 1;
+            degrees[target] += 1;
+        }
+    }
+    let mut heap: BinaryHeap<Reverse<(usize, usize)>> = graph
+        .graph
+        .node_indices()
+        .filter(|node| !removed[node.index()])
+        .map(|node| Reverse((degrees[node.index()], node.index())))
+        .collect();
+    let mut independent_set: HashSet<usize> = HashSet::new();
+    while let Some(Reverse((degree, node))) = heap.pop() {
+        // Skip the outdated entries of nodes whose degree decreased
+        if removed[node] || degree != degrees[node] {
+            continue;
+        }
+        independent_set.insert(node);
+        removed[node] = true;
+        for neighbor in graph.graph.neighbors(graph.graph.from_index(node)) {
+            let neighbor = neighbor.index();
+            if removed[neighbor] {
+                continue;
+            }
+            removed[neighbor] = true;
+            for next in graph.graph.neighbors(graph.graph.from_index(neighbor)) {
+                let next = next.index();
+                if !removed[next] {
+                    degrees[next] -= 1;
+                    heap.push(Reverse((degrees[next], next)));
+                }
+            }
+        }
+    }
+    independent_set
+}
+
+/// Find a maximum independent set of a graph
+///
+/// An independent set is a set of nodes of which no two are adjacent. By
+/// default this finds a maximal independent set with the minimum degree
+/// greedy heuristic, which repeatedly adds the node with the fewest
+/// remaining neighbors to the set and removes its neighbors from the graph.
+/// This is fast but the set found isn't necessarily maximum.
+///
+/// If ``exact`` is ``True`` a maximum independent set is found as a maximum
+/// clique of the complement of the graph with the branch and bound algorithm
+/// of :func:`~retworkx.max_clique`. The runtime is exponential in the worst
+/// case so this is only suitable for small graphs.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(7)
+///   print(retworkx.maximum_independent_set(graph, exact=True))
+///
+/// :param PyGraph graph: The undirected graph to find an independent set of.
+///     Nodes with a self loop are never in the independent set and parallel
+///     edges are ignored.
+/// :param bool exact: Whether to find a maximum independent set with an
+///     exact algorithm instead of the greedy heuristic, defaults to
+///     ``False``
+///
+/// :returns: The node indices of the independent set
+/// :rtype: set
+#[pyfunction(exact = "false")]
+#[pyo3(text_signature = "(graph, /, exact=False)")]
+pub fn maximum_independent_set(py: Python, graph: &graph::PyGraph, exact: bool) -> HashSet<usize> {
+    if !exact {
+        return greedy_independent_set(graph);
+    }
+    // Nodes with a self loop can't be in an independent set, the others are
+    // adjacent in the complement if they aren't adjacent in the graph.
+    let self_loops: HashSet<NodeIndex> = graph
+        .graph
+        .edge_references()
+        .filter(|edge| edge.source() == edge.target())
+        .map(|edge| edge.source())
+        .collect();
+    let nodes: Vec<NodeIndex> = graph
+        .graph
+        .node_indices()
+        .filter(|node| !self_loops.contains(node))
+        .collect();
+    let mut positions: Vec<Option<usize>> = vec![None; graph.graph.node_bound()];
+    for (position, node) in nodes.iter().enumerate() {
+        positions[node.index()] = Some(position);
+    }
+    let neighbors: Vec<FixedBitSet> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| {
+            let mut complement = FixedBitSet::with_capacity(nodes.len());
+            complement.insert_range(..);
+            complement.set(position, false);
+            for neighbor in graph.graph.neighbors(*node) {
+                if let Some(neighbor) = positions[neighbor.index()] {
+                    complement.set(neighbor, false);
+                }
+            }
+            complement
+        })
+        .collect();
+    let complement = Adjacency { nodes, neighbors };
+    let clique = py.allow_threads(|| maximum_clique(&complement));
+    clique
+        .into_iter()
+        .map(|node| complement.nodes[node].index())
+        .collect()
+}
+
+/// Find a minimum vertex cover of a graph with a 2-approximation
+///
+/// A vertex cover is a set of nodes which contains at least one endpoint of
+/// every edge, it's the complement of an independent set. This finds a
+/// maximal matching by adding the edges of the graph whose endpoints are
+/// both uncovered in edge index order and returns the endpoints of the
+/// matched edges. Any vertex cover contains an endpoint of each matched
+/// edge so the cover found has at most twice as many nodes as a minimum
+/// vertex cover.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(5)
+///   print(retworkx.minimum_vertex_cover(graph))
+///
+/// :param PyGraph graph: The undirected graph to find a vertex cover of. A
+///     node with a self loop is always in the vertex cover.
+///
+/// :returns: The node indices of the vertex cover
+/// :rtype: set
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn minimum_vertex_cover(graph: &graph::PyGraph) -> HashSet<usize> {
+    let mut cover: HashSet<usize> = HashSet::new();
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if !cover.contains(&source) && !cover.contains(&target) {
+            cover.insert(source);
+            cover.insert(target);
+        }
+    }
+    cover
+}
+
+/// Evaluate ``weight_fn`` for the payload of every node of ``graph``, indexed
+/// by node index.
+fn node_weights(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: &Option<PyObject>,
+) -> PyResult<Vec<f64>> {
+    let mut weights: Vec<f64> = vec![0.0; graph.graph.node_bound()];
+    for node in graph.graph.node_indices() {
+        let weight: f64 = weight_callable(py, weight_fn, &graph.graph[node], 1.0)?;
+        weights[node.index()] = is_valid_weight(weight)?;
+    }
+    Ok(weights)
+}
+
+/// Find a maximum weight independent set of a forest
+///
+/// The weight of an independent set is the sum of the weights of its nodes.
+/// Finding a maximum weight independent set is NP-hard in general, but on a
+/// forest it's solved exactly in linear time with dynamic programming over
+/// each tree, computing for every node the weight of the best set of its
+/// subtree both with and without the node in it.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4, weights=[5, 2, 2, 2])
+///   print(retworkx.tree_maximum_weight_independent_set(graph, weight_fn=float))
+///
+/// :param PyGraph graph: The forest to find an independent set of. Parallel
+///     edges are treated as a single edge.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a node, and returns its weight as
+///     a non-negative float. If not specified every node has a weight of
+///     ``1.0``, which finds a maximum independent set.
+///
+/// :returns: The node indices of the independent set. Nodes of weight ``0``
+///     are only in the set if it has no other maximum weight solution.
+/// :rtype: set
+///
+/// :raises ValueError: If the graph has a cycle or a self loop, or if a
+///     weight is negative or NaN
+#[pyfunction(weight_fn = "None")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None)")]
+pub fn tree_maximum_weight_independent_set(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<HashSet<usize>> {
+    let weights = node_weights(py, graph, &weight_fn)?;
+    let node_bound = graph.graph.node_bound();
+    let neighbors: Vec<BTreeSet<usize>> = (0..node_bound)
+        .map(|node| {
+            graph
+                .graph
+                .neighbors(NodeIndex::new(node))
+                .map(|neighbor| neighbor.index())
+                .collect()
+        })
+        .collect();
+
+    // Order the nodes of each tree by a depth first search from its lowest
+    // index node, so every node comes after its parent.
+    let mut parent: Vec<Option<usize>> = vec![None; node_bound];
+    let mut visited = FixedBitSet::with_capacity(node_bound);
+    let mut order: Vec<usize> = Vec::with_capacity(graph.graph.node_count());
+    for root in graph.graph.node_indices() {
+        if visited.put(root.index()) {
+            continue;
+        }
+        let mut stack: Vec<usize> = vec![root.index()];
+        while let Some(node) = stack.pop() {
+            order.push(node);
+            for &neighbor in &neighbors[node] {
+                if Some(neighbor) == parent[node] {
+                    continue;
+                }
+                if visited.put(neighbor) {
+                    return Err(PyValueError::new_err(format!(
+                        "The graph isn't a forest, it has a cycle through node {}",
+                        neighbor
+                    )));
+                }
+                parent[neighbor] = Some(node);
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    // The best weight of the subtree of each node with the node in the set
+    // and without it, accumulated from the leaves up.
+    let mut with_node: Vec<f64> = weights;
+    let mut without_node: Vec<f64> = vec![0.0; node_bound];
+    for &node in order.iter().rev() {
+        if let Some(parent) = parent[node] {
+            with_node[parent] += without_node[node];
+            without_node[parent] += with_node[node].max(without_node[node]);
+        }
+    }
+    let mut in_set = FixedBitSet::with_capacity(node_bound);
+    for &node in &order {
+        let parent_in_set = match parent[node] {
+            Some(parent) => in_set.contains(parent),
+            None => false,
+        };
+        if !parent_in_set && with_node[node] > without_node[node] {
+            in_set.insert(node);
+        }
+    }
+    Ok(in_set.ones().collect())
+}
+
+/// The number of the sorted ``values`` which are at most ``value``.
+fn count_at_most(values: &[f64], value: f64) -> usize {
+    match values.binary_search_by(|x| {
+        if *x <= value {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    }) {
+        Ok(position) => position,
+        Err(position) => position,
+    }
+}
+
+/// Find a maximum weight independent set of an interval graph
+///
+/// In an interval graph each node is a closed interval of the real line and
+/// two nodes are adjacent if their intervals intersect, so an independent
+/// set is a set of pairwise disjoint intervals. The interval of each node is
+/// read from its weight/data payload with ``interval_fn`` and a maximum
+/// weight set is found exactly with the weighted interval scheduling dynamic
+/// program in :math:`O(n \log n)` time after checking that the edges of the
+/// graph are exactly the pairs of intersecting intervals.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from([(0, 3, 2.0), (2, 5, 5.0), (4, 7, 4.0), (6, 9, 2.0)])
+///   graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+///   print(
+///       retworkx.interval_maximum_weight_independent_set(
+///           graph, lambda node: node[:2], weight_fn=lambda node: node[2]
+///       )
+///   )
+///
+/// :param PyGraph graph: The interval graph to find an independent set of.
+///     Parallel edges are treated as a single edge.
+/// :param interval_fn: A callable which takes a single positional argument,
+///     the weight/data payload of a node, and returns the ``(start, end)``
+///     tuple of floats of the node's interval, with ``start <= end``.
+///     Intervals which only share an endpoint intersect.
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a node, and returns its weight as
+///     a non-negative float. If not specified every node has a weight of
+///     ``1.0``, which finds a maximum independent set.
+///
+/// :returns: The node indices of the independent set
+/// :rtype: set
+///
+/// :raises ValueError: If an interval has its start after its end, if the
+///     graph isn't the interval graph of the intervals, or if a weight is
+///     negative or NaN
+#[pyfunction(weight_fn = "None")]
+#[pyo3(text_signature = "(graph, interval_fn, /, weight_fn=None)")]
+pub fn interval_maximum_weight_independent_set(
+    py: Python,
+    graph: &graph::PyGraph,
+    interval_fn: PyObject,
+    weight_fn: Option<PyObject>,
+) -> PyResult<HashSet<usize>> {
+    let weights = node_weights(py, graph, &weight_fn)?;
+    let mut intervals: Vec<(f64, f64)> = vec![(0.0, 0.0); graph.graph.node_bound()];
+    for node in graph.graph.node_indices() {
+        let (start, end): (f64, f64) = interval_fn.call1(py, (&graph.graph[node],))?.extract(py)?;
+        if start.is_nan() || end.is_nan() || start > end {
+            return Err(PyValueError::new_err(format!(
+                "Invalid interval ({}, {}) of node {}",
+                start,
+                end,
+                node.index()
+            )));
+        }
+        intervals[node.index()] = (start, end);
+    }
+
+    // The graph is the interval graph if each edge joins intersecting
+    // intervals and the number of adjacent pairs is the number of
+    // intersecting pairs.
+    let mut adjacent_pairs: HashSet<(usize, usize)> = HashSet::new();
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        let (source_start, source_end) = intervals[source];
+        let (target_start, target_end) = intervals[target];
+        if source == target || source_start.max(target_start) > source_end.min(target_end) {
+            return Err(PyValueError::new_err(format!(
+                "The graph isn't the interval graph of the intervals, the intervals of \
+                 the nodes {} and {} of edge {} don't intersect",
+                source,
+                target,
+                edge.id().index()
+            )));
+        }
+        adjacent_pairs.insert((source.min(target), source.max(target)));
+    }
+    let mut nodes: Vec<usize> = graph.graph.node_indices().map(|n| n.index()).collect();
+    nodes.sort_by(|a, b| intervals[*a].0.partial_cmp(&intervals[*b].0).unwrap());
+    let starts: Vec<f64> = nodes.iter().map(|node| intervals[*node].0).collect();
+    // An interval intersects each of the later intervals in start order
+    // which start before it ends.
+    let intersecting_pairs: usize = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| count_at_most(&starts, intervals[*node].1) - position - 1)
+        .sum();
+    if intersecting_pairs != adjacent_pairs.len() {
+        return Err(PyValueError::new_err(
+            "The graph isn't the interval graph of the intervals, it's missing edges \
+             between intersecting intervals",
+        ));
+    }
+
+    // Weighted interval scheduling over the intervals sorted by their end,
+    // best[i] is the weight of the best set of the first i intervals.
+    nodes.sort_by(|a, b| intervals[*a].1.partial_cmp(&intervals[*b].1).unwrap());
+    let ends: Vec<f64> = nodes.iter().map(|node| intervals[*node].1).collect();
+    let mut best: Vec<f64> = vec![0.0; nodes.len() + 1];
+    let mut compatible: Vec<usize> = vec![0; nodes.len()];
+    for (position, node) in nodes.iter().enumerate() {
+        let (start, _) = intervals[*node];
+        // The intervals ending strictly before this one starts
+        compatible[position] = match ends[..position].binary_search_by(|end| {
+            if *end < start {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Greater
+            }
+        }) {
+            Ok(count) => count,
+            Err(count) => count,
+        };
+        best[position + 1] = best[position].max(weights[*node] + best[compatible[position]]);
+    }
+    let mut in_set: HashSet<usize> = HashSet::new();
+    let mut position = nodes.len();
+    while position > 0 {
+        let node = nodes[position - 1];
+        if weights[node] + best[compatible[position - 1]] > best[position - 1] {
+            in_set.insert(node);
+            position = compatible[position - 1];
+        } else {
+            position -= 1;
+        }
+    }
+    Ok(in_set)
+}
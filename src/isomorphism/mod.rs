@@ -12,15 +12,20 @@
 
 #![allow(clippy::too_many_arguments)]
 
-mod vf2;
+pub mod vf2;
 
-use crate::{digraph, graph};
+use crate::graph_hashing::subgraph_hashes;
+use crate::{digraph, graph, StablePyGraph};
 
 use std::cmp::Ordering;
 
 use pyo3::prelude::*;
 use pyo3::Python;
 
+use petgraph::{Directed, EdgeType, Undirected};
+use rayon::prelude::*;
+use retworkx_core::dictmap::*;
+
 /// Determine if 2 directed graphs are isomorphic
 ///
 /// This checks if 2 graphs are isomorphic both structurally and also
@@ -443,3 +448,213 @@ pub fn graph_vf2_mapping(
         call_limit,
     )
 }
+
+/// Group ``graphs`` by isomorphism class, as lists of indices in ``graphs``
+/// in the order of their first graph.
+///
+/// The graphs are first bucketed by the sorted Weisfeiler-Lehman hashes of
+/// their nodes, which are equal for isomorphic graphs, and only graphs in the
+/// same bucket are compared with VF2. In each bucket the first graph without
+/// a class is compared to the rest of the graphs without a class in parallel
+/// and the ones isomorphic to it form its class.
+fn isomorphism_classes<Ty: EdgeType + Sync>(
+    py: Python,
+    graphs: &[&StablePyGraph<Ty>],
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    id_order: bool,
+    call_limit: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let mut buckets: DictMap<(usize, usize, Vec<String>), Vec<usize>> = DictMap::new();
+    for (index, graph) in graphs.iter().enumerate() {
+        let mut hashes: Vec<String> = subgraph_hashes(py, graph, 3, None, None)?
+            .into_iter()
+            .map(|(_, hash)| hash)
+            .collect();
+        hashes.sort_unstable();
+        buckets
+            .entry((graph.node_count(), graph.edge_count(), hashes))
+            .or_default()
+            .push(index);
+    }
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    for (_, mut remaining) in buckets {
+        while !remaining.is_empty() {
+            let representative = graphs[remaining[0]];
+            let candidates = &remaining[1..];
+            let matches: Vec<bool> = py.allow_threads(|| {
+                candidates
+                    .par_iter()
+                    .map(|index| {
+                        vf2::is_isomorphic_nogil(
+                            representative,
+                            graphs[*index],
+                            &node_matcher,
+                            &edge_matcher,
+                            id_order,
+                            call_limit,
+                        )
+                    })
+                    .collect::<PyResult<Vec<bool>>>()
+            })?;
+            let mut class = vec![remaining[0]];
+            let mut unmatched: Vec<usize> = Vec::new();
+            for (index, is_match) in candidates.iter().zip(matches) {
+                if is_match {
+                    class.push(*index);
+                } else {
+                    unmatched.push(*index);
+                }
+            }
+            classes.push(class);
+            remaining = unmatched;
+        }
+    }
+    classes.sort_unstable_by_key(|class| class[0]);
+    Ok(classes)
+}
+
+/// Group a list of :class:`~retworkx.PyDiGraph` objects into isomorphism
+/// classes
+///
+/// This compares all the graphs in ``graphs`` and groups the ones which are
+/// isomorphic, both structurally and comparing the node and edge data with
+/// the optional matcher functions as in :func:`~retworkx.is_isomorphic`.
+/// To avoid comparing every pair of graphs the graphs are first grouped by
+/// an isomorphism invariant (the sorted Weisfeiler-Lehman subtree hashes of
+/// their nodes, see :func:`~retworkx.digraph_weisfeiler_lehman_subgraph_hashes`)
+/// and only graphs with the same invariant are compared with the VF2
+/// algorithm. Each graph is compared to one graph of each candidate class
+/// and these comparisons are run in parallel. You can tune the number of
+/// threads with the ``RAYON_NUM_THREADS`` environment variable.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graphs = [
+///       retworkx.generators.directed_path_graph(3),
+///       retworkx.generators.directed_star_graph(3),
+///       retworkx.generators.directed_path_graph(3),
+///       retworkx.generators.directed_star_graph(3, inward=True),
+///   ]
+///   print(retworkx.digraph_isomorphism_classes(graphs))
+///
+/// :param list graphs: The list of :class:`~retworkx.PyDiGraph` objects to
+///     group
+/// :param callable node_matcher: An optional python callable object that
+///     takes 2 positional arguments, one for each node data object. If the
+///     return of this function evaluates to True then the nodes passed to it
+///     are viewed as matching.
+/// :param callable edge_matcher: An optional python callable object that
+///     takes 2 positional arguments, one for each edge data object. If the
+///     return of this function evaluates to True then the edges passed to it
+///     are viewed as matching.
+/// :param bool id_order: If set to ``False`` this function will use a
+///     heuristic matching order based on [VF2]_ paper. Otherwise it will
+///     default to matching the nodes in order specified by their ids.
+/// :param int call_limit: An optional bound on the number of states that VF2
+///     algorithm visits while searching for a solution for each pair of
+///     graphs. If it exceeds this limit the graphs are treated as not
+///     isomorphic.
+///
+/// :returns: A list of the isomorphism classes, each a list of the indices in
+///     ``graphs`` of its graphs in increasing order. The classes are ordered
+///     by their first index.
+/// :rtype: list
+#[pyfunction(id_order = "true")]
+#[pyo3(
+    text_signature = "(graphs, /, node_matcher=None, edge_matcher=None, id_order=True,
+                    call_limit=None)"
+)]
+pub fn digraph_isomorphism_classes(
+    py: Python,
+    graphs: Vec<PyRef<digraph::PyDiGraph>>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    id_order: bool,
+    call_limit: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let graphs: Vec<&StablePyGraph<Directed>> = graphs.iter().map(|graph| &*graph.graph).collect();
+    isomorphism_classes(
+        py,
+        &graphs,
+        node_matcher,
+        edge_matcher,
+        id_order,
+        call_limit,
+    )
+}
+
+/// Group a list of :class:`~retworkx.PyGraph` objects into isomorphism
+/// classes
+///
+/// This compares all the graphs in ``graphs`` and groups the ones which are
+/// isomorphic, both structurally and comparing the node and edge data with
+/// the optional matcher functions as in :func:`~retworkx.is_isomorphic`.
+/// To avoid comparing every pair of graphs the graphs are first grouped by
+/// an isomorphism invariant (the sorted Weisfeiler-Lehman subtree hashes of
+/// their nodes, see :func:`~retworkx.graph_weisfeiler_lehman_subgraph_hashes`)
+/// and only graphs with the same invariant are compared with the VF2
+/// algorithm. Each graph is compared to one graph of each candidate class
+/// and these comparisons are run in parallel. You can tune the number of
+/// threads with the ``RAYON_NUM_THREADS`` environment variable.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graphs = [
+///       retworkx.generators.path_graph(4),
+///       retworkx.generators.star_graph(4),
+///       retworkx.generators.cycle_graph(4),
+///       retworkx.generators.path_graph(4),
+///   ]
+///   print(retworkx.graph_isomorphism_classes(graphs))
+///
+/// :param list graphs: The list of :class:`~retworkx.PyGraph` objects to
+///     group
+/// :param callable node_matcher: An optional python callable object that
+///     takes 2 positional arguments, one for each node data object. If the
+///     return of this function evaluates to True then the nodes passed to it
+///     are viewed as matching.
+/// :param callable edge_matcher: An optional python callable object that
+///     takes 2 positional arguments, one for each edge data object. If the
+///     return of this function evaluates to True then the edges passed to it
+///     are viewed as matching.
+/// :param bool id_order: If set to ``False`` this function will use a
+///     heuristic matching order based on [VF2]_ paper. Otherwise it will
+///     default to matching the nodes in order specified by their ids.
+/// :param int call_limit: An optional bound on the number of states that VF2
+///     algorithm visits while searching for a solution for each pair of
+///     graphs. If it exceeds this limit the graphs are treated as not
+///     isomorphic.
+///
+/// :returns: A list of the isomorphism classes, each a list of the indices in
+///     ``graphs`` of its graphs in increasing order. The classes are ordered
+///     by their first index.
+/// :rtype: list
+#[pyfunction(id_order = "true")]
+#[pyo3(
+    text_signature = "(graphs, /, node_matcher=None, edge_matcher=None, id_order=True,
+                    call_limit=None)"
+)]
+pub fn graph_isomorphism_classes(
+    py: Python,
+    graphs: Vec<PyRef<graph::PyGraph>>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    id_order: bool,
+    call_limit: Option<usize>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let graphs: Vec<&StablePyGraph<Undirected>> =
+        graphs.iter().map(|graph| &*graph.graph).collect();
+    isomorphism_classes(
+        py,
+        &graphs,
+        node_matcher,
+        edge_matcher,
+        id_order,
+        call_limit,
+    )
+}
@@ -27,7 +27,6 @@ use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
 use pyo3::PyTraverseError;
 
-use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
 use petgraph::EdgeType;
 use petgraph::{Directed, Incoming, Outgoing, Undirected};
@@ -35,7 +34,7 @@ use petgraph::{Directed, Incoming, Outgoing, Undirected};
 use rayon::slice::ParallelSliceMut;
 
 use crate::iterators::NodeMap;
-use crate::StablePyGraph;
+use crate::{NodeIndex, StablePyGraph};
 
 /// Returns `true` if we can map every element of `xs` to a unique
 /// element of `ys` while using `matcher` func to compare two elements.
@@ -402,7 +401,9 @@ where
 
 trait SemanticMatcher<T> {
     fn enabled(&self) -> bool;
-    fn eq(&self, _: Python, _: &T, _: &T) -> PyResult<bool>;
+    /// Compare two weights, `py` is `None` when the search runs with the
+    /// GIL released.
+    fn eq(&self, py: Option<Python>, _: &T, _: &T) -> PyResult<bool>;
 }
 
 impl SemanticMatcher<PyObject> for Option<PyObject> {
@@ -411,9 +412,15 @@ impl SemanticMatcher<PyObject> for Option<PyObject> {
         self.is_some()
     }
     #[inline]
-    fn eq(&self, py: Python, a: &PyObject, b: &PyObject) -> PyResult<bool> {
-        let res = self.as_ref().unwrap().call1(py, (a, b))?;
-        res.is_true(py)
+    fn eq(&self, py: Option<Python>, a: &PyObject, b: &PyObject) -> PyResult<bool> {
+        let call = |py: Python| {
+            let res = self.as_ref().unwrap().call1(py, (a, b))?;
+            res.is_true(py)
+        };
+        match py {
+            Some(py) => call(py),
+            None => Python::with_gil(call),
+        }
     }
 }
 
@@ -423,7 +430,7 @@ impl SemanticMatcher<PyObject> for Option<PyObject> {
 /// graph isomorphism (graph structure and matching node and edge weights).
 ///
 /// The graphs should not be multigraphs.
-pub fn is_isomorphic<Ty: EdgeType>(
+pub fn is_isomorphic<Ty: EdgeType + Send>(
     py: Python,
     g0: &StablePyGraph<Ty>,
     g1: &StablePyGraph<Ty>,
@@ -449,6 +456,41 @@ pub fn is_isomorphic<Ty: EdgeType>(
     Ok(false)
 }
 
+/// [Graph] Return `true` if the graphs `g0` and `g1` are isomorphic.
+///
+/// This is for threads which don't hold the GIL, it's only acquired to set
+/// up the search and to call the semantic matchers.
+pub fn is_isomorphic_nogil<Ty: EdgeType>(
+    g0: &StablePyGraph<Ty>,
+    g1: &StablePyGraph<Ty>,
+    node_match: &Option<PyObject>,
+    edge_match: &Option<PyObject>,
+    id_order: bool,
+    call_limit: Option<usize>,
+) -> PyResult<bool> {
+    if g0.node_count() != g1.node_count() || g0.edge_count() != g1.edge_count() {
+        return Ok(false);
+    }
+    let mut vf2 = Python::with_gil(|py| {
+        Vf2Algorithm::new(
+            py,
+            g0,
+            g1,
+            node_match.as_ref().map(|matcher| matcher.clone_ref(py)),
+            edge_match.as_ref().map(|matcher| matcher.clone_ref(py)),
+            id_order,
+            Ordering::Equal,
+            true,
+            call_limit,
+        )
+    });
+    let result = vf2.search(None);
+    // Drop the copies of the graphs with the GIL held to release the
+    // references to their weights.
+    Python::with_gil(|_| drop(vf2));
+    Ok(result?.is_some())
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 enum OpenList {
     Out,
@@ -607,7 +649,7 @@ where
     }
 
     fn is_feasible(
-        py: Python,
+        py: Option<Python>,
         st: &mut [Vf2State<Ty>; 2],
         nodes: [NodeIndex; 2],
         node_match: &mut F,
@@ -859,7 +901,22 @@ where
     }
 
     /// Return Some(mapping) if isomorphism is decided, else None.
-    fn next(&mut self, py: Python) -> PyResult<Option<NodeMap>> {
+    ///
+    /// The GIL is released for the search unless a semantic matcher needs to
+    /// call into Python for every candidate pair.
+    fn next(&mut self, py: Python) -> PyResult<Option<NodeMap>>
+    where
+        Self: Send,
+    {
+        if self.node_match.enabled() || self.edge_match.enabled() {
+            self.search(Some(py))
+        } else {
+            py.allow_threads(|| self.search(None))
+        }
+    }
+
+    /// Run the search, `py` is `None` if it runs with the GIL released.
+    fn search(&mut self, py: Option<Python>) -> PyResult<Option<NodeMap>> {
         if (self.st[0]
             .graph
             .node_count()
@@ -999,6 +1056,12 @@ macro_rules! vf2_mapping_impl {
                 );
                 $name { vf2 }
             }
+
+            /// Return the next mapping found or ``None`` once there are no
+            /// more mappings.
+            pub fn next_mapping(&mut self, py: Python) -> PyResult<Option<NodeMap>> {
+                self.vf2.next(py)
+            }
         }
 
         #[pymethods]
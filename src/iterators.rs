@@ -19,6 +19,9 @@
 //  e.g `custom_vec_iter_impl!(MyReadOnlyType, data, (usize, f64), "Docs");`
 //      defines a new type named `MyReadOnlyType` that holds a vector called `data`
 //      of values `(usize, f64)`.
+//  Adding `set_ops` after the docstring also defines the set operations `union`,
+//  `intersection`, `difference`, `unique` and `sorted`, which requires `T` to
+//  implement the `SetItem` trait.
 //
 // :`custom_hash_map_iter_impl` holds a `DictMap<K, V>` and can be used as
 //  a read-only mapping/dict. To use it, you should specify the name of the new type,
@@ -39,15 +42,24 @@
 
 use std::collections::hash_map::DefaultHasher;
 use std::convert::TryInto;
-use std::hash::Hasher;
+use std::hash::{Hash, Hasher};
 
+use hashbrown::HashSet;
 use num_bigint::BigUint;
 use retworkx_core::dictmap::*;
 
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+
+use crate::{digraph, graph, weight_callable, NoEdgeBetweenNodes, NodeIndex, StablePyGraph};
+
 use pyo3::class::iter::IterNextOutput;
-use pyo3::exceptions::{PyIndexError, PyKeyError, PyNotImplementedError};
+use pyo3::exceptions::{
+    PyIndexError, PyKeyError, PyNotImplementedError, PyTypeError, PyValueError,
+};
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict, PySlice};
 use pyo3::PyTraverseError;
 
 macro_rules! last_type {
@@ -406,8 +418,94 @@ trait PyGCProtocol {
     fn __clear__(&mut self) {}
 }
 
-macro_rules! custom_vec_iter_impl {
-    ($name:ident, $data:ident, $T:ty, $doc:literal) => {
+/// Convert the contents of a custom return type, as a list, to a numpy
+/// array with ``numpy.asarray``.
+fn to_numpy_array(py: Python, data: PyObject, dtype: Option<PyObject>) -> PyResult<PyObject> {
+    let kwargs = [("dtype", dtype)].into_py_dict(py);
+    Ok(py
+        .import("numpy")?
+        .call_method("asarray", (data,), Some(kwargs))?
+        .into())
+}
+
+/// Merge two mappings into a new ``dict`` for the ``|`` operator, the values
+/// of ``second`` replace those of ``first`` for keys in both. If either isn't
+/// a mapping ``NotImplemented`` is returned, like for a ``dict``.
+fn merge_mappings(py: Python, first: &PyAny, second: &PyAny) -> PyResult<PyObject> {
+    if !first.hasattr("keys")? || !second.hasattr("keys")? {
+        return Ok(py.NotImplemented());
+    }
+    let out_dict = PyDict::new(py);
+    out_dict.call_method1("update", (first,))?;
+    out_dict.call_method1("update", (second,))?;
+    Ok(out_dict.into())
+}
+
+/// An item of a custom return type with set operations. Items are compared
+/// by their key: node and edge indices by the index and edges by their
+/// endpoints, ignoring the weight.
+trait SetItem: Clone {
+    type Key: Hash + Eq + Ord;
+
+    fn set_key(&self) -> Self::Key;
+
+    /// The key of a Python object, an item of the same type or, for an edge
+    /// with a weight, a tuple starting with its endpoints.
+    fn py_set_key(item: &PyAny) -> PyResult<Self::Key>;
+}
+
+impl SetItem for usize {
+    type Key = usize;
+
+    fn set_key(&self) -> usize {
+        *self
+    }
+
+    fn py_set_key(item: &PyAny) -> PyResult<usize> {
+        item.extract()
+    }
+}
+
+impl SetItem for (usize, usize) {
+    type Key = (usize, usize);
+
+    fn set_key(&self) -> (usize, usize) {
+        *self
+    }
+
+    fn py_set_key(item: &PyAny) -> PyResult<(usize, usize)> {
+        item.extract()
+    }
+}
+
+impl SetItem for (usize, usize, PyObject) {
+    type Key = (usize, usize);
+
+    fn set_key(&self) -> (usize, usize) {
+        (self.0, self.1)
+    }
+
+    fn py_set_key(item: &PyAny) -> PyResult<(usize, usize)> {
+        Ok((item.get_item(0)?.extract()?, item.get_item(1)?.extract()?))
+    }
+}
+
+/// The first occurrence of each item of ``data``, in order.
+fn set_unique<T: SetItem>(data: &[T]) -> Vec<T> {
+    let mut seen: HashSet<T::Key> = HashSet::with_capacity(data.len());
+    data.iter()
+        .filter(|item| seen.insert(item.set_key()))
+        .cloned()
+        .collect()
+}
+
+/// The keys of the items of the Python iterable ``other``.
+fn set_keys<T: SetItem>(other: &PyAny) -> PyResult<HashSet<T::Key>> {
+    other.iter()?.map(|item| T::py_set_key(item?)).collect()
+}
+
+macro_rules! custom_vec_iter_impl_body {
+    ($name:ident, $data:ident, $T:ty, $doc:literal, { $($methods:tt)* }) => {
         #[doc = $doc]
         #[pyclass(module = "retworkx")]
         #[derive(Clone)]
@@ -471,17 +569,77 @@ macro_rules! custom_vec_iter_impl {
                 Ok(self.$data.len())
             }
 
-            fn __getitem__(&self, idx: isize) -> PyResult<$T> {
+            fn __getitem__(&self, py: Python, idx: &PyAny) -> PyResult<PyObject> {
+                if let Ok(slice) = idx.downcast::<PySlice>() {
+                    let indices = slice.indices(self.$data.len().try_into().unwrap())?;
+                    let $data: Vec<$T> = (0..indices.slicelength)
+                        .map(|i| self.$data[(indices.start + i * indices.step) as usize].clone())
+                        .collect();
+                    return Ok(Py::new(py, $name { $data })?.into_py(py));
+                }
+                let idx: isize = idx.extract()?;
                 if idx.abs() >= self.$data.len().try_into().unwrap() {
                     Err(PyIndexError::new_err(format!("Invalid index, {}", idx)))
                 } else if idx < 0 {
                     let len = self.$data.len();
-                    Ok(self.$data[len - idx.abs() as usize].clone())
+                    Ok(self.$data[len - idx.abs() as usize].clone().into_py(py))
                 } else {
-                    Ok(self.$data[idx as usize].clone())
+                    Ok(self.$data[idx as usize].clone().into_py(py))
+                }
+            }
+
+            fn __contains__(&self, py: Python, value: &PyAny) -> PyResult<bool> {
+                for item in self.$data.iter() {
+                    if PyEq::eq(item, value, py)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+
+            /// Return the position of the first occurrence of ``value``
+            ///
+            /// :raises ValueError: If ``value`` isn't present
+            #[pyo3(text_signature = "(self, value, /)")]
+            fn index(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+                for (i, item) in self.$data.iter().enumerate() {
+                    if PyEq::eq(item, value, py)? {
+                        return Ok(i);
+                    }
                 }
+                Err(PyValueError::new_err(format!(
+                    "{} is not in {}",
+                    value.repr()?,
+                    stringify!($name)
+                )))
             }
 
+            /// Return the number of occurrences of ``value``
+            #[pyo3(text_signature = "(self, value, /)")]
+            fn count(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+                let mut count = 0;
+                for item in self.$data.iter() {
+                    if PyEq::eq(item, value, py)? {
+                        count += 1;
+                    }
+                }
+                Ok(count)
+            }
+
+            #[args(dtype = "None", copy = "None")]
+            fn __array__(
+                &self,
+                py: Python,
+                dtype: Option<PyObject>,
+                copy: Option<bool>,
+            ) -> PyResult<PyObject> {
+                // The contents are always copied to a new array
+                let _ = copy;
+                to_numpy_array(py, self.$data.clone().into_py(py), dtype)
+            }
+
+            $($methods)*
+
             fn __traverse__(&self, vis: PyVisit) -> Result<(), PyTraverseError> {
                 PyGCProtocol::__traverse__(self, vis)
             }
@@ -493,6 +651,98 @@ macro_rules! custom_vec_iter_impl {
     };
 }
 
+macro_rules! custom_vec_iter_impl {
+    ($name:ident, $data:ident, $T:ty, $doc:literal) => {
+        custom_vec_iter_impl_body!($name, $data, $T, $doc, {});
+    };
+    // A sequence of node indices or edges with set operations, ``$T`` has to
+    // implement ``SetItem``.
+    ($name:ident, $data:ident, $T:ty, $doc:literal, set_ops) => {
+        custom_vec_iter_impl_body!($name, $data, $T, $doc, {
+            /// Return the union with ``other``
+            ///
+            /// The result has the unique items of this object followed by
+            /// the items of ``other`` which aren't in it, in order.
+            ///
+            /// :param other: An iterable of items of the same type, for
+            ///     example another object of this type, a list or a set
+            ///
+            /// :returns: A new object with the union
+            #[pyo3(text_signature = "(self, other, /)")]
+            fn union(&self, other: &PyAny) -> PyResult<Self> {
+                let mut $data = set_unique(&self.$data);
+                let mut seen: HashSet<<$T as SetItem>::Key> =
+                    $data.iter().map(SetItem::set_key).collect();
+                for item in other.iter()? {
+                    let item: $T = item?.extract()?;
+                    if seen.insert(item.set_key()) {
+                        $data.push(item);
+                    }
+                }
+                Ok($name { $data })
+            }
+
+            /// Return the intersection with ``other``
+            ///
+            /// The result has the unique items of this object which are also
+            /// in ``other``, in order.
+            ///
+            /// :param other: An iterable of items of the same type, for
+            ///     example another object of this type, a list or a set
+            ///
+            /// :returns: A new object with the intersection
+            #[pyo3(text_signature = "(self, other, /)")]
+            fn intersection(&self, other: &PyAny) -> PyResult<Self> {
+                let other = set_keys::<$T>(other)?;
+                let $data = set_unique(&self.$data)
+                    .into_iter()
+                    .filter(|item| other.contains(&item.set_key()))
+                    .collect();
+                Ok($name { $data })
+            }
+
+            /// Return the difference with ``other``
+            ///
+            /// The result has the unique items of this object which aren't
+            /// in ``other``, in order.
+            ///
+            /// :param other: An iterable of items of the same type, for
+            ///     example another object of this type, a list or a set
+            ///
+            /// :returns: A new object with the difference
+            #[pyo3(text_signature = "(self, other, /)")]
+            fn difference(&self, other: &PyAny) -> PyResult<Self> {
+                let other = set_keys::<$T>(other)?;
+                let $data = set_unique(&self.$data)
+                    .into_iter()
+                    .filter(|item| !other.contains(&item.set_key()))
+                    .collect();
+                Ok($name { $data })
+            }
+
+            /// Return the unique items, keeping the first occurrence of each
+            ///
+            /// :returns: A new object with the unique items in order
+            #[pyo3(text_signature = "(self, /)")]
+            fn unique(&self) -> Self {
+                $name {
+                    $data: set_unique(&self.$data),
+                }
+            }
+
+            /// Return the items sorted
+            ///
+            /// :returns: A new object with the items in increasing order
+            #[pyo3(text_signature = "(self, /)")]
+            fn sorted(&self) -> Self {
+                let mut $data = self.$data.clone();
+                $data.sort_by_key(SetItem::set_key);
+                $name { $data }
+            }
+        });
+    };
+}
+
 custom_vec_iter_impl!(
     BFSSuccessors,
     bfs_successors,
@@ -573,7 +823,8 @@ custom_vec_iter_impl!(
         first_element = next(nodes_iter)
         second_element = next(nodes_iter)
 
-    "
+    ",
+    set_ops
 );
 impl PyGCProtocol for NodeIndices {}
 
@@ -611,7 +862,8 @@ custom_vec_iter_impl!(
         first_element = next(edges_iter)
         second_element = next(edges_iter)
 
-    "
+    ",
+    set_ops
 );
 impl PyGCProtocol for EdgeList {}
 
@@ -649,7 +901,8 @@ custom_vec_iter_impl!(
         first_element = next(edges_iter)
         second_element = next(edges_iter)
 
-    "
+    ",
+    set_ops
 );
 
 impl PyGCProtocol for WeightedEdgeList {
@@ -759,6 +1012,20 @@ macro_rules! py_iter_protocol_impl {
 
         #[pymethods]
         impl $name {
+            // Like the iterators of a dict this is pickled as an iterator
+            // over a list of the remaining items.
+            fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+                let remaining: Vec<$T> = self.$data[self.iter_pos..].to_vec();
+                Ok((
+                    py.import("builtins")?.getattr("iter")?.into(),
+                    (remaining.into_py(py),),
+                ))
+            }
+
+            fn __length_hint__(&self) -> usize {
+                self.$data.len() - self.iter_pos
+            }
+
             fn __iter__(slf: PyRef<Self>) -> Py<$name> {
                 slf.into()
             }
@@ -868,6 +1135,43 @@ macro_rules! custom_hash_map_iter_impl {
                 }
             }
 
+            /// Return the value for ``key`` if it's present, else ``default``
+            #[args(default = "None")]
+            #[pyo3(text_signature = "(self, key, default=None, /)")]
+            fn get(&self, py: Python, key: &PyAny, default: Option<PyObject>) -> PyObject {
+                let value = key
+                    .extract::<$K>()
+                    .ok()
+                    .and_then(|key| self.$data.get(&key).cloned());
+                match value {
+                    Some(value) => value.into_py(py),
+                    None => default.into_py(py),
+                }
+            }
+
+            fn __or__(slf: PyRef<Self>, other: &PyAny) -> PyResult<PyObject> {
+                let py = other.py();
+                merge_mappings(py, slf.into_py(py).as_ref(py), other)
+            }
+
+            fn __ror__(slf: PyRef<Self>, other: &PyAny) -> PyResult<PyObject> {
+                let py = other.py();
+                merge_mappings(py, other, slf.into_py(py).as_ref(py))
+            }
+
+            #[args(dtype = "None", copy = "None")]
+            fn __array__(
+                &self,
+                py: Python,
+                dtype: Option<PyObject>,
+                copy: Option<bool>,
+            ) -> PyResult<PyObject> {
+                // The values are always copied to a new array
+                let _ = copy;
+                let values: Vec<$V> = self.$data.values().cloned().collect();
+                to_numpy_array(py, values.into_py(py), dtype)
+            }
+
             fn __iter__(slf: PyRef<Self>) -> $nameKeys {
                 $nameKeys {
                     $keys: slf.$data.keys().copied().collect(),
@@ -949,6 +1253,68 @@ impl PyGCProtocol for EdgeIndexMap {
     }
 }
 
+/// The edges along ``path`` in ``graph`` and their total weight. Of parallel
+/// edges between two consecutive nodes the one with the lowest weight is
+/// used, the one with the lowest index on ties.
+fn path_edges<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    path: &[usize],
+    weight_fn: &Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<(Vec<usize>, f64)> {
+    let mut edges: Vec<usize> = Vec::with_capacity(path.len().saturating_sub(1));
+    let mut total_weight = 0.;
+    for pair in path.windows(2) {
+        let mut best: Option<(f64, usize)> = None;
+        let target = NodeIndex::new(pair[1]);
+        for edge in graph
+            .edges(NodeIndex::new(pair[0]))
+            .filter(|edge| edge.target() == target)
+        {
+            let weight: f64 = weight_callable(py, weight_fn, edge.weight(), default_weight)?;
+            let index = edge.id().index();
+            best = match best {
+                Some((best_weight, best_index))
+                    if best_weight < weight || (best_weight == weight && best_index < index) =>
+                {
+                    Some((best_weight, best_index))
+                }
+                _ => Some((weight, index)),
+            };
+        }
+        match best {
+            Some((weight, index)) => {
+                edges.push(index);
+                total_weight += weight;
+            }
+            None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
+        }
+    }
+    Ok((edges, total_weight))
+}
+
+/// [`path_edges`] for a :class:`~retworkx.PyGraph` or
+/// :class:`~retworkx.PyDiGraph` Python object.
+fn py_path_edges(
+    py: Python,
+    graph: &PyAny,
+    path: &[usize],
+    weight_fn: &Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<(Vec<usize>, f64)> {
+    if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+        path_edges(py, &graph.graph, path, weight_fn, default_weight)
+    } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+        path_edges(py, &graph.graph, path, weight_fn, default_weight)
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "Invalid Input Type {} for graph",
+            graph.get_type()
+        )))
+    }
+}
+
 /// A custom class for the return of paths to target nodes
 ///
 /// The class is a read-only mapping of node indices to a list of node indices
@@ -986,6 +1352,15 @@ pub struct PathMapping {
     pub paths: DictMap<usize, Vec<usize>>,
 }
 
+impl PathMapping {
+    fn path(&self, target: usize) -> PyResult<&[usize]> {
+        match self.paths.get(&target) {
+            Some(path) => Ok(path),
+            None => Err(PyIndexError::new_err("No node found for index")),
+        }
+    }
+}
+
 #[pymethods]
 impl PathMapping {
     #[new]
@@ -1075,6 +1450,141 @@ impl PathMapping {
         Ok(self.paths.contains_key(&index))
     }
 
+    /// Return the path to ``key`` if it's present, else ``default``
+    #[args(default = "None")]
+    #[pyo3(text_signature = "(self, key, default=None, /)")]
+    fn get(&self, py: Python, key: &PyAny, default: Option<PyObject>) -> PyObject {
+        let path = key
+            .extract::<usize>()
+            .ok()
+            .and_then(|key| self.paths.get(&key));
+        match path {
+            Some(path) => NodeIndices {
+                nodes: path.clone(),
+            }
+            .into_py(py),
+            None => default.into_py(py),
+        }
+    }
+
+    /// Return the indices of the edges along the path to ``target``
+    ///
+    /// Of parallel edges between two consecutive nodes of the path the edge
+    /// with the lowest weight is used, with ties broken by the lowest edge
+    /// index.
+    ///
+    /// :param graph: The :class:`~retworkx.PyGraph` or
+    ///     :class:`~retworkx.PyDiGraph` the paths were computed on
+    /// :param int target: The node index of the target of the path
+    /// :param weight_fn: An optional callable which will be passed the data
+    ///     payload of an edge and returns its weight as a float, used to pick
+    ///     between parallel edges. If it isn't specified every edge has the
+    ///     weight ``default_weight``.
+    /// :param float default_weight: The weight of every edge if ``weight_fn``
+    ///     isn't specified, by default ``1.0``
+    ///
+    /// :returns: The indices of the edges along the path in order
+    /// :rtype: EdgeIndices
+    ///
+    /// :raises IndexError: If there is no path to ``target``
+    /// :raises NoEdgeBetweenNodes: If two consecutive nodes of the path aren't
+    ///     connected by an edge of ``graph``
+    #[args(weight_fn = "None", default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, graph, target, /, weight_fn=None, default_weight=1.0)")]
+    fn edge_indices(
+        &self,
+        py: Python,
+        graph: &PyAny,
+        target: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<EdgeIndices> {
+        let (edges, _) = py_path_edges(py, graph, self.path(target)?, &weight_fn, default_weight)?;
+        Ok(EdgeIndices { edges })
+    }
+
+    /// Return the total weight of the path to ``target``
+    ///
+    /// Of parallel edges between two consecutive nodes of the path the
+    /// weight of the edge with the lowest weight is used.
+    ///
+    /// :param graph: The :class:`~retworkx.PyGraph` or
+    ///     :class:`~retworkx.PyDiGraph` the paths were computed on
+    /// :param int target: The node index of the target of the path
+    /// :param weight_fn: An optional callable which will be passed the data
+    ///     payload of an edge and returns its weight as a float. If it isn't
+    ///     specified every edge has the weight ``default_weight``.
+    /// :param float default_weight: The weight of every edge if ``weight_fn``
+    ///     isn't specified, by default ``1.0``
+    ///
+    /// :returns: The sum of the weights of the edges along the path
+    /// :rtype: float
+    ///
+    /// :raises IndexError: If there is no path to ``target``
+    /// :raises NoEdgeBetweenNodes: If two consecutive nodes of the path aren't
+    ///     connected by an edge of ``graph``
+    #[args(weight_fn = "None", default_weight = "1.0")]
+    #[pyo3(text_signature = "(self, graph, target, /, weight_fn=None, default_weight=1.0)")]
+    fn path_weight(
+        &self,
+        py: Python,
+        graph: &PyAny,
+        target: usize,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<f64> {
+        let (_, weight) = py_path_edges(py, graph, self.path(target)?, &weight_fn, default_weight)?;
+        Ok(weight)
+    }
+
+    /// Return the subgraph induced by the nodes of the path to ``target``
+    ///
+    /// :param graph: The :class:`~retworkx.PyGraph` or
+    ///     :class:`~retworkx.PyDiGraph` the paths were computed on
+    /// :param int target: The node index of the target of the path
+    ///
+    /// :returns: The subgraph of ``graph`` with the nodes of the path and
+    ///     all the edges between them, of the same type as ``graph``
+    ///
+    /// :raises IndexError: If there is no path to ``target``
+    #[pyo3(text_signature = "(self, graph, target, /)")]
+    fn subgraph(&self, py: Python, graph: &PyAny, target: usize) -> PyResult<PyObject> {
+        let nodes = self.path(target)?.to_vec();
+        if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+            Ok(graph.subgraph(py, nodes).into_py(py))
+        } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+            Ok(graph.subgraph(py, nodes).into_py(py))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Invalid Input Type {} for graph",
+                graph.get_type()
+            )))
+        }
+    }
+
+    fn __or__(slf: PyRef<Self>, other: &PyAny) -> PyResult<PyObject> {
+        let py = other.py();
+        merge_mappings(py, slf.into_py(py).as_ref(py), other)
+    }
+
+    fn __ror__(slf: PyRef<Self>, other: &PyAny) -> PyResult<PyObject> {
+        let py = other.py();
+        merge_mappings(py, other, slf.into_py(py).as_ref(py))
+    }
+
+    #[args(dtype = "None", copy = "None")]
+    fn __array__(
+        &self,
+        py: Python,
+        dtype: Option<PyObject>,
+        copy: Option<bool>,
+    ) -> PyResult<PyObject> {
+        // The paths are always copied to a new array
+        let _ = copy;
+        let paths: Vec<Vec<usize>> = self.paths.values().cloned().collect();
+        to_numpy_array(py, paths.into_py(py), dtype)
+    }
+
     fn __iter__(slf: PyRef<Self>) -> PathMappingKeys {
         PathMappingKeys {
             path_keys: slf.paths.keys().copied().collect(),
@@ -1338,6 +1848,54 @@ custom_hash_map_iter_impl!(
 );
 impl PyGCProtocol for NodeMap {}
 
+custom_hash_map_iter_impl!(
+    EdgeMap,
+    EdgeMapKeys,
+    EdgeMapValues,
+    EdgeMapItems,
+    edge_map,
+    edge_map_keys,
+    edge_map_values,
+    edge_map_items,
+    usize,
+    usize,
+    "A class representing a mapping of edge indices to edge indices
+
+     This class is equivalent to having a dict of the form::
+
+         {1: 0, 3: 1}
+
+    Unlike a dict though this class is unordered and multiple EdgeMap
+    objects with the same contents might yield a different order when
+    iterated over. If a consistent order is required you should sort
+    the object.
+    "
+);
+impl PyGCProtocol for EdgeMap {}
+
+custom_hash_map_iter_impl!(
+    EdgeFlowMapping,
+    EdgeFlowMappingKeys,
+    EdgeFlowMappingValues,
+    EdgeFlowMappingItems,
+    flows,
+    flows_keys,
+    flows_values,
+    flows_items,
+    usize,
+    f64,
+    "A class representing a mapping of edge indices to the flow along that edge
+
+     This class is equivalent to having a dict of the form::
+
+         {0: 1.0, 1: 0.5}
+
+    It implements the Python mapping protocol so you can treat the return as
+    a read-only mapping/dict.
+    "
+);
+impl PyGCProtocol for EdgeFlowMapping {}
+
 custom_hash_map_iter_impl!(
     ProductNodeMap,
     ProductNodeMapKeys,
@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::mem;
+
+use hashbrown::{HashMap, HashSet};
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::gc::PyVisit;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::PyTraverseError;
+use pyo3::Python;
+
+/// The indices of the nodes or edges of a graph which changed since its key
+/// index was last updated. Changes are only tracked while there's a key index.
+#[derive(Default)]
+pub struct ChangeTracker {
+    enabled: bool,
+    all: bool,
+    indices: Vec<usize>,
+}
+
+impl ChangeTracker {
+    /// Record a change to ``index``. Once more changes are pending than there
+    /// are indices (``bound``) the whole index is rebuilt instead.
+    pub fn touch(&mut self, index: usize, bound: usize) {
+        if !self.enabled || self.all {
+            return;
+        }
+        if self.indices.len() > bound {
+            self.touch_all();
+        } else {
+            self.indices.push(index);
+        }
+    }
+
+    pub fn touch_all(&mut self) {
+        if self.enabled {
+            self.all = true;
+            self.indices.clear();
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn enable(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.all = false;
+        self.indices.clear();
+    }
+
+    /// Take the pending changes, returning ``None`` if everything changed.
+    fn take(&mut self) -> Option<Vec<usize>> {
+        if mem::replace(&mut self.all, false) {
+            return None;
+        }
+        let mut indices = mem::take(&mut self.indices);
+        indices.sort_unstable();
+        indices.dedup();
+        Some(indices)
+    }
+}
+
+/// A hash index from a key computed from the weight/data payloads of the
+/// nodes or edges of a graph to their indices. Without a ``key_fn`` the
+/// payloads themselves are the keys.
+pub struct KeyIndex {
+    key_fn: Option<PyObject>,
+    /// The position in ``members`` of each key.
+    slots: Py<PyDict>,
+    /// The key of each slot and the indices with that key.
+    members: Vec<(Option<PyObject>, HashSet<usize>)>,
+    free_slots: Vec<usize>,
+    /// The slot of each indexed node or edge.
+    index_slot: HashMap<usize, usize>,
+}
+
+impl KeyIndex {
+    pub fn new(py: Python, key_fn: Option<PyObject>) -> Self {
+        KeyIndex {
+            key_fn,
+            slots: PyDict::new(py).into(),
+            members: Vec::new(),
+            free_slots: Vec::new(),
+            index_slot: HashMap::new(),
+        }
+    }
+
+    /// Whether the keys are the weight/data payloads themselves.
+    pub fn by_payload(&self) -> bool {
+        self.key_fn.is_none()
+    }
+
+    fn insert(&mut self, py: Python, index: usize, weight: &PyObject) -> PyResult<()> {
+        let key = match &self.key_fn {
+            Some(key_fn) => key_fn.call1(py, (weight,))?,
+            None => weight.clone_ref(py),
+        };
+        let slots = self.slots.as_ref(py);
+        let slot = match slots.get_item(&key) {
+            Some(slot) => slot.extract()?,
+            None => {
+                let slot = match self.free_slots.pop() {
+                    Some(slot) => slot,
+                    None => {
+                        self.members.push((None, HashSet::new()));
+                        self.members.len() - 1
+                    }
+                };
+                slots.set_item(&key, slot)?;
+                self.members[slot].0 = Some(key);
+                slot
+            }
+        };
+        self.members[slot].1.insert(index);
+        self.index_slot.insert(index, slot);
+        Ok(())
+    }
+
+    fn remove(&mut self, py: Python, index: usize) -> PyResult<()> {
+        if let Some(slot) = self.index_slot.remove(&index) {
+            let (key, members) = &mut self.members[slot];
+            members.remove(&index);
+            if members.is_empty() {
+                if let Some(key) = key.take() {
+                    self.slots.as_ref(py).del_item(key)?;
+                }
+                self.free_slots.push(slot);
+            }
+        }
+        Ok(())
+    }
+
+    /// Bring the index up to date with the changes recorded by ``tracker``.
+    /// ``weights`` returns the weight/data payload at an index, if there's a
+    /// node or edge there, and ``indices`` returns all of them. If ``key_fn``
+    /// raises an error the whole index is rebuilt on the next update.
+    pub fn update<'a, W, I>(
+        &mut self,
+        py: Python,
+        tracker: &mut ChangeTracker,
+        weights: W,
+        mut indices: I,
+    ) -> PyResult<()>
+    where
+        W: Fn(usize) -> Option<&'a PyObject>,
+        I: Iterator<Item = (usize, &'a PyObject)>,
+    {
+        let res = match tracker.take() {
+            None => {
+                self.slots = PyDict::new(py).into();
+                self.members.clear();
+                self.free_slots.clear();
+                self.index_slot.clear();
+                indices.try_for_each(|(index, weight)| self.insert(py, index, weight))
+            }
+            Some(changed) => changed.into_iter().try_for_each(|index| {
+                self.remove(py, index)?;
+                match weights(index) {
+                    Some(weight) => self.insert(py, index, weight),
+                    None => Ok(()),
+                }
+            }),
+        };
+        if res.is_err() {
+            tracker.touch_all();
+        }
+        res
+    }
+
+    /// The sorted indices with a given key, the index must be up to date.
+    pub fn get(&self, py: Python, key: PyObject) -> PyResult<Vec<usize>> {
+        // PyDict::get_item() hides the error for an unhashable key.
+        key.as_ref(py).hash()?;
+        let slot: Option<usize> = match self.slots.as_ref(py).get_item(key) {
+            Some(slot) => Some(slot.extract()?),
+            None => None,
+        };
+        let mut indices: Vec<usize> = match slot {
+            Some(slot) => self.members[slot].1.iter().copied().collect(),
+            None => Vec::new(),
+        };
+        indices.sort_unstable();
+        Ok(indices)
+    }
+
+    fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(key_fn) = &self.key_fn {
+            visit.call(key_fn)?;
+        }
+        visit.call(&self.slots)?;
+        for key in self.members.iter().filter_map(|(key, _)| key.as_ref()) {
+            visit.call(key)?;
+        }
+        Ok(())
+    }
+}
+
+/// The key indexes of the nodes and edges of a graph.
+#[derive(Default)]
+pub struct KeyIndexes {
+    pub nodes: Option<KeyIndex>,
+    pub edges: Option<KeyIndex>,
+}
+
+// A copy of a graph doesn't inherit the key indexes of the original, like
+// its open transactions.
+impl Clone for KeyIndexes {
+    fn clone(&self) -> Self {
+        KeyIndexes::default()
+    }
+}
+
+impl KeyIndexes {
+    pub fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(index) = &self.nodes {
+            index.traverse(visit)?;
+        }
+        if let Some(index) = &self.edges {
+            index.traverse(visit)?;
+        }
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes = None;
+        self.edges = None;
+    }
+}
+
+pub fn not_indexed(kind: &str) -> PyErr {
+    PyRuntimeError::new_err(format!(
+        "The {kind}s are not indexed, call index_{kind}s_by() first",
+        kind = kind
+    ))
+}
@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+
+use crate::{graph, weight_callable};
+
+/// The edges of ``graph`` as ``(source, target, weight)`` tuples of node
+/// indices, without self loops which don't change the Laplacian.
+pub fn laplacian_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: &Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<(usize, usize, f64)>> {
+    let mut edges: Vec<(usize, usize, f64)> = Vec::with_capacity(graph.graph.edge_count());
+    for edge in graph.graph.edge_references() {
+        let weight = weight_callable(py, weight_fn, edge.weight(), default_weight)?;
+        if weight.is_nan() || weight < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "Edge weight {} is negative or NaN",
+                weight
+            )));
+        }
+        if edge.source() != edge.target() {
+            edges.push((edge.source().index(), edge.target().index(), weight));
+        }
+    }
+    Ok(edges)
+}
+
+/// The Laplacian matrix of a graph stored as the weighted degree and the
+/// neighbors of each node by node index.
+pub struct Laplacian {
+    degree: Vec<f64>,
+    neighbors: Vec<Vec<(usize, f64)>>,
+}
+
+impl Laplacian {
+    pub fn new(node_bound: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut laplacian = Laplacian {
+            degree: vec![0.0; node_bound],
+            neighbors: vec![Vec::new(); node_bound],
+        };
+        for (source, target, weight) in edges {
+            laplacian.degree[*source] += weight;
+            laplacian.degree[*target] += weight;
+            laplacian.neighbors[*source].push((*target, *weight));
+            laplacian.neighbors[*target].push((*source, *weight));
+        }
+        laplacian
+    }
+
+    pub fn node_bound(&self) -> usize {
+        self.degree.len()
+    }
+
+    /// Label each node index with the smallest node index in its connected
+    /// component.
+    pub fn components(&self) -> Vec<usize> {
+        let mut labels = vec![usize::MAX; self.node_bound()];
+        let mut stack: Vec<usize> = Vec::new();
+        for start in 0..self.node_bound() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            labels[start] = start;
+            stack.push(start);
+            while let Some(node) = stack.pop() {
+                for (neighbor, _) in &self.neighbors[node] {
+                    if labels[*neighbor] == usize::MAX {
+                        labels[*neighbor] = start;
+                        stack.push(*neighbor);
+                    }
+                }
+            }
+        }
+        labels
+    }
+
+    pub fn multiply(&self, vector: &[f64], out: &mut [f64]) {
+        for (i, neighbors) in self.neighbors.iter().enumerate() {
+            out[i] = self.degree[i] * vector[i]
+                - neighbors
+                    .iter()
+                    .map(|(j, weight)| weight * vector[*j])
+                    .sum::<f64>();
+        }
+    }
+
+    /// Solve ``L x = b`` with the conjugate gradient method preconditioned
+    /// by the degrees. ``b`` has to sum to zero over each connected
+    /// component, then the solution is unique up to adding a constant to
+    /// each component.
+    pub fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = b.len();
+        let precondition = |r: &[f64]| -> Vec<f64> {
+            r.iter()
+                .zip(&self.degree)
+                .map(|(r, degree)| if *degree > 0.0 { r / degree } else { 0.0 })
+                .collect()
+        };
+        let tolerance = 1e-10 * dot(b, b).sqrt();
+        let mut x = vec![0.0; n];
+        let mut r = b.to_vec();
+        let mut z = precondition(&r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+        let mut ap = vec![0.0; n];
+        for _ in 0..4 * n + 100 {
+            if dot(&r, &r).sqrt() <= tolerance {
+                break;
+            }
+            self.multiply(&p, &mut ap);
+            let pap = dot(&p, &ap);
+            if pap <= 0.0 {
+                break;
+            }
+            let alpha = rz / pap;
+            for i in 0..n {
+                x[i] += alpha * p[i];
+                r[i] -= alpha * ap[i];
+            }
+            z = precondition(&r);
+            let next_rz = dot(&r, &z);
+            let beta = next_rz / rz;
+            rz = next_rz;
+            for i in 0..n {
+                p[i] = z[i] + beta * p[i];
+            }
+        }
+        x
+    }
+}
+
+fn dot(first: &[f64], second: &[f64]) -> f64 {
+    first.iter().zip(second).map(|(a, b)| a * b).sum()
+}
@@ -13,9 +13,9 @@
 use petgraph::EdgeType;
 
 use rand::prelude::*;
-use rand_pcg::Pcg64;
 
 use crate::iterators::Pos2DMapping;
+use crate::random_seed::seeded_rng;
 use crate::StablePyGraph;
 
 pub fn random_layout<Ty: EdgeType>(
@@ -23,10 +23,7 @@ pub fn random_layout<Ty: EdgeType>(
     center: Option<[f64; 2]>,
     seed: Option<u64>,
 ) -> Pos2DMapping {
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng = seeded_rng(seed);
 
     Pos2DMapping {
         pos_map: graph
@@ -20,15 +20,14 @@ use hashbrown::{HashMap, HashSet};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
 use petgraph::EdgeType;
 
 use rand::distributions::{Distribution, Uniform};
-use rand::prelude::*;
-use rand_pcg::Pcg64;
 
+use crate::random_seed::seeded_rng;
+use crate::NodeIndex;
 use crate::StablePyGraph;
 
 type Nt = f64;
@@ -314,16 +313,13 @@ pub fn spring_layout<Ty>(
     seed: Option<u64>,
 ) -> PyResult<Pos2DMapping>
 where
-    Ty: EdgeType,
+    Ty: EdgeType + Sync,
 {
     if fixed.is_some() && pos.is_none() {
         return Err(PyValueError::new_err("`fixed` specified but `pos` not."));
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng = seeded_rng(seed);
 
     let dist = Uniform::new(0.0, 1.0);
 
@@ -354,7 +350,9 @@ where
         weights.insert((target, source), w);
     }
 
-    let pos = match adaptive_cooling {
+    // The iterations don't touch any Python objects so other Python threads
+    // can run while the layout is computed.
+    let pos = py.allow_threads(|| match adaptive_cooling {
         Some(false) => {
             let cs = LinearCoolingScheme::new(step, num_iter);
             evolve(
@@ -367,7 +365,7 @@ where
                 graph, vpos, fixed, f_a, f_r, cs, num_iter, tol, weights, scale, center,
             )
         }
-    };
+    });
 
     Ok(Pos2DMapping {
         pos_map: graph
@@ -0,0 +1,443 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::prelude::*;
+
+use petgraph::algo;
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+
+use crate::digraph::PyDiGraph;
+use crate::{DAGHasCycle, InvalidNode, NodeIndex};
+
+/// Return an error if ``node`` isn't a node of ``graph``.
+fn check_node(graph: &PyDiGraph, node: usize) -> PyResult<()> {
+    if graph.graph.contains_node(NodeIndex::new(node)) {
+        Ok(())
+    } else {
+        Err(InvalidNode::new_err(format!(
+            "Node index {} is not present in the graph",
+            node
+        )))
+    }
+}
+
+/// Return the nodes of ``graph`` in topological order, or an error if it
+/// has a cycle.
+fn topological_order(graph: &PyDiGraph) -> PyResult<Vec<usize>> {
+    match algo::toposort(&graph.graph, None) {
+        Ok(nodes) => Ok(nodes.into_iter().map(|node| node.index()).collect()),
+        Err(_err) => Err(DAGHasCycle::new_err("The graph has a cycle")),
+    }
+}
+
+/// The children of each node by node index if the acyclic ``graph`` is a
+/// forest of rooted trees, every node has at most one parent, and the roots
+/// of the trees.
+fn forest_children(graph: &PyDiGraph) -> Option<(Vec<Vec<usize>>, Vec<usize>)> {
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); graph.graph.node_bound()];
+    let mut roots = Vec::new();
+    for node in graph.graph.node_indices() {
+        let mut parents = graph.graph.edges_directed(node, Direction::Incoming);
+        match (parents.next(), parents.next()) {
+            (None, _) => roots.push(node.index()),
+            (Some(edge), None) => children[edge.source().index()].push(node.index()),
+            (Some(_), Some(_)) => return None,
+        }
+    }
+    Some((children, roots))
+}
+
+/// The ancestors of ``node``, including itself, as a set of positions in
+/// the topological order of the graph.
+fn dag_ancestors(graph: &PyDiGraph, position: &[usize], node: usize) -> FixedBitSet {
+    let mut ancestors = FixedBitSet::with_capacity(graph.graph.node_count());
+    ancestors.insert(position[node]);
+    let mut stack = vec![NodeIndex::new(node)];
+    while let Some(node) = stack.pop() {
+        for parent in graph.graph.neighbors_directed(node, Direction::Incoming) {
+            if !ancestors.put(position[parent.index()]) {
+                stack.push(parent);
+            }
+        }
+    }
+    ancestors
+}
+
+/// The last position in the topological order that is in both sets of
+/// ancestors, which is a lowest common ancestor as none of its descendants
+/// come before it.
+fn last_common_position(first: &FixedBitSet, second: &FixedBitSet) -> Option<usize> {
+    first
+        .as_slice()
+        .iter()
+        .zip(second.as_slice())
+        .enumerate()
+        .rev()
+        .find_map(|(block, (first, second))| {
+            let common = first & second;
+            if common == 0 {
+                None
+            } else {
+                Some(block * 32 + 31 - common.leading_zeros() as usize)
+            }
+        })
+}
+
+/// A disjoint set forest over node indices with path halving.
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(size: usize) -> Self {
+        DisjointSet {
+            parents: (0..size).collect(),
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parents[node] != node {
+            self.parents[node] = self.parents[self.parents[node]];
+            node = self.parents[node];
+        }
+        node
+    }
+
+    fn union(&mut self, root: usize, node: usize) {
+        let (root, node) = (self.find(root), self.find(node));
+        self.parents[node] = root;
+    }
+}
+
+/// Tarjan's offline lowest common ancestor algorithm on a forest.
+fn forest_lowest_common_ancestors(
+    children: &[Vec<usize>],
+    roots: &[usize],
+    pairs: &[(usize, usize)],
+) -> Vec<Option<usize>> {
+    let mut answers: Vec<Option<usize>> = vec![None; pairs.len()];
+    let mut queries: Vec<Vec<(usize, usize)>> = vec![Vec::new(); children.len()];
+    for (index, (first, second)) in pairs.iter().enumerate() {
+        if first == second {
+            answers[index] = Some(*first);
+        } else {
+            queries[*first].push((*second, index));
+            queries[*second].push((*first, index));
+        }
+    }
+    let mut sets = DisjointSet::new(children.len());
+    let mut ancestor: Vec<usize> = (0..children.len()).collect();
+    let mut tree: Vec<Option<usize>> = vec![None; children.len()];
+    let mut done = vec![false; children.len()];
+    for root in roots {
+        tree[*root] = Some(*root);
+        let mut stack: Vec<(usize, usize)> = vec![(*root, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            if let Some(child) = children[node].get(*next) {
+                *next += 1;
+                tree[*child] = Some(*root);
+                stack.push((*child, 0));
+                continue;
+            }
+            stack.pop();
+            done[node] = true;
+            for (other, index) in &queries[node] {
+                if done[*other] && tree[*other] == tree[node] {
+                    answers[*index] = Some(ancestor[sets.find(*other)]);
+                }
+            }
+            if let Some((parent, _)) = stack.last() {
+                sets.union(*parent, node);
+                let set = sets.find(*parent);
+                ancestor[set] = *parent;
+            }
+        }
+    }
+    answers
+}
+
+/// Find the lowest common ancestors of pairs of nodes
+///
+/// The lowest common ancestor of two nodes is a node that is an ancestor of
+/// both of them and has no descendant that is also an ancestor of both.
+/// A node is considered its own ancestor, so the lowest common ancestor of
+/// a node and one of its descendants is the node itself.
+///
+/// All the pairs are answered together in a single pass. If the graph is a
+/// forest of rooted trees, where every node has at most one incoming edge,
+/// this uses Tarjan's offline algorithm which runs in
+/// :math:`O((n + q) \alpha(n))` time for :math:`q` pairs. For any other
+/// directed acyclic graph the ancestors of every node in the pairs are
+/// searched, and as two nodes may then have several lowest common
+/// ancestors, the last of them in topological order is returned. To answer
+/// many queries on the same graph as they come up use :class:`~.LCAIndex`
+/// instead.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_binomial_tree_graph(3)
+///   print(retworkx.lowest_common_ancestors(graph, [(3, 5), (6, 7), (2, 4)]))
+///
+/// :param PyDiGraph graph: The directed acyclic graph to search
+/// :param list pairs: A list of tuples of node indices to find the lowest
+///     common ancestors of
+///
+/// :returns: A list with the index of the lowest common ancestor of each
+///     pair, in the same order as ``pairs``, or ``None`` if the nodes of the
+///     pair don't have a common ancestor
+/// :rtype: list
+///
+/// :raises InvalidNode: If a node index in ``pairs`` is not in the graph
+/// :raises DAGHasCycle: If the graph has a cycle
+#[pyfunction]
+#[pyo3(text_signature = "(graph, pairs, /)")]
+pub fn lowest_common_ancestors(
+    graph: &PyDiGraph,
+    pairs: Vec<(usize, usize)>,
+) -> PyResult<Vec<Option<usize>>> {
+    for (first, second) in &pairs {
+        check_node(graph, *first)?;
+        check_node(graph, *second)?;
+    }
+    let order = topological_order(graph)?;
+    if let Some((children, roots)) = forest_children(graph) {
+        return Ok(forest_lowest_common_ancestors(&children, &roots, &pairs));
+    }
+    let mut position = vec![0; graph.graph.node_bound()];
+    for (index, node) in order.iter().enumerate() {
+        position[*node] = index;
+    }
+    let mut ancestors: HashMap<usize, FixedBitSet> = HashMap::new();
+    for (first, second) in &pairs {
+        for node in &[first, second] {
+            ancestors
+                .entry(**node)
+                .or_insert_with(|| dag_ancestors(graph, &position, **node));
+        }
+    }
+    Ok(pairs
+        .iter()
+        .map(|(first, second)| {
+            last_common_position(&ancestors[first], &ancestors[second]).map(|index| order[index])
+        })
+        .collect())
+}
+
+/// The preprocessed ancestry of a graph used by :class:`LCAIndex`.
+enum Ancestry {
+    /// An Euler tour of a forest with a sparse table over the depths of the
+    /// nodes in the tour.
+    Forest {
+        tree: Vec<usize>,
+        depth: Vec<usize>,
+        first: Vec<usize>,
+        table: Vec<Vec<usize>>,
+    },
+    /// The set of ancestors of every node as positions in the topological
+    /// order of a directed acyclic graph.
+    Dag {
+        order: Vec<usize>,
+        ancestors: Vec<FixedBitSet>,
+    },
+}
+
+impl Ancestry {
+    fn forest(children: &[Vec<usize>], roots: &[usize]) -> Self {
+        let mut tree = vec![0; children.len()];
+        let mut depth = vec![0; children.len()];
+        let mut first = vec![0; children.len()];
+        let mut tour: Vec<usize> = Vec::new();
+        for root in roots {
+            tree[*root] = *root;
+            first[*root] = tour.len();
+            tour.push(*root);
+            let mut stack: Vec<(usize, usize)> = vec![(*root, 0)];
+            while let Some((node, next)) = stack.last_mut() {
+                let node = *node;
+                if let Some(child) = children[node].get(*next) {
+                    *next += 1;
+                    tree[*child] = *root;
+                    depth[*child] = depth[node] + 1;
+                    first[*child] = tour.len();
+                    tour.push(*child);
+                    stack.push((*child, 0));
+                    continue;
+                }
+                stack.pop();
+                if let Some((parent, _)) = stack.last() {
+                    tour.push(*parent);
+                }
+            }
+        }
+        // Each row of the table holds the shallowest node of the tour in the
+        // windows twice as long as the previous row
+        let length = tour.len();
+        let mut table = vec![tour];
+        let mut width = 1;
+        while 2 * width <= length {
+            let last = &table[table.len() - 1];
+            let row = (0..last.len() - width)
+                .map(|start| {
+                    let (left, right) = (last[start], last[start + width]);
+                    if depth[right] < depth[left] {
+                        right
+                    } else {
+                        left
+                    }
+                })
+                .collect();
+            table.push(row);
+            width *= 2;
+        }
+        Ancestry::Forest {
+            tree,
+            depth,
+            first,
+            table,
+        }
+    }
+
+    fn dag(graph: &PyDiGraph, order: Vec<usize>) -> Self {
+        let mut ancestors = vec![FixedBitSet::new(); graph.graph.node_bound()];
+        for (index, node) in order.iter().enumerate() {
+            let mut node_ancestors = FixedBitSet::with_capacity(order.len());
+            node_ancestors.insert(index);
+            for parent in graph
+                .graph
+                .neighbors_directed(NodeIndex::new(*node), Direction::Incoming)
+            {
+                node_ancestors.union_with(&ancestors[parent.index()]);
+            }
+            ancestors[*node] = node_ancestors;
+        }
+        Ancestry::Dag { order, ancestors }
+    }
+
+    fn query(&self, first: usize, second: usize) -> Option<usize> {
+        match self {
+            Ancestry::Forest {
+                tree,
+                depth,
+                first: positions,
+                table,
+            } => {
+                if tree[first] != tree[second] {
+                    return None;
+                }
+                let (start, end) = if positions[first] <= positions[second] {
+                    (positions[first], positions[second])
+                } else {
+                    (positions[second], positions[first])
+                };
+                let bits = 8 * std::mem::size_of::<usize>();
+                let level = bits - (end - start + 1).leading_zeros() as usize - 1;
+                let left = table[level][start];
+                let right = table[level][end + 1 - (1 << level)];
+                if depth[right] < depth[left] {
+                    Some(right)
+                } else {
+                    Some(left)
+                }
+            }
+            Ancestry::Dag { order, ancestors } => {
+                last_common_position(&ancestors[first], &ancestors[second])
+                    .map(|index| order[index])
+            }
+        }
+    }
+}
+
+/// A preprocessed index for answering lowest common ancestor queries
+///
+/// The index is built once from a directed acyclic graph and then answers
+/// each query for the lowest common ancestor of two nodes without searching
+/// the graph again. A node is considered its own ancestor, so the lowest
+/// common ancestor of a node and one of its descendants is the node itself.
+///
+/// If the graph is a forest of rooted trees, where every node has at most
+/// one incoming edge, the index stores an Euler tour of the trees with a
+/// sparse table of the depths along it, which takes :math:`O(n \log n)`
+/// time and space to build and answers each query in constant time. For any
+/// other directed acyclic graph the index stores the set of ancestors of
+/// every node, which takes :math:`O(n^2)` bits of space, and each query
+/// takes :math:`O(n)` time. As two nodes of such a graph may have several
+/// lowest common ancestors, the last of them in topological order is
+/// returned, the same one as :func:`~retworkx.lowest_common_ancestors`.
+///
+/// The index is a snapshot of the graph when it was created and doesn't
+/// track any later changes to the graph.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_binomial_tree_graph(3)
+///   index = retworkx.LCAIndex(graph)
+///   print(index.query(3, 5))
+///   print(index.query(6, 7))
+///
+/// :param PyDiGraph graph: The directed acyclic graph to build the index for
+///
+/// :raises DAGHasCycle: If the graph has a cycle
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(graph, /)")]
+pub struct LCAIndex {
+    nodes: FixedBitSet,
+    ancestry: Ancestry,
+}
+
+#[pymethods]
+impl LCAIndex {
+    #[new]
+    fn new(graph: &PyDiGraph) -> PyResult<Self> {
+        let order = topological_order(graph)?;
+        let mut nodes = FixedBitSet::with_capacity(graph.graph.node_bound());
+        nodes.extend(order.iter().copied());
+        let ancestry = match forest_children(graph) {
+            Some((children, roots)) => Ancestry::forest(&children, &roots),
+            None => Ancestry::dag(graph, order),
+        };
+        Ok(LCAIndex { nodes, ancestry })
+    }
+
+    /// Return the lowest common ancestor of two nodes
+    ///
+    /// :param int first: The index of the first node
+    /// :param int second: The index of the second node
+    ///
+    /// :returns: The index of the lowest common ancestor of the nodes or
+    ///     ``None`` if they don't have a common ancestor
+    /// :rtype: int
+    ///
+    /// :raises InvalidNode: If either node wasn't in the graph when the index
+    ///     was created
+    #[pyo3(text_signature = "(self, first, second, /)")]
+    fn query(&self, first: usize, second: usize) -> PyResult<Option<usize>> {
+        for node in &[first, second] {
+            if !self.nodes.contains(*node) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not present in the graph",
+                    node
+                )));
+            }
+        }
+        Ok(self.ancestry.query(first, second))
+    }
+}
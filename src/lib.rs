@@ -10,63 +10,150 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+mod anonymization;
+mod bipartite;
 mod cartesian_product;
 mod centrality;
+mod clique;
+mod clusters;
+mod coarsening;
 mod coloring;
 mod connectivity;
+mod csr;
 mod dag_algo;
+mod densest;
+mod difference;
 mod digraph;
+mod dominators;
 mod dot_utils;
+mod dyads;
+mod edge_filter;
+mod edge_swap;
+mod ego_graph;
+mod epidemics;
+mod facility_location;
+mod feedback_arcs;
+mod feedback_vertices;
+mod flow;
 mod generators;
 mod graph;
+mod graph_hashing;
+mod hamiltonian;
+mod independent_set;
 mod isomorphism;
 mod iterators;
+mod key_index;
+mod laplacian;
 mod layout;
+mod lca;
+mod link_prediction;
 mod matching;
+mod modules;
+mod node_filter;
+mod path_utils;
+mod planar;
+mod property;
 mod random_graph;
+mod random_seed;
+mod resistance;
+mod robustness;
+mod sampling;
+mod shared_graph;
 mod shortest_path;
+mod simrank;
+mod small_world;
+mod sparsify;
+mod spectrum;
 mod steiner_tree;
+mod summary;
+mod temporal;
 mod tensor_product;
+mod token_swapper;
 mod toposort;
+mod transaction;
 mod transitivity;
 mod traversal;
 mod tree;
+mod tsp;
 mod union;
+mod validation;
+mod workspace;
 
+use anonymization::*;
+use bipartite::*;
 use cartesian_product::*;
 use centrality::*;
+use clique::*;
+use coarsening::*;
 use coloring::*;
 use connectivity::*;
 use dag_algo::*;
+use densest::*;
+use difference::*;
+use dominators::*;
+use dyads::*;
+use edge_swap::*;
+use ego_graph::*;
+use epidemics::*;
+use facility_location::*;
+use feedback_arcs::*;
+use feedback_vertices::*;
+use flow::*;
+use graph_hashing::*;
+use hamiltonian::*;
+use independent_set::*;
 use isomorphism::*;
 use layout::*;
+use lca::*;
+use link_prediction::*;
 use matching::*;
+use modules::*;
+use path_utils::*;
+use planar::*;
 use random_graph::*;
+use random_seed::*;
+use resistance::*;
+use robustness::*;
+use sampling::*;
 use shortest_path::*;
+use simrank::*;
+use small_world::*;
+use sparsify::*;
+use spectrum::*;
 use steiner_tree::*;
+use summary::*;
+use temporal::*;
 use tensor_product::*;
+use token_swapper::*;
 use transitivity::*;
 use traversal::*;
 use tree::*;
+use tsp::*;
 use union::*;
+use validation::*;
 
 use hashbrown::HashMap;
 use indexmap::map::Entry::{Occupied, Vacant};
 use num_complex::Complex64;
+use numpy::PyReadonlyArray1;
 
 use pyo3::create_exception;
 use pyo3::exceptions::PyException;
+use pyo3::exceptions::PyIndexError;
 use pyo3::exceptions::PyValueError;
+use pyo3::gc::PyVisit;
 use pyo3::import_exception;
 use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyList};
 use pyo3::wrap_pyfunction;
 use pyo3::wrap_pymodule;
+use pyo3::PyTraverseError;
 use pyo3::Python;
 
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::{
-    Data, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    Data, EdgeIndexable, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount,
+    NodeIndexable,
 };
 use petgraph::EdgeType;
 
@@ -95,7 +182,20 @@ impl IsNan for Complex64 {
         self.re.is_nan() || self.im.is_nan()
     }
 }
-pub type StablePyGraph<Ty> = StableGraph<PyObject, PyObject, Ty>;
+/// The index type of the nodes and edges of the graph classes. By default
+/// this is ``u32``, which keeps the storage of every node and edge compact but
+/// limits a graph to ``u32::MAX - 1`` nodes and edges. Building with the
+/// ``usize-indices`` feature switches to ``usize`` indices for larger graphs.
+#[cfg(not(feature = "usize-indices"))]
+pub type GraphIx = u32;
+#[cfg(feature = "usize-indices")]
+pub type GraphIx = usize;
+
+pub type NodeIndex = petgraph::graph::NodeIndex<GraphIx>;
+pub type EdgeIndex = petgraph::graph::EdgeIndex<GraphIx>;
+
+/// The storage of the graph classes.
+pub type StablePyGraph<Ty> = StableGraph<PyObject, PyObject, Ty, GraphIx>;
 
 pub trait NodesRemoved {
     fn nodes_removed(&self) -> bool;
@@ -183,9 +283,114 @@ fn is_valid_weight(val: f64) -> PyResult<f64> {
     Ok(val)
 }
 
+/// Wrap a function to compute the weights of all the edges of a graph at once
+///
+/// Functions which take a ``weight_fn`` (or ``edge_cost_fn``) argument
+/// normally call it once for every edge in the graph, which on large graphs
+/// can spend most of the runtime calling into Python. When a
+/// :class:`~retworkx.BatchedWeightFn` is passed instead, its function is called
+/// a single time with a list of the data payloads of every edge in the graph
+/// and the weights it returns are used for the rest of the algorithm.
+///
+/// Functions which don't evaluate weights in a batch call a
+/// :class:`~retworkx.BatchedWeightFn` like a regular ``weight_fn``, with
+/// a list containing a single edge payload each time.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 2), (1, 2, 3), (0, 2, 7)])
+///   weight_fn = retworkx.BatchedWeightFn(lambda weights: [float(w) for w in weights])
+///   print(retworkx.dijkstra_shortest_path_lengths(graph, 0, weight_fn))
+///
+/// :param func: A callable which takes a single positional argument, a list
+///     of edge data payloads, and returns a sequence, such as a list or a
+///     numpy array, of the same length containing the float weight of each
+///     of those edges.
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(func, /)")]
+pub struct BatchedWeightFn {
+    func: PyObject,
+}
+
+impl BatchedWeightFn {
+    /// Evaluate the weights of all the edges of ``graph``, returns the weight
+    /// of each edge by its index.
+    fn evaluate<Ty: EdgeType>(&self, py: Python, graph: &StablePyGraph<Ty>) -> PyResult<Vec<f64>> {
+        let (edges, payloads): (Vec<EdgeIndex>, Vec<&PyObject>) = graph
+            .edge_references()
+            .map(|edge| (edge.id(), edge.weight()))
+            .unzip();
+        let res = self.func.call1(py, (PyList::new(py, &payloads),))?;
+        let weights: Vec<f64> = res.extract(py)?;
+        if weights.len() != payloads.len() {
+            return Err(PyValueError::new_err(format!(
+                "Batched weight function returned {} weights for {} edges",
+                weights.len(),
+                payloads.len()
+            )));
+        }
+        let mut edge_weights = vec![f64::NAN; graph.edge_bound()];
+        for (edge, weight) in edges.into_iter().zip(weights) {
+            edge_weights[edge.index()] = weight;
+        }
+        Ok(edge_weights)
+    }
+}
+
+#[pymethods]
+impl BatchedWeightFn {
+    #[new]
+    fn new(func: PyObject) -> Self {
+        BatchedWeightFn { func }
+    }
+
+    fn __call__(&self, py: Python, payload: PyObject) -> PyResult<f64> {
+        let res = self.func.call1(py, (PyList::new(py, &[payload]),))?;
+        let weights: Vec<f64> = res.extract(py)?;
+        match weights.as_slice() {
+            [weight] => Ok(*weight),
+            _ => Err(PyValueError::new_err(format!(
+                "Batched weight function returned {} weights for 1 edge",
+                weights.len()
+            ))),
+        }
+    }
+
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.func)?;
+        Ok(())
+    }
+
+    fn __clear__(&mut self, py: Python) {
+        self.func = py.None();
+    }
+}
+
+/// If ``weight_fn`` is a :class:`BatchedWeightFn` evaluate it for every edge
+/// of ``graph`` and return the weights by edge index.
+fn batched_weights<Ty: EdgeType>(
+    py: Python,
+    weight_fn: Option<&PyObject>,
+    graph: &StablePyGraph<Ty>,
+) -> PyResult<Option<Vec<f64>>> {
+    match weight_fn {
+        Some(weight_fn) => match weight_fn.extract::<PyRef<BatchedWeightFn>>(py) {
+            Ok(batched) => batched.evaluate(py, graph).map(Some),
+            Err(_) => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
 pub enum CostFn {
     Default(f64),
     PyFunction(PyObject),
+    /// A :class:`BatchedWeightFn` and the weights it computed for the edges
+    /// of a graph, by edge index.
+    Batched(PyObject, Vec<f64>),
 }
 
 impl From<PyObject> for CostFn {
@@ -219,13 +424,141 @@ impl CostFn {
     fn call(&self, py: Python, arg: &PyObject) -> PyResult<f64> {
         match self {
             CostFn::Default(val) => Ok(*val),
-            CostFn::PyFunction(obj) => {
+            CostFn::PyFunction(obj) | CostFn::Batched(obj, _) => {
                 let raw = obj.call1(py, (arg,))?;
                 let val: f64 = raw.extract(py)?;
                 is_valid_weight(val)
             }
         }
     }
+
+    /// The cost of the edge ``edge`` with the data payload ``arg``, batched
+    /// weights are looked up by the edge index.
+    fn call_edge(&self, py: Python, edge: EdgeIndex, arg: &PyObject) -> PyResult<f64> {
+        match self {
+            CostFn::Batched(_, weights) => Ok(weights[edge.index()]),
+            _ => self.call(py, arg),
+        }
+    }
+
+    /// Evaluate a :class:`BatchedWeightFn` for all the edges of ``graph`` up
+    /// front, other cost functions are returned unchanged.
+    fn prepare<Ty: EdgeType>(self, py: Python, graph: &StablePyGraph<Ty>) -> PyResult<Self> {
+        match self {
+            CostFn::PyFunction(obj) => match batched_weights(py, Some(&obj), graph)? {
+                Some(weights) => {
+                    for edge in graph.edge_indices() {
+                        is_valid_weight(weights[edge.index()])?;
+                    }
+                    Ok(CostFn::Batched(obj, weights))
+                }
+                None => Ok(CostFn::PyFunction(obj)),
+            },
+            cost_fn => Ok(cost_fn),
+        }
+    }
+}
+
+/// Count the number of occurrences of each degree in ``degrees``, the output
+/// has one entry for each degree from 0 to the maximum degree.
+fn degree_histogram(degrees: &[usize]) -> Vec<usize> {
+    let mut histogram: Vec<usize> = match degrees.iter().max() {
+        Some(max_degree) => vec![0; max_degree + 1],
+        None => Vec::new(),
+    };
+    for degree in degrees {
+        histogram[*degree] += 1;
+    }
+    histogram
+}
+
+/// Build the row of the adjacency matrix of ``graph`` from the
+/// ``(neighbor, weight)`` pairs of the edges of a node, summing the weights of
+/// parallel edges. The row has an entry for each node in node index order.
+fn adjacency_row<'a, Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    edges: impl Iterator<Item = (NodeIndex, &'a PyObject)>,
+    weight_fn: &Option<PyObject>,
+    default_weight: f64,
+    null_value: f64,
+) -> PyResult<Vec<f64>> {
+    let mut position: Vec<usize> = vec![0; graph.node_bound()];
+    for (count, node) in graph.node_indices().enumerate() {
+        position[node.index()] = count;
+    }
+    let mut row: Vec<Option<f64>> = vec![None; graph.node_count()];
+    for (neighbor, weight) in edges {
+        let weight = weight_callable(py, weight_fn, weight, default_weight)?;
+        let entry = &mut row[position[neighbor.index()]];
+        *entry = Some(entry.unwrap_or(0.) + weight);
+    }
+    Ok(row
+        .into_iter()
+        .map(|entry| entry.unwrap_or(null_value))
+        .collect())
+}
+
+/// Rebuild ``graph`` with contiguous node and edge indices.
+///
+/// Nodes and edges are added to the new graph in index order so the relative
+/// order of the indices is preserved. Returns the new graph along with
+/// mappings of old to new node indices and old to new edge indices.
+fn compact_graph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+) -> (
+    StablePyGraph<Ty>,
+    DictMap<usize, usize>,
+    DictMap<usize, usize>,
+) {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(graph.node_count(), graph.edge_count());
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(graph.node_count());
+    let mut edge_map: DictMap<usize, usize> = DictMap::with_capacity(graph.edge_count());
+    for node in graph.node_indices() {
+        let new_node = out_graph.add_node(graph[node].clone_ref(py));
+        node_map.insert(node.index(), new_node.index());
+    }
+    for edge in graph.edge_indices() {
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        let new_edge = out_graph.add_edge(
+            NodeIndex::new(node_map[&source.index()]),
+            NodeIndex::new(node_map[&target.index()]),
+            graph[edge].clone_ref(py),
+        );
+        edge_map.insert(edge.index(), new_edge.index());
+    }
+    (out_graph, node_map, edge_map)
+}
+
+/// Group the edges in ``graph`` by their endpoints, in edge index order.
+///
+/// For undirected graphs the endpoints in each key are ordered so the lower
+/// node index is first.
+fn parallel_edge_groups<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+) -> DictMap<(usize, usize), Vec<EdgeIndex>> {
+    let mut groups: DictMap<(usize, usize), Vec<EdgeIndex>> = DictMap::new();
+    for edge in graph.edge_references() {
+        let source = edge.source().index();
+        let target = edge.target().index();
+        let key = if !graph.is_directed() && target < source {
+            (target, source)
+        } else {
+            (source, target)
+        };
+        groups.entry(key).or_default().push(edge.id());
+    }
+    groups
+}
+
+/// Return the self loop edges of ``graph`` in edge index order.
+fn self_loop_edges<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Vec<EdgeIndex> {
+    graph
+        .edge_references()
+        .filter(|edge| edge.source() == edge.target())
+        .map(|edge| edge.id())
+        .collect()
 }
 
 fn find_node_by_weight<Ty: EdgeType>(
@@ -248,6 +581,67 @@ fn find_node_by_weight<Ty: EdgeType>(
     Ok(index)
 }
 
+/// Build a list of edges to add to ``graph`` from numpy arrays of source
+/// and target node indices and an optional sequence of weights.
+///
+/// The index arrays are converted to ``int64`` arrays (if they're not
+/// already) so any integer array or sequence type accepted by numpy can be
+/// used. All endpoints are validated before the graph is modified.
+fn edges_from_arrays<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    sources: &PyAny,
+    targets: &PyAny,
+    weights: Option<&PyAny>,
+) -> PyResult<Vec<(NodeIndex, NodeIndex, PyObject)>> {
+    let numpy = py.import("numpy")?;
+    let as_index_array = |obj: &PyAny| -> PyResult<Vec<NodeIndex>> {
+        let kwargs = [("dtype", "int64")].into_py_dict(py);
+        let array: PyReadonlyArray1<i64> = numpy
+            .call_method("asarray", (obj,), Some(kwargs))?
+            .extract()?;
+        array
+            .as_array()
+            .iter()
+            .map(|index| {
+                if *index < 0 || !graph.contains_node(NodeIndex::new(*index as usize)) {
+                    Err(PyIndexError::new_err(format!(
+                        "Node index {} is not present in the graph",
+                        index
+                    )))
+                } else {
+                    Ok(NodeIndex::new(*index as usize))
+                }
+            })
+            .collect()
+    };
+    let sources = as_index_array(sources)?;
+    let targets = as_index_array(targets)?;
+    if sources.len() != targets.len() {
+        return Err(PyValueError::new_err(
+            "The sources and targets arrays must be the same length",
+        ));
+    }
+    let weights: Vec<PyObject> = match weights {
+        Some(weights) => match weights.extract::<PyReadonlyArray1<f64>>() {
+            Ok(array) => array.as_array().iter().map(|w| w.to_object(py)).collect(),
+            Err(_) => weights.extract()?,
+        },
+        None => vec![py.None(); sources.len()],
+    };
+    if weights.len() != sources.len() {
+        return Err(PyValueError::new_err(
+            "The weights must be the same length as the sources and targets arrays",
+        ));
+    }
+    Ok(sources
+        .into_iter()
+        .zip(targets)
+        .zip(weights)
+        .map(|((source, target), weight)| (source, target, weight))
+        .collect())
+}
+
 fn merge_duplicates<K, V, F, E>(xs: Vec<(K, V)>, mut merge_fn: F) -> Result<Vec<(K, V)>, E>
 where
     K: Hash + Eq,
@@ -281,11 +675,61 @@ create_exception!(retworkx, NoSuitableNeighbors, PyException);
 create_exception!(retworkx, NullGraph, PyException);
 // No path was found between the specified nodes.
 create_exception!(retworkx, NoPathFound, PyException);
+// The token swapper could not find swaps to complete the mapping.
+create_exception!(retworkx, InvalidMapping, PyException);
+// An iterative algorithm failed to converge in the maximum number of iterations.
+create_exception!(retworkx, FailedToConverge, PyException);
+// The graph is too large for the requested algorithm.
+create_exception!(retworkx, GraphTooLarge, PyException);
+// A negative cycle was found, so the shortest paths aren't defined.
+create_exception!(retworkx, NegativeCycleError, PyValueError);
+// The algorithm requires a connected graph.
+create_exception!(retworkx, DisconnectedGraphError, PyValueError);
+// The algorithm requires the graph to be a tree.
+create_exception!(retworkx, InvalidTreeError, PyValueError);
+// An iterative algorithm failed to converge, with the number of iterations run.
+create_exception!(retworkx, ConvergenceError, FailedToConverge);
 // Prune part of the search tree while traversing a graph.
 import_exception!(retworkx.visit, PruneSearch);
 // Stop graph traversal.
 import_exception!(retworkx.visit, StopSearch);
 
+/// Create a :class:`ConvergenceError` with its ``iterations`` attribute set
+/// to the number of iterations that were run.
+fn convergence_error(py: Python, message: String, iterations: usize) -> PyErr {
+    let err = ConvergenceError::new_err(message);
+    match err.value(py).setattr("iterations", iterations) {
+        Ok(()) => err,
+        Err(setattr_err) => setattr_err,
+    }
+}
+
+/// Create a :class:`NegativeCycleError` with its ``cycle`` attribute set to
+/// the node indices of the cycle found.
+fn negative_cycle_error(py: Python, cycle: Vec<usize>) -> PyErr {
+    let err = NegativeCycleError::new_err("The graph has a negative cycle");
+    match err.value(py).setattr("cycle", cycle) {
+        Ok(()) => err,
+        Err(setattr_err) => setattr_err,
+    }
+}
+
+/// Convert an error returned by a retworkx-core function into the matching
+/// Python exception. Errors raised by Python callbacks are passed through
+/// unchanged.
+fn core_error<E: Into<PyErr>>(err: retworkx_core::Error<E>) -> PyErr {
+    match err {
+        retworkx_core::Error::InvalidNode => {
+            InvalidNode::new_err("The input node index is not present in the graph")
+        }
+        retworkx_core::Error::MapNotPossible => {
+            InvalidMapping::new_err("No sequence of swaps can complete the mapping")
+        }
+        retworkx_core::Error::DAGHasCycle => DAGHasCycle::new_err("Sort encountered a cycle"),
+        retworkx_core::Error::Callback(err) => err.into(),
+    }
+}
+
 #[pymodule]
 fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
@@ -295,7 +739,17 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add("DAGHasCycle", py.get_type::<DAGHasCycle>())?;
     m.add("NoSuitableNeighbors", py.get_type::<NoSuitableNeighbors>())?;
     m.add("NoPathFound", py.get_type::<NoPathFound>())?;
+    m.add("InvalidMapping", py.get_type::<InvalidMapping>())?;
     m.add("NullGraph", py.get_type::<NullGraph>())?;
+    m.add("FailedToConverge", py.get_type::<FailedToConverge>())?;
+    m.add("GraphTooLarge", py.get_type::<GraphTooLarge>())?;
+    m.add("NegativeCycleError", py.get_type::<NegativeCycleError>())?;
+    m.add(
+        "DisconnectedGraphError",
+        py.get_type::<DisconnectedGraphError>(),
+    )?;
+    m.add("InvalidTreeError", py.get_type::<InvalidTreeError>())?;
+    m.add("ConvergenceError", py.get_type::<ConvergenceError>())?;
     m.add_wrapped(wrap_pyfunction!(bfs_successors))?;
     m.add_wrapped(wrap_pyfunction!(graph_bfs_search))?;
     m.add_wrapped(wrap_pyfunction!(digraph_bfs_search))?;
@@ -305,6 +759,7 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(dag_longest_path_length))?;
     m.add_wrapped(wrap_pyfunction!(dag_weighted_longest_path))?;
     m.add_wrapped(wrap_pyfunction!(dag_weighted_longest_path_length))?;
+    m.add_wrapped(wrap_pyfunction!(dag_minimum_path_cover))?;
     m.add_wrapped(wrap_pyfunction!(number_connected_components))?;
     m.add_wrapped(wrap_pyfunction!(connected_components))?;
     m.add_wrapped(wrap_pyfunction!(is_connected))?;
@@ -313,17 +768,31 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(weakly_connected_components))?;
     m.add_wrapped(wrap_pyfunction!(is_weakly_connected))?;
     m.add_wrapped(wrap_pyfunction!(is_directed_acyclic_graph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_summary))?;
+    m.add_wrapped(wrap_pyfunction!(validate))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_subgraph_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_subgraph_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(digraph_vf2_mapping))?;
     m.add_wrapped(wrap_pyfunction!(graph_vf2_mapping))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_isomorphism_classes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_isomorphism_classes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_weisfeiler_lehman_subgraph_hashes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_weisfeiler_lehman_subgraph_hashes))?;
     m.add_wrapped(wrap_pyfunction!(digraph_union))?;
     m.add_wrapped(wrap_pyfunction!(graph_union))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_difference))?;
+    m.add_wrapped(wrap_pyfunction!(graph_difference))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_symmetric_difference))?;
+    m.add_wrapped(wrap_pyfunction!(graph_symmetric_difference))?;
+    m.add_wrapped(wrap_pyfunction!(graph_diff))?;
     m.add_wrapped(wrap_pyfunction!(digraph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(graph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(topological_sort))?;
+    m.add_wrapped(wrap_pyfunction!(feedback_arc_set))?;
+    m.add_wrapped(wrap_pyfunction!(graph_feedback_vertex_set))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_feedback_vertex_set))?;
     m.add_wrapped(wrap_pyfunction!(descendants))?;
     m.add_wrapped(wrap_pyfunction!(ancestors))?;
     m.add_wrapped(wrap_pyfunction!(lexicographical_topological_sort))?;
@@ -334,14 +803,23 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(collect_runs))?;
     m.add_wrapped(wrap_pyfunction!(collect_bicolor_runs))?;
     m.add_wrapped(wrap_pyfunction!(layers))?;
+    m.add_wrapped(wrap_pyfunction!(dag_parallel_map))?;
     m.add_wrapped(wrap_pyfunction!(graph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_adjacency_matrix))?;
     m.add_wrapped(wrap_pyfunction!(graph_adjacency_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_modularity_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_modularity_matrix))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_simple_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_simple_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_iter_all_simple_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_iter_all_simple_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_all_simple_edge_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_all_simple_edge_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_path_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_path_edges))?;
     m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_pairs_dijkstra_path_lengths))?;
@@ -350,34 +828,146 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_betweenness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_betweenness_centrality_array))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_betweenness_centrality_array))?;
+    m.add_wrapped(wrap_pyfunction!(graph_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_eigenvector_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_eigenvector_centrality))?;
     m.add_wrapped(wrap_pyfunction!(graph_astar_shortest_path))?;
     m.add_wrapped(wrap_pyfunction!(digraph_astar_shortest_path))?;
+    m.add_wrapped(wrap_pyfunction!(graph_shortest_path_between_sets))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_shortest_path_between_sets))?;
+    m.add_wrapped(wrap_pyfunction!(graph_is_path))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_is_path))?;
+    m.add_wrapped(wrap_pyfunction!(graph_path_weight))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_path_weight))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edges_of_path))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edges_of_path))?;
+    m.add_wrapped(wrap_pyfunction!(graph_longest_common_subpath))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_longest_common_subpath))?;
+    m.add_wrapped(wrap_pyfunction!(graph_path_jaccard_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_path_jaccard_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(path_frechet_distance))?;
     m.add_wrapped(wrap_pyfunction!(graph_greedy_color))?;
+    m.add_wrapped(wrap_pyfunction!(max_clique))?;
+    m.add_wrapped(wrap_pyfunction!(find_cliques))?;
+    m.add_wrapped(wrap_pyfunction!(maximum_independent_set))?;
+    m.add_wrapped(wrap_pyfunction!(tree_maximum_weight_independent_set))?;
+    m.add_wrapped(wrap_pyfunction!(interval_maximum_weight_independent_set))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_vertex_cover))?;
+    m.add_wrapped(wrap_pyfunction!(is_planar))?;
+    m.add_wrapped(wrap_pyfunction!(check_planarity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_hamiltonian_path))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_hamiltonian_path))?;
     m.add_wrapped(wrap_pyfunction!(graph_tensor_product))?;
     m.add_wrapped(wrap_pyfunction!(digraph_tensor_product))?;
+    m.add_wrapped(wrap_pyfunction!(graph_token_swapper))?;
+    m.add_wrapped(wrap_pyfunction!(graph_ego_graph))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_ego_graph))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_maximum_flow))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_minimum_cut))?;
+    m.add_wrapped(wrap_pyfunction!(densest_subgraph))?;
+    m.add_wrapped(wrap_pyfunction!(approximate_densest_subgraph))?;
     m.add_wrapped(wrap_pyfunction!(directed_gnp_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(undirected_gnp_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(directed_gnm_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(undirected_gnm_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(random_geometric_graph))?;
     m.add_wrapped(wrap_pyfunction!(cycle_basis))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_cycle_basis))?;
+    m.add_wrapped(wrap_pyfunction!(simple_cycles))?;
     m.add_wrapped(wrap_pyfunction!(strongly_connected_components))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dfs_edges))?;
     m.add_wrapped(wrap_pyfunction!(graph_dfs_edges))?;
     m.add_wrapped(wrap_pyfunction!(digraph_find_cycle))?;
     m.add_wrapped(wrap_pyfunction!(digraph_k_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(graph_k_shortest_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_bellman_ford_shortest_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_bellman_ford_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(is_matching))?;
     m.add_wrapped(wrap_pyfunction!(is_maximal_matching))?;
     m.add_wrapped(wrap_pyfunction!(max_weight_matching))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_edges))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_spanning_edge_indices))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(random_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(degree_constrained_spanning_tree))?;
+    m.add_wrapped(wrap_pyfunction!(lowest_common_ancestors))?;
+    m.add_wrapped(wrap_pyfunction!(immediate_dominators))?;
+    m.add_wrapped(wrap_pyfunction!(dominance_frontiers))?;
+    m.add_wrapped(wrap_pyfunction!(tree_center))?;
+    m.add_wrapped(wrap_pyfunction!(tree_centroid))?;
+    m.add_wrapped(wrap_pyfunction!(tree_diameter_path))?;
+    m.add_wrapped(wrap_pyfunction!(graph_is_tree))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_is_tree))?;
+    m.add_wrapped(wrap_pyfunction!(graph_is_forest))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_is_forest))?;
+    m.add_wrapped(wrap_pyfunction!(is_arborescence))?;
+    m.add_wrapped(wrap_pyfunction!(is_branching))?;
     m.add_wrapped(wrap_pyfunction!(graph_transitivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_triangles))?;
+    m.add_wrapped(wrap_pyfunction!(graph_clustering))?;
+    m.add_wrapped(wrap_pyfunction!(graph_average_clustering))?;
+    m.add_wrapped(wrap_pyfunction!(reciprocity))?;
+    m.add_wrapped(wrap_pyfunction!(node_reciprocity))?;
+    m.add_wrapped(wrap_pyfunction!(dyad_census))?;
+    m.add_wrapped(wrap_pyfunction!(random_reference))?;
+    m.add_wrapped(wrap_pyfunction!(lattice_reference))?;
+    m.add_wrapped(wrap_pyfunction!(small_world_sigma))?;
+    m.add_wrapped(wrap_pyfunction!(small_world_omega))?;
+    m.add_wrapped(wrap_pyfunction!(adjacency_spectrum))?;
+    m.add_wrapped(wrap_pyfunction!(laplacian_spectrum))?;
+    m.add_wrapped(wrap_pyfunction!(spectral_sparsify))?;
+    m.add_wrapped(wrap_pyfunction!(resistance_distance))?;
+    m.add_wrapped(wrap_pyfunction!(resistance_distance_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(jaccard_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(adamic_adar_index))?;
+    m.add_wrapped(wrap_pyfunction!(preferential_attachment))?;
+    m.add_wrapped(wrap_pyfunction!(resource_allocation_index))?;
+    m.add_wrapped(wrap_pyfunction!(graph_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_simrank_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(modular_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(graph_robustness_curve))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_robustness_curve))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sample_nodes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sample_nodes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sample_edges))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sample_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sample_subgraph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sample_subgraph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_epidemic_simulation))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_epidemic_simulation))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_descendants))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_descendants))?;
+    m.add_wrapped(wrap_pyfunction!(graph_earliest_arrival_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_earliest_arrival_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_earliest_arrival_times))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_earliest_arrival_times))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_connected_components))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_connected_components))?;
+    m.add_wrapped(wrap_pyfunction!(graph_coarsen))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_coarsen))?;
+    m.add_wrapped(wrap_pyfunction!(graph_double_edge_swap))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_double_edge_swap))?;
+    m.add_wrapped(wrap_pyfunction!(connected_double_edge_swap))?;
+    m.add_wrapped(wrap_pyfunction!(anonymize_degree_sequence))?;
+    m.add_wrapped(wrap_pyfunction!(is_k_degree_anonymous))?;
+    m.add_wrapped(wrap_pyfunction!(k_degree_anonymize))?;
+    m.add_wrapped(wrap_pyfunction!(bipartite_density))?;
+    m.add_wrapped(wrap_pyfunction!(bipartite_degree_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(node_redundancy))?;
+    m.add_wrapped(wrap_pyfunction!(bipartite_clustering))?;
+    m.add_wrapped(wrap_pyfunction!(set_random_seed))?;
     m.add_wrapped(wrap_pyfunction!(digraph_transitivity))?;
     m.add_wrapped(wrap_pyfunction!(graph_core_number))?;
     m.add_wrapped(wrap_pyfunction!(digraph_core_number))?;
+    m.add_wrapped(wrap_pyfunction!(graph_truss_number))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_truss_number))?;
     m.add_wrapped(wrap_pyfunction!(graph_complement))?;
     m.add_wrapped(wrap_pyfunction!(digraph_complement))?;
+    m.add_wrapped(wrap_pyfunction!(graph_power))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_power))?;
     m.add_wrapped(wrap_pyfunction!(graph_random_layout))?;
     m.add_wrapped(wrap_pyfunction!(digraph_random_layout))?;
     m.add_wrapped(wrap_pyfunction!(graph_bipartite_layout))?;
@@ -400,6 +990,9 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     ))?;
     m.add_wrapped(wrap_pyfunction!(metric_closure))?;
     m.add_wrapped(wrap_pyfunction!(steiner_tree::steiner_tree))?;
+    m.add_wrapped(wrap_pyfunction!(traveling_salesman_problem))?;
+    m.add_wrapped(wrap_pyfunction!(p_median))?;
+    m.add_wrapped(wrap_pyfunction!(k_center))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dfs_search))?;
     m.add_wrapped(wrap_pyfunction!(graph_dfs_search))?;
     m.add_wrapped(wrap_pyfunction!(articulation_points))?;
@@ -407,7 +1000,17 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(chain_decomposition))?;
     m.add_class::<digraph::PyDiGraph>()?;
     m.add_class::<graph::PyGraph>()?;
+    m.add_class::<BatchedWeightFn>()?;
+    m.add_class::<csr::CSRSnapshot>()?;
+    m.add_class::<property::PropertyArray>()?;
+    m.add_class::<transaction::GraphTransaction>()?;
+    m.add_class::<workspace::Workspace>()?;
     m.add_class::<toposort::TopologicalSorter>()?;
+    m.add_class::<lca::LCAIndex>()?;
+    m.add_class::<tree::TreeCheck>()?;
+    m.add_class::<clique::CliqueIterator>()?;
+    m.add_class::<connectivity::johnson_simple_cycles::SimpleCycleIterator>()?;
+    m.add_class::<connectivity::all_simple_paths::SimplePathIterator>()?;
     m.add_class::<iterators::BFSSuccessors>()?;
     m.add_class::<iterators::Chains>()?;
     m.add_class::<iterators::NodeIndices>()?;
@@ -423,6 +1026,8 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<iterators::AllPairsPathMapping>()?;
     m.add_class::<iterators::NodesCountMapping>()?;
     m.add_class::<iterators::NodeMap>()?;
+    m.add_class::<iterators::EdgeMap>()?;
+    m.add_class::<iterators::EdgeFlowMapping>()?;
     m.add_class::<iterators::ProductNodeMap>()?;
     m.add_class::<iterators::BiconnectedComponents>()?;
     m.add_wrapped(wrap_pymodule!(generators::generators))?;
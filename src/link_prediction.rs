@@ -0,0 +1,370 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+
+use petgraph::visit::NodeIndexable;
+use rayon::prelude::*;
+
+use crate::transitivity::sorted_adjacency;
+use crate::{graph, InvalidNode};
+
+#[derive(Clone, Copy)]
+enum Index {
+    Jaccard,
+    AdamicAdar,
+    PreferentialAttachment,
+    ResourceAllocation,
+}
+
+impl Index {
+    /// Whether the index is zero for every pair of nodes without a common
+    /// neighbor.
+    fn needs_common_neighbors(self) -> bool {
+        !matches!(self, Index::PreferentialAttachment)
+    }
+
+    fn score(self, adjacency: &[Vec<usize>], u: usize, v: usize) -> f64 {
+        let (first, second) = (&adjacency[u], &adjacency[v]);
+        if let Index::PreferentialAttachment = self {
+            return (first.len() * second.len()) as f64;
+        }
+        let (mut i, mut j) = (0, 0);
+        let (mut common, mut total) = (0, 0.0);
+        while i < first.len() && j < second.len() {
+            match first[i].cmp(&second[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    // A common neighbor only has a single neighbor when it's
+                    // a node paired with itself, which is left out of the
+                    // Adamic-Adar index to avoid dividing by log(1) = 0
+                    let degree = adjacency[first[i]].len() as f64;
+                    common += 1;
+                    total += match self {
+                        Index::AdamicAdar if degree > 1.0 => 1.0 / degree.ln(),
+                        Index::AdamicAdar => 0.0,
+                        _ => 1.0 / degree,
+                    };
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        match self {
+            Index::Jaccard => {
+                let union = first.len() + second.len() - common;
+                if union == 0 {
+                    0.0
+                } else {
+                    common as f64 / union as f64
+                }
+            }
+            _ => total,
+        }
+    }
+}
+
+/// Score either the given pairs of nodes or every pair of distinct, non
+/// adjacent nodes whose score is above ``threshold`` and return the pairs
+/// and scores as numpy arrays.
+fn link_prediction(
+    py: Python,
+    graph: &graph::PyGraph,
+    index: Index,
+    pairs: Option<Vec<(usize, usize)>>,
+    threshold: f64,
+) -> PyResult<(PyObject, PyObject)> {
+    let (nodes, adjacency) = sorted_adjacency(graph);
+    let mut compact: Vec<Option<usize>> = vec![None; graph.graph.node_bound()];
+    for (position, node) in nodes.iter().enumerate() {
+        compact[node.index()] = Some(position);
+    }
+    let scored: Vec<(usize, usize, f64)> = match pairs {
+        Some(pairs) => {
+            let lookup = |node: usize| -> PyResult<usize> {
+                compact.get(node).copied().flatten().ok_or_else(|| {
+                    InvalidNode::new_err(format!("Node index {} is not present in the graph", node))
+                })
+            };
+            let positions = pairs
+                .iter()
+                .map(|(u, v)| Ok((lookup(*u)?, lookup(*v)?)))
+                .collect::<PyResult<Vec<(usize, usize)>>>()?;
+            py.allow_threads(|| {
+                positions
+                    .par_iter()
+                    .zip(pairs.par_iter())
+                    .map(|((u, v), (source, target))| {
+                        (*source, *target, index.score(&adjacency, *u, *v))
+                    })
+                    .collect()
+            })
+        }
+        None => py.allow_threads(|| {
+            let candidates_only = index.needs_common_neighbors() && threshold >= 0.0;
+            let per_node: Vec<Vec<(usize, usize, f64)>> = (0..nodes.len())
+                .into_par_iter()
+                .map(|u| {
+                    let mut candidates: Vec<usize> = if candidates_only {
+                        adjacency[u]
+                            .iter()
+                            .flat_map(|w| adjacency[*w].iter().copied())
+                            .filter(|v| *v > u)
+                            .collect()
+                    } else {
+                        (u + 1..nodes.len()).collect()
+                    };
+                    candidates.sort_unstable();
+                    candidates.dedup();
+                    candidates
+                        .into_iter()
+                        .filter(|v| adjacency[u].binary_search(v).is_err())
+                        .filter_map(|v| {
+                            let score = index.score(&adjacency, u, v);
+                            if score > threshold {
+                                Some((nodes[u].index(), nodes[v].index(), score))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            per_node.into_iter().flatten().collect()
+        }),
+    };
+    let mut node_pairs = Array2::<usize>::zeros((scored.len(), 2));
+    let mut scores = Array1::<f64>::zeros(scored.len());
+    for (row, (u, v, score)) in scored.into_iter().enumerate() {
+        node_pairs[[row, 0]] = u;
+        node_pairs[[row, 1]] = v;
+        scores[row] = score;
+    }
+    Ok((
+        node_pairs.into_pyarray(py).into(),
+        scores.into_pyarray(py).into(),
+    ))
+}
+
+/// Compute the Jaccard coefficient of pairs of nodes
+///
+/// The Jaccard coefficient of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \frac{|\Gamma(u) \cap \Gamma(v)|}{|\Gamma(u) \cup \Gamma(v)|}
+///
+/// where :math:`\Gamma(u)` is the set of neighbors of :math:`u`. It is 0 if
+/// neither node has a neighbor. Self loops and parallel edges are ignored.
+///
+/// If ``pairs`` is not set every pair of distinct nodes which aren't
+/// adjacent and have a score above ``threshold`` is scored, which are the
+/// candidate links to predict. The pairs are scored in parallel, the env
+/// var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4)
+///   graph.add_edge(1, 2, None)
+///   pairs, scores = retworkx.jaccard_coefficient(graph)
+///   print(pairs)
+///   print(scores)
+///
+/// :param PyGraph graph: The graph to score the pairs of nodes of
+/// :param list pairs: An optional list of node index pairs to score. If it's
+///     not set all the pairs of distinct nodes which aren't adjacent are
+///     considered.
+/// :param float threshold: Only the pairs with a score strictly above this
+///     value are returned when ``pairs`` is not set. By default this is
+///     ``0.0``. It is ignored if ``pairs`` is set.
+///
+/// :returns: A tuple of a numpy array with a row of the two node indices of
+///     each pair, in the order they were given if ``pairs`` is set, and a
+///     numpy array of their scores
+/// :rtype: tuple
+///
+/// :raises InvalidNode: If a node index in ``pairs`` is not present in the
+///     graph
+#[pyfunction(threshold = "0.0")]
+#[pyo3(text_signature = "(graph, /, pairs=None, threshold=0.0)")]
+pub fn jaccard_coefficient(
+    py: Python,
+    graph: &graph::PyGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    threshold: f64,
+) -> PyResult<(PyObject, PyObject)> {
+    link_prediction(py, graph, Index::Jaccard, pairs, threshold)
+}
+
+/// Compute the Adamic-Adar index of pairs of nodes
+///
+/// The Adamic-Adar index of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \sum_{w \in \Gamma(u) \cap \Gamma(v)} \frac{1}{\log |\Gamma(w)|}
+///
+/// where :math:`\Gamma(u)` is the set of neighbors of :math:`u`. Self loops
+/// and parallel edges are ignored.
+///
+/// If ``pairs`` is not set every pair of distinct nodes which aren't
+/// adjacent and have a score above ``threshold`` is scored, which are the
+/// candidate links to predict. The pairs are scored in parallel, the env
+/// var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4)
+///   graph.add_edge(1, 2, None)
+///   pairs, scores = retworkx.adamic_adar_index(graph)
+///   print(pairs)
+///   print(scores)
+///
+/// :param PyGraph graph: The graph to score the pairs of nodes of
+/// :param list pairs: An optional list of node index pairs to score. If it's
+///     not set all the pairs of distinct nodes which aren't adjacent are
+///     considered.
+/// :param float threshold: Only the pairs with a score strictly above this
+///     value are returned when ``pairs`` is not set. By default this is
+///     ``0.0``. It is ignored if ``pairs`` is set.
+///
+/// :returns: A tuple of a numpy array with a row of the two node indices of
+///     each pair, in the order they were given if ``pairs`` is set, and a
+///     numpy array of their scores
+/// :rtype: tuple
+///
+/// :raises InvalidNode: If a node index in ``pairs`` is not present in the
+///     graph
+#[pyfunction(threshold = "0.0")]
+#[pyo3(text_signature = "(graph, /, pairs=None, threshold=0.0)")]
+pub fn adamic_adar_index(
+    py: Python,
+    graph: &graph::PyGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    threshold: f64,
+) -> PyResult<(PyObject, PyObject)> {
+    link_prediction(py, graph, Index::AdamicAdar, pairs, threshold)
+}
+
+/// Compute the preferential attachment score of pairs of nodes
+///
+/// The preferential attachment score of the nodes :math:`u` and :math:`v`
+/// is :math:`|\Gamma(u)| |\Gamma(v)|` where :math:`\Gamma(u)` is the set of
+/// neighbors of :math:`u`. Self loops and parallel edges are ignored.
+///
+/// If ``pairs`` is not set every pair of distinct nodes which aren't
+/// adjacent and have a score above ``threshold`` is scored, which are the
+/// candidate links to predict. The pairs are scored in parallel, the env
+/// var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4)
+///   graph.add_edge(1, 2, None)
+///   pairs, scores = retworkx.preferential_attachment(graph)
+///   print(pairs)
+///   print(scores)
+///
+/// :param PyGraph graph: The graph to score the pairs of nodes of
+/// :param list pairs: An optional list of node index pairs to score. If it's
+///     not set all the pairs of distinct nodes which aren't adjacent are
+///     considered.
+/// :param float threshold: Only the pairs with a score strictly above this
+///     value are returned when ``pairs`` is not set. By default this is
+///     ``0.0``. It is ignored if ``pairs`` is set.
+///
+/// :returns: A tuple of a numpy array with a row of the two node indices of
+///     each pair, in the order they were given if ``pairs`` is set, and a
+///     numpy array of their scores
+/// :rtype: tuple
+///
+/// :raises InvalidNode: If a node index in ``pairs`` is not present in the
+///     graph
+#[pyfunction(threshold = "0.0")]
+#[pyo3(text_signature = "(graph, /, pairs=None, threshold=0.0)")]
+pub fn preferential_attachment(
+    py: Python,
+    graph: &graph::PyGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    threshold: f64,
+) -> PyResult<(PyObject, PyObject)> {
+    link_prediction(py, graph, Index::PreferentialAttachment, pairs, threshold)
+}
+
+/// Compute the resource allocation index of pairs of nodes
+///
+/// The resource allocation index of the nodes :math:`u` and :math:`v` is
+///
+/// .. math::
+///
+///     \sum_{w \in \Gamma(u) \cap \Gamma(v)} \frac{1}{|\Gamma(w)|}
+///
+/// where :math:`\Gamma(u)` is the set of neighbors of :math:`u`. Self loops
+/// and parallel edges are ignored.
+///
+/// If ``pairs`` is not set every pair of distinct nodes which aren't
+/// adjacent and have a score above ``threshold`` is scored, which are the
+/// candidate links to predict. The pairs are scored in parallel, the env
+/// var ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4)
+///   graph.add_edge(1, 2, None)
+///   pairs, scores = retworkx.resource_allocation_index(graph)
+///   print(pairs)
+///   print(scores)
+///
+/// :param PyGraph graph: The graph to score the pairs of nodes of
+/// :param list pairs: An optional list of node index pairs to score. If it's
+///     not set all the pairs of distinct nodes which aren't adjacent are
+///     considered.
+/// :param float threshold: Only the pairs with a score strictly above this
+///     value are returned when ``pairs`` is not set. By default this is
+///     ``0.0``. It is ignored if ``pairs`` is set.
+///
+/// :returns: A tuple of a numpy array with a row of the two node indices of
+///     each pair, in the order they were given if ``pairs`` is set, and a
+///     numpy array of their scores
+/// :rtype: tuple
+///
+/// :raises InvalidNode: If a node index in ``pairs`` is not present in the
+///     graph
+#[pyfunction(threshold = "0.0")]
+#[pyo3(text_signature = "(graph, /, pairs=None, threshold=0.0)")]
+pub fn resource_allocation_index(
+    py: Python,
+    graph: &graph::PyGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    threshold: f64,
+) -> PyResult<(PyObject, PyObject)> {
+    link_prediction(py, graph, Index::ResourceAllocation, pairs, threshold)
+}
@@ -0,0 +1,377 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::algo;
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+
+use crate::clusters::Clusters;
+use crate::key_index::KeyIndexes;
+use crate::transaction::MutationLog;
+use crate::{digraph, graph, NodeIndex, StablePyGraph};
+
+/// The simple adjacency of a graph, by node index, with scratch space to
+/// work on one subset of its nodes at a time.
+struct Decomposer {
+    neighbors: Vec<Vec<usize>>,
+    in_set: Vec<bool>,
+    position: Vec<usize>,
+    mark: Vec<usize>,
+    stamp: usize,
+    count: Vec<usize>,
+    counted: Vec<usize>,
+}
+
+impl Decomposer {
+    fn new(graph: &graph::PyGraph) -> Self {
+        let bound = graph.graph.node_bound();
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); bound];
+        for node in graph.graph.node_indices() {
+            let adjacent = &mut neighbors[node.index()];
+            adjacent.extend(
+                graph
+                    .graph
+                    .neighbors(node)
+                    .map(|neighbor| neighbor.index())
+                    .filter(|neighbor| *neighbor != node.index()),
+            );
+            adjacent.sort_unstable();
+            adjacent.dedup();
+        }
+        Decomposer {
+            neighbors,
+            in_set: vec![false; bound],
+            position: vec![0; bound],
+            mark: vec![0; bound],
+            stamp: 0,
+            count: vec![0; bound],
+            counted: vec![0; bound],
+        }
+    }
+
+    /// Split the nodes of a set into the connected components of the graph
+    /// induced by the set.
+    fn components(&self, set: &[usize]) -> Vec<Vec<usize>> {
+        let mut seen: Vec<usize> = Vec::new();
+        let mut visited = vec![false; set.len()];
+        let mut components = Vec::new();
+        for (index, start) in set.iter().enumerate() {
+            if visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            seen.push(*start);
+            let mut component = Vec::new();
+            while let Some(node) = seen.pop() {
+                component.push(node);
+                for neighbor in &self.neighbors[node] {
+                    if self.in_set[*neighbor] && !visited[self.position[*neighbor]] {
+                        visited[self.position[*neighbor]] = true;
+                        seen.push(*neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Split the nodes of a set into the connected components of the
+    /// complement of the graph induced by the set.
+    fn co_components(&mut self, set: &[usize]) -> Vec<Vec<usize>> {
+        let mut unvisited: Vec<usize> = set.iter().rev().copied().collect();
+        let mut components = Vec::new();
+        while let Some(start) = unvisited.pop() {
+            let mut component = Vec::new();
+            let mut seen = vec![start];
+            while let Some(node) = seen.pop() {
+                component.push(node);
+                self.stamp += 1;
+                for neighbor in &self.neighbors[node] {
+                    self.mark[*neighbor] = self.stamp;
+                }
+                // Every unvisited node not adjacent to this one joins the
+                // component, which costs as much as the edges kept.
+                let stamp = self.stamp;
+                let mark = &self.mark;
+                let (adjacent, others): (Vec<usize>, Vec<usize>) = unvisited
+                    .iter()
+                    .partition(|candidate| mark[**candidate] == stamp);
+                unvisited = adjacent;
+                seen.extend(others);
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Return the smallest module of the graph induced by a set which
+    /// contains two of its nodes, or ``None`` if that is the whole set.
+    ///
+    /// Nodes outside of the module which are adjacent to some but not all of
+    /// its nodes are added to it until there are none. Such a node is either
+    /// adjacent to the node just added and to no other node of the module,
+    /// or adjacent to all the other nodes of the module but not that one, so
+    /// only the nodes adjacent to all of the module need to be tracked.
+    fn closure(&mut self, set: &[usize], first: usize, second: usize) -> Option<Vec<usize>> {
+        self.stamp += 1;
+        let stamp = self.stamp;
+        let mut module = vec![first];
+        let mut pending = vec![second];
+        self.mark[first] = stamp;
+        self.mark[second] = stamp;
+        let mut full: Vec<usize> = Vec::new();
+        for neighbor in &self.neighbors[first] {
+            if self.in_set[*neighbor] {
+                self.count[*neighbor] = 1;
+                self.counted[*neighbor] = stamp;
+                if self.mark[*neighbor] != stamp {
+                    full.push(*neighbor);
+                }
+            }
+        }
+        while let Some(node) = pending.pop() {
+            module.push(node);
+            if module.len() + pending.len() == set.len() {
+                return None;
+            }
+            for neighbor in &self.neighbors[node] {
+                if !self.in_set[*neighbor] {
+                    continue;
+                }
+                if self.counted[*neighbor] != stamp {
+                    self.counted[*neighbor] = stamp;
+                    self.count[*neighbor] = 0;
+                }
+                self.count[*neighbor] += 1;
+                if self.count[*neighbor] == 1 && self.mark[*neighbor] != stamp {
+                    self.mark[*neighbor] = stamp;
+                    pending.push(*neighbor);
+                }
+            }
+            let mut still_full = Vec::with_capacity(full.len());
+            for candidate in full {
+                if self.mark[candidate] == stamp {
+                    continue;
+                }
+                if self.count[candidate] == module.len() {
+                    still_full.push(candidate);
+                } else {
+                    self.mark[candidate] = stamp;
+                    pending.push(candidate);
+                }
+            }
+            full = still_full;
+        }
+        Some(module)
+    }
+
+    /// Split the nodes of a set, whose induced graph and its complement are
+    /// both connected, into its maximal strong modules.
+    ///
+    /// The other nodes of the set are first split into the maximal modules
+    /// not containing its first node by refining the partition into its
+    /// neighbors and non neighbors until no node outside of a part is
+    /// adjacent to some but not all of it. Those parts are all maximal strong
+    /// modules except the ones inside the maximal strong module of the first
+    /// node, which are the parts with a node whose smallest module with the
+    /// first node isn't the whole set.
+    fn maximal_modules(&mut self, set: &[usize]) -> Vec<Vec<usize>> {
+        let first = set[0];
+        self.stamp += 1;
+        for neighbor in &self.neighbors[first] {
+            self.mark[*neighbor] = self.stamp;
+        }
+        let (adjacent, others): (Vec<usize>, Vec<usize>) = set[1..]
+            .iter()
+            .partition(|node| self.mark[**node] == self.stamp);
+        let mut parts: Vec<Vec<usize>> = vec![adjacent, others];
+        parts.retain(|part| !part.is_empty());
+        let mut part_of = vec![0; set.len()];
+        for (index, part) in parts.iter().enumerate() {
+            for node in part {
+                part_of[self.position[*node]] = index;
+            }
+        }
+        let mut moved: Vec<Vec<usize>> = vec![Vec::new(); parts.len()];
+        let mut queued = vec![true; set.len()];
+        let mut queue: Vec<usize> = set[1..].to_vec();
+        while let Some(pivot) = queue.pop() {
+            queued[self.position[pivot]] = false;
+            let own = part_of[self.position[pivot]];
+            let mut touched: Vec<usize> = Vec::new();
+            for neighbor in &self.neighbors[pivot] {
+                if !self.in_set[*neighbor] || *neighbor == first {
+                    continue;
+                }
+                let part = part_of[self.position[*neighbor]];
+                if part != own {
+                    if moved[part].is_empty() {
+                        touched.push(part);
+                    }
+                    moved[part].push(*neighbor);
+                }
+            }
+            for part in touched {
+                let split = std::mem::take(&mut moved[part]);
+                if split.len() == parts[part].len() {
+                    continue;
+                }
+                self.stamp += 1;
+                let new_part = parts.len();
+                for node in &split {
+                    self.mark[*node] = self.stamp;
+                    part_of[self.position[*node]] = new_part;
+                }
+                let stamp = self.stamp;
+                let mark = &self.mark;
+                parts[part].retain(|node| mark[*node] != stamp);
+                parts.push(split);
+                moved.push(Vec::new());
+                // The nodes of the two halves were never compared with the
+                // other half
+                for node in parts[part].iter().chain(parts[new_part].iter()) {
+                    if !queued[self.position[*node]] {
+                        queued[self.position[*node]] = true;
+                        queue.push(*node);
+                    }
+                }
+            }
+        }
+        let mut module = vec![first];
+        let mut modules = Vec::new();
+        for part in parts {
+            if self.closure(set, first, part[0]).is_some() {
+                module.extend(part);
+            } else {
+                modules.push(part);
+            }
+        }
+        modules.push(module);
+        modules
+    }
+
+    /// Find the type of the root of the modular decomposition tree of the
+    /// graph induced by a set of nodes and the sets of its children.
+    fn split(&mut self, set: &[usize]) -> (&'static str, Vec<Vec<usize>>) {
+        if set.len() == 1 {
+            return ("leaf", Vec::new());
+        }
+        for (index, node) in set.iter().enumerate() {
+            self.in_set[*node] = true;
+            self.position[*node] = index;
+        }
+        let mut children = self.components(set);
+        let mut kind = "parallel";
+        if children.len() == 1 {
+            children = self.co_components(set);
+            kind = "series";
+            if children.len() == 1 {
+                children = self.maximal_modules(set);
+                kind = "prime";
+            }
+        }
+        for node in set {
+            self.in_set[*node] = false;
+        }
+        for child in children.iter_mut() {
+            child.sort_unstable();
+        }
+        children.sort_unstable_by_key(|child| child[0]);
+        (kind, children)
+    }
+}
+
+/// Compute the modular decomposition of an undirected graph
+///
+/// A module of a graph is a set of nodes which all have the same neighbors
+/// outside of the set, and it is strong if it doesn't partially overlap any
+/// other module. The strong modules form a tree, the modular decomposition
+/// tree, whose root is the set of all the nodes and whose leaves are the
+/// single nodes. Every other node of the tree is the union of its children
+/// and is one of:
+///
+/// * ``"parallel"``: the graph induced by the module is disconnected and its
+///   children are its connected components
+/// * ``"series"``: the complement of the graph induced by the module is
+///   disconnected and its children are the connected components of the
+///   complement
+/// * ``"prime"``: the graph induced by the module and its complement are both
+///   connected and its children are its maximal proper modules
+///
+/// The graph is a cograph if and only if its tree has no prime nodes. Edge
+/// directions, parallel edges and self loops are ignored.
+///
+/// The tree is built top down by splitting every module into its children.
+/// The children of series and parallel modules are found in linear time,
+/// while the maximal modules of a prime module are found by partition
+/// refinement, which takes :math:`O(|V||E|)` time in the worst case.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   graph.add_nodes_from(range(3))
+///   graph.add_edges_from_no_data([(4, 1), (5, 3), (6, 3), (5, 6)])
+///   tree = retworkx.modular_decomposition(graph)
+///   for node in tree.node_indices():
+///       print(node, tree[node], list(tree.successor_indices(node)))
+///
+/// :param PyGraph graph: The graph to decompose
+///
+/// :returns: The modular decomposition tree, with an edge from every module
+///     to each of its children. The nodes of the tree are indexed in depth
+///     first order from the root at index ``0``, with the children of every
+///     module in order of their smallest node. The data of every node of the tree is a tuple of its
+///     type, one of ``"leaf"``, ``"parallel"``, ``"series"`` or ``"prime"``,
+///     and a sorted list of the indices of the nodes of the graph in the
+///     module. If the graph has no nodes the tree is empty.
+/// :rtype: PyDiGraph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn modular_decomposition(py: Python, graph: &graph::PyGraph) -> digraph::PyDiGraph {
+    let mut tree = StablePyGraph::<Directed>::default();
+    let mut decomposer = Decomposer::new(graph);
+    let nodes: Vec<usize> = graph.graph.node_indices().map(|n| n.index()).collect();
+    let mut stack: Vec<(Vec<usize>, Option<NodeIndex>)> = Vec::new();
+    if !nodes.is_empty() {
+        stack.push((nodes, None));
+    }
+    while let Some((set, parent)) = stack.pop() {
+        let (kind, children) = decomposer.split(&set);
+        let module = tree.add_node((kind, set).into_py(py));
+        if let Some(parent) = parent {
+            tree.add_edge(parent, module, py.None());
+        }
+        // Push the children in reverse so they're added to the tree in order
+        stack.extend(
+            children
+                .into_iter()
+                .rev()
+                .map(|child| (child, Some(module))),
+        );
+    }
+    digraph::PyDiGraph {
+        graph: tree.into(),
+        cycle_state: algo::DfsSpace::default(),
+        check_cycle: false,
+        node_removed: false,
+        multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
+    }
+}
@@ -0,0 +1,252 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{
+    Data, FilterNode, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+    IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCount, NodeFiltered,
+    NodeIndexable, Visitable,
+};
+use petgraph::EdgeType;
+
+use numpy::PyReadonlyArray1;
+
+use retworkx_core::contains::ContainsNode;
+
+use crate::{InvalidNode, NodeIndex, StablePyGraph};
+
+/// The nodes of a graph which aren't masked by a ``node_mask`` argument,
+/// either a boolean numpy array indexed by node index or an iterable of node
+/// indices. The masked nodes are hidden by the [`NodeMasked`] view so
+/// algorithms treat them as removed without building a subgraph.
+pub struct NodeMask {
+    mask: Option<FixedBitSet>,
+    node_count: usize,
+}
+
+impl NodeMask {
+    pub fn new<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        node_mask: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let node_mask = match node_mask {
+            Some(node_mask) => node_mask,
+            None => {
+                return Ok(NodeMask {
+                    mask: None,
+                    node_count: graph.node_count(),
+                })
+            }
+        };
+        let mut masked = FixedBitSet::with_capacity(graph.node_bound());
+        if node_mask.hasattr("dtype")? {
+            let kwargs = [("dtype", "bool")].into_py_dict(py);
+            let array: PyReadonlyArray1<bool> = py
+                .import("numpy")?
+                .call_method("asarray", (node_mask,), Some(kwargs))?
+                .extract()?;
+            let array = array.as_array();
+            if array.len() < graph.node_bound() {
+                return Err(PyValueError::new_err(format!(
+                    "The node mask has {} entries but the largest node index is {}",
+                    array.len(),
+                    graph.node_bound() - 1
+                )));
+            }
+            for (index, is_masked) in array.iter().take(graph.node_bound()).enumerate() {
+                masked.set(index, *is_masked);
+            }
+        } else {
+            for node in node_mask.iter()? {
+                let node: usize = node?.extract()?;
+                if !graph.contains_node(NodeIndex::new(node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node index {} is not in the graph",
+                        node
+                    )));
+                }
+                masked.insert(node);
+            }
+        }
+        let mut mask = FixedBitSet::with_capacity(graph.node_bound());
+        for node in graph.node_indices() {
+            if !masked[node.index()] {
+                mask.insert(node.index());
+            }
+        }
+        Ok(NodeMask {
+            node_count: mask.count_ones(..),
+            mask: Some(mask),
+        })
+    }
+
+    pub fn contains(&self, node: NodeIndex) -> bool {
+        match &self.mask {
+            Some(mask) => mask.contains(node.index()),
+            None => true,
+        }
+    }
+
+    /// A view of ``graph`` without the masked nodes and their edges.
+    pub fn filter<G>(&self, graph: G) -> NodeMasked<'_, G> {
+        NodeMasked {
+            graph: NodeFiltered(graph, self),
+            node_count: self.node_count,
+        }
+    }
+}
+
+impl FilterNode<NodeIndex> for &NodeMask {
+    fn include_node(&self, node: NodeIndex) -> bool {
+        self.contains(node)
+    }
+}
+
+/// A graph view hiding the masked nodes of a [`NodeMask`]. This delegates to
+/// petgraph's ``NodeFiltered`` adaptor, which doesn't implement
+/// ``NodeCount``, and counts only the nodes which aren't masked.
+#[derive(Clone, Copy)]
+pub struct NodeMasked<'a, G> {
+    graph: NodeFiltered<G, &'a NodeMask>,
+    node_count: usize,
+}
+
+impl<'a, G: GraphBase> GraphBase for NodeMasked<'a, G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<'a, G: Data> Data for NodeMasked<'a, G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<'a, G: GraphProp> GraphProp for NodeMasked<'a, G> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<'a, G: NodeIndexable> NodeIndexable for NodeMasked<'a, G> {
+    fn node_bound(&self) -> usize {
+        self.graph.0.node_bound()
+    }
+
+    fn to_index(&self, node: G::NodeId) -> usize {
+        self.graph.0.to_index(node)
+    }
+
+    fn from_index(&self, index: usize) -> G::NodeId {
+        self.graph.0.from_index(index)
+    }
+}
+
+impl<'a, G: GraphBase> NodeCount for NodeMasked<'a, G> {
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+}
+
+// A masked node is still present, so searching from it finds nothing
+// instead of raising an error like a removed node.
+impl<'a, G: ContainsNode> ContainsNode for NodeMasked<'a, G> {
+    fn contains_node(&self, node: G::NodeId) -> bool {
+        self.graph.0.contains_node(node)
+    }
+}
+
+impl<'a, G: Visitable> Visitable for NodeMasked<'a, G> {
+    type Map = G::Map;
+
+    fn visit_map(&self) -> G::Map {
+        self.graph.0.visit_map()
+    }
+
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.0.reset_map(map)
+    }
+}
+
+impl<'b, 'a, G> IntoNeighbors for &'b NodeMasked<'a, G>
+where
+    G: IntoNeighbors<NodeId = NodeIndex>,
+{
+    type Neighbors = <&'b NodeFiltered<G, &'a NodeMask> as IntoNeighbors>::Neighbors;
+
+    fn neighbors(self, node: NodeIndex) -> Self::Neighbors {
+        (&self.graph).neighbors(node)
+    }
+}
+
+impl<'b, 'a, G> IntoNeighborsDirected for &'b NodeMasked<'a, G>
+where
+    G: IntoNeighborsDirected<NodeId = NodeIndex>,
+{
+    type NeighborsDirected =
+        <&'b NodeFiltered<G, &'a NodeMask> as IntoNeighborsDirected>::NeighborsDirected;
+
+    fn neighbors_directed(self, node: NodeIndex, direction: Direction) -> Self::NeighborsDirected {
+        (&self.graph).neighbors_directed(node, direction)
+    }
+}
+
+impl<'b, 'a, G> IntoNodeIdentifiers for &'b NodeMasked<'a, G>
+where
+    G: IntoNodeIdentifiers<NodeId = NodeIndex>,
+{
+    type NodeIdentifiers =
+        <&'b NodeFiltered<G, &'a NodeMask> as IntoNodeIdentifiers>::NodeIdentifiers;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        (&self.graph).node_identifiers()
+    }
+}
+
+impl<'b, 'a, G> IntoEdgeReferences for &'b NodeMasked<'a, G>
+where
+    G: IntoEdgeReferences<NodeId = NodeIndex>,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = <&'b NodeFiltered<G, &'a NodeMask> as IntoEdgeReferences>::EdgeReferences;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        (&self.graph).edge_references()
+    }
+}
+
+impl<'b, 'a, G> IntoEdges for &'b NodeMasked<'a, G>
+where
+    G: IntoEdges<NodeId = NodeIndex>,
+{
+    type Edges = <&'b NodeFiltered<G, &'a NodeMask> as IntoEdges>::Edges;
+
+    fn edges(self, node: NodeIndex) -> Self::Edges {
+        (&self.graph).edges(node)
+    }
+}
+
+impl<'b, 'a, G> IntoEdgesDirected for &'b NodeMasked<'a, G>
+where
+    G: IntoEdgesDirected<NodeId = NodeIndex>,
+{
+    type EdgesDirected = <&'b NodeFiltered<G, &'a NodeMask> as IntoEdgesDirected>::EdgesDirected;
+
+    fn edges_directed(self, node: NodeIndex, direction: Direction) -> Self::EdgesDirected {
+        (&self.graph).edges_directed(node, direction)
+    }
+}
@@ -0,0 +1,709 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+
+use hashbrown::HashSet;
+
+use crate::iterators::{EdgeIndices, NodeIndices};
+use crate::{
+    digraph, graph, weight_callable, EdgeIndex, InvalidNode, NoEdgeBetweenNodes, NodeIndex,
+    StablePyGraph,
+};
+
+fn is_path<Ty: EdgeType>(graph: &StablePyGraph<Ty>, path: &[usize]) -> bool {
+    !path.is_empty()
+        && path
+            .iter()
+            .all(|node| graph.contains_node(NodeIndex::new(*node)))
+        && path
+            .windows(2)
+            .all(|pair| graph.contains_edge(NodeIndex::new(pair[0]), NodeIndex::new(pair[1])))
+}
+
+fn check_nodes<Ty: EdgeType>(graph: &StablePyGraph<Ty>, path: &[usize]) -> PyResult<()> {
+    match path
+        .iter()
+        .find(|node| !graph.contains_node(NodeIndex::new(**node)))
+    {
+        Some(node) => Err(InvalidNode::new_err(format!(
+            "Node index {} of the path is not present in the graph",
+            node
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Check that a list of nodes is a path, raising the same errors as
+/// [`path_edges`] if it isn't.
+fn check_path<Ty: EdgeType>(graph: &StablePyGraph<Ty>, path: &[usize]) -> PyResult<()> {
+    check_nodes(graph, path)?;
+    match path
+        .windows(2)
+        .find(|pair| !graph.contains_edge(NodeIndex::new(pair[0]), NodeIndex::new(pair[1])))
+    {
+        Some(pair) => Err(NoEdgeBetweenNodes::new_err(format!(
+            "No edge between nodes {} and {} of the path",
+            pair[0], pair[1]
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// The edges along a path of nodes. The path doesn't identify which of any
+/// parallel edges is traversed, so the edge with the lowest cost between
+/// each pair of nodes is used, the one with the lowest index on a tie.
+pub fn path_edges<Ty, F>(
+    graph: &StablePyGraph<Ty>,
+    path: &[usize],
+    mut cost: F,
+) -> PyResult<Vec<(EdgeIndex, f64)>>
+where
+    Ty: EdgeType,
+    F: FnMut(EdgeIndex, &PyObject) -> PyResult<f64>,
+{
+    check_nodes(graph, path)?;
+    let mut edges: Vec<(EdgeIndex, f64)> = Vec::with_capacity(path.len().saturating_sub(1));
+    for pair in path.windows(2) {
+        let (source, target) = (NodeIndex::new(pair[0]), NodeIndex::new(pair[1]));
+        let mut best: Option<(EdgeIndex, f64)> = None;
+        for edge in graph.edges(source).filter(|edge| edge.target() == target) {
+            let edge_cost = cost(edge.id(), edge.weight())?;
+            let better = match best {
+                Some((best_edge, best_cost)) => {
+                    edge_cost < best_cost || edge_cost == best_cost && edge.id() < best_edge
+                }
+                None => true,
+            };
+            if better {
+                best = Some((edge.id(), edge_cost));
+            }
+        }
+        match best {
+            Some(best) => edges.push(best),
+            None => {
+                return Err(NoEdgeBetweenNodes::new_err(format!(
+                    "No edge between nodes {} and {} of the path",
+                    pair[0], pair[1]
+                )))
+            }
+        }
+    }
+    Ok(edges)
+}
+
+/// The total cost of a path of edges. Consecutive edges have to share an
+/// endpoint, in the direction of the edges for a directed graph.
+fn edge_path_weight<Ty, F>(graph: &StablePyGraph<Ty>, path: &[usize], mut cost: F) -> PyResult<f64>
+where
+    Ty: EdgeType,
+    F: FnMut(EdgeIndex, &PyObject) -> PyResult<f64>,
+{
+    // The node the path could be at after each edge, an undirected edge can
+    // be traversed either way.
+    let mut ends: Vec<NodeIndex> = Vec::with_capacity(2);
+    let mut total = 0.;
+    for (position, edge) in path.iter().enumerate() {
+        let index = EdgeIndex::new(*edge);
+        let (source, target) = match graph.edge_endpoints(index) {
+            Some(endpoints) => endpoints,
+            None => {
+                return Err(PyIndexError::new_err(format!(
+                    "Edge index {} of the path is not present in the graph",
+                    edge
+                )))
+            }
+        };
+        let mut next_ends: Vec<NodeIndex> = Vec::with_capacity(2);
+        if position == 0 || ends.contains(&source) {
+            next_ends.push(target);
+        }
+        if !Ty::is_directed() && (position == 0 || ends.contains(&target)) {
+            next_ends.push(source);
+        }
+        if next_ends.is_empty() {
+            return Err(PyValueError::new_err(format!(
+                "Edge index {} of the path doesn't continue from the previous edge",
+                edge
+            )));
+        }
+        ends = next_ends;
+        total += cost(index, graph.edge_weight(index).unwrap())?;
+    }
+    Ok(total)
+}
+
+fn path_weight<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    path: &[usize],
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    edge_path: bool,
+) -> PyResult<f64> {
+    let cost = |_, weight: &PyObject| weight_callable(py, &weight_fn, weight, default_weight);
+    if edge_path {
+        edge_path_weight(graph, path, cost)
+    } else {
+        Ok(path_edges(graph, path, cost)?
+            .into_iter()
+            .map(|(_, cost)| cost)
+            .sum())
+    }
+}
+
+fn edges_of_path<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    path: &[usize],
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    let cost = |_, weight: &PyObject| weight_callable(py, &weight_fn, weight, default_weight);
+    Ok(EdgeIndices {
+        edges: path_edges(graph, path, cost)?
+            .into_iter()
+            .map(|(edge, _)| edge.index())
+            .collect(),
+    })
+}
+
+/// Check if a list of nodes is a path in a :class:`~retworkx.PyGraph`
+///
+/// The nodes are a path if they're all in the graph and there is an edge
+/// between each pair of consecutive nodes. The path doesn't have to be
+/// simple, it can visit a node more than once.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   print(retworkx.graph_is_path(graph, [3, 2, 1]))
+///   print(retworkx.graph_is_path(graph, [0, 2]))
+///
+/// :param PyGraph graph: The graph to check the path in
+/// :param list path: The node indices of the path
+///
+/// :returns: ``True`` if ``path`` is a path in the graph, an empty list is
+///     not a path
+/// :rtype: bool
+#[pyfunction]
+#[pyo3(text_signature = "(graph, path, /)")]
+pub fn graph_is_path(graph: &graph::PyGraph, path: Vec<usize>) -> bool {
+    is_path(&graph.graph, &path)
+}
+
+/// Check if a list of nodes is a path in a :class:`~retworkx.PyDiGraph`
+///
+/// The nodes are a path if they're all in the graph and there is an edge
+/// from each node to the next node. The path doesn't have to be simple, it
+/// can visit a node more than once.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(4)
+///   print(retworkx.digraph_is_path(graph, [1, 2, 3]))
+///   print(retworkx.digraph_is_path(graph, [3, 2, 1]))
+///
+/// :param PyDiGraph graph: The graph to check the path in
+/// :param list path: The node indices of the path
+///
+/// :returns: ``True`` if ``path`` is a path in the graph, an empty list is
+///     not a path
+/// :rtype: bool
+#[pyfunction]
+#[pyo3(text_signature = "(graph, path, /)")]
+pub fn digraph_is_path(graph: &digraph::PyDiGraph, path: Vec<usize>) -> bool {
+    is_path(&graph.graph, &path)
+}
+
+/// Compute the total weight of a path in a :class:`~retworkx.PyGraph`
+///
+/// The path is either a list of node indices or, if ``edge_path`` is set, a
+/// list of edge indices such as those returned by
+/// :func:`~retworkx.graph_all_simple_edge_paths`. If there are parallel
+/// edges between two consecutive nodes of a path of nodes the edge with the
+/// lowest weight is used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0)])
+///   print(retworkx.graph_path_weight(graph, [0, 1, 2], weight_fn=float))
+///   print(retworkx.graph_path_weight(graph, [0, 1], weight_fn=float, edge_path=True))
+///
+/// :param PyGraph graph: The graph to compute the path weight in
+/// :param list path: The node indices, or edge indices if ``edge_path`` is
+///     set, of the path
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight/cost of each edge.
+/// :param bool edge_path: If set to ``True`` ``path`` is a list of edge
+///     indices instead of node indices
+///
+/// :returns: The sum of the weights of the edges of the path
+/// :rtype: float
+/// :raises InvalidNode: If a node of the path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge between two consecutive
+///     nodes of the path
+/// :raises IndexError: If an edge of the path isn't in the graph
+/// :raises ValueError: If two consecutive edges of the path don't share an
+///     endpoint
+#[pyfunction(default_weight = "1.0", edge_path = "false")]
+#[pyo3(text_signature = "(graph, path, /, weight_fn=None, default_weight=1.0, edge_path=False)")]
+pub fn graph_path_weight(
+    py: Python,
+    graph: &graph::PyGraph,
+    path: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    edge_path: bool,
+) -> PyResult<f64> {
+    path_weight(
+        py,
+        &graph.graph,
+        &path,
+        weight_fn,
+        default_weight,
+        edge_path,
+    )
+}
+
+/// Compute the total weight of a path in a :class:`~retworkx.PyDiGraph`
+///
+/// The path is either a list of node indices or, if ``edge_path`` is set, a
+/// list of edge indices such as those returned by
+/// :func:`~retworkx.digraph_all_simple_edge_paths`. If there are parallel
+/// edges between two consecutive nodes of a path of nodes the edge with the
+/// lowest weight is used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0)])
+///   print(retworkx.digraph_path_weight(graph, [0, 1, 2], weight_fn=float))
+///   print(retworkx.digraph_path_weight(graph, [0, 1], weight_fn=float, edge_path=True))
+///
+/// :param PyDiGraph graph: The graph to compute the path weight in
+/// :param list path: The node indices, or edge indices if ``edge_path`` is
+///     set, of the path
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight/cost of each edge.
+/// :param bool edge_path: If set to ``True`` ``path`` is a list of edge
+///     indices instead of node indices
+///
+/// :returns: The sum of the weights of the edges of the path
+/// :rtype: float
+/// :raises InvalidNode: If a node of the path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge from a node of the path
+///     to the next node
+/// :raises IndexError: If an edge of the path isn't in the graph
+/// :raises ValueError: If an edge of the path doesn't start where the
+///     previous edge ends
+#[pyfunction(default_weight = "1.0", edge_path = "false")]
+#[pyo3(text_signature = "(graph, path, /, weight_fn=None, default_weight=1.0, edge_path=False)")]
+pub fn digraph_path_weight(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    path: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    edge_path: bool,
+) -> PyResult<f64> {
+    path_weight(
+        py,
+        &graph.graph,
+        &path,
+        weight_fn,
+        default_weight,
+        edge_path,
+    )
+}
+
+/// Get the edges along a path of nodes in a :class:`~retworkx.PyGraph`
+///
+/// A path of nodes doesn't identify which of any parallel edges between two
+/// consecutive nodes is traversed, the edge with the lowest weight is used
+/// and on a tie the one with the lowest index.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0)])
+///   print(retworkx.graph_edges_of_path(graph, [0, 1, 2], weight_fn=float))
+///
+/// :param PyGraph graph: The graph the path is in
+/// :param list path: The node indices of the path
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to choose between parallel edges
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+///
+/// :returns: The edge indices along the path
+/// :rtype: EdgeIndices
+/// :raises InvalidNode: If a node of the path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge between two consecutive
+///     nodes of the path
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, path, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_edges_of_path(
+    py: Python,
+    graph: &graph::PyGraph,
+    path: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    edges_of_path(py, &graph.graph, &path, weight_fn, default_weight)
+}
+
+/// Get the edges along a path of nodes in a :class:`~retworkx.PyDiGraph`
+///
+/// A path of nodes doesn't identify which of any parallel edges between two
+/// consecutive nodes is traversed, the edge with the lowest weight is used
+/// and on a tie the one with the lowest index.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_weighted_edge_list([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0)])
+///   print(retworkx.digraph_edges_of_path(graph, [0, 1, 2], weight_fn=float))
+///
+/// :param PyDiGraph graph: The graph the path is in
+/// :param list path: The node indices of the path
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to choose between parallel edges
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+///
+/// :returns: The edge indices along the path
+/// :rtype: EdgeIndices
+/// :raises InvalidNode: If a node of the path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge from a node of the path
+///     to the next node
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, path, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_edges_of_path(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    path: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    edges_of_path(py, &graph.graph, &path, weight_fn, default_weight)
+}
+
+/// The longest contiguous run of nodes that is in both ``first`` and
+/// ``second``, the earliest one in ``first`` on a tie, as its length and the
+/// position in ``first`` it ends at.
+fn longest_common_run(first: &[usize], second: &[usize]) -> (usize, usize) {
+    let mut best = (0, 0);
+    let mut previous: Vec<usize> = vec![0; second.len() + 1];
+    let mut current: Vec<usize> = vec![0; second.len() + 1];
+    for (i, a) in first.iter().enumerate() {
+        for (j, b) in second.iter().enumerate() {
+            current[j + 1] = if a == b { previous[j] + 1 } else { 0 };
+            if current[j + 1] > best.0 {
+                best = (current[j + 1], i + 1);
+            }
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    best
+}
+
+fn longest_common_subpath<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    first: &[usize],
+    second: &[usize],
+) -> PyResult<NodeIndices> {
+    check_path(graph, first)?;
+    check_path(graph, second)?;
+    let (mut length, mut end) = longest_common_run(first, second);
+    if !Ty::is_directed() {
+        // An undirected path is the same path traversed backwards
+        let reversed: Vec<usize> = second.iter().rev().copied().collect();
+        let (reversed_length, reversed_end) = longest_common_run(first, &reversed);
+        if reversed_length > length || reversed_length == length && reversed_end < end {
+            length = reversed_length;
+            end = reversed_end;
+        }
+    }
+    Ok(NodeIndices {
+        nodes: first[end - length..end].to_vec(),
+    })
+}
+
+fn path_jaccard_similarity<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    first: &[usize],
+    second: &[usize],
+) -> PyResult<f64> {
+    check_path(graph, first)?;
+    check_path(graph, second)?;
+    let edge_set = |path: &[usize]| -> HashSet<(usize, usize)> {
+        path.windows(2)
+            .map(|pair| {
+                if !Ty::is_directed() && pair[0] > pair[1] {
+                    (pair[1], pair[0])
+                } else {
+                    (pair[0], pair[1])
+                }
+            })
+            .collect()
+    };
+    let first_edges = edge_set(first);
+    let second_edges = edge_set(second);
+    let union = first_edges.union(&second_edges).count();
+    if union == 0 {
+        return Ok(if first == second { 1. } else { 0. });
+    }
+    Ok(first_edges.intersection(&second_edges).count() as f64 / union as f64)
+}
+
+/// Find the longest common subpath of two paths in a :class:`~retworkx.PyGraph`
+///
+/// The longest common subpath is the longest run of consecutive nodes that
+/// is in both paths. As the edges are undirected the run can be in
+/// ``second`` in reverse. On a tie the run which ends first in ``first`` is
+/// returned.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   print(retworkx.graph_longest_common_subpath(graph, [0, 1, 2, 5, 8], [6, 7, 4, 1, 2, 5]))
+///
+/// :param PyGraph graph: The graph the paths are in
+/// :param list first: The node indices of the first path
+/// :param list second: The node indices of the second path
+///
+/// :returns: The node indices of the longest common subpath, in the order
+///     of ``first``. If the paths don't have a node in common it is empty
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If a node of a path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge between two consecutive
+///     nodes of a path
+#[pyfunction]
+#[pyo3(text_signature = "(graph, first, second, /)")]
+pub fn graph_longest_common_subpath(
+    graph: &graph::PyGraph,
+    first: Vec<usize>,
+    second: Vec<usize>,
+) -> PyResult<NodeIndices> {
+    longest_common_subpath(&graph.graph, &first, &second)
+}
+
+/// Find the longest common subpath of two paths in a :class:`~retworkx.PyDiGraph`
+///
+/// The longest common subpath is the longest run of consecutive nodes that
+/// is in both paths, in the same order. On a tie the run which ends first in
+/// ``first`` is returned.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(3, 3)
+///   print(retworkx.digraph_longest_common_subpath(graph, [0, 1, 2, 5, 8], [0, 3, 4, 5, 8]))
+///
+/// :param PyDiGraph graph: The graph the paths are in
+/// :param list first: The node indices of the first path
+/// :param list second: The node indices of the second path
+///
+/// :returns: The node indices of the longest common subpath. If the paths
+///     don't have a node in common it is empty
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If a node of a path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge from a node of a path to
+///     the next node
+#[pyfunction]
+#[pyo3(text_signature = "(graph, first, second, /)")]
+pub fn digraph_longest_common_subpath(
+    graph: &digraph::PyDiGraph,
+    first: Vec<usize>,
+    second: Vec<usize>,
+) -> PyResult<NodeIndices> {
+    longest_common_subpath(&graph.graph, &first, &second)
+}
+
+/// Compute the Jaccard similarity of the edges of two paths in a
+/// :class:`~retworkx.PyGraph`
+///
+/// The similarity is the number of edges traversed by both paths divided
+/// by the number of edges traversed by either path. An edge is identified by
+/// its endpoints, in either order, so parallel edges are the same edge and
+/// the direction a path traverses an edge in doesn't matter.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   print(retworkx.graph_path_jaccard_similarity(graph, [0, 1, 2, 5], [0, 1, 4, 5]))
+///
+/// :param PyGraph graph: The graph the paths are in
+/// :param list first: The node indices of the first path
+/// :param list second: The node indices of the second path
+///
+/// :returns: The similarity of the paths between ``0.0`` and ``1.0``. If
+///     neither path has an edge it is ``1.0`` if the paths are equal and
+///     ``0.0`` otherwise
+/// :rtype: float
+/// :raises InvalidNode: If a node of a path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge between two consecutive
+///     nodes of a path
+#[pyfunction]
+#[pyo3(text_signature = "(graph, first, second, /)")]
+pub fn graph_path_jaccard_similarity(
+    graph: &graph::PyGraph,
+    first: Vec<usize>,
+    second: Vec<usize>,
+) -> PyResult<f64> {
+    path_jaccard_similarity(&graph.graph, &first, &second)
+}
+
+/// Compute the Jaccard similarity of the edges of two paths in a
+/// :class:`~retworkx.PyDiGraph`
+///
+/// The similarity is the number of edges traversed by both paths divided
+/// by the number of edges traversed by either path. An edge is identified by
+/// its source and target, so parallel edges are the same edge.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(3, 3)
+///   print(retworkx.digraph_path_jaccard_similarity(graph, [0, 1, 2, 5], [0, 1, 4, 5]))
+///
+/// :param PyDiGraph graph: The graph the paths are in
+/// :param list first: The node indices of the first path
+/// :param list second: The node indices of the second path
+///
+/// :returns: The similarity of the paths between ``0.0`` and ``1.0``. If
+///     neither path has an edge it is ``1.0`` if the paths are equal and
+///     ``0.0`` otherwise
+/// :rtype: float
+/// :raises InvalidNode: If a node of a path isn't in the graph
+/// :raises NoEdgeBetweenNodes: If there is no edge from a node of a path to
+///     the next node
+#[pyfunction]
+#[pyo3(text_signature = "(graph, first, second, /)")]
+pub fn digraph_path_jaccard_similarity(
+    graph: &digraph::PyDiGraph,
+    first: Vec<usize>,
+    second: Vec<usize>,
+) -> PyResult<f64> {
+    path_jaccard_similarity(&graph.graph, &first, &second)
+}
+
+/// Compute the discrete Fréchet distance between two paths of nodes
+///
+/// The nodes are points at the positions given by ``pos``, and the
+/// distance is the shortest leash length needed to walk along both paths
+/// from their first to their last nodes, where each step moves forward on
+/// one or both of the paths. This is how far apart the paths are, taking
+/// the order of their nodes into account, which makes it a measure of how
+/// different two routes are.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   pos = {node: (node % 3, node // 3) for node in graph.node_indices()}
+///   print(retworkx.path_frechet_distance([0, 1, 2, 5, 8], [0, 3, 6, 7, 8], pos))
+///
+/// :param list first: The node indices of the first path
+/// :param list second: The node indices of the second path
+/// :param pos: A mapping of node index to the position of the node, as a
+///     sequence of coordinates, such as a dict or the output of one of the
+///     layout functions. Every position must have the same number of
+///     coordinates and the distance between two positions is the Euclidean
+///     distance
+///
+/// :returns: The discrete Fréchet distance between the paths
+/// :rtype: float
+/// :raises ValueError: If a path is empty or the positions don't all have
+///     the same number of coordinates
+#[pyfunction]
+#[pyo3(text_signature = "(first, second, pos, /)")]
+pub fn path_frechet_distance(first: Vec<usize>, second: Vec<usize>, pos: &PyAny) -> PyResult<f64> {
+    if first.is_empty() || second.is_empty() {
+        return Err(PyValueError::new_err("A path is empty"));
+    }
+    let positions = |path: &[usize]| -> PyResult<Vec<Vec<f64>>> {
+        path.iter()
+            .map(|node| pos.get_item(*node)?.extract::<Vec<f64>>())
+            .collect()
+    };
+    let first_pos = positions(&first)?;
+    let second_pos = positions(&second)?;
+    let dimension = first_pos[0].len();
+    if first_pos
+        .iter()
+        .chain(second_pos.iter())
+        .any(|point| point.len() != dimension)
+    {
+        return Err(PyValueError::new_err(
+            "The positions don't all have the same number of coordinates",
+        ));
+    }
+    let distance = |a: &[f64], b: &[f64]| -> f64 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f64>()
+            .sqrt()
+    };
+    // The leash length needed to reach each node of ``second`` while at the
+    // current node of ``first``
+    let mut previous: Vec<f64> = Vec::with_capacity(second_pos.len());
+    for (i, a) in first_pos.iter().enumerate() {
+        let mut current: Vec<f64> = Vec::with_capacity(second_pos.len());
+        for (j, b) in second_pos.iter().enumerate() {
+            let reach = match (i, j) {
+                (0, 0) => 0.,
+                (0, _) => current[j - 1],
+                (_, 0) => previous[0],
+                _ => previous[j].min(previous[j - 1]).min(current[j - 1]),
+            };
+            current.push(reach.max(distance(a, b)));
+        }
+        previous = current;
+    }
+    Ok(previous[second_pos.len() - 1])
+}
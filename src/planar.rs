@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::planar::{self, Planarity};
+
+use crate::graph;
+use crate::iterators::EdgeList;
+
+/// Check whether a graph is planar
+///
+/// A graph is planar if it can be drawn in the plane without any of its
+/// edges crossing. This uses the left-right planarity test of Brandes,
+/// which runs in linear time.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(5)
+///   print(retworkx.is_planar(graph))
+///
+/// :param PyGraph graph: The undirected graph to test. Self loops and
+///     parallel edges don't change the planarity of a graph and are ignored.
+///
+/// :returns: ``True`` if the graph is planar and ``False`` otherwise
+/// :rtype: bool
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn is_planar(py: Python, graph: &graph::PyGraph) -> bool {
+    py.allow_threads(|| planar::is_planar(&graph.graph))
+}
+
+/// Check whether a graph is planar and return a certificate
+///
+/// If the graph is planar this returns a combinatorial planar embedding of
+/// it, a dictionary mapping each node index to the list of its neighbors in
+/// clockwise order around it in a drawing of the graph without crossing
+/// edges. Self loops and parallel edges are ignored and don't appear in the
+/// embedding.
+///
+/// If the graph isn't planar this returns the edges of a Kuratowski
+/// subgraph, a subgraph of the graph which is a subdivision of the complete
+/// graph :math:`K_5` or of the complete bipartite graph :math:`K_{3,3}`. It's
+/// found by removing the edges of the graph one at a time and keeping those
+/// whose removal makes the graph planar, so this runs in quadratic time for
+/// graphs which aren't planar.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(4)
+///   print(retworkx.check_planarity(graph))
+///   graph.extend_from_edge_list([(0, 2), (1, 3), (4, 0), (4, 1), (4, 2), (4, 3)])
+///   print(retworkx.check_planarity(graph))
+///
+/// :param PyGraph graph: The undirected graph to test
+///
+/// :returns: A tuple of whether the graph is planar and either the planar
+///     embedding as a dictionary of node indices to lists of node indices if
+///     it's planar or an edge list of a Kuratowski subgraph if it's not.
+/// :rtype: tuple
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn check_planarity(py: Python, graph: &graph::PyGraph) -> (bool, PyObject) {
+    let result = py.allow_threads(|| planar::planarity(&graph.graph));
+    match result {
+        Planarity::Planar(embedding) => {
+            let embedding = embedding
+                .into_iter()
+                .map(|(node, neighbors)| {
+                    (
+                        node.index(),
+                        neighbors
+                            .into_iter()
+                            .map(|neighbor| neighbor.index())
+                            .collect::<Vec<usize>>(),
+                    )
+                })
+                .collect::<DictMap<usize, Vec<usize>>>();
+            (true, embedding.into_py(py))
+        }
+        Planarity::NonPlanar(edges) => {
+            let edges = edges
+                .into_iter()
+                .map(|(source, target)| (source.index(), target.index()))
+                .collect();
+            (false, EdgeList { edges }.into_py(py))
+        }
+    }
+}
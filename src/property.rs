@@ -0,0 +1,233 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use fixedbitset::FixedBitSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pyo3::Python;
+
+use numpy::IntoPyArray;
+
+/// The values of a :class:`PropertyArray`, stored unboxed by ``dtype``. The
+/// second field is the default value of entries which haven't been set.
+enum PropertyValues {
+    Bool(FixedBitSet, bool),
+    Int(Vec<i64>, i64),
+    Float(Vec<f64>, f64),
+}
+
+/// An array of boolean, integer or float values for each node or edge of a
+/// graph
+///
+/// A property array stores a value for each node or edge index of a graph
+/// in Rust, as a bitset for ``"bool"`` properties and as a flat array of 64
+/// bit values for ``"i64"`` and ``"f64"`` properties, so numeric inputs and
+/// outputs of algorithms implemented in Python can be kept by index instead
+/// of in the data payloads of the graph. A property array is created with
+/// :meth:`~retworkx.PyGraph.new_node_property` or
+/// :meth:`~retworkx.PyGraph.new_edge_property` (or the equivalent
+/// :class:`~retworkx.PyDiGraph` methods) with an entry for each index up to
+/// the largest node or edge index of the graph.
+///
+/// The values are read and written by node or edge index with
+/// ``property[index]``. Indices of nodes or edges added to the graph after
+/// the property array was created can be used too, reading one which hasn't
+/// been written returns the default value and writing one grows the array.
+/// The array doesn't track removals from the graph, the entries of removed
+/// indices are kept until they're overwritten. ``numpy.asarray(property)``
+/// returns a copy of the values as a numpy array.
+///
+/// .. jupyter-execute::
+///
+///   import numpy as np
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   visited = graph.new_node_property("bool")
+///   distance = graph.new_node_property("f64", default=np.inf)
+///   visited[0] = True
+///   distance[0] = 0.0
+///   print(np.asarray(visited))
+///   print(np.asarray(distance))
+#[pyclass(module = "retworkx")]
+pub struct PropertyArray {
+    values: PropertyValues,
+}
+
+impl PropertyArray {
+    pub fn new(size: usize, dtype: &str, default: Option<&PyAny>) -> PyResult<Self> {
+        let values = match dtype {
+            "bool" => {
+                let default: bool = match default {
+                    Some(default) => default.extract()?,
+                    None => false,
+                };
+                let mut bits = FixedBitSet::with_capacity(size);
+                bits.set_range(.., default);
+                PropertyValues::Bool(bits, default)
+            }
+            "i64" => {
+                let default: i64 = match default {
+                    Some(default) => default.extract()?,
+                    None => 0,
+                };
+                PropertyValues::Int(vec![default; size], default)
+            }
+            "f64" => {
+                let default: f64 = match default {
+                    Some(default) => default.extract()?,
+                    None => 0.,
+                };
+                PropertyValues::Float(vec![default; size], default)
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid dtype '{}', it must be one of 'bool', 'i64' or 'f64'",
+                    dtype
+                )))
+            }
+        };
+        Ok(PropertyArray { values })
+    }
+
+    fn get(&self, py: Python, index: usize) -> PyObject {
+        match &self.values {
+            PropertyValues::Bool(bits, default) => {
+                if index < bits.len() {
+                    bits[index].into_py(py)
+                } else {
+                    default.into_py(py)
+                }
+            }
+            PropertyValues::Int(values, default) => {
+                values.get(index).unwrap_or(default).into_py(py)
+            }
+            PropertyValues::Float(values, default) => {
+                values.get(index).unwrap_or(default).into_py(py)
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl PropertyArray {
+    /// The type of the values, one of ``"bool"``, ``"i64"`` or ``"f64"``
+    #[getter]
+    fn dtype(&self) -> &'static str {
+        match self.values {
+            PropertyValues::Bool(..) => "bool",
+            PropertyValues::Int(..) => "i64",
+            PropertyValues::Float(..) => "f64",
+        }
+    }
+
+    /// The value of entries which haven't been written
+    #[getter]
+    fn default(&self, py: Python) -> PyObject {
+        match self.values {
+            PropertyValues::Bool(_, default) => default.into_py(py),
+            PropertyValues::Int(_, default) => default.into_py(py),
+            PropertyValues::Float(_, default) => default.into_py(py),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        match &self.values {
+            PropertyValues::Bool(bits, _) => bits.len(),
+            PropertyValues::Int(values, _) => values.len(),
+            PropertyValues::Float(values, _) => values.len(),
+        }
+    }
+
+    fn __getitem__(&self, py: Python, index: usize) -> PyObject {
+        self.get(py, index)
+    }
+
+    fn __setitem__(&mut self, index: usize, value: &PyAny) -> PyResult<()> {
+        match &mut self.values {
+            PropertyValues::Bool(bits, default) => {
+                let value: bool = value.extract()?;
+                if index >= bits.len() {
+                    let len = bits.len();
+                    bits.grow(index + 1);
+                    bits.set_range(len.., *default);
+                }
+                bits.set(index, value);
+            }
+            PropertyValues::Int(values, default) => {
+                let value: i64 = value.extract()?;
+                if index >= values.len() {
+                    values.resize(index + 1, *default);
+                }
+                values[index] = value;
+            }
+            PropertyValues::Float(values, default) => {
+                let value: f64 = value.extract()?;
+                if index >= values.len() {
+                    values.resize(index + 1, *default);
+                }
+                values[index] = value;
+            }
+        }
+        Ok(())
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<PyObject> {
+        let values: Vec<PyObject> = (0..self.__len__()).map(|i| self.get(py, i)).collect();
+        Ok(PyList::new(py, values).as_ref().iter()?.into())
+    }
+
+    #[args(dtype = "None", copy = "None")]
+    fn __array__(
+        &self,
+        py: Python,
+        dtype: Option<PyObject>,
+        copy: Option<bool>,
+    ) -> PyResult<PyObject> {
+        // The values are always copied to a new array
+        let _ = copy;
+        let array: PyObject = match &self.values {
+            PropertyValues::Bool(bits, _) => {
+                let values: Vec<bool> = (0..bits.len()).map(|i| bits[i]).collect();
+                values.into_pyarray(py).into()
+            }
+            PropertyValues::Int(values, _) => values.clone().into_pyarray(py).into(),
+            PropertyValues::Float(values, _) => values.clone().into_pyarray(py).into(),
+        };
+        match dtype {
+            Some(dtype) => array.call_method1(py, "astype", (dtype,)),
+            None => Ok(array),
+        }
+    }
+
+    /// Set every entry to a value
+    ///
+    /// :param value: The value to set, it has to be convertible to the
+    ///     ``dtype`` of the property array
+    #[pyo3(text_signature = "(self, value, /)")]
+    fn fill(&mut self, value: &PyAny) -> PyResult<()> {
+        match &mut self.values {
+            PropertyValues::Bool(bits, _) => bits.set_range(.., value.extract()?),
+            PropertyValues::Int(values, _) => {
+                let value: i64 = value.extract()?;
+                values.iter_mut().for_each(|x| *x = value);
+            }
+            PropertyValues::Float(values, _) => {
+                let value: f64 = value.extract()?;
+                values.iter_mut().for_each(|x| *x = value);
+            }
+        }
+        Ok(())
+    }
+}
@@ -12,7 +12,11 @@
 
 #![allow(clippy::float_cmp)]
 
-use crate::{digraph, graph, StablePyGraph};
+use crate::clusters::Clusters;
+use crate::key_index::KeyIndexes;
+use crate::random_seed::seeded_rng;
+use crate::transaction::MutationLog;
+use crate::{digraph, graph, NodeIndex, StablePyGraph};
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -20,12 +24,9 @@ use pyo3::types::PyDict;
 use pyo3::Python;
 
 use petgraph::algo;
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 
 use rand::distributions::{Distribution, Uniform};
-use rand::prelude::*;
-use rand_pcg::Pcg64;
 
 /// Return a :math:`G_{np}` directed random graph, also known as an
 /// Erdős-Rényi graph or a binomial graph.
@@ -67,11 +68,8 @@ pub fn directed_gnp_random_graph(
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
-    let mut inner_graph = StablePyGraph::<Directed>::new();
+    let mut rng = seeded_rng(seed);
+    let mut inner_graph = StablePyGraph::<Directed>::default();
     for x in 0..num_nodes {
         inner_graph.add_node(x.to_object(py));
     }
@@ -126,11 +124,14 @@ pub fn directed_gnp_random_graph(
     }
 
     let graph = digraph::PyDiGraph {
-        graph: inner_graph,
+        graph: inner_graph.into(),
         cycle_state: algo::DfsSpace::default(),
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     };
     Ok(graph)
 }
@@ -175,10 +176,7 @@ pub fn undirected_gnp_random_graph(
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng = seeded_rng(seed);
     let mut inner_graph = StablePyGraph::<Undirected>::default();
     for x in 0..num_nodes {
         inner_graph.add_node(x.to_object(py));
@@ -222,9 +220,12 @@ pub fn undirected_gnp_random_graph(
     }
 
     let graph = graph::PyGraph {
-        graph: inner_graph,
+        graph: inner_graph.into(),
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     };
     Ok(graph)
 }
@@ -264,11 +265,8 @@ pub fn directed_gnm_random_graph(
     if num_edges < 0 {
         return Err(PyValueError::new_err("num_edges must be >= 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
-    let mut inner_graph = StablePyGraph::<Directed>::new();
+    let mut rng = seeded_rng(seed);
+    let mut inner_graph = StablePyGraph::<Directed>::default();
     for x in 0..num_nodes {
         inner_graph.add_node(x.to_object(py));
     }
@@ -301,11 +299,14 @@ pub fn directed_gnm_random_graph(
         }
     }
     let graph = digraph::PyDiGraph {
-        graph: inner_graph,
+        graph: inner_graph.into(),
         cycle_state: algo::DfsSpace::default(),
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     };
     Ok(graph)
 }
@@ -345,10 +346,7 @@ pub fn undirected_gnm_random_graph(
     if num_edges < 0 {
         return Err(PyValueError::new_err("num_edges must be >= 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng = seeded_rng(seed);
     let mut inner_graph = StablePyGraph::<Undirected>::default();
     for x in 0..num_nodes {
         inner_graph.add_node(x.to_object(py));
@@ -379,9 +377,12 @@ pub fn undirected_gnm_random_graph(
         }
     }
     let graph = graph::PyGraph {
-        graph: inner_graph,
+        graph: inner_graph.into(),
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     };
     Ok(graph)
 }
@@ -448,10 +449,7 @@ pub fn random_geometric_graph(
     let mut inner_graph = StablePyGraph::<Undirected>::default();
 
     let radius_p = pnorm(radius, p);
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    let mut rng = seeded_rng(seed);
 
     let dist = Uniform::new(0.0, 1.0);
     let pos = pos.unwrap_or_else(|| {
@@ -482,9 +480,12 @@ pub fn random_geometric_graph(
     }
 
     let graph = graph::PyGraph {
-        graph: inner_graph,
+        graph: inner_graph.into(),
         node_removed: false,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     };
     Ok(graph)
 }
@@ -0,0 +1,81 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use pyo3::prelude::*;
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+// The global random number generator is a stream of seeds, one for each call
+// of a function which isn't passed a seed. The stream is stored as its seed
+// and the number of seeds drawn from it so far.
+static SEEDED: AtomicBool = AtomicBool::new(false);
+static GLOBAL_SEED: AtomicU64 = AtomicU64::new(0);
+static DRAWS: AtomicU64 = AtomicU64::new(0);
+
+/// Return ``seed`` if there is one, otherwise the next seed from the global
+/// random number generator if it's been seeded with
+/// :func:`~retworkx.set_random_seed`.
+pub fn resolve_seed(seed: Option<u64>) -> Option<u64> {
+    if seed.is_some() || !SEEDED.load(Ordering::SeqCst) {
+        return seed;
+    }
+    let draw = DRAWS.fetch_add(1, Ordering::SeqCst);
+    let mut rng = Pcg64::seed_from_u64(GLOBAL_SEED.load(Ordering::SeqCst));
+    // Each seed is drawn with a single step of the generator.
+    rng.advance(draw as u128);
+    Some(rng.gen())
+}
+
+/// Create a random number generator from ``seed`` or, if there isn't one,
+/// from the global random number generator or entropy.
+pub fn seeded_rng(seed: Option<u64>) -> Pcg64 {
+    match resolve_seed(seed) {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    }
+}
+
+/// Seed the global random number generator
+///
+/// Every function in retworkx which takes a ``seed`` argument, such as the
+/// random graph generators, the random layouts and the randomized algorithms,
+/// draws its seed from the global random number generator when it isn't
+/// passed one. Seeding the global random number generator makes a whole
+/// sequence of calls to these functions reproducible, while an explicit
+/// ``seed`` argument still takes precedence for a single call and doesn't
+/// advance the global random number generator.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   retworkx.set_random_seed(42)
+///   graph = retworkx.undirected_gnp_random_graph(10, 0.5)
+///   layout = retworkx.random_layout(graph)
+///   retworkx.set_random_seed(42)
+///   print(graph.edge_list() == retworkx.undirected_gnp_random_graph(10, 0.5).edge_list())
+///   print(layout == retworkx.random_layout(graph))
+///   retworkx.set_random_seed(None)
+///
+/// :param int seed: The seed for the global random number generator. If
+///     ``None`` functions that aren't passed a seed are seeded from the
+///     operating system's entropy again, which is the default.
+#[pyfunction]
+#[pyo3(text_signature = "(seed, /)")]
+pub fn set_random_seed(seed: Option<u64>) {
+    GLOBAL_SEED.store(seed.unwrap_or(0), Ordering::SeqCst);
+    DRAWS.store(0, Ordering::SeqCst);
+    SEEDED.store(seed.is_some(), Ordering::SeqCst);
+}
@@ -0,0 +1,196 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::NodeIndexable;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+use rayon::prelude::*;
+
+use crate::laplacian::{laplacian_edges, Laplacian};
+use crate::{graph, InvalidNode, NodeIndex};
+
+/// Compute the effective resistance between two nodes of a graph
+///
+/// The effective resistance, or resistance distance, between two nodes is the
+/// resistance between them in the electrical network where each edge is a
+/// resistor whose conductance is the weight of the edge. It's
+///
+/// .. math::
+///
+///     R_{ab} = (e_a - e_b)^T L^+ (e_a - e_b)
+///
+/// where :math:`L^+` is the pseudo-inverse of the Laplacian matrix of the
+/// graph. Unlike the shortest path distance it decreases as more paths join
+/// the nodes, so it's commonly used to measure how robustly two nodes are
+/// connected. This solves a single Laplacian system with the conjugate
+/// gradient method instead of computing the pseudo-inverse.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(4)
+///   print(retworkx.resistance_distance(graph, 0, 2))
+///
+/// :param PyGraph graph: The graph to use
+/// :param int node_a: The index of the first node
+/// :param int node_b: The index of the second node
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a
+///     non-negative float which will be used as the conductance of the edge,
+///     so a weight function returning ``1 / resistance`` can be used for
+///     resistances.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+///
+/// :returns: The effective resistance between ``node_a`` and ``node_b``, this
+///     is infinite if they aren't connected.
+/// :rtype: float
+///
+/// :raises InvalidNode: If either node index isn't in the graph
+/// :raises ValueError: If an edge weight is negative or NaN
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, node_a, node_b, /, weight_fn=None, default_weight=1.0)")]
+pub fn resistance_distance(
+    py: Python,
+    graph: &graph::PyGraph,
+    node_a: usize,
+    node_b: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<f64> {
+    for node in &[node_a, node_b] {
+        if !graph.graph.contains_node(NodeIndex::new(*node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+    }
+    if node_a == node_b {
+        return Ok(0.0);
+    }
+    let edges = laplacian_edges(py, graph, &weight_fn, default_weight)?;
+    let laplacian = Laplacian::new(graph.graph.node_bound(), &edges);
+    let components = laplacian.components();
+    if components[node_a] != components[node_b] {
+        return Ok(f64::INFINITY);
+    }
+    let mut current = vec![0.0; laplacian.node_bound()];
+    current[node_a] = 1.0;
+    current[node_b] = -1.0;
+    let potential = laplacian.solve(&current);
+    Ok(potential[node_a] - potential[node_b])
+}
+
+/// Compute the effective resistance between every pair of nodes of a graph
+///
+/// This computes the effective resistance, or resistance distance, described
+/// in :func:`~retworkx.resistance_distance` between all pairs of nodes. Each
+/// column of the pseudo-inverse of the Laplacian matrix is found by solving a
+/// Laplacian system with the conjugate gradient method, so this takes one
+/// solve per node.
+///
+/// This function is also multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 300). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(4)
+///   print(retworkx.resistance_distance_matrix(graph))
+///
+/// :param PyGraph graph: The graph to use
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a
+///     non-negative float which will be used as the conductance of the edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the matrix in parallel at. It defaults to 300, but this can be tuned
+/// :param float null_value: An optional float that will be used for pairs of
+///     nodes which aren't connected. By default this is ``0.0``.
+///
+/// :returns: The matrix of effective resistances, the rows and columns are in
+///     the order of :meth:`~retworkx.PyGraph.node_indices`
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If an edge weight is negative or NaN
+#[pyfunction(default_weight = "1.0", parallel_threshold = "300", null_value = "0.0")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, parallel_threshold=300, null_value=0.0)"
+)]
+pub fn resistance_distance_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+    null_value: f64,
+) -> PyResult<PyObject> {
+    let edges = laplacian_edges(py, graph, &weight_fn, default_weight)?;
+    let laplacian = Laplacian::new(graph.graph.node_bound(), &edges);
+    let components = laplacian.components();
+    let nodes: Vec<usize> = graph
+        .graph
+        .node_indices()
+        .map(|node| node.index())
+        .collect();
+    let mut component_sizes = vec![0.0; laplacian.node_bound()];
+    for node in &nodes {
+        component_sizes[components[*node]] += 1.0;
+    }
+    // Solve for L^+ e_i up to a constant, the right hand side is centered on
+    // the component of i so the system has a solution.
+    let solve = |node: &usize| -> Vec<f64> {
+        let label = components[*node];
+        let mut current: Vec<f64> = components
+            .iter()
+            .map(|component| {
+                if *component == label {
+                    -1.0 / component_sizes[label]
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        current[*node] += 1.0;
+        laplacian.solve(&current)
+    };
+    let potentials: Vec<Vec<f64>> = if nodes.len() < parallel_threshold {
+        nodes.iter().map(solve).collect()
+    } else {
+        nodes.par_iter().map(solve).collect()
+    };
+    let n = nodes.len();
+    let mut matrix = Array2::<f64>::from_elem((n, n), null_value);
+    for i in 0..n {
+        matrix[[i, i]] = 0.0;
+        for j in i + 1..n {
+            let (a, b) = (nodes[i], nodes[j]);
+            if components[a] == components[b] {
+                let resistance =
+                    potentials[i][a] - potentials[i][b] + potentials[j][b] - potentials[j][a];
+                matrix[[i, j]] = resistance;
+                matrix[[j, i]] = resistance;
+            }
+        }
+    }
+    Ok(matrix.into_pyarray(py).into())
+}
@@ -0,0 +1,431 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use rand::prelude::*;
+
+use retworkx_core::centrality::betweenness_centrality;
+
+use crate::iterators::{EdgeIndices, NodeIndices};
+use crate::random_seed::seeded_rng;
+use crate::{digraph, graph, EdgeIndex, InvalidNode, NodeIndex, StablePyGraph};
+
+/// The order to remove the nodes or edges of a graph in, either the name of
+/// a strategy or the indices to remove.
+#[derive(FromPyObject)]
+pub enum RemovalOrder {
+    Strategy(String),
+    Indices(Vec<usize>),
+}
+
+/// The degree of every node, with the edge directions ignored and self loops
+/// counted twice.
+fn degrees<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Vec<f64> {
+    let mut degree = vec![0.0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        degree[edge.source().index()] += 1.0;
+        degree[edge.target().index()] += 1.0;
+    }
+    degree
+}
+
+/// Compute the betweenness centrality of every edge, without normalization,
+/// with Brandes' algorithm.
+fn edge_betweenness<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Vec<f64> {
+    let node_bound = graph.node_bound();
+    let mut betweenness = vec![0.0; graph.edge_bound()];
+    let mut distance: Vec<Option<usize>> = vec![None; node_bound];
+    let mut sigma = vec![0.0; node_bound];
+    let mut delta = vec![0.0; node_bound];
+    let mut predecessors: Vec<Vec<(usize, usize)>> = vec![Vec::new(); node_bound];
+    for source in graph.node_indices() {
+        let mut visited: Vec<usize> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        distance[source.index()] = Some(0);
+        sigma[source.index()] = 1.0;
+        queue.push_back(source.index());
+        while let Some(node) = queue.pop_front() {
+            visited.push(node);
+            let node_distance = distance[node].unwrap();
+            for edge in graph.edges(NodeIndex::new(node)) {
+                let next = if edge.source().index() == node {
+                    edge.target().index()
+                } else {
+                    edge.source().index()
+                };
+                if distance[next].is_none() {
+                    distance[next] = Some(node_distance + 1);
+                    queue.push_back(next);
+                }
+                if distance[next] == Some(node_distance + 1) {
+                    sigma[next] += sigma[node];
+                    predecessors[next].push((node, edge.id().index()));
+                }
+            }
+        }
+        for node in visited.iter().rev() {
+            for (predecessor, edge) in &predecessors[*node] {
+                let share = sigma[*predecessor] / sigma[*node] * (1.0 + delta[*node]);
+                betweenness[*edge] += share;
+                delta[*predecessor] += share;
+            }
+        }
+        for node in visited {
+            distance[node] = None;
+            sigma[node] = 0.0;
+            delta[node] = 0.0;
+            predecessors[node].clear();
+        }
+    }
+    betweenness
+}
+
+/// Sort the indices by decreasing score, ties are broken by index.
+fn rank(mut indices: Vec<usize>, score: &[f64]) -> Vec<usize> {
+    indices.sort_by(|a, b| {
+        score[*b]
+            .partial_cmp(&score[*a])
+            .unwrap()
+            .then_with(|| a.cmp(b))
+    });
+    indices
+}
+
+fn removal_order<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    order: Option<RemovalOrder>,
+    remove_edges: bool,
+    seed: Option<u64>,
+) -> PyResult<Vec<usize>> {
+    let all: Vec<usize> = if remove_edges {
+        graph.edge_indices().map(|e| e.index()).collect()
+    } else {
+        graph.node_indices().map(|n| n.index()).collect()
+    };
+    let strategy = match order {
+        None => String::from("degree"),
+        Some(RemovalOrder::Strategy(strategy)) => strategy,
+        Some(RemovalOrder::Indices(indices)) => {
+            let bound = if remove_edges {
+                graph.edge_bound()
+            } else {
+                graph.node_bound()
+            };
+            let mut seen = vec![false; bound];
+            for index in &indices {
+                if remove_edges && graph.edge_weight(EdgeIndex::new(*index)).is_none() {
+                    return Err(PyIndexError::new_err(format!(
+                        "Edge index {} is not present in the graph",
+                        index
+                    )));
+                }
+                if !remove_edges && !graph.contains_node(NodeIndex::new(*index)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node index {} is not present in the graph",
+                        index
+                    )));
+                }
+                if seen[*index] {
+                    return Err(PyValueError::new_err(format!(
+                        "Index {} is removed more than once",
+                        index
+                    )));
+                }
+                seen[*index] = true;
+            }
+            return Ok(indices);
+        }
+    };
+    match strategy.as_str() {
+        "degree" => {
+            let degree = degrees(graph);
+            if remove_edges {
+                let mut score = vec![0.0; graph.edge_bound()];
+                for edge in graph.edge_references() {
+                    score[edge.id().index()] =
+                        degree[edge.source().index()] * degree[edge.target().index()];
+                }
+                Ok(rank(all, &score))
+            } else {
+                Ok(rank(all, &degree))
+            }
+        }
+        "betweenness" => {
+            if remove_edges {
+                Ok(rank(all, &edge_betweenness(graph)))
+            } else {
+                let score: Vec<f64> = betweenness_centrality(graph, false, false, 50)
+                    .into_iter()
+                    .map(|value| value.unwrap_or(0.0))
+                    .collect();
+                Ok(rank(all, &score))
+            }
+        }
+        "random" => {
+            let mut rng = seeded_rng(seed);
+            let mut all = all;
+            all.shuffle(&mut rng);
+            Ok(all)
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid order '{}', must be 'degree', 'betweenness', 'random' or a sequence of indices",
+            strategy
+        ))),
+    }
+}
+
+/// A union find over the node indices tracking the size of every set.
+struct Components {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    largest: usize,
+}
+
+impl Components {
+    fn new(node_bound: usize) -> Self {
+        Components {
+            parent: (0..node_bound).collect(),
+            size: vec![1; node_bound],
+            largest: 0,
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    fn add(&mut self) {
+        self.largest = std::cmp::max(self.largest, 1);
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.largest = std::cmp::max(self.largest, self.size[a]);
+    }
+}
+
+/// Find the size of the largest connected component after removing every
+/// prefix of the order, by adding the removed nodes or edges back in
+/// reverse order.
+fn largest_component_sizes<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    order: &[usize],
+    remove_edges: bool,
+) -> Vec<usize> {
+    let mut components = Components::new(graph.node_bound());
+    let mut sizes = vec![0; order.len() + 1];
+    if remove_edges {
+        let mut removed = vec![false; graph.edge_bound()];
+        for edge in order {
+            removed[*edge] = true;
+        }
+        for _ in graph.node_indices() {
+            components.add();
+        }
+        for edge in graph.edge_references() {
+            if !removed[edge.id().index()] {
+                components.union(edge.source().index(), edge.target().index());
+            }
+        }
+        sizes[order.len()] = components.largest;
+        for (position, edge) in order.iter().enumerate().rev() {
+            let (source, target) = graph.edge_endpoints(EdgeIndex::new(*edge)).unwrap();
+            components.union(source.index(), target.index());
+            sizes[position] = components.largest;
+        }
+    } else {
+        let mut present = vec![true; graph.node_bound()];
+        for node in order {
+            present[*node] = false;
+        }
+        let add_node = |components: &mut Components, present: &[bool], node: NodeIndex| {
+            components.add();
+            for neighbor in graph.neighbors_undirected(node) {
+                if present[neighbor.index()] {
+                    components.union(node.index(), neighbor.index());
+                }
+            }
+        };
+        for node in graph.node_indices() {
+            if present[node.index()] {
+                add_node(&mut components, &present, node);
+            }
+        }
+        sizes[order.len()] = components.largest;
+        for (position, node) in order.iter().enumerate().rev() {
+            present[*node] = true;
+            add_node(&mut components, &present, NodeIndex::new(*node));
+            sizes[position] = components.largest;
+        }
+    }
+    sizes
+}
+
+fn robustness_curve<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    order: Option<RemovalOrder>,
+    remove_edges: bool,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    let order = removal_order(graph, order, remove_edges, seed)?;
+    let sizes = largest_component_sizes(graph, &order, remove_edges);
+    if remove_edges {
+        Ok((EdgeIndices { edges: order }, sizes).into_py(py))
+    } else {
+        Ok((NodeIndices { nodes: order }, sizes).into_py(py))
+    }
+}
+
+/// Compute the robustness curve of an undirected graph
+///
+/// The nodes, or edges, of the graph are removed one at a time and the size
+/// of the largest connected component is recorded after every removal. The
+/// order the nodes or edges are removed in can either be given explicitly or
+/// be one of the following strategies:
+///
+/// * ``"degree"``: nodes in order of decreasing degree, or edges in order of
+///   decreasing product of the degrees of their endpoints [1]_
+/// * ``"betweenness"``: nodes or edges in order of decreasing betweenness
+///   centrality
+/// * ``"random"``: a uniformly random order, which simulates random failures
+///   or site and bond percolation
+///
+/// The degrees and centralities are those of the original graph and ties are
+/// broken by index. The curve is computed by adding the removed nodes or
+/// edges back in reverse order and merging components with a union find, so
+/// it takes :math:`O((|V| + |E|) \alpha(|V|))` time once the order is known.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(5)
+///   order, sizes = retworkx.graph_robustness_curve(graph)
+///   print(list(order), sizes)
+///
+/// :param PyGraph graph: The graph to remove nodes or edges from
+/// :param order: Either the name of a strategy, ``"degree"``,
+///     ``"betweenness"`` or ``"random"``, or a sequence of the indices of the
+///     nodes, or edges, to remove in order. A sequence doesn't need to contain
+///     every node or edge. By default this is ``"degree"``.
+/// :param bool remove_edges: If set to ``True`` edges are removed instead of
+///     nodes.
+/// :param int seed: An optional seed to use for the random number generator
+///     of the ``"random"`` strategy
+///
+/// :returns: A tuple of the indices of the nodes, or edges, in the order they
+///     are removed and a list of the sizes of the largest connected component,
+///     as a number of nodes, before any removal and after each removal. The
+///     size is ``0`` when there are no nodes left.
+/// :rtype: tuple
+/// :raises ValueError: If ``order`` is an invalid strategy or contains an
+///     index more than once
+/// :raises InvalidNode: If ``order`` contains an invalid node index
+/// :raises IndexError: If ``order`` contains an invalid edge index
+///
+/// .. [1] Holme, Petter, et al. "Attack vulnerability of complex networks."
+///     Physical Review E 65.5 (2002): 056109.
+#[pyfunction(remove_edges = "false")]
+#[pyo3(text_signature = "(graph, /, order=None, remove_edges=False, seed=None)")]
+pub fn graph_robustness_curve(
+    py: Python,
+    graph: &graph::PyGraph,
+    order: Option<RemovalOrder>,
+    remove_edges: bool,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    robustness_curve(py, &graph.graph, order, remove_edges, seed)
+}
+
+/// Compute the robustness curve of a directed graph
+///
+/// The nodes, or edges, of the graph are removed one at a time and the size
+/// of the largest weakly connected component is recorded after every
+/// removal. The order the nodes or edges are removed in can either be given
+/// explicitly or be one of the following strategies:
+///
+/// * ``"degree"``: nodes in order of decreasing total in and out degree, or
+///   edges in order of decreasing product of the degrees of their
+///   endpoints [1]_
+/// * ``"betweenness"``: nodes or edges in order of decreasing betweenness
+///   centrality, following the edge directions
+/// * ``"random"``: a uniformly random order, which simulates random failures
+///   or site and bond percolation
+///
+/// The degrees and centralities are those of the original graph and ties are
+/// broken by index. The curve is computed by adding the removed nodes or
+/// edges back in reverse order and merging components with a union find, so
+/// it takes :math:`O((|V| + |E|) \alpha(|V|))` time once the order is known.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(5)
+///   order, sizes = retworkx.digraph_robustness_curve(graph, order="betweenness")
+///   print(list(order), sizes)
+///
+/// :param PyDiGraph graph: The graph to remove nodes or edges from
+/// :param order: Either the name of a strategy, ``"degree"``,
+///     ``"betweenness"`` or ``"random"``, or a sequence of the indices of the
+///     nodes, or edges, to remove in order. A sequence doesn't need to contain
+///     every node or edge. By default this is ``"degree"``.
+/// :param bool remove_edges: If set to ``True`` edges are removed instead of
+///     nodes.
+/// :param int seed: An optional seed to use for the random number generator
+///     of the ``"random"`` strategy
+///
+/// :returns: A tuple of the indices of the nodes, or edges, in the order they
+///     are removed and a list of the sizes of the largest weakly connected
+///     component, as a number of nodes, before any removal and after each
+///     removal. The size is ``0`` when there are no nodes left.
+/// :rtype: tuple
+/// :raises ValueError: If ``order`` is an invalid strategy or contains an
+///     index more than once
+/// :raises InvalidNode: If ``order`` contains an invalid node index
+/// :raises IndexError: If ``order`` contains an invalid edge index
+///
+/// .. [1] Holme, Petter, et al. "Attack vulnerability of complex networks."
+///     Physical Review E 65.5 (2002): 056109.
+#[pyfunction(remove_edges = "false")]
+#[pyo3(text_signature = "(graph, /, order=None, remove_edges=False, seed=None)")]
+pub fn digraph_robustness_curve(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    order: Option<RemovalOrder>,
+    remove_edges: bool,
+    seed: Option<u64>,
+) -> PyResult<PyObject> {
+    robustness_curve(py, &graph.graph, order, remove_edges, seed)
+}
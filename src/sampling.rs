@@ -0,0 +1,773 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::algo;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use retworkx_core::dictmap::*;
+
+use crate::clusters::Clusters;
+use crate::iterators::{EdgeIndices, NodeIndices, NodeMap};
+use crate::key_index::KeyIndexes;
+use crate::random_seed::seeded_rng;
+use crate::transaction::MutationLog;
+use crate::{digraph, graph, is_valid_weight, weight_callable, NodeIndex, StablePyGraph};
+
+/// An alias table for sampling from a discrete distribution in constant
+/// time, built with Vose's method.
+struct AliasTable {
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Build the table for the (not necessarily normalized) ``weights``,
+    /// which have a positive sum.
+    fn new(weights: &[f64]) -> Self {
+        let count = weights.len();
+        let total: f64 = weights.iter().sum();
+        let mut scaled: Vec<f64> = weights
+            .iter()
+            .map(|weight| weight * count as f64 / total)
+            .collect();
+        let mut probability = vec![1.0; count];
+        let mut alias: Vec<usize> = (0..count).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..count).partition(|index| scaled[*index] < 1.0);
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            probability[less] = scaled[less];
+            alias[less] = more;
+            scaled[more] += scaled[less] - 1.0;
+            if scaled[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Anything left over only differs from 1 by rounding errors and keeps
+        // a probability of 1.
+        AliasTable { probability, alias }
+    }
+
+    fn sample(&self, rng: &mut Pcg64) -> usize {
+        let index = rng.gen_range(0..self.probability.len());
+        if rng.gen::<f64>() < self.probability[index] {
+            index
+        } else {
+            self.alias[index]
+        }
+    }
+}
+
+/// Draw ``k`` of the ``indices`` with probabilities proportional to their
+/// ``weights``.
+///
+/// With replacement each draw is made from an alias table. Without
+/// replacement, where an alias table would have to be rebuilt after every
+/// draw, the Efraimidis-Spirakis method is used instead: each index gets the
+/// key ``ln(u) / weight`` for a uniform random ``u`` and the ``k`` indices
+/// with the largest keys are drawn in order of decreasing key, which is
+/// equivalent to ``k`` successive weighted draws.
+fn sample(
+    indices: Vec<usize>,
+    weights: Vec<f64>,
+    k: usize,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<Vec<usize>> {
+    // Items with a weight of 0 can never be drawn
+    let (indices, weights): (Vec<usize>, Vec<f64>) = indices
+        .into_iter()
+        .zip(weights)
+        .filter(|(_, weight)| *weight > 0.0)
+        .unzip();
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    if replace {
+        if indices.is_empty() {
+            return Err(PyValueError::new_err(
+                "Can't sample from a population without a positive weight",
+            ));
+        }
+        let mut rng = seeded_rng(seed);
+        let table = AliasTable::new(&weights);
+        Ok((0..k).map(|_| indices[table.sample(&mut rng)]).collect())
+    } else {
+        if k > indices.len() {
+            return Err(PyValueError::new_err(format!(
+                "Sample of size {} is larger than the population of {} with a positive weight",
+                k,
+                indices.len()
+            )));
+        }
+        let mut rng = seeded_rng(seed);
+        let mut keyed: Vec<(f64, usize)> = indices
+            .into_iter()
+            .zip(weights)
+            .map(|(index, weight)| {
+                // gen() is in [0, 1) so 1 - gen() is never 0
+                let uniform: f64 = 1.0 - rng.gen::<f64>();
+                (uniform.ln() / weight, index)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap().then(a.1.cmp(&b.1)));
+        Ok(keyed.into_iter().take(k).map(|(_, index)| index).collect())
+    }
+}
+
+fn checked_weight(py: Python, weight_fn: &Option<PyObject>, payload: &PyObject) -> PyResult<f64> {
+    let weight = is_valid_weight(weight_callable(py, weight_fn, payload, 1.0)?)?;
+    if weight.is_infinite() {
+        return Err(PyValueError::new_err("Infinite weights not supported."));
+    }
+    Ok(weight)
+}
+
+fn sample_nodes<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<NodeIndices> {
+    let indices: Vec<usize> = graph.node_indices().map(|node| node.index()).collect();
+    let weights = graph
+        .node_indices()
+        .map(|node| checked_weight(py, &weight_fn, &graph[node]))
+        .collect::<PyResult<Vec<f64>>>()?;
+    Ok(NodeIndices {
+        nodes: sample(indices, weights, k, replace, seed)?,
+    })
+}
+
+fn sample_edges<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<EdgeIndices> {
+    let indices: Vec<usize> = graph
+        .edge_references()
+        .map(|edge| edge.id().index())
+        .collect();
+    let weights = graph
+        .edge_references()
+        .map(|edge| checked_weight(py, &weight_fn, edge.weight()))
+        .collect::<PyResult<Vec<f64>>>()?;
+    Ok(EdgeIndices {
+        edges: sample(indices, weights, k, replace, seed)?,
+    })
+}
+
+/// Randomly sample nodes of a directed graph
+///
+/// The nodes are drawn with probabilities proportional to their weight,
+/// or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+/// is independent and takes constant time using an alias table [1]_ built
+/// in linear time. Without replacement a node is drawn at most once, which is
+/// equivalent to ``k`` successive weighted draws each excluding the nodes
+/// drawn before, using the method of Efraimidis and Spirakis [2]_ in
+/// :math:`O(n \log n)` time. Nodes with a weight of ``0`` are never drawn.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_path_graph(5)
+///   print(retworkx.digraph_sample_nodes(graph, 8, replace=True, seed=42))
+///
+/// :param PyDiGraph graph: The graph to sample nodes from
+/// :param int k: The number of nodes to draw
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a node, and returns its
+///     sampling weight as a non-negative ``float``. If not specified every
+///     node has a weight of ``1.0``.
+/// :param bool replace: If set to ``True`` nodes are drawn with
+///     replacement, so a node can be drawn more than once
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The indices of the drawn nodes, in the order they were drawn
+/// :rtype: NodeIndices
+/// :raises ValueError: If a weight is negative, NaN or infinite, or if there
+///     aren't enough nodes with a positive weight to draw ``k`` nodes
+///     from
+///
+/// .. [1] Vose, Michael D. "A linear algorithm for generating random numbers
+///     with a given distribution." IEEE Transactions on Software Engineering
+///     17.9 (1991): 972-975.
+/// .. [2] Efraimidis, Pavlos S., and Paul G. Spirakis. "Weighted random
+///     sampling with a reservoir." Information Processing Letters 97.5
+///     (2006): 181-185.
+#[pyfunction(replace = "false")]
+#[pyo3(text_signature = "(graph, k, /, weight_fn=None, replace=False, seed=None)")]
+pub fn digraph_sample_nodes(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<NodeIndices> {
+    sample_nodes(py, &graph.graph, k, weight_fn, replace, seed)
+}
+
+/// Randomly sample nodes of an undirected graph
+///
+/// The nodes are drawn with probabilities proportional to their weight,
+/// or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+/// is independent and takes constant time using an alias table [1]_ built
+/// in linear time. Without replacement a node is drawn at most once, which is
+/// equivalent to ``k`` successive weighted draws each excluding the nodes
+/// drawn before, using the method of Efraimidis and Spirakis [2]_ in
+/// :math:`O(n \log n)` time. Nodes with a weight of ``0`` are never drawn.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(5)
+///   graph[0] = 10
+///   for node in range(1, 5):
+///       graph[node] = 1
+///   print(retworkx.graph_sample_nodes(graph, 3, weight_fn=float, seed=42))
+///
+/// :param PyGraph graph: The graph to sample nodes from
+/// :param int k: The number of nodes to draw
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a node, and returns its
+///     sampling weight as a non-negative ``float``. If not specified every
+///     node has a weight of ``1.0``.
+/// :param bool replace: If set to ``True`` nodes are drawn with
+///     replacement, so a node can be drawn more than once
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The indices of the drawn nodes, in the order they were drawn
+/// :rtype: NodeIndices
+/// :raises ValueError: If a weight is negative, NaN or infinite, or if there
+///     aren't enough nodes with a positive weight to draw ``k`` nodes
+///     from
+///
+/// .. [1] Vose, Michael D. "A linear algorithm for generating random numbers
+///     with a given distribution." IEEE Transactions on Software Engineering
+///     17.9 (1991): 972-975.
+/// .. [2] Efraimidis, Pavlos S., and Paul G. Spirakis. "Weighted random
+///     sampling with a reservoir." Information Processing Letters 97.5
+///     (2006): 181-185.
+#[pyfunction(replace = "false")]
+#[pyo3(text_signature = "(graph, k, /, weight_fn=None, replace=False, seed=None)")]
+pub fn graph_sample_nodes(
+    py: Python,
+    graph: &graph::PyGraph,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<NodeIndices> {
+    sample_nodes(py, &graph.graph, k, weight_fn, replace, seed)
+}
+
+/// Randomly sample edges of a directed graph
+///
+/// The edges are drawn with probabilities proportional to their weight,
+/// or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+/// is independent and takes constant time using an alias table [1]_ built
+/// in linear time. Without replacement a edge is drawn at most once, which is
+/// equivalent to ``k`` successive weighted draws each excluding the edges
+/// drawn before, using the method of Efraimidis and Spirakis [2]_ in
+/// :math:`O(n \log n)` time. Edges with a weight of ``0`` are never drawn.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_cycle_graph(5)
+///   print(retworkx.digraph_sample_edges(graph, 3, seed=42))
+///
+/// :param PyDiGraph graph: The graph to sample edges from
+/// :param int k: The number of edges to draw
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a edge, and returns its
+///     sampling weight as a non-negative ``float``. If not specified every
+///     edge has a weight of ``1.0``.
+/// :param bool replace: If set to ``True`` edges are drawn with
+///     replacement, so a edge can be drawn more than once
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The indices of the drawn edges, in the order they were drawn
+/// :rtype: EdgeIndices
+/// :raises ValueError: If a weight is negative, NaN or infinite, or if there
+///     aren't enough edges with a positive weight to draw ``k`` edges
+///     from
+///
+/// .. [1] Vose, Michael D. "A linear algorithm for generating random numbers
+///     with a given distribution." IEEE Transactions on Software Engineering
+///     17.9 (1991): 972-975.
+/// .. [2] Efraimidis, Pavlos S., and Paul G. Spirakis. "Weighted random
+///     sampling with a reservoir." Information Processing Letters 97.5
+///     (2006): 181-185.
+#[pyfunction(replace = "false")]
+#[pyo3(text_signature = "(graph, k, /, weight_fn=None, replace=False, seed=None)")]
+pub fn digraph_sample_edges(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<EdgeIndices> {
+    sample_edges(py, &graph.graph, k, weight_fn, replace, seed)
+}
+
+/// Randomly sample edges of an undirected graph
+///
+/// The edges are drawn with probabilities proportional to their weight,
+/// or uniformly if ``weight_fn`` isn't set. With ``replace=True`` each draw
+/// is independent and takes constant time using an alias table [1]_ built
+/// in linear time. Without replacement a edge is drawn at most once, which is
+/// equivalent to ``k`` successive weighted draws each excluding the edges
+/// drawn before, using the method of Efraimidis and Spirakis [2]_ in
+/// :math:`O(n \log n)` time. Edges with a weight of ``0`` are never drawn.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from([(0, 1, 1.0), (1, 2, 5.0), (2, 3, 0.0)])
+///   print(retworkx.graph_sample_edges(graph, 2, weight_fn=float, seed=42))
+///
+/// :param PyGraph graph: The graph to sample edges from
+/// :param int k: The number of edges to draw
+/// :param weight_fn: An optional callable which takes a single positional
+///     argument, the weight/data payload of a edge, and returns its
+///     sampling weight as a non-negative ``float``. If not specified every
+///     edge has a weight of ``1.0``.
+/// :param bool replace: If set to ``True`` edges are drawn with
+///     replacement, so a edge can be drawn more than once
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The indices of the drawn edges, in the order they were drawn
+/// :rtype: EdgeIndices
+/// :raises ValueError: If a weight is negative, NaN or infinite, or if there
+///     aren't enough edges with a positive weight to draw ``k`` edges
+///     from
+///
+/// .. [1] Vose, Michael D. "A linear algorithm for generating random numbers
+///     with a given distribution." IEEE Transactions on Software Engineering
+///     17.9 (1991): 972-975.
+/// .. [2] Efraimidis, Pavlos S., and Paul G. Spirakis. "Weighted random
+///     sampling with a reservoir." Information Processing Letters 97.5
+///     (2006): 181-185.
+#[pyfunction(replace = "false")]
+#[pyo3(text_signature = "(graph, k, /, weight_fn=None, replace=False, seed=None)")]
+pub fn graph_sample_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    replace: bool,
+    seed: Option<u64>,
+) -> PyResult<EdgeIndices> {
+    sample_edges(py, &graph.graph, k, weight_fn, replace, seed)
+}
+
+/// The method used to explore the graph by :func:`sample_subgraph`.
+enum Exploration {
+    Snowball,
+    ForestFire(f64),
+    RandomWalk(f64),
+}
+
+/// Add ``node`` to the sample if it hasn't been visited, returns whether it
+/// was added.
+fn visit(node: NodeIndex, visited: &mut [bool], sampled: &mut Vec<NodeIndex>) -> bool {
+    if visited[node.index()] {
+        return false;
+    }
+    visited[node.index()] = true;
+    sampled.push(node);
+    true
+}
+
+/// Pick the nodes of a sampled subgraph by exploring ``graph`` from random
+/// start nodes, ignoring the edge directions, until ``num_nodes`` nodes are
+/// visited. Returns the visited nodes in the order they were visited.
+fn explore<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    num_nodes: usize,
+    exploration: Exploration,
+    rng: &mut Pcg64,
+) -> Vec<NodeIndex> {
+    let mut visited = vec![false; graph.node_bound()];
+    let mut sampled: Vec<NodeIndex> = Vec::with_capacity(num_nodes);
+    // Start nodes are taken from a random permutation of the nodes, skipping
+    // nodes already visited, whenever the exploration can't continue.
+    let mut starts: Vec<NodeIndex> = graph.node_indices().collect();
+    starts.shuffle(rng);
+    let mut starts = starts.into_iter();
+    while sampled.len() < num_nodes {
+        let start = match starts.next() {
+            Some(start) => start,
+            None => break,
+        };
+        if !visit(start, &mut visited, &mut sampled) {
+            continue;
+        }
+        match exploration {
+            Exploration::Snowball => {
+                // A breadth first search visiting the neighbors of each node
+                // in a random order
+                let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+                queue.push_back(start);
+                while let Some(node) = queue.pop_front() {
+                    let mut neighbors: Vec<NodeIndex> = graph.neighbors_undirected(node).collect();
+                    neighbors.shuffle(rng);
+                    for neighbor in neighbors {
+                        if sampled.len() == num_nodes {
+                            break;
+                        }
+                        if visit(neighbor, &mut visited, &mut sampled) {
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+            Exploration::ForestFire(burn_probability) => {
+                // Each burning node sets fire to a geometrically distributed
+                // number of its unburned neighbors, with a mean of
+                // p / (1 - p)
+                let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+                queue.push_back(start);
+                while let Some(node) = queue.pop_front() {
+                    let mut count: usize = 0;
+                    while rng.gen::<f64>() < burn_probability {
+                        count += 1;
+                    }
+                    let mut neighbors: Vec<NodeIndex> = graph
+                        .neighbors_undirected(node)
+                        .filter(|neighbor| !visited[neighbor.index()])
+                        .collect();
+                    neighbors.sort_unstable();
+                    neighbors.dedup();
+                    neighbors.shuffle(rng);
+                    for neighbor in neighbors.into_iter().take(count) {
+                        if sampled.len() == num_nodes {
+                            break;
+                        }
+                        visit(neighbor, &mut visited, &mut sampled);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            Exploration::RandomWalk(restart_probability) => {
+                // The walk moves to a random neighbor, or back to the start
+                // node with the restart probability, until it hasn't found a
+                // new node for as many steps as there are nodes in the graph
+                let mut node = start;
+                let mut steps_without_new_node: usize = 0;
+                while sampled.len() < num_nodes && steps_without_new_node < graph.node_count() {
+                    let neighbors: Vec<NodeIndex> = graph.neighbors_undirected(node).collect();
+                    if neighbors.is_empty() {
+                        break;
+                    }
+                    node = if rng.gen::<f64>() < restart_probability {
+                        start
+                    } else {
+                        neighbors[rng.gen_range(0..neighbors.len())]
+                    };
+                    if visit(node, &mut visited, &mut sampled) {
+                        steps_without_new_node = 0;
+                    } else {
+                        steps_without_new_node += 1;
+                    }
+                }
+            }
+        }
+    }
+    sampled
+}
+
+fn sample_subgraph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    num_nodes: usize,
+    method: &str,
+    burn_probability: f64,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(StablePyGraph<Ty>, NodeMap)> {
+    if num_nodes > graph.node_count() {
+        return Err(PyValueError::new_err(format!(
+            "num_nodes {} is larger than the number of nodes in the graph {}",
+            num_nodes,
+            graph.node_count()
+        )));
+    }
+    let exploration = match method {
+        "snowball" => Exploration::Snowball,
+        "forest_fire" => {
+            if !(0.0..1.0).contains(&burn_probability) {
+                return Err(PyValueError::new_err(
+                    "burn_probability must be at least 0 and less than 1",
+                ));
+            }
+            Exploration::ForestFire(burn_probability)
+        }
+        "random_walk" => {
+            if !(0.0..1.0).contains(&restart_probability) {
+                return Err(PyValueError::new_err(
+                    "restart_probability must be at least 0 and less than 1",
+                ));
+            }
+            Exploration::RandomWalk(restart_probability)
+        }
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid method '{}', must be 'snowball', 'forest_fire' or 'random_walk'",
+                method
+            )))
+        }
+    };
+    let mut rng = seeded_rng(seed);
+    let mut sampled = explore(graph, num_nodes, exploration, &mut rng);
+    // The nodes of the subgraph keep the relative order of their indices
+    sampled.sort_unstable();
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(sampled.len(), 0);
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(sampled.len());
+    for node in &sampled {
+        let new_node = out_graph.add_node(graph[*node].clone_ref(py));
+        node_map.insert(node.index(), new_node.index());
+    }
+    for edge in graph.edge_references() {
+        if let (Some(source), Some(target)) = (
+            node_map.get(&edge.source().index()),
+            node_map.get(&edge.target().index()),
+        ) {
+            out_graph.add_edge(
+                NodeIndex::new(*source),
+                NodeIndex::new(*target),
+                edge.weight().clone_ref(py),
+            );
+        }
+    }
+    Ok((out_graph, NodeMap { node_map }))
+}
+
+/// Sample a subgraph of a directed graph
+///
+/// The nodes are picked by exploring the graph from a random start node
+/// with one of the following methods, until ``num_nodes`` nodes are
+/// visited:
+///
+/// * ``"snowball"``: a breadth first search, visiting the neighbors of each
+///   node in a random order
+/// * ``"forest_fire"``: the forest fire model, each visited node "burns" a
+///   geometrically distributed number of its unvisited neighbors, with a
+///   mean of ``burn_probability / (1 - burn_probability)``, which are
+///   visited next
+/// * ``"random_walk"``: a random walk which moves to a random neighbor, or
+///   jumps back to the start node with probability ``restart_probability``
+///
+/// These are the exploration based sampling methods of Leskovec and
+/// Faloutsos [1]_, which keep more of the local structure of the graph than
+/// sampling nodes independently. Whenever the exploration can't continue,
+/// because the fire died out, the connected component is exhausted, or the
+/// random walk didn't find a new node in as many steps as there are nodes in
+/// the graph, it's restarted from a new random node. The edge directions are
+/// ignored when exploring the graph but they're kept in the subgraph. The
+/// sampled subgraph is the subgraph induced by the visited nodes, with its
+/// nodes in the same relative order as in ``graph``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(10, 10)
+///   subgraph, node_map = retworkx.digraph_sample_subgraph(
+///       graph, 10, method="random_walk", seed=42
+///   )
+///   print(subgraph.edge_list())
+///   print(dict(node_map))
+///
+/// :param PyDiGraph graph: The graph to sample a subgraph of
+/// :param int num_nodes: The number of nodes in the sampled subgraph
+/// :param str method: The exploration method, ``"snowball"``,
+///     ``"forest_fire"`` or ``"random_walk"``. By default this is
+///     ``"snowball"``.
+/// :param float burn_probability: The burn probability of the
+///     ``"forest_fire"`` method, by default ``0.7``
+/// :param float restart_probability: The probability the ``"random_walk"``
+///     method jumps back to its start node at each step, by default ``0.15``
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the sampled subgraph and a
+///     :class:`~retworkx.NodeMap` mapping the index of each sampled node in
+///     ``graph`` to its index in the subgraph
+/// :rtype: tuple
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     in ``graph``, ``method`` is invalid, or the probability of the method
+///     isn't at least ``0`` and less than ``1``
+///
+/// .. [1] Leskovec, Jure, and Christos Faloutsos. "Sampling from large
+///     graphs." Proceedings of the 12th ACM SIGKDD International Conference
+///     on Knowledge Discovery and Data Mining (2006): 631-636.
+#[pyfunction(
+    method = "\"snowball\"",
+    burn_probability = "0.7",
+    restart_probability = "0.15"
+)]
+#[pyo3(
+    text_signature = "(graph, num_nodes, /, method=\"snowball\", burn_probability=0.7, restart_probability=0.15, seed=None)"
+)]
+pub fn digraph_sample_subgraph(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    method: &str,
+    burn_probability: f64,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    let (out_graph, node_map) = sample_subgraph(
+        py,
+        &graph.graph,
+        num_nodes,
+        method,
+        burn_probability,
+        restart_probability,
+        seed,
+    )?;
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph.into(),
+            cycle_state: algo::DfsSpace::default(),
+            check_cycle: false,
+            node_removed: false,
+            multigraph: graph.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
+        },
+        node_map,
+    ))
+}
+
+/// Sample a subgraph of an undirected graph
+///
+/// The nodes are picked by exploring the graph from a random start node
+/// with one of the following methods, until ``num_nodes`` nodes are
+/// visited:
+///
+/// * ``"snowball"``: a breadth first search, visiting the neighbors of each
+///   node in a random order
+/// * ``"forest_fire"``: the forest fire model, each visited node "burns" a
+///   geometrically distributed number of its unvisited neighbors, with a
+///   mean of ``burn_probability / (1 - burn_probability)``, which are
+///   visited next
+/// * ``"random_walk"``: a random walk which moves to a random neighbor, or
+///   jumps back to the start node with probability ``restart_probability``
+///
+/// These are the exploration based sampling methods of Leskovec and
+/// Faloutsos [1]_, which keep more of the local structure of the graph than
+/// sampling nodes independently. Whenever the exploration can't continue,
+/// because the fire died out, the connected component is exhausted, or the
+/// random walk didn't find a new node in as many steps as there are nodes in
+/// the graph, it's restarted from a new random node. The sampled subgraph is
+/// the subgraph induced by the visited nodes, with its nodes in the same
+/// relative order as in ``graph``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(10, 10)
+///   subgraph, node_map = retworkx.graph_sample_subgraph(
+///       graph, 10, method="forest_fire", seed=42
+///   )
+///   print(subgraph.edge_list())
+///   print(dict(node_map))
+///
+/// :param PyGraph graph: The graph to sample a subgraph of
+/// :param int num_nodes: The number of nodes in the sampled subgraph
+/// :param str method: The exploration method, ``"snowball"``,
+///     ``"forest_fire"`` or ``"random_walk"``. By default this is
+///     ``"snowball"``.
+/// :param float burn_probability: The burn probability of the
+///     ``"forest_fire"`` method, by default ``0.7``
+/// :param float restart_probability: The probability the ``"random_walk"``
+///     method jumps back to its start node at each step, by default ``0.15``
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the sampled subgraph and a
+///     :class:`~retworkx.NodeMap` mapping the index of each sampled node in
+///     ``graph`` to its index in the subgraph
+/// :rtype: tuple
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     in ``graph``, ``method`` is invalid, or the probability of the method
+///     isn't at least ``0`` and less than ``1``
+///
+/// .. [1] Leskovec, Jure, and Christos Faloutsos. "Sampling from large
+///     graphs." Proceedings of the 12th ACM SIGKDD International Conference
+///     on Knowledge Discovery and Data Mining (2006): 631-636.
+#[pyfunction(
+    method = "\"snowball\"",
+    burn_probability = "0.7",
+    restart_probability = "0.15"
+)]
+#[pyo3(
+    text_signature = "(graph, num_nodes, /, method=\"snowball\", burn_probability=0.7, restart_probability=0.15, seed=None)"
+)]
+pub fn graph_sample_subgraph(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    method: &str,
+    burn_probability: f64,
+    restart_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    let (out_graph, node_map) = sample_subgraph(
+        py,
+        &graph.graph,
+        num_nodes,
+        method,
+        burn_probability,
+        restart_probability,
+        seed,
+    )?;
+    Ok((
+        graph::PyGraph {
+            graph: out_graph.into(),
+            node_removed: false,
+            multigraph: graph.multigraph,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
+        },
+        node_map,
+    ))
+}
@@ -0,0 +1,234 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use fixedbitset::FixedBitSet;
+use petgraph::prelude::*;
+use petgraph::stable_graph::{EdgeReference, EdgeReferences, Edges, Neighbors, NodeReferences};
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCount, NodeIndexable, Visitable,
+};
+use petgraph::EdgeType;
+use pyo3::prelude::*;
+use retworkx_core::contains::ContainsNode;
+
+use crate::{EdgeIndex, GraphIx, NodeIndex, NodesRemoved, StablePyGraph};
+
+/// The last mutation count handed out to a graph, shared by all graphs so
+/// that two different graph states never have the same count.
+static MUTATION_COUNT: AtomicU64 = AtomicU64::new(0);
+
+fn next_mutation_count() -> u64 {
+    MUTATION_COUNT.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+/// The graph storage of a :class:`~retworkx.PyGraph` or
+/// :class:`~retworkx.PyDiGraph`, shared between a graph and its snapshots
+/// until one of them is mutated, at which point the mutated side gets its
+/// own copy.
+///
+/// Every mutable access to the storage advances its mutation count, which
+/// can be used to tell whether a graph changed since it was last seen.
+pub struct SharedGraph<Ty: EdgeType>(Arc<StablePyGraph<Ty>>, u64);
+
+impl<Ty: EdgeType> SharedGraph<Ty> {
+    /// Share the storage with a new graph without copying it.
+    pub fn share(&self) -> Self {
+        SharedGraph(Arc::clone(&self.0), self.1)
+    }
+
+    /// The mutation count of the storage. It's advanced on every mutation
+    /// and is unique across all graphs, two graphs only have the same count
+    /// if one is an unmodified snapshot of the other.
+    pub fn mutation_count(&self) -> u64 {
+        self.1
+    }
+
+    /// Whether the storage is shared with another graph.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.0) > 1
+    }
+}
+
+impl<Ty: EdgeType> From<StablePyGraph<Ty>> for SharedGraph<Ty> {
+    fn from(graph: StablePyGraph<Ty>) -> Self {
+        SharedGraph(Arc::new(graph), next_mutation_count())
+    }
+}
+
+impl<Ty: EdgeType> Clone for SharedGraph<Ty> {
+    fn clone(&self) -> Self {
+        SharedGraph(
+            Arc::new(StablePyGraph::clone(&self.0)),
+            next_mutation_count(),
+        )
+    }
+}
+
+impl<Ty: EdgeType> Deref for SharedGraph<Ty> {
+    type Target = StablePyGraph<Ty>;
+
+    fn deref(&self) -> &StablePyGraph<Ty> {
+        &self.0
+    }
+}
+
+impl<Ty: EdgeType> DerefMut for SharedGraph<Ty> {
+    fn deref_mut(&mut self) -> &mut StablePyGraph<Ty> {
+        self.1 = next_mutation_count();
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+// Forward the petgraph visit traits to the shared storage so a
+// ``SharedGraph`` can be passed to graph algorithms directly.
+
+impl<Ty: EdgeType> GraphBase for SharedGraph<Ty> {
+    type NodeId = NodeIndex;
+    type EdgeId = EdgeIndex;
+}
+
+impl<Ty: EdgeType> Data for SharedGraph<Ty> {
+    type NodeWeight = PyObject;
+    type EdgeWeight = PyObject;
+}
+
+impl<Ty: EdgeType> GraphProp for SharedGraph<Ty> {
+    type EdgeType = Ty;
+}
+
+impl<Ty: EdgeType> Visitable for SharedGraph<Ty> {
+    type Map = FixedBitSet;
+
+    fn visit_map(&self) -> FixedBitSet {
+        self.0.visit_map()
+    }
+
+    fn reset_map(&self, map: &mut FixedBitSet) {
+        self.0.reset_map(map)
+    }
+}
+
+impl<Ty: EdgeType> NodeCount for SharedGraph<Ty> {
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<Ty: EdgeType> EdgeCount for SharedGraph<Ty> {
+    fn edge_count(&self) -> usize {
+        self.0.edge_count()
+    }
+}
+
+impl<Ty: EdgeType> NodeIndexable for SharedGraph<Ty> {
+    fn node_bound(&self) -> usize {
+        NodeIndexable::node_bound(&*self.0)
+    }
+
+    fn to_index(&self, ix: NodeIndex) -> usize {
+        NodeIndexable::to_index(&*self.0, ix)
+    }
+
+    fn from_index(&self, ix: usize) -> NodeIndex {
+        NodeIndexable::from_index(&*self.0, ix)
+    }
+}
+
+impl<Ty: EdgeType> ContainsNode for SharedGraph<Ty> {
+    fn contains_node(&self, node: NodeIndex) -> bool {
+        self.0.contains_node(node)
+    }
+}
+
+impl<Ty: EdgeType> EdgeIndexable for SharedGraph<Ty> {
+    fn edge_bound(&self) -> usize {
+        EdgeIndexable::edge_bound(&*self.0)
+    }
+
+    fn to_index(&self, ix: EdgeIndex) -> usize {
+        EdgeIndexable::to_index(&*self.0, ix)
+    }
+
+    fn from_index(&self, ix: usize) -> EdgeIndex {
+        EdgeIndexable::from_index(&*self.0, ix)
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoNodeIdentifiers for &'a SharedGraph<Ty> {
+    type NodeIdentifiers = petgraph::stable_graph::NodeIndices<'a, PyObject, GraphIx>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.0.node_identifiers()
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoNodeReferences for &'a SharedGraph<Ty> {
+    type NodeRef = (NodeIndex, &'a PyObject);
+    type NodeReferences = NodeReferences<'a, PyObject, GraphIx>;
+
+    fn node_references(self) -> Self::NodeReferences {
+        self.0.node_references()
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoNeighbors for &'a SharedGraph<Ty> {
+    type Neighbors = Neighbors<'a, PyObject, GraphIx>;
+
+    fn neighbors(self, n: NodeIndex) -> Self::Neighbors {
+        self.0.neighbors(n)
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoNeighborsDirected for &'a SharedGraph<Ty> {
+    type NeighborsDirected = Neighbors<'a, PyObject, GraphIx>;
+
+    fn neighbors_directed(self, n: NodeIndex, d: Direction) -> Self::NeighborsDirected {
+        self.0.neighbors_directed(n, d)
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoEdges for &'a SharedGraph<Ty> {
+    type Edges = Edges<'a, PyObject, Ty, GraphIx>;
+
+    fn edges(self, a: NodeIndex) -> Self::Edges {
+        self.0.edges(a)
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoEdgesDirected for &'a SharedGraph<Ty> {
+    type EdgesDirected = Edges<'a, PyObject, Ty, GraphIx>;
+
+    fn edges_directed(self, a: NodeIndex, dir: Direction) -> Self::EdgesDirected {
+        self.0.edges_directed(a, dir)
+    }
+}
+
+impl<'a, Ty: EdgeType> IntoEdgeReferences for &'a SharedGraph<Ty> {
+    type EdgeRef = EdgeReference<'a, PyObject, GraphIx>;
+    type EdgeReferences = EdgeReferences<'a, PyObject, GraphIx>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.0.edge_references()
+    }
+}
+
+impl<Ty: EdgeType> NodesRemoved for &SharedGraph<Ty> {
+    fn nodes_removed(&self) -> bool {
+        (&*self.0).nodes_removed()
+    }
+}
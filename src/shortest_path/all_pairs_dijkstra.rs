@@ -21,23 +21,27 @@ use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::EdgeIndexable;
 use petgraph::EdgeType;
 
 use rayon::prelude::*;
 
+use crate::edge_filter::EdgeMask;
 use crate::iterators::{
     AllPairsPathLengthMapping, AllPairsPathMapping, PathLengthMapping, PathMapping,
 };
-use crate::{CostFn, StablePyGraph};
+use crate::node_filter::NodeMask;
+use crate::{CostFn, EdgeIndex, NodeIndex, StablePyGraph};
 
 pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<AllPairsPathLengthMapping> {
+    let node_mask = NodeMask::new(py, graph, node_mask)?;
     if graph.node_count() == 0 {
         return Ok(AllPairsPathLengthMapping {
             path_lengths: DictMap::new(),
@@ -46,6 +50,7 @@ pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
         return Ok(AllPairsPathLengthMapping {
             path_lengths: graph
                 .node_indices()
+                .filter(|i| node_mask.contains(*i))
                 .map(|i| {
                     (
                         i.index(),
@@ -57,27 +62,35 @@ pub fn all_pairs_dijkstra_path_lengths<Ty: EdgeType + Sync>(
                 .collect(),
         });
     }
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, graph)?;
+    let edge_mask = EdgeMask::new(py, graph, edge_filter_fn)?;
     let mut edge_weights: Vec<Option<f64>> = Vec::with_capacity(graph.edge_bound());
     for index in 0..=graph.edge_bound() {
-        let raw_weight = graph.edge_weight(EdgeIndex::new(index));
-        match raw_weight {
-            Some(weight) => edge_weights.push(Some(edge_cost_callable.call(py, weight)?)),
-            None => edge_weights.push(None),
+        let edge = EdgeIndex::new(index);
+        match graph.edge_weight(edge) {
+            Some(weight) if edge_mask.contains(edge) => {
+                edge_weights.push(Some(edge_cost_callable.call_edge(py, edge, weight)?))
+            }
+            _ => edge_weights.push(None),
         };
     }
+    let edge_filtered = edge_mask.filter(graph);
+    let filtered = node_mask.filter(&edge_filtered);
     let edge_cost = |e: EdgeIndex| -> PyResult<f64> {
         match edge_weights[e.index()] {
             Some(weight) => Ok(weight),
             None => Err(PyIndexError::new_err("No edge found for index")),
         }
     };
-    let node_indices: Vec<NodeIndex> = graph.node_indices().collect();
+    let node_indices: Vec<NodeIndex> = graph
+        .node_indices()
+        .filter(|node| node_mask.contains(*node))
+        .collect();
     let out_map: DictMap<usize, PathLengthMapping> = node_indices
         .into_par_iter()
         .map(|x| {
-            let path_lenghts: PyResult<Vec<Option<f64>>> =
-                dijkstra(graph, x, None, |e| edge_cost(e.id()), None);
+            let path_lenghts: Result<Vec<Option<f64>>, _> =
+                dijkstra(&filtered, x, None, |e| edge_cost(e.id()), None);
             let out_map = PathLengthMapping {
                 path_lengths: path_lenghts
                     .unwrap()
@@ -104,8 +117,11 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
     py: Python,
     graph: &StablePyGraph<Ty>,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
     distances: Option<&mut HashMap<usize, DictMap<NodeIndex, f64>>>,
 ) -> PyResult<AllPairsPathMapping> {
+    let node_mask = NodeMask::new(py, graph, node_mask)?;
     if graph.node_count() == 0 {
         return Ok(AllPairsPathMapping {
             paths: DictMap::new(),
@@ -114,6 +130,7 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
         return Ok(AllPairsPathMapping {
             paths: graph
                 .node_indices()
+                .filter(|i| node_mask.contains(*i))
                 .map(|i| {
                     (
                         i.index(),
@@ -125,22 +142,30 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
                 .collect(),
         });
     }
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, graph)?;
+    let edge_mask = EdgeMask::new(py, graph, edge_filter_fn)?;
     let mut edge_weights: Vec<Option<f64>> = Vec::with_capacity(graph.edge_bound());
     for index in 0..=graph.edge_bound() {
-        let raw_weight = graph.edge_weight(EdgeIndex::new(index));
-        match raw_weight {
-            Some(weight) => edge_weights.push(Some(edge_cost_callable.call(py, weight)?)),
-            None => edge_weights.push(None),
+        let edge = EdgeIndex::new(index);
+        match graph.edge_weight(edge) {
+            Some(weight) if edge_mask.contains(edge) => {
+                edge_weights.push(Some(edge_cost_callable.call_edge(py, edge, weight)?))
+            }
+            _ => edge_weights.push(None),
         };
     }
+    let edge_filtered = edge_mask.filter(graph);
+    let filtered = node_mask.filter(&edge_filtered);
     let edge_cost = |e: EdgeIndex| -> PyResult<f64> {
         match edge_weights[e.index()] {
             Some(weight) => Ok(weight),
             None => Err(PyIndexError::new_err("No edge found for index")),
         }
     };
-    let node_indices: Vec<NodeIndex> = graph.node_indices().collect();
+    let node_indices: Vec<NodeIndex> = graph
+        .node_indices()
+        .filter(|node| node_mask.contains(*node))
+        .collect();
     let temp_distances: RwLock<HashMap<usize, DictMap<NodeIndex, f64>>> = if distances.is_some() {
         RwLock::new(HashMap::with_capacity(graph.node_count()))
     } else {
@@ -154,7 +179,7 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
                 let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> =
                     DictMap::with_capacity(graph.node_count());
                 let distance =
-                    dijkstra(graph, x, None, |e| edge_cost(e.id()), Some(&mut paths)).unwrap();
+                    dijkstra(&filtered, x, None, |e| edge_cost(e.id()), Some(&mut paths)).unwrap();
                 if distances.is_some() {
                     temp_distances.write().unwrap().insert(x.index(), distance);
                 }
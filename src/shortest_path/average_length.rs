@@ -12,12 +12,11 @@
 
 use hashbrown::HashSet;
 
-use petgraph::prelude::*;
 use petgraph::EdgeType;
 
 use rayon::prelude::*;
 
-use crate::StablePyGraph;
+use crate::{NodeIndex, StablePyGraph};
 
 pub fn compute_distance_sum<Ty: EdgeType + Sync>(
     graph: &StablePyGraph<Ty>,
@@ -15,12 +15,11 @@ use std::ops::Index;
 use hashbrown::{HashMap, HashSet};
 
 use ndarray::prelude::*;
-use petgraph::prelude::*;
 use petgraph::EdgeType;
 use rayon::prelude::*;
 
-use crate::NodesRemoved;
 use crate::StablePyGraph;
+use crate::{NodeIndex, NodesRemoved};
 
 #[inline]
 fn apply<I, M>(
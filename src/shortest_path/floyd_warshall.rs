@@ -127,12 +127,16 @@ pub fn floyd_warshall<Ty: EdgeType>(
         }
     }
 
-    // Convert to return format
+    // Convert to return format, with the targets sorted by node index so the
+    // iteration order doesn't depend on hashing
     let out_map: DictMap<usize, PathLengthMapping> = graph
         .node_indices()
         .map(|i| {
+            let mut path_lengths: Vec<(usize, f64)> =
+                mat[i.index()].iter().map(|(k, v)| (*k, *v)).collect();
+            path_lengths.sort_unstable_by_key(|(k, _)| *k);
             let out_map = PathLengthMapping {
-                path_lengths: mat[i.index()].iter().map(|(k, v)| (*k, *v)).collect(),
+                path_lengths: path_lengths.into_iter().collect(),
             };
             (i.index(), out_map)
         })
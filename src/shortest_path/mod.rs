@@ -18,21 +18,32 @@ mod num_shortest_path;
 
 use std::convert::TryFrom;
 
-use crate::{digraph, graph, CostFn, NoPathFound};
+use crate::edge_filter::EdgeMask;
+use crate::node_filter::NodeMask;
+use crate::path_utils::path_edges;
+use crate::{
+    core_error, digraph, graph, negative_cycle_error, CostFn, InvalidNode, NoPathFound, NodeIndex,
+    StablePyGraph,
+};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use petgraph::graph::NodeIndex;
-use petgraph::visit::NodeCount;
+use petgraph::visit::{EdgeRef, NodeCount};
+use petgraph::EdgeType;
 
 use numpy::IntoPyArray;
 
 use retworkx_core::dictmap::*;
-use retworkx_core::shortest_path::{astar, dijkstra, k_shortest_path};
+use retworkx_core::shortest_path::{
+    astar, bellman_ford, dijkstra, dijkstra_between_sets, dijkstra_with_workspace, k_shortest_path,
+};
+
+use crate::workspace::Workspace;
 
 use crate::iterators::{
-    AllPairsPathLengthMapping, AllPairsPathMapping, NodeIndices, NodesCountMapping,
+    AllPairsPathLengthMapping, AllPairsPathMapping, EdgeIndices, NodeIndices, NodesCountMapping,
     PathLengthMapping, PathMapping,
 };
 
@@ -51,6 +62,15 @@ use crate::iterators::{
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding the shortest path.
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: Dictionary of paths. The keys are destination node indices and
 ///     the dict values are lists of node indices making the path.
@@ -58,7 +78,10 @@ use crate::iterators::{
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction(default_weight = "1.0", as_undirected = "false")]
-#[pyo3(text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0)")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, edge_filter_fn=None, node_mask=None)"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn graph_dijkstra_shortest_paths(
     py: Python,
     graph: &graph::PyGraph,
@@ -66,20 +89,25 @@ pub fn graph_dijkstra_shortest_paths(
     target: Option<usize>,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<PathMapping> {
     let start = NodeIndex::new(source);
     let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
     let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::with_capacity(graph.node_count());
 
-    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    let edge_mask = EdgeMask::new(py, &graph.graph, edge_filter_fn)?;
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
 
     (dijkstra(
-        &graph.graph,
+        &node_mask.filter(&edge_mask.filter(&graph.graph)),
         start,
         goal_index,
-        |e| cost_fn.call(py, e.weight()),
+        |e| cost_fn.call_edge(py, e.id(), e.weight()),
         Some(&mut paths),
-    ) as PyResult<Vec<Option<f64>>>)?;
+    ) as Result<Vec<Option<f64>>, _>)
+        .map_err(core_error)?;
 
     Ok(PathMapping {
         paths: paths
@@ -114,6 +142,15 @@ pub fn graph_dijkstra_shortest_paths(
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding the shortest path.
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: Dictionary of paths. The keys are destination node indices and
 ///     the dict values are lists of node indices making the path.
@@ -122,8 +159,9 @@ pub fn graph_dijkstra_shortest_paths(
 ///     is provided.
 #[pyfunction(default_weight = "1.0", as_undirected = "false")]
 #[pyo3(
-    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False)"
+    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False, edge_filter_fn=None, node_mask=None)"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn digraph_dijkstra_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
@@ -132,31 +170,40 @@ pub fn digraph_dijkstra_shortest_paths(
     weight_fn: Option<PyObject>,
     default_weight: f64,
     as_undirected: bool,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<PathMapping> {
     let start = NodeIndex::new(source);
     let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
     let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::with_capacity(graph.node_count());
-    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
 
     if as_undirected {
+        // TODO: Use petgraph undirected adapter after
+        // https://github.com/petgraph/petgraph/pull/318 is available in
+        // a petgraph release.
+        let undirected = graph.to_undirected(py, true, None)?;
+        let edge_mask = EdgeMask::new(py, &undirected.graph, edge_filter_fn)?;
+        let node_mask = NodeMask::new(py, &undirected.graph, node_mask)?;
         (dijkstra(
-            // TODO: Use petgraph undirected adapter after
-            // https://github.com/petgraph/petgraph/pull/318 is available in
-            // a petgraph release.
-            &graph.to_undirected(py, true, None)?.graph,
+            &node_mask.filter(&edge_mask.filter(&undirected.graph)),
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            |e| cost_fn.call_edge(py, e.id(), e.weight()),
             Some(&mut paths),
-        ) as PyResult<Vec<Option<f64>>>)?;
+        ) as Result<Vec<Option<f64>>, _>)
+            .map_err(core_error)?;
     } else {
+        let edge_mask = EdgeMask::new(py, &graph.graph, edge_filter_fn)?;
+        let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
         (dijkstra(
-            &graph.graph,
+            &node_mask.filter(&edge_mask.filter(&graph.graph)),
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            |e| cost_fn.call_edge(py, e.id(), e.weight()),
             Some(&mut paths),
-        ) as PyResult<Vec<Option<f64>>>)?;
+        ) as Result<Vec<Option<f64>>, _>)
+            .map_err(core_error)?;
     }
     Ok(PathMapping {
         paths: paths
@@ -173,6 +220,125 @@ pub fn digraph_dijkstra_shortest_paths(
     })
 }
 
+fn dijkstra_shortest_path_edges<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    target: usize,
+    cost_fn: &CostFn,
+) -> PyResult<EdgeIndices> {
+    let start = NodeIndex::new(source);
+    let goal = NodeIndex::new(target);
+    if !graph.contains_node(start) || !graph.contains_node(goal) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'source' or 'target' is not a valid node index",
+        ));
+    }
+    let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::new();
+    (dijkstra(
+        graph,
+        start,
+        Some(goal),
+        |e| cost_fn.call_edge(py, e.id(), e.weight()),
+        Some(&mut paths),
+    ) as Result<Vec<Option<f64>>, _>)
+        .map_err(core_error)?;
+    let path = match paths.get(&goal) {
+        Some(path) => path,
+        None => {
+            return Err(NoPathFound::new_err(format!(
+                "No path found from {} to {}",
+                source, target
+            )))
+        }
+    };
+    let path: Vec<usize> = path.iter().map(|node| node.index()).collect();
+    let edges = path_edges(graph, &path, |edge, weight| {
+        cost_fn.call_edge(py, edge, weight)
+    })?;
+    Ok(EdgeIndices {
+        edges: edges.into_iter().map(|(edge, _)| edge.index()).collect(),
+    })
+}
+
+/// Find the shortest path between two nodes as a list of edge indices
+///
+/// This function uses Dijkstra's algorithm to find the shortest path from
+/// ``source`` to ``target`` and returns the indices of the edges along that
+/// path. Unlike :func:`~retworkx.graph_dijkstra_shortest_paths` the output
+/// is unambiguous for multigraphs; when there are parallel edges between
+/// two nodes on the path the edge with the lowest weight is used.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int source: The node index to find the path from
+/// :param int target: The node index to find the path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :return: The edge indices of the shortest path in order from ``source``
+///     to ``target``. If ``source`` and ``target`` are the same node the
+///     list will be empty.
+/// :rtype: EdgeIndices
+/// :raises InvalidNode: when ``source`` or ``target`` are not in the graph
+/// :raises NoPathFound: when there is no path from ``source`` to ``target``
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, source, target, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_dijkstra_shortest_path_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    target: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    dijkstra_shortest_path_edges(py, &graph.graph, source, target, &cost_fn)
+}
+
+/// Find the shortest path between two nodes as a list of edge indices
+///
+/// This function uses Dijkstra's algorithm to find the shortest path from
+/// ``source`` to ``target`` and returns the indices of the edges along that
+/// path. Unlike :func:`~retworkx.digraph_dijkstra_shortest_paths` the output
+/// is unambiguous for multigraphs; when there are parallel edges between
+/// two nodes on the path the edge with the lowest weight is used.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int source: The node index to find the path from
+/// :param int target: The node index to find the path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :return: The edge indices of the shortest path in order from ``source``
+///     to ``target``. If ``source`` and ``target`` are the same node the
+///     list will be empty.
+/// :rtype: EdgeIndices
+/// :raises InvalidNode: when ``source`` or ``target`` are not in the graph
+/// :raises NoPathFound: when there is no path from ``source`` to ``target``
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, source, target, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_dijkstra_shortest_path_edges(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    target: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    dijkstra_shortest_path_edges(py, &graph.graph, source, target, &cost_fn)
+}
+
 /// Compute the lengths of the shortest paths for a PyGraph object using
 /// Dijkstra's algorithm
 ///
@@ -186,6 +352,17 @@ pub fn digraph_dijkstra_shortest_paths(
 ///     When specified the traversal will stop when the goal is reached and
 ///     the output dictionary will only have a single entry with the length
 ///     of the shortest path to the goal node.
+/// :param Workspace workspace: An optional :class:`~retworkx.Workspace` whose
+///     buffers are reused for the search instead of allocating new ones.
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :returns: A dictionary of the shortest paths from the provided node where
 ///     the key is the node index of the end of the path and the value is the
@@ -194,25 +371,48 @@ pub fn digraph_dijkstra_shortest_paths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, node, edge_cost_fn, /, goal=None)")]
+#[pyo3(
+    text_signature = "(graph, node, edge_cost_fn, /, goal=None, workspace=None, edge_filter_fn=None, node_mask=None)"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn graph_dijkstra_shortest_path_lengths(
     py: Python,
     graph: &graph::PyGraph,
     node: usize,
     edge_cost_fn: PyObject,
     goal: Option<usize>,
+    workspace: Option<PyRefMut<Workspace>>,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<PathLengthMapping> {
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_mask = EdgeMask::new(py, &graph.graph, edge_filter_fn)?;
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, &graph.graph)?;
     let start = NodeIndex::new(node);
     let goal_index: Option<NodeIndex> = goal.map(NodeIndex::new);
 
+    if let Some(mut workspace) = workspace {
+        let generation = workspace.generation(&graph.graph);
+        dijkstra_with_workspace(
+            &node_mask.filter(&edge_mask.filter(&graph.graph)),
+            start,
+            goal_index,
+            |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
+            &mut workspace.workspace,
+            generation,
+        )
+        .map_err(core_error)?;
+        return Ok(workspace.path_lengths(node, goal));
+    }
+
     let res: Vec<Option<f64>> = dijkstra(
-        &graph.graph,
+        &node_mask.filter(&edge_mask.filter(&graph.graph)),
         start,
         goal_index,
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         None,
-    )?;
+    )
+    .map_err(core_error)?;
 
     if let Some(goal_usize) = goal {
         return Ok(PathLengthMapping {
@@ -255,6 +455,17 @@ pub fn graph_dijkstra_shortest_path_lengths(
 ///     When specified the traversal will stop when the goal is reached and
 ///     the output dictionary will only have a single entry with the length
 ///     of the shortest path to the goal node.
+/// :param Workspace workspace: An optional :class:`~retworkx.Workspace` whose
+///     buffers are reused for the search instead of allocating new ones.
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :returns: A dictionary of the shortest paths from the provided node where
 ///     the key is the node index of the end of the path and the value is the
@@ -263,26 +474,49 @@ pub fn graph_dijkstra_shortest_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, node, edge_cost_fn, /, goal=None)")]
+#[pyo3(
+    text_signature = "(graph, node, edge_cost_fn, /, goal=None, workspace=None, edge_filter_fn=None, node_mask=None)"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn digraph_dijkstra_shortest_path_lengths(
     py: Python,
     graph: &digraph::PyDiGraph,
     node: usize,
     edge_cost_fn: PyObject,
     goal: Option<usize>,
+    workspace: Option<PyRefMut<Workspace>>,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<PathLengthMapping> {
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_mask = EdgeMask::new(py, &graph.graph, edge_filter_fn)?;
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, &graph.graph)?;
 
     let start = NodeIndex::new(node);
     let goal_index: Option<NodeIndex> = goal.map(NodeIndex::new);
 
+    if let Some(mut workspace) = workspace {
+        let generation = workspace.generation(&graph.graph);
+        dijkstra_with_workspace(
+            &node_mask.filter(&edge_mask.filter(&graph.graph)),
+            start,
+            goal_index,
+            |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
+            &mut workspace.workspace,
+            generation,
+        )
+        .map_err(core_error)?;
+        return Ok(workspace.path_lengths(node, goal));
+    }
+
     let res: Vec<Option<f64>> = dijkstra(
-        &graph.graph,
+        &node_mask.filter(&edge_mask.filter(&graph.graph)),
         start,
         goal_index,
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         None,
-    )?;
+    )
+    .map_err(core_error)?;
 
     if let Some(goal_usize) = goal {
         return Ok(PathLengthMapping {
@@ -327,6 +561,15 @@ pub fn digraph_dijkstra_shortest_path_lengths(
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
 ///     weight/cost of the edge
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -342,13 +585,21 @@ pub fn digraph_dijkstra_shortest_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, /, edge_filter_fn=None, node_mask=None)")]
 pub fn digraph_all_pairs_dijkstra_path_lengths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        edge_filter_fn,
+        node_mask,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -366,6 +617,15 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
 ///     weight/cost of the edge
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: A read-only dictionary of paths. The keys are source node indices
 ///     and the values are dicts of the target node and the list of the
@@ -381,13 +641,22 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, /, edge_filter_fn=None, node_mask=None)")]
 pub fn digraph_all_pairs_dijkstra_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(py, &graph.graph, edge_cost_fn, None)
+    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        edge_filter_fn,
+        node_mask,
+        None,
+    )
 }
 
 /// For each node in the graph, calculates the lengths of the shortest paths
@@ -401,6 +670,15 @@ pub fn digraph_all_pairs_dijkstra_shortest_paths(
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
 ///     weight/cost of the edge
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: A read-only dictionary of path lengths. The keys are source
 ///     node indices and the values are dicts of the target node and the length
@@ -416,13 +694,21 @@ pub fn digraph_all_pairs_dijkstra_shortest_paths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, /, edge_filter_fn=None, node_mask=None)")]
 pub fn graph_all_pairs_dijkstra_path_lengths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<AllPairsPathLengthMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        edge_filter_fn,
+        node_mask,
+    )
 }
 
 /// For each node in the graph, finds the shortest paths to all others in a
@@ -436,6 +722,15 @@ pub fn graph_all_pairs_dijkstra_path_lengths(
 ///     an edge. It will accept a single positional argument, the edge's weight
 ///     object and will return a float which will be used to represent the
 ///     weight/cost of the edge
+/// :param edge_filter_fn: An optional callable which takes a single
+///     positional argument, the weight/data payload of an edge, and returns
+///     ``True`` if the edge should be used. Edges for which it returns
+///     ``False`` are ignored as if they weren't in the graph. If not
+///     specified every edge is used.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph. If not specified every node is used.
 ///
 /// :return: A read-only dictionary of paths. The keys are destination node
 ///     indices and the values are dicts of the target node and the list of the
@@ -451,13 +746,22 @@ pub fn graph_all_pairs_dijkstra_path_lengths(
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, edge_cost_fn, /)")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, /, edge_filter_fn=None, node_mask=None)")]
 pub fn graph_all_pairs_dijkstra_shortest_paths(
     py: Python,
     graph: &graph::PyGraph,
     edge_cost_fn: PyObject,
+    edge_filter_fn: Option<PyObject>,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<AllPairsPathMapping> {
-    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(py, &graph.graph, edge_cost_fn, None)
+    all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        edge_filter_fn,
+        node_mask,
+        None,
+    )
 }
 
 /// Compute the A* shortest path for a PyDiGraph
@@ -498,7 +802,7 @@ pub fn digraph_astar_shortest_path(
         Ok(output)
     };
 
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, &graph.graph)?;
     let estimate_cost_callable = CostFn::from(estimate_cost_fn);
     let start = NodeIndex::new(node);
 
@@ -506,9 +810,10 @@ pub fn digraph_astar_shortest_path(
         &graph.graph,
         start,
         |f| goal_fn_callable(graph.graph.node_weight(f).unwrap()),
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         |estimate| estimate_cost_callable.call(py, &graph.graph[estimate]),
-    )?;
+    )
+    .map_err(core_error)?;
     let path = match astar_res {
         Some(path) => path,
         None => return Err(NoPathFound::new_err("No path found that satisfies goal_fn")),
@@ -556,7 +861,7 @@ pub fn graph_astar_shortest_path(
         Ok(output)
     };
 
-    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let edge_cost_callable = CostFn::from(edge_cost_fn).prepare(py, &graph.graph)?;
     let estimate_cost_callable = CostFn::from(estimate_cost_fn);
     let start = NodeIndex::new(node);
 
@@ -564,9 +869,10 @@ pub fn graph_astar_shortest_path(
         &graph.graph,
         start,
         |f| goal_fn_callable(graph.graph.node_weight(f).unwrap()),
-        |e| edge_cost_callable.call(py, e.weight()),
+        |e| edge_cost_callable.call_edge(py, e.id(), e.weight()),
         |estimate| estimate_cost_callable.call(py, &graph.graph[estimate]),
-    )?;
+    )
+    .map_err(core_error)?;
     let path = match astar_res {
         Some(path) => path,
         None => return Err(NoPathFound::new_err("No path found that satisfies goal_fn")),
@@ -576,6 +882,130 @@ pub fn graph_astar_shortest_path(
     })
 }
 
+fn shortest_path_between_sets<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    cost_fn: &CostFn,
+) -> PyResult<NodeIndices> {
+    let sources: Vec<NodeIndex> = sources.into_iter().map(NodeIndex::new).collect();
+    let targets: Vec<NodeIndex> = targets.into_iter().map(NodeIndex::new).collect();
+    if sources
+        .iter()
+        .chain(targets.iter())
+        .any(|node| !graph.contains_node(*node))
+    {
+        return Err(InvalidNode::new_err(
+            "The input indices for 'sources' and 'targets' must be valid node indices",
+        ));
+    }
+    let res = dijkstra_between_sets(graph, sources, targets, |e| {
+        cost_fn.call_edge(py, e.id(), e.weight())
+    })
+    .map_err(core_error)?;
+    match res {
+        Some((_, path)) => Ok(NodeIndices {
+            nodes: path.into_iter().map(|node| node.index()).collect(),
+        }),
+        None => Err(NoPathFound::new_err(
+            "No path found from 'sources' to 'targets'",
+        )),
+    }
+}
+
+/// Find the shortest path from any node in a set of sources to any node in a
+/// set of targets in a PyGraph
+///
+/// The path is found with a single run of Dijkstra's algorithm from all the
+/// sources at once, which is the same as searching from a virtual node
+/// joined to every source by a zero weight edge to a virtual node joined to
+/// every target by a zero weight edge. It's much faster than finding the
+/// shortest path between every pair of a source and a target.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(4, 4)
+///   print(retworkx.graph_shortest_path_between_sets(graph, [0, 3], [14, 15]))
+///
+/// :param PyGraph graph: The input graph to use
+/// :param list sources: The node indices to find the path from
+/// :param list targets: The node indices to find the path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight/cost of each edge.
+///
+/// :returns: The node indices of the shortest path, starting with a node in
+///     ``sources`` and ending with a node in ``targets``. If a node is in
+///     both sets the path is just that node.
+/// :rtype: NodeIndices
+/// :raises NoPathFound: If there is no path from any source to any target
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, sources, targets, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_shortest_path_between_sets(
+    py: Python,
+    graph: &graph::PyGraph,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    shortest_path_between_sets(py, &graph.graph, sources, targets, &cost_fn)
+}
+
+/// Find the shortest path from any node in a set of sources to any node in a
+/// set of targets in a PyDiGraph
+///
+/// The path is found with a single run of Dijkstra's algorithm from all the
+/// sources at once, which is the same as searching from a virtual node with
+/// a zero weight edge to every source to a virtual node with a zero weight
+/// edge from every target. It's much faster than finding the
+/// shortest path between every pair of a source and a target.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_grid_graph(4, 4)
+///   print(retworkx.digraph_shortest_path_between_sets(graph, [0, 3], [12, 13]))
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param list sources: The node indices to find the path from
+/// :param list targets: The node indices to find the path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight/cost of each edge.
+///
+/// :returns: The node indices of the shortest path, starting with a node in
+///     ``sources`` and ending with a node in ``targets``. If a node is in
+///     both sets the path is just that node.
+/// :rtype: NodeIndices
+/// :raises NoPathFound: If there is no path from any source to any target
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, sources, targets, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_shortest_path_between_sets(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<NodeIndices> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    shortest_path_between_sets(py, &graph.graph, sources, targets, &cost_fn)
+}
+
 /// Compute the length of the kth shortest path
 ///
 /// Computes the lengths of the kth shortest path from ``start`` to every
@@ -607,12 +1037,13 @@ pub fn digraph_k_shortest_path_lengths(
     goal: Option<usize>,
 ) -> PyResult<PathLengthMapping> {
     let out_goal = goal.map(NodeIndex::new);
-    let edge_cost_callable = CostFn::from(edge_cost);
+    let edge_cost_callable = CostFn::from(edge_cost).prepare(py, &graph.graph)?;
 
     let out_map: Vec<Option<f64>> =
         k_shortest_path(&graph.graph, NodeIndex::new(start), out_goal, k, |e| {
-            edge_cost_callable.call(py, e.weight())
-        })?;
+            edge_cost_callable.call_edge(py, e.id(), e.weight())
+        })
+        .map_err(core_error)?;
 
     if let Some(goal_usize) = goal {
         return Ok(PathLengthMapping {
@@ -667,12 +1098,13 @@ pub fn graph_k_shortest_path_lengths(
     goal: Option<usize>,
 ) -> PyResult<PathLengthMapping> {
     let out_goal = goal.map(NodeIndex::new);
-    let edge_cost_callable = CostFn::from(edge_cost);
+    let edge_cost_callable = CostFn::from(edge_cost).prepare(py, &graph.graph)?;
 
     let out_map: Vec<Option<f64>> =
         k_shortest_path(&graph.graph, NodeIndex::new(start), out_goal, k, |e| {
-            edge_cost_callable.call(py, e.weight())
-        })?;
+            edge_cost_callable.call_edge(py, e.id(), e.weight())
+        })
+        .map_err(core_error)?;
 
     if let Some(goal_usize) = goal {
         return Ok(PathLengthMapping {
@@ -696,6 +1128,115 @@ pub fn graph_k_shortest_path_lengths(
     })
 }
 
+fn bellman_ford_shortest_path_lengths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    node: usize,
+    edge_cost_fn: PyObject,
+) -> PyResult<PathLengthMapping> {
+    let res: PyResult<(Vec<Option<f64>>, _, _)> = bellman_ford(graph, NodeIndex::new(node), |e| {
+        let cost: f64 = edge_cost_fn.call1(py, (e.weight(),))?.extract(py)?;
+        if cost.is_nan() {
+            return Err(PyValueError::new_err("NaN weights not supported."));
+        }
+        Ok(cost)
+    })
+    .map_err(core_error);
+    let (scores, _, negative_cycle) = res?;
+    if let Some(cycle) = negative_cycle {
+        return Err(negative_cycle_error(
+            py,
+            cycle.iter().map(|node| node.index()).collect(),
+        ));
+    }
+    Ok(PathLengthMapping {
+        path_lengths: scores
+            .into_iter()
+            .enumerate()
+            .filter_map(|(k_int, opt_v)| {
+                if k_int != node {
+                    opt_v.map(|v| (k_int, v))
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    })
+}
+
+/// Compute the lengths of the shortest paths for a PyDiGraph object using
+/// the Bellman-Ford algorithm
+///
+/// Unlike :func:`~retworkx.digraph_dijkstra_shortest_path_lengths` the edge
+/// costs can be negative, as long as there is no cycle with a negative total
+/// cost reachable from ``node``.
+///
+/// Computes in :math:`O(|V| * |E|)` time.
+///
+/// :param PyDiGraph graph: The input graph to use
+/// :param int node: The node index to use as the source for finding the
+///     shortest paths from
+/// :param edge_cost_fn: A python callable that will take in 1 parameter, an
+///     edge's data object and will return a float that represents the
+///     cost/weight of that edge. It can be negative
+///
+/// :returns: A dictionary of the shortest paths from the provided node where
+///     the key is the node index of the end of the path and the value is the
+///     cost/sum of the weights of path
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge weight with NaN value is provided.
+/// :raises InvalidNode: when ``node`` isn't a node in the graph
+/// :raises NegativeCycleError: when a negative cycle is reachable from
+///     ``node``, the ``cycle`` attribute of the exception is a list of the
+///     node indices on the cycle in order
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, edge_cost_fn, /)")]
+pub fn digraph_bellman_ford_shortest_path_lengths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    edge_cost_fn: PyObject,
+) -> PyResult<PathLengthMapping> {
+    bellman_ford_shortest_path_lengths(py, &graph.graph, node, edge_cost_fn)
+}
+
+/// Compute the lengths of the shortest paths for a PyGraph object using
+/// the Bellman-Ford algorithm
+///
+/// Unlike :func:`~retworkx.graph_dijkstra_shortest_path_lengths` the edge
+/// costs can be negative, but as every edge of a :class:`~retworkx.PyGraph`
+/// can be traversed in both directions an edge with a negative cost reachable
+/// from ``node`` is a negative cycle.
+///
+/// Computes in :math:`O(|V| * |E|)` time.
+///
+/// :param PyGraph graph: The input graph to use
+/// :param int node: The node index to use as the source for finding the
+///     shortest paths from
+/// :param edge_cost_fn: A python callable that will take in 1 parameter, an
+///     edge's data object and will return a float that represents the
+///     cost/weight of that edge
+///
+/// :returns: A dictionary of the shortest paths from the provided node where
+///     the key is the node index of the end of the path and the value is the
+///     cost/sum of the weights of path
+/// :rtype: PathLengthMapping
+/// :raises ValueError: when an edge weight with NaN value is provided.
+/// :raises InvalidNode: when ``node`` isn't a node in the graph
+/// :raises NegativeCycleError: when a negative cycle is reachable from
+///     ``node``, the ``cycle`` attribute of the exception is a list of the
+///     node indices on the cycle in order
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, edge_cost_fn, /)")]
+pub fn graph_bellman_ford_shortest_path_lengths(
+    py: Python,
+    graph: &graph::PyGraph,
+    node: usize,
+    edge_cost_fn: PyObject,
+) -> PyResult<PathLengthMapping> {
+    bellman_ford_shortest_path_lengths(py, &graph.graph, node, edge_cost_fn)
+}
+
 /// Find all-pairs shortest path lengths using Floyd's algorithm
 ///
 /// Floyd's algorithm is used for finding shortest paths in dense graphs
@@ -15,13 +15,12 @@ use retworkx_core::dictmap::*;
 use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
 
-use petgraph::graph::NodeIndex;
 use petgraph::visit::{Bfs, NodeIndexable};
 use petgraph::EdgeType;
 
 use num_bigint::{BigUint, ToBigUint};
 
-use crate::StablePyGraph;
+use crate::{NodeIndex, StablePyGraph};
 
 pub fn num_shortest_paths_unweighted<Ty: EdgeType>(
     graph: &StablePyGraph<Ty>,
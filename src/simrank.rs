@@ -0,0 +1,375 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+use rayon::prelude::*;
+
+use crate::{convergence_error, digraph, graph, InvalidNode, StablePyGraph};
+
+/// The in neighbors of every node by compact node index, without repeats
+/// from parallel edges, and the compact index of every node index.
+fn in_neighbors<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> (Vec<Vec<usize>>, Vec<Option<usize>>) {
+    let mut compact: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    for (position, node) in graph.node_indices().enumerate() {
+        compact[node.index()] = Some(position);
+    }
+    let neighbors = graph
+        .node_indices()
+        .map(|node| {
+            let mut neighbors: Vec<usize> = graph
+                .neighbors_directed(node, Incoming)
+                .map(|neighbor| compact[neighbor.index()].unwrap())
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+    (neighbors, compact)
+}
+
+/// Iterate the SimRank equations on the full similarity matrix until every
+/// entry changes by at most ``tolerance`` relative to its previous value.
+fn simrank_matrix(
+    neighbors: &[Vec<usize>],
+    importance_factor: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> Option<Array2<f64>> {
+    let n = neighbors.len();
+    let mut similarity = Array2::<f64>::eye(n);
+    for _ in 0..max_iterations {
+        // Average the columns of the in neighbors of every node first, so an
+        // iteration takes O(nm) time rather than O(n^2 d^2)
+        let mut partial = Array2::<f64>::zeros((n, n));
+        partial
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .zip(similarity.axis_iter(Axis(0)).into_par_iter())
+            .for_each(|(mut row, previous)| {
+                for (column, in_neighbors) in neighbors.iter().enumerate() {
+                    if !in_neighbors.is_empty() {
+                        row[column] = in_neighbors.iter().map(|j| previous[*j]).sum::<f64>()
+                            / in_neighbors.len() as f64;
+                    }
+                }
+            });
+        let mut next = Array2::<f64>::zeros((n, n));
+        next.axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(node, mut row)| {
+                let in_neighbors = &neighbors[node];
+                if !in_neighbors.is_empty() {
+                    let scale = importance_factor / in_neighbors.len() as f64;
+                    for i in in_neighbors {
+                        row.scaled_add(scale, &partial.row(*i));
+                    }
+                }
+                row[node] = 1.0;
+            });
+        let converged = next
+            .iter()
+            .zip(similarity.iter())
+            .all(|(new, old)| (new - old).abs() <= tolerance * (1.0 + old.abs()));
+        similarity = next;
+        if converged {
+            return Some(similarity);
+        }
+    }
+    None
+}
+
+/// Compute the SimRank similarity of a single pair of nodes as the expected
+/// value of ``importance_factor`` to the power of the first meeting time of
+/// two random walks along the in edges of the nodes, until the remaining
+/// contribution is at most ``tolerance``.
+fn simrank_pair(
+    neighbors: &[Vec<usize>],
+    first: usize,
+    second: usize,
+    importance_factor: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> Option<f64> {
+    if first == second {
+        return Some(1.0);
+    }
+    let mut walkers: HashMap<(usize, usize), f64> = HashMap::new();
+    walkers.insert((first, second), 1.0);
+    let mut score = 0.0;
+    let mut weight = 1.0;
+    for _ in 0..max_iterations {
+        weight *= importance_factor;
+        let mut next: HashMap<(usize, usize), f64> = HashMap::new();
+        for ((a, b), mass) in walkers {
+            let (in_a, in_b) = (&neighbors[a], &neighbors[b]);
+            if in_a.is_empty() || in_b.is_empty() {
+                continue;
+            }
+            let step = mass / (in_a.len() * in_b.len()) as f64;
+            for i in in_a {
+                for j in in_b {
+                    if i == j {
+                        score += weight * step;
+                    } else {
+                        let key = if i < j { (*i, *j) } else { (*j, *i) };
+                        *next.entry(key).or_insert(0.0) += step;
+                    }
+                }
+            }
+        }
+        walkers = next;
+        let remaining: f64 = walkers.values().sum();
+        if remaining * weight * importance_factor <= tolerance {
+            return Some(score);
+        }
+    }
+    None
+}
+
+fn simrank<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    pairs: Option<Vec<(usize, usize)>>,
+    importance_factor: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<PyObject> {
+    if !(0.0..=1.0).contains(&importance_factor) {
+        return Err(PyValueError::new_err(
+            "importance_factor must be between 0 and 1",
+        ));
+    }
+    let (neighbors, compact) = in_neighbors(graph);
+    let not_converged = || {
+        convergence_error(
+            py,
+            format!(
+                "SimRank did not converge after {} iterations",
+                max_iterations
+            ),
+            max_iterations,
+        )
+    };
+    match pairs {
+        None => {
+            let similarity = py
+                .allow_threads(|| {
+                    simrank_matrix(&neighbors, importance_factor, max_iterations, tolerance)
+                })
+                .ok_or_else(not_converged)?;
+            Ok(similarity.into_pyarray(py).into())
+        }
+        Some(pairs) => {
+            let lookup = |node: usize| -> PyResult<usize> {
+                compact.get(node).copied().flatten().ok_or_else(|| {
+                    InvalidNode::new_err(format!("Node index {} is not present in the graph", node))
+                })
+            };
+            let positions = pairs
+                .iter()
+                .map(|(u, v)| Ok((lookup(*u)?, lookup(*v)?)))
+                .collect::<PyResult<Vec<(usize, usize)>>>()?;
+            let scores = py
+                .allow_threads(|| {
+                    positions
+                        .par_iter()
+                        .map(|(u, v)| {
+                            simrank_pair(
+                                &neighbors,
+                                *u,
+                                *v,
+                                importance_factor,
+                                max_iterations,
+                                tolerance,
+                            )
+                        })
+                        .collect::<Option<Vec<f64>>>()
+                })
+                .ok_or_else(not_converged)?;
+            Ok(Array1::from(scores).into_pyarray(py).into())
+        }
+    }
+}
+
+/// Compute the SimRank similarity of the nodes of a graph
+///
+/// SimRank [1]_ considers two nodes similar if their neighbors are similar,
+/// the similarity :math:`s(u, v)` of two distinct nodes is
+///
+/// .. math::
+///
+///     s(u, v) = \frac{C}{|\Gamma(u)| |\Gamma(v)|}
+///         \sum_{a \in \Gamma(u)} \sum_{b \in \Gamma(v)} s(a, b)
+///
+/// where :math:`\Gamma(u)` is the set of neighbors of :math:`u` and
+/// :math:`C` is the importance factor, and every node has a similarity of 1
+/// with itself. Parallel edges are ignored.
+///
+/// If ``pairs`` isn't set the full similarity matrix is computed by
+/// iterating the equation above until no entry changes by more than
+/// ``tolerance`` times one plus its previous value. This takes
+/// :math:`O(nm)` time per iteration and :math:`O(n^2)` memory. For large
+/// graphs ``pairs`` can be set to only compute the similarity of those
+/// pairs, each from the equivalent expected value of :math:`C^t` where
+/// :math:`t` is the first time two random walks from the nodes meet, until
+/// the remaining contribution to the similarity is at most ``tolerance``.
+/// The pairs are computed in parallel, the env var ``RAYON_NUM_THREADS``
+/// can be used to adjust how many threads will be used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(4)
+///   print(retworkx.graph_simrank_similarity(graph))
+///
+/// :param PyGraph graph: The graph to compute the similarities of
+/// :param list pairs: An optional list of node index pairs to compute the
+///     similarity of
+/// :param float importance_factor: The factor :math:`C` the similarity of
+///     the neighbors is scaled by, between 0 and 1. By default this is
+///     ``0.9``.
+/// :param int max_iterations: The maximum number of iterations. By default
+///     this is ``1000``.
+/// :param float tolerance: The tolerance for convergence. By default this
+///     is ``1e-4``.
+///
+/// :returns: If ``pairs`` isn't set a numpy array of the similarity of every
+///     pair of nodes, with the rows and columns in node index order,
+///     otherwise a numpy array of the similarity of each pair in ``pairs``
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``importance_factor`` is not between 0 and 1
+/// :raises InvalidNode: If a node index in ``pairs`` is not in the graph
+/// :raises ConvergenceError: If the similarities didn't converge within
+///     ``max_iterations`` iterations
+///
+/// .. [1] Jeh, Glen, and Jennifer Widom. "SimRank: a measure of
+///     structural-context similarity." Proceedings of the eighth ACM SIGKDD
+///     international conference on Knowledge discovery and data mining
+///     (2002).
+#[pyfunction(importance_factor = "0.9", max_iterations = "1000", tolerance = "1e-4")]
+#[pyo3(
+    text_signature = "(graph, /, pairs=None, importance_factor=0.9, max_iterations=1000, tolerance=1e-4)"
+)]
+pub fn graph_simrank_similarity(
+    py: Python,
+    graph: &graph::PyGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    importance_factor: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<PyObject> {
+    simrank(
+        py,
+        &graph.graph,
+        pairs,
+        importance_factor,
+        max_iterations,
+        tolerance,
+    )
+}
+
+/// Compute the SimRank similarity of the nodes of a directed graph
+///
+/// SimRank [1]_ considers two nodes similar if they are pointed to by
+/// similar nodes, the similarity :math:`s(u, v)` of two distinct nodes is
+///
+/// .. math::
+///
+///     s(u, v) = \frac{C}{|I(u)| |I(v)|}
+///         \sum_{a \in I(u)} \sum_{b \in I(v)} s(a, b)
+///
+/// where :math:`I(u)` is the set of predecessors of :math:`u` and :math:`C`
+/// is the importance factor, and every node has a similarity of 1 with
+/// itself. Nodes without predecessors have a similarity of 0 with every
+/// other node. Parallel edges are ignored.
+///
+/// If ``pairs`` isn't set the full similarity matrix is computed by
+/// iterating the equation above until no entry changes by more than
+/// ``tolerance`` times one plus its previous value. This takes
+/// :math:`O(nm)` time per iteration and :math:`O(n^2)` memory. For large
+/// graphs ``pairs`` can be set to only compute the similarity of those
+/// pairs, each from the equivalent expected value of :math:`C^t` where
+/// :math:`t` is the first time two random walks backwards from the nodes
+/// meet, until the remaining contribution to the similarity is at most
+/// ``tolerance``. The pairs are computed in parallel, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be
+/// used.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_star_graph(4)
+///   print(retworkx.digraph_simrank_similarity(graph, pairs=[(1, 2), (0, 1)]))
+///
+/// :param PyDiGraph graph: The directed graph to compute the similarities
+///     of
+/// :param list pairs: An optional list of node index pairs to compute the
+///     similarity of
+/// :param float importance_factor: The factor :math:`C` the similarity of
+///     the predecessors is scaled by, between 0 and 1. By default this is
+///     ``0.9``.
+/// :param int max_iterations: The maximum number of iterations. By default
+///     this is ``1000``.
+/// :param float tolerance: The tolerance for convergence. By default this
+///     is ``1e-4``.
+///
+/// :returns: If ``pairs`` isn't set a numpy array of the similarity of every
+///     pair of nodes, with the rows and columns in node index order,
+///     otherwise a numpy array of the similarity of each pair in ``pairs``
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``importance_factor`` is not between 0 and 1
+/// :raises InvalidNode: If a node index in ``pairs`` is not in the graph
+/// :raises ConvergenceError: If the similarities didn't converge within
+///     ``max_iterations`` iterations
+///
+/// .. [1] Jeh, Glen, and Jennifer Widom. "SimRank: a measure of
+///     structural-context similarity." Proceedings of the eighth ACM SIGKDD
+///     international conference on Knowledge discovery and data mining
+///     (2002).
+#[pyfunction(importance_factor = "0.9", max_iterations = "1000", tolerance = "1e-4")]
+#[pyo3(
+    text_signature = "(graph, /, pairs=None, importance_factor=0.9, max_iterations=1000, tolerance=1e-4)"
+)]
+pub fn digraph_simrank_similarity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    pairs: Option<Vec<(usize, usize)>>,
+    importance_factor: f64,
+    max_iterations: usize,
+    tolerance: f64,
+) -> PyResult<PyObject> {
+    simrank(
+        py,
+        &graph.graph,
+        pairs,
+        importance_factor,
+        max_iterations,
+        tolerance,
+    )
+}
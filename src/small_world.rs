@@ -0,0 +1,537 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use hashbrown::HashSet;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+use rayon::prelude::*;
+
+use crate::random_seed::seeded_rng;
+use crate::transitivity::{clustering_coefficient, node_triangles};
+use crate::{graph, DisconnectedGraphError, NodeIndex};
+
+/// The edges of a simple undirected graph over compact node indices, which
+/// are rewired by double edge swaps that keep the degree of every node.
+#[derive(Clone)]
+struct Rewiring {
+    edges: Vec<(usize, usize)>,
+    neighbors: Vec<Vec<usize>>,
+    edge_set: HashSet<(usize, usize)>,
+}
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn replace_neighbor(neighbors: &mut [usize], old: usize, new: usize) {
+    let position = neighbors.iter().position(|node| *node == old).unwrap();
+    neighbors[position] = new;
+}
+
+impl Rewiring {
+    /// Build the rewiring of ``graph``, ignoring self loops and parallel
+    /// edges, along with the node index of each compact node index.
+    fn new(graph: &graph::PyGraph) -> PyResult<(Vec<usize>, Self)> {
+        if graph.graph.node_count() < 4 {
+            return Err(PyValueError::new_err("The graph has fewer than four nodes"));
+        }
+        let nodes: Vec<usize> = graph
+            .graph
+            .node_indices()
+            .map(|node| node.index())
+            .collect();
+        let mut compact = vec![0; graph.graph.node_bound()];
+        for (position, node) in nodes.iter().enumerate() {
+            compact[*node] = position;
+        }
+        let mut rewiring = Rewiring {
+            edges: Vec::with_capacity(graph.graph.edge_count()),
+            neighbors: vec![Vec::new(); nodes.len()],
+            edge_set: HashSet::with_capacity(graph.graph.edge_count()),
+        };
+        for edge in graph.graph.edge_references() {
+            let a = compact[edge.source().index()];
+            let b = compact[edge.target().index()];
+            if a != b && rewiring.edge_set.insert(edge_key(a, b)) {
+                rewiring.edges.push((a, b));
+                rewiring.neighbors[a].push(b);
+                rewiring.neighbors[b].push(a);
+            }
+        }
+        Ok((nodes, rewiring))
+    }
+
+    /// Replace the edges ``(a, b)`` at ``first`` and ``(c, d)`` at
+    /// ``second`` with ``(a, d)`` and ``(c, b)``.
+    fn swap(
+        &mut self,
+        first: usize,
+        second: usize,
+        (a, b): (usize, usize),
+        (c, d): (usize, usize),
+    ) {
+        self.edges[first] = (a, d);
+        self.edges[second] = (c, b);
+        self.edge_set.remove(&edge_key(a, b));
+        self.edge_set.remove(&edge_key(c, d));
+        self.edge_set.insert(edge_key(a, d));
+        self.edge_set.insert(edge_key(c, b));
+        replace_neighbor(&mut self.neighbors[a], b, d);
+        replace_neighbor(&mut self.neighbors[b], a, c);
+        replace_neighbor(&mut self.neighbors[c], d, b);
+        replace_neighbor(&mut self.neighbors[d], c, a);
+    }
+
+    fn connected(&self, source: usize, target: usize) -> bool {
+        let mut seen = vec![false; self.neighbors.len()];
+        let mut queue = VecDeque::from(vec![source]);
+        seen[source] = true;
+        while let Some(node) = queue.pop_front() {
+            for neighbor in &self.neighbors[node] {
+                if *neighbor == target {
+                    return true;
+                }
+                if !seen[*neighbor] {
+                    seen[*neighbor] = true;
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+        false
+    }
+
+    /// Run ``niter`` rounds of double edge swaps per edge. Each swap is
+    /// attempted a bounded number of times and a candidate swap is only made
+    /// if it doesn't create parallel edges or self loops, if ``accept``
+    /// returns ``true`` for it, and, when ``connectivity`` is set, if the
+    /// graph stays connected.
+    fn rewire<F>(&mut self, rng: &mut Pcg64, niter: usize, connectivity: bool, accept: F)
+    where
+        F: Fn((usize, usize), (usize, usize)) -> bool,
+    {
+        let edge_count = self.edges.len();
+        if edge_count < 2 {
+            return;
+        }
+        let max_attempts = std::cmp::max(1, 2 * edge_count / (self.neighbors.len() - 1));
+        let orient = |(a, b): (usize, usize), flip: bool| if flip { (b, a) } else { (a, b) };
+        for _ in 0..niter * edge_count {
+            for _ in 0..max_attempts {
+                let first = rng.gen_range(0..edge_count);
+                let second = rng.gen_range(0..edge_count);
+                let (a, b) = orient(self.edges[first], rng.gen());
+                let (c, d) = orient(self.edges[second], rng.gen());
+                if first == second || a == c || a == d || b == c || b == d {
+                    continue;
+                }
+                if self.edge_set.contains(&edge_key(a, d))
+                    || self.edge_set.contains(&edge_key(c, b))
+                {
+                    continue;
+                }
+                if !accept((a, b), (c, d)) {
+                    continue;
+                }
+                self.swap(first, second, (a, b), (c, d));
+                if connectivity && !self.connected(a, b) {
+                    self.swap(first, second, (a, d), (c, b));
+                    continue;
+                }
+                break;
+            }
+        }
+    }
+
+    fn randomize(&mut self, rng: &mut Pcg64, niter: usize, connectivity: bool) {
+        self.rewire(rng, niter, connectivity, |_, _| true);
+    }
+
+    /// Rewire towards a ring lattice, only swapping edges if the new edges
+    /// are no longer around the ring of compact node indices than the old.
+    fn latticize(&mut self, rng: &mut Pcg64, niter: usize, connectivity: bool) {
+        let node_count = self.neighbors.len();
+        let ring_distance = |a: usize, b: usize| {
+            let distance = std::cmp::max(a, b) - std::cmp::min(a, b);
+            std::cmp::min(distance, node_count - distance)
+        };
+        self.rewire(rng, niter, connectivity, |(a, b), (c, d)| {
+            ring_distance(a, b) + ring_distance(c, d) >= ring_distance(a, d) + ring_distance(c, b)
+        });
+    }
+
+    /// The neighbor lists of every compact node index, sorted.
+    fn sorted_neighbors(&self) -> Vec<Vec<usize>> {
+        self.neighbors
+            .iter()
+            .map(|neighbors| {
+                let mut neighbors = neighbors.clone();
+                neighbors.sort_unstable();
+                neighbors
+            })
+            .collect()
+    }
+
+    fn transitivity(&self) -> f64 {
+        let adjacency = self.sorted_neighbors();
+        let triangles: usize = node_triangles(&adjacency).iter().sum();
+        let triples: usize = adjacency
+            .iter()
+            .map(|neighbors| neighbors.len() * neighbors.len().saturating_sub(1) / 2)
+            .sum();
+        match triangles {
+            0 => 0.0,
+            _ => triangles as f64 / triples as f64,
+        }
+    }
+
+    fn average_clustering(&self) -> f64 {
+        let adjacency = self.sorted_neighbors();
+        let total: f64 = node_triangles(&adjacency)
+            .iter()
+            .zip(&adjacency)
+            .map(|(triangles, neighbors)| clustering_coefficient(*triangles, neighbors.len()))
+            .sum();
+        total / adjacency.len() as f64
+    }
+
+    /// The average shortest path length over all pairs of nodes, or
+    /// ``None`` if the graph isn't connected.
+    fn average_shortest_path_length(&self) -> Option<f64> {
+        let node_count = self.neighbors.len();
+        let distances: Option<usize> = (0..node_count)
+            .into_par_iter()
+            .map(|source| {
+                let mut distance: Vec<Option<usize>> = vec![None; node_count];
+                let mut queue = VecDeque::from(vec![source]);
+                distance[source] = Some(0);
+                let mut total = 0;
+                let mut reached = 1;
+                while let Some(node) = queue.pop_front() {
+                    let next = distance[node].unwrap() + 1;
+                    for neighbor in &self.neighbors[node] {
+                        if distance[*neighbor].is_none() {
+                            distance[*neighbor] = Some(next);
+                            total += next;
+                            reached += 1;
+                            queue.push_back(*neighbor);
+                        }
+                    }
+                }
+                if reached == node_count {
+                    Some(total)
+                } else {
+                    None
+                }
+            })
+            .sum();
+        distances.map(|total| total as f64 / (node_count * (node_count - 1)) as f64)
+    }
+
+    fn to_graph(&self, py: Python, graph: &graph::PyGraph, nodes: &[usize]) -> graph::PyGraph {
+        let mut out = graph.clone();
+        out.graph.clear_edges();
+        for (a, b) in &self.edges {
+            out.graph.add_edge(
+                NodeIndex::new(nodes[*a]),
+                NodeIndex::new(nodes[*b]),
+                py.None(),
+            );
+        }
+        out
+    }
+}
+
+fn not_connected() -> PyErr {
+    DisconnectedGraphError::new_err("The graph is not connected")
+}
+
+/// Generate a random reference graph with the same degrees as a graph
+///
+/// The reference graph is created by repeated double edge swaps, which
+/// replace two edges ``(a, b)`` and ``(c, d)`` with ``(a, d)`` and
+/// ``(c, b)``, so every node keeps its degree while the rest of the
+/// structure of the graph is randomized [1]_. Swaps that would create self
+/// loops or parallel edges are skipped.
+///
+/// The returned graph has the same nodes, with the same indices and data
+/// payloads, as the input graph. Its edges have ``None`` as their data
+/// payload. Self loops and parallel edges in the input graph are ignored.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(4, 4)
+///   reference = retworkx.random_reference(graph, seed=42)
+///   print(retworkx.graph_transitivity(reference))
+///
+/// :param PyGraph graph: The graph to generate a reference graph for
+/// :param int niter: The number of rounds of rewiring, each round attempts to
+///     swap as many pairs of edges as there are edges in the graph
+/// :param bool connectivity: If set to ``True`` swaps that would disconnect
+///     the graph are rejected
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The random reference graph
+/// :rtype: PyGraph
+///
+/// :raises ValueError: If the graph has fewer than four nodes
+///
+/// .. [1] Maslov, Sergei, and Kim Sneppen. "Specificity and stability in
+///    topology of protein networks." Science 296.5569 (2002): 910-913.
+#[pyfunction(niter = "1", connectivity = "true")]
+#[pyo3(text_signature = "(graph, /, niter=1, connectivity=True, seed=None)")]
+pub fn random_reference(
+    py: Python,
+    graph: &graph::PyGraph,
+    niter: usize,
+    connectivity: bool,
+    seed: Option<u64>,
+) -> PyResult<graph::PyGraph> {
+    let (nodes, mut rewiring) = Rewiring::new(graph)?;
+    let mut rng = seeded_rng(seed);
+    rewiring.randomize(&mut rng, niter, connectivity);
+    Ok(rewiring.to_graph(py, graph, &nodes))
+}
+
+/// Generate a lattice reference graph with the same degrees as a graph
+///
+/// The reference graph is created by repeated double edge swaps, like
+/// :func:`~retworkx.random_reference`, but a swap is only made if the new
+/// edges are no longer than the old ones around a ring of the nodes in node
+/// index order [1]_. This pushes the edges of the graph towards a ring
+/// lattice while every node keeps its degree.
+///
+/// The returned graph has the same nodes, with the same indices and data
+/// payloads, as the input graph. Its edges have ``None`` as their data
+/// payload. Self loops and parallel edges in the input graph are ignored.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.undirected_gnm_random_graph(20, 60, seed=42)
+///   reference = retworkx.lattice_reference(graph, seed=42)
+///   print(retworkx.graph_average_clustering(graph))
+///   print(retworkx.graph_average_clustering(reference))
+///
+/// :param PyGraph graph: The graph to generate a reference graph for
+/// :param int niter: The number of rounds of rewiring, each round attempts to
+///     swap as many pairs of edges as there are edges in the graph
+/// :param bool connectivity: If set to ``True`` swaps that would disconnect
+///     the graph are rejected
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The lattice reference graph
+/// :rtype: PyGraph
+///
+/// :raises ValueError: If the graph has fewer than four nodes
+///
+/// .. [1] Sporns, Olaf, and Jonathan D. Zwi. "The small world of the
+///    cerebral cortex." Neuroinformatics 2.2 (2004): 145-162.
+#[pyfunction(niter = "5", connectivity = "true")]
+#[pyo3(text_signature = "(graph, /, niter=5, connectivity=True, seed=None)")]
+pub fn lattice_reference(
+    py: Python,
+    graph: &graph::PyGraph,
+    niter: usize,
+    connectivity: bool,
+    seed: Option<u64>,
+) -> PyResult<graph::PyGraph> {
+    let (nodes, mut rewiring) = Rewiring::new(graph)?;
+    let mut rng = seeded_rng(seed);
+    rewiring.latticize(&mut rng, niter, connectivity);
+    Ok(rewiring.to_graph(py, graph, &nodes))
+}
+
+/// Compute the small-world coefficient sigma of a graph
+///
+/// The small-world coefficient sigma [1]_ is defined as:
+///
+/// .. math::
+///     \sigma = \frac{C / C_r}{L / L_r}
+///
+/// where :math:`C` and :math:`L` are the transitivity and the average
+/// shortest path length of the graph, and :math:`C_r` and :math:`L_r` are
+/// their averages over ``nrand`` random reference graphs generated like
+/// :func:`~retworkx.random_reference`. A graph is commonly considered small
+/// world if :math:`\sigma > 1`.
+///
+/// The reference graphs are generated and measured in parallel. This
+/// function is multithreaded and will run
+/// launch a thread pool with threads equal to the number of CPUs by default.
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(30)
+///   graph.add_edges_from_no_data([(i, (i + 2) % 30) for i in range(30)])
+///   graph.add_edges_from_no_data([(0, 15), (7, 22)])
+///   print(retworkx.small_world_sigma(graph, niter=10, nrand=4, seed=42))
+///
+/// :param PyGraph graph: The connected graph to compute sigma for
+/// :param int niter: The number of rounds of rewiring for each random
+///     reference graph
+/// :param int nrand: The number of random reference graphs to average over
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The small-world coefficient sigma
+/// :rtype: float
+///
+/// :raises ValueError: If the graph has fewer than four nodes or if
+///     ``nrand`` is 0
+/// :raises DisconnectedGraphError: If the graph isn't connected
+///
+/// .. [1] Humphries, Mark D., and Kevin Gurney. "Network 'small-world-ness':
+///    a quantitative method for determining canonical network equivalence."
+///    PloS one 3.4 (2008): e0002002.
+#[pyfunction(niter = "100", nrand = "10")]
+#[pyo3(text_signature = "(graph, /, niter=100, nrand=10, seed=None)")]
+pub fn small_world_sigma(
+    py: Python,
+    graph: &graph::PyGraph,
+    niter: usize,
+    nrand: usize,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    if nrand == 0 {
+        return Err(PyValueError::new_err("nrand must be > 0"));
+    }
+    let (_nodes, rewiring) = Rewiring::new(graph)?;
+    let mut rng = seeded_rng(seed);
+    let seeds: Vec<u64> = (0..nrand).map(|_| rng.gen()).collect();
+    py.allow_threads(|| {
+        let length = rewiring
+            .average_shortest_path_length()
+            .ok_or_else(not_connected)?;
+        let clustering = rewiring.transitivity();
+        let (reference_clustering, reference_length) = seeds
+            .par_iter()
+            .map(|seed| {
+                let mut reference = rewiring.clone();
+                reference.randomize(&mut Pcg64::seed_from_u64(*seed), niter, true);
+                (
+                    reference.transitivity(),
+                    reference.average_shortest_path_length().unwrap(),
+                )
+            })
+            .reduce(|| (0.0, 0.0), |(c, l), (rc, rl)| (c + rc, l + rl));
+        let reference_clustering = reference_clustering / nrand as f64;
+        let reference_length = reference_length / nrand as f64;
+        Ok((clustering / reference_clustering) / (length / reference_length))
+    })
+}
+
+/// Compute the small-world coefficient omega of a graph
+///
+/// The small-world coefficient omega [1]_ is defined as:
+///
+/// .. math::
+///     \omega = \frac{L_r}{L} - \frac{C}{C_l}
+///
+/// where :math:`C` and :math:`L` are the average clustering coefficient and
+/// the average shortest path length of the graph, :math:`L_r` is the
+/// average shortest path length averaged over ``nrand`` random reference
+/// graphs generated like :func:`~retworkx.random_reference`, and
+/// :math:`C_l` is the largest average clustering coefficient of the graph
+/// and ``nrand`` lattice reference graphs generated like
+/// :func:`~retworkx.lattice_reference`. Values close to 0 indicate a small
+/// world graph, values close to -1 a lattice like graph, and values close
+/// to 1 a random graph.
+///
+/// The reference graphs are generated and measured in parallel. This
+/// function is multithreaded and will run
+/// launch a thread pool with threads equal to the number of CPUs by default.
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(30)
+///   graph.add_edges_from_no_data([(i, (i + 2) % 30) for i in range(30)])
+///   graph.add_edges_from_no_data([(0, 15), (7, 22)])
+///   print(retworkx.small_world_omega(graph, nrand=4, seed=42))
+///
+/// :param PyGraph graph: The connected graph to compute omega for
+/// :param int niter: The number of rounds of rewiring for each lattice
+///     reference graph, the random reference graphs use twice as many
+/// :param int nrand: The number of random and lattice reference graphs to
+///     generate
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The small-world coefficient omega
+/// :rtype: float
+///
+/// :raises ValueError: If the graph has fewer than four nodes or if
+///     ``nrand`` is 0
+/// :raises DisconnectedGraphError: If the graph isn't connected
+///
+/// .. [1] Telesford, Qawi K., et al. "The ubiquity of small-world networks."
+///    Brain connectivity 1.5 (2011): 367-375.
+#[pyfunction(niter = "5", nrand = "10")]
+#[pyo3(text_signature = "(graph, /, niter=5, nrand=10, seed=None)")]
+pub fn small_world_omega(
+    py: Python,
+    graph: &graph::PyGraph,
+    niter: usize,
+    nrand: usize,
+    seed: Option<u64>,
+) -> PyResult<f64> {
+    if nrand == 0 {
+        return Err(PyValueError::new_err("nrand must be > 0"));
+    }
+    let (_nodes, rewiring) = Rewiring::new(graph)?;
+    let mut rng = seeded_rng(seed);
+    let seeds: Vec<(u64, u64)> = (0..nrand).map(|_| (rng.gen(), rng.gen())).collect();
+    py.allow_threads(|| {
+        let length = rewiring
+            .average_shortest_path_length()
+            .ok_or_else(not_connected)?;
+        let clustering = rewiring.average_clustering();
+        let (lattice_clustering, reference_length) = seeds
+            .par_iter()
+            .map(|(random_seed, lattice_seed)| {
+                let mut reference = rewiring.clone();
+                reference.randomize(&mut Pcg64::seed_from_u64(*random_seed), 2 * niter, true);
+                let mut lattice = rewiring.clone();
+                lattice.latticize(&mut Pcg64::seed_from_u64(*lattice_seed), niter, true);
+                (
+                    lattice.average_clustering(),
+                    reference.average_shortest_path_length().unwrap(),
+                )
+            })
+            .reduce(|| (clustering, 0.0), |(c, l), (rc, rl)| (c.max(rc), l + rl));
+        let reference_length = reference_length / nrand as f64;
+        Ok(reference_length / length - clustering / lattice_clustering)
+    })
+}
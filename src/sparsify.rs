@@ -0,0 +1,166 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::visit::NodeIndexable;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::graph;
+use crate::laplacian::{laplacian_edges, Laplacian};
+use crate::random_seed::seeded_rng;
+
+/// Approximate the effective resistance between the endpoints of each edge
+/// with ``dimensions`` random projections. The resistance of edge ``e`` is
+/// the squared norm of column ``e`` of :math:`W^{1/2} B L^+`, which is
+/// preserved up to a small error by projecting it onto random vectors
+/// (Johnson-Lindenstrauss), so only one Laplacian solve per projection is
+/// needed instead of one per edge.
+fn effective_resistances(
+    laplacian: &Laplacian,
+    edges: &[(usize, usize, f64)],
+    dimensions: usize,
+    rng: &mut Pcg64,
+) -> Vec<f64> {
+    let scale = 1.0 / (dimensions as f64).sqrt();
+    let mut resistances = vec![0.0; edges.len()];
+    let mut projection = vec![0.0; laplacian.node_bound()];
+    for _ in 0..dimensions {
+        projection.iter_mut().for_each(|value| *value = 0.0);
+        for (source, target, weight) in edges {
+            let value = if rng.gen() { scale } else { -scale } * weight.sqrt();
+            projection[*source] += value;
+            projection[*target] -= value;
+        }
+        let solution = laplacian.solve(&projection);
+        for (resistance, (source, target, _)) in resistances.iter_mut().zip(edges) {
+            let difference = solution[*source] - solution[*target];
+            *resistance += difference * difference;
+        }
+    }
+    resistances
+}
+
+/// Sparsify a graph by sampling edges by their effective resistance
+///
+/// This computes a spectral sparsifier [1]_ of the graph, a reweighted
+/// subgraph :math:`H` whose Laplacian approximates the Laplacian of the
+/// graph :math:`G`,
+///
+/// .. math::
+///
+///     (1 - \epsilon) x^T L_G x \le x^T L_H x \le (1 + \epsilon) x^T L_G x
+///
+/// for all vectors :math:`x` with high probability, so the weights of all
+/// cuts and the spectrum of the Laplacian are approximately preserved. The
+/// edges are sampled with replacement with a probability proportional to
+/// their weight times the effective resistance between their endpoints and
+/// each sample adds the weight of the edge divided by the expected number of
+/// times it's sampled. The effective resistances are approximated with
+/// random projections and Laplacian solves with the conjugate gradient
+/// method. Edges which are the only connection between two parts of the
+/// graph have the largest effective resistance and are always kept with
+/// enough samples, while edges of densely connected parts of the graph are
+/// sampled rarely.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(100)
+///   sparse = retworkx.spectral_sparsify(graph, samples=1000, seed=42)
+///   print(graph.num_edges(), sparse.num_edges())
+///
+/// :param PyGraph graph: The graph to sparsify
+/// :param float epsilon: The approximation error, this sets the number of
+///     samples if ``samples`` isn't specified. By default this is ``0.5``.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a
+///     non-negative float which will be used as the weight of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge.
+/// :param int samples: The number of edges to sample. By default this is
+///     :math:`\lceil 4 n \ln n / \epsilon^2 \rceil` for a graph with
+///     :math:`n` nodes, a large graph has to have many more edges than that
+///     to be sparsified.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A new graph with the same nodes, and node indices, as ``graph``
+///     and an edge for each edge of ``graph`` which was sampled, in the same
+///     order, whose weight/data payload is its new weight as a float. Self
+///     loops are never sampled.
+/// :rtype: PyGraph
+///
+/// :raises ValueError: If ``epsilon`` isn't positive or an edge weight is
+///     negative or NaN
+///
+/// .. [1] Spielman, Daniel A., and Nikhil Srivastava. "Graph sparsification
+///    by effective resistances." SIAM Journal on Computing 40.6 (2011):
+///    1913-1926.
+#[pyfunction(epsilon = "0.5", default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, /, epsilon=0.5, weight_fn=None, default_weight=1.0, samples=None, seed=None)"
+)]
+pub fn spectral_sparsify(
+    py: Python,
+    graph: &graph::PyGraph,
+    epsilon: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    samples: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<graph::PyGraph> {
+    if epsilon.is_nan() || epsilon <= 0.0 {
+        return Err(PyValueError::new_err("epsilon must be positive"));
+    }
+    let edges = laplacian_edges(py, graph, &weight_fn, default_weight)?;
+    let node_count = graph.graph.node_count() as f64;
+    let samples = samples.unwrap_or_else(|| {
+        (4.0 * node_count * node_count.ln() / (epsilon * epsilon)).ceil() as usize
+    });
+    let dimensions = (4.0 * node_count.ln()).ceil().max(1.0) as usize;
+    let mut rng = seeded_rng(seed);
+    let node_bound = graph.graph.node_bound();
+    let new_weights: Vec<f64> = py.allow_threads(|| {
+        let laplacian = Laplacian::new(node_bound, &edges);
+        let resistances = effective_resistances(&laplacian, &edges, dimensions, &mut rng);
+        let scores: Vec<f64> = edges
+            .iter()
+            .zip(&resistances)
+            .map(|((_, _, weight), resistance)| weight * resistance)
+            .collect();
+        let total: f64 = scores.iter().sum();
+        let mut new_weights = vec![0.0; edges.len()];
+        if samples == 0 || !total.is_normal() {
+            return new_weights;
+        }
+        let distribution = WeightedIndex::new(&scores).unwrap();
+        for _ in 0..samples {
+            let index = distribution.sample(&mut rng);
+            new_weights[index] += edges[index].2 * total / (samples as f64 * scores[index]);
+        }
+        new_weights
+    });
+    let mut sparse_graph = (*graph).clone();
+    sparse_graph.graph.clear_edges();
+    for ((source, target, _), weight) in edges.iter().zip(new_weights) {
+        if weight > 0.0 {
+            sparse_graph.add_edge(*source, *target, weight.to_object(py))?;
+        }
+    }
+    Ok(sparse_graph)
+}
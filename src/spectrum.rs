@@ -0,0 +1,688 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use super::{get_edge_iter_with_weights, graph, weight_callable};
+
+/// A real symmetric matrix stored as its diagonal and the off diagonal
+/// entries of each row, with the rows in node index order.
+struct SymmetricMatrix {
+    diagonal: Vec<f64>,
+    rows: Vec<Vec<(usize, f64)>>,
+}
+
+impl SymmetricMatrix {
+    /// The adjacency matrix of ``graph``, the weights of parallel edges
+    /// are summed.
+    fn adjacency(
+        py: Python,
+        graph: &graph::PyGraph,
+        weight_fn: &Option<PyObject>,
+        default_weight: f64,
+    ) -> PyResult<Self> {
+        let n = graph.graph.node_count();
+        let mut matrix = SymmetricMatrix {
+            diagonal: vec![0.0; n],
+            rows: vec![Vec::new(); n],
+        };
+        for (i, j, weight) in get_edge_iter_with_weights(&graph.graph) {
+            let weight = weight_callable(py, weight_fn, &weight, default_weight)?;
+            if i == j {
+                matrix.diagonal[i] += weight;
+            } else {
+                matrix.rows[i].push((j, weight));
+                matrix.rows[j].push((i, weight));
+            }
+        }
+        for row in matrix.rows.iter_mut() {
+            row.sort_unstable_by_key(|(column, _)| *column);
+            row.dedup_by(|(column, weight), (kept_column, kept_weight)| {
+                if column == kept_column {
+                    *kept_weight += *weight;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+        Ok(matrix)
+    }
+
+    /// Turn an adjacency matrix into its Laplacian matrix :math:`D - A`, or
+    /// the normalized Laplacian :math:`D^{-1/2} (D - A) D^{-1/2}`.
+    fn into_laplacian(self, normalized: bool) -> Self {
+        let degree: Vec<f64> = self
+            .diagonal
+            .iter()
+            .zip(&self.rows)
+            .map(|(loop_weight, row)| loop_weight + row.iter().map(|(_, w)| w).sum::<f64>())
+            .collect();
+        let scale: Vec<f64> = degree
+            .iter()
+            .map(|degree| {
+                if !normalized {
+                    1.0
+                } else if *degree == 0.0 {
+                    0.0
+                } else {
+                    1.0 / degree.sqrt()
+                }
+            })
+            .collect();
+        let diagonal = self
+            .diagonal
+            .iter()
+            .enumerate()
+            .map(|(i, loop_weight)| (degree[i] - loop_weight) * scale[i] * scale[i])
+            .collect();
+        let rows = self
+            .rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.into_iter()
+                    .map(|(j, weight)| (j, -weight * scale[i] * scale[j]))
+                    .collect()
+            })
+            .collect();
+        SymmetricMatrix { diagonal, rows }
+    }
+
+    fn dense(&self) -> Array2<f64> {
+        let n = self.diagonal.len();
+        let mut matrix = Array2::<f64>::zeros((n, n));
+        for (i, row) in self.rows.iter().enumerate() {
+            matrix[[i, i]] = self.diagonal[i];
+            for (j, weight) in row {
+                matrix[[i, *j]] = *weight;
+            }
+        }
+        matrix
+    }
+
+    fn multiply(&self, vector: &[f64], out: &mut [f64]) {
+        for (i, row) in self.rows.iter().enumerate() {
+            out[i] = self.diagonal[i] * vector[i]
+                + row
+                    .iter()
+                    .map(|(j, weight)| weight * vector[*j])
+                    .sum::<f64>();
+        }
+    }
+}
+
+/// Householder reduction of the symmetric matrix in ``v`` to tridiagonal
+/// form, ``d`` and ``e`` are set to the diagonal and subdiagonal, with
+/// ``e[0]`` unused, and ``v`` to the accumulated transformation. This and
+/// [`tql2`] are derived from the EISPACK routines of the same name.
+fn tred2(v: &mut Array2<f64>, d: &mut [f64], e: &mut [f64]) {
+    let n = d.len();
+    for j in 0..n {
+        d[j] = v[[n - 1, j]];
+    }
+    for i in (1..n).rev() {
+        let mut scale = 0.0;
+        let mut h = 0.0;
+        for value in d.iter().take(i) {
+            scale += value.abs();
+        }
+        if scale == 0.0 {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[[i - 1, j]];
+                v[[i, j]] = 0.0;
+                v[[j, i]] = 0.0;
+            }
+        } else {
+            for value in d.iter_mut().take(i) {
+                *value /= scale;
+                h += *value * *value;
+            }
+            let mut f = d[i - 1];
+            let mut g = if f > 0.0 { -h.sqrt() } else { h.sqrt() };
+            e[i] = scale * g;
+            h -= f * g;
+            d[i - 1] = f - g;
+            for value in e.iter_mut().take(i) {
+                *value = 0.0;
+            }
+            for j in 0..i {
+                f = d[j];
+                v[[j, i]] = f;
+                g = e[j] + v[[j, j]] * f;
+                for k in j + 1..i {
+                    g += v[[k, j]] * d[k];
+                    e[k] += v[[k, j]] * f;
+                }
+                e[j] = g;
+            }
+            f = 0.0;
+            for j in 0..i {
+                e[j] /= h;
+                f += e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] -= hh * d[j];
+            }
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+                for k in j..i {
+                    v[[k, j]] -= f * e[k] + g * d[k];
+                }
+                d[j] = v[[i - 1, j]];
+                v[[i, j]] = 0.0;
+            }
+        }
+        d[i] = h;
+    }
+    for i in 0..n - 1 {
+        v[[n - 1, i]] = v[[i, i]];
+        v[[i, i]] = 1.0;
+        let h = d[i + 1];
+        if h != 0.0 {
+            for k in 0..=i {
+                d[k] = v[[k, i + 1]] / h;
+            }
+            for j in 0..=i {
+                let mut g = 0.0;
+                for k in 0..=i {
+                    g += v[[k, i + 1]] * v[[k, j]];
+                }
+                for k in 0..=i {
+                    v[[k, j]] -= g * d[k];
+                }
+            }
+        }
+        for k in 0..=i {
+            v[[k, i + 1]] = 0.0;
+        }
+    }
+    for j in 0..n {
+        d[j] = v[[n - 1, j]];
+        v[[n - 1, j]] = 0.0;
+    }
+    v[[n - 1, n - 1]] = 1.0;
+    e[0] = 0.0;
+}
+
+/// Find the eigenvalues of the symmetric tridiagonal matrix with diagonal
+/// ``d`` and subdiagonal ``e[1..]`` with the implicit QL method. On return
+/// ``d`` holds the eigenvalues in ascending order and, if ``vectors`` is
+/// set, the columns of ``v`` are replaced by ``v`` times the eigenvectors.
+fn tql2(v: &mut Array2<f64>, d: &mut [f64], e: &mut [f64], vectors: bool) {
+    let n = d.len();
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = 0.0;
+    let mut f = 0.0;
+    let mut tst1: f64 = 0.0;
+    let eps = f64::EPSILON;
+    for l in 0..n {
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > eps * tst1 {
+            m += 1;
+        }
+        if m > l {
+            for _ in 0..30 * n {
+                let mut g = d[l];
+                let mut p = (d[l + 1] - g) / (2.0 * e[l]);
+                let mut r = p.hypot(1.0);
+                if p < 0.0 {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let mut h = g - d[l];
+                for value in d.iter_mut().skip(l + 2) {
+                    *value -= h;
+                }
+                f += h;
+                p = d[m];
+                let mut c = 1.0;
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = 0.0;
+                let mut s2 = 0.0;
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    g = c * e[i];
+                    h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+                    if vectors {
+                        for k in 0..v.nrows() {
+                            h = v[[k, i + 1]];
+                            v[[k, i + 1]] = s * v[[k, i]] + c * h;
+                            v[[k, i]] = c * v[[k, i]] - s * h;
+                        }
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+                if e[l].abs() <= eps * tst1 || e[l].is_nan() {
+                    break;
+                }
+            }
+        }
+        d[l] += f;
+        e[l] = 0.0;
+    }
+    // Sort the eigenvalues, and the eigenvectors with them, in ascending
+    // order
+    for i in 0..n.saturating_sub(1) {
+        let mut k = i;
+        for j in i + 1..n {
+            if d[j] < d[k] {
+                k = j;
+            }
+        }
+        if k != i {
+            d.swap(i, k);
+            if vectors {
+                for row in 0..v.nrows() {
+                    v.swap([row, i], [row, k]);
+                }
+            }
+        }
+    }
+}
+
+/// All the eigenvalues, in ascending order, and optionally the eigenvectors,
+/// as columns, of a dense symmetric matrix.
+fn dense_eigen(matrix: Array2<f64>, vectors: bool) -> (Vec<f64>, Option<Array2<f64>>) {
+    let n = matrix.nrows();
+    if n == 0 {
+        return (Vec::new(), if vectors { Some(matrix) } else { None });
+    }
+    let mut v = matrix;
+    let mut d = vec![0.0; n];
+    let mut e = vec![0.0; n];
+    tred2(&mut v, &mut d, &mut e);
+    tql2(&mut v, &mut d, &mut e, vectors);
+    (d, if vectors { Some(v) } else { None })
+}
+
+fn dot(first: &[f64], second: &[f64]) -> f64 {
+    first.iter().zip(second).map(|(a, b)| a * b).sum()
+}
+
+/// Orthogonalize ``vector`` against the orthonormal vectors of ``bases``
+/// twice, for numerical stability, and return its remaining norm.
+fn orthogonalize(vector: &mut [f64], bases: &[&[Vec<f64>]]) -> f64 {
+    for _ in 0..2 {
+        for q in bases.iter().flat_map(|basis| basis.iter()) {
+            let projection = dot(q, vector);
+            for (value, q_value) in vector.iter_mut().zip(q) {
+                *value -= projection * q_value;
+            }
+        }
+    }
+    dot(vector, vector).sqrt()
+}
+
+/// A random unit vector orthogonal to the vectors of ``bases``.
+fn random_orthogonal(rng: &mut Pcg64, n: usize, bases: &[&[Vec<f64>]]) -> Vec<f64> {
+    loop {
+        let mut vector: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        let norm = orthogonalize(&mut vector, bases);
+        if norm > 1e-8 {
+            vector.iter_mut().for_each(|value| *value /= norm);
+            return vector;
+        }
+    }
+}
+
+/// Run the Lanczos method restricted to the orthogonal complement of the
+/// ``locked`` eigenvectors and return up to ``k`` Ritz pairs at one end of
+/// its spectrum, the most extreme first.
+///
+/// The Krylov basis is fully reorthogonalized and grown until the residuals
+/// of the wanted Ritz pairs are negligible, which always happens once the
+/// basis spans the whole complement. If the basis becomes invariant before
+/// that it is extended with a new random orthogonal vector.
+fn lanczos(
+    matrix: &SymmetricMatrix,
+    k: usize,
+    largest: bool,
+    locked: &[Vec<f64>],
+    rng: &mut Pcg64,
+) -> Vec<(f64, Vec<f64>)> {
+    let n = matrix.diagonal.len();
+    let dimension = n - locked.len();
+    let k = std::cmp::min(k, dimension);
+    let mut basis: Vec<Vec<f64>> = vec![random_orthogonal(rng, n, &[locked])];
+    let mut alpha: Vec<f64> = Vec::new();
+    let mut beta: Vec<f64> = Vec::new();
+    let mut work = vec![0.0; n];
+    let mut target = std::cmp::min(dimension, std::cmp::max(2 * k + 1, 20));
+    loop {
+        while alpha.len() < target {
+            let j = alpha.len();
+            matrix.multiply(&basis[j], &mut work);
+            alpha.push(dot(&basis[j], &work));
+            let norm = orthogonalize(&mut work, &[locked, &basis]);
+            if j + 1 == dimension {
+                break;
+            }
+            if norm <= 1e-10 * (alpha[j].abs() + beta.last().copied().unwrap_or(0.0) + 1.0) {
+                beta.push(0.0);
+                basis.push(random_orthogonal(rng, n, &[locked, &basis]));
+            } else {
+                beta.push(norm);
+                basis.push(work.iter().map(|value| value / norm).collect());
+            }
+        }
+        let m = alpha.len();
+        let mut d = alpha.clone();
+        let mut e: Vec<f64> = std::iter::once(0.0).chain(beta.iter().copied()).collect();
+        e.truncate(m);
+        let mut s = Array2::<f64>::eye(m);
+        tql2(&mut s, &mut d, &mut e, true);
+        let wanted: Vec<usize> = if largest {
+            (m - k..m).rev().collect()
+        } else {
+            (0..k).collect()
+        };
+        let residual = beta.get(m - 1).copied().unwrap_or(0.0);
+        let converged = m == dimension
+            || wanted
+                .iter()
+                .all(|i| (residual * s[[m - 1, *i]]).abs() <= 1e-10 * (d[*i].abs() + 1.0));
+        if converged {
+            return wanted
+                .into_iter()
+                .map(|i| {
+                    let mut vector = vec![0.0; n];
+                    for (j, q) in basis.iter().take(m).enumerate() {
+                        let weight = s[[j, i]];
+                        for (value, q_value) in vector.iter_mut().zip(q) {
+                            *value += weight * q_value;
+                        }
+                    }
+                    (d[i], vector)
+                })
+                .collect();
+        }
+        target = std::cmp::min(dimension, 2 * target);
+    }
+}
+
+/// Find ``k`` eigenvalues at one end of the spectrum, in ascending order,
+/// and optionally their eigenvectors, as columns, of a sparse symmetric
+/// matrix with the Lanczos method.
+///
+/// A single Krylov subspace only holds one eigenvector of each distinct
+/// eigenvalue, so repeated eigenvalues, which are common in the spectra of
+/// graphs, can be missed. The pairs found are therefore locked and the
+/// method is run again on their orthogonal complement until it no longer
+/// finds a more extreme eigenvalue than the ones locked.
+fn lanczos_eigen(
+    matrix: &SymmetricMatrix,
+    k: usize,
+    largest: bool,
+    vectors: bool,
+) -> (Vec<f64>, Option<Array2<f64>>) {
+    let n = matrix.diagonal.len();
+    let mut rng = Pcg64::seed_from_u64(0);
+    let more_extreme = |first: f64, second: f64| {
+        let tolerance = 1e-9 * (first.abs() + second.abs() + 1.0);
+        if largest {
+            first > second + tolerance
+        } else {
+            first < second - tolerance
+        }
+    };
+    let mut pairs: Vec<(f64, Vec<f64>)> = Vec::new();
+    loop {
+        let locked: Vec<Vec<f64>> = pairs.iter().map(|(_, vector)| vector.clone()).collect();
+        let found = lanczos(matrix, k, largest, &locked, &mut rng);
+        let improved = pairs.len() < k
+            || matches!(found.first(), Some((value, _)) if more_extreme(*value, pairs[k - 1].0));
+        if !improved {
+            break;
+        }
+        pairs.extend(found);
+        pairs.sort_by(|a, b| {
+            let order = a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal);
+            if largest {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        pairs.truncate(k);
+        if pairs.len() == n {
+            break;
+        }
+    }
+    if largest {
+        pairs.reverse();
+    }
+    let values = pairs.iter().map(|(value, _)| *value).collect();
+    let eigenvectors = if vectors {
+        let mut out = Array2::<f64>::zeros((n, k));
+        for (column, (_, vector)) in pairs.iter().enumerate() {
+            for (row, value) in vector.iter().enumerate() {
+                out[[row, column]] = *value;
+            }
+        }
+        Some(out)
+    } else {
+        None
+    };
+    (values, eigenvectors)
+}
+
+/// Compute the eigenpairs of ``matrix`` and convert them to the Python
+/// return value of the spectrum functions.
+fn spectrum(
+    py: Python,
+    matrix: SymmetricMatrix,
+    eigenvectors: bool,
+    k: Option<usize>,
+    which: &str,
+) -> PyResult<PyObject> {
+    let largest = match which {
+        "largest" => true,
+        "smallest" => false,
+        _ => {
+            return Err(PyValueError::new_err(
+                "which must be either 'largest' or 'smallest'",
+            ))
+        }
+    };
+    let (values, vectors) = match k {
+        None => {
+            let dense = matrix.dense();
+            py.allow_threads(|| dense_eigen(dense, eigenvectors))
+        }
+        Some(k) => {
+            let n = matrix.diagonal.len();
+            if k == 0 || k > n {
+                return Err(PyValueError::new_err(format!(
+                    "k must be between 1 and the number of nodes, {}",
+                    n
+                )));
+            }
+            py.allow_threads(|| lanczos_eigen(&matrix, k, largest, eigenvectors))
+        }
+    };
+    let values = Array1::from(values).into_pyarray(py).to_object(py);
+    Ok(match vectors {
+        Some(vectors) => (values, vectors.into_pyarray(py)).to_object(py),
+        None => values,
+    })
+}
+
+/// Compute the eigenvalues of the adjacency matrix of a graph
+///
+/// The adjacency matrix is the same as the one returned by
+/// :func:`~retworkx.graph_adjacency_matrix`, with the rows and columns in
+/// node index order and the weights of parallel edges summed. As it is
+/// symmetric all its eigenvalues are real.
+///
+/// By default all the eigenvalues are computed with a dense symmetric
+/// eigensolver, Householder tridiagonalization followed by the implicit QL
+/// method, which takes :math:`O(n^3)` time and :math:`O(n^2)` memory. If
+/// ``k`` is set only the ``k`` largest or smallest eigenvalues are computed
+/// with the Lanczos method, which works on the sparse matrix directly and
+/// is much faster for large graphs when ``k`` is small.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(6)
+///   print(retworkx.adjacency_spectrum(graph))
+///
+/// :param PyGraph graph: The graph to compute the spectrum of
+/// :param weight_fn: An optional callable object (function, lambda, etc)
+///     which will be passed the edge object and expected to return a
+///     ``float`` weight for the edge. If it's not specified
+///     ``default_weight`` is used for all edges.
+/// :param float default_weight: The weight to use for all edges if
+///     ``weight_fn`` isn't specified. By default this is ``1.0``.
+/// :param bool eigenvectors: If set to ``True`` the eigenvectors are
+///     returned along with the eigenvalues.
+/// :param int k: If set, only compute the ``k`` eigenvalues at the end of
+///     the spectrum selected by ``which`` with the Lanczos method.
+/// :param str which: Either ``"largest"`` or ``"smallest"``, which end of the
+///     spectrum to compute when ``k`` is set. By default this is
+///     ``"largest"``.
+///
+/// :returns: A numpy array of the eigenvalues in ascending order. If
+///     ``eigenvectors`` is ``True`` a tuple of the eigenvalues and a numpy
+///     array whose columns are the matching unit eigenvectors.
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``k`` is not between 1 and the number of nodes, or
+///     ``which`` is not a valid value
+#[pyfunction(default_weight = "1.0", eigenvectors = "false", which = "\"largest\"")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, eigenvectors=False, k=None, which=\"largest\")"
+)]
+pub fn adjacency_spectrum(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    eigenvectors: bool,
+    k: Option<usize>,
+    which: &str,
+) -> PyResult<PyObject> {
+    let matrix = SymmetricMatrix::adjacency(py, graph, &weight_fn, default_weight)?;
+    spectrum(py, matrix, eigenvectors, k, which)
+}
+
+/// Compute the eigenvalues of the Laplacian matrix of a graph
+///
+/// The Laplacian matrix is :math:`L = D - A` where :math:`A` is the
+/// adjacency matrix of the graph, as returned by
+/// :func:`~retworkx.graph_adjacency_matrix`, and :math:`D` is the diagonal
+/// matrix of the weighted degrees of the nodes. The normalized Laplacian
+/// matrix is :math:`D^{-1/2} L D^{-1/2}`, where the rows and columns of
+/// nodes without any edges are 0. Both are symmetric and positive
+/// semidefinite, so all their eigenvalues are real and nonnegative for
+/// nonnegative weights. The multiplicity of the eigenvalue 0 is the number
+/// of connected components of the graph, and the second smallest
+/// eigenvalue is its algebraic connectivity.
+///
+/// By default all the eigenvalues are computed with a dense symmetric
+/// eigensolver, Householder tridiagonalization followed by the implicit QL
+/// method, which takes :math:`O(n^3)` time and :math:`O(n^2)` memory. If
+/// ``k`` is set only the ``k`` largest or smallest eigenvalues are computed
+/// with the Lanczos method, which works on the sparse matrix directly and
+/// is much faster for large graphs when ``k`` is small.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(5)
+///   print(retworkx.laplacian_spectrum(graph))
+///   print(retworkx.laplacian_spectrum(graph, k=2, which="smallest"))
+///
+/// :param PyGraph graph: The graph to compute the spectrum of
+/// :param weight_fn: An optional callable object (function, lambda, etc)
+///     which will be passed the edge object and expected to return a
+///     ``float`` weight for the edge. If it's not specified
+///     ``default_weight`` is used for all edges.
+/// :param float default_weight: The weight to use for all edges if
+///     ``weight_fn`` isn't specified. By default this is ``1.0``.
+/// :param bool normalized: If set to ``True`` compute the spectrum of the
+///     normalized Laplacian matrix.
+/// :param bool eigenvectors: If set to ``True`` the eigenvectors are
+///     returned along with the eigenvalues.
+/// :param int k: If set, only compute the ``k`` eigenvalues at the end of
+///     the spectrum selected by ``which`` with the Lanczos method.
+/// :param str which: Either ``"largest"`` or ``"smallest"``, which end of the
+///     spectrum to compute when ``k`` is set. By default this is
+///     ``"largest"``.
+///
+/// :returns: A numpy array of the eigenvalues in ascending order. If
+///     ``eigenvectors`` is ``True`` a tuple of the eigenvalues and a numpy
+///     array whose columns are the matching unit eigenvectors.
+/// :rtype: numpy.ndarray
+///
+/// :raises ValueError: If ``k`` is not between 1 and the number of nodes, or
+///     ``which`` is not a valid value
+#[pyfunction(
+    default_weight = "1.0",
+    normalized = "false",
+    eigenvectors = "false",
+    which = "\"largest\""
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, normalized=False, eigenvectors=False, k=None, which=\"largest\")"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn laplacian_spectrum(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    eigenvectors: bool,
+    k: Option<usize>,
+    which: &str,
+) -> PyResult<PyObject> {
+    let matrix = SymmetricMatrix::adjacency(py, graph, &weight_fn, default_weight)?;
+    spectrum(
+        py,
+        matrix.into_laplacian(normalized),
+        eigenvectors,
+        k,
+        which,
+    )
+}
@@ -11,22 +11,22 @@
 // under the License.
 
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 
 use hashbrown::{HashMap, HashSet};
 use rayon::prelude::*;
 
-use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use petgraph::stable_graph::{EdgeIndex, EdgeReference, NodeIndex};
+use petgraph::stable_graph::EdgeReference;
 use petgraph::unionfind::UnionFind;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
 
 use crate::generators::pairwise;
 use crate::graph;
-use crate::is_valid_weight;
 use crate::shortest_path::all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths;
+use crate::{core_error, is_valid_weight, DisconnectedGraphError, EdgeIndex, GraphIx, NodeIndex};
 
 use retworkx_core::dictmap::*;
 use retworkx_core::shortest_path::dijkstra;
@@ -52,6 +52,7 @@ struct MetricClosureEdge {
 /// :rtype: PyGraph
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
+/// :raises DisconnectedGraphError: when the graph isn't connected
 #[pyfunction]
 #[pyo3(text_signature = "(graph, weight_fn, /)")]
 pub fn metric_closure(
@@ -83,21 +84,29 @@ fn _metric_closure_edges(
     }
     let mut out_vec = Vec::with_capacity(node_count * (node_count - 1) / 2);
     let mut distances = HashMap::with_capacity(graph.graph.node_count());
-    let paths =
-        all_pairs_dijkstra_shortest_paths(py, &graph.graph, weight_fn, Some(&mut distances))?.paths;
-    let mut nodes: HashSet<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
+    let paths = all_pairs_dijkstra_shortest_paths(
+        py,
+        &graph.graph,
+        weight_fn,
+        None,
+        None,
+        Some(&mut distances),
+    )?
+    .paths;
+    // A BTreeSet so the edges to the remaining nodes are added in index order
+    let mut nodes: BTreeSet<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
     let first_node = graph
         .graph
         .node_indices()
         .map(|x| x.index())
         .next()
         .unwrap();
-    let path_keys: HashSet<usize> = paths[&first_node].paths.keys().copied().collect();
+    let path_keys: BTreeSet<usize> = paths[&first_node].paths.keys().copied().collect();
     // first_node will always be missing from path_keys so if the difference
     // is > 1 with nodes that means there is another node in the graph that
     // first_node doesn't have a path to.
     if nodes.difference(&path_keys).count() > 1 {
-        return Err(PyValueError::new_err(
+        return Err(DisconnectedGraphError::new_err(
             "The input graph must be a connected graph. The metric closure is \
             not defined for a graph with unconnected nodes",
         ));
@@ -144,7 +153,7 @@ fn fast_metric_edges(
             .add_edge(dummy, NodeIndex::new(*node), py.None());
     }
 
-    let cost_fn = |edge: EdgeReference<'_, PyObject>| -> PyResult<f64> {
+    let cost_fn = |edge: EdgeReference<'_, PyObject, GraphIx>| -> PyResult<f64> {
         if edge.source() != dummy && edge.target() != dummy {
             let weight: f64 = weight_fn.call1(py, (edge.weight(),))?.extract(py)?;
             is_valid_weight(weight)
@@ -155,7 +164,7 @@ fn fast_metric_edges(
 
     let mut paths = DictMap::with_capacity(graph.graph.node_count());
     let mut distance: DictMap<NodeIndex, f64> =
-        dijkstra(&graph.graph, dummy, None, cost_fn, Some(&mut paths))?;
+        dijkstra(&graph.graph, dummy, None, cost_fn, Some(&mut paths)).map_err(core_error)?;
     paths.remove(&dummy);
     distance.remove(&dummy);
     graph.graph.remove_node(dummy);
@@ -251,6 +260,8 @@ fn fast_metric_edges(
 /// :rtype: PyGraph
 /// :raises ValueError: when an edge weight with NaN or negative value
 ///     is provided.
+/// :raises DisconnectedGraphError: when the terminal nodes aren't in the
+///     same connected component
 ///
 /// .. [1] Kou, Markowsky & Berman,
 ///    "A fast algorithm for Steiner trees"
@@ -284,7 +295,7 @@ pub fn steiner_tree(
     }
     // assert that the terminal nodes are connected.
     if !terminal_nodes.is_empty() && mst_edges.len() != terminal_nodes.len() - 1 {
-        return Err(PyValueError::new_err(
+        return Err(DisconnectedGraphError::new_err(
             "The terminal nodes in the input graph must belong to the same connected component. \
                   The steiner tree is not defined for a graph with unconnected terminal nodes",
         ));
@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use crate::{digraph, graph, NodeIndex, StablePyGraph};
+
+fn summary<Ty: EdgeType>(py: Python, graph: &StablePyGraph<Ty>) -> PyResult<PyObject> {
+    let node_count = graph.node_count();
+    let edge_count = graph.edge_count();
+
+    // A single pass over the edges for the degrees, and the in degrees used
+    // to check for cycles in a directed graph.
+    let mut degree: Vec<usize> = vec![0; graph.node_bound()];
+    let mut in_degree: Vec<usize> = vec![0; graph.node_bound()];
+    for edge in graph.edge_references() {
+        degree[edge.source().index()] += 1;
+        degree[edge.target().index()] += 1;
+        in_degree[edge.target().index()] += 1;
+    }
+    let degrees: Vec<usize> = graph
+        .node_indices()
+        .map(|node| degree[node.index()])
+        .collect();
+
+    // A single breadth first search ignoring edge directions both counts the
+    // connected components and 2-colors the nodes to check if the graph is
+    // bipartite.
+    let mut color: Vec<Option<bool>> = vec![None; graph.node_bound()];
+    let mut num_components: usize = 0;
+    let mut is_bipartite = true;
+    let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+    for start in graph.node_indices() {
+        if color[start.index()].is_some() {
+            continue;
+        }
+        num_components += 1;
+        color[start.index()] = Some(false);
+        queue.push_back(start);
+        while let Some(node) = queue.pop_front() {
+            let node_color = color[node.index()].unwrap();
+            for neighbor in graph.neighbors_undirected(node) {
+                match color[neighbor.index()] {
+                    Some(neighbor_color) => {
+                        if neighbor_color == node_color {
+                            is_bipartite = false;
+                        }
+                    }
+                    None => {
+                        color[neighbor.index()] = Some(!node_color);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    let is_dag = if Ty::is_directed() {
+        let mut stack: Vec<NodeIndex> = graph
+            .node_indices()
+            .filter(|node| in_degree[node.index()] == 0)
+            .collect();
+        let mut visited: usize = 0;
+        while let Some(node) = stack.pop() {
+            visited += 1;
+            for successor in graph.neighbors_directed(node, Outgoing) {
+                in_degree[successor.index()] -= 1;
+                if in_degree[successor.index()] == 0 {
+                    stack.push(successor);
+                }
+            }
+        }
+        visited == node_count
+    } else {
+        false
+    };
+
+    let density = if node_count > 1 {
+        let pairs = (node_count * (node_count - 1)) as f64;
+        if Ty::is_directed() {
+            edge_count as f64 / pairs
+        } else {
+            2.0 * edge_count as f64 / pairs
+        }
+    } else {
+        0.0
+    };
+    let mean_degree = if node_count > 0 {
+        degrees.iter().sum::<usize>() as f64 / node_count as f64
+    } else {
+        0.0
+    };
+
+    let out_dict = PyDict::new(py);
+    out_dict.set_item("num_nodes", node_count)?;
+    out_dict.set_item("num_edges", edge_count)?;
+    out_dict.set_item("density", density)?;
+    out_dict.set_item("min_degree", degrees.iter().min().copied().unwrap_or(0))?;
+    out_dict.set_item("max_degree", degrees.iter().max().copied().unwrap_or(0))?;
+    out_dict.set_item("mean_degree", mean_degree)?;
+    out_dict.set_item("num_components", num_components)?;
+    out_dict.set_item("is_directed", Ty::is_directed())?;
+    out_dict.set_item("is_dag", is_dag)?;
+    out_dict.set_item(
+        "is_tree",
+        num_components == 1 && edge_count == node_count - 1,
+    )?;
+    out_dict.set_item("is_bipartite", is_bipartite)?;
+    Ok(out_dict.into())
+}
+
+/// Compute a summary of the basic metrics of a graph
+///
+/// This computes the node and edge counts, the density, the minimum, maximum
+/// and mean degree, the number of (weakly) connected components and whether
+/// the graph is a DAG, a tree and bipartite. Instead of a separate pass over
+/// the graph for each metric, the degrees come from a single pass over the
+/// edges and the components and bipartiteness from a single breadth first
+/// search, so this is cheaper than calling :func:`~retworkx.is_connected`,
+/// :func:`~retworkx.is_directed_acyclic_graph` and so on separately.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   print(retworkx.graph_summary(graph))
+///
+/// :param graph: The graph to summarize, either a :class:`~retworkx.PyGraph`
+///     or :class:`~retworkx.PyDiGraph`
+///
+/// :returns: A dictionary with the keys:
+///
+///     * ``num_nodes``: The number of nodes
+///     * ``num_edges``: The number of edges
+///     * ``density``: The number of edges divided by the number of possible
+///       edges without self loops or parallel edges, ``0.0`` for graphs with
+///       fewer than 2 nodes
+///     * ``min_degree``, ``max_degree`` and ``mean_degree``: The minimum,
+///       maximum and mean degree of the nodes, the sum of the in and out
+///       degrees for a :class:`~retworkx.PyDiGraph`. Self loops add 2 to the
+///       degree of their node.
+///     * ``num_components``: The number of connected components, ignoring
+///       edge directions
+///     * ``is_directed``: Whether the graph is a :class:`~retworkx.PyDiGraph`
+///     * ``is_dag``: Whether the graph is a directed acyclic graph, always
+///       ``False`` for a :class:`~retworkx.PyGraph`
+///     * ``is_tree``: Whether the graph, ignoring edge directions, is a tree
+///     * ``is_bipartite``: Whether the graph, ignoring edge directions, is
+///       bipartite
+/// :rtype: dict
+///
+/// :raises TypeError: If ``graph`` isn't a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_summary(py: Python, graph: &PyAny) -> PyResult<PyObject> {
+    if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+        summary(py, &graph.graph)
+    } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+        summary(py, &graph.graph)
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "Invalid Input Type {} for graph",
+            graph.get_type()
+        )))
+    }
+}
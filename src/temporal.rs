@@ -0,0 +1,601 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::{HashMap, HashSet};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use rayon::prelude::*;
+
+use crate::iterators::{PathLengthMapping, PathMapping};
+use crate::{digraph, graph, InvalidNode, NodeIndex, StablePyGraph};
+use retworkx_core::dictmap::*;
+
+/// The edges of a graph as instantaneous contacts sorted by time, with
+/// undirected edges usable in both directions.
+struct Contacts {
+    node_bound: usize,
+    edges: Vec<(f64, usize, usize)>,
+}
+
+/// The earliest arrival time at every node reachable from a source and the
+/// node it was reached from.
+struct Arrivals {
+    time: Vec<Option<f64>>,
+    predecessor: Vec<Option<usize>>,
+}
+
+impl Contacts {
+    fn new<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        time_fn: &Option<PyObject>,
+    ) -> PyResult<Self> {
+        let mut edges: Vec<(f64, usize, usize)> = Vec::with_capacity(graph.edge_count());
+        for edge in graph.edge_references() {
+            let time: f64 = match time_fn {
+                Some(time_fn) => time_fn.call1(py, (edge.weight(),))?.extract(py)?,
+                None => edge.weight().extract(py)?,
+            };
+            if time.is_nan() {
+                return Err(PyValueError::new_err("Edge times can't be NaN"));
+            }
+            let (source, target) = (edge.source().index(), edge.target().index());
+            edges.push((time, source, target));
+            if !Ty::is_directed() && source != target {
+                edges.push((time, target, source));
+            }
+        }
+        edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        Ok(Contacts {
+            node_bound: graph.node_bound(),
+            edges,
+        })
+    }
+
+    /// Find the earliest arrival times from a source with a single pass over
+    /// the contacts in time order. The contacts at the same time as each
+    /// other are searched breadth first, since they can be chained unless
+    /// the paths are strict.
+    fn earliest_arrivals(&self, source: usize, start_time: f64, strict: bool) -> Arrivals {
+        let mut time: Vec<Option<f64>> = vec![None; self.node_bound];
+        let mut predecessor: Vec<Option<usize>> = vec![None; self.node_bound];
+        time[source] = Some(start_time);
+        let mut group_start = self
+            .edges
+            .iter()
+            .take_while(|edge| edge.0 < start_time)
+            .count();
+        while group_start < self.edges.len() {
+            let now = self.edges[group_start].0;
+            let mut group_end = group_start;
+            while group_end < self.edges.len() && self.edges[group_end].0 == now {
+                group_end += 1;
+            }
+            let group = &self.edges[group_start..group_end];
+            group_start = group_end;
+            if strict {
+                for (_, from, to) in group {
+                    let ready = match time[*from] {
+                        Some(arrival) => *from == source || arrival < now,
+                        None => false,
+                    };
+                    if ready && time[*to].is_none() {
+                        time[*to] = Some(now);
+                        predecessor[*to] = Some(*from);
+                    }
+                }
+                continue;
+            }
+            let mut adjacent: HashMap<usize, Vec<usize>> = HashMap::new();
+            let mut queue: Vec<usize> = Vec::new();
+            for (_, from, to) in group {
+                adjacent.entry(*from).or_insert_with(Vec::new).push(*to);
+                if time[*from].is_some() && time[*to].is_none() {
+                    queue.push(*from);
+                }
+            }
+            while let Some(from) = queue.pop() {
+                if let Some(targets) = adjacent.get(&from) {
+                    for to in targets {
+                        if time[*to].is_none() {
+                            time[*to] = Some(now);
+                            predecessor[*to] = Some(from);
+                            queue.push(*to);
+                        }
+                    }
+                }
+            }
+        }
+        Arrivals { time, predecessor }
+    }
+}
+
+fn check_source<Ty: EdgeType>(graph: &StablePyGraph<Ty>, source: usize) -> PyResult<()> {
+    if !graph.contains_node(NodeIndex::new(source)) {
+        return Err(InvalidNode::new_err(format!(
+            "Node index {} is not present in the graph",
+            source
+        )));
+    }
+    Ok(())
+}
+
+fn earliest_arrivals<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<Arrivals> {
+    check_source(graph, source)?;
+    let contacts = Contacts::new(py, graph, &time_fn)?;
+    Ok(contacts.earliest_arrivals(source, start_time.unwrap_or(f64::NEG_INFINITY), strict))
+}
+
+fn temporal_descendants<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<HashSet<usize>> {
+    let arrivals = earliest_arrivals(py, graph, source, time_fn, start_time, strict)?;
+    Ok(arrivals
+        .time
+        .iter()
+        .enumerate()
+        .filter(|(node, time)| *node != source && time.is_some())
+        .map(|(node, _)| node)
+        .collect())
+}
+
+fn earliest_arrival_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathMapping> {
+    let arrivals = earliest_arrivals(py, graph, source, time_fn, start_time, strict)?;
+    let mut paths: DictMap<usize, Vec<usize>> = DictMap::new();
+    for node in graph.node_indices() {
+        let node = node.index();
+        if node == source || arrivals.time[node].is_none() {
+            continue;
+        }
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = arrivals.predecessor[current] {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        paths.insert(node, path);
+    }
+    Ok(PathMapping { paths })
+}
+
+fn earliest_arrival_times<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathLengthMapping> {
+    let arrivals = earliest_arrivals(py, graph, source, time_fn, start_time, strict)?;
+    let mut path_lengths: DictMap<usize, f64> = DictMap::new();
+    for node in graph.node_indices() {
+        let node = node.index();
+        if node == source {
+            continue;
+        }
+        if let Some(time) = arrivals.time[node] {
+            path_lengths.insert(node, time);
+        }
+    }
+    Ok(PathLengthMapping { path_lengths })
+}
+
+fn temporal_connected_components<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    time_fn: Option<PyObject>,
+    strict: bool,
+) -> PyResult<Vec<HashSet<usize>>> {
+    let contacts = Contacts::new(py, graph, &time_fn)?;
+    let nodes: Vec<usize> = graph.node_indices().map(|n| n.index()).collect();
+    let reachable: Vec<HashSet<usize>> = py.allow_threads(|| {
+        nodes
+            .par_iter()
+            .map(|source| {
+                let arrivals = contacts.earliest_arrivals(*source, f64::NEG_INFINITY, strict);
+                arrivals
+                    .time
+                    .iter()
+                    .enumerate()
+                    .filter(|(node, time)| node != source && time.is_some())
+                    .map(|(node, _)| node)
+                    .collect()
+            })
+            .collect()
+    });
+    let mut position: Vec<usize> = vec![0; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        position[*node] = index;
+    }
+    let mut components: UnionFind<usize> = UnionFind::new(nodes.len());
+    for (index, source) in nodes.iter().enumerate() {
+        for target in &reachable[index] {
+            if reachable[position[*target]].contains(source) {
+                components.union(index, position[*target]);
+            }
+        }
+    }
+    let mut out: Vec<HashSet<usize>> = Vec::new();
+    let mut component_of: HashMap<usize, usize> = HashMap::new();
+    for (index, node) in nodes.iter().enumerate() {
+        let root = components.find_mut(index);
+        let component = *component_of.entry(root).or_insert_with(|| {
+            out.push(HashSet::new());
+            out.len() - 1
+        });
+        out[component].insert(*node);
+    }
+    Ok(out)
+}
+
+/// Find the nodes reachable from a node by a time-respecting path in a
+/// :class:`~retworkx.PyGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can be traversed in either direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``.
+///
+/// :param PyGraph graph: The graph to search
+/// :param int source: The node index to find the descendants of
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A set of the indices of the nodes reachable from ``source`` by a
+///     time-respecting path, not including ``source``
+/// :rtype: set
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn graph_temporal_descendants(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<HashSet<usize>> {
+    temporal_descendants(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the nodes reachable from a node by a time-respecting path in a
+/// :class:`~retworkx.PyDiGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can only be traversed in its direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from([(0, 1, 1), (1, 2, 3), (2, 3, 2)])
+///   print(retworkx.digraph_temporal_descendants(graph, 0))
+///
+/// :param PyDiGraph graph: The graph to search
+/// :param int source: The node index to find the descendants of
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A set of the indices of the nodes reachable from ``source`` by a
+///     time-respecting path, not including ``source``
+/// :rtype: set
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn digraph_temporal_descendants(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<HashSet<usize>> {
+    temporal_descendants(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the earliest arrival paths from a node in a :class:`~retworkx.PyGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can be traversed in either direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``. The path returned for every node is a time-respecting path
+/// reaching it at the earliest possible time, which is found with a single
+/// pass over the edges in time order in :math:`O(|E| \log |E|)` time.
+///
+/// :param PyGraph graph: The graph to search
+/// :param int source: The node index to find the paths from
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A mapping of the index of every node reachable from ``source``
+///     by a time-respecting path to a list of the node indices of an earliest
+///     arrival path from ``source`` to it
+/// :rtype: PathMapping
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn graph_earliest_arrival_paths(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathMapping> {
+    earliest_arrival_paths(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the earliest arrival paths from a node in a
+/// :class:`~retworkx.PyDiGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can only be traversed in its direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``. The path returned for every node is a time-respecting path
+/// reaching it at the earliest possible time, which is found with a single
+/// pass over the edges in time order in :math:`O(|E| \log |E|)` time.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from([(0, 1, 1), (1, 3, 5), (0, 2, 2), (2, 3, 3)])
+///   print(retworkx.digraph_earliest_arrival_paths(graph, 0))
+///
+/// :param PyDiGraph graph: The graph to search
+/// :param int source: The node index to find the paths from
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A mapping of the index of every node reachable from ``source``
+///     by a time-respecting path to a list of the node indices of an earliest
+///     arrival path from ``source`` to it
+/// :rtype: PathMapping
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn digraph_earliest_arrival_paths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathMapping> {
+    earliest_arrival_paths(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the earliest arrival times from a node in a :class:`~retworkx.PyGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can be traversed in either direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``. The earliest arrival time of a node is the smallest time
+/// of the last edge of a time-respecting path to it.
+///
+/// :param PyGraph graph: The graph to search
+/// :param int source: The node index to find the arrival times from
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A mapping of the index of every node reachable from ``source``
+///     by a time-respecting path to its earliest arrival time
+/// :rtype: PathLengthMapping
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn graph_earliest_arrival_times(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathLengthMapping> {
+    earliest_arrival_times(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the earliest arrival times from a node in a
+/// :class:`~retworkx.PyDiGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can only be traversed in its direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``, and whose first edge isn't before
+/// ``start_time``. The earliest arrival time of a node is the smallest time
+/// of the last edge of a time-respecting path to it.
+///
+/// :param PyDiGraph graph: The graph to search
+/// :param int source: The node index to find the arrival times from
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param float start_time: The earliest time a path can start at. By
+///     default paths can start at any time.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A mapping of the index of every node reachable from ``source``
+///     by a time-respecting path to its earliest arrival time
+/// :rtype: PathLengthMapping
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, source, /, time_fn=None, start_time=None, strict=False)")]
+pub fn digraph_earliest_arrival_times(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    start_time: Option<f64>,
+    strict: bool,
+) -> PyResult<PathLengthMapping> {
+    earliest_arrival_times(py, &graph.graph, source, time_fn, start_time, strict)
+}
+
+/// Find the temporal connected components of a :class:`~retworkx.PyGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can be traversed in either direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``. Reachability by time-respecting paths is neither
+/// symmetric nor transitive, and finding the largest sets of nodes which can
+/// all reach each other is NP-hard, so two nodes are in the same temporal
+/// connected component if they are linked by a chain of pairs of nodes which
+/// can reach each other by time-respecting paths.
+///
+/// The nodes reachable from every node are found in parallel, in
+/// :math:`O(|V||E|)` time overall.
+///
+/// :param PyGraph graph: The graph to find the components of
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A list of sets where each set is a temporal connected component
+///     of the graph
+/// :rtype: list
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, /, time_fn=None, strict=False)")]
+pub fn graph_temporal_connected_components(
+    py: Python,
+    graph: &graph::PyGraph,
+    time_fn: Option<PyObject>,
+    strict: bool,
+) -> PyResult<Vec<HashSet<usize>>> {
+    temporal_connected_components(py, &graph.graph, time_fn, strict)
+}
+
+/// Find the temporal connected components of a :class:`~retworkx.PyDiGraph`
+///
+/// Every edge of the graph is an instantaneous contact at a given time, and
+/// can only be traversed in its direction at that time. A time-respecting
+/// path is a path whose edge times never decrease, or always increase if
+/// ``strict`` is ``True``. Reachability by time-respecting paths isn't
+/// transitive, and finding the largest sets of nodes which can all reach each
+/// other is NP-hard, so two nodes are in the same temporal connected
+/// component if they are linked by a chain of pairs of nodes which can reach
+/// each other by time-respecting paths.
+///
+/// The nodes reachable from every node are found in parallel, in
+/// :math:`O(|V||E|)` time overall.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from([(0, 1, 1), (1, 0, 2), (1, 2, 3), (2, 3, 4), (3, 2, 5)])
+///   print(retworkx.digraph_temporal_connected_components(graph))
+///
+/// :param PyDiGraph graph: The graph to find the components of
+/// :param time_fn: An optional callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return the ``float``
+///     time of the edge. If it's not specified the edge objects are used as
+///     the times.
+/// :param bool strict: If set to ``True`` the times of consecutive edges on
+///     a path must be strictly increasing.
+///
+/// :returns: A list of sets where each set is a temporal connected component
+///     of the graph
+/// :rtype: list
+/// :raises ValueError: If an edge time is NaN
+#[pyfunction(strict = "false")]
+#[pyo3(text_signature = "(graph, /, time_fn=None, strict=False)")]
+pub fn digraph_temporal_connected_components(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    time_fn: Option<PyObject>,
+    strict: bool,
+) -> PyResult<Vec<HashSet<usize>>> {
+    temporal_connected_components(py, &graph.graph, time_fn, strict)
+}
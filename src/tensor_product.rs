@@ -10,10 +10,13 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::clusters::Clusters;
 use crate::iterators::ProductNodeMap;
+use crate::key_index::KeyIndexes;
+use crate::transaction::MutationLog;
 use crate::{digraph, graph, StablePyGraph};
 
-use hashbrown::HashMap;
+use retworkx_core::dictmap::*;
 
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
 use petgraph::{algo, EdgeType};
@@ -35,7 +38,7 @@ fn tensor_product<Ty: EdgeType>(
     }
 
     let mut final_graph = StablePyGraph::<Ty>::with_capacity(num_nodes, num_edges);
-    let mut hash_nodes = HashMap::with_capacity(num_nodes);
+    let mut hash_nodes = DictMap::with_capacity(num_nodes);
 
     for x in first.node_indices() {
         for y in second.node_indices() {
@@ -130,9 +133,12 @@ pub fn graph_tensor_product(
 
     (
         graph::PyGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             multigraph: true,
             node_removed: false,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         },
         out_node_map,
     )
@@ -178,11 +184,14 @@ pub fn digraph_tensor_product(
 
     (
         digraph::PyDiGraph {
-            graph: out_graph,
+            graph: out_graph.into(),
             cycle_state: algo::DfsSpace::default(),
             check_cycle: false,
             node_removed: false,
             multigraph: true,
+            log: MutationLog::default(),
+            clusters: Clusters::default(),
+            indexes: KeyIndexes::default(),
         },
         out_node_map,
     )
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::EdgeList;
+use crate::random_seed::resolve_seed;
+use crate::{core_error, graph, InvalidNode, NodeIndex};
+
+use hashbrown::HashMap;
+
+use pyo3::prelude::*;
+
+use retworkx_core::token_swapper;
+
+/// Find a sequence of swaps along the edges of a graph which moves each
+/// token to its destination.
+///
+/// Each node in the graph can hold at most one token and a swap exchanges the
+/// tokens (if any) on the two endpoints of an edge. This is an approximate
+/// solver which will find a sequence of swaps that is at most 4 times longer
+/// than the optimal solution. The algorithm used is based on:
+///
+/// Miltzow, Tillmann, et al. Approximation and Hardness of Token Swapping.
+/// 24th Annual European Symposium on Algorithms (ESA 2016), 66:1-66:15.
+/// https://arxiv.org/abs/1602.05150
+///
+/// The algorithm is randomized, it is run ``trials`` times and the shortest
+/// sequence of swaps found is returned. This function is multithreaded and
+/// will run the trials in parallel if the number of nodes in the graph is
+/// above the value of ``parallel_threshold`` (it defaults to 50). If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS``
+/// can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The input graph to find swaps on
+/// :param dict mapping: A mapping of node indices to node indices where the
+///     key is a node with a token and the value is the destination node for
+///     that token. Any node not included as a key doesn't have a token.
+/// :param int trials: The number of randomized trials to run, by default
+///     this is 4
+/// :param int seed: An optional seed to use for the random number generator
+/// :param int parallel_threshold: The number of nodes to run the trials in
+///     parallel at. If the number of nodes in ``graph`` is less than this
+///     value the trials will run in a single thread. The default value is 50.
+///
+/// :returns: The sequence of swaps to perform, in order, as a list of edges
+/// :rtype: EdgeList
+/// :raises InvalidNode: If a node index in ``mapping`` is not present in
+///     ``graph``
+/// :raises InvalidMapping: If a token has no path to its destination or
+///     more than one token has the same destination
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(4)
+///   mapping = {0: 3, 1: 2, 2: 1, 3: 0}
+///   print(retworkx.graph_token_swapper(graph, mapping, seed=42))
+#[pyfunction(parallel_threshold = "50")]
+#[pyo3(text_signature = "(graph, mapping, /, trials=None, seed=None, parallel_threshold=50)")]
+pub fn graph_token_swapper(
+    graph: &graph::PyGraph,
+    mapping: HashMap<usize, usize>,
+    trials: Option<usize>,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> PyResult<EdgeList> {
+    let mut node_mapping: HashMap<NodeIndex, NodeIndex> = HashMap::with_capacity(mapping.len());
+    for (node, dest) in mapping {
+        let node = NodeIndex::new(node);
+        let dest = NodeIndex::new(dest);
+        if !graph.graph.contains_node(node) || !graph.graph.contains_node(dest) {
+            return Err(InvalidNode::new_err(
+                "A node index in the mapping is not present in the graph",
+            ));
+        }
+        node_mapping.insert(node, dest);
+    }
+    let swaps = token_swapper::token_swapper(
+        &graph.graph,
+        node_mapping,
+        trials,
+        resolve_seed(seed),
+        Some(parallel_threshold),
+    )
+    .map_err(core_error)?;
+    Ok(EdgeList {
+        edges: swaps
+            .into_iter()
+            .map(|(node_a, node_b)| (node_a.index(), node_b.index()))
+            .collect(),
+    })
+}
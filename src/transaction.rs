@@ -0,0 +1,520 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::mem;
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::gc::PyVisit;
+use pyo3::prelude::*;
+use pyo3::PyTraverseError;
+use pyo3::Python;
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, NodeIndexable};
+use petgraph::EdgeType;
+
+use crate::clusters::Clusters;
+use crate::key_index::ChangeTracker;
+use crate::{digraph, graph, EdgeIndex, NodeIndex, StablePyGraph};
+
+/// A change made to a graph inside a transaction, along with what's needed
+/// to undo it.
+enum Mutation<Ty: EdgeType> {
+    AddNode(NodeIndex),
+    RemoveNode(NodeIndex, PyObject),
+    AddEdge(EdgeIndex),
+    RemoveEdge(EdgeIndex, NodeIndex, NodeIndex, PyObject),
+    SetNodeWeight(NodeIndex, PyObject),
+    SetEdgeWeight(EdgeIndex, PyObject),
+    SetCluster(NodeIndex, Option<String>),
+    Replace(StablePyGraph<Ty>, Clusters),
+}
+
+/// The log of the changes made to a graph by its open transactions.
+///
+/// Every mutation of a graph goes through the log, which only records it
+/// while a transaction is open so there's no overhead otherwise. Each open
+/// transaction has a savepoint, the length of the log when it was opened, and
+/// rolling it back undoes the recorded changes after the savepoint in reverse
+/// order. Removing a node is recorded as the removal of each of its edges
+/// followed by the removal of the node (and its cluster assignment), so only
+/// the primitive changes need to be undone.
+///
+/// The log also tracks which nodes and edges changed for the key indexes of
+/// the graph, whether or not a transaction is open.
+pub struct MutationLog<Ty: EdgeType> {
+    mutations: Vec<Mutation<Ty>>,
+    savepoints: Vec<usize>,
+    pub node_changes: ChangeTracker,
+    pub edge_changes: ChangeTracker,
+}
+
+impl<Ty: EdgeType> Default for MutationLog<Ty> {
+    fn default() -> Self {
+        MutationLog {
+            mutations: Vec::new(),
+            savepoints: Vec::new(),
+            node_changes: ChangeTracker::default(),
+            edge_changes: ChangeTracker::default(),
+        }
+    }
+}
+
+// A copy of a graph doesn't inherit the open transactions of the original,
+// or its key indexes.
+impl<Ty: EdgeType> Clone for MutationLog<Ty> {
+    fn clone(&self) -> Self {
+        MutationLog::default()
+    }
+}
+
+impl<Ty: EdgeType> MutationLog<Ty> {
+    /// The number of open transactions.
+    pub fn depth(&self) -> usize {
+        self.savepoints.len()
+    }
+
+    fn recording(&self) -> bool {
+        !self.savepoints.is_empty()
+    }
+
+    pub fn begin(&mut self) {
+        self.savepoints.push(self.mutations.len());
+    }
+
+    /// Close the innermost transaction keeping its changes. They stay in the
+    /// log until the outermost transaction is closed, so they can still be
+    /// rolled back by an enclosing transaction.
+    pub fn commit(&mut self) {
+        self.savepoints.pop();
+        if self.savepoints.is_empty() {
+            self.mutations.clear();
+        }
+    }
+
+    /// Undo the changes made since the innermost transaction was opened. The
+    /// transaction stays open.
+    pub fn rollback(&mut self, py: Python, graph: &mut StablePyGraph<Ty>, clusters: &mut Clusters) {
+        let savepoint = match self.savepoints.last() {
+            Some(savepoint) => *savepoint,
+            None => return,
+        };
+        while self.mutations.len() > savepoint {
+            match self.mutations.pop().unwrap() {
+                Mutation::AddNode(node) => {
+                    graph.remove_node(node);
+                    self.touch_node(graph, node);
+                }
+                Mutation::RemoveNode(node, weight) => {
+                    restore_node(py, graph, node, weight);
+                    self.touch_node(graph, node);
+                }
+                Mutation::AddEdge(edge) => {
+                    graph.remove_edge(edge);
+                    self.touch_edge(graph, edge);
+                }
+                Mutation::RemoveEdge(edge, source, target, weight) => {
+                    restore_edge(py, graph, edge, source, target, weight);
+                    self.touch_edge(graph, edge);
+                }
+                Mutation::SetNodeWeight(node, weight) => {
+                    graph[node] = weight;
+                    self.touch_node(graph, node);
+                }
+                Mutation::SetEdgeWeight(edge, weight) => {
+                    graph[edge] = weight;
+                    self.touch_edge(graph, edge);
+                }
+                Mutation::SetCluster(node, name) => {
+                    match name {
+                        Some(name) => clusters.insert(node.index(), name),
+                        None => clusters.swap_remove(&node.index()),
+                    };
+                }
+                Mutation::Replace(old_graph, old_clusters) => {
+                    *graph = old_graph;
+                    *clusters = old_clusters;
+                    self.node_changes.touch_all();
+                    self.edge_changes.touch_all();
+                }
+            }
+        }
+    }
+
+    fn touch_node(&mut self, graph: &StablePyGraph<Ty>, node: NodeIndex) {
+        self.node_changes.touch(node.index(), graph.node_bound());
+    }
+
+    fn touch_edge(&mut self, graph: &StablePyGraph<Ty>, edge: EdgeIndex) {
+        self.edge_changes.touch(edge.index(), graph.edge_bound());
+    }
+
+    pub fn add_node(&mut self, graph: &mut StablePyGraph<Ty>, weight: PyObject) -> NodeIndex {
+        let node = graph.add_node(weight);
+        self.touch_node(graph, node);
+        if self.recording() {
+            self.mutations.push(Mutation::AddNode(node));
+        }
+        node
+    }
+
+    pub fn add_edge(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        source: NodeIndex,
+        target: NodeIndex,
+        weight: PyObject,
+    ) -> EdgeIndex {
+        let edge = graph.add_edge(source, target, weight);
+        self.touch_edge(graph, edge);
+        if self.recording() {
+            self.mutations.push(Mutation::AddEdge(edge));
+        }
+        edge
+    }
+
+    pub fn remove_edge(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        edge: EdgeIndex,
+    ) -> Option<PyObject> {
+        if !self.recording() {
+            let weight = graph.remove_edge(edge)?;
+            self.touch_edge(graph, edge);
+            return Some(weight);
+        }
+        let (source, target) = graph.edge_endpoints(edge)?;
+        let weight = graph.remove_edge(edge)?;
+        self.touch_edge(graph, edge);
+        self.mutations
+            .push(Mutation::RemoveEdge(edge, source, target, weight.clone()));
+        Some(weight)
+    }
+
+    pub fn remove_node(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        clusters: &mut Clusters,
+        node: NodeIndex,
+    ) -> Option<PyObject> {
+        self.set_cluster(clusters, node, None);
+        if !self.recording() && !self.edge_changes.enabled() {
+            let weight = graph.remove_node(node)?;
+            self.touch_node(graph, node);
+            return Some(weight);
+        }
+        let mut edges: Vec<EdgeIndex> = graph
+            .edges_directed(node, Direction::Outgoing)
+            .chain(graph.edges_directed(node, Direction::Incoming))
+            .map(|edge| edge.id())
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        for edge in edges {
+            self.remove_edge(graph, edge);
+        }
+        let weight = graph.remove_node(node)?;
+        self.touch_node(graph, node);
+        if !self.recording() {
+            return Some(weight);
+        }
+        self.mutations
+            .push(Mutation::RemoveNode(node, weight.clone()));
+        Some(weight)
+    }
+
+    /// Replace the weight/data payload of a node, returning ``None`` if the
+    /// node isn't in the graph.
+    pub fn set_node_weight(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        node: NodeIndex,
+        weight: PyObject,
+    ) -> Option<()> {
+        let old_weight = mem::replace(graph.node_weight_mut(node)?, weight);
+        self.touch_node(graph, node);
+        if self.recording() {
+            self.mutations
+                .push(Mutation::SetNodeWeight(node, old_weight));
+        }
+        Some(())
+    }
+
+    /// Replace the weight/data payload of an edge, returning ``None`` if the
+    /// edge isn't in the graph.
+    pub fn set_edge_weight(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        edge: EdgeIndex,
+        weight: PyObject,
+    ) -> Option<()> {
+        let old_weight = mem::replace(graph.edge_weight_mut(edge)?, weight);
+        self.touch_edge(graph, edge);
+        if self.recording() {
+            self.mutations
+                .push(Mutation::SetEdgeWeight(edge, old_weight));
+        }
+        Some(())
+    }
+
+    /// Assign a node to a cluster, or remove it from its cluster if ``name``
+    /// is ``None``, returning the name of its previous cluster.
+    pub fn set_cluster(
+        &mut self,
+        clusters: &mut Clusters,
+        node: NodeIndex,
+        name: Option<String>,
+    ) -> Option<String> {
+        let assign = name.is_some();
+        let old_name = match name {
+            Some(name) => clusters.insert(node.index(), name),
+            None => clusters.swap_remove(&node.index()),
+        };
+        if self.recording() && (assign || old_name.is_some()) {
+            self.mutations
+                .push(Mutation::SetCluster(node, old_name.clone()));
+        }
+        old_name
+    }
+
+    /// Replace the whole graph and its cluster assignments, for changes which
+    /// renumber the graph.
+    pub fn replace(
+        &mut self,
+        graph: &mut StablePyGraph<Ty>,
+        clusters: &mut Clusters,
+        new_graph: StablePyGraph<Ty>,
+        new_clusters: Clusters,
+    ) {
+        let old_graph = mem::replace(graph, new_graph);
+        let old_clusters = mem::replace(clusters, new_clusters);
+        self.node_changes.touch_all();
+        self.edge_changes.touch_all();
+        if self.recording() {
+            self.mutations
+                .push(Mutation::Replace(old_graph, old_clusters));
+        }
+    }
+
+    pub fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        for mutation in &self.mutations {
+            match mutation {
+                Mutation::RemoveNode(_, weight)
+                | Mutation::RemoveEdge(_, _, _, weight)
+                | Mutation::SetNodeWeight(_, weight)
+                | Mutation::SetEdgeWeight(_, weight) => visit.call(weight)?,
+                Mutation::Replace(old_graph, _) => {
+                    for weight in old_graph.node_weights() {
+                        visit.call(weight)?;
+                    }
+                    for weight in old_graph.edge_weights() {
+                        visit.call(weight)?;
+                    }
+                }
+                Mutation::AddNode(_) | Mutation::AddEdge(_) | Mutation::SetCluster(_, _) => (),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.mutations.clear();
+        self.savepoints.clear();
+    }
+}
+
+// A removed node or edge is normally restored at its old index by adding it
+// back, since the free lists of the graph are LIFO and the changes are undone
+// in reverse. Placeholders are added until the old index is reached, and then
+// removed in reverse, in case the free list was reordered in between.
+fn restore_node<Ty: EdgeType>(
+    py: Python,
+    graph: &mut StablePyGraph<Ty>,
+    node: NodeIndex,
+    weight: PyObject,
+) {
+    let mut placeholders: Vec<NodeIndex> = Vec::new();
+    loop {
+        let new_node = graph.add_node(py.None());
+        if new_node == node {
+            graph[node] = weight;
+            break;
+        }
+        placeholders.push(new_node);
+    }
+    for placeholder in placeholders.into_iter().rev() {
+        graph.remove_node(placeholder);
+    }
+}
+
+fn restore_edge<Ty: EdgeType>(
+    py: Python,
+    graph: &mut StablePyGraph<Ty>,
+    edge: EdgeIndex,
+    source: NodeIndex,
+    target: NodeIndex,
+    weight: PyObject,
+) {
+    let mut placeholders: Vec<EdgeIndex> = Vec::new();
+    loop {
+        let new_edge = graph.add_edge(source, target, py.None());
+        if new_edge == edge {
+            graph[edge] = weight;
+            break;
+        }
+        placeholders.push(new_edge);
+    }
+    for placeholder in placeholders.into_iter().rev() {
+        graph.remove_edge(placeholder);
+    }
+}
+
+enum TransactionGraph {
+    Graph(Py<graph::PyGraph>),
+    DiGraph(Py<digraph::PyDiGraph>),
+}
+
+enum TransactionState {
+    New,
+    Open(usize),
+    Closed,
+}
+
+/// A transaction on a :class:`~retworkx.PyGraph` or
+/// :class:`~retworkx.PyDiGraph`
+///
+/// A transaction is a context manager created by
+/// :meth:`PyGraph.transaction() <retworkx.PyGraph.transaction>` or
+/// :meth:`PyDiGraph.transaction() <retworkx.PyDiGraph.transaction>`. While
+/// it's open every change made to the graph through its methods (adding,
+/// removing and updating nodes and edges, and assigning nodes to clusters) is
+/// logged, so it can be undone without copying the graph. If the ``with`` block exits with an exception
+/// the changes are rolled back, otherwise they are kept.
+/// :meth:`~retworkx.GraphTransaction.rollback` undoes the changes made so far
+/// and leaves the transaction open, which makes it cheap to try out several
+/// modifications of a large graph in turn:
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(5)
+///   with graph.transaction() as transaction:
+///       for node in graph.node_indices():
+///           graph.remove_node(node)
+///           print(node, retworkx.number_connected_components(graph))
+///           transaction.rollback()
+///   print(graph.node_indices())
+///
+/// Undoing the changes restores the node and edge indices along with the
+/// weight/data payloads, but mutating a payload object in place isn't
+/// tracked. Transactions can be nested, rolling back an inner transaction
+/// only undoes the changes made since it was opened, and the changes kept by
+/// an inner transaction are still undone if an enclosing transaction is
+/// rolled back.
+#[pyclass(module = "retworkx")]
+pub struct GraphTransaction {
+    graph: TransactionGraph,
+    state: TransactionState,
+}
+
+impl GraphTransaction {
+    pub fn from_graph(graph: Py<graph::PyGraph>) -> Self {
+        GraphTransaction {
+            graph: TransactionGraph::Graph(graph),
+            state: TransactionState::New,
+        }
+    }
+
+    pub fn from_digraph(graph: Py<digraph::PyDiGraph>) -> Self {
+        GraphTransaction {
+            graph: TransactionGraph::DiGraph(graph),
+            state: TransactionState::New,
+        }
+    }
+
+    fn depth(&self, py: Python) -> PyResult<usize> {
+        Ok(match &self.graph {
+            TransactionGraph::Graph(graph) => graph.try_borrow(py)?.log.depth(),
+            TransactionGraph::DiGraph(graph) => graph.try_borrow(py)?.log.depth(),
+        })
+    }
+
+    /// Check the transaction is open and the innermost open transaction on the
+    /// graph.
+    fn check_innermost(&self, py: Python) -> PyResult<()> {
+        match self.state {
+            TransactionState::Open(depth) => {
+                if depth != self.depth(py)? {
+                    return Err(PyRuntimeError::new_err(
+                        "Only the innermost open transaction can be closed or rolled back",
+                    ));
+                }
+                Ok(())
+            }
+            _ => Err(PyRuntimeError::new_err("Transaction is not open")),
+        }
+    }
+}
+
+#[pymethods]
+impl GraphTransaction {
+    fn __enter__<'p>(mut slf: PyRefMut<'p, Self>, py: Python<'p>) -> PyResult<PyRefMut<'p, Self>> {
+        if !matches!(slf.state, TransactionState::New) {
+            return Err(PyRuntimeError::new_err(
+                "Transaction has already been opened",
+            ));
+        }
+        match &slf.graph {
+            TransactionGraph::Graph(graph) => graph.try_borrow_mut(py)?.log.begin(),
+            TransactionGraph::DiGraph(graph) => graph.try_borrow_mut(py)?.log.begin(),
+        }
+        slf.state = TransactionState::Open(slf.depth(py)?);
+        Ok(slf)
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python,
+        exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        self.check_innermost(py)?;
+        if exc_type.is_some() {
+            self.rollback(py)?;
+        }
+        match &self.graph {
+            TransactionGraph::Graph(graph) => graph.try_borrow_mut(py)?.log.commit(),
+            TransactionGraph::DiGraph(graph) => graph.try_borrow_mut(py)?.log.commit(),
+        }
+        self.state = TransactionState::Closed;
+        Ok(false)
+    }
+
+    /// Undo all the changes made to the graph since the transaction was opened
+    ///
+    /// The transaction stays open, so the graph can be modified and rolled
+    /// back again.
+    ///
+    /// :raises RuntimeError: If the transaction isn't open or there's a nested
+    ///     transaction open inside of it
+    #[pyo3(text_signature = "(self)")]
+    pub fn rollback(&mut self, py: Python) -> PyResult<()> {
+        self.check_innermost(py)?;
+        match &self.graph {
+            TransactionGraph::Graph(graph) => graph.try_borrow_mut(py)?.rollback_transaction(py),
+            TransactionGraph::DiGraph(graph) => graph.try_borrow_mut(py)?.rollback_transaction(py),
+        }
+        Ok(())
+    }
+}
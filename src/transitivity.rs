@@ -10,36 +10,216 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use std::cmp::Ordering;
+
 use super::{digraph, graph};
+use crate::{DictMap, NodeIndex};
 use hashbrown::HashSet;
 
 use pyo3::prelude::*;
 
-use petgraph::graph::NodeIndex;
+use petgraph::visit::NodeIndexable;
 use rayon::prelude::*;
 
-fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
-    let mut triangles: usize = 0;
+/// The neighbors of every node of ``graph`` by compact node index, the
+/// position of the node in ``nodes``. The neighbor lists are sorted and
+/// don't contain self loops or repeats from parallel edges.
+pub(crate) fn sorted_adjacency(graph: &graph::PyGraph) -> (Vec<NodeIndex>, Vec<Vec<usize>>) {
+    let nodes: Vec<NodeIndex> = graph.graph.node_indices().collect();
+    let mut compact = vec![0; graph.graph.node_bound()];
+    for (position, node) in nodes.iter().enumerate() {
+        compact[node.index()] = position;
+    }
+    let adjacency = nodes
+        .par_iter()
+        .map(|node| {
+            let mut neighbors: Vec<usize> = graph
+                .graph
+                .neighbors(*node)
+                .filter(|neighbor| neighbor != node)
+                .map(|neighbor| compact[neighbor.index()])
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+    (nodes, adjacency)
+}
 
-    let index = NodeIndex::new(node);
-    let mut neighbors: HashSet<NodeIndex> = graph.graph.neighbors(index).collect();
-    neighbors.remove(&index);
+/// Count the common elements of two sorted slices.
+fn common_count(first: &[usize], second: &[usize]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < first.len() && j < second.len() {
+        match first[i].cmp(&second[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
 
-    for nodev in &neighbors {
-        triangles += graph
-            .graph
-            .neighbors(*nodev)
-            .filter(|&x| (x != *nodev) && neighbors.contains(&x))
-            .count();
+/// The number of triangles containing each node by compact node index.
+pub(crate) fn node_triangles(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    adjacency
+        .par_iter()
+        .map(|neighbors| {
+            neighbors
+                .iter()
+                .map(|neighbor| common_count(neighbors, &adjacency[*neighbor]))
+                .sum::<usize>()
+                / 2
+        })
+        .collect()
+}
+
+/// The local clustering coefficient of a node with ``triangles`` triangles
+/// and ``degree`` distinct neighbors.
+pub(crate) fn clustering_coefficient(triangles: usize, degree: usize) -> f64 {
+    if triangles == 0 {
+        0.0
+    } else {
+        2.0 * triangles as f64 / (degree * (degree - 1)) as f64
     }
+}
 
-    let d: usize = neighbors.len();
-    let triples: usize = match d {
-        0 => 0,
-        _ => (d * (d - 1)) / 2,
-    };
+/// Count the triangles containing each node of an undirected graph.
+///
+/// A triangle is a set of three nodes that are all adjacent to each other.
+/// Self loops are ignored and parallel edges are only counted once.
+///
+/// The triangles are counted by intersecting the sorted neighbor lists of
+/// adjacent nodes. This function is multithreaded and will run
+/// launch a thread pool with threads equal to the number of CPUs by default.
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.add_node(None)
+///   print(retworkx.graph_triangles(graph))
+///
+/// :param PyGraph graph: Graph to be used.
+///
+/// :returns: A dictionary mapping each node index to the number of
+///     triangles containing it.
+/// :rtype: dict
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_triangles(graph: &graph::PyGraph) -> DictMap<usize, usize> {
+    let (nodes, adjacency) = sorted_adjacency(graph);
+    nodes
+        .iter()
+        .map(|node| node.index())
+        .zip(node_triangles(&adjacency))
+        .collect()
+}
 
-    (triangles / 2, triples)
+/// Compute the local clustering coefficient of each node of an undirected
+/// graph.
+///
+/// The local clustering coefficient of a node :math:`u` is the fraction of
+/// pairs of its neighbors that are adjacent:
+///
+/// .. math::
+///     c_u = \frac{2 T(u)}{\deg(u)(\deg(u) - 1)}
+///
+/// where :math:`T(u)` is the number of triangles containing :math:`u` and
+/// :math:`\deg(u)` is the number of distinct neighbors of :math:`u`, not
+/// counting self loops. Nodes with less than two neighbors have a
+/// clustering coefficient of 0.
+///
+/// This function is multithreaded and will run
+/// launch a thread pool with threads equal to the number of CPUs by default.
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.remove_edge(0, 1)
+///   print(retworkx.graph_clustering(graph))
+///
+/// :param PyGraph graph: Graph to be used.
+///
+/// :returns: A dictionary mapping each node index to its clustering
+///     coefficient.
+/// :rtype: dict
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_clustering(graph: &graph::PyGraph) -> DictMap<usize, f64> {
+    let (nodes, adjacency) = sorted_adjacency(graph);
+    let triangles = node_triangles(&adjacency);
+    nodes
+        .iter()
+        .zip(triangles.iter().zip(&adjacency))
+        .map(|(node, (triangles, neighbors))| {
+            (
+                node.index(),
+                clustering_coefficient(*triangles, neighbors.len()),
+            )
+        })
+        .collect()
+}
+
+/// Compute the average clustering coefficient of an undirected graph.
+///
+/// This is the mean of the local clustering coefficients of the nodes of the
+/// graph, as computed by :func:`~retworkx.graph_clustering`.
+///
+/// This function is multithreaded and will run
+/// launch a thread pool with threads equal to the number of CPUs by default.
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(4)
+///   graph.add_nodes_from([None, None])
+///   print(retworkx.graph_average_clustering(graph))
+///   print(retworkx.graph_average_clustering(graph, count_zeros=False))
+///
+/// :param PyGraph graph: Graph to be used.
+/// :param bool count_zeros: If set to ``False`` only the nodes with a
+///     nonzero clustering coefficient are included in the average.
+///
+/// :returns: The average clustering coefficient, 0.0 if there are no nodes
+///     to average over.
+/// :rtype: float
+#[pyfunction(count_zeros = "true")]
+#[pyo3(text_signature = "(graph, /, count_zeros=True)")]
+pub fn graph_average_clustering(graph: &graph::PyGraph, count_zeros: bool) -> f64 {
+    let (_nodes, adjacency) = sorted_adjacency(graph);
+    let (total, count) = node_triangles(&adjacency)
+        .par_iter()
+        .zip(adjacency.par_iter())
+        .map(|(triangles, neighbors)| clustering_coefficient(*triangles, neighbors.len()))
+        .filter(|coefficient| count_zeros || *coefficient > 0.0)
+        .map(|coefficient| (coefficient, 1))
+        .reduce(
+            || (0.0, 0),
+            |(sumx, sumy), (resx, resy)| (sumx + resx, sumy + resy),
+        );
+
+    match count {
+        0 => 0.0,
+        _ => total / count as f64,
+    }
 }
 
 /// Compute the transitivity of an undirected graph.
@@ -58,11 +238,7 @@ fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
-/// .. note::
-///
-///     The function implicitly assumes that there are no parallel edges
-///     or self loops. It may produce incorrect/unexpected results if the
-///     input graph has self loops or parallel edges.
+/// Self loops are ignored and parallel edges are only counted once.
 ///
 /// :param PyGraph graph: Graph to be used.
 ///
@@ -71,10 +247,18 @@ fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn graph_transitivity(graph: &graph::PyGraph) -> f64 {
-    let node_indices: Vec<NodeIndex> = graph.graph.node_indices().collect();
-    let (triangles, triples) = node_indices
+    let (_nodes, adjacency) = sorted_adjacency(graph);
+    let (triangles, triples) = node_triangles(&adjacency)
         .par_iter()
-        .map(|node| _graph_triangles(graph, node.index()))
+        .zip(adjacency.par_iter())
+        .map(|(triangles, neighbors)| {
+            let d = neighbors.len();
+            let triples = match d {
+                0 => 0,
+                _ => (d * (d - 1)) / 2,
+            };
+            (*triangles, triples)
+        })
         .reduce(
             || (0, 0),
             |(sumx, sumy), (resx, resy)| (sumx + resx, sumy + resy),
@@ -12,10 +12,9 @@
 
 use pyo3::prelude::*;
 
-use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::{Control, Time};
 
-use crate::PruneSearch;
+use crate::{NodeIndex, PruneSearch};
 use retworkx_core::traversal::DfsEvent;
 
 #[derive(FromPyObject)]
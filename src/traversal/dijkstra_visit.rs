@@ -12,10 +12,9 @@
 
 use pyo3::prelude::*;
 
-use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::Control;
 
-use crate::{PruneSearch, StopSearch};
+use crate::{NodeIndex, PruneSearch, StopSearch};
 use retworkx_core::traversal::DijkstraEvent;
 
 #[derive(FromPyObject)]
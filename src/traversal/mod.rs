@@ -19,10 +19,10 @@ use dfs_visit::{dfs_handler, PyDfsVisitor};
 use dijkstra_visit::{dijkstra_handler, PyDijkstraVisitor};
 
 use retworkx_core::traversal::{
-    breadth_first_search, depth_first_search, dfs_edges, dijkstra_search,
+    bfs_with_workspace, breadth_first_search, depth_first_search, dfs_edges, dijkstra_search,
 };
 
-use super::{digraph, graph, iterators, CostFn};
+use super::{core_error, digraph, graph, iterators, CostFn, NodeIndex};
 
 use std::convert::TryFrom;
 
@@ -32,10 +32,11 @@ use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::algo;
-use petgraph::graph::NodeIndex;
-use petgraph::visit::{Bfs, NodeCount, Reversed};
+use petgraph::visit::{Bfs, EdgeRef, NodeCount, Reversed};
 
 use crate::iterators::EdgeList;
+use crate::node_filter::NodeMask;
+use crate::workspace::Workspace;
 
 /// Get an edge list of the tree edges from a depth-first traversal
 ///
@@ -174,13 +175,30 @@ pub fn bfs_successors(
 ///
 /// :param PyDiGraph graph: The graph to get the ancestors from.
 /// :param int node: The index of the graph node to get the ancestors for
+/// :param Workspace workspace: An optional :class:`~retworkx.Workspace` whose
+///     buffers are reused for the traversal instead of allocating new ones.
 ///
 /// :returns: A set of node indices of ancestors of provided node.
 /// :rtype: set
 #[pyfunction]
-#[pyo3(text_signature = "(graph, node, /)")]
-pub fn ancestors(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
+#[pyo3(text_signature = "(graph, node, /, workspace=None)")]
+pub fn ancestors(
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    workspace: Option<PyRefMut<Workspace>>,
+) -> PyResult<HashSet<usize>> {
     let index = NodeIndex::new(node);
+    if let Some(mut workspace) = workspace {
+        let generation = workspace.generation(&graph.graph);
+        bfs_with_workspace(
+            Reversed(&graph.graph),
+            index,
+            &mut workspace.workspace,
+            generation,
+        )
+        .map_err(core_error)?;
+        return Ok(workspace.workspace.reached()[1..].iter().copied().collect());
+    }
     let mut out_set: HashSet<usize> = HashSet::new();
     let reverse_graph = Reversed(&graph.graph);
     let res = algo::dijkstra(reverse_graph, index, None, |_| 1);
@@ -189,7 +207,7 @@ pub fn ancestors(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
         out_set.insert(n_int);
     }
     out_set.remove(&node);
-    out_set
+    Ok(out_set)
 }
 
 /// Return the descendants of a node in a graph.
@@ -201,13 +219,25 @@ pub fn ancestors(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
 ///
 /// :param PyDiGraph graph: The graph to get the descendants from
 /// :param int node: The index of the graph node to get the descendants for
+/// :param Workspace workspace: An optional :class:`~retworkx.Workspace` whose
+///     buffers are reused for the traversal instead of allocating new ones.
 ///
 /// :returns: A set of node indices of descendants of provided node.
 /// :rtype: set
 #[pyfunction]
-#[pyo3(text_signature = "(graph, node, /)")]
-pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
+#[pyo3(text_signature = "(graph, node, /, workspace=None)")]
+pub fn descendants(
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    workspace: Option<PyRefMut<Workspace>>,
+) -> PyResult<HashSet<usize>> {
     let index = NodeIndex::new(node);
+    if let Some(mut workspace) = workspace {
+        let generation = workspace.generation(&graph.graph);
+        bfs_with_workspace(&graph.graph, index, &mut workspace.workspace, generation)
+            .map_err(core_error)?;
+        return Ok(workspace.workspace.reached()[1..].iter().copied().collect());
+    }
     let mut out_set: HashSet<usize> = HashSet::new();
     let res = algo::dijkstra(&graph.graph, index, None, |_| 1);
     for n in res.keys() {
@@ -215,7 +245,7 @@ pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
         out_set.insert(n_int);
     }
     out_set.remove(&node);
-    out_set
+    Ok(out_set)
 }
 
 /// Breadth-first traversal of a directed graph.
@@ -284,20 +314,28 @@ pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
 ///     graph are searched.
 /// :param visitor: A visitor object that is invoked at the event points inside the
 ///     algorithm. This should be a subclass of :class:`~retworkx.visit.BFSVisitor`.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph, including masked nodes in ``source``. If not specified every
+///     node is used.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, source, visitor)")]
+#[pyo3(text_signature = "(graph, source, visitor, node_mask=None)")]
 pub fn digraph_bfs_search(
     py: Python,
     graph: &digraph::PyDiGraph,
     source: Option<Vec<usize>>,
     visitor: PyBfsVisitor,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<()> {
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
     let starts: Vec<_> = match source {
         Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
         None => graph.graph.node_indices().collect(),
     };
+    let starts = starts.into_iter().filter(|node| node_mask.contains(*node));
 
-    breadth_first_search(&graph.graph, starts, |event| {
+    breadth_first_search(&node_mask.filter(&graph.graph), starts, |event| {
         bfs_handler(py, &visitor, event)
     })?;
 
@@ -370,20 +408,28 @@ pub fn digraph_bfs_search(
 ///     graph are searched.
 /// :param visitor: A visitor object that is invoked at the event points inside the
 ///     algorithm. This should be a subclass of :class:`~retworkx.visit.BFSVisitor`.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph, including masked nodes in ``source``. If not specified every
+///     node is used.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, source, visitor)")]
+#[pyo3(text_signature = "(graph, source, visitor, node_mask=None)")]
 pub fn graph_bfs_search(
     py: Python,
     graph: &graph::PyGraph,
     source: Option<Vec<usize>>,
     visitor: PyBfsVisitor,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<()> {
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
     let starts: Vec<_> = match source {
         Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
         None => graph.graph.node_indices().collect(),
     };
+    let starts = starts.into_iter().filter(|node| node_mask.contains(*node));
 
-    breadth_first_search(&graph.graph, starts, |event| {
+    breadth_first_search(&node_mask.filter(&graph.graph), starts, |event| {
         bfs_handler(py, &visitor, event)
     })?;
 
@@ -454,20 +500,28 @@ pub fn graph_bfs_search(
 ///     graph are searched.
 /// :param visitor: A visitor object that is invoked at the event points inside the
 ///     algorithm. This should be a subclass of :class:`~retworkx.visit.DFSVisitor`.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph, including masked nodes in ``source``. If not specified every
+///     node is used.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, source, visitor)")]
+#[pyo3(text_signature = "(graph, source, visitor, node_mask=None)")]
 pub fn digraph_dfs_search(
     py: Python,
     graph: &digraph::PyDiGraph,
     source: Option<Vec<usize>>,
     visitor: PyDfsVisitor,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<()> {
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
     let starts: Vec<_> = match source {
         Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
         None => graph.graph.node_indices().collect(),
     };
+    let starts = starts.into_iter().filter(|node| node_mask.contains(*node));
 
-    depth_first_search(&graph.graph, starts, |event| {
+    depth_first_search(&node_mask.filter(&graph.graph), starts, |event| {
         dfs_handler(py, &visitor, event)
     })?;
 
@@ -538,20 +592,28 @@ pub fn digraph_dfs_search(
 ///     graph are searched.
 /// :param visitor: A visitor object that is invoked at the event points inside the
 ///     algorithm. This should be a subclass of :class:`~retworkx.visit.DFSVisitor`.
+/// :param node_mask: An optional boolean numpy array with an entry for each
+///     node index, or an iterable of node indices, of the nodes to mask.
+///     Masked nodes, and their edges, are ignored as if they weren't in the
+///     graph, including masked nodes in ``source``. If not specified every
+///     node is used.
 #[pyfunction]
-#[pyo3(text_signature = "(graph, source, visitor)")]
+#[pyo3(text_signature = "(graph, source, visitor, node_mask=None)")]
 pub fn graph_dfs_search(
     py: Python,
     graph: &graph::PyGraph,
     source: Option<Vec<usize>>,
     visitor: PyDfsVisitor,
+    node_mask: Option<&PyAny>,
 ) -> PyResult<()> {
+    let node_mask = NodeMask::new(py, &graph.graph, node_mask)?;
     let starts: Vec<_> = match source {
         Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
         None => graph.graph.node_indices().collect(),
     };
+    let starts = starts.into_iter().filter(|node| node_mask.contains(*node));
 
-    depth_first_search(&graph.graph, starts, |event| {
+    depth_first_search(&node_mask.filter(&graph.graph), starts, |event| {
         dfs_handler(py, &visitor, event)
     })?;
 
@@ -622,11 +684,11 @@ pub fn digraph_dijkstra_search(
         None => graph.graph.node_indices().collect(),
     };
 
-    let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?;
+    let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?.prepare(py, &graph.graph)?;
     dijkstra_search(
         &graph.graph,
         starts,
-        |e| edge_cost_fn.call(py, e.weight()),
+        |e| edge_cost_fn.call_edge(py, e.id(), e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
     )??;
 
@@ -697,11 +759,11 @@ pub fn graph_dijkstra_search(
         None => graph.graph.node_indices().collect(),
     };
 
-    let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?;
+    let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?.prepare(py, &graph.graph)?;
     dijkstra_search(
         &graph.graph,
         starts,
-        |e| edge_cost_fn.call(py, e.weight()),
+        |e| edge_cost_fn.call_edge(py, e.id(), e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
     )??;
 
@@ -11,8 +11,11 @@
 // under the License.
 
 use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicUsize};
 
-use super::{graph, weight_callable};
+use fixedbitset::FixedBitSet;
+
+use super::{batched_weights, digraph, graph, weight_callable};
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -21,15 +24,128 @@ use pyo3::Python;
 use petgraph::prelude::*;
 use petgraph::stable_graph::EdgeReference;
 use petgraph::unionfind::UnionFind;
-use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
 
+use rand::prelude::*;
 use rayon::prelude::*;
 
-use crate::iterators::WeightedEdgeList;
+use crate::iterators::{EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use crate::random_seed::seeded_rng;
+use crate::{EdgeIndex, GraphIx, InvalidTreeError, NodeIndex, NullGraph, StablePyGraph};
+
+/// Select the minimum spanning forest from ``endpoints``, the endpoints of
+/// the edges of a graph sorted by weight, with Borůvka's algorithm.
+///
+/// In every round each component of the forest picks its cheapest outgoing
+/// edge in parallel and all the picked edges are added to the forest, which
+/// at least halves the number of components. Ties are broken by the
+/// position of the edge in ``endpoints`` so the selected edges are the same
+/// as with Kruskal's algorithm. Returns the positions of the selected edges
+/// in ascending order.
+fn boruvka_edges(node_bound: usize, endpoints: &[(usize, usize)]) -> Vec<usize> {
+    let mut subgraphs = UnionFind::<usize>::new(node_bound);
+    let cheapest: Vec<AtomicUsize> = (0..node_bound)
+        .map(|_| AtomicUsize::new(usize::MAX))
+        .collect();
+    let mut candidates: Vec<usize> = (0..endpoints.len()).collect();
+    let mut selected: Vec<usize> = Vec::new();
+    loop {
+        let labels: Vec<usize> = (0..node_bound)
+            .into_par_iter()
+            .map(|node| subgraphs.find(node))
+            .collect();
+        candidates = candidates
+            .into_par_iter()
+            .filter(|pos| {
+                let (source, target) = endpoints[*pos];
+                labels[source] != labels[target]
+            })
+            .collect();
+        if candidates.is_empty() {
+            break;
+        }
+        candidates.par_iter().for_each(|pos| {
+            let (source, target) = endpoints[*pos];
+            cheapest[labels[source]].fetch_min(*pos, atomic::Ordering::Relaxed);
+            cheapest[labels[target]].fetch_min(*pos, atomic::Ordering::Relaxed);
+        });
+        for component in cheapest.iter() {
+            let pos = component.swap(usize::MAX, atomic::Ordering::Relaxed);
+            if pos != usize::MAX {
+                let (source, target) = endpoints[pos];
+                // Both endpoints' components can pick the same edge
+                if subgraphs.union(source, target) {
+                    selected.push(pos);
+                }
+            }
+        }
+    }
+    selected.par_sort_unstable();
+    selected
+}
+
+/// Find the edges of the minimum spanning tree or forest of ``graph``, in
+/// order of increasing weight.
+///
+/// The edges are selected with Kruskal's algorithm, or with a parallel
+/// Borůvka's algorithm if the graph has at least ``parallel_threshold``
+/// edges. Both select the same edges.
+fn minimum_spanning_edge_refs<'a>(
+    py: Python,
+    graph: &'a graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<Vec<EdgeReference<'a, PyObject, GraphIx>>> {
+    let mut edge_list: Vec<(f64, EdgeReference<PyObject, GraphIx>)> =
+        Vec::with_capacity(graph.graph.edge_count());
+    let batched = batched_weights(py, weight_fn.as_ref(), &graph.graph)?;
+    for edge in graph.graph.edge_references() {
+        let weight = match &batched {
+            Some(weights) => weights[edge.id().index()],
+            None => weight_callable(py, &weight_fn, edge.weight(), default_weight)?,
+        };
+        if weight.is_nan() {
+            return Err(PyValueError::new_err("NaN found as an edge weight"));
+        }
+        edge_list.push((weight, edge));
+    }
+
+    edge_list.par_sort_unstable_by(|a, b| {
+        let weight_a = a.0;
+        let weight_b = b.0;
+        weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
+    });
+
+    if edge_list.len() >= parallel_threshold {
+        let endpoints: Vec<(usize, usize)> = edge_list
+            .iter()
+            .map(|(_, edge)| (edge.source().index(), edge.target().index()))
+            .collect();
+        return Ok(boruvka_edges(graph.graph.node_bound(), &endpoints)
+            .into_iter()
+            .map(|pos| edge_list[pos].1)
+            .collect());
+    }
+
+    let mut subgraphs = UnionFind::<usize>::new(graph.graph.node_bound());
+    Ok(edge_list
+        .into_iter()
+        .map(|(_, edge)| edge)
+        .filter(|edge| subgraphs.union(edge.source().index(), edge.target().index()))
+        .collect())
+}
 
 /// Find the edges in the minimum spanning tree or forest of a graph
 /// using Kruskal's algorithm.
 ///
+/// The edges are selected in parallel with Borůvka's algorithm instead if the
+/// number of edges in the graph is at least ``parallel_threshold`` (it
+/// defaults to 100000), both algorithms select the same edges. If the function
+/// will be running in parallel the env var ``RAYON_NUM_THREADS`` can be used
+/// to adjust how many threads will be used.
+///
 /// :param PyGraph graph: Undirected graph
 /// :param weight_fn: A callable object (function, lambda, etc) which
 ///     will be passed the edge object and expected to return a ``float``. This
@@ -45,53 +161,112 @@ use crate::iterators::WeightedEdgeList;
 ///     to cast the edge object as a float as the weight.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
+/// :param int parallel_threshold: The number of edges to select the
+///     spanning tree edges in parallel at, if the number of edges in the graph
+///     is less than this value Kruskal's algorithm is used in a single
+///     thread. The default value is 100000
 ///
 /// :returns: The :math:`N - |c|` edges of the Minimum Spanning Tree (or Forest, if :math:`|c| > 1`)
 ///     where :math:`N` is the number of nodes and :math:`|c|` is the number of connected components of the graph
 /// :rtype: WeightedEdgeList
-#[pyfunction(weight_fn = "None", default_weight = "1.0")]
-#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyfunction(
+    weight_fn = "None",
+    default_weight = "1.0",
+    parallel_threshold = "100000"
+)]
+#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000)")]
 pub fn minimum_spanning_edges(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    parallel_threshold: usize,
 ) -> PyResult<WeightedEdgeList> {
-    let mut subgraphs = UnionFind::<usize>::new(graph.graph.node_bound());
-
-    let mut edge_list: Vec<(f64, EdgeReference<PyObject>)> =
-        Vec::with_capacity(graph.graph.edge_count());
-    for edge in graph.graph.edge_references() {
-        let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
-        if weight.is_nan() {
-            return Err(PyValueError::new_err("NaN found as an edge weight"));
-        }
-        edge_list.push((weight, edge));
-    }
-
-    edge_list.par_sort_unstable_by(|a, b| {
-        let weight_a = a.0;
-        let weight_b = b.0;
-        weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
-    });
-
-    let mut answer: Vec<(usize, usize, PyObject)> = Vec::new();
-    for float_edge_pair in edge_list.iter() {
-        let edge = float_edge_pair.1;
-        let u = edge.source().index();
-        let v = edge.target().index();
-        if subgraphs.union(u, v) {
-            let w = edge.weight().clone_ref(py);
-            answer.push((u, v, w));
-        }
-    }
+    Ok(WeightedEdgeList {
+        edges: minimum_spanning_edge_refs(
+            py,
+            graph,
+            weight_fn,
+            default_weight,
+            parallel_threshold,
+        )?
+        .into_iter()
+        .map(|edge| {
+            (
+                edge.source().index(),
+                edge.target().index(),
+                edge.weight().clone_ref(py),
+            )
+        })
+        .collect(),
+    })
+}
 
-    Ok(WeightedEdgeList { edges: answer })
+/// Find the edge indices of the minimum spanning tree or forest of a graph
+/// using Kruskal's algorithm.
+///
+/// The edges are selected in parallel with Borůvka's algorithm instead if the
+/// number of edges in the graph is at least ``parallel_threshold`` (it
+/// defaults to 100000), both algorithms select the same edges. If the function
+/// will be running in parallel the env var ``RAYON_NUM_THREADS`` can be used
+/// to adjust how many threads will be used.
+///
+/// This is the same as :func:`~retworkx.minimum_spanning_edges` except that
+/// it returns edge indices instead of edge tuples, which is unambiguous
+/// when there are parallel edges in the graph.
+///
+/// :param PyGraph graph: Undirected graph
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``. This
+///     tells retworkx/rust how to extract a numerical weight as a ``float``
+///     for edge object.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+/// :param int parallel_threshold: The number of edges to select the
+///     spanning tree edges in parallel at, if the number of edges in the graph
+///     is less than this value Kruskal's algorithm is used in a single
+///     thread. The default value is 100000
+///
+/// :returns: The indices of the :math:`N - |c|` edges of the Minimum Spanning
+///     Tree (or Forest, if :math:`|c| > 1`) where :math:`N` is the number of
+///     nodes and :math:`|c|` is the number of connected components of the graph
+/// :rtype: EdgeIndices
+#[pyfunction(
+    weight_fn = "None",
+    default_weight = "1.0",
+    parallel_threshold = "100000"
+)]
+#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000)")]
+pub fn minimum_spanning_edge_indices(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<EdgeIndices> {
+    Ok(EdgeIndices {
+        edges: minimum_spanning_edge_refs(
+            py,
+            graph,
+            weight_fn,
+            default_weight,
+            parallel_threshold,
+        )?
+        .into_iter()
+        .map(|edge| edge.id().index())
+        .collect(),
+    })
 }
 
 /// Find the minimum spanning tree or forest of a graph
 /// using Kruskal's algorithm.
 ///
+/// The edges are selected in parallel with Borůvka's algorithm instead if the
+/// number of edges in the graph is at least ``parallel_threshold`` (it
+/// defaults to 100000), both algorithms select the same edges. If the function
+/// will be running in parallel the env var ``RAYON_NUM_THREADS`` can be used
+/// to adjust how many threads will be used.
+///
 /// :param PyGraph graph: Undirected graph
 /// :param weight_fn: A callable object (function, lambda, etc) which
 ///     will be passed the edge object and expected to return a ``float``. This
@@ -107,6 +282,10 @@ pub fn minimum_spanning_edges(
 ///     to cast the edge object as a float as the weight.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
+/// :param int parallel_threshold: The number of edges to select the
+///     spanning tree edges in parallel at, if the number of edges in the graph
+///     is less than this value Kruskal's algorithm is used in a single
+///     thread. The default value is 100000
 ///
 /// :returns: A Minimum Spanning Tree (or Forest, if the graph is not connected).
 ///
@@ -115,18 +294,23 @@ pub fn minimum_spanning_edges(
 /// .. note::
 ///
 ///     The new graph will keep the same node indices, but edge indices might differ.
-#[pyfunction(weight_fn = "None", default_weight = "1.0")]
-#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyfunction(
+    weight_fn = "None",
+    default_weight = "1.0",
+    parallel_threshold = "100000"
+)]
+#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0, parallel_threshold=100000)")]
 pub fn minimum_spanning_tree(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    parallel_threshold: usize,
 ) -> PyResult<graph::PyGraph> {
     let mut spanning_tree = (*graph).clone();
     spanning_tree.graph.clear_edges();
 
-    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight)?
+    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight, parallel_threshold)?
         .edges
         .iter()
     {
@@ -135,3 +319,902 @@ pub fn minimum_spanning_tree(
 
     Ok(spanning_tree)
 }
+
+/// Mark the nodes connected to ``start`` by the edges of a forest, other than
+/// ``removed``, with ``label`` in ``side``.
+fn mark_forest_side(
+    graph: &StablePyGraph<Undirected>,
+    in_tree: &[bool],
+    start: NodeIndex,
+    removed: EdgeIndex,
+    side: &mut [u8],
+    label: u8,
+) {
+    side[start.index()] = label;
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if in_tree[edge.id().index()] && edge.id() != removed && side[next.index()] == 0 {
+                side[next.index()] = label;
+                stack.push(next);
+            }
+        }
+    }
+}
+
+/// The edges of the path between ``source`` and ``target`` in a forest, or
+/// ``None`` if they're in different trees.
+fn forest_path(
+    graph: &StablePyGraph<Undirected>,
+    in_tree: &[bool],
+    source: NodeIndex,
+    target: NodeIndex,
+) -> Option<Vec<EdgeIndex>> {
+    let mut parents: Vec<Option<(NodeIndex, EdgeIndex)>> = vec![None; graph.node_bound()];
+    let mut visited = vec![false; graph.node_bound()];
+    visited[source.index()] = true;
+    let mut stack = vec![source];
+    while let Some(node) = stack.pop() {
+        if node == target {
+            break;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if in_tree[edge.id().index()] && !visited[next.index()] {
+                visited[next.index()] = true;
+                parents[next.index()] = Some((node, edge.id()));
+                stack.push(next);
+            }
+        }
+    }
+    if !visited[target.index()] {
+        return None;
+    }
+    let mut path = Vec::new();
+    let mut node = target;
+    while let Some((parent, edge)) = parents[node.index()] {
+        path.push(edge);
+        node = parent;
+    }
+    Some(path)
+}
+
+/// A spanning forest as the edges of the graph in it and the degree of each
+/// node in it.
+struct DegreeForest {
+    in_tree: Vec<bool>,
+    degree: Vec<usize>,
+}
+
+impl DegreeForest {
+    fn exchange(&mut self, graph: &StablePyGraph<Undirected>, remove: EdgeIndex, add: EdgeIndex) {
+        let (source, target) = graph.edge_endpoints(remove).unwrap();
+        self.in_tree[remove.index()] = false;
+        self.degree[source.index()] -= 1;
+        self.degree[target.index()] -= 1;
+        let (source, target) = graph.edge_endpoints(add).unwrap();
+        self.in_tree[add.index()] = true;
+        self.degree[source.index()] += 1;
+        self.degree[target.index()] += 1;
+    }
+
+    /// Exchange tree edges of the nodes with too high a degree for edges
+    /// which reconnect the tree, the cheapest exchange first, until every
+    /// node has at most ``max_degree`` tree edges. Each exchange lowers the
+    /// degree of a node above the limit and doesn't raise any degree above
+    /// it. Returns ``false`` if there is a node above the limit but no such
+    /// exchange.
+    fn repair(
+        &mut self,
+        graph: &StablePyGraph<Undirected>,
+        weights: &[f64],
+        max_degree: usize,
+    ) -> bool {
+        let mut side: Vec<u8> = vec![0; graph.node_bound()];
+        loop {
+            let mut best: Option<(f64, EdgeIndex, EdgeIndex)> = None;
+            let mut exceeded = false;
+            for node in graph.node_indices() {
+                if self.degree[node.index()] <= max_degree {
+                    continue;
+                }
+                exceeded = true;
+                for remove in graph
+                    .edges(node)
+                    .filter(|edge| self.in_tree[edge.id().index()])
+                {
+                    // Removing the edge splits its tree in two sides, an edge
+                    // between them reconnects it
+                    let other = remove.target();
+                    side.iter_mut().for_each(|label| *label = 0);
+                    mark_forest_side(graph, &self.in_tree, other, remove.id(), &mut side, 1);
+                    mark_forest_side(graph, &self.in_tree, node, remove.id(), &mut side, 2);
+                    let degree_after = |x: NodeIndex| {
+                        self.degree[x.index()] - usize::from(x == node || x == other)
+                    };
+                    for add in graph.edge_references() {
+                        let (a, b) = (add.source(), add.target());
+                        if self.in_tree[add.id().index()]
+                            || side[a.index()] == 0
+                            || side[b.index()] == 0
+                            || side[a.index()] == side[b.index()]
+                            || degree_after(a) >= max_degree
+                            || degree_after(b) >= max_degree
+                        {
+                            continue;
+                        }
+                        let cost = weights[add.id().index()] - weights[remove.id().index()];
+                        let better = match best {
+                            Some((best_cost, _, _)) => cost < best_cost,
+                            None => true,
+                        };
+                        if better {
+                            best = Some((cost, remove.id(), add.id()));
+                        }
+                    }
+                }
+            }
+            if !exceeded {
+                return true;
+            }
+            match best {
+                Some((_, remove, add)) => self.exchange(graph, remove, add),
+                None => return false,
+            }
+        }
+    }
+
+    /// Exchange a tree edge for a cheaper edge which reconnects the tree,
+    /// keeping every degree at most ``max_degree``, until there is no such
+    /// exchange. Each exchange lowers the total weight of the forest.
+    fn improve(&mut self, graph: &StablePyGraph<Undirected>, weights: &[f64], max_degree: usize) {
+        let mut candidates: Vec<EdgeIndex> = graph
+            .edge_references()
+            .filter(|edge| edge.source() != edge.target())
+            .map(|edge| edge.id())
+            .collect();
+        candidates.sort_by(|a, b| {
+            weights[a.index()]
+                .partial_cmp(&weights[b.index()])
+                .unwrap_or(Ordering::Less)
+        });
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for add in &candidates {
+                if self.in_tree[add.index()] {
+                    continue;
+                }
+                let (a, b) = graph.edge_endpoints(*add).unwrap();
+                let path = match forest_path(graph, &self.in_tree, a, b) {
+                    Some(path) => path,
+                    None => continue,
+                };
+                let fits = |remove: &EdgeIndex| {
+                    let (source, target) = graph.edge_endpoints(*remove).unwrap();
+                    [a, b].iter().all(|x| {
+                        self.degree[x.index()] + 1 - usize::from(*x == source || *x == target)
+                            <= max_degree
+                    })
+                };
+                let mut best: Option<EdgeIndex> = None;
+                for remove in path.into_iter().filter(fits) {
+                    let better = match best {
+                        Some(best) => weights[remove.index()] > weights[best.index()],
+                        None => true,
+                    };
+                    if better {
+                        best = Some(remove);
+                    }
+                }
+                if let Some(remove) = best {
+                    if weights[remove.index()] > weights[add.index()] {
+                        self.exchange(graph, remove, *add);
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Find a low weight spanning tree of a graph with a maximum node degree
+///
+/// Finding a minimum spanning tree whose nodes have at most ``max_degree``
+/// edges in it is NP-hard for a ``max_degree`` of 2 or more, so this is a
+/// heuristic. It starts from the minimum spanning tree found by
+/// :func:`~retworkx.minimum_spanning_edge_indices`. While a node has more
+/// than ``max_degree`` tree edges, one of them is exchanged for an edge of
+/// the graph which reconnects the tree without raising the degree of a node
+/// above ``max_degree``, choosing the exchange which increases the total
+/// weight the least. Then the tree is improved by local search: a tree edge
+/// is exchanged for a lighter edge which reconnects the tree, as long as the
+/// degrees stay at most ``max_degree``, until there is no such exchange.
+///
+/// If the graph isn't connected a spanning forest is found, with a spanning
+/// tree of each connected component.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.mesh_graph(5)
+///   for source, target in graph.edge_list():
+///       graph.update_edge(source, target, float(source + target))
+///   edges = retworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=float)
+///   print([graph.get_edge_endpoints_by_index(edge) for edge in edges])
+///
+/// :param PyGraph graph: Undirected graph
+/// :param int max_degree: The maximum number of edges of the tree a node
+///     can have
+/// :param weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float``. This
+///     tells retworkx/rust how to extract a numerical weight as a ``float``
+///     for edge object.
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+///
+/// :returns: The indices of the :math:`N - |c|` edges of the spanning tree
+///     (or forest, if :math:`|c| > 1`) in ascending order, where :math:`N`
+///     is the number of nodes and :math:`|c|` is the number of connected
+///     components of the graph
+/// :rtype: EdgeIndices
+/// :raises ValueError: If the heuristic can't find a spanning tree whose
+///     nodes have at most ``max_degree`` edges, which may not exist, or if
+///     an edge weight is NaN
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, max_degree, /, weight_fn=None, default_weight=1.0)")]
+pub fn degree_constrained_spanning_tree(
+    py: Python,
+    graph: &graph::PyGraph,
+    max_degree: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeIndices> {
+    let spanning_edges = minimum_spanning_edge_refs(
+        py,
+        graph,
+        weight_fn.as_ref().map(|weight_fn| weight_fn.clone_ref(py)),
+        default_weight,
+        usize::MAX,
+    )?;
+    let mut forest = DegreeForest {
+        in_tree: vec![false; graph.graph.edge_bound()],
+        degree: vec![0; graph.graph.node_bound()],
+    };
+    for edge in spanning_edges {
+        forest.in_tree[edge.id().index()] = true;
+        forest.degree[edge.source().index()] += 1;
+        forest.degree[edge.target().index()] += 1;
+    }
+    let mut weights: Vec<f64> = vec![0.; graph.graph.edge_bound()];
+    for edge in graph.graph.edge_references() {
+        weights[edge.id().index()] =
+            weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+    }
+    let repaired = py.allow_threads(|| {
+        if !forest.repair(&graph.graph, &weights, max_degree) {
+            return false;
+        }
+        forest.improve(&graph.graph, &weights, max_degree);
+        true
+    });
+    if !repaired {
+        return Err(PyValueError::new_err(format!(
+            "Unable to find a spanning tree with a maximum degree of {}",
+            max_degree
+        )));
+    }
+    Ok(EdgeIndices {
+        edges: forest
+            .in_tree
+            .iter()
+            .enumerate()
+            .filter(|(_, in_tree)| **in_tree)
+            .map(|(edge, _)| edge)
+            .collect(),
+    })
+}
+
+/// Sample a uniformly random spanning tree of a graph
+///
+/// The spanning tree is sampled with Wilson's algorithm [1]_, which builds it
+/// from loop erased random walks. Each random walk starts from a node which
+/// isn't in the tree yet and ends when it reaches the tree, the path of the
+/// walk with its loops removed is added to the tree. Every spanning tree of
+/// the graph is equally likely, parallel edges are treated as distinct so a
+/// pair of nodes joined by more edges is more likely to be joined in the
+/// tree. If the graph isn't connected a spanning forest is sampled, with a
+/// uniformly random spanning tree of each connected component.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   edges = retworkx.random_spanning_tree(graph, seed=42)
+///   print(edges)
+///   print([graph.get_edge_endpoints_by_index(edge) for edge in edges])
+///
+/// :param PyGraph graph: Undirected graph
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The indices of the :math:`N - |c|` edges of the spanning tree
+///     (or forest, if :math:`|c| > 1`) in ascending order, where :math:`N`
+///     is the number of nodes and :math:`|c|` is the number of connected
+///     components of the graph
+/// :rtype: EdgeIndices
+///
+/// .. [1] Wilson, David Bruce. "Generating random spanning trees more quickly
+///    than the cover time." Proceedings of the Twenty-Eighth Annual ACM
+///    Symposium on Theory of Computing (1996): 296-303.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, seed=None)")]
+pub fn random_spanning_tree(graph: &graph::PyGraph, seed: Option<u64>) -> EdgeIndices {
+    let node_bound = graph.graph.node_bound();
+    // The neighbors of each node and the edges to them, self loops can't be
+    // in a spanning tree.
+    let mut neighbors: Vec<Vec<(usize, usize)>> = vec![Vec::new(); node_bound];
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if source != target {
+            neighbors[source].push((target, edge.id().index()));
+            neighbors[target].push((source, edge.id().index()));
+        }
+    }
+    // Start the tree of each connected component from its lowest node, the
+    // distribution of the sampled tree doesn't depend on the root.
+    let mut in_tree = FixedBitSet::with_capacity(node_bound);
+    let mut reached = FixedBitSet::with_capacity(node_bound);
+    for node in graph.graph.node_indices() {
+        if reached[node.index()] {
+            continue;
+        }
+        in_tree.insert(node.index());
+        reached.insert(node.index());
+        let mut stack = vec![node.index()];
+        while let Some(current) = stack.pop() {
+            for (neighbor, _) in &neighbors[current] {
+                if !reached[*neighbor] {
+                    reached.insert(*neighbor);
+                    stack.push(*neighbor);
+                }
+            }
+        }
+    }
+    let mut rng = seeded_rng(seed);
+    // The edge the random walk last left each node by, following them from
+    // the start of the walk gives the walk with its loops erased.
+    let mut next: Vec<(usize, usize)> = vec![(0, 0); node_bound];
+    let mut edges: Vec<usize> = Vec::with_capacity(graph.graph.node_count());
+    for node in graph.graph.node_indices() {
+        let mut current = node.index();
+        while !in_tree[current] {
+            let choices = &neighbors[current];
+            next[current] = choices[rng.gen_range(0..choices.len())];
+            current = next[current].0;
+        }
+        current = node.index();
+        while !in_tree[current] {
+            in_tree.insert(current);
+            edges.push(next[current].1);
+            current = next[current].0;
+        }
+    }
+    edges.sort_unstable();
+    EdgeIndices { edges }
+}
+
+/// The neighbors of each node of a tree by node index, returns an error if
+/// ``graph`` is empty or isn't a tree.
+fn tree_neighbors(graph: &graph::PyGraph) -> PyResult<Vec<Vec<usize>>> {
+    let node_count = graph.graph.node_count();
+    if node_count == 0 {
+        return Err(NullGraph::new_err("Invalid operation on a NullGraph"));
+    }
+    let not_a_tree = || InvalidTreeError::new_err("The input graph is not a tree");
+    if graph.graph.edge_count() != node_count - 1 {
+        return Err(not_a_tree());
+    }
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); graph.graph.node_bound()];
+    for edge in graph.graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        neighbors[source].push(target);
+        neighbors[target].push(source);
+    }
+    // With one less edge than nodes the graph is a tree if it's connected
+    let start = graph.graph.node_indices().next().unwrap().index();
+    if tree_bfs(&neighbors, start).0.len() != node_count {
+        return Err(not_a_tree());
+    }
+    Ok(neighbors)
+}
+
+/// Run a breadth first search of a tree from ``start``, returns the nodes in
+/// the order they're visited and the parent of each node.
+fn tree_bfs(neighbors: &[Vec<usize>], start: usize) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut parents: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut visited = vec![false; neighbors.len()];
+    let mut order = vec![start];
+    visited[start] = true;
+    let mut position = 0;
+    while position < order.len() {
+        let node = order[position];
+        position += 1;
+        for neighbor in &neighbors[node] {
+            if !visited[*neighbor] {
+                visited[*neighbor] = true;
+                parents[*neighbor] = Some(node);
+                order.push(*neighbor);
+            }
+        }
+    }
+    (order, parents)
+}
+
+/// Find a longest path of a tree with two breadth first searches, the
+/// farthest node from any node is an end of a longest path.
+fn longest_tree_path(graph: &graph::PyGraph, neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let start = graph.graph.node_indices().next().unwrap().index();
+    let (order, _) = tree_bfs(neighbors, start);
+    let first = *order.last().unwrap();
+    let (order, parents) = tree_bfs(neighbors, first);
+    let mut path = vec![*order.last().unwrap()];
+    while let Some(parent) = parents[*path.last().unwrap()] {
+        path.push(parent);
+    }
+    path
+}
+
+/// Return the center of a tree
+///
+/// The center of a tree is the set of nodes with the minimum eccentricity,
+/// the largest distance to any other node. It's the middle node or the two
+/// middle nodes of any longest path of the tree, which is found with two
+/// breadth first searches.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.path_graph(6)
+///   print(retworkx.tree_center(graph))
+///
+/// :param PyGraph graph: The tree to find the center of
+///
+/// :returns: The indices of the one or two center nodes in ascending order
+/// :rtype: NodeIndices
+///
+/// :raises NullGraph: If the graph is empty
+/// :raises InvalidTreeError: If the graph isn't a tree
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn tree_center(graph: &graph::PyGraph) -> PyResult<NodeIndices> {
+    let neighbors = tree_neighbors(graph)?;
+    let path = longest_tree_path(graph, &neighbors);
+    let middle = path.len() / 2;
+    let mut nodes = if path.len() % 2 == 1 {
+        vec![path[middle]]
+    } else {
+        vec![path[middle - 1], path[middle]]
+    };
+    nodes.sort_unstable();
+    Ok(NodeIndices { nodes })
+}
+
+/// Return the centroid of a tree
+///
+/// The centroid of a tree is the set of nodes whose removal leaves the
+/// smallest largest connected component. A tree has one centroid node or
+/// two adjacent ones, and removing a centroid node leaves components of at
+/// most half the nodes of the tree.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.star_graph(6)
+///   graph.extend_from_edge_list([(5, 6), (6, 7), (7, 8)])
+///   print(retworkx.tree_centroid(graph))
+///
+/// :param PyGraph graph: The tree to find the centroid of
+///
+/// :returns: The indices of the one or two centroid nodes in ascending order
+/// :rtype: NodeIndices
+///
+/// :raises NullGraph: If the graph is empty
+/// :raises InvalidTreeError: If the graph isn't a tree
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn tree_centroid(graph: &graph::PyGraph) -> PyResult<NodeIndices> {
+    let neighbors = tree_neighbors(graph)?;
+    let node_count = graph.graph.node_count();
+    let start = graph.graph.node_indices().next().unwrap().index();
+    let (order, parents) = tree_bfs(&neighbors, start);
+    // Accumulate the subtree sizes from the leaves up, along with the size
+    // of the largest component left by removing each node
+    let mut sizes = vec![1; neighbors.len()];
+    let mut largest = vec![0; neighbors.len()];
+    for node in order.iter().rev() {
+        largest[*node] = largest[*node].max(node_count - sizes[*node]);
+        if let Some(parent) = parents[*node] {
+            sizes[parent] += sizes[*node];
+            largest[parent] = largest[parent].max(sizes[*node]);
+        }
+    }
+    let minimum = order.iter().map(|node| largest[*node]).min().unwrap();
+    let mut nodes: Vec<usize> = order
+        .into_iter()
+        .filter(|node| largest[*node] == minimum)
+        .collect();
+    nodes.sort_unstable();
+    Ok(NodeIndices { nodes })
+}
+
+/// Return a longest path of a tree
+///
+/// The path is found with two breadth first searches, the first from an
+/// arbitrary node finds the farthest node from it, which is an end of a
+/// longest path, and the second finds the farthest node from that end. The
+/// number of edges of the path is the diameter of the tree.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.binomial_tree_graph(3)
+///   path = retworkx.tree_diameter_path(graph)
+///   print(path, len(path) - 1)
+///
+/// :param PyGraph graph: The tree to find a longest path of
+///
+/// :returns: The node indices of the path from one end to the other
+/// :rtype: NodeIndices
+///
+/// :raises NullGraph: If the graph is empty
+/// :raises InvalidTreeError: If the graph isn't a tree
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn tree_diameter_path(graph: &graph::PyGraph) -> PyResult<NodeIndices> {
+    let neighbors = tree_neighbors(graph)?;
+    Ok(NodeIndices {
+        nodes: longest_tree_path(graph, &neighbors),
+    })
+}
+
+/// The reason a graph isn't a tree, forest, arborescence or branching.
+enum TreeViolation {
+    NullGraph,
+    MultipleParents(usize),
+    Cycle(Vec<(usize, usize)>),
+    Disconnected(usize),
+}
+
+/// The result of checking whether a graph is a tree, forest, arborescence or
+/// branching
+///
+/// A :class:`~retworkx.TreeCheck` is truthy if the graph passed the check, so
+/// it can be used as a ``bool``, and falsy if it didn't, in which case
+/// :attr:`~retworkx.TreeCheck.reason` and
+/// :attr:`~retworkx.TreeCheck.counterexample` tell why to help find the
+/// problem:
+///
+/// * ``"null graph"``: The graph has no nodes, the counterexample is
+///   ``None``
+/// * ``"multiple parents"``: A node has more than one incoming edge, the
+///   counterexample is the lowest index of such a node
+/// * ``"cycle"``: The graph has a cycle, ignoring the direction of its
+///   edges, the counterexample is an :class:`~retworkx.EdgeList` of the
+///   edges of a cycle in the order it traverses them, which can be against
+///   their direction
+/// * ``"disconnected"``: The graph isn't (weakly) connected, the
+///   counterexample is the index of a node not connected to the node with
+///   the lowest index
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_binomial_tree_graph(2)
+///   graph.add_edge(2, 1, None)
+///   check = retworkx.is_arborescence(graph)
+///   if not check:
+///       print(check.reason, check.counterexample)
+#[pyclass(module = "retworkx")]
+pub struct TreeCheck {
+    reason: Option<&'static str>,
+    counterexample: PyObject,
+}
+
+impl TreeCheck {
+    fn new(py: Python, violation: Option<TreeViolation>) -> Self {
+        let (reason, counterexample) = match violation {
+            None => (None, py.None()),
+            Some(TreeViolation::NullGraph) => (Some("null graph"), py.None()),
+            Some(TreeViolation::MultipleParents(node)) => {
+                (Some("multiple parents"), node.into_py(py))
+            }
+            Some(TreeViolation::Cycle(edges)) => (Some("cycle"), EdgeList { edges }.into_py(py)),
+            Some(TreeViolation::Disconnected(node)) => (Some("disconnected"), node.into_py(py)),
+        };
+        TreeCheck {
+            reason,
+            counterexample,
+        }
+    }
+}
+
+#[pymethods]
+impl TreeCheck {
+    /// The reason the graph failed the check, or ``None`` if it passed
+    #[getter]
+    fn reason(&self) -> Option<&'static str> {
+        self.reason
+    }
+
+    /// A counterexample for the reason the graph failed the check, or
+    /// ``None`` if it passed
+    #[getter]
+    fn counterexample(&self, py: Python) -> PyObject {
+        self.counterexample.clone_ref(py)
+    }
+
+    fn __bool__(&self) -> bool {
+        self.reason.is_none()
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(match self.reason {
+            None => "TreeCheck(True)".to_string(),
+            Some(reason) => format!(
+                "TreeCheck(reason='{}', counterexample={})",
+                reason,
+                self.counterexample.as_ref(py).str()?
+            ),
+        })
+    }
+}
+
+/// Find a cycle of a graph, ignoring edge directions, by adding its edges in
+/// index order to a forest until one connects two nodes already connected in
+/// it. Returns the cycle closed by that edge, starting with it, if there is
+/// one and otherwise the connected components of the graph.
+fn forest_components<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+) -> Result<UnionFind<usize>, TreeViolation> {
+    let node_bound = graph.node_bound();
+    let mut components: UnionFind<usize> = UnionFind::new(node_bound);
+    let mut forest: Vec<Vec<usize>> = vec![Vec::new(); node_bound];
+    for edge in graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if components.union(source, target) {
+            forest[source].push(target);
+            forest[target].push(source);
+            continue;
+        }
+        // Close the cycle along the path from target back to source in the
+        // forest, this is a single edge for a self loop
+        let (_, parents) = tree_bfs(&forest, source);
+        let mut cycle = vec![(source, target)];
+        let mut node = target;
+        while let Some(parent) = parents[node] {
+            cycle.push((node, parent));
+            node = parent;
+        }
+        return Err(TreeViolation::Cycle(cycle));
+    }
+    Ok(components)
+}
+
+/// Find a node which isn't connected to the node with the lowest index.
+fn disconnected_node<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    components: &UnionFind<usize>,
+) -> Option<TreeViolation> {
+    let mut nodes = graph.node_indices().map(|node| node.index());
+    let root = components.find(nodes.next()?);
+    nodes
+        .find(|node| components.find(*node) != root)
+        .map(TreeViolation::Disconnected)
+}
+
+/// Find the node with the lowest index with more than one incoming edge.
+fn multiple_parents(graph: &StablePyGraph<Directed>) -> Option<TreeViolation> {
+    graph
+        .node_indices()
+        .find(|node| graph.edges_directed(*node, Incoming).nth(1).is_some())
+        .map(|node| TreeViolation::MultipleParents(node.index()))
+}
+
+fn tree_violation<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> Option<TreeViolation> {
+    if graph.node_count() == 0 {
+        return Some(TreeViolation::NullGraph);
+    }
+    match forest_components(graph) {
+        Ok(components) => disconnected_node(graph, &components),
+        Err(cycle) => Some(cycle),
+    }
+}
+
+fn arborescence_violation(graph: &StablePyGraph<Directed>) -> Option<TreeViolation> {
+    if graph.node_count() == 0 {
+        return Some(TreeViolation::NullGraph);
+    }
+    multiple_parents(graph).or_else(|| tree_violation(graph))
+}
+
+fn branching_violation(graph: &StablePyGraph<Directed>) -> Option<TreeViolation> {
+    multiple_parents(graph).or_else(|| forest_components(graph).err())
+}
+
+/// Check if a :class:`~retworkx.PyGraph` is a tree
+///
+/// A tree is a connected graph without cycles. Self loops and parallel
+/// edges are cycles and a graph without nodes isn't a tree.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.binomial_tree_graph(3)
+///   print(bool(retworkx.graph_is_tree(graph)))
+///   graph.add_edge(1, 2, None)
+///   print(retworkx.graph_is_tree(graph))
+///
+/// :param PyGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     a tree. Otherwise it's falsy and its reason is ``"null graph"``,
+///     ``"cycle"`` or ``"disconnected"``, with a counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_is_tree(py: Python, graph: &graph::PyGraph) -> TreeCheck {
+    TreeCheck::new(py, tree_violation(&graph.graph))
+}
+
+/// Check if a :class:`~retworkx.PyDiGraph` is a tree, ignoring the direction
+/// of its edges
+///
+/// A tree is a connected graph without cycles. Self loops and parallel
+/// edges, including edges in opposite directions between the same nodes,
+/// are cycles and a graph without nodes isn't a tree. To also check that the
+/// edges are directed away from a root use
+/// :func:`~retworkx.is_arborescence`.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_binomial_tree_graph(3)
+///   print(bool(retworkx.digraph_is_tree(graph)))
+///   graph.add_edge(2, 1, None)
+///   print(retworkx.digraph_is_tree(graph))
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     a tree. Otherwise it's falsy and its reason is ``"null graph"``,
+///     ``"cycle"`` or ``"disconnected"``, with a counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_is_tree(py: Python, graph: &digraph::PyDiGraph) -> TreeCheck {
+    TreeCheck::new(py, tree_violation(&graph.graph))
+}
+
+/// Check if a :class:`~retworkx.PyGraph` is a forest
+///
+/// A forest is a graph without cycles, so each of its connected components
+/// is a tree. Self loops and parallel edges are cycles and a graph without
+/// nodes is a forest.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+///   print(bool(retworkx.graph_is_forest(graph)))
+///   graph.add_edge(2, 0, None)
+///   print(retworkx.graph_is_forest(graph))
+///
+/// :param PyGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     a forest. Otherwise it's falsy and its reason is ``"cycle"``, with
+///     the edges of a cycle as the counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_is_forest(py: Python, graph: &graph::PyGraph) -> TreeCheck {
+    TreeCheck::new(py, forest_components(&graph.graph).err())
+}
+
+/// Check if a :class:`~retworkx.PyDiGraph` is a forest, ignoring the
+/// direction of its edges
+///
+/// A forest is a graph without cycles, so each of its weakly connected
+/// components is a tree. Self loops and parallel edges, including edges in
+/// opposite directions between the same nodes, are cycles and a graph
+/// without nodes is a forest. To also check that the edges of each tree are
+/// directed away from a root use :func:`~retworkx.is_branching`.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+///   print(bool(retworkx.digraph_is_forest(graph)))
+///   graph.add_edge(0, 2, None)
+///   print(retworkx.digraph_is_forest(graph))
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     a forest. Otherwise it's falsy and its reason is ``"cycle"``, with
+///     the edges of a cycle as the counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_is_forest(py: Python, graph: &digraph::PyDiGraph) -> TreeCheck {
+    TreeCheck::new(py, forest_components(&graph.graph).err())
+}
+
+/// Check if a :class:`~retworkx.PyDiGraph` is an arborescence
+///
+/// An arborescence is a directed tree with all of its edges directed away
+/// from a root node, so every node other than the root has exactly one
+/// incoming edge. A graph without nodes isn't an arborescence.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.directed_binomial_tree_graph(3)
+///   print(bool(retworkx.is_arborescence(graph)))
+///   graph.add_edge(1, 2, None)
+///   print(retworkx.is_arborescence(graph))
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     an arborescence. Otherwise it's falsy and its reason is
+///     ``"null graph"``, ``"multiple parents"``, ``"cycle"`` or
+///     ``"disconnected"``, with a counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn is_arborescence(py: Python, graph: &digraph::PyDiGraph) -> TreeCheck {
+    TreeCheck::new(py, arborescence_violation(&graph.graph))
+}
+
+/// Check if a :class:`~retworkx.PyDiGraph` is a branching
+///
+/// A branching is a directed forest whose trees are arborescences, so no
+/// node has more than one incoming edge and there are no cycles, ignoring
+/// the direction of the edges. A graph without nodes is a branching.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (3, 4)])
+///   print(bool(retworkx.is_branching(graph)))
+///   graph.add_edge(4, 3, None)
+///   print(retworkx.is_branching(graph))
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: A :class:`~retworkx.TreeCheck` which is truthy if the graph is
+///     a branching. Otherwise it's falsy and its reason is
+///     ``"multiple parents"`` or ``"cycle"``, with a counterexample.
+/// :rtype: TreeCheck
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn is_branching(py: Python, graph: &digraph::PyDiGraph) -> TreeCheck {
+    TreeCheck::new(py, branching_violation(&graph.graph))
+}
@@ -0,0 +1,396 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+use std::convert::{Infallible, TryFrom};
+
+use hashbrown::HashSet;
+use rayon::prelude::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::graph::UnGraph;
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+
+use retworkx_core::dictmap::*;
+use retworkx_core::max_weight_matching::max_weight_matching;
+use retworkx_core::shortest_path::dijkstra_with_predecessors;
+
+use crate::iterators::NodeIndices;
+use crate::{graph, CostFn, GraphIx, InvalidNode, NoPathFound, NodeIndex};
+
+/// The minimum decrease of the tour cost for a local search move to be
+/// applied, this avoids cycling on floating point rounding errors.
+const IMPROVEMENT_TOLERANCE: f64 = 1e-9;
+
+/// The longest segment of the tour moved by an Or-opt move.
+const OR_OPT_MAX_SEGMENT: usize = 3;
+
+/// Build a tour with the nearest neighbor heuristic, starting at ``start``
+/// and repeatedly moving to the closest node not visited yet.
+fn nearest_neighbor_tour(distances: &[Vec<f64>], start: usize) -> Vec<usize> {
+    let node_count = distances.len();
+    let mut visited = vec![false; node_count];
+    let mut tour = Vec::with_capacity(node_count);
+    let mut current = start;
+    visited[current] = true;
+    tour.push(current);
+    while tour.len() < node_count {
+        let next = (0..node_count)
+            .filter(|node| !visited[*node])
+            .min_by(|a, b| {
+                distances[current][*a]
+                    .partial_cmp(&distances[current][*b])
+                    .unwrap_or(Ordering::Less)
+            })
+            .unwrap();
+        visited[next] = true;
+        tour.push(next);
+        current = next;
+    }
+    tour
+}
+
+/// Build a tour with Christofides' algorithm, starting at ``start``.
+///
+/// The odd degree nodes of a minimum spanning tree are paired by a minimum
+/// weight perfect matching, an Euler circuit of the tree and the matching is
+/// then shortcut to visit every node once. If the distances satisfy the
+/// triangle inequality the tour costs at most 1.5 times the optimal tour.
+fn christofides_tour(distances: &[Vec<f64>], start: usize) -> Vec<usize> {
+    let node_count = distances.len();
+    // Prim's algorithm on the complete graph of distances
+    let mut in_tree = vec![false; node_count];
+    let mut best: Vec<(f64, Option<usize>)> = vec![(f64::INFINITY, None); node_count];
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+    best[start].0 = 0.;
+    for _ in 0..node_count {
+        let node = (0..node_count)
+            .filter(|node| !in_tree[*node])
+            .min_by(|a, b| {
+                best[*a]
+                    .0
+                    .partial_cmp(&best[*b].0)
+                    .unwrap_or(Ordering::Less)
+            })
+            .unwrap();
+        in_tree[node] = true;
+        if let Some(parent) = best[node].1 {
+            adjacency[node].push(parent);
+            adjacency[parent].push(node);
+        }
+        for other in 0..node_count {
+            if !in_tree[other] && distances[node][other] < best[other].0 {
+                best[other] = (distances[node][other], Some(node));
+            }
+        }
+    }
+
+    let odd: Vec<usize> = (0..node_count)
+        .filter(|node| adjacency[*node].len() % 2 == 1)
+        .collect();
+    for (a, b) in minimum_weight_perfect_matching(distances, &odd) {
+        adjacency[a].push(b);
+        adjacency[b].push(a);
+    }
+
+    // Hierholzer's algorithm, every node has an even degree now so the
+    // circuit covers every edge.
+    let mut circuit: Vec<usize> = Vec::new();
+    let mut stack: Vec<usize> = vec![start];
+    while let Some(node) = stack.last().copied() {
+        match adjacency[node].pop() {
+            Some(next) => {
+                let pos = adjacency[next].iter().position(|x| *x == node).unwrap();
+                adjacency[next].swap_remove(pos);
+                stack.push(next);
+            }
+            None => {
+                circuit.push(node);
+                stack.pop();
+            }
+        }
+    }
+    circuit.reverse();
+
+    let mut visited = vec![false; node_count];
+    circuit
+        .into_iter()
+        .filter(|node| !std::mem::replace(&mut visited[*node], true))
+        .collect()
+}
+
+/// Pair the ``nodes`` so the sum of the distances between the pairs is
+/// minimal, ``nodes`` must have an even length.
+///
+/// The distances are converted to integer weights decreasing with the
+/// distance and paired with a maximum cardinality maximum weight matching.
+fn minimum_weight_perfect_matching(distances: &[Vec<f64>], nodes: &[usize]) -> Vec<(usize, usize)> {
+    let max_distance = nodes
+        .iter()
+        .flat_map(|a| nodes.iter().map(move |b| distances[*a][*b]))
+        .fold(0., f64::max);
+    let scale = if max_distance > 0. {
+        (1u64 << 52) as f64 / max_distance
+    } else {
+        0.
+    };
+    let mut complete = UnGraph::<(), i128, GraphIx>::with_capacity(nodes.len(), 0);
+    for _ in nodes {
+        complete.add_node(());
+    }
+    for i in 0..nodes.len() {
+        for j in i + 1..nodes.len() {
+            let weight = ((max_distance - distances[nodes[i]][nodes[j]]) * scale).round();
+            complete.add_edge(NodeIndex::new(i), NodeIndex::new(j), weight as i128 + 1);
+        }
+    }
+    let matching: Result<HashSet<(usize, usize)>, Infallible> =
+        max_weight_matching(&complete, true, |e| Ok(*e.weight()), false);
+    matching
+        .unwrap()
+        .into_iter()
+        .map(|(a, b)| (nodes[a], nodes[b]))
+        .collect()
+}
+
+/// Improve ``tour`` with 2-opt moves, reversing the part of the tour between
+/// two edges when reconnecting it is shorter, until no move is found. The
+/// first node of the tour is never moved.
+fn two_opt(distances: &[Vec<f64>], tour: &mut [usize]) {
+    let node_count = tour.len();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..node_count.saturating_sub(2) {
+            for j in i + 2..node_count {
+                let (a, b) = (tour[i], tour[i + 1]);
+                let (c, d) = (tour[j], tour[(j + 1) % node_count]);
+                if a == d {
+                    continue;
+                }
+                let delta = distances[a][c] + distances[b][d] - distances[a][b] - distances[c][d];
+                if delta < -IMPROVEMENT_TOLERANCE {
+                    tour[i + 1..=j].reverse();
+                    changed = true;
+                }
+            }
+        }
+    }
+}
+
+/// Improve ``tour`` with Or-opt moves, moving a segment of up to
+/// ``OR_OPT_MAX_SEGMENT`` consecutive nodes, possibly reversed, to another
+/// position in the tour when it's shorter. Returns whether any move was
+/// applied. The first node of the tour is never moved.
+fn or_opt(distances: &[Vec<f64>], tour: &mut Vec<usize>) -> bool {
+    let node_count = tour.len();
+    let mut improved = false;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        'segments: for length in 1..=OR_OPT_MAX_SEGMENT.min(node_count.saturating_sub(2)) {
+            for i in 1..=node_count - length {
+                let first = tour[i];
+                let last = tour[i + length - 1];
+                let prev = tour[i - 1];
+                let next = tour[(i + length) % node_count];
+                let removal_gain =
+                    distances[prev][first] + distances[last][next] - distances[prev][next];
+                for j in 0..node_count {
+                    // The edges touching the segment can't be used to insert it
+                    if j + 1 >= i && j < i + length {
+                        continue;
+                    }
+                    let (p, q) = (tour[j], tour[(j + 1) % node_count]);
+                    let forward = distances[p][first] + distances[last][q];
+                    let backward = distances[p][last] + distances[first][q];
+                    let insert_cost = forward.min(backward) - distances[p][q];
+                    if insert_cost - removal_gain < -IMPROVEMENT_TOLERANCE {
+                        let mut segment: Vec<usize> = tour.drain(i..i + length).collect();
+                        if backward < forward {
+                            segment.reverse();
+                        }
+                        let insert_at = if j < i { j + 1 } else { j + 1 - length };
+                        tour.splice(insert_at..insert_at, segment);
+                        changed = true;
+                        improved = true;
+                        continue 'segments;
+                    }
+                }
+            }
+        }
+    }
+    improved
+}
+
+/// Find a short tour visiting every node of a graph
+///
+/// This is a heuristic for the (symmetric) traveling salesman problem. The
+/// tour is built on the complete graph of the shortest path distances
+/// between the nodes of ``graph``, so the graph doesn't have to be complete,
+/// and then each step of the tour is expanded into a shortest path of
+/// ``graph``. If the graph isn't complete, or the edge weights don't satisfy
+/// the triangle inequality, nodes can be repeated in the tour.
+///
+/// The initial tour is built with either:
+///
+/// * ``"greedy"``: the nearest neighbor heuristic, starting at ``source``
+///   and repeatedly moving to the closest node not visited yet.
+/// * ``"christofides"``: Christofides' algorithm, which combines a minimum
+///   spanning tree with a minimum weight perfect matching of its odd degree
+///   nodes. The tour costs at most 1.5 times the optimal tour.
+///
+/// If ``improve`` is ``True`` the tour is then improved with 2-opt and
+/// Or-opt local search moves until neither finds a shorter tour.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   tour, cost = retworkx.traveling_salesman_problem(graph, default_weight=1.0)
+///   print(list(tour), cost)
+///
+/// :param PyGraph graph: The undirected graph to find a tour of
+/// :param weight_fn: An optional callable object that will be passed an
+///     edge's data payload and expected to return a non-negative ``float``
+///     for the weight of that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     float value will be used for the weight of each edge.
+/// :param str method: The heuristic to build the initial tour with, either
+///     ``"christofides"`` (the default) or ``"greedy"``
+/// :param bool improve: Whether to improve the tour with 2-opt and Or-opt
+///     moves, defaults to ``True``
+/// :param int source: The node index to start and end the tour at, if not
+///     specified the tour starts at the first node of the graph
+///
+/// :returns: A tuple of the tour and its cost. The tour is the list of node
+///     indices visited in order, starting and ending at ``source``. For a
+///     graph with a single node the tour is just that node, and for an empty
+///     graph it's empty.
+/// :rtype: tuple
+/// :raises ValueError: when ``method`` isn't a known heuristic or an edge
+///     weight is NaN or negative
+/// :raises InvalidNode: when ``source`` isn't a node in the graph
+/// :raises NoPathFound: when the graph isn't connected
+#[pyfunction(
+    weight_fn = "None",
+    default_weight = "1.0",
+    method = "\"christofides\"",
+    improve = "true",
+    source = "None"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, method=\"christofides\", improve=True, source=None)"
+)]
+pub fn traveling_salesman_problem(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    method: &str,
+    improve: bool,
+    source: Option<usize>,
+) -> PyResult<(NodeIndices, f64)> {
+    let build_tour = match method {
+        "christofides" => christofides_tour,
+        "greedy" => nearest_neighbor_tour,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown traveling salesman method '{}', expected 'christofides' or 'greedy'",
+                method
+            )))
+        }
+    };
+    let nodes: Vec<NodeIndex> = graph.graph.node_indices().collect();
+    let start = match source {
+        Some(source) => {
+            let source = NodeIndex::new(source);
+            if !graph.graph.contains_node(source) {
+                return Err(InvalidNode::new_err(
+                    "The source node index is not present in the graph",
+                ));
+            }
+            nodes.iter().position(|node| *node == source).unwrap()
+        }
+        None => 0,
+    };
+    if nodes.len() <= 1 {
+        return Ok((
+            NodeIndices {
+                nodes: nodes.iter().map(|node| node.index()).collect(),
+            },
+            0.,
+        ));
+    }
+
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?.prepare(py, &graph.graph)?;
+    let mut weights: Vec<f64> = vec![0.; graph.graph.edge_bound()];
+    for edge in graph.graph.edge_references() {
+        weights[edge.id().index()] = cost_fn.call_edge(py, edge.id(), edge.weight())?;
+    }
+
+    let (tour, cost) = py.allow_threads(|| -> PyResult<(Vec<usize>, f64)> {
+        let closure: Vec<(Vec<f64>, DictMap<NodeIndex, NodeIndex>)> = nodes
+            .par_iter()
+            .map(|node| {
+                let res: Result<(Vec<Option<f64>>, _), _> =
+                    dijkstra_with_predecessors(&graph.graph, *node, None, |e| {
+                        Ok::<f64, Infallible>(weights[e.id().index()])
+                    });
+                let (scores, predecessors) = res.unwrap();
+                let distances = nodes
+                    .iter()
+                    .map(|other| scores[other.index()].unwrap_or(f64::INFINITY))
+                    .collect();
+                (distances, predecessors)
+            })
+            .collect();
+        if let Some(unreachable) = closure[0].0.iter().position(|d| d.is_infinite()) {
+            return Err(NoPathFound::new_err(format!(
+                "No path between nodes {} and {}, the graph must be connected",
+                nodes[0].index(),
+                nodes[unreachable].index()
+            )));
+        }
+        let distances: Vec<Vec<f64>> = closure.iter().map(|(d, _)| d.clone()).collect();
+
+        let mut tour = build_tour(&distances, start);
+        if improve {
+            two_opt(&distances, &mut tour);
+            while or_opt(&distances, &mut tour) {
+                two_opt(&distances, &mut tour);
+            }
+        }
+        tour.push(start);
+
+        let cost = tour.windows(2).map(|leg| distances[leg[0]][leg[1]]).sum();
+        // Expand each leg into a shortest path by walking the predecessors
+        // back from the end of the leg.
+        let mut walk: Vec<usize> = vec![nodes[start].index()];
+        for leg in tour.windows(2) {
+            let predecessors = &closure[leg[0]].1;
+            let mut path: Vec<usize> = Vec::new();
+            let mut node = nodes[leg[1]];
+            while node != nodes[leg[0]] {
+                path.push(node.index());
+                node = predecessors[&node];
+            }
+            walk.extend(path.into_iter().rev());
+        }
+        Ok((walk, cost))
+    })?;
+    Ok((NodeIndices { nodes: tour }, cost))
+}
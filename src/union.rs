@@ -10,9 +10,11 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use crate::{digraph, find_node_by_weight, graph, StablePyGraph};
+use crate::clusters::Clusters;
+use crate::key_index::KeyIndexes;
+use crate::transaction::MutationLog;
+use crate::{digraph, find_node_by_weight, graph, NodeIndex, StablePyGraph};
 
-use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
 use petgraph::{algo, EdgeType};
 
@@ -136,9 +138,12 @@ pub fn graph_union(
     let out_graph = union(py, &first.graph, &second.graph, merge_nodes, merge_edges)?;
 
     Ok(graph::PyGraph {
-        graph: out_graph,
+        graph: out_graph.into(),
         node_removed: first.node_removed,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
 
@@ -184,10 +189,13 @@ pub fn digraph_union(
     let out_graph = union(py, &first.graph, &second.graph, merge_nodes, merge_edges)?;
 
     Ok(digraph::PyDiGraph {
-        graph: out_graph,
+        graph: out_graph.into(),
         cycle_state: algo::DfsSpace::default(),
         check_cycle: false,
         node_removed: first.node_removed,
         multigraph: true,
+        log: MutationLog::default(),
+        clusters: Clusters::default(),
+        indexes: KeyIndexes::default(),
     })
 }
@@ -0,0 +1,145 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use petgraph::algo;
+use petgraph::EdgeType;
+
+use crate::connectivity::digraph_find_cycle;
+use crate::iterators::{EdgeIndices, NodeIndices};
+use crate::{digraph, graph, parallel_edge_groups, self_loop_edges, StablePyGraph};
+
+/// Add the issues common to both graph types to ``out_dict``.
+fn validate_structure<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    out_dict: &PyDict,
+) -> PyResult<()> {
+    let self_loops: Vec<usize> = self_loop_edges(graph)
+        .into_iter()
+        .map(|edge| edge.index())
+        .collect();
+    if !self_loops.is_empty() {
+        out_dict.set_item("self_loops", EdgeIndices { edges: self_loops }.into_py(py))?;
+    }
+
+    let parallel_edges: Vec<Vec<usize>> = parallel_edge_groups(graph)
+        .into_iter()
+        .filter(|(_, edges)| edges.len() > 1)
+        .map(|(_, edges)| edges.into_iter().map(|edge| edge.index()).collect())
+        .collect();
+    if !parallel_edges.is_empty() {
+        out_dict.set_item("parallel_edges", parallel_edges)?;
+    }
+
+    let isolated_nodes: Vec<usize> = graph
+        .node_indices()
+        .filter(|node| graph.neighbors_undirected(*node).next().is_none())
+        .map(|node| node.index())
+        .collect();
+    if !isolated_nodes.is_empty() {
+        out_dict.set_item(
+            "isolated_nodes",
+            NodeIndices {
+                nodes: isolated_nodes,
+            }
+            .into_py(py),
+        )?;
+    }
+    Ok(())
+}
+
+/// Check a graph for common structural issues
+///
+/// This checks the graph for self loops, parallel edges and isolated nodes
+/// (nodes without any edges) and, if ``expect_dag`` is set, for a cycle. The
+/// issues can be fixed with :meth:`~retworkx.PyGraph.remove_self_loops`,
+/// :meth:`~retworkx.PyGraph.remove_parallel_edges` and
+/// :meth:`~retworkx.PyGraph.remove_isolates` (or the equivalent
+/// :class:`~retworkx.PyDiGraph` methods).
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyGraph()
+///   graph.add_nodes_from(range(4))
+///   graph.add_edges_from_no_data([(0, 1), (0, 1), (2, 2)])
+///   print(retworkx.validate(graph))
+///   graph.remove_parallel_edges()
+///   graph.remove_self_loops()
+///   graph.remove_isolates()
+///   print(retworkx.validate(graph))
+///
+/// :param graph: The graph to check, either a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`
+/// :param bool expect_dag: If set to ``True`` the graph is also checked for
+///     a cycle. This can only be set for a :class:`~retworkx.PyDiGraph`.
+///
+/// :returns: A dictionary with a key for each issue found, so it's empty if
+///     the graph has no issues. The keys are:
+///
+///     * ``self_loops``: The indices of the self loop edges as an
+///       :class:`~retworkx.EdgeIndices` object
+///     * ``parallel_edges``: The groups of parallel edges, as returned by
+///       :meth:`~retworkx.PyGraph.parallel_edge_groups`
+///     * ``isolated_nodes``: The indices of the isolated nodes as a
+///       :class:`~retworkx.NodeIndices` object. A node whose only edges are
+///       self loops isn't isolated.
+///     * ``cycle``: If ``expect_dag`` is set and the graph isn't a DAG, the
+///       edges of a cycle in the graph as an :class:`~retworkx.EdgeList`
+/// :rtype: dict
+///
+/// :raises TypeError: If ``graph`` isn't a :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph`, or if ``expect_dag`` is set for a
+///     :class:`~retworkx.PyGraph`
+#[pyfunction(expect_dag = "false")]
+#[pyo3(text_signature = "(graph, /, expect_dag=False)")]
+pub fn validate(py: Python, graph: &PyAny, expect_dag: bool) -> PyResult<PyObject> {
+    let out_dict = PyDict::new(py);
+    if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+        if expect_dag {
+            return Err(PyTypeError::new_err(
+                "expect_dag can only be set for a PyDiGraph",
+            ));
+        }
+        validate_structure(py, &graph.graph, out_dict)?;
+    } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+        validate_structure(py, &graph.graph, out_dict)?;
+        if expect_dag {
+            // A cycle is found from the lowest node of the first strongly
+            // connected component which has a cycle
+            let cyclic_component = algo::tarjan_scc(&graph.graph)
+                .into_iter()
+                .find(|component| {
+                    component.len() > 1 || graph.graph.contains_edge(component[0], component[0])
+                });
+            if let Some(component) = cyclic_component {
+                let source = component.into_iter().min().unwrap();
+                out_dict.set_item(
+                    "cycle",
+                    digraph_find_cycle(&graph, Some(source.index())).into_py(py),
+                )?;
+            }
+        }
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Invalid Input Type {} for graph",
+            graph.get_type()
+        )));
+    }
+    Ok(out_dict.into())
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+
+use petgraph::EdgeType;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::workspace;
+
+use crate::iterators::PathLengthMapping;
+use crate::shared_graph::SharedGraph;
+
+/// Reusable scratch buffers for repeated shortest path and traversal calls
+///
+/// Functions which take an optional ``workspace`` argument allocate their
+/// distance, predecessor and visited buffers for every call, which dominates
+/// the runtime when they're called thousands of times on the same graph. When
+/// a :class:`~retworkx.Workspace` is passed the buffers it holds are reused
+/// instead, only the entries written by the previous call are reset.
+///
+/// The buffers are keyed to a generation counter which is advanced whenever
+/// the workspace is used with a different graph or the graph was modified
+/// since the previous call, the buffers are then resized and fully cleared. A
+/// workspace can be used with any number of graphs but reusing it with the
+/// same graph is what avoids the allocations.
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(10, 10)
+///   workspace = retworkx.Workspace()
+///   lengths = [
+///       retworkx.dijkstra_shortest_path_lengths(
+///           graph, node, lambda _: 1.0, workspace=workspace
+///       )
+///       for node in graph.node_indices()
+///   ]
+///   print(lengths[0][99])
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "()")]
+pub struct Workspace {
+    pub workspace: workspace::Workspace<f64>,
+    generation: u64,
+    mutation_count: Option<u64>,
+}
+
+impl Workspace {
+    /// Return the generation to run a search on ``graph`` with, advancing it
+    /// if the last search was on a different graph or ``graph`` was mutated
+    /// since.
+    pub fn generation<Ty: EdgeType>(&mut self, graph: &SharedGraph<Ty>) -> u64 {
+        let mutation_count = graph.mutation_count();
+        if self.mutation_count != Some(mutation_count) {
+            self.generation += 1;
+            self.mutation_count = Some(mutation_count);
+        }
+        self.generation
+    }
+
+    /// Build the output of a shortest path lengths function from the last
+    /// search, in node index order and excluding the source node.
+    pub fn path_lengths(&self, source: usize, goal: Option<usize>) -> PathLengthMapping {
+        let path_lengths: DictMap<usize, f64> = match goal {
+            Some(goal) => self
+                .workspace
+                .distance(goal)
+                .map(|length| (goal, *length))
+                .into_iter()
+                .collect(),
+            None => {
+                let mut reached: Vec<usize> = self.workspace.reached().to_vec();
+                reached.sort_unstable();
+                reached
+                    .into_iter()
+                    .filter(|index| *index != source)
+                    .filter_map(|index| self.workspace.distance(index).map(|len| (index, *len)))
+                    .collect()
+            }
+        };
+        PathLengthMapping { path_lengths }
+    }
+}
+
+#[pymethods]
+impl Workspace {
+    #[new]
+    fn new() -> Self {
+        Workspace {
+            workspace: workspace::Workspace::new(),
+            generation: 0,
+            mutation_count: None,
+        }
+    }
+
+    /// The generation of the buffers, it's advanced every time they're
+    /// resized and cleared for a different graph.
+    #[getter(generation)]
+    fn get_generation(&self) -> u64 {
+        self.generation
+    }
+}
@@ -112,3 +112,11 @@ class TestAstarDigraph(unittest.TestCase):
                         edge_cost_fn=lambda _: invalid_weight,
                         estimate_cost_fn=lambda _: 0,
                     )
+
+    def test_astar_invalid_node(self):
+        g = retworkx.PyDAG()
+        a = g.add_node("A")
+        b = g.add_node("B")
+        g.add_edge(a, b, 7)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_astar_shortest_path(g, 42, lambda goal: goal == "B", lambda _: 1, lambda _: 0)
@@ -0,0 +1,66 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestBellmanFord(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from([(0, 1, 4.0), (0, 2, 2.0), (2, 1, -1.0), (1, 3, 1.0)])
+
+    def test_negative_weights(self):
+        res = retworkx.digraph_bellman_ford_shortest_path_lengths(self.graph, 0, float)
+        self.assertEqual({1: 1.0, 2: 2.0, 3: 2.0}, res)
+
+    def test_matches_dijkstra(self):
+        graph = retworkx.generators.directed_grid_graph(3, 3, weights=list(range(9)))
+        for i, edge in enumerate(graph.edge_indices()):
+            graph.update_edge_by_index(edge, float(i % 4 + 1))
+        self.assertEqual(
+            retworkx.digraph_dijkstra_shortest_path_lengths(graph, 0, float),
+            retworkx.digraph_bellman_ford_shortest_path_lengths(graph, 0, float),
+        )
+
+    def test_negative_cycle(self):
+        self.graph.add_edge(3, 2, -1.0)
+        with self.assertRaises(retworkx.NegativeCycleError) as cm:
+            retworkx.digraph_bellman_ford_shortest_path_lengths(self.graph, 0, float)
+        cycle = cm.exception.cycle
+        start = cycle.index(2)
+        self.assertEqual([2, 1, 3], cycle[start:] + cycle[:start])
+
+    def test_unreachable_negative_cycle(self):
+        self.graph.add_edges_from([(4, 0, 1.0), (0, 4, -2.0)])
+        with self.assertRaises(retworkx.NegativeCycleError):
+            retworkx.digraph_bellman_ford_shortest_path_lengths(self.graph, 0, float)
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (2, 2, -1.0)])
+        res = retworkx.digraph_bellman_ford_shortest_path_lengths(graph, 0, float)
+        self.assertEqual({1: 1.0}, res)
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_bellman_ford_shortest_path_lengths(self.graph, 42, float)
+
+    def test_nan_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_bellman_ford_shortest_path_lengths(
+                self.graph, 0, lambda _: float("nan")
+            )
+
+    def test_universal(self):
+        res = retworkx.bellman_ford_shortest_path_lengths(self.graph, 0, float)
+        self.assertEqual({1: 1.0, 2: 2.0, 3: 2.0}, res)
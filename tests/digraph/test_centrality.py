@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -86,6 +87,87 @@ class TestCentralityDiGraph(unittest.TestCase):
         self.assertEqual(expected, betweenness)
 
 
+    def test_betweenness_centrality_array(self):
+        betweenness = retworkx.digraph_betweenness_centrality_array(self.graph, endpoints=True)
+        expected = retworkx.digraph_betweenness_centrality(self.graph, endpoints=True)
+        self.assertEqual([expected[node] for node in range(4)], betweenness.tolist())
+
+    def test_betweenness_centrality_array_universal(self):
+        betweenness = retworkx.betweenness_centrality_array(self.graph, normalized=False)
+        expected = retworkx.betweenness_centrality(self.graph, normalized=False)
+        self.assertEqual([expected[node] for node in range(4)], betweenness.tolist())
+
+    def test_betweenness_centrality_edge_filter(self):
+        betweenness = retworkx.digraph_betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda _: False
+        )
+        self.assertEqual({0: 0.0, 1: 0.0, 2: 0.0, 3: 0.0}, betweenness)
+        self.graph.add_edge(self.a, self.d, 2)
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda weight: weight == 1
+        )
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.betweenness_centrality(self.graph, normalized=False), betweenness)
+
+    def test_betweenness_centrality_node_mask(self):
+        betweenness = retworkx.digraph_betweenness_centrality(self.graph, node_mask={self.d})
+        self.graph.remove_node(self.d)
+        self.assertEqual(retworkx.digraph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_node_mask_universal(self):
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, node_mask=[self.a, self.d]
+        )
+        self.assertEqual({self.b: 0.0, self.c: 0.0}, betweenness)
+
+    def test_betweenness_centrality_weighted(self):
+        self.graph.add_edge(self.a, self.d, 10)
+        betweenness = retworkx.digraph_betweenness_centrality(self.graph, weight_fn=float)
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.digraph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_weighted_uniform(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        expected = retworkx.digraph_betweenness_centrality(graph, endpoints=True)
+        for parallel_threshold in [0, 50]:
+            betweenness = retworkx.digraph_betweenness_centrality(
+                graph,
+                endpoints=True,
+                parallel_threshold=parallel_threshold,
+                weight_fn=lambda _: 0.5,
+            )
+            self.assertEqual(set(expected.keys()), set(betweenness.keys()))
+            for node in expected:
+                self.assertAlmostEqual(expected[node], betweenness[node])
+
+    def test_betweenness_centrality_weighted_equal_paths(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.5), (1, 3, 1.0), (0, 2, 0.5), (2, 3, 2.0)])
+        betweenness = retworkx.digraph_betweenness_centrality(
+            graph, normalized=False, weight_fn=float
+        )
+        self.assertEqual({0: 0.0, 1: 0.5, 2: 0.5, 3: 0.0}, betweenness)
+
+    def test_betweenness_centrality_default_weight(self):
+        betweenness = retworkx.digraph_betweenness_centrality(self.graph, default_weight=2.5)
+        self.assertEqual(retworkx.digraph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_weighted_universal(self):
+        self.graph.add_edge(self.a, self.d, 10)
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda w: w < 5, weight_fn=float
+        )
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.betweenness_centrality(self.graph, normalized=False), betweenness)
+
+    def test_betweenness_centrality_invalid_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_betweenness_centrality(self.graph, weight_fn=lambda _: -1.0)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_betweenness_centrality(self.graph, default_weight=float("nan"))
+
+
 class TestCentralityDiGraphDeletedNode(unittest.TestCase):
     def setUp(self):
         self.graph = retworkx.PyDiGraph()
@@ -128,3 +210,99 @@ class TestCentralityDiGraphDeletedNode(unittest.TestCase):
         )
         expected = {0: 0.0, 1: 2.0, 2: 2.0, 4: 0.0}
         self.assertEqual(expected, betweenness)
+
+    def test_betweenness_centrality_array(self):
+        betweenness = retworkx.digraph_betweenness_centrality_array(self.graph).tolist()
+        expected = retworkx.digraph_betweenness_centrality(self.graph)
+        self.assertEqual(5, len(betweenness))
+        self.assertTrue(math.isnan(betweenness[3]))
+        for node, value in expected.items():
+            self.assertEqual(value, betweenness[node])
+
+
+class TestClosenessCentralityDiGraph(unittest.TestCase):
+    def test_closeness_centrality(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        closeness = retworkx.digraph_closeness_centrality(graph)
+        expected = {0: 0.0, 1: 1 / 3, 2: 4 / 9, 3: 0.5}
+        self.assertEqual(set(expected), set(closeness))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_not_wf_improved(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        closeness = retworkx.digraph_closeness_centrality(graph, wf_improved=False)
+        expected = {0: 0.0, 1: 1.0, 2: 2 / 3, 3: 0.5}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_reverse(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(1, 0), (2, 1), (3, 2)])
+        closeness = retworkx.digraph_closeness_centrality(graph)
+        expected = {0: 0.5, 1: 4 / 9, 2: 1 / 3, 3: 0.0}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_weight_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 2.0), (1, 2, 3.0), (0, 2, 10.0)])
+        closeness = retworkx.digraph_closeness_centrality(graph, weight_fn=float)
+        expected = {0: 0.0, 1: 0.25, 2: 2 / 8}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_invalid_weight(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_closeness_centrality(graph, weight_fn=lambda _: float("nan"))
+
+    def test_closeness_centrality_universal(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        self.assertEqual(
+            retworkx.digraph_closeness_centrality(graph), retworkx.closeness_centrality(graph)
+        )
+
+
+class TestEigenvectorCentralityDiGraph(unittest.TestCase):
+    def test_eigenvector_centrality(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        graph.add_node(3)
+        graph.add_edge(2, 3, None)
+        centrality = retworkx.digraph_eigenvector_centrality(graph, tol=1e-9)
+        self.assertEqual({0, 1, 2, 3}, set(centrality))
+        for node in graph.node_indices():
+            self.assertAlmostEqual(0.5, centrality[node], places=6)
+
+    def test_eigenvector_centrality_uses_predecessors(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (0, 2)])
+        centrality = retworkx.digraph_eigenvector_centrality(graph, tol=1e-9)
+        for node in graph.node_indices():
+            self.assertAlmostEqual(1 / math.sqrt(3), centrality[node], places=6)
+        graph.remove_edge(0, 2)
+        graph.add_edge(2, 0, None)
+        centrality = retworkx.digraph_eigenvector_centrality(graph, tol=1e-9)
+        self.assertAlmostEqual(0.0, centrality[2], places=6)
+
+    def test_eigenvector_centrality_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 4.0), (1, 0, 1.0)])
+        centrality = retworkx.digraph_eigenvector_centrality(graph, weight_fn=float, tol=1e-9)
+        self.assertAlmostEqual(1 / math.sqrt(5), centrality[0], places=6)
+        self.assertAlmostEqual(2 / math.sqrt(5), centrality[1], places=6)
+
+    def test_eigenvector_centrality_no_convergence(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        with self.assertRaises(retworkx.ConvergenceError):
+            retworkx.digraph_eigenvector_centrality(graph, max_iter=5)
+
+    def test_eigenvector_centrality_universal(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        self.assertEqual(
+            retworkx.digraph_eigenvector_centrality(graph), retworkx.eigenvector_centrality(graph)
+        )
@@ -0,0 +1,129 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import copy
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestClusters(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(weights=list(range(6)))
+        self.graph.assign_cluster([0, 1, 2], "a")
+        self.graph.assign_cluster([3, 4], "b")
+
+    def test_clusters(self):
+        self.assertEqual({"a": [0, 1, 2], "b": [3, 4]}, self.graph.clusters())
+        self.assertEqual("a", self.graph.node_cluster(1))
+        self.assertIsNone(self.graph.node_cluster(5))
+        self.assertIsNone(self.graph.node_cluster(42))
+
+    def test_empty_graph(self):
+        graph = retworkx.PyDiGraph()
+        self.assertEqual({}, graph.clusters())
+        self.assertEqual({}, graph.cluster_subgraphs())
+        self.assertEqual(0, len(graph.quotient_graph()))
+
+    def test_reassign(self):
+        self.graph.assign_cluster([2, 5], "b")
+        self.assertEqual({"a": [0, 1], "b": [2, 3, 4, 5]}, self.graph.clusters())
+
+    def test_assign_invalid_node(self):
+        with self.assertRaises(IndexError):
+            self.graph.assign_cluster([5, 42], "c")
+        self.assertIsNone(self.graph.node_cluster(5))
+
+    def test_unassign(self):
+        self.graph.unassign_cluster([0, 3, 4, 5, 42])
+        self.assertEqual({"a": [1, 2]}, self.graph.clusters())
+
+    def test_remove_node(self):
+        self.graph.remove_node(1)
+        new_node = self.graph.add_node(None)
+        self.assertEqual(1, new_node)
+        self.assertIsNone(self.graph.node_cluster(new_node))
+        self.assertEqual({"a": [0, 2], "b": [3, 4]}, self.graph.clusters())
+
+    def test_compact(self):
+        self.graph.remove_nodes_from([0, 3])
+        self.graph.compact()
+        self.assertEqual({"a": [0, 1], "b": [2]}, self.graph.clusters())
+
+    def test_transaction_rollback(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(1)
+            self.graph.assign_cluster([5], "a")
+            self.graph.unassign_cluster([3])
+            self.graph.compact()
+            transaction.rollback()
+        self.assertEqual({"a": [0, 1, 2], "b": [3, 4]}, self.graph.clusters())
+
+    def test_copy_and_pickle(self):
+        for graph in [copy.copy(self.graph), pickle.loads(pickle.dumps(self.graph))]:
+            self.assertEqual({"a": [0, 1, 2], "b": [3, 4]}, graph.clusters())
+            graph.unassign_cluster([0])
+        self.assertEqual({"a": [0, 1, 2], "b": [3, 4]}, self.graph.clusters())
+
+    def test_cluster_subgraph(self):
+        subgraph = self.graph.cluster_subgraph("a")
+        self.assertIsInstance(subgraph, retworkx.PyDiGraph)
+        self.assertEqual([0, 1, 2], subgraph.nodes())
+        self.assertEqual([(0, 1), (1, 2)], subgraph.edge_list())
+
+    def test_cluster_subgraph_invalid_name(self):
+        with self.assertRaises(KeyError):
+            self.graph.cluster_subgraph("c")
+
+    def test_cluster_subgraphs(self):
+        subgraphs = self.graph.cluster_subgraphs()
+        self.assertEqual(["a", "b"], list(subgraphs))
+        self.assertEqual([3, 4], subgraphs["b"].nodes())
+        self.assertEqual([(0, 1)], subgraphs["b"].edge_list())
+
+    def test_quotient_graph(self):
+        quotient = self.graph.quotient_graph()
+        self.assertIsInstance(quotient, retworkx.PyDiGraph)
+        self.assertEqual(["a", "b", 5], quotient.nodes())
+        self.assertEqual([(0, 1, [None]), (1, 2, [None])], quotient.weighted_edge_list())
+
+    def test_quotient_graph_edge_weight_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 2, 1), (1, 2, 2), (1, 3, 3), (0, 1, 4)])
+        graph.assign_cluster([0, 1], "x")
+        graph.assign_cluster([2, 3], "y")
+        quotient = graph.quotient_graph(sum)
+        self.assertEqual([(0, 1, 6)], quotient.weighted_edge_list())
+
+    def test_hierarchy(self):
+        quotient = self.graph.quotient_graph()
+        quotient.assign_cluster([0, 1], "top")
+        top = quotient.quotient_graph(len)
+        self.assertEqual(["top", 5], top.nodes())
+        self.assertEqual([(0, 1, 1)], top.weighted_edge_list())
+
+    def test_quotient_graph_direction(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        graph.assign_cluster([0, 1], "x")
+        graph.assign_cluster([2, 3], "y")
+        quotient = graph.quotient_graph(len)
+        self.assertEqual([(0, 1, 1), (1, 0, 1)], quotient.weighted_edge_list())
+
+    def test_quotient_graph_of_dag(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        dag.assign_cluster([0, 2], "x")
+        quotient = dag.quotient_graph(len)
+        self.assertFalse(quotient.check_cycle)
+        self.assertEqual([(0, 1, 1), (1, 0, 1)], quotient.weighted_edge_list())
@@ -0,0 +1,66 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestCoarsen(unittest.TestCase):
+    def assertValidHierarchy(self, graph, levels):
+        fine = graph
+        for depth, (coarse, node_map) in enumerate(levels):
+            self.assertIsInstance(coarse, retworkx.PyDiGraph)
+            self.assertEqual(set(fine.node_indices()), set(node_map.keys()))
+            self.assertEqual(set(coarse.node_indices()), set(node_map.values()))
+            self.assertLess(len(coarse), len(fine))
+            fine_weights = [1] * len(fine) if depth == 0 else fine.nodes()
+            self.assertEqual(sum(fine_weights), sum(coarse.nodes()))
+            expected = {}
+            for source, target, weight in fine.weighted_edge_list():
+                source, target = node_map[source], node_map[target]
+                if source != target:
+                    weight = 1.0 if depth == 0 else weight
+                    expected[(source, target)] = expected.get((source, target), 0) + weight
+            edges = {(s, t): w for s, t, w in coarse.weighted_edge_list()}
+            self.assertEqual(len(edges), coarse.num_edges())
+            self.assertEqual(expected, edges)
+            fine = coarse
+
+    def test_grid(self):
+        graph = retworkx.generators.directed_grid_graph(8, 8)
+        levels = retworkx.digraph_coarsen(graph, seed=42)
+        self.assertValidHierarchy(graph, levels)
+        self.assertLessEqual(len(levels[-1][0]), 6)
+
+    def test_directions_kept(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        graph.add_edge(1, 0, None)
+        graph.add_edge(3, 2, None)
+        for seed in range(20):
+            levels = retworkx.digraph_coarsen(graph, max_levels=1, seed=seed)
+            self.assertValidHierarchy(graph, levels)
+            coarse, node_map = levels[0]
+            self.assertEqual({0: 0, 1: 0, 2: 1, 3: 1}, node_map)
+            self.assertEqual([(0, 1, 1.0), (1, 0, 1.0)], coarse.weighted_edge_list())
+
+    def test_dag(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2), (0, 2)])
+        levels = retworkx.digraph_coarsen(dag, seed=5)
+        self.assertValidHierarchy(dag, levels)
+        self.assertFalse(levels[0][0].check_cycle)
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        levels = retworkx.coarsen(graph, seed=42)
+        self.assertValidHierarchy(graph, levels)
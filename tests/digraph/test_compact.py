@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import unittest
+
+import retworkx
+
+
+class TestCompact(unittest.TestCase):
+    def test_compact(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(6)))
+        graph.remove_nodes_from([0, 3])
+        node_map, edge_map = graph.compact()
+        self.assertEqual({1: 0, 2: 1, 4: 2, 5: 3}, dict(node_map))
+        self.assertEqual({1: 0, 4: 1}, dict(edge_map))
+        self.assertEqual([0, 1, 2, 3], graph.node_indices())
+        self.assertEqual([0, 1], graph.edge_indices())
+        self.assertEqual([1, 2, 4, 5], graph.nodes())
+        self.assertEqual([(0, 1), (2, 3)], graph.edge_list())
+
+    def test_compact_no_removals(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(4)))
+        node_map, edge_map = graph.compact()
+        self.assertEqual({0: 0, 1: 1, 2: 2, 3: 3}, dict(node_map))
+        self.assertEqual({0: 0, 1: 1, 2: 2}, dict(edge_map))
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
+
+    def test_compact_removed_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b"), (1, 2, "c")])
+        graph.remove_edge_from_index(0)
+        node_map, edge_map = graph.compact()
+        self.assertEqual({1: 0, 2: 1}, dict(edge_map))
+        self.assertEqual([(0, 1, "b"), (1, 2, "c")], graph.weighted_edge_list())
+
+    def test_compact_empty(self):
+        graph = retworkx.PyDiGraph()
+        node_map, edge_map = graph.compact()
+        self.assertEqual({}, dict(node_map))
+        self.assertEqual({}, dict(edge_map))
+
+    def test_compact_new_nodes_after(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        graph.remove_nodes_from([0, 1])
+        graph.compact()
+        self.assertEqual(3, graph.add_node(None))
+
+    def test_compact_shared_ref(self):
+        graph = retworkx.PyDiGraph()
+        node_weight = {"a": 1}
+        graph.add_node("b")
+        graph.add_node(node_weight)
+        graph.remove_node(0)
+        graph.compact()
+        node_weight["b"] = 2
+        self.assertEqual({"a": 1, "b": 2}, graph[0])
+
+    def test_compact_check_cycle(self):
+        graph = retworkx.PyDiGraph(check_cycle=True)
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(1, 2), (2, 3)])
+        graph.remove_node(0)
+        graph.compact()
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            graph.add_edge(2, 0, None)
@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import gc
 import unittest
 
 import retworkx
@@ -53,3 +54,74 @@ class TestCopy(unittest.TestCase):
         graph_b.get_edge_data(0, 1)["edge"] = 162
         self.assertEqual(graph_b[0]["a"], 42)
         self.assertEqual(graph_a.get_edge_data(0, 1), {"edge": 162})
+
+
+class TestSnapshot(unittest.TestCase):
+    def test_snapshot_returns_graph(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        snapshot = graph.snapshot()
+        self.assertIsInstance(snapshot, retworkx.PyDiGraph)
+        self.assertEqual(graph.node_indices(), snapshot.node_indices())
+        self.assertEqual(graph.edge_list(), snapshot.edge_list())
+
+    def test_mutate_original(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        snapshot = graph.snapshot()
+        graph.remove_node(1)
+        graph.add_edge(0, 2, "new")
+        graph[0] = "changed"
+        self.assertEqual([0, 2, 3], graph.node_indices())
+        self.assertEqual([0, 1, 2, 3], snapshot.node_indices())
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], snapshot.edge_list())
+        self.assertIsNone(snapshot[0])
+        self.assertFalse(snapshot.has_edge(0, 2))
+
+    def test_mutate_snapshot(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        snapshot = graph.snapshot()
+        snapshot.add_node("new")
+        snapshot.remove_edge(0, 1)
+        self.assertEqual(4, len(graph))
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
+        self.assertEqual(5, len(snapshot))
+        self.assertEqual([(1, 2), (2, 3)], snapshot.edge_list())
+
+    def test_chained_snapshots(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        first = graph.snapshot()
+        second = first.snapshot()
+        first.add_node(None)
+        second.remove_node(0)
+        self.assertEqual([0, 1, 2], graph.node_indices())
+        self.assertEqual([0, 1, 2, 3], first.node_indices())
+        self.assertEqual([1, 2], second.node_indices())
+
+    def test_snapshot_shared_ref(self):
+        graph = retworkx.PyDiGraph()
+        node_a = graph.add_node({"a": 1})
+        node_b = graph.add_node({"b": 2})
+        graph.add_edge(node_a, node_b, {"edge": 1})
+        snapshot = graph.snapshot()
+        graph[0]["a"] = 42
+        snapshot.get_edge_data(0, 1)["edge"] = 162
+        self.assertEqual(snapshot[0]["a"], 42)
+        self.assertEqual(graph.get_edge_data(0, 1), {"edge": 162})
+
+    def test_snapshot_outlives_original(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph[1] = ["payload"]
+        snapshot = graph.snapshot()
+        del graph
+        gc.collect()
+        self.assertEqual(["payload"], snapshot[1])
+        snapshot.add_node(None)
+        self.assertEqual(4, len(snapshot))
+
+    def test_snapshot_not_in_transaction(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with graph.transaction() as transaction:
+            graph.remove_node(0)
+            snapshot = graph.snapshot()
+            transaction.rollback()
+        self.assertEqual([0, 1, 2], graph.node_indices())
+        self.assertEqual([1, 2], snapshot.node_indices())
@@ -0,0 +1,97 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestCSRSnapshot(unittest.TestCase):
+    def test_arrays(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 2, 3.0), (0, 1, 1.0), (2, 1, 2.0)])
+        snapshot = graph.to_csr_snapshot(weight_fn=float)
+        self.assertTrue(snapshot.directed)
+        self.assertEqual(3, len(snapshot))
+        self.assertTrue(np.array_equal(snapshot.indptr, np.array([0, 2, 2, 3])))
+        self.assertTrue(np.array_equal(snapshot.indices, np.array([1, 2, 1])))
+        self.assertTrue(np.array_equal(snapshot.weights, np.array([1.0, 3.0, 2.0])))
+
+    def test_bfs_distances_follow_direction(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        snapshot = graph.to_csr_snapshot()
+        self.assertEqual({2: 1.0, 3: 2.0}, snapshot.bfs_distances(1))
+        self.assertEqual({}, snapshot.bfs_distances(3))
+
+    def test_dijkstra_matches_graph(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list(
+            [
+                (0, 1, 7),
+                (0, 2, 9),
+                (0, 5, 14),
+                (1, 2, 10),
+                (1, 3, 15),
+                (2, 3, 11),
+                (2, 5, 2),
+                (3, 4, 6),
+                (5, 4, 9),
+                (4, 0, 1),
+            ]
+        )
+        graph.remove_node(1)
+        snapshot = graph.to_csr_snapshot(weight_fn=float)
+        for source in graph.node_indices():
+            expected = retworkx.digraph_dijkstra_shortest_path_lengths(graph, source, float)
+            self.assertEqual(expected, snapshot.dijkstra_shortest_path_lengths(source))
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        snapshot = graph.to_csr_snapshot()
+        with self.assertRaises(retworkx.InvalidNode):
+            snapshot.bfs_distances(3)
+
+    def test_betweenness_matches_graph(self):
+        graph = retworkx.generators.directed_grid_graph(4, 5, bidirectional=False)
+        graph.add_edges_from_no_data([(19, 0), (7, 3)])
+        snapshot = graph.to_csr_snapshot()
+        for normalized in (True, False):
+            for endpoints in (True, False):
+                expected = retworkx.digraph_betweenness_centrality(
+                    graph, normalized=normalized, endpoints=endpoints
+                )
+                result = snapshot.betweenness_centrality(
+                    normalized=normalized, endpoints=endpoints
+                )
+                for node, value in expected.items():
+                    self.assertAlmostEqual(value, result[node])
+
+    def test_pagerank_dangling_node(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, None)
+        ranks = graph.to_csr_snapshot().pagerank()
+        self.assertAlmostEqual(0.5 / 1.425, ranks[0], places=5)
+        self.assertAlmostEqual(1 - 0.5 / 1.425, ranks[1], places=5)
+
+    def test_pagerank_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (0, 2, 3.0), (1, 0, 1.0), (2, 0, 1.0)])
+        ranks = graph.to_csr_snapshot(weight_fn=float).pagerank()
+        self.assertAlmostEqual(1.0, sum(ranks.values()))
+        self.assertLess(ranks[1], ranks[2])
+        self.assertLess(ranks[2], ranks[0])
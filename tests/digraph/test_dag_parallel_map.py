@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import threading
+import unittest
+
+import retworkx
+
+
+class TestDAGParallelMap(unittest.TestCase):
+    def test_dataflow(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from([1, 2, 3, 4])
+        dag.add_edges_from_no_data([(0, 2), (1, 2), (2, 3)])
+        res = retworkx.dag_parallel_map(dag, lambda data, inputs: data + sum(inputs.values()))
+        self.assertEqual({0: 1, 1: 2, 2: 6, 3: 10}, res)
+        self.assertEqual([0, 1, 2, 3], list(res))
+
+    def test_predecessor_results(self):
+        dag = retworkx.generators.directed_path_graph(3)
+        dag.add_edge(0, 2, None)
+        dag.add_edge(0, 2, None)
+        calls = {}
+
+        def record(_data, inputs):
+            calls[len(calls)] = inputs
+            return len(calls) - 1
+
+        retworkx.dag_parallel_map(dag, record)
+        self.assertEqual({0: {}, 1: {0: 0}, 2: {0: 0, 1: 1}}, calls)
+
+    def test_layer_order(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from(range(7))
+        dag.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (4, 5), (3, 6), (5, 6)])
+        lock = threading.Lock()
+        finished = set()
+
+        def check(node, inputs):
+            with lock:
+                self.assertEqual(set(dag.predecessor_indices(node)), set(inputs))
+                self.assertTrue(set(inputs).issubset(finished))
+                finished.add(node)
+            return node
+
+        res = retworkx.dag_parallel_map(dag, check)
+        self.assertEqual({0, 4}, set(list(res)[:2]))
+        self.assertEqual({1, 2, 5}, set(list(res)[2:5]))
+        self.assertEqual([3, 6], list(res)[5:])
+
+    def test_payload(self):
+        dag = retworkx.PyDAG()
+        a = dag.add_node("a")
+        dag.add_child(a, "b", None)
+        res = retworkx.dag_parallel_map(dag, lambda data, inputs: "".join(inputs.values()) + data)
+        self.assertEqual({0: "a", 1: "ab"}, res)
+
+    def test_removed_node(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        dag.remove_node(1)
+        res = retworkx.dag_parallel_map(dag, lambda _data, inputs: len(inputs))
+        self.assertEqual({0: 0, 2: 0, 3: 1}, res)
+
+    def test_exception(self):
+        dag = retworkx.generators.directed_path_graph(3)
+        calls = []
+
+        def fail(data, inputs):
+            calls.append(data)
+            if inputs:
+                raise ValueError("failed")
+            return 1
+
+        with self.assertRaises(ValueError):
+            retworkx.dag_parallel_map(dag, fail)
+        self.assertEqual(2, len(calls))
+
+    def test_cycle(self):
+        dag = retworkx.generators.directed_cycle_graph(3)
+        calls = []
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.dag_parallel_map(dag, lambda data, inputs: calls.append(data))
+        self.assertEqual([], calls)
+
+    def test_empty(self):
+        self.assertEqual({}, retworkx.dag_parallel_map(retworkx.PyDAG(), lambda *args: None))
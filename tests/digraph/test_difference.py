@@ -0,0 +1,118 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+import retworkx
+
+
+class TestDifference(unittest.TestCase):
+    def setUp(self):
+        self.first = retworkx.PyDiGraph()
+        self.first.add_nodes_from(["app", "lib", "util", "log"])
+        self.first.extend_from_weighted_edge_list([(0, 1, "1.0"), (1, 2, "2.0"), (1, 3, "0.1")])
+        self.second = retworkx.PyDiGraph()
+        self.second.add_nodes_from(["app", "lib", "util", "net"])
+        self.second.extend_from_weighted_edge_list([(0, 1, "1.1"), (0, 2, "2.0"), (1, 3, "0.5")])
+
+    def test_difference_by_payload(self):
+        res = retworkx.digraph_difference(self.first, self.second)
+        self.assertEqual(res.nodes(), ["app", "lib", "util", "log"])
+        self.assertEqual(res.weighted_edge_list(), [(1, 2, "2.0"), (1, 3, "0.1")])
+
+    def test_difference_direction(self):
+        second = retworkx.PyDiGraph()
+        second.add_nodes_from(["app", "lib"])
+        second.add_edge(1, 0, None)
+        res = retworkx.digraph_difference(self.first, second)
+        self.assertEqual(res.edge_list(), [(0, 1), (1, 2), (1, 3)])
+
+    def test_difference_parallel_edges(self):
+        self.first.add_edge(0, 1, "again")
+        res = retworkx.digraph_difference(self.first, self.second)
+        self.assertEqual(res.edge_list(), [(1, 2), (1, 3)])
+
+    def test_difference_node_map(self):
+        res = retworkx.digraph_difference(self.first, self.second, node_map={3: 3})
+        self.assertEqual(res.edge_list(), [(0, 1), (1, 2), (1, 3)])
+        res = retworkx.digraph_difference(self.first, self.second, node_map={0: 0, 1: 1, 3: 3})
+        self.assertEqual(res.edge_list(), [(1, 2)])
+
+    def test_difference_invalid_node_map(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_difference(self.first, self.second, node_map={4: 0})
+
+    def test_symmetric_difference(self):
+        res = retworkx.digraph_symmetric_difference(self.first, self.second)
+        self.assertEqual(res.nodes(), ["app", "lib", "util", "log", "net"])
+        self.assertEqual(
+            sorted(res.weighted_edge_list()),
+            [(0, 2, "2.0"), (1, 2, "2.0"), (1, 3, "0.1"), (1, 4, "0.5")],
+        )
+
+    def test_symmetric_difference_is_symmetric(self):
+        forward = retworkx.digraph_symmetric_difference(self.first, self.second)
+        backward = retworkx.digraph_symmetric_difference(self.second, self.first)
+        self.assertTrue(retworkx.is_isomorphic(forward, backward, node_matcher=lambda a, b: a == b))
+
+    def test_universal_functions(self):
+        res = retworkx.difference(self.first, self.second)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual(res.edge_list(), [(1, 2), (1, 3)])
+        res = retworkx.symmetric_difference(self.first, self.second)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual(res.num_edges(), 4)
+
+
+class TestGraphDiff(unittest.TestCase):
+    def setUp(self):
+        self.old = retworkx.PyDiGraph()
+        self.old.add_nodes_from([("app", "1.0"), ("lib", "2.0"), ("util", "0.1")])
+        self.old.add_edges_from([(0, 1, "requires"), (1, 2, "requires")])
+        self.new = retworkx.PyDiGraph()
+        self.new.add_nodes_from([("lib", "2.1"), ("app", "1.0"), ("log", "0.3")])
+        self.new.add_edges_from([(1, 0, "requires"), (0, 2, "optional")])
+
+    def key(self, node):
+        return node[0]
+
+    def test_diff(self):
+        res = retworkx.graph_diff(self.old, self.new, node_key_fn=self.key)
+        self.assertEqual(list(res["nodes_added"]), [2])
+        self.assertEqual(list(res["nodes_removed"]), [2])
+        self.assertEqual(res["nodes_changed"], [(1, 0)])
+        self.assertEqual(list(res["edges_added"]), [1])
+        self.assertEqual(list(res["edges_removed"]), [1])
+        self.assertEqual(res["edges_changed"], [])
+        self.assertEqual(dict(res["node_map"]), {1: 0, 0: 1})
+
+    def test_diff_direction(self):
+        new = retworkx.PyDiGraph()
+        new.add_nodes_from(self.old.nodes())
+        new.add_edges_from([(1, 0, "requires"), (1, 2, "requires")])
+        res = retworkx.graph_diff(self.old, new)
+        self.assertEqual(list(res["edges_added"]), [0])
+        self.assertEqual(list(res["edges_removed"]), [0])
+
+    def test_diff_changed_edge(self):
+        new = self.old.copy()
+        new.update_edge_by_index(1, "optional")
+        res = retworkx.graph_diff(self.old, new, node_key_fn=self.key)
+        self.assertEqual(res["edges_changed"], [(1, 1)])
+        self.assertEqual(len(res["edges_added"]), 0)
+        self.assertEqual(len(res["edges_removed"]), 0)
+
+    def test_diff_key_fn_error(self):
+        def key_fn(_node):
+            raise KeyError
+
+        with self.assertRaises(KeyError):
+            retworkx.graph_diff(self.old, self.new, node_key_fn=key_fn)
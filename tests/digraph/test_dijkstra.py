@@ -70,6 +70,43 @@ class TestDijkstraDiGraph(unittest.TestCase):
         }
         self.assertEqual(expected, paths)
 
+    def test_dijkstra_shortest_path_edges(self):
+        edges = retworkx.digraph_dijkstra_shortest_path_edges(
+            self.graph, self.a, self.e, weight_fn=float
+        )
+        self.assertEqual([2, 5], edges)
+        edges = retworkx.dijkstra_shortest_path_edges(self.graph, self.a, self.f, weight_fn=float)
+        self.assertEqual([0, 6], edges)
+
+    def test_dijkstra_shortest_path_edges_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 5.0), (0, 1, 1.0), (1, 2, 1.0), (2, 1, 0.5)])
+        edges = retworkx.dijkstra_shortest_path_edges(graph, 0, 2, weight_fn=float)
+        self.assertEqual([1, 2], edges)
+
+    def test_dijkstra_shortest_path_edges_no_path(self):
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.dijkstra_shortest_path_edges(self.graph, self.f, self.a)
+
+    def test_dijkstra_shortest_path_edges_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dijkstra_shortest_path_edges(self.graph, 42, self.a)
+
+    def test_dijkstra_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_dijkstra_shortest_paths(self.graph, 42)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_dijkstra_shortest_path_lengths(self.graph, 42, lambda _: 1)
+
+    def test_dijkstra_removed_source(self):
+        graph = self.graph.copy()
+        graph.remove_node(self.a)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_dijkstra_shortest_paths(graph, self.a)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_dijkstra_shortest_path_lengths(graph, self.a, lambda _: 1)
+
     def test_dijkstra_path_with_weight_fn(self):
         paths = retworkx.digraph_dijkstra_shortest_paths(self.graph, self.a, weight_fn=lambda x: x)
         expected = {
@@ -312,3 +349,122 @@ class TestDijkstraDiGraph(unittest.TestCase):
                     retworkx.digraph_all_pairs_dijkstra_path_lengths(
                         graph, edge_cost_fn=lambda _: invalid_weight
                     )
+
+    def test_dijkstra_shortest_path_lengths_edge_filter(self):
+        lengths = retworkx.digraph_dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, edge_filter_fn=lambda x: x != 15 and x != 11
+        )
+        expected = {self.b: 7.0, self.c: 16.0, self.d: 14.0, self.e: 23.0, self.f: 29.0}
+        self.assertEqual(expected, lengths)
+
+    def test_dijkstra_shortest_paths_edge_filter(self):
+        paths = retworkx.dijkstra_shortest_paths(
+            self.graph,
+            self.a,
+            target=self.f,
+            weight_fn=float,
+            edge_filter_fn=lambda x: x != 15 and x != 11,
+        )
+        self.assertEqual({self.f: [self.a, self.d, self.e, self.f]}, paths)
+
+    def test_dijkstra_shortest_paths_edge_filter_as_undirected(self):
+        paths = retworkx.digraph_dijkstra_shortest_paths(
+            self.graph,
+            self.f,
+            target=self.a,
+            weight_fn=float,
+            as_undirected=True,
+            edge_filter_fn=lambda x: x < 10,
+        )
+        self.assertEqual({self.a: [self.f, self.e, self.d, self.c, self.a]}, paths)
+
+    def test_all_pairs_dijkstra_edge_filter(self):
+        filtered = self.graph.copy()
+        for edge, (source, target, weight) in self.graph.edge_index_map().items():
+            if weight in (9, 14):
+                filtered.remove_edge_from_index(edge)
+        lengths = retworkx.digraph_all_pairs_dijkstra_path_lengths(
+            self.graph, float, edge_filter_fn=lambda x: x not in (9, 14)
+        )
+        self.assertEqual(retworkx.all_pairs_dijkstra_path_lengths(filtered, float), lengths)
+        paths = retworkx.digraph_all_pairs_dijkstra_shortest_paths(
+            self.graph, float, edge_filter_fn=lambda x: x not in (9, 14)
+        )
+        self.assertEqual(retworkx.all_pairs_dijkstra_shortest_paths(filtered, float), paths)
+
+    def test_shortest_path_between_sets(self):
+        path = retworkx.digraph_shortest_path_between_sets(
+            self.graph, [self.a, self.d], [self.c, self.f], weight_fn=float
+        )
+        self.assertEqual([self.d, self.c], path)
+        path = retworkx.digraph_shortest_path_between_sets(
+            self.graph, [self.a], [self.e, self.f], weight_fn=float
+        )
+        self.assertEqual([self.a, self.b, self.f], path)
+
+    def test_shortest_path_between_sets_matches_pairwise(self):
+        sources = [self.a, self.b]
+        targets = [self.e, self.f]
+        path = retworkx.digraph_shortest_path_between_sets(self.graph, sources, targets)
+        self.assertIn(path[0], sources)
+        self.assertIn(path[-1], targets)
+        expected = min(
+            retworkx.digraph_dijkstra_shortest_path_lengths(self.graph, source, lambda _: 1.0)[
+                target
+            ]
+            for source in sources
+            for target in targets
+        )
+        self.assertEqual(expected, len(path) - 1)
+
+    def test_shortest_path_between_sets_overlap(self):
+        path = retworkx.digraph_shortest_path_between_sets(
+            self.graph, [self.a, self.b], [self.b, self.f]
+        )
+        self.assertEqual([self.b], path)
+
+    def test_shortest_path_between_sets_no_path(self):
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.digraph_shortest_path_between_sets(self.graph, [self.f], [self.a, self.b])
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.digraph_shortest_path_between_sets(self.graph, [self.a], [])
+
+    def test_shortest_path_between_sets_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_shortest_path_between_sets(self.graph, [42], [self.a])
+
+    def test_shortest_path_between_sets_universal(self):
+        path = retworkx.shortest_path_between_sets(
+            self.graph, [self.a, self.d], [self.c, self.f], weight_fn=float
+        )
+        self.assertEqual([self.d, self.c], path)
+
+    def test_dijkstra_shortest_paths_node_mask(self):
+        paths = retworkx.digraph_dijkstra_shortest_paths(
+            self.graph, self.a, target=self.f, weight_fn=float, node_mask={self.c}
+        )
+        self.assertEqual({self.f: [self.a, self.b, self.f]}, paths)
+
+    def test_dijkstra_shortest_path_lengths_node_mask(self):
+        lengths = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, node_mask=[self.c]
+        )
+        self.assertEqual({self.b: 7.0, self.d: 14.0, self.e: 23.0, self.f: 22.0}, lengths)
+
+    def test_dijkstra_shortest_path_lengths_masked_source(self):
+        lengths = retworkx.digraph_dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, node_mask={self.a}
+        )
+        self.assertEqual({}, lengths)
+
+    def test_all_pairs_dijkstra_node_mask(self):
+        masked = self.graph.copy()
+        masked.remove_node(self.c)
+        lengths = retworkx.all_pairs_dijkstra_path_lengths(self.graph, float, node_mask={self.c})
+        self.assertEqual(retworkx.all_pairs_dijkstra_path_lengths(masked, float), lengths)
+        paths = retworkx.all_pairs_dijkstra_shortest_paths(self.graph, float, node_mask={self.c})
+        self.assertEqual(retworkx.all_pairs_dijkstra_shortest_paths(masked, float), paths)
+
+    def test_dijkstra_node_mask_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dijkstra_shortest_paths(self.graph, self.a, node_mask={42})
@@ -0,0 +1,129 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestImmediateDominators(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 1), (4, 5)])
+
+    def test_loop(self):
+        res = retworkx.immediate_dominators(self.graph, 0)
+        self.assertEqual({0: 0, 1: 0, 2: 1, 3: 1, 4: 1, 5: 4}, res)
+
+    def test_path(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        res = retworkx.immediate_dominators(graph, 0)
+        self.assertEqual({0: 0, 1: 0, 2: 1, 3: 2}, res)
+
+    def test_unreachable_nodes(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        res = retworkx.immediate_dominators(graph, 2)
+        self.assertEqual({2: 2, 3: 2}, res)
+
+    def test_irreducible(self):
+        # Cooper, Harvey, and Kennedy figure 4
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(5, 4), (5, 3), (4, 1), (3, 2), (1, 2), (2, 1)])
+        res = retworkx.immediate_dominators(graph, 5)
+        self.assertEqual({1: 5, 2: 5, 3: 5, 4: 5, 5: 5}, res)
+
+    def test_lengauer_tarjan_example(self):
+        graph = retworkx.PyDiGraph()
+        nodes = "RABCDEFGHIJKL"
+        graph.add_nodes_from(list(nodes))
+        edges = [
+            "RA",
+            "RB",
+            "RC",
+            "AD",
+            "BA",
+            "BD",
+            "BE",
+            "CF",
+            "CG",
+            "DL",
+            "EH",
+            "FI",
+            "GI",
+            "GJ",
+            "HE",
+            "HK",
+            "IK",
+            "JI",
+            "KI",
+            "KR",
+            "LH",
+        ]
+        graph.add_edges_from_no_data([(nodes.index(u), nodes.index(v)) for u, v in edges])
+        res = retworkx.immediate_dominators(graph, 0)
+        expected = {
+            "R": "R",
+            "A": "R",
+            "B": "R",
+            "C": "R",
+            "D": "R",
+            "E": "R",
+            "F": "C",
+            "G": "C",
+            "H": "R",
+            "I": "R",
+            "J": "G",
+            "K": "R",
+            "L": "D",
+        }
+        self.assertEqual(expected, {nodes[node]: nodes[idom] for node, idom in res.items()})
+
+    def test_invalid_root(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.immediate_dominators(self.graph, 42)
+
+
+class TestDominanceFrontiers(unittest.TestCase):
+    def test_loop(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 1), (4, 5)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        expected = {0: set(), 1: {1}, 2: {4}, 3: {4}, 4: {1}, 5: set()}
+        self.assertEqual(expected, res)
+
+    def test_diamond(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: set(), 1: {3}, 2: {3}, 3: set()}, res)
+
+    def test_back_edge_to_root(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: {0}, 1: {0}, 2: {0}}, res)
+
+    def test_unreachable_predecessor(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 2)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: set(), 1: set(), 2: set()}, res)
+
+    def test_invalid_root(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.remove_node(0)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dominance_frontiers(graph, 0)
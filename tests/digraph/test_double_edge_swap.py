@@ -0,0 +1,55 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestDoubleEdgeSwap(unittest.TestCase):
+    def degrees(self, graph):
+        return [(graph.in_degree(node), graph.out_degree(node)) for node in graph.node_indices()]
+
+    def test_degrees_kept(self):
+        graph = retworkx.generators.directed_grid_graph(6, 6)
+        graph.add_edges_from_no_data([(35, 0), (20, 3), (3, 20)])
+        degrees = self.degrees(graph)
+        edges = graph.edge_list()
+        self.assertEqual(100, retworkx.digraph_double_edge_swap(graph, nswap=100, seed=42))
+        self.assertEqual(degrees, self.degrees(graph))
+        self.assertNotEqual(edges, graph.edge_list())
+        self.assertEqual(len(graph.edge_list()), len(set(graph.edge_list())))
+        self.assertTrue(all(source != target for source, target in graph.edge_list()))
+
+    def test_dag_stays_acyclic(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(20))
+        dag.add_edges_from_no_data(
+            [(source, target) for source in range(20) for target in range(source + 1, 20, 3)]
+        )
+        degrees = self.degrees(dag)
+        swaps = retworkx.digraph_double_edge_swap(dag, nswap=200, seed=42)
+        self.assertGreater(swaps, 0)
+        self.assertTrue(retworkx.is_directed_acyclic_graph(dag))
+        self.assertEqual(degrees, self.degrees(dag))
+
+    def test_cycle_allowed_without_check(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        self.assertEqual(1, retworkx.digraph_double_edge_swap(graph, seed=42))
+        self.assertEqual({(0, 3), (2, 1)}, set(graph.edge_list()))
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        degrees = self.degrees(graph)
+        self.assertEqual(10, retworkx.double_edge_swap(graph, nswap=10, seed=42))
+        self.assertEqual(degrees, self.degrees(graph))
@@ -12,6 +12,8 @@
 
 import unittest
 
+import numpy as np
+
 import retworkx
 
 
@@ -302,6 +304,58 @@ class TestEdges(unittest.TestCase):
         res = dag.add_edges_from_no_data([])
         self.assertEqual([], res)
 
+    def test_add_edges_from_array(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        res = graph.add_edges_from_array(np.array([0, 1, 2]), np.array([1, 2, 3]))
+        self.assertEqual([0, 1, 2], res)
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
+        self.assertEqual([None, None, None], graph.edges())
+
+    def test_add_edges_from_array_weights(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_array(
+            np.array([0, 1], dtype=np.int32), np.array([1, 2]), np.array([0.5, 1.5])
+        )
+        self.assertEqual([(0, 1, 0.5), (1, 2, 1.5)], graph.weighted_edge_list())
+        self.assertIsInstance(graph.edges()[0], float)
+
+    def test_add_edges_from_array_object_weights(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_array(np.array([0, 1]), np.array([1, 2]), ["a", "b"])
+        self.assertEqual([(0, 1, "a"), (1, 2, "b")], graph.weighted_edge_list())
+
+    def test_add_edges_from_array_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([0, 1])
+        res = graph.add_edges_from_array(np.array([0, 1]), np.array([1, 0]))
+        self.assertEqual([0, 1], res)
+
+    def test_add_edges_from_array_cycle_check(self):
+        graph = retworkx.PyDiGraph(check_cycle=True)
+        graph.add_nodes_from(range(3))
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            graph.add_edges_from_array(np.array([0, 1, 2]), np.array([1, 2, 0]))
+
+    def test_add_edges_from_array_invalid_node(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        with self.assertRaises(IndexError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1, 5]))
+        with self.assertRaises(IndexError):
+            graph.add_edges_from_array(np.array([-1]), np.array([1]))
+        self.assertEqual(0, graph.num_edges())
+
+    def test_add_edges_from_array_length_mismatch(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        with self.assertRaises(ValueError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1]))
+        with self.assertRaises(ValueError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1, 2]), [1.0])
+
     def test_cycle_checking_at_init_nodes_from_no_data(self):
         dag = retworkx.PyDAG(True)
         node_a = dag.add_node("a")
@@ -633,6 +687,69 @@ class TestEdges(unittest.TestCase):
         graph = retworkx.PyDiGraph()
         self.assertFalse(graph.has_parallel_edges())
 
+    def test_selfloops(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1), (1, 2), (1, 1)])
+        self.assertEqual(3, graph.number_of_selfloops())
+        self.assertEqual([0, 2, 4], graph.selfloop_edges())
+        graph.remove_edge_from_index(2)
+        self.assertEqual(2, graph.number_of_selfloops())
+        self.assertEqual([0, 4], graph.selfloop_edges())
+
+    def test_selfloops_empty(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edge(0, 1, None)
+        self.assertEqual(0, graph.number_of_selfloops())
+        self.assertEqual([], graph.selfloop_edges())
+
+    def test_parallel_edge_groups(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (0, 1, "c"), (0, 1, "d"), (1, 2, "e")])
+        graph.add_edge(2, 0, "f")
+        self.assertEqual([[0, 2, 3], [1, 4]], graph.parallel_edge_groups())
+
+    def test_parallel_edge_groups_no_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        self.assertEqual([], graph.parallel_edge_groups())
+
+    def test_edge_multiplicities(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 1), (2, 1)])
+        self.assertEqual({(0, 1): 2, (1, 2): 1, (2, 1): 1}, graph.edge_multiplicities())
+
+    def test_edge_multiplicities_empty(self):
+        graph = retworkx.PyDiGraph()
+        self.assertEqual({}, graph.edge_multiplicities())
+
+    def test_merge_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 1, 3), (0, 1, 4)])
+        graph.merge_parallel_edges(lambda a, b: a + b)
+        self.assertFalse(graph.has_parallel_edges())
+        self.assertEqual([0, 1], graph.edge_indices())
+        self.assertEqual([(0, 1, 8), (1, 2, 2)], graph.weighted_edge_list())
+
+    def test_merge_parallel_edges_no_merge_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b")])
+        graph.merge_parallel_edges()
+        self.assertEqual([(0, 1, "a")], graph.weighted_edge_list())
+
+    def test_merge_parallel_edges_merge_fn_error(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, 2)])
+        with self.assertRaises(TypeError):
+            graph.merge_parallel_edges(lambda a, b: a + b)
+
     def test_get_edge_data_by_index(self):
         graph = retworkx.PyDiGraph()
         edge_list = [
@@ -870,6 +987,13 @@ class TestEdgesMultigraphFalse(unittest.TestCase):
         self.assertEqual([0, 0], res)
         self.assertEqual([None], graph.edges())
 
+    def test_add_edges_from_array_parallel_edges(self):
+        graph = retworkx.PyDiGraph(multigraph=False)
+        graph.add_nodes_from([0, 1])
+        res = graph.add_edges_from_array(np.array([0, 0]), np.array([1, 1]), [False, True])
+        self.assertEqual([0, 0], res)
+        self.assertEqual([True], graph.edges())
+
     def test_extend_from_weighted_edge_list_empty(self):
         graph = retworkx.PyDiGraph()
         graph.extend_from_weighted_edge_list([])
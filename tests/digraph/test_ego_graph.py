@@ -0,0 +1,59 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import unittest
+
+import retworkx
+
+
+class TestEgoGraph(unittest.TestCase):
+    def test_ego_graph_radius_one(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(5)))
+        ego = retworkx.ego_graph(graph, 2)
+        self.assertIsInstance(ego, retworkx.PyDiGraph)
+        self.assertEqual([2, 3], ego.nodes())
+        self.assertEqual([(0, 1)], ego.edge_list())
+
+    def test_ego_graph_undirected(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(5)))
+        ego = retworkx.ego_graph(graph, 2, undirected=True)
+        self.assertIsInstance(ego, retworkx.PyDiGraph)
+        self.assertEqual([1, 2, 3], ego.nodes())
+        self.assertEqual([(0, 1), (1, 2)], ego.edge_list())
+
+    def test_ego_graph_radius_two(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(5)))
+        ego = retworkx.digraph_ego_graph(graph, 0, radius=2)
+        self.assertEqual([0, 1, 2], ego.nodes())
+
+    def test_ego_graph_distance_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 1.0), (3, 0, 0.5)])
+        ego = retworkx.digraph_ego_graph(graph, 0, radius=2.0, distance_fn=float)
+        self.assertEqual(["a", "b"], ego.nodes())
+        ego = retworkx.digraph_ego_graph(graph, 0, radius=2.0, distance_fn=float, undirected=True)
+        self.assertEqual(["a", "b", "d"], ego.nodes())
+        self.assertEqual([(0, 1, 1.5), (2, 0, 0.5)], ego.weighted_edge_list())
+
+    def test_ego_graph_removed_nodes(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(5)))
+        graph.remove_node(0)
+        ego = retworkx.ego_graph(graph, 1, radius=2)
+        self.assertEqual([1, 2, 3], ego.nodes())
+
+    def test_ego_graph_invalid_node(self):
+        graph = retworkx.generators.directed_path_graph(weights=list(range(3)))
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.ego_graph(graph, 42)
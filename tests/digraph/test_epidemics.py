@@ -0,0 +1,35 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestEpidemicSimulation(unittest.TestCase):
+    def test_follows_edge_direction(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        counts, states = retworkx.epidemic_simulation(
+            graph, [2], default_transmission=1.0, recovery_rate=1.0, max_steps=5
+        )
+        np.testing.assert_array_equal(states, np.array([[0, 0, 2, 2, 2]]))
+        np.testing.assert_array_equal(counts[0, -1], [2, 0, 3])
+
+    def test_shapes(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        counts, states = retworkx.digraph_epidemic_simulation(
+            graph, [0], model="SIS", num_trials=3, max_steps=8, seed=42
+        )
+        self.assertEqual(counts.shape, (3, 9, 3))
+        self.assertEqual(states.shape, (3, 16))
+        np.testing.assert_array_equal(counts.sum(axis=2), np.full((3, 9), 16))
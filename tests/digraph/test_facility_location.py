@@ -0,0 +1,51 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestFacilityLocation(unittest.TestCase):
+    def test_p_median(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        centers, assignment, cost = retworkx.p_median(graph, 1, seed=42)
+        self.assertEqual([0], centers)
+        self.assertEqual(6.0, cost)
+        self.assertEqual({0: 0, 1: 0, 2: 0, 3: 0}, dict(assignment))
+
+    def test_k_center(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        centers, _, cost = retworkx.k_center(graph, 2, seed=42)
+        self.assertEqual(1.0, cost)
+        self.assertIn(0, centers)
+
+    def test_distances_follow_edges(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        _, assignment, cost = retworkx.p_median(graph, 1, seed=42)
+        self.assertEqual(6.0, cost)
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.remove_edge(0, 1)
+        graph.add_edge(1, 0, None)
+        centers, _, cost = retworkx.p_median(graph, 1, seed=42)
+        self.assertEqual([1], centers)
+        self.assertEqual(2.0, cost)
+
+    def test_unreachable(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 2), (1, 2)])
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.k_center(graph, 1)
+        centers, _, cost = retworkx.k_center(graph, 2, seed=42)
+        self.assertEqual([0, 1], centers)
+        self.assertEqual(1.0, cost)
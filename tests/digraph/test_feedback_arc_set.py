@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestFeedbackArcSet(unittest.TestCase):
+    def assertValidFeedbackArcSet(self, graph, edges, order):
+        order = list(order)
+        self.assertEqual(sorted(order), sorted(graph.node_indices()))
+        position = {node: index for index, node in enumerate(order)}
+        for edge in graph.edge_indices():
+            source, target = graph.get_edge_endpoints_by_index(edge)
+            self.assertEqual(position[source] >= position[target], edge in edges)
+        acyclic = graph.copy()
+        for edge in edges:
+            acyclic.remove_edge_from_index(edge)
+        self.assertTrue(retworkx.is_directed_acyclic_graph(acyclic))
+
+    def test_empty(self):
+        edges, order = retworkx.feedback_arc_set(retworkx.PyDiGraph())
+        self.assertEqual([], list(edges))
+        self.assertEqual([], list(order))
+
+    def test_dag(self):
+        graph = retworkx.generators.directed_grid_graph(3, 4)
+        edges, order = retworkx.feedback_arc_set(graph)
+        self.assertEqual([], list(edges))
+        self.assertValidFeedbackArcSet(graph, edges, order)
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(5)
+        edges, order = retworkx.feedback_arc_set(graph)
+        self.assertEqual(1, len(edges))
+        self.assertValidFeedbackArcSet(graph, edges, order)
+
+    def test_overlapping_cycles(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0), (2, 3), (3, 1)])
+        edges, order = retworkx.feedback_arc_set(graph)
+        self.assertEqual([(1, 2)], [graph.get_edge_endpoints_by_index(edge) for edge in edges])
+        self.assertEqual([2, 0, 3, 1], list(order))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        self_loop = graph.add_edge(1, 1, None)
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 0)])
+        edges, order = retworkx.feedback_arc_set(graph)
+        self.assertIn(self_loop, edges)
+        self.assertEqual(2, len(edges))
+        self.assertValidFeedbackArcSet(graph, edges, order)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.directed_cycle_graph(6, bidirectional=True)
+        graph.remove_node(2)
+        edges, order = retworkx.feedback_arc_set(graph)
+        self.assertValidFeedbackArcSet(graph, edges, order)
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.directed_gnp_random_graph(30, 0.2, seed=seed)
+            edges, order = retworkx.feedback_arc_set(graph)
+            self.assertValidFeedbackArcSet(graph, edges, order)
+            self.assertLessEqual(len(edges), graph.num_edges() / 2)
@@ -0,0 +1,58 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def is_acyclic(graph, removed):
+    remaining = graph.copy()
+    remaining.remove_nodes_from(list(removed))
+    return retworkx.is_directed_acyclic_graph(remaining)
+
+
+class TestFeedbackVertexSet(unittest.TestCase):
+    def assertMinimalFeedbackVertexSet(self, graph, nodes):
+        self.assertTrue(is_acyclic(graph, nodes))
+        for node in nodes:
+            self.assertFalse(is_acyclic(graph, nodes - {node}))
+
+    def test_empty(self):
+        res = retworkx.feedback_vertex_set(retworkx.PyDiGraph())
+        self.assertIsInstance(res, set)
+        self.assertEqual(set(), res)
+
+    def test_dag(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        self.assertEqual(set(), retworkx.digraph_feedback_vertex_set(graph))
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(5)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertEqual(1, len(res))
+
+    def test_bidirectional_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4, bidirectional=True)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertEqual({0, 2}, res)
+
+    def test_self_loop(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.add_edge(1, 1, None)
+        self.assertEqual({1}, retworkx.feedback_vertex_set(graph))
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.directed_gnp_random_graph(20, 0.15, seed=seed)
+            res = retworkx.feedback_vertex_set(graph)
+            self.assertMinimalFeedbackVertexSet(graph, res)
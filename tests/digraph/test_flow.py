@@ -0,0 +1,151 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import unittest
+
+import retworkx
+
+
+class TestMaximumFlow(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 4), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)]
+        )
+
+    def assertValidFlow(self, graph, source, sink, value, flows, capacity_fn=float):
+        balance = {node: 0.0 for node in graph.node_indices()}
+        self.assertEqual(set(graph.edge_indices()), set(flows.keys()))
+        for edge, flow in flows.items():
+            node_a, node_b = graph.get_edge_endpoints_by_index(edge)
+            self.assertGreaterEqual(flow, 0.0)
+            self.assertLessEqual(flow, capacity_fn(graph.get_edge_data_by_index(edge)))
+            balance[node_a] -= flow
+            balance[node_b] += flow
+        for node, total in balance.items():
+            if node not in (source, sink):
+                self.assertEqual(0.0, total)
+        self.assertEqual(value, balance[sink])
+        self.assertEqual(-value, balance[source])
+
+    def test_maximum_flow(self):
+        value, flows = retworkx.digraph_maximum_flow(self.graph, 0, 3, capacity_fn=float)
+        self.assertEqual(5.0, value)
+        self.assertEqual(2.0, flows[3])
+        self.assertEqual(3.0, flows[4])
+        self.assertValidFlow(self.graph, 0, 3, value, flows)
+
+    def test_maximum_flow_default_capacity(self):
+        value, flows = retworkx.digraph_maximum_flow(self.graph, 0, 3)
+        self.assertEqual(2.0, value)
+        self.assertValidFlow(self.graph, 0, 3, value, flows, capacity_fn=lambda _: 1.0)
+
+    def test_maximum_flow_custom_default_capacity(self):
+        value, _ = retworkx.digraph_maximum_flow(self.graph, 0, 3, default_capacity=2.5)
+        self.assertEqual(5.0, value)
+
+    def test_maximum_flow_follows_edge_direction(self):
+        value, flows = retworkx.digraph_maximum_flow(self.graph, 3, 0, capacity_fn=float)
+        self.assertEqual(0.0, value)
+        self.assertTrue(all(flow == 0.0 for flow in flows.values()))
+
+    def test_maximum_flow_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.5), (0, 1, 2.5), (1, 2, 10), (1, 1, 3)])
+        value, flows = retworkx.digraph_maximum_flow(graph, 0, 2, capacity_fn=float)
+        self.assertEqual(4.0, value)
+        self.assertEqual(0.0, flows[3])
+        self.assertValidFlow(graph, 0, 2, value, flows)
+
+    def test_maximum_flow_with_removed_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.remove_node(1)
+        graph.add_edges_from([(0, 2, 3), (2, 4, 2), (0, 3, 1), (3, 4, 4)])
+        value, flows = retworkx.digraph_maximum_flow(graph, 0, 4, capacity_fn=float)
+        self.assertEqual(3.0, value)
+        self.assertValidFlow(graph, 0, 4, value, flows)
+
+    def test_maximum_flow_layered_graph(self):
+        graph = retworkx.generators.directed_grid_graph(4, 5, weights=list(range(20)))
+        value, flows = retworkx.digraph_maximum_flow(graph, 0, 19)
+        self.assertEqual(2.0, value)
+        self.assertValidFlow(graph, 0, 19, value, flows, capacity_fn=lambda _: 1.0)
+
+    def test_maximum_flow_invalid_capacity(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_maximum_flow(self.graph, 0, 3, capacity_fn=lambda _: -1.0)
+
+    def test_maximum_flow_capacity_fn_exception(self):
+        def capacity_fn(_):
+            raise IndexError("capacity error")
+
+        with self.assertRaises(IndexError):
+            retworkx.digraph_maximum_flow(self.graph, 0, 3, capacity_fn=capacity_fn)
+
+    def test_maximum_flow_same_source_and_sink(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_maximum_flow(self.graph, 0, 0)
+
+    def test_maximum_flow_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_maximum_flow(self.graph, 0, 42)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_maximum_flow(self.graph, 42, 0)
+
+
+class TestMinimumCut(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 4), (0, 2, 2), (1, 2, 5), (1, 3, 2), (2, 3, 3)]
+        )
+
+    def test_minimum_cut(self):
+        value, source_side, cut_edges = retworkx.digraph_minimum_cut(
+            self.graph, 0, 3, capacity_fn=float
+        )
+        self.assertEqual(5.0, value)
+        self.assertEqual([0, 1, 2], list(source_side))
+        self.assertEqual([3, 4], list(cut_edges))
+
+    def test_minimum_cut_matches_maximum_flow(self):
+        graph = retworkx.generators.directed_grid_graph(3, 4, weights=list(range(12)))
+        flow_value, _ = retworkx.digraph_maximum_flow(graph, 0, 11)
+        value, source_side, cut_edges = retworkx.digraph_minimum_cut(graph, 0, 11)
+        self.assertEqual(flow_value, value)
+        self.assertEqual(len(cut_edges), value)
+        self.assertIn(0, source_side)
+        self.assertNotIn(11, source_side)
+        for edge in cut_edges:
+            node_a, node_b = graph.get_edge_endpoints_by_index(edge)
+            self.assertIn(node_a, source_side)
+            self.assertNotIn(node_b, source_side)
+
+    def test_minimum_cut_disconnected(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from([(0, 1, 1), (2, 3, 1)])
+        value, source_side, cut_edges = retworkx.digraph_minimum_cut(graph, 0, 3)
+        self.assertEqual(0.0, value)
+        self.assertEqual([0, 1], list(source_side))
+        self.assertEqual([], list(cut_edges))
+
+    def test_minimum_cut_same_source_and_sink(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_minimum_cut(self.graph, 1, 1)
+
+    def test_minimum_cut_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_minimum_cut(self.graph, 0, 42)
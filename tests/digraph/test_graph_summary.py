@@ -0,0 +1,64 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def test_directed_path(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        self.assertEqual(
+            {
+                "num_nodes": 4,
+                "num_edges": 3,
+                "density": 3 / 12,
+                "min_degree": 1,
+                "max_degree": 2,
+                "mean_degree": 1.5,
+                "num_components": 1,
+                "is_directed": True,
+                "is_dag": True,
+                "is_tree": True,
+                "is_bipartite": True,
+            },
+            retworkx.graph_summary(graph),
+        )
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        summary = retworkx.graph_summary(graph)
+        self.assertFalse(summary["is_dag"])
+        self.assertTrue(summary["is_bipartite"])
+        self.assertFalse(summary["is_tree"])
+
+    def test_weakly_connected_components(self):
+        graph = retworkx.PyDAG()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (2, 1), (3, 4)])
+        summary = retworkx.graph_summary(graph)
+        self.assertEqual(2, summary["num_components"])
+        self.assertTrue(summary["is_dag"])
+
+    def test_parallel_edges(self):
+        graph = retworkx.generators.directed_path_graph(2)
+        graph.add_edge(0, 1, None)
+        summary = retworkx.graph_summary(graph)
+        self.assertTrue(summary["is_dag"])
+        self.assertFalse(summary["is_tree"])
+        self.assertEqual(1.0, summary["density"])
+
+    def test_empty(self):
+        summary = retworkx.graph_summary(retworkx.PyDiGraph())
+        self.assertTrue(summary["is_dag"])
+        self.assertTrue(summary["is_directed"])
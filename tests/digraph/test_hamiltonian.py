@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestHamiltonianPath(unittest.TestCase):
+    def assertHamiltonian(self, graph, path, cycle=False):
+        self.assertIsNotNone(path)
+        path = list(path)
+        self.assertEqual(sorted(graph.node_indexes()), sorted(path))
+        for source, target in zip(path, path[1:]):
+            self.assertTrue(graph.has_edge(source, target))
+        if cycle:
+            self.assertTrue(graph.has_edge(path[-1], path[0]))
+
+    def test_directed_path(self):
+        graph = retworkx.generators.directed_path_graph(6)
+        self.assertEqual([0, 1, 2, 3, 4, 5], list(retworkx.digraph_hamiltonian_path(graph)))
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, cycle=True))
+
+    def test_edge_direction(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 1)])
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph))
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, method="heuristic"))
+
+    def test_empty_and_single_node_cycle(self):
+        graph = retworkx.PyDiGraph()
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, cycle=True))
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.digraph_hamiltonian_path(graph)))
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, cycle=True))
+
+    def test_two_node_cycle(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 0)])
+        self.assertHamiltonian(graph, retworkx.digraph_hamiltonian_path(graph, cycle=True), True)
+
+    def test_tournament(self):
+        # Every tournament has a Hamiltonian path
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(30))
+        graph.add_edges_from_no_data(
+            [(i, j) if (i * 7 + j * 3) % 2 else (j, i) for i in range(30) for j in range(i)]
+        )
+        self.assertHamiltonian(graph, retworkx.digraph_hamiltonian_path(graph))
+        self.assertHamiltonian(graph, retworkx.hamiltonian_path(graph))
+
+    def test_directed_cycle_heuristic(self):
+        graph = retworkx.generators.directed_cycle_graph(200)
+        path = retworkx.digraph_hamiltonian_path(graph, cycle=True, method="heuristic")
+        self.assertHamiltonian(graph, path, cycle=True)
+
+    def test_exact_too_large(self):
+        graph = retworkx.generators.directed_cycle_graph(65)
+        with self.assertRaises(retworkx.GraphTooLarge):
+            retworkx.digraph_hamiltonian_path(graph)
+        path = retworkx.generators.directed_cycle_graph(64)
+        self.assertIsNotNone(retworkx.digraph_hamiltonian_path(path, cycle=True))
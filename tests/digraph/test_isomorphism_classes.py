@@ -0,0 +1,46 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestIsomorphismClasses(unittest.TestCase):
+    def test_isomorphism_classes(self):
+        graphs = [
+            retworkx.generators.directed_path_graph(3),
+            retworkx.generators.directed_star_graph(3),
+            retworkx.generators.directed_path_graph(3),
+            retworkx.generators.directed_star_graph(3, inward=True),
+        ]
+        self.assertEqual([[0, 2], [1], [3]], retworkx.digraph_isomorphism_classes(graphs))
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        reverse = retworkx.PyDiGraph()
+        reverse.add_nodes_from(range(4))
+        reverse.add_edges_from_no_data([(target, source) for source, target in graph.edge_list()])
+        path = retworkx.generators.directed_path_graph(4)
+        self.assertEqual([[0, 1], [2]], retworkx.isomorphism_classes([graph, reverse, path]))
+
+    def test_node_matcher(self):
+        graphs = []
+        for labels in (["a", "b"], ["b", "a"], ["a", "b"]):
+            graph = retworkx.PyDiGraph()
+            graph.add_nodes_from(labels)
+            graph.add_edge(0, 1, None)
+            graphs.append(graph)
+        self.assertEqual(
+            [[0, 2], [1]],
+            retworkx.digraph_isomorphism_classes(graphs, node_matcher=lambda a, b: a == b),
+        )
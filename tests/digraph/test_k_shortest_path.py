@@ -85,6 +85,11 @@ class TestKShortestpath(unittest.TestCase):
                         goal=3,
                     )
 
+    def test_digraph_k_shortest_path_invalid_start(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_k_shortest_path_lengths(graph, start=42, k=1, edge_cost=lambda _: 1)
+
     def test_k_shortest_path_with_no_path(self):
         g = retworkx.PyDiGraph()
         a = g.add_node("A")
@@ -0,0 +1,79 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestKeyIndex(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a1", "b1", "a2", "c1"])
+        self.graph.add_edges_from([(0, 1, "x"), (1, 2, "y"), (2, 3, "x")])
+        self.graph.index_nodes_by(lambda node: node[0])
+        self.graph.index_edges_by(lambda edge: edge)
+
+    def test_nodes_with_key(self):
+        self.assertEqual([0, 2], self.graph.nodes_with_key("a"))
+        self.assertEqual([1], self.graph.nodes_with_key("b"))
+        self.assertEqual([], self.graph.nodes_with_key("d"))
+
+    def test_add_child_and_parent(self):
+        child = self.graph.add_child(3, "a3", "y")
+        parent = self.graph.add_parent(0, "b2", "z")
+        self.assertEqual([0, 2, child], self.graph.nodes_with_key("a"))
+        self.assertEqual([1, parent], self.graph.nodes_with_key("b"))
+        self.assertEqual([1, 3], self.graph.edges_with_key("y"))
+        self.assertEqual([4], self.graph.edges_with_key("z"))
+
+    def test_remove_node_retain_edges(self):
+        self.graph.remove_node_retain_edges(1)
+        self.assertEqual([], self.graph.nodes_with_key("b"))
+        self.assertEqual([], self.graph.edges_with_key("y"))
+        self.assertEqual([2, 3], self.graph.edges_with_key("x"))
+
+    def test_merge_nodes(self):
+        self.graph[3] = "a2"
+        self.graph.merge_nodes(2, 3)
+        self.assertEqual([0, 3], self.graph.nodes_with_key("a"))
+        edge_index_map = self.graph.edge_index_map()
+        for key in ["x", "y"]:
+            edges = self.graph.edges_with_key(key)
+            self.assertEqual(
+                sorted(edge for edge, (_, _, data) in edge_index_map.items() if data == key),
+                list(edges),
+            )
+
+    def test_rollback(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(1)
+            self.graph.update_edge(2, 3, "y")
+            self.assertEqual([2], self.graph.edges_with_key("y"))
+            transaction.rollback()
+        self.assertEqual([1], self.graph.nodes_with_key("b"))
+        self.assertEqual([0, 2], self.graph.edges_with_key("x"))
+        self.assertEqual([1], self.graph.edges_with_key("y"))
+
+    def test_compact(self):
+        self.graph.remove_node(0)
+        self.graph.compact()
+        self.assertEqual([1], self.graph.nodes_with_key("a"))
+        self.assertEqual([1], self.graph.edges_with_key("x"))
+
+    def test_not_indexed(self):
+        self.graph.drop_node_index()
+        self.graph.drop_edge_index()
+        with self.assertRaises(RuntimeError):
+            self.graph.nodes_with_key("a")
+        with self.assertRaises(RuntimeError):
+            self.graph.edges_with_key("x")
@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestLowestCommonAncestors(unittest.TestCase):
+    def setUp(self):
+        self.tree = retworkx.generators.directed_binomial_tree_graph(3)
+        self.pairs = [(3, 5), (6, 7), (2, 4), (1, 1), (2, 3), (7, 6), (5, 7)]
+        self.expected = [0, 6, 0, 1, 2, 6, 4]
+
+    def test_tree(self):
+        res = retworkx.lowest_common_ancestors(self.tree, self.pairs)
+        self.assertEqual(self.expected, res)
+
+    def test_tree_index(self):
+        index = retworkx.LCAIndex(self.tree)
+        res = [index.query(first, second) for first, second in self.pairs]
+        self.assertEqual(self.expected, res)
+
+    def test_forest(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (3, 4)])
+        pairs = [(1, 2), (1, 4), (4, 3)]
+        self.assertEqual([0, None, 3], retworkx.lowest_common_ancestors(graph, pairs))
+        index = retworkx.LCAIndex(graph)
+        self.assertEqual([0, None, 3], [index.query(*pair) for pair in pairs])
+
+    def test_dag(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (1, 4), (3, 5), (4, 5)])
+        pairs = [(3, 4), (5, 2), (2, 4), (5, 5)]
+        expected = [1, 2, 0, 5]
+        self.assertEqual(expected, retworkx.lowest_common_ancestors(graph, pairs))
+        index = retworkx.LCAIndex(graph)
+        self.assertEqual(expected, [index.query(*pair) for pair in pairs])
+
+    def test_dag_multiple_lowest(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 2), (1, 3)])
+        res = retworkx.lowest_common_ancestors(graph, [(2, 3)])
+        self.assertIn(res[0], {0, 1})
+        self.assertEqual(res[0], retworkx.LCAIndex(graph).query(2, 3))
+
+    def test_dag_no_common_ancestor(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 2), (1, 3), (0, 3)])
+        self.assertEqual([None], retworkx.lowest_common_ancestors(graph, [(1, 2)]))
+        self.assertIsNone(retworkx.LCAIndex(graph).query(1, 2))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        graph.remove_node(0)
+        graph.add_child(2, None, None)
+        self.assertEqual([2, 1], retworkx.lowest_common_ancestors(graph, [(0, 4), (1, 3)]))
+        self.assertEqual(2, retworkx.LCAIndex(graph).query(0, 4))
+
+    def test_empty_pairs(self):
+        self.assertEqual([], retworkx.lowest_common_ancestors(self.tree, []))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.lowest_common_ancestors(self.tree, [(0, 42)])
+        index = retworkx.LCAIndex(self.tree)
+        with self.assertRaises(retworkx.InvalidNode):
+            index.query(42, 0)
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.lowest_common_ancestors(graph, [(0, 1)])
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.LCAIndex(graph)
+
+    def test_index_is_snapshot(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        index = retworkx.LCAIndex(graph)
+        graph.remove_edge(0, 1)
+        self.assertEqual(1, index.query(1, 2))
@@ -0,0 +1,101 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import random
+import unittest
+
+import retworkx
+
+
+class TestMinimumPathCover(unittest.TestCase):
+    def assertPathCover(self, graph, paths):
+        covered = [node for path in paths for node in path]
+        self.assertEqual(sorted(covered), sorted(graph.node_indices()))
+        for path in paths:
+            for source, target in zip(path, path[1:]):
+                self.assertTrue(graph.has_edge(source, target))
+
+    def test_empty(self):
+        self.assertEqual(retworkx.dag_minimum_path_cover(retworkx.PyDiGraph()), [])
+
+    def test_isolated_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(retworkx.dag_minimum_path_cover(graph), [[0], [1], [2]])
+
+    def test_path(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        self.assertEqual(retworkx.dag_minimum_path_cover(graph), [[0, 1, 2, 3, 4]])
+
+    def test_diamonds(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5)])
+        paths = retworkx.dag_minimum_path_cover(graph)
+        self.assertEqual(len(paths), 3)
+        self.assertPathCover(graph, paths)
+
+    def test_star(self):
+        graph = retworkx.generators.directed_star_graph(5)
+        paths = retworkx.dag_minimum_path_cover(graph)
+        self.assertEqual(len(paths), 4)
+        self.assertPathCover(graph, paths)
+
+    def test_paths_not_transitive(self):
+        # 0 -> 1 -> 2 and 3 -> 1 -> 4, the paths can't share node 1
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 1), (1, 4)])
+        paths = retworkx.dag_minimum_path_cover(graph)
+        self.assertEqual(len(paths), 3)
+        self.assertPathCover(graph, paths)
+
+    def test_parallel_edges_and_removed_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 3), (2, 3)])
+        graph.remove_node(2)
+        self.assertEqual(retworkx.dag_minimum_path_cover(graph), [[0, 1, 3]])
+
+    def test_random_dags(self):
+        def max_matching(graph):
+            match = {}
+
+            def augment(node, seen):
+                for successor in set(graph.successor_indices(node)):
+                    if successor in seen:
+                        continue
+                    seen.add(successor)
+                    if successor not in match or augment(match[successor], seen):
+                        match[successor] = node
+                        return True
+                return False
+
+            return sum(augment(node, set()) for node in graph.node_indices())
+
+        rng = random.Random(42)
+        for _ in range(50):
+            num_nodes = rng.randint(1, 20)
+            graph = retworkx.PyDiGraph()
+            graph.add_nodes_from(range(num_nodes))
+            for source in range(num_nodes):
+                for target in range(source + 1, num_nodes):
+                    if rng.random() < 0.2:
+                        graph.add_edge(source, target, None)
+            paths = retworkx.dag_minimum_path_cover(graph)
+            self.assertPathCover(graph, paths)
+            self.assertEqual(len(paths), num_nodes - max_matching(graph))
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.dag_minimum_path_cover(graph)
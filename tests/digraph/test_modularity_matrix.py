@@ -0,0 +1,58 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestModularityMatrix(unittest.TestCase):
+    def test_path_graph(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        res = retworkx.digraph_modularity_matrix(graph)
+        self.assertIsInstance(res, np.ndarray)
+        expected = np.array([[0.0, 0.5, -0.5], [0.0, -0.5, 0.5], [0.0, 0.0, 0.0]])
+        np.testing.assert_allclose(expected, res)
+
+    def test_matches_definition(self):
+        graph = retworkx.generators.directed_heavy_hex_graph(3)
+        for edge, (source, target, _) in graph.edge_index_map().items():
+            graph.update_edge_by_index(edge, float(2 * source + target + 1))
+        graph.add_edge(0, 1, 2.5)
+        adjacency = retworkx.digraph_adjacency_matrix(graph, weight_fn=float)
+        expected = adjacency - 2.0 * np.outer(
+            adjacency.sum(axis=1), adjacency.sum(axis=0)
+        ) / adjacency.sum()
+        res = retworkx.digraph_modularity_matrix(graph, weight_fn=float, resolution=2.0)
+        np.testing.assert_allclose(expected, res)
+
+    def test_rows_and_columns_sum_to_zero(self):
+        graph = retworkx.generators.directed_grid_graph(3, 3)
+        graph.remove_node(4)
+        res = retworkx.digraph_modularity_matrix(graph)
+        self.assertEqual((8, 8), res.shape)
+        np.testing.assert_allclose(np.zeros(8), res.sum(axis=0), atol=1e-12)
+        np.testing.assert_allclose(np.zeros(8), res.sum(axis=1), atol=1e-12)
+
+    def test_no_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        np.testing.assert_array_equal(
+            np.zeros((3, 3)), retworkx.digraph_modularity_matrix(graph)
+        )
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        np.testing.assert_allclose(
+            retworkx.digraph_modularity_matrix(graph), retworkx.modularity_matrix(graph)
+        )
@@ -372,6 +372,83 @@ class TestNodes(unittest.TestCase):
         res = graph.find_node_by_weight("a")
         self.assertEqual(res, 0)
 
+    def test_filter_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(10)))
+        res = graph.filter_nodes(lambda x: x % 3 == 0)
+        self.assertEqual([0, 3, 6, 9], res)
+
+    def test_filter_nodes_removed_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "a", "c"])
+        graph.remove_node(0)
+        res = graph.filter_nodes(lambda x: x == "a")
+        self.assertEqual([2], res)
+
+    def test_filter_nodes_no_match(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(10)))
+        res = graph.filter_nodes(lambda x: x > 42)
+        self.assertEqual([], res)
+
+    def test_filter_nodes_exception(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+
+        def filter_fn(_):
+            raise IndexError("Oops")
+
+        with self.assertRaises(IndexError):
+            graph.filter_nodes(filter_fn)
+
+    def test_filter_nodes_batched(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(10)))
+        graph.remove_node(4)
+        batches = []
+
+        def filter_fn(weights):
+            batches.append(weights)
+            return [x % 3 == 0 for x in weights]
+
+        res = graph.filter_nodes(filter_fn, batch_size=4)
+        self.assertEqual([0, 3, 6, 9], res)
+        self.assertEqual([[0, 1, 2, 3], [5, 6, 7, 8], [9]], batches)
+
+    def test_filter_nodes_batched_wrong_length(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(10)))
+        with self.assertRaises(ValueError):
+            graph.filter_nodes(lambda weights: [True], batch_size=4)
+        with self.assertRaises(ValueError):
+            graph.filter_nodes(lambda weights: [True] * 5, batch_size=4)
+
+    def test_filter_nodes_batched_zero_batch_size(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        with self.assertRaises(ValueError):
+            graph.filter_nodes(lambda weights: weights, batch_size=0)
+
+    def test_find_node_by_weight_payload_index(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "a", "c"])
+        graph.index_nodes_by()
+        self.assertEqual(0, graph.find_node_by_weight("a"))
+        self.assertEqual([0, 2], graph.nodes_with_key("a"))
+        graph.remove_node(0)
+        self.assertEqual(2, graph.find_node_by_weight("a"))
+        graph[1] = "d"
+        self.assertIsNone(graph.find_node_by_weight("b"))
+        self.assertEqual(1, graph.find_node_by_weight("d"))
+        self.assertIsNone(graph.find_node_by_weight(["unhashable"]))
+
+    def test_find_node_by_weight_key_index_not_used(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([("a", 1), ("b", 2)])
+        graph.index_nodes_by(lambda node: node[0])
+        self.assertIsNone(graph.find_node_by_weight("a"))
+        self.assertEqual(1, graph.find_node_by_weight(("b", 2)))
+
     def test_merge_nodes(self):
         graph = retworkx.PyDiGraph()
         graph.add_nodes_from(["a", "a", "b", "c"])
@@ -0,0 +1,117 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestPathUtils(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0), (2, 3, 4.0)])
+
+    def test_is_path(self):
+        self.assertTrue(retworkx.digraph_is_path(self.graph, [0, 1, 2, 3]))
+        self.assertTrue(retworkx.digraph_is_path(self.graph, [2]))
+        self.assertFalse(retworkx.digraph_is_path(self.graph, [3, 2, 1]))
+        self.assertFalse(retworkx.digraph_is_path(self.graph, [0, 2]))
+        self.assertFalse(retworkx.digraph_is_path(self.graph, [0, 1, 7]))
+        self.assertFalse(retworkx.digraph_is_path(self.graph, []))
+
+    def test_path_weight(self):
+        self.assertEqual(
+            8.0, retworkx.digraph_path_weight(self.graph, [0, 1, 2, 3], weight_fn=float)
+        )
+        self.assertEqual(3.0, retworkx.digraph_path_weight(self.graph, [0, 1, 2, 3]))
+        self.assertEqual(0.0, retworkx.digraph_path_weight(self.graph, [1]))
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.digraph_path_weight(self.graph, [2, 1, 0])
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_path_weight(self.graph, [42])
+
+    def test_path_weight_edge_path(self):
+        self.assertEqual(
+            9.0,
+            retworkx.digraph_path_weight(self.graph, [0, 1, 3], weight_fn=float, edge_path=True),
+        )
+        self.assertEqual(
+            8.0,
+            retworkx.digraph_path_weight(self.graph, [2, 1, 3], weight_fn=float, edge_path=True),
+        )
+        with self.assertRaises(ValueError):
+            retworkx.digraph_path_weight(self.graph, [1, 0], edge_path=True)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_path_weight(self.graph, [0, 2], edge_path=True)
+        with self.assertRaises(IndexError):
+            retworkx.digraph_path_weight(self.graph, [0, 42], edge_path=True)
+
+    def test_edges_of_path(self):
+        self.assertEqual(
+            [2, 1, 3], retworkx.digraph_edges_of_path(self.graph, [0, 1, 2, 3], weight_fn=float)
+        )
+        self.assertEqual([0, 1], retworkx.digraph_edges_of_path(self.graph, [0, 1, 2]))
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.digraph_edges_of_path(self.graph, [1, 0])
+
+    def test_edges_of_path_matches_dijkstra(self):
+        path = retworkx.digraph_dijkstra_shortest_paths(self.graph, 0, 3, weight_fn=float)[3]
+        self.assertEqual(
+            retworkx.digraph_dijkstra_shortest_path_edges(self.graph, 0, 3, weight_fn=float),
+            retworkx.digraph_edges_of_path(self.graph, path, weight_fn=float),
+        )
+
+    def test_universal(self):
+        self.assertFalse(retworkx.is_path(self.graph, [2, 1]))
+        self.assertEqual(
+            7.0, retworkx.path_weight(self.graph, [1, 3], weight_fn=float, edge_path=True)
+        )
+        self.assertEqual([2], retworkx.edges_of_path(self.graph, [0, 1], weight_fn=float))
+
+
+class TestPathSimilarity(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_grid_graph(3, 3)
+
+    def test_longest_common_subpath(self):
+        res = retworkx.digraph_longest_common_subpath(self.graph, [0, 1, 2, 5, 8], [0, 3, 4, 5, 8])
+        self.assertEqual([5, 8], res)
+
+    def test_longest_common_subpath_not_reversed(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 1), (1, 0), (2, 3)])
+        res = retworkx.digraph_longest_common_subpath(graph, [0, 1, 2, 3], [2, 1, 0])
+        self.assertEqual([0], res)
+
+    def test_longest_common_subpath_invalid_path(self):
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.digraph_longest_common_subpath(self.graph, [1, 0], [0, 1])
+
+    def test_path_jaccard_similarity(self):
+        res = retworkx.digraph_path_jaccard_similarity(self.graph, [0, 1, 2, 5], [0, 1, 4, 5])
+        self.assertEqual(0.2, res)
+
+    def test_path_jaccard_similarity_direction(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2)])
+        self.assertEqual(0.0, retworkx.digraph_path_jaccard_similarity(graph, [0, 1], [1, 0]))
+        self.assertEqual(0.5, retworkx.digraph_path_jaccard_similarity(graph, [0, 1], [0, 1, 2]))
+
+    def test_universal(self):
+        self.assertEqual(
+            [5, 8], retworkx.longest_common_subpath(self.graph, [0, 1, 2, 5, 8], [0, 3, 4, 5, 8])
+        )
+        self.assertEqual(
+            0.2, retworkx.path_jaccard_similarity(self.graph, [0, 1, 2, 5], [0, 1, 4, 5])
+        )
@@ -0,0 +1,47 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestPower(unittest.TestCase):
+    def test_directed_path_square(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        power_graph = retworkx.power(graph, 2)
+        self.assertIsInstance(power_graph, retworkx.PyDiGraph)
+        self.assertEqual(
+            sorted(power_graph.edge_list()),
+            [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)],
+        )
+
+    def test_directed_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        power_graph = retworkx.digraph_power(graph, 3)
+        self.assertEqual(
+            sorted(power_graph.edge_list()),
+            [(i, j) for i in range(4) for j in range(4) if i != j],
+        )
+
+    def test_follows_edge_direction(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from_no_data([(0, 1), (2, 1)])
+        power_graph = retworkx.power(graph, 2)
+        self.assertEqual(power_graph.nodes(), ["a", "b", "c"])
+        self.assertEqual(sorted(power_graph.edge_list()), [(0, 1), (2, 1)])
+
+    def test_zero_power(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_power(graph, 0)
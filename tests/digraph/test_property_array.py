@@ -0,0 +1,112 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestPropertyArray(unittest.TestCase):
+    def test_node_property_default(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("i64")
+        self.assertEqual("i64", prop.dtype)
+        self.assertEqual(0, prop.default)
+        self.assertEqual(4, len(prop))
+        self.assertEqual([0, 0, 0, 0], list(prop))
+
+    def test_node_property_custom_default(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("f64", default=1.5)
+        self.assertEqual(1.5, prop.default)
+        self.assertEqual([1.5, 1.5, 1.5, 1.5], list(prop))
+
+    def test_bool_property(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("bool")
+        prop[2] = True
+        self.assertEqual([False, False, True, False], list(prop))
+        prop.fill(True)
+        self.assertEqual([True, True, True, True], list(prop))
+
+    def test_set_and_get(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("f64")
+        prop[1] = 2.5
+        self.assertEqual(2.5, prop[1])
+        self.assertEqual(0.0, prop[0])
+
+    def test_grows_for_new_nodes(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("i64", default=-1)
+        new_node = graph.add_node(None)
+        self.assertEqual(-1, prop[new_node])
+        self.assertEqual(4, len(prop))
+        prop[new_node + 2] = 7
+        self.assertEqual(7, len(prop))
+        self.assertEqual([-1, -1, -1, -1, -1, -1, 7], list(prop))
+
+    def test_removed_nodes_keep_index(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        graph.remove_node(1)
+        prop = graph.new_node_property("i64")
+        self.assertEqual(4, len(prop))
+
+    def test_edge_property(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_edge_property("f64", default=1.0)
+        self.assertEqual(3, len(prop))
+        for edge in graph.edge_indices():
+            prop[edge] = float(edge)
+        self.assertEqual([0.0, 1.0, 2.0], list(prop))
+
+    def test_fill(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_edge_property("i64")
+        prop.fill(3)
+        self.assertEqual([3, 3, 3], list(prop))
+
+    def test_invalid_dtype(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        with self.assertRaises(ValueError):
+            graph.new_node_property("str")
+
+    def test_invalid_value(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("i64")
+        with self.assertRaises(TypeError):
+            prop[0] = "a"
+
+    def test_numpy_array(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("f64", default=np.inf)
+        prop[0] = 0.0
+        array = np.asarray(prop)
+        self.assertTrue(np.array_equal(array, np.array([0.0, np.inf, np.inf, np.inf])))
+        array[1] = 1.0
+        self.assertEqual(np.inf, prop[1])
+
+    def test_numpy_array_bool(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("bool")
+        prop[3] = True
+        array = np.asarray(prop)
+        self.assertEqual(np.bool_, array.dtype)
+        self.assertTrue(np.array_equal(array, np.array([False, False, False, True])))
+
+    def test_numpy_array_dtype(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        prop = graph.new_node_property("i64")
+        array = np.asarray(prop, dtype=np.float64)
+        self.assertEqual(np.float64, array.dtype)
@@ -0,0 +1,67 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestReciprocity(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)])
+
+    def test_reciprocity(self):
+        self.assertEqual(0.8, retworkx.reciprocity(self.graph))
+
+    def test_reciprocity_ignores_self_loops_and_parallel_edges(self):
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (4, 4)])
+        self.assertEqual(0.8, retworkx.reciprocity(self.graph))
+
+    def test_reciprocity_no_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        self.assertEqual(0.0, retworkx.reciprocity(graph))
+
+    def test_reciprocity_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        self.assertEqual(0.0, retworkx.reciprocity(graph))
+        graph = retworkx.generators.directed_cycle_graph(4, bidirectional=True)
+        self.assertEqual(1.0, retworkx.reciprocity(graph))
+
+    def test_node_reciprocity(self):
+        res = retworkx.node_reciprocity(self.graph)
+        self.assertEqual({0: 1.0, 1: 2 / 3, 2: 2 / 3, 3: 1.0, 4: 0.0}, res)
+
+    def test_node_reciprocity_removed_node(self):
+        self.graph.remove_node(0)
+        res = retworkx.node_reciprocity(self.graph)
+        self.assertEqual({1: 0.0, 2: 2 / 3, 3: 1.0, 4: 0.0}, res)
+
+
+class TestDyadCensus(unittest.TestCase):
+    def test_dyad_census(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (4, 4), (1, 2)])
+        res = retworkx.dyad_census(graph)
+        self.assertEqual({"mutual": 2, "asymmetric": 1, "null": 7}, res)
+
+    def test_dyad_census_empty(self):
+        res = retworkx.dyad_census(retworkx.PyDiGraph())
+        self.assertEqual({"mutual": 0, "asymmetric": 0, "null": 0}, res)
+
+    def test_dyad_census_complete(self):
+        graph = retworkx.generators.directed_mesh_graph(5)
+        res = retworkx.dyad_census(graph)
+        self.assertEqual({"mutual": 10, "asymmetric": 0, "null": 0}, res)
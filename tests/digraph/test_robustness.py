@@ -0,0 +1,44 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestRobustnessCurve(unittest.TestCase):
+    def test_path_betweenness(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        order, sizes = retworkx.robustness_curve(graph, order="betweenness")
+        self.assertEqual(list(order), [2, 1, 3, 0, 4])
+        self.assertEqual(sizes, [5, 2, 2, 1, 1, 0])
+
+    def test_weak_components(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 1), (2, 3)])
+        order, sizes = retworkx.digraph_robustness_curve(graph, order=[0, 2])
+        self.assertEqual(sizes, [4, 3, 1])
+
+    def test_edge_removal(self):
+        graph = retworkx.generators.directed_star_graph(4)
+        order, sizes = retworkx.digraph_robustness_curve(graph, remove_edges=True)
+        self.assertEqual(list(order), [0, 1, 2])
+        self.assertEqual(sizes, [4, 3, 2, 1])
+
+    def test_random_seed(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        first = retworkx.robustness_curve(graph, order="random", remove_edges=True, seed=7)
+        second = retworkx.robustness_curve(graph, order="random", remove_edges=True, seed=7)
+        self.assertEqual(list(first[0]), list(second[0]))
+        self.assertEqual(first[1], second[1])
+        self.assertEqual(first[1][-1], 1)
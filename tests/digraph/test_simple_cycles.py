@@ -0,0 +1,92 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def canonical(cycle):
+    start = cycle.index(min(cycle))
+    return tuple(cycle[start:] + cycle[:start])
+
+
+def brute_force_cycles(graph, length_bound=None):
+    nodes = list(graph.node_indexes())
+    if length_bound is None:
+        length_bound = len(nodes)
+    cycles = set()
+    for length in range(1, length_bound + 1):
+        for cycle in itertools.permutations(nodes, length):
+            if cycle[0] != min(cycle):
+                continue
+            if all(graph.has_edge(cycle[i], cycle[(i + 1) % length]) for i in range(length)):
+                cycles.add(cycle)
+    return cycles
+
+
+class TestSimpleCycles(unittest.TestCase):
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.simple_cycles(retworkx.PyDiGraph())))
+
+    def test_dag(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        self.assertEqual([], list(retworkx.simple_cycles(graph)))
+
+    def test_cycle_graph(self):
+        graph = retworkx.generators.directed_cycle_graph(5)
+        self.assertEqual([[0, 1, 2, 3, 4]], list(retworkx.simple_cycles(graph)))
+        self.assertEqual([], list(retworkx.simple_cycles(graph, length_bound=4)))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 0), (0, 1), (0, 1), (1, 0), (1, 1), (2, 2)])
+        res = [canonical(cycle) for cycle in retworkx.simple_cycles(graph)]
+        self.assertEqual([(0,), (1,), (2,), (0, 1)], res)
+        res = list(retworkx.simple_cycles(graph, length_bound=1))
+        self.assertEqual([[0], [1], [2]], res)
+        self.assertEqual([], list(retworkx.simple_cycles(graph, length_bound=0)))
+
+    def test_mesh_graph(self):
+        graph = retworkx.generators.directed_mesh_graph(5)
+        res = [canonical(cycle) for cycle in retworkx.simple_cycles(graph)]
+        self.assertEqual(len(res), len(set(res)))
+        # Each set of k >= 2 nodes has (k - 1)! simple cycles
+        self.assertEqual(10 + 10 * 2 + 5 * 6 + 24, len(res))
+        self.assertEqual(brute_force_cycles(graph), set(res))
+        res = [canonical(cycle) for cycle in retworkx.simple_cycles(graph, length_bound=3)]
+        self.assertEqual(brute_force_cycles(graph, 3), set(res))
+
+    def test_lazy_iteration(self):
+        graph = retworkx.generators.directed_mesh_graph(20)
+        cycles = retworkx.simple_cycles(graph)
+        for _ in range(100):
+            cycle = next(cycles)
+            self.assertEqual(len(cycle), len(set(cycle)))
+
+    def test_graph_copied(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        cycles = retworkx.simple_cycles(graph)
+        graph.remove_node(0)
+        self.assertEqual([[0, 1, 2]], list(cycles))
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.directed_gnp_random_graph(6, 0.4, seed=seed)
+            for length_bound in (None, 2, 4):
+                res = [
+                    canonical(cycle)
+                    for cycle in retworkx.simple_cycles(graph, length_bound=length_bound)
+                ]
+                self.assertEqual(len(res), len(set(res)))
+                self.assertEqual(brute_force_cycles(graph, length_bound), set(res))
@@ -0,0 +1,41 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestSimRank(unittest.TestCase):
+    def test_out_star(self):
+        graph = retworkx.generators.directed_star_graph(4)
+        res = retworkx.simrank_similarity(graph)
+        self.assertIsInstance(res, np.ndarray)
+        self.assertAlmostEqual(res[1, 2], 0.9)
+        self.assertEqual(res[0, 1], 0.0)
+
+    def test_in_star(self):
+        # The leaves of an inward star have no predecessors
+        graph = retworkx.generators.directed_star_graph(4, inward=True)
+        res = retworkx.digraph_simrank_similarity(graph)
+        np.testing.assert_allclose(res, np.eye(4))
+
+    def test_pairs(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 4), (1, 4)])
+        matrix = retworkx.simrank_similarity(graph, importance_factor=0.8)
+        res = retworkx.simrank_similarity(graph, pairs=[(1, 2), (3, 4)], importance_factor=0.8)
+        np.testing.assert_allclose(res, [matrix[1, 2], matrix[3, 4]])
+        self.assertAlmostEqual(res[0], 0.8)
+        self.assertAlmostEqual(res[1], 0.8 * (1 + 0.8) / 2)
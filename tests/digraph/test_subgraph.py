@@ -136,3 +136,45 @@ class TestSubgraph(unittest.TestCase):
         subgraph = graph.edge_subgraph([(0, 1), (1, 2), (1, 3)])
         self.assertEqual([0, 1, 2], subgraph.nodes())
         self.assertEqual([(0, 1, 2), (0, 1, 3), (1, 2, 4)], subgraph.weighted_edge_list())
+
+    def test_edge_subgraph_from_indices(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1), (0, 2, 2), (0, 3, 3), (1, 3, 4)])
+        subgraph = graph.edge_subgraph_from_indices([0, 3])
+        self.assertEqual(["a", "b", "d"], subgraph.nodes())
+        self.assertEqual([0, 1, 3], subgraph.node_indexes())
+        self.assertEqual([0, 3], subgraph.edge_indices())
+        self.assertEqual([(0, 1, 1), (1, 3, 4)], subgraph.weighted_edge_list())
+
+    def test_edge_subgraph_from_indices_parallel_edge(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.extend_from_weighted_edge_list([(0, 1, 2), (0, 1, 3), (1, 2, 4)])
+        subgraph = graph.edge_subgraph_from_indices([1, 2])
+        self.assertEqual([(0, 1, 3), (1, 2, 4)], subgraph.weighted_edge_list())
+
+    def test_edge_subgraph_from_indices_invalid_index(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.extend_from_weighted_edge_list([(0, 1, 2), (1, 2, 4)])
+        subgraph = graph.edge_subgraph_from_indices([0, 42])
+        self.assertEqual([0, 1], subgraph.nodes())
+        self.assertEqual([(0, 1, 2)], subgraph.weighted_edge_list())
+
+    def test_edge_subgraph_from_indices_empty_list(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.extend_from_weighted_edge_list([(0, 1, 2), (1, 2, 4)])
+        subgraph = graph.edge_subgraph_from_indices([])
+        self.assertEqual([], subgraph.nodes())
+
+    def test_edge_subgraph_from_indices_shared_ref(self):
+        graph = retworkx.PyDiGraph()
+        node_weight = {"a": 1}
+        graph.add_node(node_weight)
+        graph.add_node("b")
+        graph.add_edge(0, 1, None)
+        subgraph = graph.edge_subgraph_from_indices([0])
+        node_weight["b"] = 2
+        self.assertEqual({"a": 1, "b": 2}, subgraph[0])
@@ -161,3 +161,26 @@ class TestSubstitute(unittest.TestCase):
             (6, 1),  # output of res[map_function(2, 1, None)] -> 6
         ]
         self.assertEqual(expected_edge_list, graph.edge_list())
+
+    def test_invalid_target_leaves_graph_unmodified(self):
+        in_graph = retworkx.generators.directed_path_graph(3)
+
+        def map_function(source, _target, _weight):
+            if source == 1:
+                return 0
+            return 42
+
+        with self.assertRaises(IndexError):
+            self.graph.substitute_node_with_subgraph(2, in_graph, map_function)
+        self.assertEqual([0, 1, 2, 3, 4], self.graph.node_indices())
+        self.assertEqual([(0, 1), (1, 2), (2, 3), (3, 4)], self.graph.edge_list())
+
+    def test_cycle_leaves_dag_unmodified(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        in_graph = retworkx.generators.directed_cycle_graph(2)
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.substitute_node_with_subgraph(1, in_graph, lambda *args: 1)
+        self.assertEqual([0, 1, 2], dag.node_indices())
+        self.assertEqual([(0, 1), (1, 2)], dag.edge_list())
@@ -0,0 +1,111 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestSubstituteSubgraph(unittest.TestCase):
+    def setUp(self):
+        self.pattern = retworkx.PyDiGraph()
+        self.pattern.add_nodes_from(["h", "h"])
+        self.pattern.add_edge(0, 1, None)
+        self.replacement = retworkx.PyDiGraph()
+        self.replacement.add_node("id")
+
+    def test_substitute_all_occurrences(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(["in", "h", "h", "x", "h", "h", "out"])
+        dag.add_edges_from([(i, i + 1, i) for i in range(6)])
+        res = dag.substitute_subgraph(
+            self.pattern, self.replacement, lambda a, b: a == b, {0: 0, 1: 0}
+        )
+        self.assertEqual([{0: 7}, {0: 2}], [dict(node_map) for node_map in res])
+        self.assertEqual(
+            ["in", "id", "x", "id", "out"],
+            [dag[node] for node in retworkx.topological_sort(dag)],
+        )
+        self.assertEqual(
+            [(0, 7, 0), (7, 3, 2), (3, 2, 3), (2, 6, 5)],
+            sorted(dag.weighted_edge_list(), key=lambda edge: edge[2]),
+        )
+
+    def test_overlapping_occurrences(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(["h", "h", "h"])
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        res = dag.substitute_subgraph(
+            self.pattern, self.replacement, lambda a, b: a == b, {0: 0, 1: 0}
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["h", "id"], dag.nodes())
+        self.assertEqual([(3, 2)], dag.edge_list())
+
+    def test_replacement_edges(self):
+        dag = retworkx.generators.directed_path_graph(3)
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from(["a", "b", "c"])
+        replacement.add_edges_from([(0, 1, "x"), (0, 2, "y")])
+        pattern = retworkx.generators.directed_path_graph(2)
+        res = dag.substitute_subgraph(pattern, replacement, None, {0: 0, 1: 1})
+        self.assertEqual([{0: 3, 1: 4, 2: 5}], [dict(node_map) for node_map in res])
+        self.assertEqual(
+            {(3, 4, "x"), (3, 5, "y"), (4, 2, None)},
+            set(dag.weighted_edge_list()),
+        )
+
+    def test_dangling_occurrence_skipped(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(["a", "h", "h", "b"])
+        dag.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        res = dag.substitute_subgraph(self.pattern, self.replacement, lambda a, b: a == b, {0: 0})
+        self.assertEqual([], res)
+        self.assertEqual(["a", "h", "h", "b"], dag.nodes())
+
+    def test_induced(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(["h", "h"])
+        dag.add_edges_from_no_data([(0, 1), (1, 0)])
+        res = dag.substitute_subgraph(
+            self.pattern, self.replacement, lambda a, b: a == b, {0: 0, 1: 0}
+        )
+        self.assertEqual([], res)
+        res = dag.substitute_subgraph(
+            self.pattern, self.replacement, lambda a, b: a == b, {0: 0, 1: 0}, induced=False
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["id"], dag.nodes())
+        self.assertEqual([], dag.edge_list())
+
+    def test_cycle_rolled_back(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(["h", "h", "c"])
+        dag.add_edges_from_no_data([(0, 1), (0, 2), (2, 1)])
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.substitute_subgraph(
+                self.pattern, self.replacement, lambda a, b: a == b, {0: 0, 1: 0}
+            )
+        self.assertEqual([0, 1, 2], dag.node_indices())
+        self.assertEqual(["h", "h", "c"], dag.nodes())
+        self.assertEqual([(0, 1), (0, 2), (2, 1)], dag.edge_list())
+
+    def test_empty_match_graph(self):
+        dag = retworkx.generators.directed_path_graph(2)
+        with self.assertRaises(ValueError):
+            dag.substitute_subgraph(retworkx.PyDiGraph(), self.replacement, None, {})
+
+    def test_invalid_boundary_map(self):
+        dag = retworkx.generators.directed_path_graph(2)
+        with self.assertRaises(IndexError):
+            dag.substitute_subgraph(self.pattern, self.replacement, None, {2: 0})
+        with self.assertRaises(IndexError):
+            dag.substitute_subgraph(self.pattern, self.replacement, None, {0: 1})
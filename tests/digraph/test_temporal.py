@@ -0,0 +1,73 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestTemporal(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 3), (2, 3, 2), (1, 3, 5), (0, 2, 4), (3, 4, 5)]
+        )
+
+    def test_temporal_descendants(self):
+        self.assertEqual({1, 2, 3, 4}, retworkx.digraph_temporal_descendants(self.graph, 0))
+        self.assertEqual({2, 3, 4}, retworkx.temporal_descendants(self.graph, 1))
+        self.assertEqual({3, 4}, retworkx.temporal_descendants(self.graph, 2))
+
+    def test_temporal_descendants_start_time(self):
+        res = retworkx.temporal_descendants(self.graph, 0, start_time=2)
+        self.assertEqual({2}, res)
+
+    def test_temporal_descendants_strict(self):
+        self.assertEqual({1, 2, 3, 4}, retworkx.temporal_descendants(self.graph, 0))
+        res = retworkx.temporal_descendants(self.graph, 0, strict=True)
+        self.assertEqual({1, 2, 3}, res)
+
+    def test_earliest_arrival_paths(self):
+        res = retworkx.digraph_earliest_arrival_paths(self.graph, 0)
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2], 3: [0, 1, 3], 4: [0, 1, 3, 4]}, res)
+
+    def test_earliest_arrival_times(self):
+        res = retworkx.earliest_arrival_times(self.graph, 0)
+        self.assertEqual({1: 1.0, 2: 3.0, 3: 5.0, 4: 5.0}, res)
+        res = retworkx.earliest_arrival_times(self.graph, 0, strict=True)
+        self.assertEqual({1: 1.0, 2: 3.0, 3: 5.0}, res)
+
+    def test_time_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, {"t": 2}), (1, 2, {"t": 1})])
+        res = retworkx.earliest_arrival_times(graph, 0, time_fn=lambda edge: edge["t"])
+        self.assertEqual({1: 2.0}, res)
+
+    def test_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.temporal_descendants(self.graph, 42)
+
+    def test_nan_time(self):
+        self.graph.add_edge(4, 0, float("nan"))
+        with self.assertRaises(ValueError):
+            retworkx.earliest_arrival_paths(self.graph, 0)
+
+    def test_temporal_connected_components(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1), (1, 0, 2), (1, 2, 3), (2, 3, 4), (3, 2, 5)])
+        res = retworkx.digraph_temporal_connected_components(graph)
+        self.assertEqual([{0, 1}, {2, 3}], sorted(res, key=min))
+
+    def test_temporal_connected_components_empty(self):
+        self.assertEqual([], retworkx.temporal_connected_components(retworkx.PyDiGraph()))
@@ -47,6 +47,25 @@ class TestToUndirected(unittest.TestCase):
         graph = digraph.to_undirected(multigraph=False, weight_combo_fn=lambda x, y: x + y)
         self.assertEqual(graph.weighted_edge_list(), [(0, 1, "ab")])
 
+    def test_reciprocal_edges_combo_weight_not_multigraph(self):
+        digraph = retworkx.PyDiGraph()
+        digraph.extend_from_weighted_edge_list([(0, 1, 2), (1, 0, 3), (1, 2, 4)])
+        for combo_fn, expected in [
+            (lambda x, y: x + y, 5),
+            (min, 2),
+            (max, 3),
+        ]:
+            with self.subTest(expected=expected):
+                graph = digraph.to_undirected(multigraph=False, weight_combo_fn=combo_fn)
+                self.assertFalse(graph.multigraph)
+                self.assertEqual(graph.weighted_edge_list(), [(0, 1, expected), (1, 2, 4)])
+
+    def test_reciprocal_edges_no_combo_keeps_last(self):
+        digraph = retworkx.PyDiGraph()
+        digraph.extend_from_weighted_edge_list([(0, 1, 2), (1, 0, 3)])
+        graph = digraph.to_undirected(multigraph=False)
+        self.assertEqual(graph.weighted_edge_list(), [(0, 1, 3)])
+
     def test_shared_ref(self):
         digraph = retworkx.PyDiGraph()
         node_weight = {"a": 1}
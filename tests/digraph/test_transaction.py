@@ -0,0 +1,61 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestTransaction(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a", "b", "c"])
+        self.graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 0, 3), (1, 1, 4)])
+
+    def assertUnchanged(self):
+        self.assertEqual([0, 1, 2], self.graph.node_indices())
+        self.assertEqual(["a", "b", "c"], self.graph.nodes())
+        self.assertEqual(
+            {0: (0, 1, 1), 1: (1, 2, 2), 2: (2, 0, 3), 3: (1, 1, 4)},
+            dict(self.graph.edge_index_map()),
+        )
+
+    def test_rollback(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(1)
+            self.graph.add_child(0, "d", 5)
+            self.graph.insert_node_on_in_edges(2, 0)
+            transaction.rollback()
+        self.assertUnchanged()
+
+    def test_rollback_on_exception(self):
+        with self.assertRaises(ValueError):
+            with self.graph.transaction():
+                self.graph.merge_nodes(0, 1)
+                self.graph.remove_edge(1, 2)
+                raise ValueError
+        self.assertUnchanged()
+
+    def test_commit(self):
+        with self.graph.transaction():
+            self.graph.remove_edge_from_index(3)
+        self.assertEqual([(0, 1), (1, 2), (2, 0)], self.graph.edge_list())
+
+    def test_dag(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        with dag.transaction() as transaction:
+            dag.remove_edge(1, 2)
+            dag.add_edge(2, 0, None)
+            transaction.rollback()
+        self.assertEqual([(0, 1), (1, 2)], dag.edge_list())
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, 2, 0, None)
@@ -0,0 +1,130 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTreePredicates(unittest.TestCase):
+    def assertFailure(self, reason, counterexample, check):
+        self.assertFalse(check)
+        self.assertEqual(reason, check.reason)
+        self.assertEqual(counterexample, check.counterexample)
+
+    def test_is_tree(self):
+        graph = retworkx.generators.directed_binomial_tree_graph(3)
+        self.assertTrue(retworkx.digraph_is_tree(graph))
+        self.assertIsNone(retworkx.digraph_is_tree(graph).reason)
+        graph.add_edge(2, 1, None)
+        self.assertFalse(retworkx.digraph_is_tree(graph))
+        self.assertFailure("cycle", [(2, 1), (1, 0), (0, 2)], retworkx.digraph_is_tree(graph))
+
+    def test_is_tree_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        self.assertFalse(retworkx.digraph_is_tree(graph))
+        self.assertFalse(retworkx.is_tree(graph))
+
+    def test_is_tree_ignores_direction(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(1, 0), (1, 2)])
+        self.assertTrue(retworkx.digraph_is_tree(graph))
+        self.assertIsNone(retworkx.digraph_is_tree(graph).reason)
+
+    def test_is_tree_antiparallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from_no_data([(0, 1), (1, 0)])
+        self.assertFalse(retworkx.digraph_is_tree(graph))
+        self.assertFailure("cycle", [(1, 0), (0, 1)], retworkx.digraph_is_tree(graph))
+
+    def test_is_tree_disconnected(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 2, None)
+        self.assertFalse(retworkx.digraph_is_tree(graph))
+        self.assertFailure("disconnected", 1, retworkx.digraph_is_tree(graph))
+
+    def test_is_forest(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (2, 1), (3, 4)])
+        self.assertTrue(retworkx.digraph_is_forest(graph))
+        self.assertIsNone(retworkx.digraph_is_forest(graph).reason)
+        graph.add_edge(4, 3, None)
+        self.assertFalse(retworkx.digraph_is_forest(graph))
+        self.assertFailure("cycle", [(4, 3), (3, 4)], retworkx.digraph_is_forest(graph))
+
+    def test_is_arborescence(self):
+        graph = retworkx.generators.directed_binomial_tree_graph(3)
+        self.assertTrue(retworkx.is_arborescence(graph))
+        self.assertIsNone(retworkx.is_arborescence(graph).reason)
+
+    def test_is_arborescence_multiple_parents(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (2, 1)])
+        self.assertFalse(retworkx.is_arborescence(graph))
+        self.assertFailure("multiple parents", 1, retworkx.is_arborescence(graph))
+
+    def test_is_arborescence_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        self.assertFalse(retworkx.is_arborescence(graph))
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_arborescence(graph))
+
+    def test_is_arborescence_disconnected(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        self.assertFalse(retworkx.is_arborescence(graph))
+        self.assertFailure("disconnected", 2, retworkx.is_arborescence(graph))
+
+    def test_is_arborescence_null_graph(self):
+        self.assertFalse(retworkx.is_arborescence(retworkx.PyDiGraph()))
+        self.assertFailure("null graph", None, retworkx.is_arborescence(retworkx.PyDiGraph())
+        )
+
+    def test_is_branching(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (3, 4)])
+        self.assertTrue(retworkx.is_branching(graph))
+        self.assertIsNone(retworkx.is_branching(graph).reason)
+        self.assertTrue(retworkx.is_branching(retworkx.PyDiGraph()))
+
+    def test_is_branching_multiple_parents(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (3, 4), (2, 4)])
+        self.assertFalse(retworkx.is_branching(graph))
+        self.assertFailure("multiple parents", 4, retworkx.is_branching(graph))
+
+    def test_is_branching_cycle(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 1)])
+        self.assertFalse(retworkx.is_branching(graph))
+        self.assertFailure("multiple parents", 1, retworkx.is_branching(graph))
+        graph = retworkx.generators.directed_cycle_graph(3)
+        self.assertFalse(retworkx.is_branching(graph))
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_branching(graph))
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        self.assertTrue(retworkx.is_tree(graph))
+        self.assertTrue(retworkx.is_forest(graph))
+        self.assertIsNone(retworkx.is_tree(graph).reason)
+        self.assertIsNone(retworkx.is_forest(graph).reason)
+        graph.add_edge(2, 0, None)
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_tree(graph))
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_forest(graph))
@@ -0,0 +1,35 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTrussNumber(unittest.TestCase):
+    def test_empty(self):
+        graph = retworkx.PyDiGraph()
+        res = retworkx.truss_number(graph)
+        self.assertIsInstance(res, dict)
+        self.assertEqual(res, {})
+
+    def test_directed_triangle(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        res = retworkx.digraph_truss_number(graph)
+        self.assertEqual(res, {0: 3, 1: 3, 2: 3})
+
+    def test_opposite_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 0), (2, 3), (3, 3)])
+        res = retworkx.truss_number(graph)
+        self.assertEqual(res, {0: 3, 1: 3, 2: 3, 3: 3, 4: 2, 5: 2})
@@ -0,0 +1,96 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestValidate(unittest.TestCase):
+    def test_valid(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        self.assertEqual({}, retworkx.validate(graph))
+        self.assertEqual({}, retworkx.validate(graph, expect_dag=True))
+
+    def test_issues(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 1), (2, 2), (1, 0)])
+        issues = retworkx.validate(graph)
+        self.assertEqual({"self_loops", "parallel_edges", "isolated_nodes"}, set(issues))
+        self.assertEqual([3], issues["self_loops"])
+        self.assertEqual([[0, 2]], issues["parallel_edges"])
+        self.assertEqual([3, 4], issues["isolated_nodes"])
+
+    def test_antiparallel_edges_not_parallel(self):
+        graph = retworkx.generators.directed_cycle_graph(2)
+        self.assertEqual({}, retworkx.validate(graph))
+
+    def test_expect_dag(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 4), (4, 2)])
+        self.assertNotIn("cycle", retworkx.validate(graph))
+        issues = retworkx.validate(graph, expect_dag=True)
+        self.assertEqual([(2, 3), (3, 4), (4, 2)], issues["cycle"])
+
+    def test_expect_dag_self_loop(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from_no_data([(0, 1), (1, 1)])
+        issues = retworkx.validate(graph, expect_dag=True)
+        self.assertEqual([(1, 1)], issues["cycle"])
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.validate("not a graph")
+
+
+class TestRepair(unittest.TestCase):
+    def test_remove_self_loops(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1), (1, 2), (1, 1)])
+        self.assertEqual([0, 2, 4], graph.remove_self_loops())
+        self.assertEqual([(0, 1), (1, 2)], graph.edge_list())
+        self.assertEqual([], graph.remove_self_loops())
+
+    def test_remove_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 1, 3), (1, 0, 5), (0, 1, 4)])
+        self.assertEqual([2, 4], graph.remove_parallel_edges(lambda a, b: a + b))
+        self.assertFalse(graph.has_parallel_edges())
+        self.assertEqual([(0, 1, 8), (1, 2, 2), (1, 0, 5)], graph.weighted_edge_list())
+
+    def test_remove_parallel_edges_no_combiner(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b")])
+        self.assertEqual([1], graph.remove_parallel_edges())
+        self.assertEqual([(0, 1, "a")], graph.weighted_edge_list())
+
+    def test_remove_isolates(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (3, 3)])
+        self.assertEqual([2, 4], graph.remove_isolates())
+        self.assertEqual([0, 1, 3], graph.node_indices())
+
+    def test_repair_then_validate(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (2, 2)])
+        graph.remove_parallel_edges()
+        graph.remove_self_loops()
+        graph.remove_isolates()
+        self.assertEqual({}, retworkx.validate(graph))
+        self.assertEqual([0, 1], graph.node_indices())
@@ -0,0 +1,65 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestWeisfeilerLehmanSubgraphHashes(unittest.TestCase):
+    def test_directed_path(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        hashes = retworkx.weisfeiler_lehman_subgraph_hashes(graph, depth=1)
+        self.assertEqual(list(hashes), [0, 1, 2, 3])
+        # Every node has a different degree or successor degree
+        self.assertEqual(len(set(hashes.values())), 4)
+        self.assertNotEqual(
+            retworkx.digraph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)[0],
+            retworkx.digraph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)[1],
+        )
+
+    def test_direction_matters(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (2, 1)])
+        hashes = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(graph, depth=1)
+        self.assertEqual(hashes[0], hashes[2])
+        reverse = retworkx.PyDiGraph()
+        reverse.add_nodes_from(range(3))
+        reverse.add_edges_from_no_data([(1, 0), (1, 2)])
+        reverse_hashes = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(reverse, depth=1)
+        self.assertNotEqual(hashes[0], reverse_hashes[0])
+        self.assertNotEqual(hashes[1], reverse_hashes[1])
+
+    def test_depth_zero_uses_in_and_out_degree(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edge(0, 1, None)
+        hashes = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)
+        self.assertNotEqual(hashes[0], hashes[1])
+        undirected = retworkx.graph_weisfeiler_lehman_subgraph_hashes(
+            graph.to_undirected(), depth=0
+        )
+        self.assertEqual(undirected[0], undirected[1])
+
+    def test_node_and_edge_attr_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "a", "b"])
+        graph.add_edges_from([(0, 2, "x"), (1, 2, "y")])
+        hashes = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(
+            graph, depth=1, node_attr_fn=str
+        )
+        self.assertEqual(hashes[0], hashes[1])
+        labeled = retworkx.digraph_weisfeiler_lehman_subgraph_hashes(
+            graph, depth=1, node_attr_fn=str, edge_attr_fn=str
+        )
+        self.assertNotEqual(labeled[0], labeled[1])
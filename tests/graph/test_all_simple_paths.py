@@ -176,3 +176,70 @@ class TestGraphAllSimplePaths(unittest.TestCase):
         dag.add_node(0)
         dag.add_node(1)
         self.assertRaises(TypeError, retworkx.graph_all_simple_paths, (dag, 0, 1))
+
+    def test_iter_all_simple_paths_matches_list(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data(self.edges)
+        for min_depth, cutoff in [(None, None), (6, None), (None, 4), (4, 4)]:
+            paths = retworkx.graph_iter_all_simple_paths(
+                graph, 0, 5, min_depth=min_depth, cutoff=cutoff
+            )
+            expected = retworkx.graph_all_simple_paths(
+                graph, 0, 5, min_depth=min_depth, cutoff=cutoff
+            )
+            # The list of paths repeats the paths through parallel edges
+            expected = sorted(list(path) for path in set(tuple(path) for path in expected))
+            self.assertEqual(expected, sorted(paths))
+
+    def test_iter_all_simple_paths_is_lazy(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data(self.edges)
+        expected = sorted(retworkx.graph_iter_all_simple_paths(graph, 0, 5))
+        paths = retworkx.graph_iter_all_simple_paths(graph, 0, 5)
+        first = next(paths)
+        graph.remove_node(5)
+        self.assertEqual(expected, sorted([first] + list(paths)))
+
+    def test_iter_all_simple_paths_invalid_node_index(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(0)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_iter_all_simple_paths(graph, 0, 5)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_all_simple_edge_paths(graph, 5, 0)
+
+    def test_iter_all_simple_paths_no_path(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        self.assertEqual([], list(retworkx.graph_iter_all_simple_paths(graph, 0, 1)))
+        self.assertEqual([], list(retworkx.graph_iter_all_simple_paths(graph, 0, 0)))
+
+    def test_all_simple_edge_paths_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (0, 2)])
+        self.assertEqual(
+            [[0, 1, 2], [0, 2]],
+            sorted(retworkx.graph_iter_all_simple_paths(graph, 0, 2)),
+        )
+        self.assertEqual(
+            [[0, 2], [1, 2], [3]],
+            sorted(retworkx.graph_all_simple_edge_paths(graph, 0, 2)),
+        )
+        self.assertEqual(
+            [[0, 2], [1, 2]],
+            sorted(retworkx.graph_all_simple_edge_paths(graph, 0, 2, min_depth=3)),
+        )
+        self.assertEqual(
+            [[3]],
+            list(retworkx.graph_all_simple_edge_paths(graph, 0, 2, cutoff=2)),
+        )
+
+    def test_iter_all_simple_paths_universal(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2)])
+        self.assertEqual([[0, 1, 2]], list(retworkx.iter_all_simple_paths(graph, 0, 2)))
+        self.assertEqual([[0, 2], [1, 2]], sorted(retworkx.all_simple_edge_paths(graph, 0, 2)))
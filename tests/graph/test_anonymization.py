@@ -0,0 +1,111 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import collections
+import unittest
+
+import retworkx
+
+
+class TestAnonymizeDegreeSequence(unittest.TestCase):
+    def test_anonymize(self):
+        self.assertEqual(
+            [5, 5, 3, 3, 2, 2],
+            retworkx.anonymize_degree_sequence([5, 3, 3, 2, 2, 1], 2),
+        )
+
+    def test_keeps_positions(self):
+        self.assertEqual(
+            [2, 5, 3, 5, 3, 2],
+            retworkx.anonymize_degree_sequence([1, 5, 2, 3, 3, 2], 2),
+        )
+
+    def test_k_one(self):
+        self.assertEqual([3, 1, 2], retworkx.anonymize_degree_sequence([3, 1, 2], 1))
+
+    def test_k_is_length(self):
+        self.assertEqual([4, 4, 4], retworkx.anonymize_degree_sequence([1, 4, 2], 3))
+
+    def test_groups(self):
+        degrees = [9, 8, 8, 7, 4, 4, 3, 3, 1]
+        anonymized = retworkx.anonymize_degree_sequence(degrees, 3)
+        counts = collections.Counter(anonymized)
+        self.assertTrue(all(count >= 3 for count in counts.values()))
+        self.assertTrue(all(a >= d for a, d in zip(anonymized, degrees)))
+        self.assertEqual([9, 9, 9, 9, 4, 4, 4, 4, 4], anonymized)
+
+    def test_empty(self):
+        self.assertEqual([], retworkx.anonymize_degree_sequence([], 2))
+
+    def test_invalid_k(self):
+        with self.assertRaises(ValueError):
+            retworkx.anonymize_degree_sequence([1, 1], 0)
+        with self.assertRaises(ValueError):
+            retworkx.anonymize_degree_sequence([1, 1], 3)
+
+
+class TestKDegreeAnonymize(unittest.TestCase):
+    def test_is_k_degree_anonymous(self):
+        graph = retworkx.generators.cycle_graph(5)
+        self.assertTrue(retworkx.is_k_degree_anonymous(graph, 5))
+        self.assertFalse(retworkx.is_k_degree_anonymous(graph, 6))
+        graph = retworkx.generators.star_graph(5)
+        self.assertTrue(retworkx.is_k_degree_anonymous(graph, 1))
+        self.assertFalse(retworkx.is_k_degree_anonymous(graph, 2))
+        with self.assertRaises(ValueError):
+            retworkx.is_k_degree_anonymous(graph, 0)
+
+    def test_star(self):
+        graph = retworkx.generators.star_graph(5)
+        graph.add_node(None)
+        added = retworkx.k_degree_anonymize(graph, 2, seed=42)
+        self.assertEqual([(1, 2), (1, 3), (1, 5)], added)
+        self.assertTrue(retworkx.is_k_degree_anonymous(graph, 2))
+        self.assertEqual([None] * 3, [graph.get_edge_data(*edge) for edge in added])
+
+    def test_supergraph(self):
+        for seed in range(10):
+            with self.subTest(seed=seed):
+                graph = retworkx.undirected_gnp_random_graph(30, 0.15, seed=seed)
+                original = set(graph.edge_list())
+                added = retworkx.k_degree_anonymize(graph, 4, seed=seed)
+                self.assertTrue(retworkx.is_k_degree_anonymous(graph, 4))
+                self.assertTrue(original.issubset(set(graph.edge_list())))
+                self.assertEqual(len(original) + len(added), graph.num_edges())
+                self.assertFalse(graph.has_parallel_edges())
+                self.assertEqual(0, graph.number_of_selfloops())
+
+    def test_already_anonymous(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        self.assertEqual([], retworkx.k_degree_anonymize(graph, 1))
+
+    def test_swaps_keep_anonymity(self):
+        graph = retworkx.undirected_gnp_random_graph(30, 0.2, seed=1)
+        retworkx.k_degree_anonymize(graph, 3, seed=1)
+        retworkx.graph_double_edge_swap(graph, nswap=20, seed=1)
+        self.assertTrue(retworkx.is_k_degree_anonymous(graph, 3))
+
+    def test_perturbation(self):
+        # The anonymized degree sequence [3, 3, 3, 3, 3] has an odd sum so it
+        # can only be realized after perturbing the degree of the center
+        graph = retworkx.generators.star_graph(4)
+        graph.add_node(None)
+        edges = graph.edge_list()
+        with self.assertRaises(ValueError):
+            retworkx.k_degree_anonymize(graph, 5, max_tries=1)
+        self.assertEqual(edges, graph.edge_list())
+        retworkx.k_degree_anonymize(graph, 5, seed=42)
+        self.assertEqual(10, graph.num_edges())
+
+    def test_invalid_k(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.k_degree_anonymize(graph, 4)
@@ -0,0 +1,37 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestBellmanFord(unittest.TestCase):
+    def test_path_lengths(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 4.0), (0, 2, 2.0), (2, 1, 1.0), (1, 3, 1.0)])
+        res = retworkx.graph_bellman_ford_shortest_path_lengths(graph, 0, float)
+        self.assertEqual({1: 3.0, 2: 2.0, 3: 4.0}, res)
+        self.assertEqual(res, retworkx.bellman_ford_shortest_path_lengths(graph, 0, float))
+
+    def test_negative_edge_is_cycle(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, -1.0)])
+        with self.assertRaises(retworkx.NegativeCycleError) as cm:
+            retworkx.graph_bellman_ford_shortest_path_lengths(graph, 0, float)
+        self.assertEqual([1, 2], sorted(cm.exception.cycle))
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_bellman_ford_shortest_path_lengths(graph, 5, lambda _: 1.0)
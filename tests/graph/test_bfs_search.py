@@ -160,3 +160,32 @@ class TestBfsSearch(unittest.TestCase):
 
         vis = PruneGrayTargetEdge()
         retworkx.graph_bfs_search(self.graph, [0], vis)
+
+    def test_graph_bfs_node_mask(self):
+        class TreeEdgesRecorder(retworkx.visit.BFSVisitor):
+            def __init__(self):
+                self.edges = []
+
+            def tree_edge(self, edge):
+                self.edges.append((edge[0], edge[1]))
+
+        vis = TreeEdgesRecorder()
+        retworkx.graph_bfs_search(self.graph, [0, 2], vis, node_mask={2})
+        masked = self.graph.copy()
+        masked.remove_node(2)
+        expected = TreeEdgesRecorder()
+        retworkx.graph_bfs_search(masked, [0], expected)
+        self.assertEqual(expected.edges, vis.edges)
+        self.assertNotIn(2, [node for edge in vis.edges for node in edge])
+
+    def test_graph_bfs_node_mask_no_starting_point(self):
+        class TreeEdgesRecorder(retworkx.visit.BFSVisitor):
+            def __init__(self):
+                self.edges = []
+
+            def tree_edge(self, edge):
+                self.edges.append((edge[0], edge[1]))
+
+        vis = TreeEdgesRecorder()
+        retworkx.bfs_search(self.graph, None, vis, node_mask=[0, 1, 2, 3, 5, 6])
+        self.assertEqual([(4, 7)], vis.edges)
@@ -0,0 +1,93 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestBipartiteMetrics(unittest.TestCase):
+    def setUp(self):
+        # Top nodes 0 and 1, bottom nodes 2, 3 and 4
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from_no_data([(0, 2), (0, 3), (0, 4), (1, 2), (1, 3)])
+
+    def test_density(self):
+        self.assertAlmostEqual(5 / 6, retworkx.bipartite_density(self.graph, [0, 1]))
+        self.assertAlmostEqual(5 / 6, retworkx.bipartite_density(self.graph, [2, 3, 4]))
+
+    def test_density_ignores_edges_within_a_set(self):
+        self.graph.add_edge(0, 1, None)
+        self.assertAlmostEqual(5 / 6, retworkx.bipartite_density(self.graph, [0, 1]))
+
+    def test_density_empty_set(self):
+        self.assertEqual(0.0, retworkx.bipartite_density(self.graph, []))
+
+    def test_density_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bipartite_density(self.graph, [0, 7])
+
+    def test_degree_centrality(self):
+        centrality = retworkx.bipartite_degree_centrality(self.graph, [0, 1])
+        expected = {0: 1.0, 1: 2 / 3, 2: 1.0, 3: 1.0, 4: 0.5}
+        self.assertEqual(set(expected), set(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node])
+
+    def test_degree_centrality_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bipartite_degree_centrality(self.graph, [5])
+
+    def test_node_redundancy(self):
+        redundancy = retworkx.node_redundancy(self.graph, [0, 1, 2, 3])
+        self.assertEqual({0: 1 / 3, 1: 1.0, 2: 1.0, 3: 1.0}, redundancy)
+
+    def test_node_redundancy_complete_bipartite(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(top, bottom) for top in range(2) for bottom in range(2, 5)])
+        self.assertEqual({node: 1.0 for node in range(5)}, retworkx.node_redundancy(graph))
+
+    def test_node_redundancy_degree_one(self):
+        with self.assertRaises(ValueError):
+            retworkx.node_redundancy(self.graph)
+
+    def test_clustering_path(self):
+        graph = retworkx.generators.path_graph(4)
+        self.assertEqual({0: 0.5, 1: 0.5, 2: 0.5, 3: 0.5}, retworkx.bipartite_clustering(graph))
+        self.assertEqual(
+            {0: 1.0, 1: 1.0, 2: 1.0, 3: 1.0},
+            retworkx.bipartite_clustering(graph, mode="min"),
+        )
+        self.assertEqual(
+            {0: 0.5, 1: 0.5, 2: 0.5, 3: 0.5},
+            retworkx.bipartite_clustering(graph, mode="max"),
+        )
+
+    def test_clustering(self):
+        clustering = retworkx.bipartite_clustering(self.graph, nodes=[0, 4])
+        # N(N(0)) = {1}, N(0) = {2, 3, 4} and N(1) = {2, 3}
+        self.assertAlmostEqual(2 / 3, clustering[0])
+        # N(N(4)) = {2, 3}, N(4) = {0} and N(2) = N(3) = {0, 1}
+        self.assertAlmostEqual(0.5, clustering[4])
+
+    def test_clustering_isolated_node(self):
+        self.graph.add_node(None)
+        self.assertEqual(0.0, retworkx.bipartite_clustering(self.graph)[5])
+
+    def test_clustering_invalid_mode(self):
+        with self.assertRaises(ValueError):
+            retworkx.bipartite_clustering(self.graph, mode="mean")
+
+    def test_clustering_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bipartite_clustering(self.graph, nodes=[9])
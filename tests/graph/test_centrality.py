@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -57,6 +58,89 @@ class TestCentralityGraph(unittest.TestCase):
         self.assertEqual(expected, betweenness)
 
 
+    def test_betweenness_centrality_array(self):
+        betweenness = retworkx.graph_betweenness_centrality_array(self.graph, endpoints=True)
+        expected = retworkx.graph_betweenness_centrality(self.graph, endpoints=True)
+        self.assertEqual([expected[node] for node in range(4)], betweenness.tolist())
+
+    def test_betweenness_centrality_array_universal(self):
+        betweenness = retworkx.betweenness_centrality_array(self.graph, normalized=False)
+        expected = retworkx.betweenness_centrality(self.graph, normalized=False)
+        self.assertEqual([expected[node] for node in range(4)], betweenness.tolist())
+
+    def test_betweenness_centrality_edge_filter(self):
+        betweenness = retworkx.graph_betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda _: False
+        )
+        self.assertEqual({0: 0.0, 1: 0.0, 2: 0.0, 3: 0.0}, betweenness)
+        self.graph.add_edge(self.a, self.d, 2)
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda weight: weight == 1
+        )
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.betweenness_centrality(self.graph, normalized=False), betweenness)
+
+    def test_betweenness_centrality_node_mask(self):
+        betweenness = retworkx.graph_betweenness_centrality(self.graph, node_mask={self.d})
+        self.graph.remove_node(self.d)
+        self.assertEqual(retworkx.graph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_node_mask_universal(self):
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, node_mask=[self.a, self.d]
+        )
+        self.assertEqual({self.b: 0.0, self.c: 0.0}, betweenness)
+
+    def test_betweenness_centrality_weighted(self):
+        self.graph.add_edge(self.a, self.d, 10)
+        betweenness = retworkx.graph_betweenness_centrality(self.graph, weight_fn=float)
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.graph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_weighted_uniform(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        expected = retworkx.graph_betweenness_centrality(graph, endpoints=True)
+        for parallel_threshold in [0, 50]:
+            betweenness = retworkx.graph_betweenness_centrality(
+                graph,
+                endpoints=True,
+                parallel_threshold=parallel_threshold,
+                weight_fn=lambda _: 0.5,
+            )
+            self.assertEqual(set(expected.keys()), set(betweenness.keys()))
+            for node in expected:
+                self.assertAlmostEqual(expected[node], betweenness[node])
+
+    def test_betweenness_centrality_weighted_equal_paths(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.5), (1, 3, 1.0), (0, 2, 0.5), (2, 3, 2.0)])
+        betweenness = retworkx.graph_betweenness_centrality(
+            graph, normalized=False, weight_fn=float
+        )
+        # Both paths between 0 and 3 have a weight of 2.5, the only shortest
+        # path between 1 and 2 goes through 0
+        self.assertEqual({0: 1.0, 1: 0.5, 2: 0.5, 3: 0.0}, betweenness)
+
+    def test_betweenness_centrality_default_weight(self):
+        betweenness = retworkx.graph_betweenness_centrality(self.graph, default_weight=2.5)
+        self.assertEqual(retworkx.graph_betweenness_centrality(self.graph), betweenness)
+
+    def test_betweenness_centrality_weighted_universal(self):
+        self.graph.add_edge(self.a, self.d, 10)
+        betweenness = retworkx.betweenness_centrality(
+            self.graph, normalized=False, edge_filter_fn=lambda w: w < 5, weight_fn=float
+        )
+        self.graph.remove_edge(self.a, self.d)
+        self.assertEqual(retworkx.betweenness_centrality(self.graph, normalized=False), betweenness)
+
+    def test_betweenness_centrality_invalid_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_betweenness_centrality(self.graph, weight_fn=lambda _: -1.0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_betweenness_centrality(self.graph, default_weight=float("nan"))
+
+
 class TestCentralityGraphDeletedNode(unittest.TestCase):
     def setUp(self):
         self.graph = retworkx.PyGraph()
@@ -99,3 +183,132 @@ class TestCentralityGraphDeletedNode(unittest.TestCase):
         )
         expected = {0: 0.0, 1: 2.0, 2: 2.0, 4: 0.0}
         self.assertEqual(expected, betweenness)
+
+    def test_betweenness_centrality_array(self):
+        betweenness = retworkx.graph_betweenness_centrality_array(self.graph).tolist()
+        expected = retworkx.graph_betweenness_centrality(self.graph)
+        self.assertEqual(5, len(betweenness))
+        self.assertTrue(math.isnan(betweenness[3]))
+        for node, value in expected.items():
+            self.assertEqual(value, betweenness[node])
+
+
+class TestClosenessCentralityGraph(unittest.TestCase):
+    def test_closeness_centrality(self):
+        graph = retworkx.generators.path_graph(5)
+        closeness = retworkx.graph_closeness_centrality(graph)
+        expected = {0: 0.4, 1: 4 / 7, 2: 4 / 6, 3: 4 / 7, 4: 0.4}
+        self.assertEqual(set(expected), set(closeness))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 4)])
+        closeness = retworkx.graph_closeness_centrality(graph)
+        expected = {0: 1 / 3, 1: 0.5, 2: 1 / 3, 3: 0.25, 4: 0.25}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+        closeness = retworkx.graph_closeness_centrality(graph, wf_improved=False)
+        expected = {0: 2 / 3, 1: 1.0, 2: 2 / 3, 3: 1.0, 4: 1.0}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_isolated_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(0)
+        self.assertEqual({0: 0.0}, retworkx.graph_closeness_centrality(graph))
+
+    def test_closeness_centrality_empty(self):
+        self.assertEqual({}, retworkx.graph_closeness_centrality(retworkx.PyGraph()))
+
+    def test_closeness_centrality_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 2.0), (1, 2, 3.0)])
+        closeness = retworkx.graph_closeness_centrality(graph, weight_fn=float)
+        expected = {0: 2 / 7, 1: 2 / 5, 2: 2 / 8}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_default_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        closeness = retworkx.graph_closeness_centrality(graph, default_weight=2.0)
+        expected = {0: 1 / 3, 1: 0.5, 2: 1 / 3}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, closeness[node])
+
+    def test_closeness_centrality_invalid_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_closeness_centrality(graph, weight_fn=lambda _: -1.0)
+
+    def test_closeness_centrality_deleted_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(3)
+        closeness = retworkx.graph_closeness_centrality(graph)
+        self.assertEqual({0, 1, 2}, set(closeness))
+        self.assertAlmostEqual(1.0, closeness[1])
+
+    def test_closeness_centrality_universal(self):
+        graph = retworkx.generators.path_graph(5)
+        self.assertEqual(
+            retworkx.graph_closeness_centrality(graph), retworkx.closeness_centrality(graph)
+        )
+
+
+class TestEigenvectorCentralityGraph(unittest.TestCase):
+    def test_eigenvector_centrality(self):
+        graph = retworkx.generators.path_graph(3)
+        centrality = retworkx.graph_eigenvector_centrality(graph, tol=1e-9)
+        expected = {0: 0.5, 1: math.sqrt(0.5), 2: 0.5}
+        self.assertEqual(set(expected), set(centrality))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_eigenvector_centrality_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(4)
+        centrality = retworkx.graph_eigenvector_centrality(graph)
+        for node in graph.node_indices():
+            self.assertAlmostEqual(0.5, centrality[node])
+
+    def test_eigenvector_centrality_weighted(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 2.0)])
+        centrality = retworkx.graph_eigenvector_centrality(graph, weight_fn=float, tol=1e-9)
+        expected = {0: 1 / math.sqrt(10), 1: math.sqrt(0.5), 2: 2 / math.sqrt(10)}
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_eigenvector_centrality_default_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        centrality = retworkx.graph_eigenvector_centrality(graph, default_weight=2.0, tol=1e-9)
+        self.assertAlmostEqual(math.sqrt(0.5), centrality[1], places=6)
+
+    def test_eigenvector_centrality_deleted_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(3)
+        centrality = retworkx.graph_eigenvector_centrality(graph, tol=1e-9)
+        self.assertEqual({0, 1, 2}, set(centrality))
+        self.assertAlmostEqual(math.sqrt(0.5), centrality[1], places=6)
+
+    def test_eigenvector_centrality_empty(self):
+        self.assertEqual({}, retworkx.graph_eigenvector_centrality(retworkx.PyGraph()))
+
+    def test_eigenvector_centrality_no_convergence(self):
+        graph = retworkx.generators.path_graph(5)
+        with self.assertRaises(retworkx.ConvergenceError):
+            retworkx.graph_eigenvector_centrality(graph, max_iter=1)
+
+    def test_eigenvector_centrality_invalid_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_eigenvector_centrality(graph, weight_fn=lambda _: -1.0)
+
+    def test_eigenvector_centrality_universal(self):
+        graph = retworkx.generators.star_graph(5)
+        self.assertEqual(
+            retworkx.graph_eigenvector_centrality(graph), retworkx.eigenvector_centrality(graph)
+        )
@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def is_clique(graph, nodes):
+    return all(graph.has_edge(a, b) for a, b in itertools.combinations(nodes, 2))
+
+
+def brute_force_cliques(graph):
+    nodes = list(graph.node_indices())
+    cliques = [
+        set(subset)
+        for size in range(1, len(nodes) + 1)
+        for subset in itertools.combinations(nodes, size)
+        if is_clique(graph, subset)
+    ]
+    return sorted(
+        sorted(clique) for clique in cliques if not any(clique < other for other in cliques)
+    )
+
+
+class TestMaxClique(unittest.TestCase):
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(6)
+        self.assertEqual([0, 1, 2, 3, 4, 5], list(retworkx.max_clique(graph)))
+
+    def test_two_cliques(self):
+        graph = retworkx.generators.mesh_graph(3)
+        graph.add_nodes_from([None] * 4)
+        graph.extend_from_edge_list([(2, 3), (3, 4), (3, 5), (3, 6), (4, 5), (4, 6), (5, 6)])
+        self.assertEqual([3, 4, 5, 6], list(retworkx.max_clique(graph)))
+
+    def test_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(14, 0.5, seed=seed)
+            clique = list(retworkx.max_clique(graph))
+            self.assertTrue(is_clique(graph, clique))
+            expected = max(len(clique) for clique in brute_force_cliques(graph))
+            self.assertEqual(expected, len(clique))
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([None] * 3)
+        self.assertEqual(1, len(retworkx.max_clique(graph)))
+
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.max_clique(retworkx.PyGraph())))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 0), (0, 1), (0, 1), (1, 2), (2, 2)])
+        self.assertEqual(2, len(retworkx.max_clique(graph)))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.mesh_graph(5)
+        graph.remove_node(1)
+        self.assertEqual([0, 2, 3, 4], list(retworkx.max_clique(graph)))
+
+
+class TestFindCliques(unittest.TestCase):
+    def test_two_cliques(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.extend_from_edge_list([(3, 4), (4, 5), (5, 3)])
+        res = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+        self.assertEqual([[0, 1, 2, 3], [3, 4, 5]], res)
+
+    def test_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(12, 0.4, seed=seed)
+            res = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+            self.assertEqual(brute_force_cliques(graph), res)
+
+    def test_isolated_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([None] * 3)
+        graph.add_edge(0, 1, None)
+        res = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+        self.assertEqual([[0, 1], [2]], res)
+
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.find_cliques(retworkx.PyGraph())))
+
+    def test_lazy(self):
+        graph = retworkx.generators.mesh_graph(3)
+        iterator = retworkx.find_cliques(graph)
+        self.assertIs(iterator, iter(iterator))
+        self.assertEqual([0, 1, 2], sorted(next(iterator)))
+        with self.assertRaises(StopIteration):
+            next(iterator)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        res = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+        self.assertEqual([[0], [2, 3]], res)
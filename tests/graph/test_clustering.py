@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTriangles(unittest.TestCase):
+    def test_triangles(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (3, 4)])
+        res = retworkx.graph_triangles(graph)
+        self.assertEqual({0: 2, 1: 1, 2: 1, 3: 1, 4: 1}, res)
+
+    def test_triangles_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.graph_triangles(graph)
+        self.assertEqual({node: 6 for node in range(5)}, res)
+
+    def test_triangles_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (0, 2), (1, 2), (2, 2), (0, 0)])
+        res = retworkx.graph_triangles(graph)
+        self.assertEqual({0: 1, 1: 1, 2: 1}, res)
+
+    def test_triangles_removed_nodes(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.remove_node(1)
+        res = retworkx.graph_triangles(graph)
+        self.assertEqual({0: 1, 2: 1, 3: 1}, res)
+
+    def test_triangles_empty(self):
+        self.assertEqual({}, retworkx.graph_triangles(retworkx.PyGraph()))
+
+
+class TestClustering(unittest.TestCase):
+    def test_clustering(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)])
+        res = retworkx.graph_clustering(graph)
+        self.assertEqual({0: 1 / 6, 1: 1.0, 2: 1.0, 3: 0.0, 4: 0.0}, res)
+
+    def test_clustering_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.graph_clustering(graph)
+        self.assertEqual({node: 1.0 for node in range(5)}, res)
+
+    def test_clustering_parallel_edges(self):
+        graph = retworkx.generators.cycle_graph(3)
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 0)])
+        res = retworkx.graph_clustering(graph)
+        self.assertEqual({0: 1.0, 1: 1.0, 2: 1.0}, res)
+
+    def test_average_clustering(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)])
+        res = retworkx.graph_average_clustering(graph)
+        self.assertAlmostEqual((1 / 6 + 2) / 5, res)
+
+    def test_average_clustering_without_zeros(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)])
+        res = retworkx.graph_average_clustering(graph, count_zeros=False)
+        self.assertAlmostEqual((1 / 6 + 2) / 3, res)
+
+    def test_average_clustering_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(0.0, retworkx.graph_average_clustering(graph))
+        graph.add_nodes_from(list(range(3)))
+        self.assertEqual(0.0, retworkx.graph_average_clustering(graph, count_zeros=False))
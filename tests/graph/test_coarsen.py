@@ -0,0 +1,119 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestCoarsen(unittest.TestCase):
+    def assertValidHierarchy(self, graph, levels, weight_fn=lambda _: 1.0):
+        fine = graph
+        for depth, (coarse, node_map) in enumerate(levels):
+            self.assertEqual(set(fine.node_indices()), set(node_map.keys()))
+            self.assertEqual(set(coarse.node_indices()), set(node_map.values()))
+            self.assertLess(len(coarse), len(fine))
+            fine_weights = [1] * len(fine) if depth == 0 else fine.nodes()
+            self.assertEqual(sum(fine_weights), sum(coarse.nodes()))
+            members = {}
+            for node, coarse_node in node_map.items():
+                members.setdefault(coarse_node, []).append(node)
+            for coarse_node, nodes in members.items():
+                self.assertLessEqual(len(nodes), 2)
+                if depth == 0:
+                    self.assertEqual(len(nodes), coarse[coarse_node])
+                else:
+                    self.assertEqual(sum(fine[node] for node in nodes), coarse[coarse_node])
+            expected = {}
+            for source, target, weight in fine.weighted_edge_list():
+                source, target = sorted((node_map[source], node_map[target]))
+                if source != target:
+                    weight = weight_fn(weight) if depth == 0 else weight
+                    expected[(source, target)] = expected.get((source, target), 0) + weight
+            edges = {tuple(sorted((s, t))): w for s, t, w in coarse.weighted_edge_list()}
+            self.assertEqual(len(edges), coarse.num_edges())
+            self.assertEqual(expected.keys(), edges.keys())
+            for key, weight in edges.items():
+                self.assertAlmostEqual(expected[key], weight)
+            fine = coarse
+
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(8, 8)
+        levels = retworkx.graph_coarsen(graph, seed=42)
+        self.assertValidHierarchy(graph, levels)
+        self.assertLessEqual(len(levels[-1][0]), 6)
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(6, 6)
+        first = retworkx.graph_coarsen(graph, seed=1234)
+        second = retworkx.graph_coarsen(graph, seed=1234)
+        self.assertEqual(
+            [(g.weighted_edge_list(), dict(m)) for g, m in first],
+            [(g.weighted_edge_list(), dict(m)) for g, m in second],
+        )
+
+    def test_min_nodes_and_max_levels(self):
+        graph = retworkx.generators.grid_graph(8, 8)
+        levels = retworkx.graph_coarsen(graph, min_nodes=20, seed=42)
+        self.assertLessEqual(len(levels[-1][0]), 20)
+        self.assertGreater(len(levels[-2][0]), 20)
+        levels = retworkx.graph_coarsen(graph, max_levels=2, seed=42)
+        self.assertEqual(2, len(levels))
+        self.assertEqual([], retworkx.graph_coarsen(graph, max_levels=0))
+        self.assertEqual([], retworkx.graph_coarsen(graph, min_nodes=64))
+
+    def test_heavy_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 10), (1, 2, 1), (2, 3, 10), (3, 0, 1)])
+        for seed in range(20):
+            levels = retworkx.graph_coarsen(graph, weight_fn=float, max_levels=1, seed=seed)
+            self.assertValidHierarchy(graph, levels, float)
+            coarse, node_map = levels[0]
+            self.assertEqual({0: 0, 1: 0, 2: 1, 3: 1}, node_map)
+            self.assertEqual([(0, 1, 2.0)], coarse.weighted_edge_list())
+
+    def test_default_weight_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 2)])
+        levels = retworkx.graph_coarsen(graph, default_weight=0.5, max_levels=1, seed=3)
+        self.assertValidHierarchy(graph, levels, lambda _: 0.5)
+        self.assertEqual({0: 0, 1: 0, 2: 1}, levels[0][1])
+        self.assertEqual([(0, 1, 0.5)], levels[0][0].weighted_edge_list())
+
+    def test_index_holes(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        graph.remove_nodes_from([0, 7, 13])
+        levels = retworkx.graph_coarsen(graph, seed=7)
+        self.assertValidHierarchy(graph, levels)
+
+    def test_star(self):
+        graph = retworkx.generators.star_graph(20)
+        levels = retworkx.graph_coarsen(graph, seed=42)
+        self.assertEqual(1, len(levels))
+        self.assertEqual(19, len(levels[0][0]))
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual([], retworkx.graph_coarsen(graph))
+        graph.add_nodes_from(range(5))
+        self.assertEqual([], retworkx.graph_coarsen(graph))
+
+    def test_universal(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        levels = retworkx.coarsen(graph, seed=42)
+        self.assertIsInstance(levels[0][0], retworkx.PyGraph)
+        self.assertValidHierarchy(graph, levels)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.coarsen(None)
@@ -36,6 +36,30 @@ class TestConnectedComponents(unittest.TestCase):
         components = retworkx.connected_components(graph)
         self.assertEqual([{0, 1, 2, 3}, {4, 5, 6, 7}], components)
 
+    def test_connected_components_isolated_nodes_and_holes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(7)))
+        graph.add_edges_from_no_data([(6, 0), (2, 5), (5, 3)])
+        graph.remove_node(1)
+        components = retworkx.connected_components(graph)
+        self.assertEqual([{0, 6}, {2, 3, 5}, {4}], components)
+
+    def test_connected_components_parallel(self):
+        graph = retworkx.undirected_gnp_random_graph(500, 0.004, seed=42)
+        graph.remove_nodes_from([3, 17, 256])
+        expected = retworkx.connected_components(graph)
+        self.assertEqual(retworkx.number_connected_components(graph), len(expected))
+        self.assertEqual(
+            sorted(graph.node_indices()), sorted(node for comp in expected for node in comp)
+        )
+        for _ in range(5):
+            components = retworkx.connected_components(graph, parallel_threshold=2)
+            self.assertEqual(expected, components)
+
+    def test_connected_components_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual([], retworkx.connected_components(graph, parallel_threshold=0))
+
     def test_node_connected_component(self):
         graph = retworkx.PyGraph()
         graph.extend_from_edge_list(
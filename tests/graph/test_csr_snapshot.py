@@ -0,0 +1,159 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestCSRSnapshot(unittest.TestCase):
+    def test_arrays(self):
+        graph = retworkx.generators.path_graph(3)
+        snapshot = graph.to_csr_snapshot()
+        self.assertFalse(snapshot.directed)
+        self.assertEqual(3, len(snapshot))
+        self.assertTrue(np.array_equal(snapshot.indptr, np.array([0, 1, 3, 4])))
+        self.assertTrue(np.array_equal(snapshot.indices, np.array([1, 0, 2, 1])))
+        self.assertEqual(np.uint32, snapshot.indices.dtype)
+        self.assertTrue(np.array_equal(snapshot.weights, np.array([1.0, 1.0, 1.0, 1.0])))
+        self.assertTrue(np.array_equal(snapshot.node_indices, np.array([0, 1, 2])))
+
+    def test_removed_nodes_are_compacted(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        snapshot = graph.to_csr_snapshot()
+        self.assertEqual(3, len(snapshot))
+        self.assertTrue(np.array_equal(snapshot.node_indices, np.array([1, 2, 3])))
+        self.assertEqual({2: 1.0, 3: 2.0}, snapshot.bfs_distances(1))
+
+    def test_self_loop_stored_once(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 0), (0, 1)])
+        snapshot = graph.to_csr_snapshot()
+        self.assertTrue(np.array_equal(snapshot.indptr, np.array([0, 2, 3])))
+
+    def test_bfs_distances(self):
+        graph = retworkx.generators.cycle_graph(6)
+        snapshot = graph.to_csr_snapshot()
+        self.assertEqual({1: 1.0, 2: 2.0, 3: 3.0, 4: 2.0, 5: 1.0}, snapshot.bfs_distances(0))
+
+    def test_bfs_distances_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edge(0, 1, None)
+        snapshot = graph.to_csr_snapshot()
+        self.assertEqual({1: 1.0}, snapshot.bfs_distances(0))
+
+    def test_dijkstra_matches_graph(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list(
+            [
+                (0, 1, 7),
+                (0, 2, 9),
+                (0, 5, 14),
+                (1, 2, 10),
+                (1, 3, 15),
+                (2, 3, 11),
+                (2, 5, 2),
+                (3, 4, 6),
+                (4, 5, 9),
+            ]
+        )
+        snapshot = graph.to_csr_snapshot(weight_fn=float)
+        expected = retworkx.graph_dijkstra_shortest_path_lengths(graph, 0, float)
+        self.assertEqual(expected, snapshot.dijkstra_shortest_path_lengths(0))
+
+    def test_dijkstra_goal(self):
+        graph = retworkx.generators.path_graph(5)
+        snapshot = graph.to_csr_snapshot(default_weight=2.0)
+        self.assertEqual({3: 6.0}, snapshot.dijkstra_shortest_path_lengths(0, goal=3))
+
+    def test_dijkstra_parallel_edges_use_lightest(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 5.0), (0, 1, 1.0)])
+        snapshot = graph.to_csr_snapshot(weight_fn=float)
+        self.assertEqual({1: 1.0}, snapshot.dijkstra_shortest_path_lengths(0))
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.remove_node(1)
+        snapshot = graph.to_csr_snapshot()
+        with self.assertRaises(retworkx.InvalidNode):
+            snapshot.bfs_distances(1)
+        with self.assertRaises(retworkx.InvalidNode):
+            snapshot.dijkstra_shortest_path_lengths(42)
+        with self.assertRaises(retworkx.InvalidNode):
+            snapshot.dijkstra_shortest_path_lengths(0, goal=1)
+
+    def test_negative_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            graph.to_csr_snapshot(default_weight=-1.0)
+
+    def test_snapshot_is_not_updated(self):
+        graph = retworkx.generators.path_graph(3)
+        snapshot = graph.to_csr_snapshot()
+        graph.add_edge(0, 2, None)
+        self.assertEqual({1: 1.0, 2: 2.0}, snapshot.bfs_distances(0))
+
+    def test_betweenness_matches_graph(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        graph.remove_node(7)
+        snapshot = graph.to_csr_snapshot()
+        for normalized in (True, False):
+            for endpoints in (True, False):
+                expected = retworkx.graph_betweenness_centrality(
+                    graph, normalized=normalized, endpoints=endpoints
+                )
+                result = snapshot.betweenness_centrality(
+                    normalized=normalized, endpoints=endpoints
+                )
+                self.assertEqual(sorted(expected.keys()), sorted(result.keys()))
+                for node, value in expected.items():
+                    self.assertAlmostEqual(value, result[node])
+
+    def test_betweenness_parallel(self):
+        graph = retworkx.generators.grid_graph(6, 6)
+        snapshot = graph.to_csr_snapshot()
+        expected = snapshot.betweenness_centrality()
+        result = snapshot.betweenness_centrality(parallel_threshold=2)
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, result[node])
+
+    def test_pagerank_cycle(self):
+        graph = retworkx.generators.cycle_graph(5)
+        ranks = graph.to_csr_snapshot().pagerank()
+        for node in range(5):
+            self.assertAlmostEqual(0.2, ranks[node])
+
+    def test_pagerank_star(self):
+        graph = retworkx.generators.star_graph(5)
+        ranks = graph.to_csr_snapshot().pagerank()
+        self.assertAlmostEqual(1.0, sum(ranks.values()))
+        for node in range(1, 5):
+            self.assertLess(ranks[node], ranks[0])
+            self.assertAlmostEqual(ranks[1], ranks[node])
+
+    def test_pagerank_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual({}, graph.to_csr_snapshot().pagerank())
+
+    def test_pagerank_failed_to_converge(self):
+        graph = retworkx.generators.star_graph(5)
+        with self.assertRaises(retworkx.ConvergenceError) as cm:
+            graph.to_csr_snapshot().pagerank(max_iter=1)
+        self.assertEqual(cm.exception.iterations, 1)
@@ -67,3 +67,71 @@ class TestCycleBasis(unittest.TestCase):
         self.graph.add_edge(1, 1, None)
         res = sorted(sorted(c) for c in retworkx.cycle_basis(self.graph, 0))
         self.assertEqual([[0, 1, 2, 3], [0, 1, 6, 7, 8], [0, 3, 4, 5], [1]], res)
+
+
+def edge_weights(graph, cycle):
+    return sum(graph.get_edge_data_by_index(edge) for edge in cycle)
+
+
+class TestMinimumCycleBasis(unittest.TestCase):
+    def assertCycles(self, graph, node_cycles, edge_cycles):
+        edges = dict(zip(graph.edge_indices(), graph.edge_list()))
+        self.assertEqual(len(node_cycles), len(edge_cycles))
+        for nodes, cycle in zip(node_cycles, edge_cycles):
+            self.assertEqual(len(nodes), len(cycle))
+            self.assertEqual(len(nodes), len(set(nodes)))
+            for index, edge in enumerate(cycle):
+                next_node = nodes[(index + 1) % len(nodes)]
+                self.assertEqual({nodes[index], next_node}, set(edges[edge]))
+
+    def test_empty_graph(self):
+        self.assertEqual([], retworkx.minimum_cycle_basis(retworkx.PyGraph()))
+
+    def test_tree(self):
+        graph = retworkx.generators.star_graph(5)
+        self.assertEqual([], retworkx.minimum_cycle_basis(graph))
+
+    def test_grid_graph(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual(6, len(res))
+        self.assertTrue(all(len(cycle) == 4 for cycle in res))
+        self.assertCycles(graph, res, retworkx.minimum_cycle_basis(graph, edge_indices=True))
+
+    def test_mesh_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual(6, len(res))
+        self.assertTrue(all(len(cycle) == 3 for cycle in res))
+
+    def test_weighted(self):
+        # A square with a heavy diagonal, the basis uses the outer cycle
+        # instead of a triangle with the diagonal
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1), (0, 2, 10)])
+        res = retworkx.minimum_cycle_basis(graph, weight_fn=float, edge_indices=True)
+        self.assertEqual([4, 12], sorted(edge_weights(graph, cycle) for cycle in res))
+        res = retworkx.minimum_cycle_basis(graph, edge_indices=True)
+        self.assertEqual([3, 3], sorted(len(cycle) for cycle in res))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.generators.cycle_graph(4)
+        loop = graph.add_edge(0, 0, None)
+        parallel = graph.add_edge(1, 2, None)
+        res = retworkx.minimum_cycle_basis(graph, edge_indices=True)
+        self.assertEqual(3, len(res))
+        self.assertIn([loop], res)
+        self.assertIn({1, parallel}, [set(cycle) for cycle in res])
+        self.assertCycles(graph, retworkx.minimum_cycle_basis(graph), res)
+
+    def test_disconnected_graph(self):
+        graph = retworkx.generators.cycle_graph(3)
+        graph.compose(retworkx.generators.cycle_graph(5), {})
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual([3, 5], sorted(len(cycle) for cycle in res))
+
+    def test_invalid_weight(self):
+        graph = retworkx.generators.cycle_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.minimum_cycle_basis(graph, weight_fn=lambda _: -1.0)
@@ -0,0 +1,116 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestDegreeStatistics(unittest.TestCase):
+    def test_degree_sequence(self):
+        graph = retworkx.generators.star_graph(5)
+        degrees = graph.degree_sequence()
+        self.assertTrue(np.array_equal(degrees, np.array([4, 1, 1, 1, 1])))
+
+    def test_degree_sequence_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 0), (0, 1), (0, 0)])
+        degrees = graph.degree_sequence()
+        self.assertTrue(np.array_equal(degrees, np.array([5, 1])))
+
+    def test_degree_sequence_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        degrees = graph.degree_sequence()
+        self.assertTrue(np.array_equal(degrees, np.array([0, 1, 1])))
+
+    def test_degree_sequence_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(0, len(graph.degree_sequence()))
+
+    def test_degree_histogram(self):
+        graph = retworkx.generators.star_graph(5)
+        histogram = graph.degree_histogram()
+        self.assertTrue(np.array_equal(histogram, np.array([0, 4, 0, 0, 1])))
+
+    def test_degree_histogram_isolated_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        histogram = graph.degree_histogram()
+        self.assertTrue(np.array_equal(histogram, np.array([3])))
+
+    def test_degree_histogram_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(0, len(graph.degree_histogram()))
+
+    def test_strength(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 2.0), (2, 2, 0.5)])
+        strength = graph.strength(weight_fn=float)
+        self.assertTrue(np.array_equal(strength, np.array([1.5, 3.5, 3.0])))
+
+    def test_strength_default_weight(self):
+        graph = retworkx.generators.star_graph(5)
+        self.assertTrue(np.array_equal(graph.strength(), graph.degree_sequence()))
+        strength = graph.strength(default_weight=2.0)
+        self.assertTrue(np.array_equal(strength, np.array([8.0, 2.0, 2.0, 2.0, 2.0])))
+
+    def test_strength_weight_fn_error(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(TypeError):
+            graph.strength(weight_fn=float)
+
+    def test_weighted_degree(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 2.0), (2, 2, 0.5)])
+        strength = graph.strength(weight_fn=float)
+        for node in graph.node_indices():
+            self.assertEqual(strength[node], graph.weighted_degree(node, weight_fn=float))
+        self.assertEqual(2.0, graph.weighted_degree(1, default_weight=1.0))
+
+    def test_weighted_degree_invalid_node(self):
+        graph = retworkx.PyGraph()
+        with self.assertRaises(IndexError):
+            graph.weighted_degree(0)
+
+    def test_adjacency_row(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 2.0), (1, 2, 1.0), (2, 2, 0.5)])
+        matrix = retworkx.adjacency_matrix(graph, weight_fn=float)
+        for node in [0, 1, 3]:
+            row = graph.adjacency_row(node, weight_fn=float)
+            self.assertTrue(np.array_equal(row, matrix[node]))
+        row = graph.adjacency_row(2, weight_fn=float)
+        self.assertTrue(np.array_equal(row, np.array([0.0, 3.0, 0.5, 0.0])))
+
+    def test_adjacency_row_null_value(self):
+        graph = retworkx.generators.path_graph(3)
+        row = graph.adjacency_row(0, default_weight=2.0, null_value=np.inf)
+        self.assertTrue(np.array_equal(row, np.array([np.inf, 2.0, np.inf])))
+
+    def test_adjacency_row_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        row = graph.adjacency_row(2)
+        self.assertTrue(np.array_equal(row, retworkx.adjacency_matrix(graph)[1]))
+
+    def test_adjacency_row_invalid_node(self):
+        graph = retworkx.generators.path_graph(2)
+        with self.assertRaises(IndexError):
+            graph.adjacency_row(5)
@@ -0,0 +1,90 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+class TestDegreeConstrainedSpanningTree(unittest.TestCase):
+    def assertSpanningForest(self, graph, edges, components, max_degree):
+        self.assertEqual(sorted(edges), list(edges))
+        self.assertEqual(len(graph) - components, len(edges))
+        forest = graph.copy()
+        for edge in graph.edge_indices():
+            if edge not in edges:
+                forest.remove_edge_from_index(edge)
+        self.assertEqual(components, retworkx.number_connected_components(forest))
+        for node in forest.node_indices():
+            self.assertLessEqual(forest.degree(node), max_degree)
+
+    def total_weight(self, graph, edges):
+        weights = graph.edges()
+        indices = list(graph.edge_indices())
+        return sum(weights[indices.index(edge)] for edge in edges)
+
+    def test_unconstrained_is_minimum_spanning_tree(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        for index, (source, target) in enumerate(graph.edge_list()):
+            graph.update_edge(source, target, float((index * 7) % 11))
+        edges = retworkx.degree_constrained_spanning_tree(graph, 4, weight_fn=float)
+        self.assertSpanningForest(graph, edges, 1, 4)
+        expected = retworkx.minimum_spanning_edge_indices(graph, weight_fn=float)
+        self.assertEqual(self.total_weight(graph, expected), self.total_weight(graph, edges))
+
+    def test_star_minimum_spanning_tree(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from(
+            [(0, 1, 1.0), (0, 2, 1.0), (0, 3, 1.0), (1, 2, 2.0), (2, 3, 2.0), (1, 3, 10.0)]
+        )
+        edges = retworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=float)
+        self.assertSpanningForest(graph, edges, 1, 2)
+        self.assertEqual(4.0, self.total_weight(graph, edges))
+
+    def test_matches_brute_force(self):
+        graph = retworkx.generators.mesh_graph(6)
+        for source, target in graph.edge_list():
+            graph.update_edge(source, target, float((source * 5 + target * 3) % 7 + 1))
+        edges = retworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=float)
+        self.assertSpanningForest(graph, edges, 1, 2)
+        # With a maximum degree of 2 the spanning trees are Hamiltonian paths
+        best = min(
+            sum(graph.get_edge_data(a, b) for a, b in zip(path, path[1:]))
+            for path in itertools.permutations(range(6))
+        )
+        self.assertEqual(best, self.total_weight(graph, edges))
+
+    def test_spanning_forest(self):
+        graph = retworkx.generators.star_graph(5)
+        graph.compose(retworkx.generators.mesh_graph(4), {})
+        graph.add_node(None)
+        # The center of the star has no other edges to exchange
+        with self.assertRaises(ValueError):
+            retworkx.degree_constrained_spanning_tree(graph, 3)
+        graph.remove_nodes_from(range(5))
+        edges = retworkx.degree_constrained_spanning_tree(graph, 2)
+        self.assertSpanningForest(graph, edges, 2, 2)
+
+    def test_infeasible(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.degree_constrained_spanning_tree(graph, 1)
+
+    def test_null_graph(self):
+        self.assertEqual([], retworkx.degree_constrained_spanning_tree(retworkx.PyGraph(), 1))
+
+    def test_nan_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.degree_constrained_spanning_tree(graph, 2, weight_fn=lambda _: float("nan"))
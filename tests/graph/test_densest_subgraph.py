@@ -0,0 +1,110 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def induced_density(graph, nodes):
+    nodes = set(nodes)
+    edges = sum(1 for (a, b) in graph.edge_list() if a in nodes and b in nodes)
+    return edges / len(nodes)
+
+
+def brute_force_density(graph):
+    nodes = graph.node_indices()
+    return max(
+        induced_density(graph, subset)
+        for size in range(1, len(nodes) + 1)
+        for subset in itertools.combinations(nodes, size)
+    )
+
+
+class TestDensestSubgraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.mesh_graph(4)
+        self.graph.add_nodes_from(range(3))
+        self.graph.add_edges_from_no_data([(3, 4), (4, 5), (5, 6)])
+
+    def test_clique_with_tail(self):
+        nodes, density = retworkx.densest_subgraph(self.graph)
+        self.assertEqual([0, 1, 2, 3], list(nodes))
+        self.assertEqual(1.5, density)
+
+    def test_whole_graph(self):
+        graph = retworkx.generators.cycle_graph(5)
+        nodes, density = retworkx.densest_subgraph(graph)
+        self.assertEqual([0, 1, 2, 3, 4], list(nodes))
+        self.assertEqual(1.0, density)
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_edge(2, 3, None)
+        graph.add_edge(2, 3, None)
+        graph.add_edge(2, 3, None)
+        graph.add_edge(0, 0, None)
+        nodes, density = retworkx.densest_subgraph(graph)
+        self.assertEqual([2, 3], list(nodes))
+        self.assertEqual(2.0, density)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.mesh_graph(5)
+        graph.remove_node(1)
+        graph.add_node(None)
+        nodes, density = retworkx.densest_subgraph(graph)
+        self.assertEqual([0, 2, 3, 4], list(nodes))
+        self.assertEqual(1.5, density)
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        nodes, density = retworkx.densest_subgraph(graph)
+        self.assertEqual([0, 1, 2], list(nodes))
+        self.assertEqual(0.0, density)
+
+    def test_empty_graph(self):
+        nodes, density = retworkx.densest_subgraph(retworkx.PyGraph())
+        self.assertEqual([], list(nodes))
+        self.assertEqual(0.0, density)
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.undirected_gnp_random_graph(9, 0.4, seed=seed)
+            nodes, density = retworkx.densest_subgraph(graph)
+            self.assertAlmostEqual(induced_density(graph, nodes), density)
+            self.assertAlmostEqual(brute_force_density(graph), density)
+
+
+class TestApproximateDensestSubgraph(unittest.TestCase):
+    def test_clique_with_tail(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(3, 4), (4, 5), (5, 6)])
+        nodes, density = retworkx.approximate_densest_subgraph(graph)
+        self.assertEqual([0, 1, 2, 3], list(nodes))
+        self.assertEqual(1.5, density)
+
+    def test_empty_graph(self):
+        nodes, density = retworkx.approximate_densest_subgraph(retworkx.PyGraph())
+        self.assertEqual([], list(nodes))
+        self.assertEqual(0.0, density)
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.undirected_gnp_random_graph(9, 0.4, seed=seed)
+            nodes, density = retworkx.approximate_densest_subgraph(graph)
+            self.assertAlmostEqual(induced_density(graph, nodes), density)
+            optimal = brute_force_density(graph)
+            self.assertLessEqual(density, optimal + 1e-9)
+            self.assertGreaterEqual(density, optimal / 2 - 1e-9)
@@ -104,3 +104,32 @@ class TestDfsSearch(unittest.TestCase):
         except retworkx.visit.StopSearch:
             pass
         self.assertEqual(vis.reconstruct_path(), [0, 2, 5, 3])
+
+    def test_graph_dfs_node_mask(self):
+        class TreeEdgesRecorder(retworkx.visit.DFSVisitor):
+            def __init__(self):
+                self.edges = []
+
+            def tree_edge(self, edge):
+                self.edges.append((edge[0], edge[1]))
+
+        vis = TreeEdgesRecorder()
+        retworkx.graph_dfs_search(self.graph, [0, 2], vis, node_mask={2})
+        masked = self.graph.copy()
+        masked.remove_node(2)
+        expected = TreeEdgesRecorder()
+        retworkx.graph_dfs_search(masked, [0], expected)
+        self.assertEqual(expected.edges, vis.edges)
+        self.assertNotIn(2, [node for edge in vis.edges for node in edge])
+
+    def test_graph_dfs_node_mask_no_starting_point(self):
+        class TreeEdgesRecorder(retworkx.visit.DFSVisitor):
+            def __init__(self):
+                self.edges = []
+
+            def tree_edge(self, edge):
+                self.edges.append((edge[0], edge[1]))
+
+        vis = TreeEdgesRecorder()
+        retworkx.dfs_search(self.graph, None, vis, node_mask=[0, 1, 2, 3, 5, 6])
+        self.assertEqual([(4, 7)], vis.edges)
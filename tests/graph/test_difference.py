@@ -0,0 +1,151 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+import retworkx
+
+
+class TestDifference(unittest.TestCase):
+    def setUp(self):
+        self.first = retworkx.PyGraph()
+        self.first.add_nodes_from(["a", "b", "c", "d"])
+        self.first.extend_from_weighted_edge_list([(0, 1, "ab"), (1, 2, "bc"), (2, 3, "cd")])
+        self.second = retworkx.PyGraph()
+        self.second.add_nodes_from(["d", "c", "b", "a", "e"])
+        self.second.extend_from_weighted_edge_list([(2, 1, "bc"), (3, 4, "ae")])
+
+    def test_difference_by_payload(self):
+        res = retworkx.graph_difference(self.first, self.second)
+        self.assertEqual(res.nodes(), ["a", "b", "c", "d"])
+        self.assertEqual(res.weighted_edge_list(), [(0, 1, "ab"), (2, 3, "cd")])
+
+    def test_difference_with_itself(self):
+        res = retworkx.graph_difference(self.first, self.first)
+        self.assertEqual(res.num_nodes(), 4)
+        self.assertEqual(res.num_edges(), 0)
+
+    def test_difference_reversed_edge(self):
+        second = retworkx.PyGraph()
+        second.add_nodes_from(["a", "b", "c", "d"])
+        second.add_edge(3, 2, None)
+        res = retworkx.graph_difference(self.first, second)
+        self.assertEqual(res.edge_list(), [(0, 1), (1, 2)])
+
+    def test_difference_ignores_edge_payloads(self):
+        second = self.first.copy()
+        for edge in second.edge_indices():
+            second.update_edge_by_index(edge, "other")
+        res = retworkx.graph_difference(self.first, second)
+        self.assertEqual(res.num_edges(), 0)
+
+    def test_difference_node_map(self):
+        second = retworkx.PyGraph()
+        second.add_nodes_from([None, None])
+        second.add_edge(0, 1, None)
+        res = retworkx.graph_difference(self.first, second, node_map={0: 2, 1: 3})
+        self.assertEqual(res.edge_list(), [(0, 1), (1, 2)])
+        # Without the node map no nodes are aligned
+        res = retworkx.graph_difference(self.first, second)
+        self.assertEqual(res.edge_list(), self.first.edge_list())
+
+    def test_difference_keeps_indices(self):
+        self.first.remove_node(0)
+        res = retworkx.graph_difference(self.first, self.second)
+        self.assertEqual(res.node_indices(), [1, 2, 3])
+        self.assertEqual(res.edge_list(), [(2, 3)])
+
+    def test_difference_invalid_node_map(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_difference(self.first, self.second, node_map={10: 0})
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_difference(self.first, self.second, node_map={0: 10})
+
+    def test_symmetric_difference(self):
+        res = retworkx.graph_symmetric_difference(self.first, self.second)
+        self.assertEqual(res.nodes(), ["a", "b", "c", "d", "e"])
+        self.assertEqual(
+            sorted(res.weighted_edge_list()),
+            [(0, 1, "ab"), (0, 4, "ae"), (2, 3, "cd")],
+        )
+
+    def test_symmetric_difference_with_itself(self):
+        res = retworkx.graph_symmetric_difference(self.first, self.first)
+        self.assertEqual(res.num_nodes(), 4)
+        self.assertEqual(res.num_edges(), 0)
+
+    def test_symmetric_difference_node_map(self):
+        second = retworkx.PyGraph()
+        second.add_nodes_from(["x", "y", "z"])
+        second.add_edges_from_no_data([(0, 1), (1, 2)])
+        res = retworkx.graph_symmetric_difference(self.first, second, node_map={0: 0, 1: 1})
+        self.assertEqual(res.nodes(), ["a", "b", "c", "d", "z"])
+        self.assertEqual(sorted(res.edge_list()), [(1, 2), (1, 4), (2, 3)])
+
+    def test_universal_functions(self):
+        res = retworkx.difference(self.first, self.second)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual(res.edge_list(), [(0, 1), (2, 3)])
+        res = retworkx.symmetric_difference(self.first, self.second)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual(res.num_edges(), 3)
+
+
+class TestGraphDiff(unittest.TestCase):
+    def setUp(self):
+        self.old = retworkx.PyGraph()
+        self.old.add_nodes_from([("a", 1), ("b", 1), ("c", 1)])
+        self.old.add_edges_from([(0, 1, "x"), (1, 2, "y")])
+        self.new = retworkx.PyGraph()
+        self.new.add_nodes_from([("c", 2), ("b", 1), ("d", 1)])
+        self.new.add_edges_from([(1, 0, "z"), (2, 0, "w")])
+
+    def key(self, node):
+        return node[0]
+
+    def test_diff(self):
+        res = retworkx.graph_diff(self.old, self.new, node_key_fn=self.key)
+        self.assertEqual(list(res["nodes_added"]), [2])
+        self.assertEqual(list(res["nodes_removed"]), [0])
+        self.assertEqual(res["nodes_changed"], [(2, 0)])
+        self.assertEqual(list(res["edges_added"]), [1])
+        self.assertEqual(list(res["edges_removed"]), [0])
+        self.assertEqual(res["edges_changed"], [(1, 0)])
+        self.assertEqual(dict(res["node_map"]), {1: 1, 2: 0})
+
+    def test_diff_identical(self):
+        res = retworkx.graph_diff(self.old, self.old.copy())
+        for key in ["nodes_added", "nodes_removed", "edges_added", "edges_removed"]:
+            self.assertEqual(len(res[key]), 0)
+        self.assertEqual(res["nodes_changed"], [])
+        self.assertEqual(res["edges_changed"], [])
+        self.assertEqual(dict(res["node_map"]), {0: 0, 1: 1, 2: 2})
+
+    def test_diff_parallel_edges(self):
+        new = self.old.copy()
+        new.add_edge(1, 0, "x")
+        res = retworkx.graph_diff(self.old, new)
+        self.assertEqual(list(res["edges_added"]), [2])
+        res = retworkx.graph_diff(new, self.old)
+        self.assertEqual(list(res["edges_removed"]), [2])
+
+    def test_diff_duplicate_key(self):
+        self.old.add_node(("a", 2))
+        with self.assertRaises(ValueError):
+            retworkx.graph_diff(self.old, self.new, node_key_fn=self.key)
+
+    def test_diff_unhashable_key(self):
+        with self.assertRaises(TypeError):
+            retworkx.graph_diff(self.old, self.new, node_key_fn=list)
+
+    def test_diff_mixed_types(self):
+        with self.assertRaises(TypeError):
+            retworkx.graph_diff(self.old, retworkx.PyDiGraph())
@@ -50,6 +50,50 @@ class TestDijkstraGraph(unittest.TestCase):
         expected = {4: [self.a, self.c, self.d, self.e]}
         self.assertEqual(expected, path)
 
+    def test_dijkstra_shortest_path_edges(self):
+        edges = retworkx.graph_dijkstra_shortest_path_edges(
+            self.graph, self.a, self.e, weight_fn=float
+        )
+        self.assertEqual([1, 4, 5], edges)
+        self.assertEqual(
+            [(self.c, self.a), (self.d, self.c), (self.d, self.e)],
+            [self.graph.get_edge_endpoints_by_index(edge) for edge in edges],
+        )
+
+    def test_dijkstra_shortest_path_edges_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 5.0), (1, 0, 1.0), (1, 2, 1.0), (2, 1, 0.5)])
+        edges = retworkx.dijkstra_shortest_path_edges(graph, 0, 2, weight_fn=float)
+        self.assertEqual([1, 3], edges)
+
+    def test_dijkstra_shortest_path_edges_same_node(self):
+        edges = retworkx.dijkstra_shortest_path_edges(self.graph, self.a, self.a)
+        self.assertEqual([], edges)
+
+    def test_dijkstra_shortest_path_edges_no_path(self):
+        self.graph.add_node("G")
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.dijkstra_shortest_path_edges(self.graph, self.a, 6)
+
+    def test_dijkstra_shortest_path_edges_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dijkstra_shortest_path_edges(self.graph, self.a, 42)
+
+    def test_dijkstra_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_dijkstra_shortest_paths(self.graph, 42)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_dijkstra_shortest_path_lengths(self.graph, 42, lambda _: 1)
+
+    def test_dijkstra_removed_source(self):
+        graph = self.graph.copy()
+        graph.remove_node(self.a)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_dijkstra_shortest_paths(graph, self.a)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_dijkstra_shortest_path_lengths(graph, self.a, lambda _: 1)
+
     def test_dijkstra_with_no_goal_set(self):
         path = retworkx.graph_dijkstra_shortest_path_lengths(self.graph, self.a, lambda x: 1)
         expected = {1: 1.0, 2: 1.0, 3: 1.0, 4: 2.0, 5: 2.0}
@@ -236,3 +280,139 @@ class TestDijkstraGraph(unittest.TestCase):
                     retworkx.graph_all_pairs_dijkstra_path_lengths(
                         graph, edge_cost_fn=lambda _: invalid_weight
                     )
+
+    def test_dijkstra_shortest_path_lengths_edge_filter(self):
+        lengths = retworkx.graph_dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, edge_filter_fn=lambda x: x < 10
+        )
+        expected = {self.b: 7.0, self.c: 9.0, self.d: 11.0, self.e: 20.0, self.f: 26.0}
+        self.assertEqual(expected, lengths)
+
+    def test_dijkstra_shortest_path_lengths_edge_filter_workspace(self):
+        workspace = retworkx.Workspace()
+        lengths = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, workspace=workspace, edge_filter_fn=lambda x: x < 10
+        )
+        self.assertEqual(26.0, lengths[self.f])
+        lengths = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, workspace=workspace
+        )
+        self.assertEqual(20.0, lengths[self.f])
+
+    def test_dijkstra_shortest_paths_edge_filter(self):
+        paths = retworkx.dijkstra_shortest_paths(
+            self.graph, self.a, target=self.f, weight_fn=float, edge_filter_fn=lambda x: x < 10
+        )
+        self.assertEqual({self.f: [self.a, self.c, self.d, self.e, self.f]}, paths)
+
+    def test_dijkstra_shortest_paths_edge_filter_no_path(self):
+        paths = retworkx.graph_dijkstra_shortest_paths(
+            self.graph, self.a, edge_filter_fn=lambda x: x == 9
+        )
+        self.assertEqual({self.c: [self.a, self.c]}, paths)
+
+    def test_all_pairs_dijkstra_edge_filter(self):
+        calls = []
+
+        def edge_cost_fn(edge):
+            calls.append(edge)
+            return float(edge)
+
+        filtered = self.graph.copy()
+        for edge, (source, target, weight) in self.graph.edge_index_map().items():
+            if weight >= 10:
+                filtered.remove_edge_from_index(edge)
+        lengths = retworkx.all_pairs_dijkstra_path_lengths(
+            self.graph, edge_cost_fn, edge_filter_fn=lambda x: x < 10
+        )
+        self.assertTrue(all(weight < 10 for weight in calls))
+        self.assertEqual(retworkx.all_pairs_dijkstra_path_lengths(filtered, float), lengths)
+        paths = retworkx.all_pairs_dijkstra_shortest_paths(
+            self.graph, float, edge_filter_fn=lambda x: x < 10
+        )
+        self.assertEqual(retworkx.all_pairs_dijkstra_shortest_paths(filtered, float), paths)
+
+    def test_shortest_path_between_sets(self):
+        path = retworkx.graph_shortest_path_between_sets(
+            self.graph, [self.a, self.b], [self.e], weight_fn=float
+        )
+        self.assertEqual([self.a, self.c, self.d, self.e], path)
+        path = retworkx.graph_shortest_path_between_sets(
+            self.graph, [self.e], [self.a, self.b], weight_fn=float
+        )
+        self.assertEqual([self.e, self.d, self.c, self.a], path)
+
+    def test_shortest_path_between_sets_matches_pairwise(self):
+        sources = [self.a, self.b]
+        targets = [self.e, self.f]
+        path = retworkx.graph_shortest_path_between_sets(self.graph, sources, targets)
+        self.assertIn(path[0], sources)
+        self.assertIn(path[-1], targets)
+        expected = min(
+            retworkx.graph_dijkstra_shortest_path_lengths(self.graph, source, lambda _: 1.0)[
+                target
+            ]
+            for source in sources
+            for target in targets
+        )
+        self.assertEqual(expected, len(path) - 1)
+
+    def test_shortest_path_between_sets_overlap(self):
+        path = retworkx.graph_shortest_path_between_sets(
+            self.graph, [self.a, self.b], [self.b, self.f]
+        )
+        self.assertEqual([self.b], path)
+
+    def test_shortest_path_between_sets_no_path(self):
+        g = self.graph.copy()
+        node = g.add_node("G")
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.graph_shortest_path_between_sets(g, [self.a, self.b], [node])
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.graph_shortest_path_between_sets(g, [], [self.a])
+
+    def test_shortest_path_between_sets_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_shortest_path_between_sets(self.graph, [self.a], [42])
+
+    def test_shortest_path_between_sets_negative_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_shortest_path_between_sets(
+                self.graph, [self.a], [self.f], weight_fn=lambda x: -1.0
+            )
+
+    def test_shortest_path_between_sets_universal(self):
+        path = retworkx.shortest_path_between_sets(
+            self.graph, [self.a, self.b], [self.e], weight_fn=float
+        )
+        self.assertEqual([self.a, self.c, self.d, self.e], path)
+
+    def test_dijkstra_shortest_paths_node_mask(self):
+        paths = retworkx.graph_dijkstra_shortest_paths(
+            self.graph, self.a, target=self.f, weight_fn=float, node_mask={self.c}
+        )
+        self.assertEqual({self.f: [self.a, self.b, self.f]}, paths)
+
+    def test_dijkstra_shortest_path_lengths_node_mask(self):
+        lengths = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, node_mask=[self.c]
+        )
+        self.assertEqual({self.b: 7.0, self.d: 14.0, self.e: 23.0, self.f: 22.0}, lengths)
+
+    def test_dijkstra_shortest_path_lengths_masked_source(self):
+        lengths = retworkx.graph_dijkstra_shortest_path_lengths(
+            self.graph, self.a, float, node_mask={self.a}
+        )
+        self.assertEqual({}, lengths)
+
+    def test_all_pairs_dijkstra_node_mask(self):
+        masked = self.graph.copy()
+        masked.remove_node(self.c)
+        lengths = retworkx.all_pairs_dijkstra_path_lengths(self.graph, float, node_mask={self.c})
+        self.assertEqual(retworkx.all_pairs_dijkstra_path_lengths(masked, float), lengths)
+        paths = retworkx.all_pairs_dijkstra_shortest_paths(self.graph, float, node_mask={self.c})
+        self.assertEqual(retworkx.all_pairs_dijkstra_shortest_paths(masked, float), paths)
+
+    def test_dijkstra_node_mask_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dijkstra_shortest_paths(self.graph, self.a, node_mask={42})
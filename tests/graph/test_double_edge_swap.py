@@ -0,0 +1,106 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestDoubleEdgeSwap(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.grid_graph(6, 6)
+        for edge in self.graph.edge_indices():
+            self.graph.update_edge_by_index(edge, edge)
+
+    def degrees(self, graph):
+        return [graph.degree(node) for node in graph.node_indices()]
+
+    def assertSimple(self, graph):
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+        self.assertTrue(all(source != target for source, target in edges))
+
+    def test_degrees_kept(self):
+        degrees = self.degrees(self.graph)
+        edges = self.graph.edge_list()
+        swaps = retworkx.graph_double_edge_swap(self.graph, nswap=100, seed=42)
+        self.assertEqual(100, swaps)
+        self.assertEqual(degrees, self.degrees(self.graph))
+        self.assertNotEqual(edges, self.graph.edge_list())
+        self.assertSimple(self.graph)
+
+    def test_edge_indices_and_payloads_kept(self):
+        edge_indices = list(self.graph.edge_indices())
+        retworkx.graph_double_edge_swap(self.graph, nswap=100, seed=42)
+        self.assertEqual(edge_indices, list(self.graph.edge_indices()))
+        for edge in self.graph.edge_indices():
+            self.assertEqual(edge, self.graph.get_edge_data_by_index(edge))
+
+    def test_seed(self):
+        other = self.graph.copy()
+        retworkx.graph_double_edge_swap(self.graph, nswap=50, seed=1234)
+        retworkx.graph_double_edge_swap(other, nswap=50, seed=1234)
+        self.assertEqual(self.graph.edge_list(), other.edge_list())
+
+    def test_max_tries(self):
+        edges = self.graph.edge_list()
+        self.assertEqual(0, retworkx.graph_double_edge_swap(self.graph, nswap=10, max_tries=0))
+        self.assertEqual(edges, self.graph.edge_list())
+        swaps = retworkx.graph_double_edge_swap(self.graph, nswap=100, max_tries=10, seed=42)
+        self.assertLessEqual(swaps, 10)
+
+    def test_no_swaps_possible(self):
+        for graph in [
+            retworkx.PyGraph(),
+            retworkx.generators.path_graph(2),
+            retworkx.generators.star_graph(6),
+            retworkx.generators.mesh_graph(5),
+        ]:
+            edges = graph.edge_list()
+            self.assertEqual(0, retworkx.graph_double_edge_swap(graph, nswap=5, seed=42))
+            self.assertEqual(edges, graph.edge_list())
+
+    def test_transaction_rollback(self):
+        edges = self.graph.weighted_edge_list()
+        with self.graph.transaction() as transaction:
+            retworkx.graph_double_edge_swap(self.graph, nswap=20, seed=42)
+            transaction.rollback()
+        self.assertEqual(edges, self.graph.weighted_edge_list())
+
+    def test_universal(self):
+        degrees = self.degrees(self.graph)
+        self.assertEqual(20, retworkx.double_edge_swap(self.graph, nswap=20, seed=42))
+        self.assertEqual(degrees, self.degrees(self.graph))
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.double_edge_swap(None)
+
+
+class TestConnectedDoubleEdgeSwap(unittest.TestCase):
+    def test_stays_connected(self):
+        graph = retworkx.generators.path_graph(20)
+        graph.add_edges_from_no_data([(0, 10), (5, 15), (3, 17)])
+        degrees = [graph.degree(node) for node in graph.node_indices()]
+        for seed in range(10):
+            swaps = retworkx.connected_double_edge_swap(graph, nswap=20, seed=seed)
+            self.assertGreater(swaps, 0)
+            self.assertTrue(retworkx.is_connected(graph))
+            self.assertEqual(degrees, [graph.degree(node) for node in graph.node_indices()])
+
+    def test_not_connected(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_node(None)
+        with self.assertRaises(retworkx.DisconnectedGraphError):
+            retworkx.connected_double_edge_swap(graph)
+
+    def test_empty_graph(self):
+        self.assertEqual(0, retworkx.connected_double_edge_swap(retworkx.PyGraph()))
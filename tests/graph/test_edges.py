@@ -12,6 +12,8 @@
 
 import unittest
 
+import numpy as np
+
 import retworkx
 
 
@@ -393,6 +395,52 @@ class TestEdges(unittest.TestCase):
         self.assertEqual([0, 1], res)
         self.assertEqual([None, None], graph.edges())
 
+    def test_add_edges_from_array(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        res = graph.add_edges_from_array(np.array([0, 1, 2]), np.array([1, 2, 3]))
+        self.assertEqual([0, 1, 2], res)
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
+        self.assertEqual([None, None, None], graph.edges())
+
+    def test_add_edges_from_array_weights(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_array(
+            np.array([0, 1], dtype=np.int32), np.array([1, 2]), np.array([0.5, 1.5])
+        )
+        self.assertEqual([(0, 1, 0.5), (1, 2, 1.5)], graph.weighted_edge_list())
+        self.assertIsInstance(graph.edges()[0], float)
+
+    def test_add_edges_from_array_object_weights(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_array(np.array([0, 1]), np.array([1, 2]), ["a", "b"])
+        self.assertEqual([(0, 1, "a"), (1, 2, "b")], graph.weighted_edge_list())
+
+    def test_add_edges_from_array_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        res = graph.add_edges_from_array(np.array([0, 1]), np.array([1, 0]))
+        self.assertEqual([0, 1], res)
+
+    def test_add_edges_from_array_invalid_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        with self.assertRaises(IndexError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1, 5]))
+        with self.assertRaises(IndexError):
+            graph.add_edges_from_array(np.array([-1]), np.array([1]))
+        self.assertEqual(0, graph.num_edges())
+
+    def test_add_edges_from_array_length_mismatch(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        with self.assertRaises(ValueError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1]))
+        with self.assertRaises(ValueError):
+            graph.add_edges_from_array(np.array([0, 1]), np.array([1, 2]), [1.0])
+
     def test_multigraph_attr(self):
         graph = retworkx.PyGraph()
         self.assertTrue(graph.multigraph)
@@ -414,6 +462,69 @@ class TestEdges(unittest.TestCase):
         graph = retworkx.PyGraph()
         self.assertFalse(graph.has_parallel_edges())
 
+    def test_selfloops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1), (1, 2), (1, 1)])
+        self.assertEqual(3, graph.number_of_selfloops())
+        self.assertEqual([0, 2, 4], graph.selfloop_edges())
+        graph.remove_edge_from_index(2)
+        self.assertEqual(2, graph.number_of_selfloops())
+        self.assertEqual([0, 4], graph.selfloop_edges())
+
+    def test_selfloops_empty(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edge(0, 1, None)
+        self.assertEqual(0, graph.number_of_selfloops())
+        self.assertEqual([], graph.selfloop_edges())
+
+    def test_parallel_edge_groups(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (0, 1, "c"), (0, 1, "d"), (1, 2, "e")])
+        graph.add_edge(2, 0, "f")
+        self.assertEqual([[0, 2, 3], [1, 4]], graph.parallel_edge_groups())
+
+    def test_parallel_edge_groups_no_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        self.assertEqual([], graph.parallel_edge_groups())
+
+    def test_edge_multiplicities(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 1), (2, 1)])
+        self.assertEqual({(0, 1): 2, (1, 2): 2}, graph.edge_multiplicities())
+
+    def test_edge_multiplicities_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual({}, graph.edge_multiplicities())
+
+    def test_merge_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 1, 3), (0, 1, 4)])
+        graph.merge_parallel_edges(lambda a, b: a + b)
+        self.assertFalse(graph.has_parallel_edges())
+        self.assertEqual([0, 1], graph.edge_indices())
+        self.assertEqual([(0, 1, 8), (1, 2, 2)], graph.weighted_edge_list())
+
+    def test_merge_parallel_edges_no_merge_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b")])
+        graph.merge_parallel_edges()
+        self.assertEqual([(0, 1, "a")], graph.weighted_edge_list())
+
+    def test_merge_parallel_edges_merge_fn_error(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, 2)])
+        with self.assertRaises(TypeError):
+            graph.merge_parallel_edges(lambda a, b: a + b)
+
     def test_edge_index_map(self):
         graph = retworkx.PyGraph()
         node_a = graph.add_node(0)
@@ -466,6 +577,26 @@ class TestEdges(unittest.TestCase):
         res = graph.incident_edge_index_map(42)
         self.assertEqual({}, res)
 
+    def test_incident_edge_index_map_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 1, "first"), (1, 0, "second")])
+        res = graph.incident_edge_index_map(1)
+        self.assertEqual({0: (1, 0, "first"), 1: (1, 0, "second")}, res)
+
+    def test_incident_edge_index_map_self_loop(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edges_from([(0, 0, "loop"), (0, 1, "edge")])
+        res = graph.incident_edge_index_map(0)
+        self.assertEqual({0: (0, 0, "loop"), 1: (0, 1, "edge")}, res)
+
+    def test_incident_edge_index_map_neighbor_iteration(self):
+        graph = retworkx.generators.star_graph(4)
+        for edge_index, (source, neighbor, _) in graph.incident_edge_index_map(0).items():
+            self.assertEqual(0, source)
+            self.assertEqual(graph.get_edge_endpoints_by_index(edge_index), (0, neighbor))
+
     def test_single_neighbor_out_edges(self):
         g = retworkx.PyGraph()
         node_a = g.add_node("a")
@@ -713,6 +844,13 @@ class TestEdgesMultigraphFalse(unittest.TestCase):
         self.assertEqual([0, 0], res)
         self.assertEqual([None], graph.edges())
 
+    def test_add_edges_from_array_parallel_edges(self):
+        graph = retworkx.PyGraph(False)
+        graph.add_nodes_from([0, 1])
+        res = graph.add_edges_from_array(np.array([0, 1]), np.array([1, 0]), [False, True])
+        self.assertEqual([0, 0], res)
+        self.assertEqual([True], graph.edges())
+
     def test_extend_from_weighted_edge_list_empty(self):
         graph = retworkx.PyGraph()
         graph.extend_from_weighted_edge_list([])
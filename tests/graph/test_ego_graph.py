@@ -0,0 +1,67 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import unittest
+
+import retworkx
+
+
+class TestEgoGraph(unittest.TestCase):
+    def test_ego_graph_radius_one(self):
+        graph = retworkx.generators.path_graph(weights=list(range(5)))
+        ego = retworkx.ego_graph(graph, 2)
+        self.assertIsInstance(ego, retworkx.PyGraph)
+        self.assertEqual([1, 2, 3], ego.nodes())
+        self.assertEqual([(0, 1), (1, 2)], ego.edge_list())
+
+    def test_ego_graph_radius_zero(self):
+        graph = retworkx.generators.path_graph(weights=list(range(5)))
+        ego = retworkx.ego_graph(graph, 2, radius=0)
+        self.assertEqual([2], ego.nodes())
+        self.assertEqual([], ego.edge_list())
+
+    def test_ego_graph_large_radius(self):
+        graph = retworkx.generators.cycle_graph(weights=list(range(6)))
+        ego = retworkx.ego_graph(graph, 0, radius=10)
+        self.assertEqual(graph.nodes(), ego.nodes())
+        self.assertEqual(6, ego.num_edges())
+
+    def test_ego_graph_distance_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, 1.0), (0, 3, 3.0)])
+        ego = retworkx.graph_ego_graph(graph, 0, radius=2.5, distance_fn=float)
+        self.assertEqual(["a", "b", "c"], ego.nodes())
+        self.assertEqual([(0, 1, 1.5), (1, 2, 1.0)], ego.weighted_edge_list())
+
+    def test_ego_graph_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        ego = retworkx.ego_graph(graph, 0, radius=5)
+        self.assertEqual([0, 1], ego.nodes())
+
+    def test_ego_graph_invalid_node(self):
+        graph = retworkx.generators.path_graph(weights=list(range(3)))
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.ego_graph(graph, 42)
+
+    def test_ego_graph_negative_distance(self):
+        graph = retworkx.generators.path_graph(weights=list(range(3)))
+        with self.assertRaises(ValueError):
+            retworkx.ego_graph(graph, 0, distance_fn=lambda _: -1.0)
+
+    def test_ego_graph_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.ego_graph(None, 0)
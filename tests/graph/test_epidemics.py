@@ -0,0 +1,115 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestEpidemicSimulation(unittest.TestCase):
+    def test_deterministic_wave(self):
+        graph = retworkx.generators.path_graph(5)
+        counts, states = retworkx.epidemic_simulation(
+            graph, [0], default_transmission=1.0, recovery_rate=1.0, max_steps=7
+        )
+        expected = [
+            [4, 1, 0],
+            [3, 1, 1],
+            [2, 1, 2],
+            [1, 1, 3],
+            [0, 1, 4],
+            [0, 0, 5],
+            [0, 0, 5],
+            [0, 0, 5],
+        ]
+        np.testing.assert_array_equal(counts, np.array([expected]))
+        np.testing.assert_array_equal(states, np.array([[2, 2, 2, 2, 2]]))
+
+    def test_shapes(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        counts, states = retworkx.graph_epidemic_simulation(
+            graph, [0, 5], num_trials=6, max_steps=12, seed=42
+        )
+        self.assertEqual(counts.shape, (6, 13, 3))
+        self.assertEqual(states.shape, (6, 16))
+        np.testing.assert_array_equal(counts.sum(axis=2), np.full((6, 13), 16))
+        np.testing.assert_array_equal(counts[:, 0], np.array([[14, 2, 0]] * 6))
+        for compartment in range(3):
+            np.testing.assert_array_equal(
+                counts[:, -1, compartment], (states == compartment).sum(axis=1)
+            )
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        first = retworkx.epidemic_simulation(graph, [12], num_trials=10, seed=7)
+        second = retworkx.epidemic_simulation(graph, [12], num_trials=10, seed=7)
+        np.testing.assert_array_equal(first[0], second[0])
+        np.testing.assert_array_equal(first[1], second[1])
+
+    def test_sis(self):
+        graph = retworkx.generators.cycle_graph(10)
+        counts, states = retworkx.epidemic_simulation(
+            graph,
+            [0],
+            model="SIS",
+            default_transmission=0.5,
+            recovery_rate=0.5,
+            num_trials=20,
+            max_steps=30,
+            seed=1,
+        )
+        np.testing.assert_array_equal(counts[:, :, 2], np.zeros((20, 31)))
+        self.assertFalse((states == 2).any())
+
+    def test_no_recovery(self):
+        graph = retworkx.generators.path_graph(4)
+        counts, states = retworkx.epidemic_simulation(
+            graph, [3], default_transmission=1.0, recovery_rate=0.0, max_steps=5
+        )
+        np.testing.assert_array_equal(counts[0, :, 1], [1, 2, 3, 4, 4, 4])
+        np.testing.assert_array_equal(states, np.ones((1, 4)))
+
+    def test_transmission_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 0.0), (0, 3, 1.0)])
+        counts, states = retworkx.epidemic_simulation(
+            graph, [0], transmission_fn=float, recovery_rate=1.0, max_steps=5
+        )
+        np.testing.assert_array_equal(states, np.array([[2, 2, 0, 2]]))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_node(1)
+        counts, states = retworkx.epidemic_simulation(
+            graph, [2], default_transmission=1.0, recovery_rate=1.0
+        )
+        np.testing.assert_array_equal(states, np.array([[0, 2, 2, 2]]))
+        np.testing.assert_array_equal(counts[0, -1], [1, 0, 3])
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.epidemic_simulation(graph, [5])
+
+    def test_invalid_model(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.epidemic_simulation(graph, [0], model="SEIR")
+
+    def test_invalid_probability(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.epidemic_simulation(graph, [0], recovery_rate=1.5)
+        with self.assertRaises(ValueError):
+            retworkx.epidemic_simulation(graph, [0], transmission_fn=lambda _: -0.5)
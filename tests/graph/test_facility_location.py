@@ -0,0 +1,156 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import collections
+import itertools
+import unittest
+
+import retworkx
+
+
+class TestFacilityLocation(unittest.TestCase):
+    def assertAssignment(self, centers, assignment, nodes):
+        self.assertEqual(sorted(nodes), sorted(assignment))
+        for center in centers:
+            self.assertEqual(center, assignment[center])
+        self.assertEqual(set(centers), set(assignment.values()))
+
+    def test_p_median_path(self):
+        graph = retworkx.generators.path_graph(7)
+        centers, assignment, cost = retworkx.p_median(graph, 1, seed=42)
+        self.assertEqual([3], centers)
+        self.assertEqual(12.0, cost)
+        self.assertAssignment(centers, assignment, graph.node_indices())
+
+    def test_k_center_path(self):
+        graph = retworkx.generators.path_graph(7)
+        centers, assignment, cost = retworkx.k_center(graph, 1, seed=42)
+        self.assertEqual([3], centers)
+        self.assertEqual(3.0, cost)
+        self.assertAssignment(centers, assignment, graph.node_indices())
+
+    def test_clusters(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.compose(retworkx.generators.mesh_graph(4), {})
+        graph.add_edge(0, 4, None)
+        centers, assignment, cost = retworkx.p_median(
+            graph, 2, weight_fn=lambda x: 1.0 if x is None else x, seed=42
+        )
+        self.assertEqual(6.0, cost)
+        self.assertAssignment(centers, assignment, graph.node_indices())
+        for node in graph.node_indices():
+            self.assertEqual(node < 4, assignment[node] < 4)
+
+    def test_p_median_matches_brute_force(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+
+        def distance(a, b):
+            return abs(a // 4 - b // 4) + abs(a % 4 - b % 4)
+
+        for p in range(1, 4):
+            best = min(
+                sum(min(distance(center, node) for center in centers) for node in range(12))
+                for centers in itertools.combinations(range(12), p)
+            )
+            centers, assignment, cost = retworkx.p_median(graph, p, seed=42)
+            self.assertEqual(best, cost)
+            self.assertEqual(p, len(centers))
+            for node, center in assignment.items():
+                self.assertEqual(min(distance(c, node) for c in centers), distance(center, node))
+
+    def test_k_center_matches_brute_force(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+
+        def distance(a, b):
+            return abs(a // 4 - b // 4) + abs(a % 4 - b % 4)
+
+        for k in range(1, 4):
+            best = min(
+                max(min(distance(center, node) for center in centers) for node in range(12))
+                for centers in itertools.combinations(range(12), k)
+            )
+            _, _, cost = retworkx.k_center(graph, k, seed=42)
+            self.assertEqual(best, cost)
+
+    def test_weighted(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 5.0)])
+        centers, _, cost = retworkx.p_median(graph, 1, weight_fn=float, seed=42)
+        self.assertEqual([1], centers)
+        self.assertEqual(6.0, cost)
+        centers, _, cost = retworkx.p_median(graph, 2, weight_fn=float, seed=42)
+        self.assertEqual(1.0, cost)
+        self.assertIn(2, centers)
+
+    def test_capacity(self):
+        graph = retworkx.generators.star_graph(5)
+        centers, assignment, cost = retworkx.p_median(graph, 2, seed=42)
+        self.assertEqual(3.0, cost)
+        centers, assignment, cost = retworkx.p_median(graph, 2, capacity=3, seed=42)
+        self.assertEqual(4.0, cost)
+        self.assertAssignment(centers, assignment, graph.node_indices())
+        loads = collections.Counter(assignment.values())
+        self.assertTrue(all(load <= 3 for load in loads.values()))
+
+    def test_capacity_too_small(self):
+        graph = retworkx.generators.star_graph(5)
+        with self.assertRaises(ValueError):
+            retworkx.p_median(graph, 2, capacity=2)
+
+    def test_invalid_p(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.p_median(graph, 0)
+        with self.assertRaises(ValueError):
+            retworkx.k_center(graph, 4)
+
+    def test_null_graph(self):
+        centers, assignment, cost = retworkx.p_median(retworkx.PyGraph(), 0)
+        self.assertEqual([], centers)
+        self.assertEqual({}, dict(assignment))
+        self.assertEqual(0.0, cost)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.p_median(graph, 1)
+        centers, assignment, cost = retworkx.p_median(graph, 2, num_samples=1, seed=0)
+        self.assertEqual(2.0, cost)
+        self.assertEqual(assignment[0], assignment[1])
+        self.assertEqual(assignment[2], assignment[3])
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(6)
+        graph.remove_node(0)
+        centers, assignment, cost = retworkx.p_median(graph, 1, seed=42)
+        self.assertEqual([3], centers)
+        self.assertEqual(6.0, cost)
+        self.assertAssignment(centers, assignment, graph.node_indices())
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        first = retworkx.p_median(graph, 3, num_samples=2, seed=7)
+        second = retworkx.p_median(graph, 3, num_samples=2, seed=7)
+        self.assertEqual(list(first[0]), list(second[0]))
+        self.assertEqual(first[2], second[2])
+
+    def test_invalid_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.p_median(graph, 1, weight_fn=lambda _: -1.0)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.p_median(None, 1)
@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def is_forest(graph, removed):
+    remaining = graph.copy()
+    remaining.remove_nodes_from(list(removed))
+    components = len(retworkx.connected_components(remaining))
+    return remaining.num_edges() == remaining.num_nodes() - components
+
+
+class TestFeedbackVertexSet(unittest.TestCase):
+    def assertMinimalFeedbackVertexSet(self, graph, nodes):
+        self.assertTrue(is_forest(graph, nodes))
+        for node in nodes:
+            self.assertFalse(is_forest(graph, nodes - {node}))
+
+    def test_empty(self):
+        res = retworkx.feedback_vertex_set(retworkx.PyGraph())
+        self.assertIsInstance(res, set)
+        self.assertEqual(set(), res)
+
+    def test_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(4)
+        self.assertEqual(set(), retworkx.graph_feedback_vertex_set(graph))
+
+    def test_cycle(self):
+        graph = retworkx.generators.cycle_graph(6)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertEqual(1, len(res))
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(6)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertEqual(4, len(res))
+        self.assertMinimalFeedbackVertexSet(graph, res)
+
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertEqual({0, 4}, res)
+
+    def test_self_loop_and_parallel_edges(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_edge(3, 3, None)
+        graph.add_edge(0, 1, None)
+        res = retworkx.feedback_vertex_set(graph)
+        self.assertIn(3, res)
+        self.assertEqual(2, len(res))
+        self.assertMinimalFeedbackVertexSet(graph, res)
+
+    def test_random_graphs(self):
+        for seed in range(20):
+            graph = retworkx.undirected_gnp_random_graph(20, 0.2, seed=seed)
+            res = retworkx.feedback_vertex_set(graph)
+            self.assertMinimalFeedbackVertexSet(graph, res)
@@ -0,0 +1,87 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def test_grid_graph(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        self.assertEqual(
+            {
+                "num_nodes": 9,
+                "num_edges": 12,
+                "density": 12 / 36,
+                "min_degree": 2,
+                "max_degree": 4,
+                "mean_degree": 24 / 9,
+                "num_components": 1,
+                "is_directed": False,
+                "is_dag": False,
+                "is_tree": False,
+                "is_bipartite": True,
+            },
+            retworkx.graph_summary(graph),
+        )
+
+    def test_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(3)
+        summary = retworkx.graph_summary(graph)
+        self.assertTrue(summary["is_tree"])
+        self.assertTrue(summary["is_bipartite"])
+        self.assertEqual(1, summary["num_components"])
+
+    def test_odd_cycle(self):
+        summary = retworkx.graph_summary(retworkx.generators.cycle_graph(5))
+        self.assertFalse(summary["is_bipartite"])
+        self.assertFalse(summary["is_tree"])
+        self.assertEqual(2, summary["min_degree"])
+        self.assertEqual(2, summary["max_degree"])
+
+    def test_disconnected(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_nodes_from([None, None])
+        graph.add_edge(3, 4, None)
+        graph.add_node(None)
+        summary = retworkx.graph_summary(graph)
+        self.assertEqual(3, summary["num_components"])
+        self.assertFalse(summary["is_tree"])
+        self.assertEqual(0, summary["min_degree"])
+
+    def test_self_loop(self):
+        graph = retworkx.generators.path_graph(2)
+        graph.add_edge(1, 1, None)
+        summary = retworkx.graph_summary(graph)
+        self.assertFalse(summary["is_bipartite"])
+        self.assertFalse(summary["is_tree"])
+        self.assertEqual(3, summary["max_degree"])
+        self.assertEqual(2.0, summary["mean_degree"])
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        summary = retworkx.graph_summary(graph)
+        self.assertEqual(3, summary["num_nodes"])
+        self.assertTrue(summary["is_tree"])
+
+    def test_empty(self):
+        summary = retworkx.graph_summary(retworkx.PyGraph())
+        self.assertEqual(0, summary["num_nodes"])
+        self.assertEqual(0.0, summary["density"])
+        self.assertEqual(0, summary["num_components"])
+        self.assertFalse(summary["is_tree"])
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.graph_summary(None)
@@ -0,0 +1,118 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def brute_force_exists(graph, cycle):
+    nodes = list(graph.node_indexes())
+    for path in itertools.permutations(nodes):
+        edges = list(zip(path, path[1:]))
+        if cycle:
+            edges.append((path[-1], path[0]))
+        if all(graph.has_edge(*edge) for edge in edges):
+            return True
+    return False
+
+
+class TestHamiltonianPath(unittest.TestCase):
+    def assertHamiltonian(self, graph, path, cycle=False):
+        self.assertIsNotNone(path)
+        path = list(path)
+        self.assertEqual(sorted(graph.node_indexes()), sorted(path))
+        for source, target in zip(path, path[1:]):
+            self.assertTrue(graph.has_edge(source, target))
+        if cycle:
+            self.assertTrue(graph.has_edge(path[-1], path[0]))
+
+    def test_empty_and_single_node(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual([], list(retworkx.graph_hamiltonian_path(graph)))
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.graph_hamiltonian_path(graph)))
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True, method="heuristic"))
+
+    def test_two_nodes_no_cycle(self):
+        graph = retworkx.generators.path_graph(2)
+        self.assertIn(list(retworkx.graph_hamiltonian_path(graph)), [[0, 1], [1, 0]])
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(4)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph))
+        graph = retworkx.generators.star_graph(3)
+        self.assertHamiltonian(graph, retworkx.graph_hamiltonian_path(graph))
+
+    def test_petersen_graph(self):
+        # The Petersen graph has a Hamiltonian path but no Hamiltonian cycle
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        self.assertHamiltonian(graph, retworkx.graph_hamiltonian_path(graph))
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+
+    def test_grid_graph_search(self):
+        # Large enough to use the depth first search
+        graph = retworkx.generators.grid_graph(5, 6)
+        path = retworkx.graph_hamiltonian_path(graph, cycle=True)
+        self.assertHamiltonian(graph, path, cycle=True)
+        # A bipartite graph with an odd number of nodes has no cycle
+        graph = retworkx.generators.grid_graph(5, 5)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+        self.assertHamiltonian(graph, retworkx.graph_hamiltonian_path(graph))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.cycle_graph(6)
+        graph.remove_node(0)
+        path = retworkx.graph_hamiltonian_path(graph)
+        self.assertIn(list(path), [[1, 2, 3, 4, 5], [5, 4, 3, 2, 1]])
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+
+    def test_self_loops_and_parallel_edges_ignored(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1)])
+        self.assertHamiltonian(graph, retworkx.graph_hamiltonian_path(graph))
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, cycle=True))
+
+    def test_random_graphs_against_brute_force(self):
+        for seed in range(30):
+            graph = retworkx.undirected_gnp_random_graph(7, 0.35, seed=seed)
+            for cycle in (False, True):
+                res = retworkx.graph_hamiltonian_path(graph, cycle=cycle)
+                if brute_force_exists(graph, cycle):
+                    self.assertHamiltonian(graph, res, cycle=cycle)
+                else:
+                    self.assertIsNone(res)
+                heuristic = retworkx.graph_hamiltonian_path(graph, cycle=cycle, method="heuristic")
+                if heuristic is not None:
+                    self.assertHamiltonian(graph, heuristic, cycle=cycle)
+
+    def test_heuristic_large_graph(self):
+        graph = retworkx.generators.grid_graph(20, 20)
+        path = retworkx.graph_hamiltonian_path(graph, method="heuristic")
+        self.assertHamiltonian(graph, path)
+
+    def test_exact_too_large(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        with self.assertRaises(retworkx.GraphTooLarge):
+            retworkx.graph_hamiltonian_path(graph)
+
+    def test_invalid_method(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_hamiltonian_path(graph, method="greedy")
+
+    def test_universal_function(self):
+        graph = retworkx.generators.cycle_graph(8)
+        self.assertHamiltonian(graph, retworkx.hamiltonian_path(graph, cycle=True), cycle=True)
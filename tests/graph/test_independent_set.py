@@ -0,0 +1,254 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import random
+import unittest
+
+import retworkx
+
+
+def is_independent_set(graph, nodes):
+    return all(
+        source not in nodes or target not in nodes for source, target in graph.edge_list()
+    )
+
+
+def brute_force_independence_number(graph):
+    nodes = list(graph.node_indexes())
+    for size in range(len(nodes), 0, -1):
+        for subset in itertools.combinations(nodes, size):
+            if is_independent_set(graph, set(subset)):
+                return size
+    return 0
+
+
+def brute_force_max_weight(graph, weights):
+    nodes = list(graph.node_indexes())
+    best = 0
+    for size in range(1, len(nodes) + 1):
+        for subset in itertools.combinations(nodes, size):
+            if is_independent_set(graph, set(subset)):
+                best = max(best, sum(weights[node] for node in subset))
+    return best
+
+
+def interval_graph(intervals):
+    graph = retworkx.PyGraph()
+    graph.add_nodes_from(intervals)
+    for first, second in itertools.combinations(graph.node_indexes(), 2):
+        (first_start, first_end), (second_start, second_end) = intervals[first], intervals[second]
+        if max(first_start, second_start) <= min(first_end, second_end):
+            graph.add_edge(first, second, None)
+    return graph
+
+
+class TestMaximumIndependentSet(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(set(), retworkx.maximum_independent_set(graph))
+        self.assertEqual(set(), retworkx.maximum_independent_set(graph, exact=True))
+
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(6)
+        expected = {1, 2, 3, 4, 5}
+        self.assertEqual(expected, retworkx.maximum_independent_set(graph))
+        self.assertEqual(expected, retworkx.maximum_independent_set(graph, exact=True))
+
+    def test_cycle_graph_exact(self):
+        graph = retworkx.generators.cycle_graph(7)
+        res = retworkx.maximum_independent_set(graph, exact=True)
+        self.assertEqual(3, len(res))
+        self.assertTrue(is_independent_set(graph, res))
+
+    def test_mesh_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        self.assertEqual(1, len(retworkx.maximum_independent_set(graph)))
+        self.assertEqual(1, len(retworkx.maximum_independent_set(graph, exact=True)))
+
+    def test_self_loops_excluded(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (2, 2)])
+        self.assertEqual({1}, retworkx.maximum_independent_set(graph))
+        self.assertEqual({1}, retworkx.maximum_independent_set(graph, exact=True))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(6)
+        graph.remove_node(2)
+        res = retworkx.maximum_independent_set(graph, exact=True)
+        self.assertEqual(3, len(res))
+        self.assertTrue(is_independent_set(graph, res))
+        self.assertNotIn(2, res)
+
+    def test_random_graphs_against_brute_force(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(10, 0.4, seed=seed)
+            greedy = retworkx.maximum_independent_set(graph)
+            exact = retworkx.maximum_independent_set(graph, exact=True)
+            self.assertTrue(is_independent_set(graph, greedy))
+            self.assertTrue(is_independent_set(graph, exact))
+            self.assertEqual(brute_force_independence_number(graph), len(exact))
+            self.assertLessEqual(len(greedy), len(exact))
+            # The greedy set is maximal
+            for node in set(graph.node_indexes()) - greedy:
+                self.assertFalse(is_independent_set(graph, greedy | {node}))
+
+
+class TestMinimumVertexCover(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(set(), retworkx.minimum_vertex_cover(graph))
+
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(4)
+        self.assertEqual({0, 1, 2, 3}, retworkx.minimum_vertex_cover(graph))
+
+    def test_self_loop(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from_no_data([(1, 1)])
+        self.assertEqual({1}, retworkx.minimum_vertex_cover(graph))
+
+    def test_random_graphs_approximation(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(10, 0.4, seed=seed)
+            cover = retworkx.minimum_vertex_cover(graph)
+            for source, target in graph.edge_list():
+                self.assertTrue(source in cover or target in cover)
+            minimum = len(graph) - brute_force_independence_number(graph)
+            self.assertLessEqual(len(cover), 2 * minimum)
+
+
+class TestTreeMaximumWeightIndependentSet(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(set(), retworkx.tree_maximum_weight_independent_set(graph))
+
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(4, weights=[5, 2, 2, 2])
+        self.assertEqual(
+            {1, 2, 3}, retworkx.tree_maximum_weight_independent_set(graph, weight_fn=float)
+        )
+        graph[0] = 7
+        self.assertEqual({0}, retworkx.tree_maximum_weight_independent_set(graph, weight_fn=float))
+
+    def test_unweighted_path(self):
+        graph = retworkx.generators.path_graph(5)
+        self.assertEqual({0, 2, 4}, retworkx.tree_maximum_weight_independent_set(graph))
+
+    def test_forest_with_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([1, 3, 1, 4, 1])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 2), (3, 4)])
+        self.assertEqual(
+            {1, 3}, retworkx.tree_maximum_weight_independent_set(graph, weight_fn=float)
+        )
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(6)
+        graph.remove_node(2)
+        res = retworkx.tree_maximum_weight_independent_set(graph)
+        self.assertEqual(3, len(res))
+        self.assertTrue(is_independent_set(graph, res))
+
+    def test_random_trees_against_brute_force(self):
+        rng = random.Random(42)
+        for _ in range(20):
+            num_nodes = rng.randint(1, 12)
+            graph = retworkx.PyGraph()
+            graph.add_nodes_from([rng.uniform(0, 10) for _ in range(num_nodes)])
+            for node in range(1, num_nodes):
+                graph.add_edge(rng.randrange(node), node, None)
+            res = retworkx.tree_maximum_weight_independent_set(graph, weight_fn=lambda x: x)
+            self.assertTrue(is_independent_set(graph, res))
+            self.assertAlmostEqual(
+                brute_force_max_weight(graph, graph.nodes()), sum(graph[node] for node in res)
+            )
+
+    def test_cycle(self):
+        graph = retworkx.generators.cycle_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.tree_maximum_weight_independent_set(graph)
+
+    def test_self_loop(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(1, 1, None)
+        with self.assertRaises(ValueError):
+            retworkx.tree_maximum_weight_independent_set(graph)
+
+    def test_negative_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.tree_maximum_weight_independent_set(graph, weight_fn=lambda _: -1.0)
+
+
+class TestIntervalMaximumWeightIndependentSet(unittest.TestCase):
+    def test_empty_graph(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(set(), retworkx.interval_maximum_weight_independent_set(graph, tuple))
+
+    def test_weighted_intervals(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([(0, 3, 2.0), (2, 5, 5.0), (4, 7, 4.0), (6, 9, 2.0)])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        res = retworkx.interval_maximum_weight_independent_set(
+            graph, lambda node: node[:2], weight_fn=lambda node: node[2]
+        )
+        self.assertEqual({1, 3}, res)
+
+    def test_unweighted(self):
+        graph = interval_graph([(0, 10), (1, 2), (3, 4), (5, 6)])
+        self.assertEqual({1, 2, 3}, retworkx.interval_maximum_weight_independent_set(graph, tuple))
+
+    def test_shared_endpoint_intersects(self):
+        graph = interval_graph([(0, 1), (1, 2)])
+        self.assertEqual(1, graph.num_edges())
+        res = retworkx.interval_maximum_weight_independent_set(graph, tuple)
+        self.assertEqual(1, len(res))
+
+    def test_random_intervals_against_brute_force(self):
+        rng = random.Random(7)
+        for _ in range(20):
+            intervals = []
+            for _ in range(rng.randint(1, 12)):
+                start = rng.randint(0, 20)
+                intervals.append((start, start + rng.randint(0, 6)))
+            graph = interval_graph(intervals)
+            weights = [rng.uniform(0, 10) for _ in intervals]
+            res = retworkx.interval_maximum_weight_independent_set(
+                graph, tuple, weight_fn=lambda node: weights[intervals.index(node)]
+            )
+            self.assertTrue(is_independent_set(graph, res))
+            self.assertAlmostEqual(
+                brute_force_max_weight(graph, [weights[intervals.index(i)] for i in intervals]),
+                sum(weights[intervals.index(graph[node])] for node in res),
+            )
+
+    def test_missing_edge(self):
+        graph = interval_graph([(0, 2), (1, 3), (2, 4)])
+        graph.remove_edge(0, 2)
+        with self.assertRaises(ValueError):
+            retworkx.interval_maximum_weight_independent_set(graph, tuple)
+
+    def test_extra_edge(self):
+        graph = interval_graph([(0, 1), (2, 3)])
+        graph.add_edge(0, 1, None)
+        with self.assertRaises(ValueError):
+            retworkx.interval_maximum_weight_independent_set(graph, tuple)
+
+    def test_invalid_interval(self):
+        graph = retworkx.PyGraph()
+        graph.add_node((2, 1))
+        with self.assertRaises(ValueError):
+            retworkx.interval_maximum_weight_independent_set(graph, tuple)
@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestIsomorphismClasses(unittest.TestCase):
+    def test_isomorphism_classes(self):
+        graphs = [
+            retworkx.generators.path_graph(4),
+            retworkx.generators.star_graph(4),
+            retworkx.generators.cycle_graph(4),
+            retworkx.generators.path_graph(4),
+            retworkx.generators.star_graph(4),
+        ]
+        self.assertEqual([[0, 3], [1, 4], [2]], retworkx.graph_isomorphism_classes(graphs))
+
+    def test_relabeled_graphs(self):
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        relabeled = retworkx.PyGraph()
+        relabeled.add_nodes_from(range(10))
+        mapping = [7, 2, 9, 0, 5, 1, 8, 3, 6, 4]
+        relabeled.add_edges_from_no_data(
+            [(mapping[source], mapping[target]) for source, target in graph.edge_list()]
+        )
+        other = retworkx.generators.generalized_petersen_graph(5, 1)
+        self.assertEqual([[0, 1], [2]], retworkx.isomorphism_classes([graph, relabeled, other]))
+
+    def test_same_invariant_not_isomorphic(self):
+        # Two triangles and a hexagon have the same Weisfeiler-Lehman hashes
+        triangles = retworkx.generators.cycle_graph(3)
+        triangles.compose(retworkx.generators.cycle_graph(3), {})
+        hexagon = retworkx.generators.cycle_graph(6)
+        self.assertEqual(
+            [[0, 2], [1]],
+            retworkx.graph_isomorphism_classes([triangles, hexagon, triangles.copy()]),
+        )
+
+    def test_node_matcher(self):
+        graphs = []
+        for labels in (["a", "b"], ["b", "a"], ["a", "a"]):
+            graph = retworkx.PyGraph()
+            graph.add_nodes_from(labels)
+            graph.add_edge(0, 1, None)
+            graphs.append(graph)
+        self.assertEqual([[0, 1, 2]], retworkx.graph_isomorphism_classes(graphs))
+        self.assertEqual(
+            [[0, 1], [2]],
+            retworkx.graph_isomorphism_classes(graphs, node_matcher=lambda a, b: a == b),
+        )
+
+    def test_edge_matcher(self):
+        graphs = []
+        for weight in (1, 2, 1):
+            graph = retworkx.generators.path_graph(3)
+            graph.update_edge(0, 1, weight)
+            graphs.append(graph)
+        self.assertEqual(
+            [[0, 2], [1]],
+            retworkx.graph_isomorphism_classes(graphs, edge_matcher=lambda a, b: a == b),
+        )
+
+    def test_matcher_exception(self):
+        graphs = [retworkx.generators.path_graph(2), retworkx.generators.path_graph(2)]
+
+        def matcher(a, b):
+            raise ValueError("matcher error")
+
+        with self.assertRaises(ValueError):
+            retworkx.graph_isomorphism_classes(graphs, node_matcher=matcher)
+
+    def test_id_order_false(self):
+        graphs = [retworkx.generators.grid_graph(3, 4), retworkx.generators.grid_graph(4, 3)]
+        self.assertEqual([[0, 1]], retworkx.graph_isomorphism_classes(graphs, id_order=False))
+
+    def test_empty(self):
+        self.assertEqual([], retworkx.graph_isomorphism_classes([]))
+        self.assertEqual([], retworkx.isomorphism_classes([]))
+
+    def test_mixed_types(self):
+        with self.assertRaises(TypeError):
+            retworkx.isomorphism_classes(
+                [retworkx.PyGraph(), retworkx.generators.directed_path_graph(2)]
+            )
@@ -64,6 +64,11 @@ class TestKShortestpath(unittest.TestCase):
                         goal=3,
                     )
 
+    def test_graph_k_shortest_path_invalid_start(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_k_shortest_path_lengths(graph, start=42, k=1, edge_cost=lambda _: 1)
+
     def test_k_shortest_path_with_no_path(self):
         g = retworkx.PyGraph()
         a = g.add_node("A")
@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestKeyIndex(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a1", "b1", "a2", "c1", "b2"])
+        self.graph.add_edges_from([(0, 1, "x"), (1, 2, "y"), (2, 3, "x"), (3, 4, "z")])
+
+    def test_nodes_with_key(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        self.assertEqual([0, 2], self.graph.nodes_with_key("a"))
+        self.assertEqual([1, 4], self.graph.nodes_with_key("b"))
+        self.assertEqual([], self.graph.nodes_with_key("d"))
+
+    def test_add_remove_and_update_nodes(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        new_node = self.graph.add_node("a3")
+        self.graph.remove_node(0)
+        self.graph[1] = "c2"
+        self.assertEqual([2, new_node], self.graph.nodes_with_key("a"))
+        self.assertEqual([4], self.graph.nodes_with_key("b"))
+        self.assertEqual([1, 3], self.graph.nodes_with_key("c"))
+        self.graph.remove_nodes_from([2, new_node])
+        self.assertEqual([], self.graph.nodes_with_key("a"))
+
+    def test_key_fn_only_called_for_changes(self):
+        calls = []
+
+        def key_fn(node):
+            calls.append(node)
+            return node[0]
+
+        self.graph.index_nodes_by(key_fn)
+        self.assertEqual(5, len(calls))
+        calls.clear()
+        self.graph.add_node("d1")
+        self.graph.nodes_with_key("d")
+        self.graph.nodes_with_key("d")
+        self.assertEqual(["d1"], calls)
+
+    def test_compact(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        self.graph.remove_node(1)
+        self.graph.compact()
+        self.assertEqual([0, 1], self.graph.nodes_with_key("a"))
+        self.assertEqual([3], self.graph.nodes_with_key("b"))
+
+    def test_rollback(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        self.graph.index_edges_by(lambda edge: edge)
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(2)
+            self.graph.add_node("a3")
+            self.assertEqual([0], self.graph.edges_with_key("x"))
+            transaction.rollback()
+        self.assertEqual([0, 2], self.graph.nodes_with_key("a"))
+        self.assertEqual([0, 2], self.graph.edges_with_key("x"))
+        self.assertEqual([1], self.graph.edges_with_key("y"))
+
+    def test_edges_with_key(self):
+        self.graph.index_edges_by(lambda edge: edge)
+        self.assertEqual([0, 2], self.graph.edges_with_key("x"))
+        self.graph.remove_edge(0, 1)
+        self.graph.update_edge(3, 4, "x")
+        new_edge = self.graph.add_edge(0, 4, "y")
+        self.assertEqual([2, 3], self.graph.edges_with_key("x"))
+        self.assertEqual(sorted([1, new_edge]), self.graph.edges_with_key("y"))
+        self.graph.remove_node(2)
+        self.assertEqual([3], self.graph.edges_with_key("x"))
+        self.assertEqual([new_edge], self.graph.edges_with_key("y"))
+
+    def test_not_indexed(self):
+        with self.assertRaises(RuntimeError):
+            self.graph.nodes_with_key("a")
+        with self.assertRaises(RuntimeError):
+            self.graph.edges_with_key("x")
+
+    def test_drop_index(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        self.graph.index_edges_by(lambda edge: edge)
+        self.graph.drop_node_index()
+        self.graph.drop_edge_index()
+        with self.assertRaises(RuntimeError):
+            self.graph.nodes_with_key("a")
+        with self.assertRaises(RuntimeError):
+            self.graph.edges_with_key("x")
+
+    def test_key_fn_error(self):
+        def key_fn(node):
+            if node == "c2":
+                raise ValueError("bad node")
+            return node[0]
+
+        with self.assertRaises(ZeroDivisionError):
+            self.graph.index_nodes_by(lambda _: 1 / 0)
+        with self.assertRaises(RuntimeError):
+            self.graph.nodes_with_key("a")
+        self.graph.index_nodes_by(key_fn)
+        node = self.graph.add_node("c2")
+        with self.assertRaises(ValueError):
+            self.graph.nodes_with_key("c")
+        self.graph.remove_node(node)
+        self.assertEqual([0, 2], self.graph.nodes_with_key("a"))
+        self.assertEqual([3], self.graph.nodes_with_key("c"))
+
+    def test_unhashable_key(self):
+        with self.assertRaises(TypeError):
+            self.graph.index_nodes_by(list)
+        self.graph.index_nodes_by(lambda node: node[0])
+        with self.assertRaises(TypeError):
+            self.graph.nodes_with_key(["a"])
+
+    def test_copy_and_pickle_not_indexed(self):
+        self.graph.index_nodes_by(lambda node: node[0])
+        for graph in [self.graph.copy(), pickle.loads(pickle.dumps(self.graph))]:
+            with self.assertRaises(RuntimeError):
+                graph.nodes_with_key("a")
+        self.assertEqual([0, 2], self.graph.nodes_with_key("a"))
@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestLinkPrediction(unittest.TestCase):
+    def setUp(self):
+        # A star with center 0 and leaves 1, 2, 3 plus the edge (1, 2) and a
+        # pendant node 4 attached to node 3
+        self.graph = retworkx.generators.star_graph(4)
+        self.graph.add_node(4)
+        self.graph.add_edges_from_no_data([(1, 2), (3, 4)])
+
+    def assertScores(self, result, expected):
+        pairs, scores = result
+        self.assertIsInstance(pairs, np.ndarray)
+        self.assertIsInstance(scores, np.ndarray)
+        self.assertEqual(pairs.shape, (len(expected), 2))
+        got = {tuple(pair): score for pair, score in zip(pairs.tolist(), scores.tolist())}
+        self.assertEqual(got.keys(), expected.keys())
+        for pair, score in expected.items():
+            self.assertAlmostEqual(got[pair], score)
+
+    def test_jaccard_coefficient(self):
+        self.assertScores(
+            retworkx.jaccard_coefficient(self.graph),
+            {(0, 4): 1 / 3, (1, 3): 1 / 3, (2, 3): 1 / 3},
+        )
+
+    def test_adamic_adar_index(self):
+        self.assertScores(
+            retworkx.adamic_adar_index(self.graph),
+            {(0, 4): 1 / math.log(2), (1, 3): 1 / math.log(3), (2, 3): 1 / math.log(3)},
+        )
+
+    def test_preferential_attachment(self):
+        self.assertScores(
+            retworkx.preferential_attachment(self.graph),
+            {(0, 4): 3, (1, 3): 4, (1, 4): 2, (2, 3): 4, (2, 4): 2},
+        )
+
+    def test_resource_allocation_index(self):
+        self.assertScores(
+            retworkx.resource_allocation_index(self.graph),
+            {(0, 4): 1 / 2, (1, 3): 1 / 3, (2, 3): 1 / 3},
+        )
+
+    def test_threshold(self):
+        self.assertScores(
+            retworkx.adamic_adar_index(self.graph, threshold=1.0),
+            {(0, 4): 1 / math.log(2)},
+        )
+        self.assertScores(
+            retworkx.preferential_attachment(self.graph, threshold=3),
+            {(1, 3): 4, (2, 3): 4},
+        )
+
+    def test_negative_threshold_scores_all_non_edges(self):
+        pairs, scores = retworkx.jaccard_coefficient(self.graph, threshold=-1.0)
+        self.assertEqual(len(pairs), 10 - self.graph.num_edges())
+        self.assertEqual(0.0, scores.min())
+
+    def test_explicit_pairs(self):
+        pairs, scores = retworkx.resource_allocation_index(
+            self.graph, pairs=[(3, 1), (1, 2), (4, 1)]
+        )
+        self.assertEqual([[3, 1], [1, 2], [4, 1]], pairs.tolist())
+        np.testing.assert_allclose(scores, [1 / 3, 1 / 3, 0.0])
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(0)
+        self.assertScores(
+            retworkx.jaccard_coefficient(self.graph),
+            {},
+        )
+        self.assertScores(
+            retworkx.preferential_attachment(self.graph),
+            {(1, 3): 1, (1, 4): 1, (2, 3): 1, (2, 4): 1},
+        )
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.jaccard_coefficient(self.graph, pairs=[(0, 10)])
+
+    def test_empty_graph(self):
+        pairs, scores = retworkx.adamic_adar_index(retworkx.PyGraph())
+        self.assertEqual(pairs.shape, (0, 2))
+        self.assertEqual(len(scores), 0)
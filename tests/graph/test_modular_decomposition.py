@@ -0,0 +1,98 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def nested(tree, node=0):
+    kind, nodes = tree[node]
+    if kind == "leaf":
+        return nodes[0]
+    children = sorted(tree.successor_indices(node))
+    return (kind, [nested(tree, child) for child in children])
+
+
+class TestModularDecomposition(unittest.TestCase):
+    def test_empty_graph(self):
+        tree = retworkx.modular_decomposition(retworkx.PyGraph())
+        self.assertIsInstance(tree, retworkx.PyDiGraph)
+        self.assertEqual(len(tree), 0)
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(tree.nodes(), [("leaf", [0])])
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(4)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(nested(tree), ("series", [0, 1, 2, 3]))
+        self.assertEqual(tree[0], ("series", [0, 1, 2, 3]))
+
+    def test_edgeless_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(nested(tree), ("parallel", [0, 1, 2]))
+
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(4)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(nested(tree), ("prime", [0, 1, 2, 3]))
+
+    def test_complete_bipartite(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(u, v) for u in range(2) for v in range(2, 5)])
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(
+            nested(tree),
+            ("series", [("parallel", [0, 1]), ("parallel", [2, 3, 4])]),
+        )
+
+    def test_prime_with_modules(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(4, 1), (5, 3), (6, 3), (5, 6)])
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(
+            nested(tree),
+            ("prime", [("parallel", [0, 4]), 1, 2, 3, ("series", [5, 6])]),
+        )
+        self.assertEqual(tree[0], ("prime", list(range(7))))
+        self.assertEqual(tree[1], ("parallel", [0, 4]))
+
+    def test_disconnected(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_nodes_from(range(2))
+        graph.add_edge(4, 5, None)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(
+            nested(tree),
+            ("parallel", [("prime", [0, 1, 2, 3]), ("series", [4, 5])]),
+        )
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.star_graph(5)
+        graph.remove_node(2)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(nested(tree), ("series", [0, ("parallel", [1, 3, 4])]))
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(0, 1, None)
+        graph.add_edge(2, 2, None)
+        tree = retworkx.modular_decomposition(graph)
+        self.assertEqual(nested(tree), ("series", [("parallel", [0, 2]), 1]))
@@ -0,0 +1,65 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestModularityMatrix(unittest.TestCase):
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_modularity_matrix(graph)
+        self.assertIsInstance(res, np.ndarray)
+        expected = np.array([[-0.25, 0.5, -0.25], [0.5, -1.0, 0.5], [-0.25, 0.5, -0.25]])
+        np.testing.assert_allclose(expected, res)
+
+    def test_matches_definition(self):
+        graph = retworkx.generators.heavy_hex_graph(3)
+        for edge, (source, target, _) in graph.edge_index_map().items():
+            graph.update_edge_by_index(edge, float(source + target + 1))
+        adjacency = retworkx.graph_adjacency_matrix(graph, weight_fn=float)
+        degree = adjacency.sum(axis=1)
+        expected = adjacency - 0.5 * np.outer(degree, degree) / adjacency.sum()
+        res = retworkx.graph_modularity_matrix(graph, weight_fn=float, resolution=0.5)
+        np.testing.assert_allclose(expected, res)
+        np.testing.assert_allclose(res, res.T)
+
+    def test_rows_sum_to_zero(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        graph.add_edge(0, 0, None)
+        graph.remove_node(4)
+        res = retworkx.graph_modularity_matrix(graph)
+        self.assertEqual((8, 8), res.shape)
+        self.assertAlmostEqual(1.0 - 3.0 * 3.0 / 17.0, res[0, 0])
+        np.testing.assert_allclose(np.zeros(8), res.sum(axis=1), atol=1e-12)
+
+    def test_modularity_of_partition(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (2, 3)])
+        res = retworkx.graph_modularity_matrix(graph)
+        communities = np.array([1, 1, 1, -1, -1, -1])
+        modularity = (res * (np.outer(communities, communities) + 1) / 2).sum() / 14
+        self.assertAlmostEqual(5.0 / 14.0, modularity)
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        np.testing.assert_array_equal(np.zeros((3, 3)), retworkx.graph_modularity_matrix(graph))
+
+    def test_universal(self):
+        graph = retworkx.generators.path_graph(3)
+        np.testing.assert_allclose(
+            retworkx.graph_modularity_matrix(graph), retworkx.modularity_matrix(graph)
+        )
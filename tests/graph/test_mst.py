@@ -92,6 +92,31 @@ class TestMinimumSpanningTree(unittest.TestCase):
         mst_graph = retworkx.minimum_spanning_tree(mutligraph, weight_fn=lambda x: x)
         self.assertEqualEdgeList([(0, 1, 1), (1, 2, 1)], mst_graph.weighted_edge_list())
 
+    def test_multigraph_edge_indices(self):
+        mutligraph = retworkx.PyGraph(multigraph=True)
+        mutligraph.extend_from_weighted_edge_list(
+            [(0, 1, 1), (0, 2, 3), (1, 2, 2), (0, 0, -10), (1, 2, 1)]
+        )
+
+        mst_edges = retworkx.minimum_spanning_edge_indices(mutligraph, weight_fn=lambda x: x)
+        self.assertEqual([0, 4], sorted(mst_edges))
+
+    def test_edge_indices_match_edges(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        edges = retworkx.minimum_spanning_edges(graph)
+        edge_indices = retworkx.minimum_spanning_edge_indices(graph)
+        self.assertEqual(
+            [(source, target) for source, target, _ in edges],
+            [graph.get_edge_endpoints_by_index(edge) for edge in edge_indices],
+        )
+
+    def test_edge_indices_nan_weight(self):
+        invalid_graph = retworkx.PyGraph()
+        invalid_graph.extend_from_weighted_edge_list([(0, 1, 0.5), (0, 2, float("nan"))])
+
+        with self.assertRaises(ValueError):
+            retworkx.minimum_spanning_edge_indices(invalid_graph, lambda x: x)
+
     def test_default_weight(self):
         weightless_graph = retworkx.PyGraph()
         weightless_graph.extend_from_edge_list(
@@ -120,3 +145,40 @@ class TestMinimumSpanningTree(unittest.TestCase):
 
         with self.assertRaises(ValueError):
             retworkx.minimum_spanning_tree(invalid_graph, lambda x: x)
+
+    def test_parallel_edges(self):
+        mst_edges = retworkx.minimum_spanning_edges(
+            self.graph, weight_fn=lambda x: x, parallel_threshold=1
+        )
+        self.assertEqualEdgeList(self.expected_edges, mst_edges)
+
+    def test_parallel_forest(self):
+        s = self.graph.add_node("S")
+        t = self.graph.add_node("T")
+        u = self.graph.add_node("U")
+        self.graph.add_node("V")
+        self.graph.remove_node(self.b)
+        self.graph.add_edges_from([(s, t, 10), (t, u, 9), (s, u, 8)])
+        expected = retworkx.minimum_spanning_edges(self.graph, weight_fn=lambda x: x)
+        msf_graph = retworkx.minimum_spanning_tree(
+            self.graph, weight_fn=lambda x: x, parallel_threshold=1
+        )
+        self.assertEqual(self.graph.nodes(), msf_graph.nodes())
+        self.assertEqualEdgeList(list(expected), msf_graph.weighted_edge_list())
+
+    def test_parallel_matches_serial(self):
+        graph = retworkx.undirected_gnm_random_graph(200, 2000, seed=42)
+        for index, edge in enumerate(graph.edge_indices()):
+            graph.update_edge_by_index(edge, float((index * 7919) % 101))
+        serial = retworkx.minimum_spanning_edges(graph, weight_fn=float)
+        parallel = retworkx.minimum_spanning_edges(graph, weight_fn=float, parallel_threshold=1)
+        self.assertEqual(len(serial), len(parallel))
+        self.assertEqual(sum(edge[2] for edge in serial), sum(edge[2] for edge in parallel))
+        self.assertEqual(sorted(edge[2] for edge in serial), [edge[2] for edge in parallel])
+
+    def test_parallel_edge_indices_multigraph(self):
+        mg = retworkx.PyGraph()
+        mg.extend_from_weighted_edge_list([(0, 1, 5), (0, 1, 2), (1, 2, 3), (0, 2, 1)])
+        serial = retworkx.minimum_spanning_edge_indices(mg, weight_fn=float)
+        parallel = retworkx.minimum_spanning_edge_indices(mg, weight_fn=float, parallel_threshold=1)
+        self.assertEqual(list(serial), list(parallel))
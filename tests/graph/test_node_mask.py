@@ -0,0 +1,56 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestNodeMask(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.grid_graph(3, 3)
+        self.masked = self.graph.copy()
+        self.masked.remove_node(4)
+
+    def test_boolean_array(self):
+        mask = np.zeros(len(self.graph), dtype=bool)
+        mask[4] = True
+        lengths = retworkx.all_pairs_dijkstra_path_lengths(self.graph, float, node_mask=mask)
+        expected = retworkx.all_pairs_dijkstra_path_lengths(self.masked, float)
+        self.assertEqual(expected, lengths)
+
+    def test_boolean_array_betweenness(self):
+        mask = np.zeros(len(self.graph), dtype=bool)
+        mask[4] = True
+        betweenness = retworkx.betweenness_centrality_array(self.graph, node_mask=mask)
+        expected = retworkx.betweenness_centrality_array(self.masked)
+        np.testing.assert_array_equal(expected, betweenness)
+
+    def test_boolean_array_reused(self):
+        mask = np.zeros(len(self.graph), dtype=bool)
+        for node in self.graph.node_indices():
+            mask[node] = True
+            betweenness = retworkx.betweenness_centrality(self.graph, node_mask=mask)
+            mask[node] = False
+            masked = self.graph.copy()
+            masked.remove_node(node)
+            self.assertEqual(retworkx.betweenness_centrality(masked), betweenness)
+
+    def test_boolean_array_too_short(self):
+        with self.assertRaises(ValueError):
+            retworkx.betweenness_centrality(self.graph, node_mask=np.zeros(4, dtype=bool))
+
+    def test_removed_node_index(self):
+        mask = np.zeros(max(self.masked.node_indices()) + 1, dtype=bool)
+        betweenness = retworkx.betweenness_centrality(self.masked, node_mask=mask)
+        self.assertEqual(retworkx.betweenness_centrality(self.masked), betweenness)
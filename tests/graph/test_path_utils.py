@@ -0,0 +1,183 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestPathUtils(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from([(0, 1, 2.0), (1, 2, 3.0), (0, 1, 1.0), (2, 3, 4.0)])
+
+    def test_is_path(self):
+        self.assertTrue(retworkx.graph_is_path(self.graph, [0, 1, 2, 3]))
+        self.assertTrue(retworkx.graph_is_path(self.graph, [3, 2, 1]))
+        self.assertTrue(retworkx.graph_is_path(self.graph, [0, 1, 0]))
+        self.assertTrue(retworkx.graph_is_path(self.graph, [2]))
+        self.assertFalse(retworkx.graph_is_path(self.graph, [0, 2]))
+        self.assertFalse(retworkx.graph_is_path(self.graph, [0, 1, 7]))
+        self.assertFalse(retworkx.graph_is_path(self.graph, [7]))
+        self.assertFalse(retworkx.graph_is_path(self.graph, []))
+
+    def test_path_weight(self):
+        self.assertEqual(
+            8.0, retworkx.graph_path_weight(self.graph, [0, 1, 2, 3], weight_fn=float)
+        )
+        self.assertEqual(4.0, retworkx.graph_path_weight(self.graph, [2, 1, 0], weight_fn=float))
+        self.assertEqual(3.0, retworkx.graph_path_weight(self.graph, [0, 1, 2, 3]))
+        self.assertEqual(
+            2.5, retworkx.graph_path_weight(self.graph, [0, 1], default_weight=2.5)
+        )
+        self.assertEqual(0.0, retworkx.graph_path_weight(self.graph, [1]))
+        self.assertEqual(0.0, retworkx.graph_path_weight(self.graph, []))
+
+    def test_path_weight_edge_path(self):
+        self.assertEqual(
+            9.0,
+            retworkx.graph_path_weight(self.graph, [0, 1, 3], weight_fn=float, edge_path=True),
+        )
+        self.assertEqual(
+            8.0,
+            retworkx.graph_path_weight(self.graph, [3, 1, 2], weight_fn=float, edge_path=True),
+        )
+        self.assertEqual(
+            3.0, retworkx.graph_path_weight(self.graph, [0, 2], weight_fn=float, edge_path=True)
+        )
+        with self.assertRaises(ValueError):
+            retworkx.graph_path_weight(self.graph, [0, 3], edge_path=True)
+        with self.assertRaises(IndexError):
+            retworkx.graph_path_weight(self.graph, [0, 42], edge_path=True)
+
+    def test_path_weight_matches_edge_path(self):
+        for path in retworkx.graph_all_simple_edge_paths(self.graph, 0, 3):
+            nodes = [0]
+            for edge in path:
+                source, target = self.graph.get_edge_endpoints_by_index(edge)
+                nodes.append(target if source == nodes[-1] else source)
+            self.assertLessEqual(
+                retworkx.graph_path_weight(self.graph, nodes, weight_fn=float),
+                retworkx.graph_path_weight(self.graph, path, weight_fn=float, edge_path=True),
+            )
+
+    def test_path_weight_invalid_path(self):
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.graph_path_weight(self.graph, [0, 2])
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_path_weight(self.graph, [0, 1, 42])
+
+    def test_path_weight_weight_fn_error(self):
+        def weight_fn(_):
+            raise KeyError("weight")
+
+        with self.assertRaises(KeyError):
+            retworkx.graph_path_weight(self.graph, [0, 1], weight_fn=weight_fn)
+
+    def test_edges_of_path(self):
+        self.assertEqual(
+            [2, 1, 3], retworkx.graph_edges_of_path(self.graph, [0, 1, 2, 3], weight_fn=float)
+        )
+        self.assertEqual([1, 2], retworkx.graph_edges_of_path(self.graph, [2, 1, 0], float))
+        # With the default weight the parallel edges tie
+        self.assertEqual([0, 1], retworkx.graph_edges_of_path(self.graph, [0, 1, 2]))
+        self.assertEqual([], retworkx.graph_edges_of_path(self.graph, [0]))
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.graph_edges_of_path(self.graph, [0, 3])
+
+    def test_universal(self):
+        self.assertTrue(retworkx.is_path(self.graph, [2, 1]))
+        self.assertEqual(4.0, retworkx.path_weight(self.graph, [2, 1, 0], weight_fn=float))
+        self.assertEqual([2], retworkx.edges_of_path(self.graph, [1, 0], weight_fn=float))
+
+
+class TestPathSimilarity(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.grid_graph(3, 3)
+        self.pos = {node: (node % 3, node // 3) for node in self.graph.node_indices()}
+
+    def test_longest_common_subpath(self):
+        res = retworkx.graph_longest_common_subpath(self.graph, [0, 1, 2, 5, 8], [6, 7, 4, 1, 2, 5])
+        self.assertEqual([1, 2, 5], res)
+
+    def test_longest_common_subpath_reversed(self):
+        res = retworkx.graph_longest_common_subpath(self.graph, [0, 1, 2, 5], [5, 2, 1, 4])
+        self.assertEqual([1, 2, 5], res)
+
+    def test_longest_common_subpath_tie(self):
+        res = retworkx.graph_longest_common_subpath(self.graph, [0, 1, 4, 7, 8], [7, 8, 5, 4, 1])
+        self.assertEqual([1, 4], res)
+
+    def test_longest_common_subpath_no_common_node(self):
+        res = retworkx.graph_longest_common_subpath(self.graph, [0, 1], [7, 8])
+        self.assertEqual([], res)
+
+    def test_longest_common_subpath_invalid_path(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_longest_common_subpath(self.graph, [0, 1], [9])
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.graph_longest_common_subpath(self.graph, [0, 2], [0, 1])
+
+    def test_path_jaccard_similarity(self):
+        res = retworkx.graph_path_jaccard_similarity(self.graph, [0, 1, 2, 5], [0, 1, 4, 5])
+        self.assertEqual(0.2, res)
+        res = retworkx.graph_path_jaccard_similarity(self.graph, [0, 1, 2, 5], [5, 2, 1, 0])
+        self.assertEqual(1.0, res)
+        res = retworkx.graph_path_jaccard_similarity(self.graph, [0, 1, 2], [6, 7, 8])
+        self.assertEqual(0.0, res)
+
+    def test_path_jaccard_similarity_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2)])
+        self.assertEqual(0.5, retworkx.graph_path_jaccard_similarity(graph, [0, 1], [0, 1, 2]))
+
+    def test_path_jaccard_similarity_no_edges(self):
+        self.assertEqual(1.0, retworkx.graph_path_jaccard_similarity(self.graph, [4], [4]))
+        self.assertEqual(0.0, retworkx.graph_path_jaccard_similarity(self.graph, [4], [5]))
+
+    def test_path_jaccard_similarity_invalid_path(self):
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            retworkx.graph_path_jaccard_similarity(self.graph, [0, 1], [0, 4])
+
+    def test_path_frechet_distance(self):
+        res = retworkx.path_frechet_distance([0, 1, 2, 5, 8], [0, 3, 6, 7, 8], self.pos)
+        self.assertAlmostEqual(2.0, res)
+        res = retworkx.path_frechet_distance([0, 1, 2], [3, 4, 5], self.pos)
+        self.assertAlmostEqual(1.0, res)
+        res = retworkx.path_frechet_distance([0, 1, 2], [0, 1, 2], self.pos)
+        self.assertEqual(0.0, res)
+
+    def test_path_frechet_distance_single_node(self):
+        res = retworkx.path_frechet_distance([0], [8], self.pos)
+        self.assertAlmostEqual(8**0.5, res)
+
+    def test_path_frechet_distance_layout(self):
+        pos = retworkx.random_layout(self.graph, seed=42)
+        res = retworkx.path_frechet_distance([0, 1, 2], [0, 1, 2], pos)
+        self.assertEqual(0.0, res)
+
+    def test_path_frechet_distance_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.path_frechet_distance([], [0], self.pos)
+        with self.assertRaises(ValueError):
+            retworkx.path_frechet_distance([0], [1], {0: (0.0, 0.0), 1: (1.0, 1.0, 1.0)})
+        with self.assertRaises(KeyError):
+            retworkx.path_frechet_distance([0], [1], {0: (0.0, 0.0)})
+
+    def test_universal(self):
+        self.assertEqual(
+            [1, 2, 5], retworkx.longest_common_subpath(self.graph, [0, 1, 2, 5], [5, 2, 1])
+        )
+        self.assertEqual(
+            0.2, retworkx.path_jaccard_similarity(self.graph, [0, 1, 2, 5], [0, 1, 4, 5])
+        )
@@ -0,0 +1,144 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def count_faces(embedding):
+    successor = {}
+    for node, neighbors in embedding.items():
+        for index, neighbor in enumerate(neighbors):
+            successor[(node, neighbor)] = neighbors[(index + 1) % len(neighbors)]
+    visited = set()
+    faces = 0
+    for half_edge in successor:
+        if half_edge in visited:
+            continue
+        faces += 1
+        source, target = half_edge
+        while (source, target) not in visited:
+            visited.add((source, target))
+            source, target = target, successor[(target, source)]
+    return faces
+
+
+def k33():
+    graph = retworkx.PyGraph()
+    graph.add_nodes_from(range(6))
+    graph.add_edges_from_no_data([(i, j) for i in range(3) for j in range(3, 6)])
+    return graph
+
+
+class TestPlanarity(unittest.TestCase):
+    def assertValidEmbedding(self, graph, embedding):
+        self.assertEqual(set(graph.node_indexes()), set(embedding))
+        for node, neighbors in embedding.items():
+            expected = {n for n in graph.neighbors(node) if n != node}
+            self.assertEqual(len(expected), len(neighbors))
+            self.assertEqual(expected, set(neighbors))
+        # Euler's formula holds for each connected component with edges
+        components = [c for c in retworkx.connected_components(graph) if len(c) > 1]
+        nodes = sum(len(c) for c in components)
+        edges = sum(len(neighbors) for neighbors in embedding.values()) // 2
+        self.assertEqual(2 * len(components), nodes - edges + count_faces(embedding))
+
+    def assertKuratowskiSubgraph(self, graph, edges):
+        self.assertFalse(retworkx.is_planar(graph.edge_subgraph(list(edges))))
+        for edge in edges:
+            self.assertTrue(graph.has_edge(*edge))
+            remaining = [other for other in edges if other != edge]
+            subgraph = graph.edge_subgraph(remaining)
+            self.assertTrue(retworkx.is_planar(subgraph))
+
+    def test_empty_graph(self):
+        graph = retworkx.PyGraph()
+        self.assertTrue(retworkx.is_planar(graph))
+        self.assertEqual((True, {}), retworkx.check_planarity(graph))
+
+    def test_mesh_graphs(self):
+        for size in range(1, 5):
+            graph = retworkx.generators.mesh_graph(size)
+            self.assertTrue(retworkx.is_planar(graph))
+            planar, embedding = retworkx.check_planarity(graph)
+            self.assertTrue(planar)
+            self.assertValidEmbedding(graph, embedding)
+        self.assertFalse(retworkx.is_planar(retworkx.generators.mesh_graph(5)))
+
+    def test_grid_graph(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        planar, embedding = retworkx.check_planarity(graph)
+        self.assertTrue(planar)
+        self.assertValidEmbedding(graph, embedding)
+
+    def test_wheel_with_self_loops_and_parallel_edges(self):
+        graph = retworkx.generators.cycle_graph(8)
+        center = graph.add_node(None)
+        graph.add_edges_from_no_data([(center, node) for node in range(8)])
+        graph.add_edges_from_no_data([(0, 0), (center, 3), (1, 2)])
+        planar, embedding = retworkx.check_planarity(graph)
+        self.assertTrue(planar)
+        self.assertValidEmbedding(graph, embedding)
+
+    def test_disconnected_graph(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.add_nodes_from(range(2))
+        graph.compose(retworkx.generators.cycle_graph(5), {})
+        planar, embedding = retworkx.check_planarity(graph)
+        self.assertTrue(planar)
+        self.assertValidEmbedding(graph, embedding)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        graph.remove_nodes_from([0, 5, 10])
+        planar, embedding = retworkx.check_planarity(graph)
+        self.assertTrue(planar)
+        self.assertValidEmbedding(graph, embedding)
+
+    def test_k5(self):
+        graph = retworkx.generators.mesh_graph(5)
+        planar, edges = retworkx.check_planarity(graph)
+        self.assertFalse(planar)
+        self.assertEqual(10, len(edges))
+        self.assertKuratowskiSubgraph(graph, edges)
+
+    def test_k33(self):
+        graph = k33()
+        self.assertFalse(retworkx.is_planar(graph))
+        planar, edges = retworkx.check_planarity(graph)
+        self.assertFalse(planar)
+        self.assertEqual(9, len(edges))
+        self.assertKuratowskiSubgraph(graph, edges)
+
+    def test_petersen_graph(self):
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        planar, edges = retworkx.check_planarity(graph)
+        self.assertFalse(planar)
+        self.assertKuratowskiSubgraph(graph, edges)
+        degrees = {}
+        for source, target in edges:
+            degrees[source] = degrees.get(source, 0) + 1
+            degrees[target] = degrees.get(target, 0) + 1
+        # The Petersen graph only contains a subdivision of K3,3
+        self.assertEqual(6, sum(1 for degree in degrees.values() if degree == 3))
+        self.assertTrue(all(degree in (2, 3) for degree in degrees.values()))
+
+    def test_random_graphs(self):
+        for seed in range(50):
+            graph = retworkx.undirected_gnp_random_graph(9, 0.4, seed=seed)
+            planar, certificate = retworkx.check_planarity(graph)
+            self.assertEqual(planar, retworkx.is_planar(graph))
+            if planar:
+                self.assertValidEmbedding(graph, certificate)
+            else:
+                self.assertKuratowskiSubgraph(graph, certificate)
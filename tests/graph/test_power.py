@@ -0,0 +1,75 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestPower(unittest.TestCase):
+    def test_path_square(self):
+        graph = retworkx.generators.path_graph(5)
+        power_graph = retworkx.power(graph, 2)
+        self.assertIsInstance(power_graph, retworkx.PyGraph)
+        self.assertEqual(
+            sorted(power_graph.edge_list()),
+            [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4)],
+        )
+        self.assertEqual(power_graph.edges(), [None] * 7)
+
+    def test_first_power(self):
+        graph = retworkx.generators.cycle_graph(6)
+        power_graph = retworkx.graph_power(graph, 1)
+        self.assertEqual(
+            sorted(tuple(sorted(edge)) for edge in power_graph.edge_list()),
+            sorted(tuple(sorted(edge)) for edge in graph.edge_list()),
+        )
+
+    def test_large_power_is_complete(self):
+        graph = retworkx.generators.path_graph(6)
+        power_graph = retworkx.power(graph, 10)
+        self.assertTrue(retworkx.is_isomorphic(power_graph, retworkx.generators.mesh_graph(6)))
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 4)])
+        power_graph = retworkx.power(graph, 3)
+        self.assertEqual(sorted(power_graph.edge_list()), [(0, 1), (0, 2), (1, 2), (3, 4)])
+
+    def test_node_payloads_and_indices(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, "x"), (1, 2, "y"), (2, 3, "z")])
+        graph.remove_node(1)
+        graph.add_edge(0, 2, "w")
+        power_graph = retworkx.power(graph, 2)
+        self.assertEqual(power_graph.node_indices(), [0, 2, 3])
+        self.assertEqual(power_graph.nodes(), ["a", "c", "d"])
+        self.assertEqual(sorted(power_graph.edge_list()), [(0, 2), (0, 3), (2, 3)])
+
+    def test_no_parallel_edges_or_self_loops(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(0, 1, None)
+        graph.add_edge(1, 1, None)
+        power_graph = retworkx.power(graph, 2)
+        self.assertTrue(power_graph.multigraph)
+        self.assertEqual(sorted(power_graph.edge_list()), [(0, 1), (0, 2), (1, 2)])
+
+    def test_null_graph(self):
+        power_graph = retworkx.power(retworkx.PyGraph(), 2)
+        self.assertEqual(len(power_graph), 0)
+
+    def test_zero_power(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.power(graph, 0)
@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import collections
+import unittest
+
+import retworkx
+
+
+class TestRandomSpanningTree(unittest.TestCase):
+    def assertSpanningForest(self, graph, edges, components):
+        self.assertEqual(sorted(edges), list(edges))
+        self.assertEqual(len(graph) - components, len(edges))
+        forest = graph.copy()
+        for edge in graph.edge_indices():
+            if edge not in edges:
+                forest.remove_edge_from_index(edge)
+        self.assertEqual(components, retworkx.number_connected_components(forest))
+
+    def test_spanning_tree(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        for seed in range(10):
+            edges = retworkx.random_spanning_tree(graph, seed=seed)
+            self.assertSpanningForest(graph, edges, 1)
+
+    def test_spanning_forest(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        graph.compose(retworkx.generators.cycle_graph(4), {})
+        graph.add_node(None)
+        graph.remove_node(4)
+        edges = retworkx.random_spanning_tree(graph, seed=42)
+        self.assertSpanningForest(graph, edges, 3)
+
+    def test_seed(self):
+        graph = retworkx.generators.mesh_graph(8)
+        self.assertEqual(
+            retworkx.random_spanning_tree(graph, seed=1234),
+            retworkx.random_spanning_tree(graph, seed=1234),
+        )
+
+    def test_uniform(self):
+        # A complete graph with 4 nodes has 16 spanning trees
+        graph = retworkx.generators.mesh_graph(4)
+        counts = collections.Counter(
+            tuple(retworkx.random_spanning_tree(graph, seed=seed)) for seed in range(3200)
+        )
+        self.assertEqual(16, len(counts))
+        for count in counts.values():
+            self.assertGreater(count, 120)
+            self.assertLess(count, 280)
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (2, 0), (1, 1)])
+        trees = {tuple(retworkx.random_spanning_tree(graph, seed=seed)) for seed in range(200)}
+        self.assertEqual({(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)}, trees)
+
+    def test_empty_graph(self):
+        self.assertEqual([], retworkx.random_spanning_tree(retworkx.PyGraph()))
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual([], retworkx.random_spanning_tree(graph))
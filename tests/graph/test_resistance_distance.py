@@ -0,0 +1,98 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import math
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestResistanceDistance(unittest.TestCase):
+    def test_path(self):
+        graph = retworkx.generators.path_graph(5)
+        for node in range(5):
+            self.assertAlmostEqual(node, retworkx.resistance_distance(graph, 0, node))
+
+    def test_cycle(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertAlmostEqual(0.75, retworkx.resistance_distance(graph, 0, 1))
+        self.assertAlmostEqual(1.0, retworkx.resistance_distance(graph, 0, 2))
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(10)
+        self.assertAlmostEqual(0.2, retworkx.resistance_distance(graph, 3, 7))
+
+    def test_parallel_edges_weighted(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edges_from([(0, 1, 2.0), (0, 1, 3.0), (1, 1, 5.0)])
+        resistance = retworkx.resistance_distance(graph, 0, 1, weight_fn=float)
+        self.assertAlmostEqual(0.2, resistance)
+
+    def test_default_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        resistance = retworkx.resistance_distance(graph, 0, 2, default_weight=4.0)
+        self.assertAlmostEqual(0.5, resistance)
+
+    def test_disconnected(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_node(None)
+        self.assertTrue(math.isinf(retworkx.resistance_distance(graph, 0, 3)))
+
+    def test_same_node(self):
+        graph = retworkx.generators.path_graph(3)
+        self.assertEqual(0.0, retworkx.resistance_distance(graph, 1, 1))
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        self.assertAlmostEqual(2.0, retworkx.resistance_distance(graph, 1, 3))
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.resistance_distance(graph, 0, 3)
+
+    def test_negative_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.resistance_distance(graph, 0, 2, weight_fn=lambda _: -1.0)
+
+
+class TestResistanceDistanceMatrix(unittest.TestCase):
+    def test_matches_pseudo_inverse(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+        laplacian = np.diag(np.sum(retworkx.adjacency_matrix(graph), axis=1))
+        laplacian -= retworkx.adjacency_matrix(graph)
+        inverse = np.linalg.pinv(laplacian)
+        diagonal = np.diag(inverse)
+        expected = diagonal[:, None] + diagonal[None, :] - 2 * inverse
+        matrix = retworkx.resistance_distance_matrix(graph)
+        np.testing.assert_allclose(expected, matrix, atol=1e-8)
+
+    def test_parallel(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+        expected = retworkx.resistance_distance_matrix(graph)
+        matrix = retworkx.resistance_distance_matrix(graph, parallel_threshold=1)
+        np.testing.assert_allclose(expected, matrix, atol=1e-8)
+
+    def test_disconnected(self):
+        graph = retworkx.generators.path_graph(2)
+        graph.add_node(None)
+        matrix = retworkx.resistance_distance_matrix(graph, null_value=np.inf)
+        expected = np.array([[0.0, 1.0, np.inf], [1.0, 0.0, np.inf], [np.inf, np.inf, 0.0]])
+        np.testing.assert_allclose(expected, matrix)
+
+    def test_removed_node(self):
+        graph = retworkx.generators.cycle_graph(5)
+        graph.remove_node(0)
+        matrix = retworkx.resistance_distance_matrix(graph)
+        self.assertEqual((4, 4), matrix.shape)
+        self.assertAlmostEqual(3.0, matrix[0, 3])
@@ -0,0 +1,94 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestRobustnessCurve(unittest.TestCase):
+    def test_star_degree(self):
+        graph = retworkx.generators.star_graph(5)
+        order, sizes = retworkx.robustness_curve(graph)
+        self.assertEqual(list(order), [0, 1, 2, 3, 4])
+        self.assertEqual(sizes, [5, 1, 1, 1, 1, 0])
+
+    def test_path_betweenness(self):
+        graph = retworkx.generators.path_graph(5)
+        order, sizes = retworkx.graph_robustness_curve(graph, order="betweenness")
+        self.assertEqual(list(order), [2, 1, 3, 0, 4])
+        self.assertEqual(sizes, [5, 2, 2, 1, 1, 0])
+
+    def test_path_edge_betweenness(self):
+        graph = retworkx.generators.path_graph(5)
+        order, sizes = retworkx.robustness_curve(graph, order="betweenness", remove_edges=True)
+        self.assertIsInstance(order, retworkx.EdgeIndices)
+        self.assertEqual(list(order), [1, 2, 0, 3])
+        self.assertEqual(sizes, [5, 3, 2, 2, 1])
+
+    def test_edge_degree(self):
+        graph = retworkx.generators.star_graph(4)
+        graph.add_edge(1, 2, None)
+        order, sizes = retworkx.robustness_curve(graph, remove_edges=True)
+        self.assertEqual(list(order), [0, 1, 3, 2])
+        self.assertEqual(sizes, [4, 4, 2, 2, 1])
+
+    def test_explicit_order(self):
+        graph = retworkx.generators.cycle_graph(6)
+        order, sizes = retworkx.robustness_curve(graph, order=[0, 3])
+        self.assertEqual(list(order), [0, 3])
+        self.assertEqual(sizes, [6, 5, 2])
+        order, sizes = retworkx.robustness_curve(graph, order=[0, 3], remove_edges=True)
+        self.assertEqual(sizes, [6, 6, 3])
+
+    def test_random_seed(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        order, sizes = retworkx.robustness_curve(graph, order="random", seed=42)
+        self.assertEqual(sorted(order), list(range(25)))
+        self.assertEqual(len(sizes), 26)
+        self.assertEqual(sizes[0], 25)
+        self.assertEqual(sizes[-1], 0)
+        same_order, same_sizes = retworkx.robustness_curve(graph, order="random", seed=42)
+        self.assertEqual(list(order), list(same_order))
+        self.assertEqual(sizes, same_sizes)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        order, sizes = retworkx.robustness_curve(graph)
+        self.assertEqual(list(order), [2, 3, 1, 4])
+        self.assertEqual(sizes, [4, 2, 1, 1, 0])
+
+    def test_null_graph(self):
+        order, sizes = retworkx.robustness_curve(retworkx.PyGraph())
+        self.assertEqual(list(order), [])
+        self.assertEqual(sizes, [0])
+
+    def test_invalid_strategy(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.robustness_curve(graph, order="closeness")
+
+    def test_duplicate_index(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.robustness_curve(graph, order=[1, 1])
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.robustness_curve(graph, order=[5])
+
+    def test_invalid_edge(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(IndexError):
+            retworkx.robustness_curve(graph, order=[5], remove_edges=True)
@@ -0,0 +1,168 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import collections
+import unittest
+
+import retworkx
+
+
+class TestSampling(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from([1.0, 2.0, 0.0, 3.0])
+        self.graph.add_edges_from([(0, 1, 1.0), (1, 2, 0.0), (2, 3, 4.0), (3, 0, 1.0)])
+
+    def test_sample_nodes_without_replacement(self):
+        nodes = retworkx.graph_sample_nodes(self.graph, 3, weight_fn=float, seed=42)
+        self.assertEqual([0, 1, 3], sorted(nodes))
+
+    def test_sample_nodes_uniform(self):
+        nodes = retworkx.graph_sample_nodes(self.graph, 4, seed=42)
+        self.assertEqual([0, 1, 2, 3], sorted(nodes))
+
+    def test_sample_nodes_seed(self):
+        first = retworkx.graph_sample_nodes(self.graph, 20, weight_fn=float, replace=True, seed=7)
+        second = retworkx.graph_sample_nodes(self.graph, 20, weight_fn=float, replace=True, seed=7)
+        self.assertEqual(first, second)
+        self.assertEqual(20, len(first))
+        self.assertNotIn(2, first)
+
+    def test_sample_nodes_with_replacement_distribution(self):
+        nodes = retworkx.graph_sample_nodes(self.graph, 60000, weight_fn=float, replace=True, seed=1)
+        counts = collections.Counter(nodes)
+        self.assertEqual({0, 1, 3}, set(counts))
+        self.assertAlmostEqual(1 / 6, counts[0] / 60000, delta=0.01)
+        self.assertAlmostEqual(2 / 6, counts[1] / 60000, delta=0.01)
+        self.assertAlmostEqual(3 / 6, counts[3] / 60000, delta=0.01)
+
+    def test_sample_nodes_without_replacement_distribution(self):
+        counts = collections.Counter()
+        for seed in range(6000):
+            counts.update(retworkx.graph_sample_nodes(self.graph, 1, weight_fn=float, seed=seed))
+        self.assertAlmostEqual(1 / 6, counts[0] / 6000, delta=0.02)
+        self.assertAlmostEqual(3 / 6, counts[3] / 6000, delta=0.02)
+
+    def test_sample_edges(self):
+        edges = retworkx.graph_sample_edges(self.graph, 3, weight_fn=float, seed=42)
+        self.assertEqual([0, 2, 3], sorted(edges))
+        edges = retworkx.graph_sample_edges(self.graph, 10, weight_fn=float, replace=True, seed=42)
+        self.assertEqual(10, len(edges))
+        self.assertNotIn(1, edges)
+
+    def test_sample_edges_removed_edge(self):
+        self.graph.remove_edge_from_index(0)
+        edges = retworkx.graph_sample_edges(self.graph, 3, seed=42)
+        self.assertEqual([1, 2, 3], sorted(edges))
+
+    def test_sample_zero(self):
+        self.assertEqual([], retworkx.graph_sample_nodes(retworkx.PyGraph(), 0))
+        self.assertEqual([], retworkx.graph_sample_edges(retworkx.PyGraph(), 0, replace=True))
+
+    def test_sample_too_large(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_nodes(self.graph, 4, weight_fn=float)
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_edges(retworkx.PyGraph(), 1, replace=True)
+
+    def test_invalid_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_nodes(self.graph, 1, weight_fn=lambda _: -1.0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_nodes(self.graph, 1, weight_fn=lambda _: float("nan"))
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_edges(self.graph, 1, weight_fn=lambda _: float("inf"))
+
+    def test_universal(self):
+        nodes = retworkx.sample_nodes(self.graph, 2, weight_fn=float, seed=3)
+        self.assertEqual(retworkx.graph_sample_nodes(self.graph, 2, weight_fn=float, seed=3), nodes)
+        edges = retworkx.sample_edges(self.graph, 2, seed=3)
+        self.assertEqual(retworkx.graph_sample_edges(self.graph, 2, seed=3), edges)
+
+
+class TestSampleSubgraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.grid_graph(10, 10)
+        for node in self.graph.node_indices():
+            self.graph[node] = node
+
+    def assert_induced_subgraph(self, subgraph, node_map):
+        self.assertEqual(sorted(node_map.keys()), list(node_map.keys()))
+        self.assertEqual(list(range(len(node_map))), list(node_map.values()))
+        for node, new_node in node_map.items():
+            self.assertEqual(node, subgraph[new_node])
+        expected = sorted(
+            (node_map[source], node_map[target])
+            for source, target in self.graph.edge_list()
+            if source in node_map and target in node_map
+        )
+        self.assertEqual(expected, sorted(subgraph.edge_list()))
+
+    def test_methods(self):
+        for method in ["snowball", "forest_fire", "random_walk"]:
+            with self.subTest(method=method):
+                subgraph, node_map = retworkx.graph_sample_subgraph(
+                    self.graph, 15, method=method, seed=42
+                )
+                self.assertIsInstance(subgraph, retworkx.PyGraph)
+                self.assertEqual(15, len(subgraph))
+                self.assert_induced_subgraph(subgraph, node_map)
+
+    def test_snowball_connected(self):
+        subgraph, _ = retworkx.graph_sample_subgraph(self.graph, 20, seed=7)
+        self.assertEqual(1, retworkx.graph_summary(subgraph)["num_components"])
+
+    def test_seed(self):
+        first = retworkx.graph_sample_subgraph(self.graph, 30, method="forest_fire", seed=3)
+        second = retworkx.graph_sample_subgraph(self.graph, 30, method="forest_fire", seed=3)
+        self.assertEqual(dict(first[1]), dict(second[1]))
+
+    def test_whole_graph(self):
+        for method in ["snowball", "forest_fire", "random_walk"]:
+            with self.subTest(method=method):
+                subgraph, node_map = retworkx.graph_sample_subgraph(
+                    self.graph, 100, method=method, seed=1
+                )
+                self.assertEqual(100, len(subgraph))
+                self.assertEqual(self.graph.num_edges(), subgraph.num_edges())
+
+    def test_restart_on_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(6)))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        for method in ["snowball", "forest_fire", "random_walk"]:
+            with self.subTest(method=method):
+                subgraph, node_map = retworkx.graph_sample_subgraph(graph, 5, method=method)
+                self.assertEqual(5, len(subgraph))
+
+    def test_zero_nodes(self):
+        subgraph, node_map = retworkx.graph_sample_subgraph(self.graph, 0)
+        self.assertEqual(0, len(subgraph))
+        self.assertEqual({}, dict(node_map))
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_subgraph(self.graph, 101)
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_subgraph(self.graph, 10, method="bfs")
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_subgraph(
+                self.graph, 10, method="forest_fire", burn_probability=1.0
+            )
+        with self.assertRaises(ValueError):
+            retworkx.graph_sample_subgraph(
+                self.graph, 10, method="random_walk", restart_probability=-0.1
+            )
+
+    def test_universal(self):
+        subgraph, node_map = retworkx.sample_subgraph(self.graph, 10, seed=5)
+        expected = retworkx.graph_sample_subgraph(self.graph, 10, seed=5)
+        self.assertEqual(dict(expected[1]), dict(node_map))
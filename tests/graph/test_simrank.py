@@ -0,0 +1,82 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestSimRank(unittest.TestCase):
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(4)
+        res = retworkx.simrank_similarity(graph)
+        self.assertIsInstance(res, np.ndarray)
+        expected = np.array(
+            [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.9, 0.9],
+                [0.0, 0.9, 1.0, 0.9],
+                [0.0, 0.9, 0.9, 1.0],
+            ]
+        )
+        np.testing.assert_allclose(res, expected)
+
+    def test_importance_factor(self):
+        graph = retworkx.generators.star_graph(3)
+        res = retworkx.graph_simrank_similarity(graph, importance_factor=0.5)
+        self.assertAlmostEqual(res[1, 2], 0.5)
+
+    def test_path_graph(self):
+        # Node pairs at an odd distance can never be reached by walks of the
+        # same length so their similarity is 0
+        graph = retworkx.generators.path_graph(4)
+        res = retworkx.simrank_similarity(graph, tolerance=1e-10)
+        self.assertEqual(res[0, 1], 0.0)
+        self.assertEqual(res[1, 2], 0.0)
+        self.assertGreater(res[0, 2], 0.0)
+        np.testing.assert_allclose(res, res.T)
+
+    def test_pairs_match_matrix(self):
+        graph = retworkx.undirected_gnp_random_graph(15, 0.3, seed=4)
+        matrix = retworkx.simrank_similarity(graph, tolerance=1e-9)
+        pairs = [(0, 1), (3, 7), (5, 5), (14, 2)]
+        res = retworkx.simrank_similarity(graph, pairs=pairs, tolerance=1e-9)
+        self.assertEqual(res.shape, (4,))
+        np.testing.assert_allclose(res, [matrix[u, v] for u, v in pairs], atol=1e-6)
+
+    def test_removed_node(self):
+        graph = retworkx.generators.star_graph(4)
+        graph.remove_node(1)
+        res = retworkx.simrank_similarity(graph)
+        self.assertEqual(res.shape, (3, 3))
+        self.assertAlmostEqual(res[1, 2], 0.9)
+        res = retworkx.simrank_similarity(graph, pairs=[(2, 3)])
+        self.assertAlmostEqual(res[0], 0.9)
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.star_graph(4)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.simrank_similarity(graph, pairs=[(0, 10)])
+
+    def test_invalid_importance_factor(self):
+        graph = retworkx.generators.star_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.simrank_similarity(graph, importance_factor=1.5)
+
+    def test_failed_to_converge(self):
+        graph = retworkx.generators.cycle_graph(5)
+        with self.assertRaises(retworkx.ConvergenceError) as cm:
+            retworkx.simrank_similarity(graph, max_iterations=2)
+        self.assertEqual(cm.exception.iterations, 2)
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.simrank_similarity(graph, pairs=[(0, 2)], max_iterations=2)
@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def ring_lattice(num_nodes, shortcuts=()):
+    graph = retworkx.generators.cycle_graph(num_nodes)
+    graph.add_edges_from_no_data([(i, (i + 2) % num_nodes) for i in range(num_nodes)])
+    graph.add_edges_from_no_data(list(shortcuts))
+    return graph
+
+
+def degrees(graph):
+    return [graph.degree(node) for node in graph.node_indices()]
+
+
+class TestReferenceGraphs(unittest.TestCase):
+    def setUp(self):
+        self.graph = ring_lattice(30, [(0, 15), (7, 22)])
+
+    def test_random_reference_keeps_degrees(self):
+        reference = retworkx.random_reference(self.graph, niter=2, seed=42)
+        self.assertEqual(degrees(self.graph), degrees(reference))
+        self.assertEqual(self.graph.num_edges(), reference.num_edges())
+        self.assertTrue(retworkx.is_connected(reference))
+        self.assertNotEqual(set(self.graph.edge_list()), set(reference.edge_list()))
+
+    def test_random_reference_lowers_clustering(self):
+        reference = retworkx.random_reference(self.graph, niter=5, seed=42)
+        self.assertLess(
+            retworkx.graph_average_clustering(reference),
+            retworkx.graph_average_clustering(self.graph),
+        )
+
+    def test_random_reference_seed(self):
+        first = retworkx.random_reference(self.graph, seed=7)
+        second = retworkx.random_reference(self.graph, seed=7)
+        self.assertEqual(list(first.edge_list()), list(second.edge_list()))
+
+    def test_random_reference_keeps_nodes(self):
+        self.graph.remove_node(3)
+        reference = retworkx.random_reference(self.graph, seed=42)
+        self.assertEqual(list(self.graph.node_indices()), list(reference.node_indices()))
+        self.assertEqual(self.graph.nodes(), reference.nodes())
+        self.assertEqual(degrees(self.graph), degrees(reference))
+
+    def test_lattice_reference_raises_clustering(self):
+        random_graph = retworkx.random_reference(self.graph, niter=5, seed=42)
+        lattice = retworkx.lattice_reference(random_graph, niter=10, seed=42)
+        self.assertEqual(degrees(random_graph), degrees(lattice))
+        self.assertTrue(retworkx.is_connected(lattice))
+        self.assertGreater(
+            retworkx.graph_average_clustering(lattice),
+            retworkx.graph_average_clustering(random_graph),
+        )
+
+    def test_too_few_nodes(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.random_reference(graph)
+        with self.assertRaises(ValueError):
+            retworkx.lattice_reference(graph)
+
+
+class TestSmallWorldCoefficients(unittest.TestCase):
+    def setUp(self):
+        self.graph = ring_lattice(30, [(0, 15), (7, 22)])
+
+    def test_sigma_small_world(self):
+        sigma = retworkx.small_world_sigma(self.graph, niter=10, nrand=4, seed=42)
+        self.assertGreater(sigma, 1.0)
+
+    def test_sigma_seed(self):
+        first = retworkx.small_world_sigma(self.graph, niter=5, nrand=3, seed=1)
+        second = retworkx.small_world_sigma(self.graph, niter=5, nrand=3, seed=1)
+        self.assertEqual(first, second)
+
+    def test_omega_lattice(self):
+        omega = retworkx.small_world_omega(ring_lattice(30), nrand=4, seed=42)
+        self.assertLess(omega, -0.3)
+
+    def test_omega_range(self):
+        omega = retworkx.small_world_omega(self.graph, nrand=4, seed=42)
+        self.assertGreater(omega, -1.0)
+        self.assertLess(omega, 1.0)
+
+    def test_disconnected(self):
+        graph = retworkx.generators.cycle_graph(4)
+        graph.add_node(None)
+        with self.assertRaises(retworkx.DisconnectedGraphError):
+            retworkx.small_world_sigma(graph)
+        with self.assertRaises(retworkx.DisconnectedGraphError):
+            retworkx.small_world_omega(graph)
+
+    def test_no_references(self):
+        with self.assertRaises(ValueError):
+            retworkx.small_world_sigma(self.graph, nrand=0)
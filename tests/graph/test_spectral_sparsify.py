@@ -0,0 +1,91 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import random
+import unittest
+
+import retworkx
+
+
+class TestSpectralSparsify(unittest.TestCase):
+    def quadratic_form(self, graph, vector, weight_fn=float):
+        return sum(
+            weight_fn(weight) * (vector[source] - vector[target]) ** 2
+            for source, target, weight in graph.weighted_edge_list()
+        )
+
+    def test_tree_edges_kept(self):
+        graph = retworkx.generators.path_graph(20)
+        sparse = retworkx.spectral_sparsify(graph, seed=42)
+        self.assertEqual(graph.edge_list(), sparse.edge_list())
+        for weight in sparse.edges():
+            self.assertAlmostEqual(1.0, weight, delta=0.5)
+
+    def test_dense_graph(self):
+        graph = retworkx.generators.mesh_graph(40)
+        sparse = retworkx.spectral_sparsify(graph, samples=2000, seed=42)
+        self.assertLess(sparse.num_edges(), graph.num_edges())
+        self.assertTrue(retworkx.is_connected(sparse))
+        edges = set(graph.edge_list())
+        for edge in sparse.edge_list():
+            self.assertIn(edge, edges)
+        rng = random.Random(42)
+        for _ in range(5):
+            vector = [rng.gauss(0, 1) for _ in range(40)]
+            expected = self.quadratic_form(graph, vector, lambda _: 1.0)
+            ratio = self.quadratic_form(sparse, vector) / expected
+            self.assertAlmostEqual(1.0, ratio, delta=0.5)
+
+    def test_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 2.0), (1, 2, 4.0)])
+        sparse = retworkx.spectral_sparsify(graph, weight_fn=float, samples=1000, seed=42)
+        self.assertEqual([(0, 1), (1, 2)], sparse.edge_list())
+        self.assertAlmostEqual(2.0, sparse.get_edge_data(0, 1), delta=0.5)
+        self.assertAlmostEqual(4.0, sparse.get_edge_data(1, 2), delta=1.0)
+
+    def test_node_indices_preserved(self):
+        graph = retworkx.generators.cycle_graph(6)
+        graph.add_node("a")
+        graph.remove_node(3)
+        sparse = retworkx.spectral_sparsify(graph, seed=42)
+        self.assertEqual(graph.node_indices(), sparse.node_indices())
+        self.assertEqual(graph.nodes(), sparse.nodes())
+
+    def test_seed(self):
+        graph = retworkx.generators.mesh_graph(20)
+        first = retworkx.spectral_sparsify(graph, samples=100, seed=1234)
+        second = retworkx.spectral_sparsify(graph, samples=100, seed=1234)
+        self.assertEqual(first.weighted_edge_list(), second.weighted_edge_list())
+
+    def test_self_loop_ignored(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(1, 1, None)
+        sparse = retworkx.spectral_sparsify(graph, seed=42)
+        self.assertEqual([(0, 1), (1, 2)], sparse.edge_list())
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        sparse = retworkx.spectral_sparsify(graph)
+        self.assertEqual(4, len(sparse))
+        self.assertEqual(0, sparse.num_edges())
+
+    def test_invalid_epsilon(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.spectral_sparsify(graph, epsilon=0.0)
+
+    def test_negative_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.spectral_sparsify(graph, weight_fn=lambda _: -1.0)
@@ -0,0 +1,117 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestAdjacencySpectrum(unittest.TestCase):
+    def test_cycle_graph(self):
+        graph = retworkx.generators.cycle_graph(6)
+        res = retworkx.adjacency_spectrum(graph)
+        self.assertIsInstance(res, np.ndarray)
+        np.testing.assert_allclose(res, [-2.0, -1.0, -1.0, 1.0, 1.0, 2.0], atol=1e-10)
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.adjacency_spectrum(graph)
+        np.testing.assert_allclose(res, [-1.0, -1.0, -1.0, -1.0, 4.0], atol=1e-10)
+
+    def test_matches_adjacency_matrix(self):
+        graph = retworkx.undirected_gnp_random_graph(30, 0.2, seed=42)
+        graph.add_edge(0, 0, None)
+        graph.add_edge(1, 2, None)
+        res = retworkx.adjacency_spectrum(graph, weight_fn=lambda _: 1.5)
+        matrix = retworkx.graph_adjacency_matrix(graph, weight_fn=lambda _: 1.5)
+        np.testing.assert_allclose(res, np.linalg.eigvalsh(matrix), atol=1e-8)
+
+    def test_eigenvectors(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        values, vectors = retworkx.adjacency_spectrum(graph, eigenvectors=True)
+        matrix = retworkx.graph_adjacency_matrix(graph)
+        self.assertEqual(vectors.shape, (20, 20))
+        np.testing.assert_allclose(matrix @ vectors, vectors * values, atol=1e-8)
+        np.testing.assert_allclose(vectors.T @ vectors, np.eye(20), atol=1e-8)
+
+    def test_top_k(self):
+        graph = retworkx.undirected_gnp_random_graph(60, 0.1, seed=7)
+        expected = retworkx.adjacency_spectrum(graph)
+        np.testing.assert_allclose(retworkx.adjacency_spectrum(graph, k=3), expected[-3:], atol=1e-8)
+        np.testing.assert_allclose(
+            retworkx.adjacency_spectrum(graph, k=3, which="smallest"), expected[:3], atol=1e-8
+        )
+
+    def test_top_k_eigenvectors(self):
+        graph = retworkx.undirected_gnp_random_graph(60, 0.1, seed=7)
+        values, vectors = retworkx.adjacency_spectrum(graph, eigenvectors=True, k=4)
+        matrix = retworkx.graph_adjacency_matrix(graph)
+        self.assertEqual(vectors.shape, (60, 4))
+        np.testing.assert_allclose(matrix @ vectors, vectors * values, atol=1e-6)
+
+    def test_top_k_repeated_eigenvalue(self):
+        graph = retworkx.generators.mesh_graph(20)
+        res = retworkx.adjacency_spectrum(graph, k=4, which="smallest")
+        np.testing.assert_allclose(res, [-1.0, -1.0, -1.0, -1.0], atol=1e-8)
+
+    def test_empty_graph(self):
+        res = retworkx.adjacency_spectrum(retworkx.PyGraph())
+        self.assertEqual(len(res), 0)
+
+    def test_invalid_k(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.adjacency_spectrum(graph, k=0)
+        with self.assertRaises(ValueError):
+            retworkx.adjacency_spectrum(graph, k=5)
+
+    def test_invalid_which(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.adjacency_spectrum(graph, k=2, which="middle")
+
+
+class TestLaplacianSpectrum(unittest.TestCase):
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(5)
+        res = retworkx.laplacian_spectrum(graph)
+        expected = [2 - 2 * np.cos(np.pi * i / 5) for i in range(5)]
+        np.testing.assert_allclose(res, expected, atol=1e-10)
+
+    def test_zero_multiplicity_is_components(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_nodes_from([None, None])
+        graph.add_edge(3, 4, None)
+        res = retworkx.laplacian_spectrum(graph)
+        self.assertEqual(sum(1 for value in res if abs(value) < 1e-10), 2)
+
+    def test_matches_degree_minus_adjacency(self):
+        graph = retworkx.undirected_gnp_random_graph(25, 0.3, seed=3)
+        graph.add_edge(2, 2, 4.0)
+        res = retworkx.laplacian_spectrum(graph, weight_fn=lambda x: x or 1.0)
+        matrix = retworkx.graph_adjacency_matrix(graph, weight_fn=lambda x: x or 1.0)
+        laplacian = np.diag(matrix.sum(axis=1)) - matrix
+        np.testing.assert_allclose(res, np.linalg.eigvalsh(laplacian), atol=1e-8)
+
+    def test_normalized(self):
+        graph = retworkx.generators.cycle_graph(8)
+        graph.add_node(None)
+        res = retworkx.laplacian_spectrum(graph, normalized=True)
+        self.assertTrue(all(-1e-10 < value < 2 + 1e-10 for value in res))
+        np.testing.assert_allclose(res[-1], 2.0, atol=1e-10)
+
+    def test_smallest_k(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        expected = retworkx.laplacian_spectrum(graph)
+        res = retworkx.laplacian_spectrum(graph, k=2, which="smallest")
+        np.testing.assert_allclose(res, expected[:2], atol=1e-8)
@@ -79,7 +79,7 @@ class TestSteinerTree(unittest.TestCase):
 
     def test_not_connected_metric_closure(self):
         self.graph.add_node(None)
-        with self.assertRaises(ValueError):
+        with self.assertRaises(retworkx.DisconnectedGraphError):
             retworkx.metric_closure(self.graph, weight_fn=float)
 
     def test_partially_connected_metric_closure(self):
@@ -150,7 +150,7 @@ class TestSteinerTree(unittest.TestCase):
 
     def test_not_connected_steiner_tree(self):
         self.graph.add_node(None)
-        with self.assertRaises(ValueError):
+        with self.assertRaises(retworkx.DisconnectedGraphError):
             retworkx.steiner_tree(self.graph, [1, 2, 8], weight_fn=float)
 
     def test_steiner_tree_empty_graph(self):
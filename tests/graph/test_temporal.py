@@ -0,0 +1,52 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestTemporal(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from([(0, 1, 2), (1, 2, 1), (2, 3, 3)])
+
+    def test_temporal_descendants(self):
+        self.assertEqual({1}, retworkx.graph_temporal_descendants(self.graph, 0))
+        self.assertEqual({0, 1, 3}, retworkx.temporal_descendants(self.graph, 2))
+
+    def test_earliest_arrival_paths(self):
+        res = retworkx.graph_earliest_arrival_paths(self.graph, 3)
+        self.assertEqual({2: [3, 2]}, res)
+        res = retworkx.earliest_arrival_paths(self.graph, 1)
+        self.assertEqual({0: [1, 0], 2: [1, 2], 3: [1, 2, 3]}, res)
+
+    def test_earliest_arrival_times(self):
+        res = retworkx.graph_earliest_arrival_times(self.graph, 2, start_time=2)
+        self.assertEqual({3: 3.0}, res)
+
+    def test_equal_times(self):
+        graph = retworkx.generators.path_graph(4)
+        for edge in graph.edge_indices():
+            graph.update_edge_by_index(edge, 1)
+        self.assertEqual({1, 2, 3}, retworkx.temporal_descendants(graph, 0))
+        self.assertEqual({1}, retworkx.temporal_descendants(graph, 0, strict=True))
+
+    def test_temporal_connected_components(self):
+        res = retworkx.graph_temporal_connected_components(self.graph)
+        self.assertEqual([{0, 1, 2, 3}], res)
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 1), (3, 3, 0)])
+        res = retworkx.temporal_connected_components(graph, strict=True)
+        self.assertEqual([{0, 1, 2}, {3}], sorted(res, key=min))
+        self.assertEqual({1}, retworkx.temporal_descendants(graph, 0, strict=True))
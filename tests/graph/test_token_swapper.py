@@ -0,0 +1,120 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import itertools
+import random
+import unittest
+
+import retworkx
+
+
+class TestGraphTokenSwapper(unittest.TestCase):
+    def assertSwapsComplete(self, graph, mapping, swaps):
+        edges = set(graph.edge_list())
+        tokens = dict(mapping)
+        for node_a, node_b in swaps:
+            self.assertTrue((node_a, node_b) in edges or (node_b, node_a) in edges)
+            token_a = tokens.pop(node_a, None)
+            token_b = tokens.pop(node_b, None)
+            if token_a is not None:
+                tokens[node_b] = token_a
+            if token_b is not None:
+                tokens[node_a] = token_b
+        for node, dest in tokens.items():
+            self.assertEqual(node, dest)
+
+    def test_simple_swap(self):
+        graph = retworkx.generators.path_graph(2)
+        swaps = retworkx.graph_token_swapper(graph, {0: 1, 1: 0}, seed=42)
+        self.assertEqual([(0, 1)], [tuple(sorted(swap)) for swap in swaps])
+
+    def test_small_path_reversal(self):
+        graph = retworkx.generators.path_graph(4)
+        mapping = {0: 3, 1: 2, 2: 1, 3: 0}
+        swaps = retworkx.graph_token_swapper(graph, mapping, seed=42)
+        self.assertSwapsComplete(graph, mapping, swaps)
+        self.assertEqual(6, len(swaps))
+
+    def test_happy_tokens(self):
+        graph = retworkx.generators.path_graph(4)
+        mapping = {0: 0, 1: 1, 2: 2, 3: 3}
+        self.assertEqual([], retworkx.graph_token_swapper(graph, mapping, seed=42))
+
+    def test_empty_mapping(self):
+        graph = retworkx.generators.path_graph(4)
+        self.assertEqual([], retworkx.graph_token_swapper(graph, {}))
+
+    def test_partial_mapping(self):
+        graph = retworkx.generators.path_graph(5)
+        mapping = {0: 4}
+        swaps = retworkx.graph_token_swapper(graph, mapping, seed=42)
+        self.assertSwapsComplete(graph, mapping, swaps)
+        self.assertEqual(4, len(swaps))
+
+    def test_grid_permutations(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        nodes = list(graph.node_indices())
+        rng = random.Random(1234)
+        for _ in range(25):
+            permutation = nodes[:]
+            rng.shuffle(permutation)
+            mapping = dict(zip(nodes, permutation))
+            with self.subTest(mapping=mapping):
+                swaps = retworkx.graph_token_swapper(graph, mapping, trials=2, seed=42)
+                self.assertSwapsComplete(graph, mapping, swaps)
+
+    def test_all_permutations_path(self):
+        graph = retworkx.generators.path_graph(4)
+        nodes = list(graph.node_indices())
+        for permutation in itertools.permutations(nodes):
+            mapping = dict(zip(nodes, permutation))
+            with self.subTest(mapping=mapping):
+                swaps = retworkx.graph_token_swapper(graph, mapping, seed=42)
+                self.assertSwapsComplete(graph, mapping, swaps)
+
+    def test_parallel(self):
+        graph = retworkx.generators.hexagonal_lattice_graph(4, 4)
+        nodes = list(graph.node_indices())
+        permutation = nodes[:]
+        random.Random(42).shuffle(permutation)
+        mapping = dict(zip(nodes, permutation))
+        swaps = retworkx.graph_token_swapper(graph, mapping, seed=42, parallel_threshold=1)
+        self.assertSwapsComplete(graph, mapping, swaps)
+        serial = retworkx.graph_token_swapper(graph, mapping, seed=42, parallel_threshold=1000)
+        self.assertEqual(swaps, serial)
+
+    def test_seed_is_deterministic(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        nodes = list(graph.node_indices())
+        mapping = dict(zip(nodes, reversed(nodes)))
+        first = retworkx.graph_token_swapper(graph, mapping, seed=7)
+        second = retworkx.graph_token_swapper(graph, mapping, seed=7)
+        self.assertEqual(first, second)
+
+    def test_disconnected_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        with self.assertRaises(retworkx.InvalidMapping):
+            retworkx.graph_token_swapper(graph, {0: 3, 3: 0})
+
+    def test_duplicate_destination(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidMapping):
+            retworkx.graph_token_swapper(graph, {0: 2, 1: 2})
+
+    def test_invalid_node(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_token_swapper(graph, {0: 42})
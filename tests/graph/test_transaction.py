@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestTransaction(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from([(0, 1, 1), (1, 2, 2), (2, 3, 3)])
+        self.graph.remove_node(3)
+
+    def assertUnchanged(self):
+        self.assertEqual([0, 1, 2], self.graph.node_indices())
+        self.assertEqual(["a", "b", "c"], self.graph.nodes())
+        self.assertEqual({0: (0, 1, 1), 1: (1, 2, 2)}, dict(self.graph.edge_index_map()))
+
+    def test_commit(self):
+        with self.graph.transaction():
+            self.graph.remove_node(1)
+            self.graph.add_edge(0, 2, 4)
+        self.assertEqual([0, 2], self.graph.node_indices())
+        self.assertEqual([(0, 2, 4)], self.graph.weighted_edge_list())
+
+    def test_rollback(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(1)
+            self.graph.add_node("e")
+            self.graph.add_edge(0, 2, 4)
+            self.graph[0] = "z"
+            self.graph.update_edge_by_index(1, 5)
+            transaction.rollback()
+            self.assertUnchanged()
+            self.graph.add_edge(0, 2, 6)
+        self.assertEqual([(0, 1, 1), (1, 2, 2), (0, 2, 6)], self.graph.weighted_edge_list())
+
+    def test_rollback_on_exception(self):
+        with self.assertRaises(KeyError):
+            with self.graph.transaction():
+                self.graph.remove_nodes_from([0, 2])
+                self.graph.contract_nodes([1], "x")
+                raise KeyError
+        self.assertUnchanged()
+
+    def test_rollback_restores_removed_indices(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_edge(0, 1)
+            self.graph.remove_node(2)
+            transaction.rollback()
+        self.assertUnchanged()
+        self.assertEqual(3, self.graph.add_node("d"))
+
+    def test_rollback_compact(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(0)
+            self.graph.compact()
+            self.assertEqual([0, 1], self.graph.node_indices())
+            transaction.rollback()
+        self.assertUnchanged()
+
+    def test_nested(self):
+        with self.graph.transaction() as outer:
+            self.graph.remove_node(0)
+            with self.graph.transaction() as inner:
+                self.graph.remove_node(1)
+                inner.rollback()
+            self.assertEqual([1, 2], self.graph.node_indices())
+            self.graph.remove_node(2)
+            with self.graph.transaction():
+                self.graph.add_node("e")
+            outer.rollback()
+        self.assertUnchanged()
+
+    def test_rollback_outer_with_inner_open(self):
+        with self.graph.transaction() as outer:
+            with self.graph.transaction():
+                with self.assertRaises(RuntimeError):
+                    outer.rollback()
+
+    def test_rollback_not_open(self):
+        transaction = self.graph.transaction()
+        with self.assertRaises(RuntimeError):
+            transaction.rollback()
+        with transaction:
+            pass
+        with self.assertRaises(RuntimeError):
+            transaction.rollback()
+        with self.assertRaises(RuntimeError):
+            with transaction:
+                pass
+
+    def test_copy_not_in_transaction(self):
+        with self.graph.transaction() as transaction:
+            self.graph.remove_node(0)
+            copy = self.graph.copy()
+            transaction.rollback()
+        self.assertUnchanged()
+        self.assertEqual([1, 2], copy.node_indices())
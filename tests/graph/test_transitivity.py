@@ -47,3 +47,12 @@ class TestTransitivity(unittest.TestCase):
         graph.add_nodes_from(list(range(3)))
         res = retworkx.transitivity(graph)
         self.assertEqual(res, 0.0)
+
+    def test_transitivity_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 2), (2, 1), (0, 0), (3, 3)]
+        )
+        res = retworkx.transitivity(graph)
+        self.assertEqual(res, 3 / 8)
@@ -0,0 +1,218 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTreeCenter(unittest.TestCase):
+    def test_path_odd(self):
+        graph = retworkx.generators.path_graph(7)
+        self.assertEqual([3], list(retworkx.tree_center(graph)))
+
+    def test_path_even(self):
+        graph = retworkx.generators.path_graph(6)
+        self.assertEqual([2, 3], list(retworkx.tree_center(graph)))
+
+    def test_star(self):
+        graph = retworkx.generators.star_graph(6)
+        self.assertEqual([0], list(retworkx.tree_center(graph)))
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.tree_center(graph)))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_node(0)
+        self.assertEqual([2, 3], list(retworkx.tree_center(graph)))
+
+    def test_null_graph(self):
+        with self.assertRaises(retworkx.NullGraph):
+            retworkx.tree_center(retworkx.PyGraph())
+
+    def test_not_a_tree(self):
+        graph = retworkx.generators.cycle_graph(4)
+        with self.assertRaises(retworkx.InvalidTreeError):
+            retworkx.tree_center(graph)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (2, 3)])
+        with self.assertRaises(retworkx.InvalidTreeError):
+            retworkx.tree_center(graph)
+
+
+class TestTreeCentroid(unittest.TestCase):
+    def test_path_even(self):
+        graph = retworkx.generators.path_graph(6)
+        self.assertEqual([2, 3], list(retworkx.tree_centroid(graph)))
+
+    def test_centroid_differs_from_center(self):
+        graph = retworkx.generators.star_graph(6)
+        graph.extend_from_edge_list([(5, 6), (6, 7), (7, 8)])
+        self.assertEqual([0], list(retworkx.tree_centroid(graph)))
+        self.assertEqual([5, 6], list(retworkx.tree_center(graph)))
+
+    def test_binomial_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(3)
+        centroid = list(retworkx.tree_centroid(graph))
+        self.assertEqual(2, len(centroid))
+        self.assertTrue(graph.has_edge(centroid[0], centroid[1]))
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.tree_centroid(graph)))
+
+    def test_null_graph(self):
+        with self.assertRaises(retworkx.NullGraph):
+            retworkx.tree_centroid(retworkx.PyGraph())
+
+    def test_not_a_tree(self):
+        graph = retworkx.generators.mesh_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.tree_centroid(graph)
+
+
+class TestTreeDiameterPath(unittest.TestCase):
+    def test_path(self):
+        graph = retworkx.generators.path_graph(5)
+        path = list(retworkx.tree_diameter_path(graph))
+        self.assertIn(path, [[0, 1, 2, 3, 4], [4, 3, 2, 1, 0]])
+
+    def test_spider(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(7))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 3), (0, 4), (4, 5), (5, 6)])
+        path = list(retworkx.tree_diameter_path(graph))
+        self.assertIn(path, [[2, 1, 0, 4, 5, 6], [6, 5, 4, 0, 1, 2]])
+
+    def test_path_is_valid(self):
+        graph = retworkx.generators.binomial_tree_graph(4)
+        path = list(retworkx.tree_diameter_path(graph))
+        self.assertEqual(8, len(path))
+        for source, target in zip(path, path[1:]):
+            self.assertTrue(graph.has_edge(source, target))
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.tree_diameter_path(graph)))
+
+    def test_null_graph(self):
+        with self.assertRaises(retworkx.NullGraph):
+            retworkx.tree_diameter_path(retworkx.PyGraph())
+
+    def test_not_a_tree(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.add_edge(0, 3, None)
+        with self.assertRaises(ValueError):
+            retworkx.tree_diameter_path(graph)
+
+
+class TestTreePredicates(unittest.TestCase):
+    def assertFailure(self, reason, counterexample, check):
+        self.assertFalse(check)
+        self.assertEqual(reason, check.reason)
+        self.assertEqual(counterexample, check.counterexample)
+
+    def test_is_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(3)
+        self.assertTrue(retworkx.graph_is_tree(graph))
+        self.assertIsNone(retworkx.graph_is_tree(graph).reason)
+
+    def test_is_tree_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(0)
+        self.assertTrue(retworkx.graph_is_tree(graph))
+        self.assertIsNone(retworkx.graph_is_tree(graph).reason)
+
+    def test_is_tree_null_graph(self):
+        self.assertFalse(retworkx.graph_is_tree(retworkx.PyGraph()))
+        self.assertFailure("null graph", None, retworkx.graph_is_tree(retworkx.PyGraph()))
+
+    def test_is_tree_cycle(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertFalse(retworkx.graph_is_tree(graph))
+        self.assertFalse(retworkx.is_tree(graph))
+        graph = retworkx.generators.path_graph(5)
+        graph.add_edge(3, 1, None)
+        self.assertFalse(retworkx.graph_is_tree(graph))
+        self.assertFailure("cycle", [(3, 1), (1, 2), (2, 3)], retworkx.graph_is_tree(graph))
+
+    def test_is_tree_grid(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        self.assertFalse(retworkx.is_tree(graph))
+        self.assertEqual("cycle", retworkx.is_tree(graph).reason)
+
+    def test_is_tree_self_loop(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(2, 2, None)
+        self.assertFalse(retworkx.graph_is_tree(graph))
+        self.assertFailure("cycle", [(2, 2)], retworkx.graph_is_tree(graph))
+
+    def test_is_tree_parallel_edge(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(1, 0, None)
+        self.assertFalse(retworkx.graph_is_tree(graph))
+        self.assertFailure("cycle", [(1, 0), (0, 1)], retworkx.graph_is_tree(graph))
+
+    def test_is_tree_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        self.assertFalse(retworkx.graph_is_tree(graph))
+        self.assertFailure("disconnected", 2, retworkx.graph_is_tree(graph))
+
+    def test_is_tree_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        self.assertTrue(retworkx.graph_is_tree(graph))
+        self.assertIsNone(retworkx.graph_is_tree(graph).reason)
+
+    def test_is_forest(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (3, 4)])
+        self.assertTrue(retworkx.graph_is_forest(graph))
+        self.assertIsNone(retworkx.graph_is_forest(graph).reason)
+        graph.add_edge(4, 3, None)
+        self.assertFalse(retworkx.graph_is_forest(graph))
+        self.assertFailure("cycle", [(4, 3), (3, 4)], retworkx.graph_is_forest(graph))
+
+    def test_is_forest_null_graph(self):
+        self.assertTrue(retworkx.graph_is_forest(retworkx.PyGraph()))
+        self.assertIsNone(retworkx.graph_is_forest(retworkx.PyGraph()).reason)
+
+    def test_check_result(self):
+        graph = retworkx.generators.path_graph(3)
+        check = retworkx.graph_is_tree(graph)
+        self.assertIsNone(check.counterexample)
+        self.assertEqual("TreeCheck(True)", repr(check))
+        graph.add_edge(2, 2, None)
+        check = retworkx.graph_is_tree(graph)
+        self.assertTrue(not check)
+        self.assertIsInstance(check.counterexample, retworkx.EdgeList)
+        self.assertEqual("TreeCheck(reason='cycle', counterexample=EdgeList[(2, 2)])", repr(check))
+
+    def test_universal(self):
+        graph = retworkx.generators.cycle_graph(3)
+        self.assertFalse(retworkx.is_tree(graph))
+        self.assertFalse(retworkx.is_forest(graph))
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_tree(graph))
+        self.assertFailure("cycle", [(2, 0), (0, 1), (1, 2)], retworkx.is_forest(graph))
+        with self.assertRaises(TypeError):
+            retworkx.is_tree(None)
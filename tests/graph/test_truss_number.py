@@ -0,0 +1,67 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTrussNumber(unittest.TestCase):
+    def test_empty(self):
+        graph = retworkx.PyGraph()
+        res = retworkx.truss_number(graph)
+        self.assertIsInstance(res, dict)
+        self.assertEqual(res, {})
+
+    def test_path(self):
+        graph = retworkx.generators.path_graph(4)
+        res = retworkx.graph_truss_number(graph)
+        self.assertEqual(res, {0: 2, 1: 2, 2: 2})
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.truss_number(graph)
+        self.assertEqual(res, {edge: 5 for edge in graph.edge_indices()})
+
+    def test_cliques_joined_by_triangles(self):
+        # A 4 clique on nodes 0-3 sharing node 3 with a triangle 3, 4, 5 and
+        # a pendant edge from node 5 to node 6
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(7))
+        graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (3, 5), (5, 6)]
+        )
+        res = retworkx.truss_number(graph)
+        self.assertEqual(res, {0: 4, 1: 4, 2: 4, 3: 4, 4: 4, 5: 4, 6: 3, 7: 3, 8: 3, 9: 2})
+
+    def test_removed_edges_are_not_counted(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.remove_edge(0, 1)
+        res = retworkx.truss_number(graph)
+        self.assertEqual(set(res.values()), {3})
+        self.assertEqual(len(res), 5)
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.generators.cycle_graph(3)
+        parallel = graph.add_edge(0, 1, None)
+        self_loop = graph.add_edge(2, 2, None)
+        res = retworkx.truss_number(graph)
+        self.assertEqual(res[parallel], 3)
+        self.assertEqual(res[self_loop], 2)
+        self.assertEqual(res[0], 3)
+
+    def test_bound_by_core_number(self):
+        graph = retworkx.undirected_gnp_random_graph(40, 0.3, seed=10)
+        truss = retworkx.truss_number(graph)
+        core = retworkx.core_number(graph)
+        for edge, (source, target) in zip(graph.edge_indices(), graph.edge_list()):
+            self.assertLessEqual(truss[edge] - 1, min(core[source], core[target]))
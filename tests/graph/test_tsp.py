@@ -0,0 +1,163 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import math
+import random
+import unittest
+
+import retworkx
+
+
+def euclidean_graph(num_nodes, seed):
+    rng = random.Random(seed)
+    points = [(rng.random(), rng.random()) for _ in range(num_nodes)]
+    graph = retworkx.PyGraph()
+    graph.add_nodes_from(points)
+    for i, j in itertools.combinations(range(num_nodes), 2):
+        graph.add_edge(i, j, math.dist(points[i], points[j]))
+    return graph
+
+
+def optimal_cost(graph):
+    nodes = list(graph.node_indices())
+    first = nodes[0]
+    weights = {}
+    for i, j, weight in graph.weighted_edge_list():
+        weights[(i, j)] = weights[(j, i)] = weight
+    best = math.inf
+    for perm in itertools.permutations(nodes[1:]):
+        tour = (first,) + perm + (first,)
+        best = min(best, sum(weights[leg] for leg in zip(tour, tour[1:])))
+    return best
+
+
+class TestTravelingSalesmanProblem(unittest.TestCase):
+    def assertValidTour(self, graph, tour, cost, source=None, weight_fn=float):
+        tour = list(tour)
+        if source is None:
+            source = graph.node_indices()[0]
+        self.assertEqual(source, tour[0])
+        self.assertEqual(source, tour[-1])
+        self.assertEqual(set(graph.node_indices()), set(tour))
+        total = 0
+        for a, b in zip(tour, tour[1:]):
+            total += min(weight_fn(weight) for weight in graph.get_all_edge_data(a, b))
+        self.assertAlmostEqual(total, cost)
+
+    def test_christofides(self):
+        for seed in range(5):
+            graph = euclidean_graph(8, seed)
+            tour, cost = retworkx.traveling_salesman_problem(
+                graph, weight_fn=float, improve=False
+            )
+            self.assertEqual(9, len(tour))
+            self.assertValidTour(graph, tour, cost)
+            self.assertLessEqual(cost, 1.5 * optimal_cost(graph) + 1e-9)
+
+    def test_greedy(self):
+        for seed in range(5):
+            graph = euclidean_graph(8, seed)
+            tour, cost = retworkx.traveling_salesman_problem(
+                graph, weight_fn=float, method="greedy", improve=False
+            )
+            self.assertEqual(9, len(tour))
+            self.assertValidTour(graph, tour, cost)
+            # The first step of the greedy tour is to the closest node
+            first_step = min(graph.edges()[edge] for edge in graph.incident_edges(0))
+            self.assertEqual(first_step, graph.get_edge_data(tour[0], tour[1]))
+
+    def test_improve(self):
+        for method in ["christofides", "greedy"]:
+            for seed in range(5):
+                graph = euclidean_graph(8, seed)
+                _, initial_cost = retworkx.traveling_salesman_problem(
+                    graph, weight_fn=float, method=method, improve=False
+                )
+                tour, cost = retworkx.traveling_salesman_problem(
+                    graph, weight_fn=float, method=method
+                )
+                self.assertValidTour(graph, tour, cost)
+                self.assertLessEqual(cost, initial_cost + 1e-9)
+                self.assertGreaterEqual(cost, optimal_cost(graph) - 1e-9)
+
+    def test_larger_graph(self):
+        graph = euclidean_graph(60, 42)
+        _, greedy_cost = retworkx.traveling_salesman_problem(
+            graph, weight_fn=float, method="greedy", improve=False
+        )
+        tour, cost = retworkx.traveling_salesman_problem(graph, weight_fn=float, source=17)
+        self.assertEqual(61, len(tour))
+        self.assertValidTour(graph, tour, cost, source=17)
+        self.assertLess(cost, greedy_cost)
+
+    def test_incomplete_graph(self):
+        graph = retworkx.generators.path_graph(4)
+        tour, cost = retworkx.traveling_salesman_problem(graph)
+        self.assertEqual([0, 1, 2, 3, 2, 1, 0], list(tour))
+        self.assertEqual(6.0, cost)
+
+    def test_grid_graph(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        tour, cost = retworkx.traveling_salesman_problem(graph, source=5)
+        self.assertEqual(16.0, cost)
+        self.assertEqual(17, len(tour))
+        self.assertValidTour(graph, tour, cost, source=5, weight_fn=lambda _: 1.0)
+
+    def test_two_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 2.5)])
+        tour, cost = retworkx.traveling_salesman_problem(graph, weight_fn=float)
+        self.assertEqual([0, 1, 0], list(tour))
+        self.assertEqual(5.0, cost)
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        tour, cost = retworkx.traveling_salesman_problem(graph)
+        self.assertEqual([0], list(tour))
+        self.assertEqual(0.0, cost)
+
+    def test_empty_graph(self):
+        tour, cost = retworkx.traveling_salesman_problem(retworkx.PyGraph())
+        self.assertEqual([], list(tour))
+        self.assertEqual(0.0, cost)
+
+    def test_removed_nodes(self):
+        graph = euclidean_graph(7, 3)
+        graph.remove_node(0)
+        graph.remove_node(4)
+        tour, cost = retworkx.traveling_salesman_problem(graph, weight_fn=float)
+        self.assertValidTour(graph, tour, cost)
+        self.assertAlmostEqual(optimal_cost(graph), cost)
+
+    def test_disconnected_graph(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 3)])
+        with self.assertRaises(retworkx.NoPathFound):
+            retworkx.traveling_salesman_problem(graph)
+
+    def test_invalid_source(self):
+        graph = retworkx.generators.cycle_graph(4)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.traveling_salesman_problem(graph, source=10)
+
+    def test_invalid_method(self):
+        graph = retworkx.generators.cycle_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.traveling_salesman_problem(graph, method="exact")
+
+    def test_negative_weight(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, -1.0), (0, 2, 1.0)])
+        with self.assertRaises(ValueError):
+            retworkx.traveling_salesman_problem(graph, weight_fn=float)
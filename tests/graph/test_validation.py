@@ -0,0 +1,67 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestValidate(unittest.TestCase):
+    def test_valid(self):
+        graph = retworkx.generators.path_graph(4)
+        self.assertEqual({}, retworkx.validate(graph))
+
+    def test_issues(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 0), (2, 2)])
+        issues = retworkx.validate(graph)
+        self.assertEqual({"self_loops", "parallel_edges", "isolated_nodes"}, set(issues))
+        self.assertEqual([3], issues["self_loops"])
+        self.assertEqual([[0, 2]], issues["parallel_edges"])
+        self.assertEqual([3, 4], issues["isolated_nodes"])
+
+    def test_expect_dag_invalid(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(TypeError):
+            retworkx.validate(graph, expect_dag=True)
+
+
+class TestRepair(unittest.TestCase):
+    def test_remove_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 1), (1, 2)])
+        self.assertEqual([0, 2], graph.remove_self_loops())
+        self.assertEqual([(0, 1), (1, 2)], graph.edge_list())
+
+    def test_remove_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 2), (1, 0, 3), (0, 1, 4)])
+        self.assertEqual([2, 3], graph.remove_parallel_edges(lambda a, b: a + b))
+        self.assertFalse(graph.has_parallel_edges())
+        self.assertEqual([(0, 1, 8), (1, 2, 2)], graph.weighted_edge_list())
+
+    def test_remove_parallel_edges_combiner_error(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, 2)])
+        with self.assertRaises(TypeError):
+            graph.remove_parallel_edges(lambda a, b: a + b)
+
+    def test_remove_isolates(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 1), (3, 3)])
+        self.assertEqual([2, 4], graph.remove_isolates())
+        self.assertEqual([0, 1, 3], graph.node_indices())
+        self.assertEqual([], graph.remove_isolates())
@@ -0,0 +1,97 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestWeisfeilerLehmanSubgraphHashes(unittest.TestCase):
+    def test_path_graph_symmetry(self):
+        graph = retworkx.generators.path_graph(5)
+        hashes = retworkx.weisfeiler_lehman_subgraph_hashes(graph, depth=2)
+        self.assertEqual(list(hashes), [0, 1, 2, 3, 4])
+        self.assertEqual(hashes[0], hashes[4])
+        self.assertEqual(hashes[1], hashes[3])
+        self.assertEqual(len({hashes[0], hashes[1], hashes[2]}), 3)
+
+    def test_depth_zero_is_degree(self):
+        graph = retworkx.generators.star_graph(4)
+        hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)
+        self.assertEqual(len(set(hashes.values())), 2)
+        self.assertEqual(hashes[1], hashes[2])
+        self.assertNotEqual(hashes[0], hashes[1])
+
+    def test_depth_distinguishes_nodes(self):
+        graph = retworkx.generators.path_graph(6)
+        shallow = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)
+        self.assertEqual(shallow[1], shallow[2])
+        deep = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=1)
+        self.assertEqual(deep[2], deep[3])
+        self.assertNotEqual(deep[1], deep[2])
+
+    def test_hashes_match_between_isomorphic_graphs(self):
+        graph = retworkx.generators.cycle_graph(5)
+        graph.add_edge(0, 2, None)
+        other = retworkx.PyGraph()
+        other.add_nodes_from(range(5))
+        mapping = [3, 1, 4, 0, 2]
+        other.add_edges_from_no_data(
+            [(mapping[source], mapping[target]) for source, target in graph.edge_list()]
+        )
+        hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph)
+        other_hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(other)
+        for node in range(5):
+            self.assertEqual(hashes[node], other_hashes[mapping[node]])
+
+    def test_hashes_are_stable(self):
+        graph = retworkx.generators.path_graph(2)
+        hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=0)
+        # 128 bit FNV-1a of "1"
+        expected = "d228cb693f1a8caf78912b704e4a4e54"
+        self.assertEqual(hashes, {0: expected, 1: expected})
+
+    def test_node_attr_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "a"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(
+            graph, depth=0, node_attr_fn=str
+        )
+        self.assertEqual(hashes[0], hashes[2])
+        self.assertNotEqual(hashes[0], hashes[1])
+
+    def test_edge_attr_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, "x"), (1, 2, "y")])
+        unlabeled = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, depth=1)
+        self.assertEqual(unlabeled[0], unlabeled[2])
+        labeled = retworkx.graph_weisfeiler_lehman_subgraph_hashes(
+            graph, depth=1, edge_attr_fn=str
+        )
+        self.assertNotEqual(labeled[0], labeled[2])
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        hashes = retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph)
+        self.assertEqual(list(hashes), [1, 2, 3])
+        self.assertEqual(hashes[1], hashes[3])
+
+    def test_invalid_attr_fn_return(self):
+        graph = retworkx.generators.path_graph(2)
+        with self.assertRaises(TypeError):
+            retworkx.graph_weisfeiler_lehman_subgraph_hashes(graph, node_attr_fn=lambda _: 1)
+
+    def test_empty_graph(self):
+        self.assertEqual({}, retworkx.graph_weisfeiler_lehman_subgraph_hashes(retworkx.PyGraph()))
@@ -0,0 +1,178 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class CountingWeights:
+    def __init__(self):
+        self.calls = 0
+
+    def __call__(self, payloads):
+        self.calls += 1
+        return [float(payload) for payload in payloads]
+
+
+class TestBatchedWeightFn(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15), (2, 3, 11), (2, 5, 2)]
+        )
+        self.digraph = retworkx.PyDiGraph()
+        self.digraph.extend_from_weighted_edge_list(
+            [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15), (2, 3, 11), (2, 5, 2)]
+        )
+
+    def test_dijkstra_shortest_paths(self):
+        counter = CountingWeights()
+        weight_fn = retworkx.BatchedWeightFn(counter)
+        res = retworkx.dijkstra_shortest_paths(self.graph, 0, weight_fn=weight_fn)
+        expected = retworkx.dijkstra_shortest_paths(self.graph, 0, weight_fn=float)
+        self.assertEqual(expected, res)
+        self.assertEqual(1, counter.calls)
+
+    def test_dijkstra_shortest_path_lengths(self):
+        for graph in (self.graph, self.digraph):
+            counter = CountingWeights()
+            res = retworkx.dijkstra_shortest_path_lengths(
+                graph, 0, retworkx.BatchedWeightFn(counter)
+            )
+            expected = retworkx.dijkstra_shortest_path_lengths(graph, 0, float)
+            self.assertEqual(expected, res)
+            self.assertEqual(1, counter.calls)
+
+    def test_all_pairs_dijkstra_path_lengths(self):
+        counter = CountingWeights()
+        res = retworkx.all_pairs_dijkstra_path_lengths(
+            self.digraph, retworkx.BatchedWeightFn(counter)
+        )
+        expected = retworkx.all_pairs_dijkstra_path_lengths(self.digraph, float)
+        self.assertEqual(expected, res)
+        self.assertEqual(1, counter.calls)
+
+    def test_astar_shortest_path(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(6)))
+        graph.add_edges_from(self.graph.weighted_edge_list())
+        counter = CountingWeights()
+        res = retworkx.astar_shortest_path(
+            graph, 0, lambda x: x == 3, retworkx.BatchedWeightFn(counter), lambda _: 0
+        )
+        self.assertEqual([0, 2, 3], res)
+        self.assertEqual(1, counter.calls)
+
+    def test_minimum_spanning_edges(self):
+        counter = CountingWeights()
+        res = retworkx.minimum_spanning_edges(
+            self.graph, weight_fn=retworkx.BatchedWeightFn(counter)
+        )
+        expected = retworkx.minimum_spanning_edges(self.graph, weight_fn=float)
+        self.assertEqual(expected, res)
+        self.assertEqual(1, counter.calls)
+
+    def test_minimum_spanning_tree_negative_weights(self):
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [-float(x) for x in payloads])
+        res = retworkx.minimum_spanning_tree(self.graph, weight_fn=weight_fn)
+        expected = retworkx.minimum_spanning_tree(self.graph, weight_fn=lambda x: -float(x))
+        self.assertEqual(expected.weighted_edge_list(), res.weighted_edge_list())
+
+    def test_shared_payload(self):
+        graph = retworkx.generators.path_graph(4)
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [2.0 for _ in payloads])
+        self.assertEqual(
+            {1: 2.0, 2: 4.0, 3: 6.0},
+            retworkx.dijkstra_shortest_path_lengths(graph, 0, weight_fn),
+        )
+
+    def test_none_payloads(self):
+        graph = retworkx.generators.path_graph(4)
+        weight_fn = retworkx.BatchedWeightFn(
+            lambda payloads: [float(i + 1) for i in range(len(payloads))]
+        )
+        self.assertEqual(
+            {1: 1.0, 2: 3.0, 3: 6.0},
+            retworkx.dijkstra_shortest_path_lengths(graph, 0, weight_fn),
+        )
+        self.assertEqual(
+            {0: 6.0, 1: 5.0, 2: 3.0},
+            retworkx.all_pairs_dijkstra_path_lengths(graph, weight_fn)[3],
+        )
+
+    def test_none_payloads_shortest_path(self):
+        for graph in (retworkx.PyGraph(), retworkx.PyDiGraph()):
+            graph.add_nodes_from(range(4))
+            graph.add_edges_from_no_data([(0, 1), (1, 3), (0, 2), (2, 3)])
+            weights = [5.0, 5.0, 1.0, 1.0]
+            weight_fn = retworkx.BatchedWeightFn(lambda payloads: weights)
+            res = retworkx.dijkstra_shortest_paths(graph, 0, target=3, weight_fn=weight_fn)
+            self.assertEqual([0, 2, 3], res[3])
+            weights = [1.0, 1.0, 5.0, 5.0]
+            res = retworkx.dijkstra_shortest_paths(graph, 0, target=3, weight_fn=weight_fn)
+            self.assertEqual([0, 1, 3], res[3])
+
+    def test_none_payloads_removed_edge(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_edge_from_index(1)
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [1.0, 2.0, 10.0])
+        self.assertEqual(
+            {1: 1.0}, dict(retworkx.dijkstra_shortest_path_lengths(graph, 0, weight_fn))
+        )
+        self.assertEqual(
+            {3: 2.0, 4: 12.0}, dict(retworkx.dijkstra_shortest_path_lengths(graph, 2, weight_fn))
+        )
+
+    def test_none_payloads_minimum_spanning_edges(self):
+        graph = retworkx.generators.cycle_graph(4)
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [1.0, 5.0, 2.0, 3.0])
+        res = retworkx.minimum_spanning_edges(graph, weight_fn=weight_fn)
+        self.assertEqual([(0, 1, None), (2, 3, None), (3, 0, None)], list(res))
+
+    def test_tuple_return(self):
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: tuple(float(x) for x in payloads))
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, weight_fn)
+        self.assertEqual(retworkx.dijkstra_shortest_path_lengths(self.graph, 0, float), res)
+
+    def test_wrong_length(self):
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [1.0])
+        with self.assertRaises(ValueError):
+            retworkx.dijkstra_shortest_path_lengths(self.graph, 0, weight_fn)
+
+    def test_negative_weight(self):
+        weight_fn = retworkx.BatchedWeightFn(lambda payloads: [-1.0 for _ in payloads])
+        with self.assertRaises(ValueError):
+            retworkx.dijkstra_shortest_paths(self.graph, 0, weight_fn=weight_fn)
+
+    def test_exception_propagates(self):
+        def weights(payloads):
+            raise KeyError("boom")
+
+        with self.assertRaises(KeyError):
+            retworkx.dijkstra_shortest_path_lengths(
+                self.graph, 0, retworkx.BatchedWeightFn(weights)
+            )
+
+    def test_call_single_payload(self):
+        counter = CountingWeights()
+        weight_fn = retworkx.BatchedWeightFn(counter)
+        self.assertEqual(3.0, weight_fn(3))
+        self.assertEqual(1, counter.calls)
+
+    def test_unbatched_function(self):
+        counter = CountingWeights()
+        weight_fn = retworkx.BatchedWeightFn(counter)
+        res = retworkx.graph_floyd_warshall(self.graph, weight_fn=weight_fn)
+        expected = retworkx.graph_floyd_warshall(self.graph, weight_fn=float)
+        self.assertEqual(expected, res)
+        self.assertEqual(self.graph.num_edges(), counter.calls)
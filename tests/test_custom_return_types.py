@@ -1252,6 +1252,150 @@ class TestNodeMap(unittest.TestCase):
         self.assertEqual(first_iter, third_iter)
 
 
+class TestEdgeMap(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.path_graph(4)
+        self.graph.remove_edge_from_index(0)
+        _, self.edge_map = self.graph.compact()
+
+    def test__eq__match(self):
+        self.assertTrue(self.edge_map == {1: 0, 2: 1})
+
+    def test__eq__not_match_keys(self):
+        self.assertFalse(self.edge_map == {0: 0, 2: 1})
+
+    def test__eq__not_match_values(self):
+        self.assertFalse(self.edge_map == {1: 1, 2: 1})
+
+    def test__eq__different_length(self):
+        self.assertFalse(self.edge_map == {1: 0})
+
+    def test__ne__match(self):
+        self.assertFalse(self.edge_map != {1: 0, 2: 1})
+
+    def test__ne__not_match(self):
+        self.assertTrue(self.edge_map != {1: 0, 2: 2})
+
+    def test__gt__not_implemented(self):
+        with self.assertRaises(NotImplementedError):
+            self.edge_map > {1: 2}
+
+    def test__len__(self):
+        self.assertEqual(2, len(self.edge_map))
+
+    def test_deepcopy(self):
+        edge_map_copy = copy.deepcopy(self.edge_map)
+        self.assertEqual(self.edge_map, edge_map_copy)
+
+    def test_pickle(self):
+        edge_map_pickle = pickle.dumps(self.edge_map)
+        edge_map_copy = pickle.loads(edge_map_pickle)
+        self.assertEqual(self.edge_map, edge_map_copy)
+
+    def test_str(self):
+        self.assertEqual("EdgeMap{1: 0, 2: 1}", str(self.edge_map))
+
+    def test_hash(self):
+        hash_res = hash(self.edge_map)
+        self.assertIsInstance(hash_res, int)
+        # Assert hash is stable
+        self.assertEqual(hash_res, hash(self.edge_map))
+
+    def test_index_error(self):
+        with self.assertRaises(IndexError):
+            self.edge_map[42]
+
+    def test_keys(self):
+        self.assertEqual([1, 2], list(self.edge_map.keys()))
+
+    def test_values(self):
+        self.assertEqual([0, 1], list(self.edge_map.values()))
+
+    def test_items(self):
+        self.assertEqual([(1, 0), (2, 1)], list(self.edge_map.items()))
+
+    def test_iter(self):
+        self.assertEqual([1, 2], list(iter(self.edge_map)))
+
+    def test_contains(self):
+        self.assertIn(1, self.edge_map)
+
+    def test_not_contains(self):
+        self.assertNotIn(0, self.edge_map)
+
+
+
+class TestEdgeFlowMapping(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_weighted_edge_list([(0, 1, 2.0), (1, 2, 1.5)])
+        _, self.flows = retworkx.digraph_maximum_flow(self.graph, 0, 2, capacity_fn=float)
+
+    def test__eq__match(self):
+        self.assertTrue(self.flows == {0: 1.5, 1: 1.5})
+
+    def test__eq__not_match_keys(self):
+        self.assertFalse(self.flows == {2: 1.5, 1: 1.5})
+
+    def test__eq__not_match_values(self):
+        self.assertFalse(self.flows == {0: 2.0, 1: 1.5})
+
+    def test__eq__different_length(self):
+        self.assertFalse(self.flows == {0: 1.5})
+
+    def test__ne__match(self):
+        self.assertFalse(self.flows != {0: 1.5, 1: 1.5})
+
+    def test__ne__not_match(self):
+        self.assertTrue(self.flows != {0: 1.5, 1: 1.0})
+
+    def test__gt__not_implemented(self):
+        with self.assertRaises(NotImplementedError):
+            self.flows > {0: 1.5}
+
+    def test__len__(self):
+        self.assertEqual(2, len(self.flows))
+
+    def test_deepcopy(self):
+        flows_copy = copy.deepcopy(self.flows)
+        self.assertEqual(self.flows, flows_copy)
+
+    def test_pickle(self):
+        flows_pickle = pickle.dumps(self.flows)
+        flows_copy = pickle.loads(flows_pickle)
+        self.assertEqual(self.flows, flows_copy)
+
+    def test_str(self):
+        self.assertEqual("EdgeFlowMapping{0: 1.5, 1: 1.5}", str(self.flows))
+
+    def test_hash(self):
+        hash_res = hash(self.flows)
+        self.assertIsInstance(hash_res, int)
+        # Assert hash is stable
+        self.assertEqual(hash_res, hash(self.flows))
+
+    def test_index_error(self):
+        with self.assertRaises(IndexError):
+            self.flows[42]
+
+    def test_keys(self):
+        self.assertEqual([0, 1], list(self.flows.keys()))
+
+    def test_values(self):
+        self.assertEqual([1.5, 1.5], list(self.flows.values()))
+
+    def test_items(self):
+        self.assertEqual([(0, 1.5), (1, 1.5)], list(self.flows.items()))
+
+    def test_iter(self):
+        self.assertEqual([0, 1], list(iter(self.flows)))
+
+    def test_contains(self):
+        self.assertIn(1, self.flows)
+
+    def test_not_contains(self):
+        self.assertNotIn(2, self.flows)
+
 class TestChainsComparisons(unittest.TestCase):
     def setUp(self):
         self.graph = retworkx.generators.cycle_graph(3)
@@ -1489,3 +1633,252 @@ class TestBiconnectedComponentsMap(unittest.TestCase):
 
     def test_not_contains(self):
         self.assertNotIn((0, 2), self.bicon_map)
+
+
+class TestSequenceProtocol(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(5)
+
+    def test_slice(self):
+        nodes = self.graph.node_indexes()
+        res = nodes[1:3]
+        self.assertIsInstance(res, retworkx.NodeIndices)
+        self.assertEqual([1, 2], res)
+
+    def test_slice_step(self):
+        nodes = self.graph.node_indexes()
+        self.assertEqual([4, 3, 2, 1, 0], nodes[::-1])
+        self.assertEqual([0, 2, 4], nodes[::2])
+
+    def test_slice_empty(self):
+        nodes = self.graph.node_indexes()
+        self.assertEqual([], nodes[3:1])
+
+    def test_slice_edge_list(self):
+        edges = self.graph.edge_list()
+        res = edges[-2:]
+        self.assertIsInstance(res, retworkx.EdgeList)
+        self.assertEqual([(2, 3), (3, 4)], res)
+
+    def test_contains(self):
+        edges = self.graph.edge_list()
+        self.assertIn((1, 2), edges)
+        self.assertNotIn((2, 1), edges)
+
+    def test_index(self):
+        nodes = self.graph.node_indexes()
+        self.assertEqual(3, nodes.index(3))
+
+    def test_index_missing(self):
+        nodes = self.graph.node_indexes()
+        with self.assertRaises(ValueError):
+            nodes.index(7)
+
+    def test_count(self):
+        self.graph.add_edge(0, 1, None)
+        edges = self.graph.edge_list()
+        self.assertEqual(2, edges.count((0, 1)))
+        self.assertEqual(0, edges.count((1, 0)))
+
+    def test_pickle_slice(self):
+        nodes = self.graph.node_indexes()[1:]
+        self.assertEqual(nodes, pickle.loads(pickle.dumps(nodes)))
+
+
+class TestMappingProtocol(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(3)
+        self.fn = lambda _: 1.0
+
+    def test_get(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        self.assertEqual(2.0, res.get(2))
+
+    def test_get_default(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        self.assertIsNone(res.get(0))
+        self.assertEqual(-1.0, res.get(0, -1.0))
+
+    def test_get_invalid_key_type(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        self.assertIsNone(res.get("a"))
+
+    def test_get_tuple_key(self):
+        res = retworkx.biconnected_components(retworkx.generators.path_graph(2))
+        self.assertEqual(0, res.get((0, 1)))
+        self.assertIsNone(res.get((1, 0)))
+
+    def test_get_path_mapping(self):
+        res = retworkx.dijkstra_shortest_paths(self.graph, 0)
+        self.assertEqual([0, 1, 2], res.get(2))
+        self.assertEqual([], res.get(0, []))
+
+    def test_or(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        merged = res | {2: 5.0, 7: 1.0}
+        self.assertIsInstance(merged, dict)
+        self.assertEqual({1: 1.0, 2: 5.0, 7: 1.0}, merged)
+
+    def test_ror(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        merged = {2: 5.0, 7: 1.0} | res
+        self.assertIsInstance(merged, dict)
+        self.assertEqual({2: 2.0, 7: 1.0, 1: 1.0}, merged)
+
+    def test_or_same_type(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        other = retworkx.dijkstra_shortest_path_lengths(self.graph, 1, self.fn)
+        self.assertEqual({1: 1.0, 2: 1.0}, res | other)
+
+    def test_or_path_mapping(self):
+        res = retworkx.dijkstra_shortest_paths(self.graph, 0)
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2], 3: [3]}, res | {3: [3]})
+
+    def test_or_invalid_type(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        with self.assertRaises(TypeError):
+            res | [1, 2]
+
+    def test_pickle_keys_iterator(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        keys = iter(res)
+        next(keys)
+        self.assertEqual([2], list(pickle.loads(pickle.dumps(keys))))
+        self.assertEqual([2], list(keys))
+
+    def test_deepcopy_items_iterator(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, self.fn)
+        items = res.items()
+        self.assertEqual([(1, 1.0), (2, 2.0)], list(copy.deepcopy(items)))
+        self.assertEqual([(1, 1.0), (2, 2.0)], list(items))
+
+
+class TestSetOperations(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(5)
+
+    def test_union(self):
+        res = self.graph.node_indexes().union([7, 1, 7])
+        self.assertIsInstance(res, retworkx.NodeIndices)
+        self.assertEqual([0, 1, 2, 3, 4, 7], res)
+
+    def test_union_same_type(self):
+        res = self.graph.successor_indices(0).union(self.graph.successor_indices(3))
+        self.assertEqual([1, 4], res)
+
+    def test_union_deduplicates(self):
+        res = retworkx.NodeIndices().union([3, 1, 3])
+        self.assertEqual([3, 1], res)
+
+    def test_intersection(self):
+        res = self.graph.node_indexes().intersection({1, 3, 9})
+        self.assertIsInstance(res, retworkx.NodeIndices)
+        self.assertEqual([1, 3], res)
+
+    def test_difference(self):
+        res = self.graph.node_indexes().difference(self.graph.successor_indices(1))
+        self.assertEqual([0, 1, 3, 4], res)
+
+    def test_unique(self):
+        self.graph.add_edge(0, 1, None)
+        edges = self.graph.edge_list()
+        res = edges.unique()
+        self.assertIsInstance(res, retworkx.EdgeList)
+        self.assertEqual([(0, 1), (1, 2), (2, 3), (3, 4)], res)
+
+    def test_sorted(self):
+        res = self.graph.edge_list()[::-1].sorted()
+        self.assertIsInstance(res, retworkx.EdgeList)
+        self.assertEqual([(0, 1), (1, 2), (2, 3), (3, 4)], res)
+
+    def test_edge_list_union(self):
+        res = self.graph.edge_list().union([(4, 0), (0, 1)])
+        self.assertEqual([(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)], res)
+
+    def test_edge_list_directed_edges_differ(self):
+        res = self.graph.edge_list().intersection([(1, 0), (1, 2)])
+        self.assertEqual([(1, 2)], res)
+
+    def test_weighted_edge_list_compares_endpoints(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (1, 2, "b"), (2, 3, "c")])
+        edges = graph.weighted_edge_list()
+        self.assertEqual([(1, 2, "b")], edges.intersection([(1, 2)]))
+        self.assertEqual([(0, 1, "a")], edges.difference([(1, 2, "x"), (2, 3)]))
+
+    def test_weighted_edge_list_union(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a")])
+        res = graph.weighted_edge_list().union([(0, 1, "b"), (1, 2, "c")])
+        self.assertIsInstance(res, retworkx.WeightedEdgeList)
+        self.assertEqual([(0, 1, "a"), (1, 2, "c")], res)
+
+    def test_weighted_edge_list_sorted(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(2, 3, "c"), (0, 1, "a")])
+        self.assertEqual([(0, 1, "a"), (2, 3, "c")], graph.weighted_edge_list().sorted())
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            self.graph.node_indexes().union(["a"])
+        with self.assertRaises(TypeError):
+            self.graph.node_indexes().intersection(1)
+
+
+class TestPathMappingConversions(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from(
+            [(0, 1, 3.0), (0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.0), (0, 3, 10.0)]
+        )
+        self.paths = retworkx.dijkstra_shortest_paths(self.graph, 0, weight_fn=float)
+
+    def test_edge_indices(self):
+        res = self.paths.edge_indices(self.graph, 3, weight_fn=float)
+        self.assertIsInstance(res, retworkx.EdgeIndices)
+        self.assertEqual([1, 2, 3], res)
+
+    def test_edge_indices_default_weight(self):
+        # Without weights the parallel edge with the lowest index is used
+        self.assertEqual([0, 2, 3], self.paths.edge_indices(self.graph, 3))
+
+    def test_path_weight(self):
+        self.assertEqual(4.0, self.paths.path_weight(self.graph, 3, weight_fn=float))
+        self.assertEqual(3.0, self.paths.path_weight(self.graph, 3))
+        self.assertEqual(1.5, self.paths.path_weight(self.graph, 2, default_weight=0.75))
+
+    def test_subgraph(self):
+        res = self.paths.subgraph(self.graph, 2)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual([0, 1, 2], res.nodes())
+        self.assertEqual([(0, 1), (0, 1), (1, 2)], res.edge_list())
+
+    def test_undirected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(1, 0, 2.0), (2, 1, 0.5)])
+        paths = retworkx.dijkstra_shortest_paths(graph, 0)
+        self.assertEqual([0, 1], paths.edge_indices(graph, 2))
+        self.assertEqual(2.5, paths.path_weight(graph, 2, weight_fn=float))
+        self.assertIsInstance(paths.subgraph(graph, 2), retworkx.PyGraph)
+
+    def test_all_pairs(self):
+        paths = retworkx.all_pairs_dijkstra_shortest_paths(self.graph, float)
+        self.assertEqual([3], paths[2].edge_indices(self.graph, 3))
+
+    def test_missing_target(self):
+        with self.assertRaises(IndexError):
+            self.paths.edge_indices(self.graph, 7)
+        with self.assertRaises(IndexError):
+            self.paths.subgraph(self.graph, 7)
+
+    def test_no_edge(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            self.paths.path_weight(graph, 3)
+
+    def test_invalid_graph_type(self):
+        with self.assertRaises(TypeError):
+            self.paths.edge_indices(None, 3)
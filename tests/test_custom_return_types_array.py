@@ -0,0 +1,54 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestCustomReturnTypesArray(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(3)
+
+    def test_node_indices(self):
+        res = np.asarray(self.graph.node_indexes())
+        self.assertTrue(np.array_equal(np.array([0, 1, 2]), res))
+
+    def test_edge_list(self):
+        res = np.asarray(self.graph.edge_list())
+        self.assertEqual((2, 2), res.shape)
+        self.assertTrue(np.array_equal(np.array([[0, 1], [1, 2]]), res))
+
+    def test_dtype(self):
+        res = np.asarray(self.graph.node_indexes(), dtype=np.float64)
+        self.assertEqual(np.float64, res.dtype)
+
+    def test_mapping_values(self):
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, lambda _: 1.5)
+        self.assertTrue(np.array_equal(np.array([1.5, 3.0]), np.asarray(res)))
+
+    def test_centrality_mapping(self):
+        res = retworkx.betweenness_centrality(self.graph)
+        array = np.asarray(res)
+        self.assertEqual(np.float64, array.dtype)
+        self.assertEqual(list(res.values()), list(array))
+
+    def test_path_mapping(self):
+        graph = retworkx.generators.directed_star_graph(3)
+        res = np.asarray(retworkx.dijkstra_shortest_paths(graph, 0))
+        self.assertTrue(np.array_equal(np.array([[0, 1], [0, 2]]), res))
+
+    def test_empty(self):
+        res = np.asarray(retworkx.PyDiGraph().node_indexes())
+        self.assertEqual((0,), res.shape)
@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestDeterministicOrder(unittest.TestCase):
+    def setUp(self):
+        # Nodes and edges are added out of index order so results which
+        # follow insertion or hash order aren't sorted by accident
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(8)))
+        self.graph.add_edges_from_no_data(
+            [(7, 6), (6, 5), (5, 7), (4, 3), (3, 2), (2, 4), (5, 4), (1, 0)]
+        )
+        self.digraph = retworkx.PyDiGraph()
+        self.digraph.add_nodes_from(list(range(8)))
+        self.digraph.add_edges_from_no_data(
+            [(7, 6), (6, 5), (5, 7), (4, 3), (3, 2), (2, 4), (5, 4), (1, 0)]
+        )
+
+    def test_graph_neighbors(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from_no_data([(0, 4), (0, 2), (0, 3), (0, 1)])
+        self.assertEqual([1, 2, 3, 4], list(graph.neighbors(0)))
+
+    def test_digraph_neighbors(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(list(range(5)))
+        dag.add_edges_from_no_data([(0, 4), (0, 2), (0, 3), (0, 1)])
+        self.assertEqual([1, 2, 3, 4], list(dag.neighbors(0)))
+
+    def test_strongly_connected_components_sorted(self):
+        components = retworkx.strongly_connected_components(self.digraph)
+        for component in components:
+            self.assertEqual(sorted(component), component)
+        self.assertEqual(
+            sorted(sorted(x) for x in components),
+            [[0], [1], [2, 3, 4], [5, 6, 7]],
+        )
+
+    def test_find_cliques_sorted(self):
+        cliques = list(retworkx.find_cliques(self.graph))
+        for clique in cliques:
+            self.assertEqual(sorted(clique), clique)
+        self.assertEqual(
+            sorted(cliques), [[0, 1], [2, 3, 4], [4, 5], [5, 6, 7]]
+        )
+
+    def test_biconnected_components_sorted(self):
+        components = retworkx.biconnected_components(self.graph)
+        keys = list(components.keys())
+        self.assertEqual(sorted(keys), keys)
+
+    def test_floyd_warshall_sorted(self):
+        lengths = retworkx.floyd_warshall(self.graph)
+        for node in self.graph.node_indices():
+            targets = list(lengths[node].keys())
+            self.assertEqual(sorted(targets), targets)
+
+    def test_digraph_find_cycle_lowest_source(self):
+        # No cycle is reachable from node 0, the lowest node index
+        self.assertEqual([], list(retworkx.digraph_find_cycle(self.digraph)))
+        self.digraph.add_edge(0, 5, None)
+        self.assertEqual(
+            [(5, 7), (7, 6), (6, 5)],
+            list(retworkx.digraph_find_cycle(self.digraph)),
+        )
+
+    def test_digraph_find_cycle_empty_graph(self):
+        self.assertEqual([], list(retworkx.digraph_find_cycle(retworkx.PyDiGraph())))
+
+    def test_cycle_basis_lowest_root(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(6)))
+        graph.add_edges_from_no_data(
+            [(5, 4), (4, 3), (3, 5), (2, 1), (1, 0), (0, 2)]
+        )
+        first = retworkx.cycle_basis(graph)
+        for _ in range(5):
+            self.assertEqual(first, retworkx.cycle_basis(graph))
+        self.assertEqual(0, first[0][-1])
+
+    def test_max_weight_matching_lower_index_first(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from([(1, 0, 5), (3, 2, 5)])
+        matching = retworkx.max_weight_matching(
+            graph, weight_fn=lambda x: x
+        )
+        self.assertEqual({(0, 1), (2, 3)}, matching)
+
+    def test_tensor_product_node_map_sorted(self):
+        first = retworkx.generators.path_graph(3)
+        second = retworkx.generators.path_graph(2)
+        _, node_map = retworkx.graph_tensor_product(first, second)
+        keys = list(node_map.keys())
+        self.assertEqual(sorted(keys), keys)
@@ -0,0 +1,33 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestExceptions(unittest.TestCase):
+    def test_value_error_subclasses(self):
+        for exc in [
+            retworkx.NegativeCycleError,
+            retworkx.DisconnectedGraphError,
+            retworkx.InvalidTreeError,
+        ]:
+            with self.subTest(exc=exc):
+                self.assertTrue(issubclass(exc, ValueError))
+
+    def test_convergence_error(self):
+        self.assertTrue(issubclass(retworkx.ConvergenceError, retworkx.FailedToConverge))
+        graph = retworkx.generators.star_graph(5)
+        with self.assertRaises(retworkx.ConvergenceError) as cm:
+            graph.to_csr_snapshot().pagerank(max_iter=3)
+        self.assertEqual(cm.exception.iterations, 3)
+        self.assertIn("3 iterations", str(cm.exception))
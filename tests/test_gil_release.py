@@ -0,0 +1,65 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+from concurrent.futures import ThreadPoolExecutor
+
+import retworkx
+
+
+class TestGILRelease(unittest.TestCase):
+    threads = 4
+
+    def assertConcurrentEqual(self, func):
+        expected = func()
+        with ThreadPoolExecutor(max_workers=self.threads) as executor:
+            futures = [executor.submit(func) for _ in range(2 * self.threads)]
+            for future in futures:
+                self.assertEqual(expected, future.result())
+
+    def test_graph_betweenness_centrality(self):
+        graph = retworkx.undirected_gnp_random_graph(60, 0.1, seed=42)
+        self.assertConcurrentEqual(lambda: dict(retworkx.betweenness_centrality(graph)))
+
+    def test_digraph_betweenness_centrality(self):
+        graph = retworkx.directed_gnp_random_graph(60, 0.1, seed=42)
+        self.assertConcurrentEqual(
+            lambda: dict(retworkx.betweenness_centrality(graph, parallel_threshold=10))
+        )
+
+    def test_is_isomorphic(self):
+        first = retworkx.generators.grid_graph(6, 6)
+        second = retworkx.generators.grid_graph(6, 6)
+        self.assertConcurrentEqual(lambda: retworkx.is_isomorphic(first, second))
+
+    def test_is_isomorphic_node_matcher(self):
+        first = retworkx.generators.directed_path_graph(20)
+        second = retworkx.generators.directed_path_graph(20)
+        self.assertConcurrentEqual(
+            lambda: retworkx.is_isomorphic(first, second, node_matcher=lambda a, b: a == b)
+        )
+
+    def test_spring_layout(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        self.assertConcurrentEqual(lambda: dict(retworkx.spring_layout(graph, seed=42)))
+
+    def test_connected_components(self):
+        graph = retworkx.undirected_gnp_random_graph(300, 0.005, seed=42)
+        self.assertConcurrentEqual(
+            lambda: retworkx.connected_components(graph, parallel_threshold=2)
+        )
+
+    def test_csr_snapshot(self):
+        graph = retworkx.directed_gnp_random_graph(60, 0.1, seed=42)
+        snapshot = graph.to_csr_snapshot()
+        self.assertConcurrentEqual(lambda: dict(snapshot.betweenness_centrality()))
+        self.assertConcurrentEqual(lambda: dict(snapshot.pagerank()))
@@ -220,3 +220,45 @@ class TestRandomSubGraphIsomorphism(unittest.TestCase):
         self.assertTrue(
             retworkx.is_subgraph_isomorphic(graph, subgraph, id_order=True, induced=False)
         )
+
+
+class TestGlobalRandomSeed(unittest.TestCase):
+    def tearDown(self):
+        retworkx.set_random_seed(None)
+
+    def pipeline(self):
+        graph = retworkx.undirected_gnm_random_graph(20, 40)
+        retworkx.graph_double_edge_swap(graph, nswap=5)
+        return (
+            graph.edge_list(),
+            dict(retworkx.random_layout(graph)),
+            dict(retworkx.spring_layout(graph, num_iter=5)),
+        )
+
+    def test_reproducible_pipeline(self):
+        retworkx.set_random_seed(42)
+        first = self.pipeline()
+        retworkx.set_random_seed(42)
+        second = self.pipeline()
+        self.assertEqual(first, second)
+        retworkx.set_random_seed(43)
+        self.assertNotEqual(first, self.pipeline())
+
+    def test_successive_calls_differ(self):
+        retworkx.set_random_seed(42)
+        first = retworkx.directed_gnp_random_graph(20, 0.5).edge_list()
+        second = retworkx.directed_gnp_random_graph(20, 0.5).edge_list()
+        self.assertNotEqual(first, second)
+
+    def test_explicit_seed_overrides_global_seed(self):
+        expected = retworkx.directed_gnp_random_graph(20, 0.5, seed=10).edge_list()
+        retworkx.set_random_seed(42)
+        graph = retworkx.directed_gnp_random_graph(20, 0.5, seed=10)
+        self.assertEqual(expected, graph.edge_list())
+
+    def test_explicit_seed_does_not_advance_global_seed(self):
+        retworkx.set_random_seed(42)
+        expected = retworkx.directed_gnp_random_graph(20, 0.5).edge_list()
+        retworkx.set_random_seed(42)
+        retworkx.directed_gnp_random_graph(20, 0.5, seed=10)
+        self.assertEqual(expected, retworkx.directed_gnp_random_graph(20, 0.5).edge_list())
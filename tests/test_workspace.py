@@ -0,0 +1,127 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestWorkspace(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15), (2, 3, 11), (2, 5, 2)]
+        )
+        self.graph.add_node(None)
+        self.digraph = retworkx.PyDiGraph()
+        self.digraph.extend_from_edge_list([(0, 1), (1, 2), (2, 3), (4, 2), (5, 0)])
+
+    def test_dijkstra_shortest_path_lengths(self):
+        workspace = retworkx.Workspace()
+        for node in self.graph.node_indices():
+            expected = retworkx.dijkstra_shortest_path_lengths(self.graph, node, float)
+            res = retworkx.dijkstra_shortest_path_lengths(
+                self.graph, node, float, workspace=workspace
+            )
+            self.assertEqual(expected, res)
+            self.assertEqual(list(expected.keys()), list(res.keys()))
+        self.assertEqual(1, workspace.generation)
+
+    def test_dijkstra_shortest_path_lengths_digraph(self):
+        workspace = retworkx.Workspace()
+        for node in self.digraph.node_indices():
+            expected = retworkx.dijkstra_shortest_path_lengths(self.digraph, node, lambda _: 1.0)
+            res = retworkx.dijkstra_shortest_path_lengths(
+                self.digraph, node, lambda _: 1.0, workspace=workspace
+            )
+            self.assertEqual(expected, res)
+
+    def test_dijkstra_shortest_path_lengths_goal(self):
+        workspace = retworkx.Workspace()
+        res = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, 0, float, goal=3, workspace=workspace
+        )
+        self.assertEqual({3: 20.0}, res)
+        res = retworkx.dijkstra_shortest_path_lengths(
+            self.graph, 0, float, goal=6, workspace=workspace
+        )
+        self.assertEqual({}, res)
+
+    def test_descendants_ancestors(self):
+        workspace = retworkx.Workspace()
+        for node in self.digraph.node_indices():
+            self.assertEqual(
+                retworkx.descendants(self.digraph, node),
+                retworkx.descendants(self.digraph, node, workspace=workspace),
+            )
+            self.assertEqual(
+                retworkx.ancestors(self.digraph, node),
+                retworkx.ancestors(self.digraph, node, workspace=workspace),
+            )
+
+    def test_generation_advances_on_graph_change(self):
+        workspace = retworkx.Workspace()
+        retworkx.descendants(self.digraph, 0, workspace=workspace)
+        retworkx.descendants(self.digraph, 1, workspace=workspace)
+        self.assertEqual(1, workspace.generation)
+        new_node = self.digraph.add_node(None)
+        self.digraph.add_edge(3, new_node, None)
+        res = retworkx.descendants(self.digraph, 0, workspace=workspace)
+        self.assertEqual({1, 2, 3, new_node}, res)
+        self.assertEqual(2, workspace.generation)
+        retworkx.dijkstra_shortest_path_lengths(self.graph, 0, float, workspace=workspace)
+        self.assertEqual(3, workspace.generation)
+
+    def test_generation_advances_on_edge_change(self):
+        workspace = retworkx.Workspace()
+        self.assertEqual({1, 2, 3}, retworkx.descendants(self.digraph, 0, workspace=workspace))
+        self.assertEqual(1, workspace.generation)
+        self.digraph.remove_edge(1, 2)
+        self.assertEqual({1}, retworkx.descendants(self.digraph, 0, workspace=workspace))
+        self.assertEqual(2, workspace.generation)
+        self.digraph.add_edge(1, 3, None)
+        self.assertEqual({1, 3}, retworkx.descendants(self.digraph, 0, workspace=workspace))
+        self.assertEqual(3, workspace.generation)
+
+    def test_generation_kept_for_snapshot(self):
+        workspace = retworkx.Workspace()
+        snapshot = self.digraph.snapshot()
+        retworkx.descendants(self.digraph, 0, workspace=workspace)
+        retworkx.descendants(snapshot, 0, workspace=workspace)
+        self.assertEqual(1, workspace.generation)
+        self.digraph[0] = "new"
+        retworkx.descendants(snapshot, 0, workspace=workspace)
+        self.assertEqual(1, workspace.generation)
+        retworkx.descendants(self.digraph, 0, workspace=workspace)
+        self.assertEqual(2, workspace.generation)
+
+    def test_reuse_with_removed_nodes(self):
+        workspace = retworkx.Workspace()
+        self.assertEqual({1, 2, 3}, retworkx.descendants(self.digraph, 0, workspace=workspace))
+        self.digraph.remove_node(2)
+        self.assertEqual({1}, retworkx.descendants(self.digraph, 0, workspace=workspace))
+
+    def test_invalid_node(self):
+        workspace = retworkx.Workspace()
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.descendants(self.digraph, 42, workspace=workspace)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dijkstra_shortest_path_lengths(self.graph, 42, float, workspace=workspace)
+
+    def test_invalid_weight(self):
+        workspace = retworkx.Workspace()
+        with self.assertRaises(ValueError):
+            retworkx.dijkstra_shortest_path_lengths(
+                self.graph, 0, lambda _: -1.0, workspace=workspace
+            )
+        res = retworkx.dijkstra_shortest_path_lengths(self.graph, 0, float, workspace=workspace)
+        self.assertEqual({1: 7.0, 2: 9.0, 3: 20.0, 5: 11.0}, res)